@@ -0,0 +1,190 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use super::batched;
+use snarkvm_curves::traits::AffineCurve;
+use snarkvm_fields::PrimeField;
+
+use anyhow::{bail, Error, Result};
+use core::str::FromStr;
+use std::sync::{
+    atomic::{AtomicU8, Ordering},
+    OnceLock,
+};
+
+/// The environment variable that selects the MSM backend, i.e. `cpu` or `cuda`.
+pub const MSM_BACKEND_ENV: &str = "SNARKVM_MSM_BACKEND";
+
+/// A backend for variable base multi-scalar multiplication over BLS12-377.
+///
+/// The backend is selected at compile time by feature (e.g. `cuda`), and at runtime by `MSM_BACKEND_ENV`
+/// or `set_msm_backend`. A Metal backend is not yet implemented.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[repr(u8)]
+pub enum MsmBackend {
+    /// The CPU backend, which uses the batched addition technique.
+    Cpu = 0,
+    /// The CUDA backend, which falls back to the CPU for small inputs, or if the GPU fails.
+    #[cfg(all(feature = "cuda", target_arch = "x86_64"))]
+    Cuda = 1,
+}
+
+impl MsmBackend {
+    /// The minimum number of scalars for which the GPU is used.
+    /// Note: Below this size, the cost of transferring the inputs to the GPU outweighs the speedup.
+    #[cfg(all(feature = "cuda", target_arch = "x86_64"))]
+    pub const CUDA_MIN_SIZE: usize = 1024;
+
+    /// Returns the name of the backend.
+    pub const fn name(&self) -> &'static str {
+        match self {
+            Self::Cpu => "cpu",
+            #[cfg(all(feature = "cuda", target_arch = "x86_64"))]
+            Self::Cuda => "cuda",
+        }
+    }
+
+    /// Returns the backend selected by `MSM_BACKEND_ENV`, or the default backend if the variable is not set.
+    /// Returns an error if the variable names an unknown or unavailable backend.
+    pub fn from_env() -> Result<Self> {
+        match std::env::var(MSM_BACKEND_ENV) {
+            Ok(name) => Self::from_str(&name),
+            Err(_) => Ok(Self::default()),
+        }
+    }
+
+    /// Returns the MSM of the given BLS12-377 bases and scalars,
+    /// or `None` if the backend does not support the input, in which case the CPU is used instead.
+    pub(super) fn msm<G: AffineCurve>(
+        &self,
+        bases: &[G],
+        scalars: &[<G::ScalarField as PrimeField>::BigInteger],
+    ) -> Option<G::Projective> {
+        match self {
+            Self::Cpu => Some(batched::msm(bases, scalars)),
+            #[cfg(all(feature = "cuda", target_arch = "x86_64"))]
+            Self::Cuda => match scalars.len() > Self::CUDA_MIN_SIZE {
+                true => snarkvm_algorithms_cuda::msm::<G, G::Projective, <G::ScalarField as PrimeField>::BigInteger>(
+                    bases, scalars,
+                )
+                .ok(),
+                false => None,
+            },
+        }
+    }
+
+    /// Returns the backend with the given discriminant.
+    fn from_u8(value: u8) -> Self {
+        match value {
+            #[cfg(all(feature = "cuda", target_arch = "x86_64"))]
+            1 => Self::Cuda,
+            _ => Self::Cpu,
+        }
+    }
+}
+
+impl Default for MsmBackend {
+    /// Returns the default MSM backend, i.e. CUDA if it is enabled, and the CPU otherwise.
+    fn default() -> Self {
+        #[cfg(all(feature = "cuda", target_arch = "x86_64"))]
+        return Self::Cuda;
+        #[cfg(not(all(feature = "cuda", target_arch = "x86_64")))]
+        return Self::Cpu;
+    }
+}
+
+impl FromStr for MsmBackend {
+    type Err = Error;
+
+    /// Returns the MSM backend with the given name.
+    fn from_str(name: &str) -> Result<Self> {
+        match name.trim().to_ascii_lowercase().as_str() {
+            "cpu" => Ok(Self::Cpu),
+            #[cfg(all(feature = "cuda", target_arch = "x86_64"))]
+            "cuda" => Ok(Self::Cuda),
+            #[cfg(not(all(feature = "cuda", target_arch = "x86_64")))]
+            "cuda" => bail!("The 'cuda' MSM backend requires the 'cuda' feature on x86_64"),
+            "metal" => bail!("The 'metal' MSM backend is not yet supported"),
+            _ => bail!("Unknown MSM backend '{name}'"),
+        }
+    }
+}
+
+/// Returns the process-wide MSM backend, initialized from `MSM_BACKEND_ENV` on first use.
+/// If the variable is invalid, the default backend is used; call `MsmBackend::from_env` to surface the error.
+fn msm_backend_cell() -> &'static AtomicU8 {
+    static BACKEND: OnceLock<AtomicU8> = OnceLock::new();
+    BACKEND.get_or_init(|| AtomicU8::new(MsmBackend::from_env().unwrap_or_default() as u8))
+}
+
+/// Returns the process-wide MSM backend.
+pub fn msm_backend() -> MsmBackend {
+    MsmBackend::from_u8(msm_backend_cell().load(Ordering::Relaxed))
+}
+
+/// Sets the process-wide MSM backend, which applies to every subsequent MSM in the process.
+pub fn set_msm_backend(backend: MsmBackend) {
+    msm_backend_cell().store(backend as u8, Ordering::Relaxed);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::msm::{standard, VariableBase};
+    use snarkvm_curves::{
+        bls12_377::{Fr, G1Affine},
+        ProjectiveCurve,
+    };
+    use snarkvm_utilities::{rand::TestRng, Uniform};
+
+    #[test]
+    fn test_msm_backend_from_str() {
+        assert_eq!(MsmBackend::from_str("cpu").unwrap(), MsmBackend::Cpu);
+        assert_eq!(MsmBackend::from_str(" CPU ").unwrap().name(), "cpu");
+        assert!(MsmBackend::from_str("unknown").is_err());
+        assert!(MsmBackend::from_str("metal").is_err());
+        #[cfg(not(all(feature = "cuda", target_arch = "x86_64")))]
+        assert!(MsmBackend::from_str("cuda").is_err());
+    }
+
+    #[test]
+    fn test_cpu_msm_backend() {
+        let rng = &mut TestRng::default();
+        let bases = (0..100).map(|_| G1Affine::rand(rng)).collect::<Vec<_>>();
+        let scalars = (0..100).map(|_| Fr::rand(rng).to_bigint()).collect::<Vec<_>>();
+
+        let expected = standard::msm(&bases, &scalars).to_affine();
+        let candidate = MsmBackend::Cpu.msm(&bases, &scalars).unwrap().to_affine();
+        assert_eq!(expected, candidate);
+    }
+
+    #[test]
+    fn test_set_msm_backend() {
+        let rng = &mut TestRng::default();
+        let bases = (0..100).map(|_| G1Affine::rand(rng)).collect::<Vec<_>>();
+        let scalars = (0..100).map(|_| Fr::rand(rng).to_bigint()).collect::<Vec<_>>();
+
+        // Install the CPU backend, and ensure `VariableBase::msm` is unchanged.
+        let previous = msm_backend();
+        set_msm_backend(MsmBackend::Cpu);
+        assert_eq!(msm_backend(), MsmBackend::Cpu);
+
+        let candidate = VariableBase::msm(&bases, &scalars).to_affine();
+        assert_eq!(standard::msm(&bases, &scalars).to_affine(), candidate);
+
+        // Restore the previous backend.
+        set_msm_backend(previous);
+        assert_eq!(msm_backend(), previous);
+    }
+}
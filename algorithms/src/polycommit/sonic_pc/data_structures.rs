@@ -386,6 +386,18 @@ impl<'a, E: PairingEngine> CommitterUnionKey<'a, E> {
     }
 }
 
+/// The accumulated elements of a batch check, prior to the final product of pairings.
+/// Multiple pairing checks can be combined into one, with `SonicKZG10::check_pairings`.
+#[derive(Clone, Debug)]
+pub struct PairingCheck<E: PairingEngine> {
+    /// The combined commitments, indexed by their degree bound.
+    pub(crate) combined_comms: BTreeMap<Option<usize>, E::G1Projective>,
+    /// The combined witness.
+    pub(crate) combined_witness: E::G1Projective,
+    /// The combined adjusted witness.
+    pub(crate) combined_adjusted_witness: E::G1Projective,
+}
+
 /// Evaluation proof at a query set.
 #[derive(Clone, Debug, Default, PartialEq, Eq, Hash, CanonicalSerialize, CanonicalDeserialize)]
 pub struct BatchProof<E: PairingEngine>(pub(crate) Vec<kzg10::KZGProof<E>>);
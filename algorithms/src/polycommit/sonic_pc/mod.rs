@@ -24,6 +24,7 @@ use hashbrown::HashMap;
 use itertools::Itertools;
 use snarkvm_curves::traits::{AffineCurve, PairingCurve, PairingEngine, ProjectiveCurve};
 use snarkvm_fields::{One, Zero};
+use snarkvm_utilities::rand::Uniform;
 
 use anyhow::{bail, ensure, Result};
 use core::{convert::TryInto, marker::PhantomData, ops::Mul};
@@ -39,6 +40,9 @@ pub use data_structures::*;
 mod polynomial;
 pub use polynomial::*;
 
+mod shard;
+pub use shard::*;
+
 /// Polynomial commitment based on [\[KZG10\]][kzg], with degree enforcement and
 /// batching taken from [[MBKM19, “Sonic”]][sonic] (more precisely, their
 /// counterparts in [[Gabizon19, “AuroraLight”]][al] that avoid negative G1 powers).
@@ -349,6 +353,22 @@ impl<E: PairingEngine, S: AlgebraicSponge<E::Fq, 2>> SonicKZG10<E, S> {
         proof: &BatchProof<E>,
         fs_rng: &mut S,
     ) -> Result<bool>
+    where
+        Commitment<E>: 'a,
+    {
+        let check = Self::prepare_batch_check(vk, commitments, query_set, values, proof, fs_rng)?;
+        Self::check_pairing(vk, check)
+    }
+
+    /// Accumulates the elements of `batch_check`, without performing the final product of pairings.
+    pub fn prepare_batch_check<'a>(
+        vk: &UniversalVerifier<E>,
+        commitments: impl IntoIterator<Item = &'a LabeledCommitment<Commitment<E>>>,
+        query_set: &QuerySet<E::Fr>,
+        values: &Evaluations<E::Fr>,
+        proof: &BatchProof<E>,
+        fs_rng: &mut S,
+    ) -> Result<PairingCheck<E>>
     where
         Commitment<E>: 'a,
     {
@@ -405,9 +425,44 @@ impl<E: PairingEngine, S: AlgebraicSponge<E::Fq, 2>> SonicKZG10<E, S> {
             randomizer = fs_rng.squeeze_short_nonnative_field_element::<E::Fr>();
         }
 
-        let result = Self::check_elems(vk, combined_comms, combined_witness, combined_adjusted_witness);
         end_timer!(batch_check_time);
-        result.map_err(Into::into)
+        Ok(PairingCheck { combined_comms, combined_witness, combined_adjusted_witness })
+    }
+
+    /// Checks the given pairing check, with a product of pairings.
+    pub fn check_pairing(vk: &UniversalVerifier<E>, check: PairingCheck<E>) -> Result<bool> {
+        Self::check_elems(vk, check.combined_comms, check.combined_witness, check.combined_adjusted_witness)
+    }
+
+    /// Checks all of the given pairing checks, with a single product of pairings.
+    /// Each pairing check is scaled by a random 128-bit challenge, sampled from `rng`,
+    /// which must not be known to the provers in advance.
+    pub fn check_pairings<R: RngCore>(
+        vk: &UniversalVerifier<E>,
+        checks: impl IntoIterator<Item = PairingCheck<E>>,
+        rng: &mut R,
+    ) -> Result<bool> {
+        let check_time = start_timer!(|| "Combining pairing checks");
+        let mut combined_comms = BTreeMap::new();
+        let mut combined_witness = E::G1Projective::zero();
+        let mut combined_adjusted_witness = E::G1Projective::zero();
+
+        for (i, check) in checks.into_iter().enumerate() {
+            // We don't need to sample randomizers from the full field,
+            // only from 128-bit strings.
+            let randomizer = match i {
+                0 => E::Fr::one(),
+                _ => u128::rand(rng).into(),
+            };
+            for (degree_bound, comm) in check.combined_comms {
+                *combined_comms.entry(degree_bound).or_insert_with(E::G1Projective::zero) += &comm.mul(randomizer);
+            }
+            combined_witness += &check.combined_witness.mul(randomizer);
+            combined_adjusted_witness += &check.combined_adjusted_witness.mul(randomizer);
+        }
+        end_timer!(check_time);
+
+        Self::check_elems(vk, combined_comms, combined_witness, combined_adjusted_witness)
     }
 
     pub fn open_combinations<'a>(
@@ -483,6 +538,31 @@ impl<E: PairingEngine, S: AlgebraicSponge<E::Fq, 2>> SonicKZG10<E, S> {
         proof: &BatchLCProof<E>,
         fs_rng: &mut S,
     ) -> Result<bool>
+    where
+        Commitment<E>: 'a,
+    {
+        let check = Self::prepare_check_combinations(
+            vk,
+            linear_combinations,
+            commitments,
+            query_set,
+            evaluations,
+            proof,
+            fs_rng,
+        )?;
+        Self::check_pairing(vk, check)
+    }
+
+    /// Accumulates the elements of `check_combinations`, without performing the final product of pairings.
+    pub fn prepare_check_combinations<'a>(
+        vk: &UniversalVerifier<E>,
+        linear_combinations: impl IntoIterator<Item = &'a LinearCombination<E::Fr>>,
+        commitments: impl IntoIterator<Item = &'a LabeledCommitment<Commitment<E>>>,
+        query_set: &QuerySet<E::Fr>,
+        evaluations: &Evaluations<E::Fr>,
+        proof: &BatchLCProof<E>,
+        fs_rng: &mut S,
+    ) -> Result<PairingCheck<E>>
     where
         Commitment<E>: 'a,
     {
@@ -540,7 +620,7 @@ impl<E: PairingEngine, S: AlgebraicSponge<E::Fq, 2>> SonicKZG10<E, S> {
             .collect::<Vec<_>>();
         end_timer!(combined_comms_norm_time);
 
-        Self::batch_check(vk, &lc_commitments, query_set, &evaluations, proof, fs_rng)
+        Self::prepare_batch_check(vk, &lc_commitments, query_set, &evaluations, proof, fs_rng)
     }
 }
 
@@ -682,9 +762,13 @@ impl<E: PairingEngine, S: AlgebraicSponge<E::Fq, 2>> SonicKZG10<E, S> {
 mod tests {
     #![allow(non_camel_case_types)]
 
-    use super::{CommitterKey, SonicKZG10};
-    use crate::{crypto_hash::PoseidonSponge, polycommit::test_templates::*};
-    use snarkvm_curves::bls12_377::{Bls12_377, Fq};
+    use super::{CommitterKey, CommitterKeyShard, SonicKZG10};
+    use crate::{
+        crypto_hash::PoseidonSponge,
+        fft::DensePolynomial,
+        polycommit::{kzg10, test_templates::*},
+    };
+    use snarkvm_curves::bls12_377::{Bls12_377, Fq, Fr};
     use snarkvm_utilities::{rand::TestRng, FromBytes, ToBytes};
 
     use rand::distributions::Distribution;
@@ -711,6 +795,39 @@ mod tests {
         assert_eq!(&ck_bytes, &ck_recovered_bytes);
     }
 
+    #[test]
+    fn test_committer_key_shards() {
+        let rng = &mut TestRng::default();
+        let max_degree = rand::distributions::Uniform::from(8..=64).sample(rng);
+        let supported_degree = rand::distributions::Uniform::from(1..=max_degree).sample(rng);
+
+        let lagrange_size = |d: usize| if d.is_power_of_two() { d } else { d.next_power_of_two() >> 1 };
+
+        let pp = PC_Bls12_377::load_srs(max_degree).unwrap();
+        let (ck, _vk) = PC_Bls12_377::trim(&pp, supported_degree, [lagrange_size(supported_degree)], 0, None).unwrap();
+        let num_powers = ck.powers_of_beta_g.len();
+
+        // Ensure an invalid number of shards is rejected.
+        assert!(ck.shard(0).is_err());
+        assert!(ck.shard(num_powers + 1).is_err());
+
+        for num_shards in [1, 2, 3, num_powers].into_iter().filter(|n| *n <= num_powers) {
+            let shards = ck.shard(num_shards).unwrap();
+            assert_eq!(shards.len(), num_shards);
+            assert_eq!(shards.iter().map(|shard| shard.powers_of_beta_g().len()).sum::<usize>(), num_powers);
+
+            // Ensure the combined partial commitments match the commitment to the full polynomial.
+            let polynomial = DensePolynomial::<Fr>::rand(supported_degree, rng);
+            let powers = kzg10::Powers {
+                powers_of_beta_g: ck.powers_of_beta_g.as_slice().into(),
+                powers_of_beta_times_gamma_g: ck.powers_of_beta_times_gamma_g.as_slice().into(),
+            };
+            let (expected, _) = kzg10::KZG10::commit(&powers, &(&polynomial).into(), None, None).unwrap();
+            let candidate = CommitterKeyShard::combine(shards.iter().map(|shard| shard.commit(&polynomial)));
+            assert_eq!(expected, candidate);
+        }
+    }
+
     #[test]
     fn test_single_poly() {
         single_poly_test::<Bls12_377, Sponge>().expect("test failed for bls12-377");
@@ -0,0 +1,90 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::{Commitment, CommitterKey};
+use crate::{fft::DensePolynomial, msm::VariableBase};
+use snarkvm_curves::traits::{PairingEngine, ProjectiveCurve};
+use snarkvm_fields::{PrimeField, Zero};
+use snarkvm_utilities::serialize::*;
+
+use anyhow::{ensure, Result};
+use core::ops::Range;
+
+/// `CommitterKeyShard` is a contiguous range of the powers of beta in a `CommitterKey`.
+///
+/// Shards can be handed to separate processes (or devices), each of which computes a partial
+/// commitment over its range of coefficients. The partial commitments are then combined into the
+/// commitment to the full polynomial with `CommitterKeyShard::combine`. As a KZG opening is itself
+/// a commitment to a witness polynomial, openings are computed in the same way.
+///
+/// Shards only cover the non-hiding part of a commitment; any blinding must be added by the combiner.
+#[derive(Clone, Debug, PartialEq, Eq, CanonicalSerialize, CanonicalDeserialize)]
+pub struct CommitterKeyShard<E: PairingEngine> {
+    /// The index of the first power of beta in this shard.
+    offset: usize,
+    /// The powers of beta in this shard.
+    powers_of_beta_g: Vec<E::G1Affine>,
+}
+
+impl<E: PairingEngine> CommitterKey<E> {
+    /// Splits the powers of beta into `num_shards` contiguous shards of (nearly) equal size.
+    pub fn shard(&self, num_shards: usize) -> Result<Vec<CommitterKeyShard<E>>> {
+        let num_powers = self.powers_of_beta_g.len();
+        ensure!(num_shards > 0, "The number of shards must be greater than zero");
+        ensure!(num_shards <= num_powers, "Cannot split {num_powers} powers into {num_shards} shards");
+
+        // Distribute the remainder across the first shards.
+        let (shard_size, remainder) = (num_powers / num_shards, num_powers % num_shards);
+        let mut offset = 0;
+        let mut shards = Vec::with_capacity(num_shards);
+        for i in 0..num_shards {
+            let size = shard_size + usize::from(i < remainder);
+            let powers_of_beta_g = self.powers_of_beta_g[offset..offset + size].to_vec();
+            shards.push(CommitterKeyShard { offset, powers_of_beta_g });
+            offset += size;
+        }
+        Ok(shards)
+    }
+}
+
+impl<E: PairingEngine> CommitterKeyShard<E> {
+    /// Returns the range of coefficients covered by this shard.
+    pub fn range(&self) -> Range<usize> {
+        self.offset..self.offset + self.powers_of_beta_g.len()
+    }
+
+    /// Returns the powers of beta in this shard.
+    pub fn powers_of_beta_g(&self) -> &[E::G1Affine] {
+        &self.powers_of_beta_g
+    }
+
+    /// Returns the partial commitment to the coefficients of `polynomial` covered by this shard.
+    pub fn commit(&self, polynomial: &DensePolynomial<E::Fr>) -> E::G1Projective {
+        let num_coeffs = polynomial.coeffs.len();
+        let range = self.range();
+        let (start, end) = (range.start.min(num_coeffs), range.end.min(num_coeffs));
+        if start == end {
+            return E::G1Projective::zero();
+        }
+
+        let scalars = polynomial.coeffs[start..end].iter().map(|coeff| coeff.to_bigint()).collect::<Vec<_>>();
+        VariableBase::msm(&self.powers_of_beta_g[..scalars.len()], &scalars)
+    }
+
+    /// Combines the partial commitments from each shard into a commitment to the full polynomial.
+    pub fn combine(partial_commitments: impl IntoIterator<Item = E::G1Projective>) -> Commitment<E> {
+        Commitment::<E>(partial_commitments.into_iter().sum::<E::G1Projective>().to_affine())
+    }
+}
@@ -92,6 +92,12 @@ impl<F: PrimeField, SM: SNARKMode> AHPForR1CS<F, SM> {
         })
     }
 
+    /// Returns the ID of the indexed circuit, i.e. the hash of its index info and R1CS matrices,
+    /// without computing the FFT precomputations.
+    pub fn index_id<C: ConstraintSynthesizer<F>>(c: &C) -> Result<CircuitId> {
+        Ok(Self::index_helper(c).map_err(|e| anyhow!("{e:?}"))?.id)
+    }
+
     pub fn index_polynomial_info<'a>(
         circuit_ids: impl Iterator<Item = &'a CircuitId> + 'a,
     ) -> BTreeMap<PolynomialLabel, PolynomialInfo> {
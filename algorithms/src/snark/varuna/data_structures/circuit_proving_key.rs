@@ -25,6 +25,7 @@ use snarkvm_utilities::{
     ToBytes,
 };
 
+use anyhow::Result;
 use std::{cmp::Ordering, sync::Arc};
 
 /// Proving key for a specific circuit (i.e., R1CS matrices).
@@ -39,6 +40,14 @@ pub struct CircuitProvingKey<E: PairingEngine, SM: SNARKMode> {
     pub committer_key: Arc<sonic_pc::CommitterKey<E>>,
 }
 
+impl<E: PairingEngine, SM: SNARKMode> CircuitProvingKey<E, SM> {
+    /// Splits the committer key into `num_shards` shards, which can be used to compute
+    /// partial commitments and openings in separate processes.
+    pub fn shard_committer_key(&self, num_shards: usize) -> Result<Vec<sonic_pc::CommitterKeyShard<E>>> {
+        self.committer_key.shard(num_shards)
+    }
+}
+
 impl<E: PairingEngine, SM: SNARKMode> ToBytes for CircuitProvingKey<E, SM> {
     fn write_le<W: Write>(&self, mut writer: W) -> io::Result<()> {
         CanonicalSerialize::serialize_compressed(&self.circuit_verifying_key, &mut writer)?;
@@ -14,14 +14,17 @@
 // limitations under the License.
 
 use crate::{
-    polycommit::sonic_pc,
+    polycommit::{
+        kzg10::{KZGCommitment, KZGProof},
+        sonic_pc,
+    },
     snark::varuna::{ahp, CircuitId},
     SNARKError,
 };
 
-use ahp::prover::{FourthMessage, ThirdMessage};
-use snarkvm_curves::PairingEngine;
-use snarkvm_fields::PrimeField;
+use ahp::prover::{FourthMessage, MatrixSums, ThirdMessage};
+use snarkvm_curves::{AffineCurve, PairingEngine};
+use snarkvm_fields::{PrimeField, Zero};
 use snarkvm_utilities::{
     error,
     io::{self, Read, Write},
@@ -264,6 +267,51 @@ impl<E: PairingEngine> Proof<E> {
         &self.batch_sizes
     }
 
+    /// Returns the number of bytes in a proof for the given batch sizes, with the given compression mode.
+    /// This allows the size of a proof to be known before it is computed, as it only depends on its shape.
+    pub fn serialized_size_for(batch_sizes: &[usize], is_hiding: bool, compress: Compress) -> usize {
+        // Note: The values are placeholders, as the size of each element is fixed.
+        let commitment = KZGCommitment::<E>(E::G1Affine::zero());
+        let sums = MatrixSums { sum_a: E::Fr::zero(), sum_b: E::Fr::zero(), sum_c: E::Fr::zero() };
+        let num_circuits = batch_sizes.len();
+        let num_instances = batch_sizes.iter().sum::<usize>();
+        let proof = Self {
+            batch_sizes: batch_sizes.to_vec(),
+            commitments: Commitments {
+                witness_commitments: vec![WitnessCommitments { w: commitment }; num_instances],
+                mask_poly: is_hiding.then_some(commitment),
+                h_0: commitment,
+                g_1: commitment,
+                h_1: commitment,
+                g_a_commitments: vec![commitment; num_circuits],
+                g_b_commitments: vec![commitment; num_circuits],
+                g_c_commitments: vec![commitment; num_circuits],
+                h_2: commitment,
+            },
+            evaluations: Evaluations {
+                g_1_eval: E::Fr::zero(),
+                g_a_evals: vec![E::Fr::zero(); num_circuits],
+                g_b_evals: vec![E::Fr::zero(); num_circuits],
+                g_c_evals: vec![E::Fr::zero(); num_circuits],
+            },
+            third_msg: ThirdMessage {
+                sums: batch_sizes.iter().map(|batch_size| vec![sums.clone(); *batch_size]).collect(),
+            },
+            fourth_msg: FourthMessage { sums: vec![sums; num_circuits] },
+            // The polynomials are opened at three query points (alpha, beta, and gamma).
+            pc_proof: sonic_pc::BatchLCProof {
+                proof: sonic_pc::BatchProof(vec![
+                    KZGProof {
+                        w: E::G1Affine::zero(),
+                        random_v: is_hiding.then(E::Fr::zero)
+                    };
+                    3
+                ]),
+            },
+        };
+        proof.serialized_size(compress)
+    }
+
     /// Check that the number of messages is consistent with our batch size
     pub fn check_batch_sizes(&self) -> Result<(), SNARKError> {
         let total_instances = self
@@ -385,13 +433,7 @@ mod test {
 
     use super::*;
 
-    use crate::{
-        polycommit::{
-            kzg10::{KZGCommitment, KZGProof},
-            sonic_pc::BatchProof,
-        },
-        snark::varuna::prover::MatrixSums,
-    };
+    use crate::polycommit::sonic_pc::BatchProof;
     use snarkvm_curves::{
         bls12_377::{Bls12_377, Fr, G1Affine},
         AffineCurve,
@@ -515,4 +557,27 @@ mod test {
             }
         }
     }
+
+    #[test]
+    fn test_serialized_size_for() {
+        let rng = &mut TestRng::default();
+
+        for i in 1..11 {
+            for j in 1..11 {
+                let is_hiding = i * j % 2 == 1;
+                let batch_sizes = vec![j; i];
+                let commitments = rand_commitments(j, i, !is_hiding);
+                let evaluations: Evaluations<Fr> = rand_evaluations(rng, i);
+                let third_msg = ThirdMessage::<Fr> { sums: vec![vec![rand_sums(rng); j]; i] };
+                let fourth_msg = FourthMessage::<Fr> { sums: vec![rand_sums(rng); i] };
+                let pc_proof = sonic_pc::BatchLCProof { proof: BatchProof(vec![rand_kzg_proof(rng, !is_hiding); 3]) };
+                let proof = Proof { batch_sizes, commitments, evaluations, third_msg, fourth_msg, pc_proof };
+                for (compress, _) in modes() {
+                    let expected = Proof::serialized_size(&proof, compress);
+                    let candidate = Proof::<Bls12_377>::serialized_size_for(proof.batch_sizes(), is_hiding, compress);
+                    assert_eq!(expected, candidate);
+                }
+            }
+        }
+    }
 }
@@ -77,6 +77,15 @@ mod varuna {
                         let proof = $snark_inst::prove(universal_prover, &fs_parameters, &index_pk, &circ, rng).unwrap();
                         println!("Called prover");
 
+                        // Verify multiple proofs with a single product of pairings.
+                        let second_proof = $snark_inst::prove(universal_prover, &fs_parameters, &index_pk, &circ, rng).unwrap();
+                        let (valid, fake) = ([public_inputs.clone()], [fake_inputs.clone()]);
+                        let batches = [(BTreeMap::from([(&index_vk, &valid[..])]), &proof), (BTreeMap::from([(&index_vk, &valid[..])]), &second_proof)];
+                        assert!($snark_inst::verify_batches(universal_verifier, &fs_parameters, &batches, rng).unwrap());
+                        let batches = [(BTreeMap::from([(&index_vk, &valid[..])]), &proof), (BTreeMap::from([(&index_vk, &fake[..])]), &second_proof)];
+                        assert!(!$snark_inst::verify_batches(universal_verifier, &fs_parameters, &batches, rng).unwrap());
+                        println!("Called batch verifier");
+
                         assert!($snark_inst::verify(universal_verifier, &fs_parameters, &index_vk, public_inputs, &proof).unwrap());
                         println!("Called verifier");
                         eprintln!("\nShould not verify (i.e. verifier messages should print below):");
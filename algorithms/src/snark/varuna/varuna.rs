@@ -21,6 +21,7 @@ use crate::{
         CommitterUnionKey,
         Evaluations,
         LabeledCommitment,
+        PairingCheck,
         QuerySet,
         Randomness,
         SonicKZG10,
@@ -45,7 +46,7 @@ use crate::{
 use rand::RngCore;
 use snarkvm_curves::PairingEngine;
 use snarkvm_fields::{One, PrimeField, ToConstraintField, Zero};
-use snarkvm_utilities::{to_bytes_le, ToBytes};
+use snarkvm_utilities::{cfg_iter, to_bytes_le, ToBytes};
 
 use anyhow::{anyhow, bail, ensure, Result};
 use core::marker::PhantomData;
@@ -54,6 +55,8 @@ use rand::{CryptoRng, Rng};
 use std::{borrow::Borrow, collections::BTreeMap, ops::Deref, sync::Arc};
 
 use crate::srs::UniversalProver;
+#[cfg(not(feature = "serial"))]
+use rayon::prelude::*;
 #[cfg(not(feature = "std"))]
 use snarkvm_utilities::println;
 
@@ -629,6 +632,72 @@ where
         keys_to_inputs: &BTreeMap<&Self::VerifyingKey, &[B]>,
         proof: &Self::Proof,
     ) -> Result<bool> {
+        // Run the verifier, up to the final product of pairings.
+        let Some(pairing_check) = Self::prepare_verify_batch(universal_verifier, fs_parameters, keys_to_inputs, proof)?
+        else {
+            return Ok(false);
+        };
+
+        let pc_time = start_timer!(|| "Checking linear combinations with PC");
+        let evaluations_are_correct = SonicKZG10::<E, FS>::check_pairing(universal_verifier, pairing_check)?;
+        end_timer!(pc_time);
+
+        if !evaluations_are_correct {
+            #[cfg(debug_assertions)]
+            eprintln!("SonicKZG10::Check failed for the AHP Verifier linear equations");
+        }
+        Ok(evaluations_are_correct)
+    }
+}
+
+impl<E: PairingEngine, FS, SM> VarunaSNARK<E, FS, SM>
+where
+    E::Fr: PrimeField,
+    E::Fq: PrimeField,
+    FS: AlgebraicSponge<E::Fq, 2>,
+    SM: SNARKMode,
+{
+    /// Verifies multiple batch proofs, with a single product of pairings.
+    /// The randomizers used to combine the pairing checks are sampled from `rng`.
+    ///
+    /// Note: On failure, this does not identify which of the batch proofs is invalid.
+    pub fn verify_batches<B: Borrow<[E::Fr]> + Sync, R: RngCore>(
+        universal_verifier: &UniversalVerifier<E>,
+        fs_parameters: &FS::Parameters,
+        batches: &[(BTreeMap<&CircuitVerifyingKey<E>, &[B]>, &Proof<E>)],
+        rng: &mut R,
+    ) -> Result<bool> {
+        if batches.is_empty() {
+            bail!(SNARKError::EmptyBatch);
+        }
+        let verifier_time = start_timer!(|| format!("Varuna::VerifyBatches with {} proofs", batches.len()));
+
+        // Run the verifier for each batch proof, up to the final product of pairings.
+        let pairing_checks = cfg_iter!(batches)
+            .map(|(keys_to_inputs, proof)| {
+                Self::prepare_verify_batch(universal_verifier, fs_parameters, keys_to_inputs, proof)
+            })
+            .collect::<Result<Option<Vec<_>>>>()?;
+        // If any of the batch proofs has an incorrect ZK mode, then the batch is invalid.
+        let Some(pairing_checks) = pairing_checks else {
+            end_timer!(verifier_time);
+            return Ok(false);
+        };
+
+        // Check all of the batch proofs, with a single product of pairings.
+        let evaluations_are_correct = SonicKZG10::<E, FS>::check_pairings(universal_verifier, pairing_checks, rng)?;
+        end_timer!(verifier_time, || format!("Result: {evaluations_are_correct}"));
+        Ok(evaluations_are_correct)
+    }
+
+    /// Runs the verifier for the given batch proof, up to the final product of pairings.
+    /// Returns `None` if the proof has an incorrect ZK mode.
+    fn prepare_verify_batch<B: Borrow<[E::Fr]>>(
+        universal_verifier: &UniversalVerifier<E>,
+        fs_parameters: &FS::Parameters,
+        keys_to_inputs: &BTreeMap<&CircuitVerifyingKey<E>, &[B]>,
+        proof: &Proof<E>,
+    ) -> Result<Option<PairingCheck<E>>> {
         if keys_to_inputs.is_empty() {
             bail!(SNARKError::EmptyBatch);
         }
@@ -724,7 +793,7 @@ where
                 "Found `mask_poly` in the first round when not expected, or proof has incorrect hiding mode ({})",
                 proof.pc_proof.is_hiding()
             );
-            return Ok(false);
+            return Ok(None);
         }
 
         let verifier_time = start_timer!(|| format!("Varuna::Verify with batch sizes: {:?}", batch_sizes));
@@ -897,8 +966,8 @@ where
         )?;
         end_timer!(lc_time);
 
-        let pc_time = start_timer!(|| "Checking linear combinations with PC");
-        let evaluations_are_correct = SonicKZG10::<E, FS>::check_combinations(
+        let pc_time = start_timer!(|| "Accumulating linear combinations with PC");
+        let pairing_check = SonicKZG10::<E, FS>::prepare_check_combinations(
             universal_verifier,
             lc_s.values(),
             &commitments,
@@ -909,15 +978,7 @@ where
         )?;
         end_timer!(pc_time);
 
-        if !evaluations_are_correct {
-            #[cfg(debug_assertions)]
-            eprintln!("SonicKZG10::Check failed using final challenge: {:?}", verifier_state.gamma);
-        }
-
-        end_timer!(verifier_time, || format!(
-            " SonicKZG10::Check for AHP Verifier linear equations: {}",
-            evaluations_are_correct & proof_has_correct_zk_mode
-        ));
-        Ok(evaluations_are_correct & proof_has_correct_zk_mode)
+        end_timer!(verifier_time);
+        Ok(Some(pairing_check))
     }
 }
@@ -22,3 +22,12 @@ pub struct UniversalProver<E: PairingEngine> {
     pub max_degree: usize,
     pub _unused: Option<E>,
 }
+
+#[cfg(feature = "msm")]
+impl<E: PairingEngine> UniversalProver<E> {
+    /// Sets the MSM backend used for proving, e.g. a hardware accelerator.
+    /// Note: The MSM backend is process-wide, so it applies to all provers, and to every other MSM in the process.
+    pub fn set_global_msm_backend(backend: crate::msm::MsmBackend) {
+        crate::msm::set_msm_backend(backend);
+    }
+}
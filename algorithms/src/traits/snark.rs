@@ -18,7 +18,8 @@ use snarkvm_fields::PrimeField;
 use snarkvm_utilities::{CanonicalDeserialize, CanonicalSerialize, FromBytes, ToBytes};
 
 use anyhow::Result;
-use rand::{CryptoRng, Rng};
+use rand::{CryptoRng, Rng, SeedableRng};
+use rand_chacha::ChaChaRng;
 use std::{borrow::Borrow, collections::BTreeMap, fmt::Debug};
 
 /// Defines trait that describes preparing from an unprepared version to a prepare version.
@@ -88,6 +89,33 @@ pub trait SNARK {
         rng: &mut R,
     ) -> Result<Self::Proof>;
 
+    /// Returns a proof whose randomness is derived deterministically from the given `seed`.
+    /// Proofs for the same seed, proving key, and constraints are bit-for-bit identical.
+    ///
+    /// This is intended for test vectors and regression suites; a seed must never be reused across witnesses.
+    fn prove_with_seed<C: ConstraintSynthesizer<Self::ScalarField>>(
+        universal_prover: &Self::UniversalProver,
+        fs_parameters: &Self::FSParameters,
+        proving_key: &Self::ProvingKey,
+        constraints: &C,
+        seed: [u8; 32],
+    ) -> Result<Self::Proof> {
+        Self::prove(universal_prover, fs_parameters, proving_key, constraints, &mut ChaChaRng::from_seed(seed))
+    }
+
+    /// Returns a batch proof whose randomness is derived deterministically from the given `seed`.
+    /// Proofs for the same seed, proving keys, and constraints are bit-for-bit identical.
+    ///
+    /// This is intended for test vectors and regression suites; a seed must never be reused across witnesses.
+    fn prove_batch_with_seed<C: ConstraintSynthesizer<Self::ScalarField>>(
+        universal_prover: &Self::UniversalProver,
+        fs_parameters: &Self::FSParameters,
+        keys_to_constraints: &BTreeMap<&Self::ProvingKey, &[C]>,
+        seed: [u8; 32],
+    ) -> Result<Self::Proof> {
+        Self::prove_batch(universal_prover, fs_parameters, keys_to_constraints, &mut ChaChaRng::from_seed(seed))
+    }
+
     fn verify_vk<C: ConstraintSynthesizer<Self::ScalarField>>(
         universal_verifier: &Self::UniversalVerifier,
         fs_parameters: &Self::FSParameters,
@@ -0,0 +1,54 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use super::*;
+
+impl<E: Environment> HashToCurve<E> {
+    /// Returns `len_in_bytes` uniform bytes derived from the given message, as `expand_message_xmd` over SHA3-256.
+    /// The message must be byte-aligned, and the output bytes are given as bits.
+    pub fn expand_message(&self, message: &[Boolean<E>], len_in_bytes: usize) -> Vec<Boolean<E>> {
+        // Ensure the message is byte-aligned.
+        if message.len() % 8 != 0 {
+            E::halt("The message to expand must be byte-aligned")
+        }
+        // Ensure the requested number of bytes is within the supported range.
+        let ell = (len_in_bytes + B_IN_BYTES - 1) / B_IN_BYTES;
+        let (Ok(ell), Ok(l_i_b_str)) = (u8::try_from(ell), u16::try_from(len_in_bytes)) else {
+            E::halt(format!("Cannot expand the message into {len_in_bytes} bytes"))
+        };
+
+        // Compute b_0 = H(Z_pad || msg || l_i_b_str || I2OSP(0, 1) || DST_prime).
+        let mut preimage = constant_bits_le(&[0u8; S_IN_BYTES]);
+        preimage.extend_from_slice(message);
+        preimage.extend(constant_bits_le(&l_i_b_str.to_be_bytes()));
+        preimage.extend(constant_bits_le(&[0u8]));
+        preimage.extend_from_slice(&self.dst_prime);
+        let b_0 = self.sha3.hash(&preimage);
+
+        let mut uniform_bits = Vec::with_capacity(usize::from(ell) * B_IN_BYTES * 8);
+        let mut b_i = constant_bits_le(&[0u8; B_IN_BYTES]);
+        for i in 1..=ell {
+            // Compute b_i = H(strxor(b_0, b_(i - 1)) || I2OSP(i, 1) || DST_prime), where b_1 = H(b_0 || ...).
+            // Note: The XOR with `b_0` is the identity for b_1, as `b_i` is initialized to zero.
+            let mut preimage = b_i.iter().zip_eq(&b_0).map(|(b_i, b_0)| b_i ^ b_0).collect::<Vec<_>>();
+            preimage.extend(constant_bits_le(&[i]));
+            preimage.extend_from_slice(&self.dst_prime);
+            b_i = self.sha3.hash(&preimage);
+            uniform_bits.extend_from_slice(&b_i);
+        }
+        // Return the first `len_in_bytes` bytes.
+        uniform_bits.truncate(len_in_bytes * 8);
+        uniform_bits
+    }
+}
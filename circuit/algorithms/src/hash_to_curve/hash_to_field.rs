@@ -0,0 +1,36 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use super::*;
+
+impl<E: Environment> HashToCurve<E> {
+    /// Returns `count` base field elements derived from the given message, as `hash_to_field` in RFC 9380.
+    pub fn hash_to_field(&self, message: &[Boolean<E>], count: usize) -> Vec<Field<E>> {
+        // Expand the message into `count * L` uniform bytes.
+        let num_bytes = console::HashToCurve::<E::Network>::num_bytes_per_field();
+        let uniform_bits = self.expand_message(message, count * num_bytes);
+
+        // Map each chunk of `L` bytes to a field element, as `OS2IP(tv) mod p`.
+        uniform_bits
+            .chunks(num_bytes * 8)
+            .map(|chunk| {
+                // Convert the big-endian bytes into little-endian bits.
+                let bits_le = chunk.chunks(8).rev().flatten().cloned().collect::<Vec<_>>();
+                // Split the bits at the number of data bits, and recombine them as `lo + hi * 2^DATA_BITS`.
+                let (lo, hi) = bits_le.split_at(E::BaseField::size_in_data_bits());
+                Field::from_bits_le(lo) + Field::from_bits_le(hi) * &self.shift
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,94 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use super::*;
+
+impl<E: Environment> HashToGroup for HashToCurve<E> {
+    type Group = Group<E>;
+    type Input = Boolean<E>;
+    type Scalar = Scalar<E>;
+
+    /// Returns the group element for the given input, as `hash_to_curve` in RFC 9380.
+    /// The input bits are zero-padded to a byte boundary.
+    fn hash_to_group(&self, input: &[Self::Input]) -> Self::Group {
+        // Pack the input into the message bytes.
+        let mut message = input.to_vec();
+        message.resize((input.len() + 7) / 8 * 8, Boolean::constant(false));
+
+        // Derive two field elements from the message.
+        match self.hash_to_field(&message, 2).iter().collect_tuple() {
+            // Map each field element to a curve point, add the points, and clear the cofactor.
+            Some((u0, u1)) => (self.map_to_curve(u0) + self.map_to_curve(u1)).mul_by_cofactor(),
+            None => E::halt("Failed to compute the hash to curve"),
+        }
+    }
+}
+
+#[cfg(all(test, console))]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_types::environment::Circuit;
+
+    use anyhow::Result;
+
+    const ITERATIONS: u64 = 10;
+    const DST: &str = "HashToCurveCircuit0";
+
+    fn check_hash_to_group(mode: Mode, num_bits: u64) -> Result<()> {
+        use console::HashToGroup as H;
+
+        let native = console::HashToCurve::<<Circuit as Environment>::Network>::setup(DST)?;
+        let circuit = HashToCurve::<Circuit>::constant(native.clone());
+
+        let rng = &mut TestRng::default();
+
+        for i in 0..ITERATIONS {
+            // Sample a random input.
+            let input = (0..num_bits).map(|_| bool::rand(rng)).collect::<Vec<_>>();
+            // Compute the expected hash.
+            let expected = native.hash_to_group(&input)?;
+            // Prepare the circuit input.
+            let circuit_input = input.iter().map(|bit| Boolean::<Circuit>::new(mode, *bit)).collect::<Vec<_>>();
+
+            Circuit::scope(format!("HashToCurve {mode} {i}"), || {
+                // Perform the hash operation.
+                let candidate = circuit.hash_to_group(&circuit_input);
+                assert_eq!(expected, candidate.eject_value());
+                assert!(Circuit::is_satisfied_in_scope());
+            });
+            Circuit::reset();
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_hash_to_group_constant() -> Result<()> {
+        check_hash_to_group(Mode::Constant, 0)?;
+        check_hash_to_group(Mode::Constant, 8)?;
+        check_hash_to_group(Mode::Constant, 13)
+    }
+
+    #[test]
+    fn test_hash_to_group_public() -> Result<()> {
+        check_hash_to_group(Mode::Public, 8)?;
+        check_hash_to_group(Mode::Public, 256)
+    }
+
+    #[test]
+    fn test_hash_to_group_private() -> Result<()> {
+        check_hash_to_group(Mode::Private, 8)?;
+        check_hash_to_group(Mode::Private, 13)?;
+        check_hash_to_group(Mode::Private, 256)
+    }
+}
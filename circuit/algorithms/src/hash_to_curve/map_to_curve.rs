@@ -0,0 +1,85 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use super::*;
+
+impl<E: Environment> HashToCurve<E> {
+    /// Returns the curve point for the given field element, as `map_to_curve_elligator2` in RFC 9380,
+    /// followed by the rational map from the Montgomery curve to the twisted Edwards curve.
+    ///
+    /// Note: The output is on the curve, but is not necessarily in the prime-order subgroup.
+    pub fn map_to_curve(&self, u: &Field<E>) -> Group<E> {
+        // Define `1` as a constant.
+        let one = Field::one();
+
+        // Define the Montgomery curve coefficients J and K.
+        let j = console::Group::<E::Network>::MONTGOMERY_A;
+        let k = console::Group::<E::Network>::MONTGOMERY_B;
+        let k_inverse = match k.inverse() {
+            Ok(k_inverse) => k_inverse,
+            Err(_) => E::halt("Montgomery B must be invertible in order to hash to the curve"),
+        };
+        // Define the coefficients of the curve: y^2 = x^3 + (J / K) * x^2 + (1 / K^2) * x.
+        let c1 = Field::constant(j * k_inverse);
+        let c2 = Field::constant(k_inverse.square());
+        let k = Field::constant(k);
+        // Returns `g(x) = x^3 + c1 * x^2 + c2 * x`.
+        let g = |x: &Field<E>| (x.square() + (&c1 * x) + &c2) * x;
+
+        // Define the MODULUS_MINUS_ONE_DIV_TWO as a constant.
+        let modulus_minus_one_div_two = match E::BaseField::from_bigint(E::BaseField::modulus_minus_one_div_two()) {
+            Some(modulus_minus_one_div_two) => Field::constant(console::Field::new(modulus_minus_one_div_two)),
+            None => E::halt("Failed to initialize MODULUS_MINUS_ONE_DIV_TWO as a constant"),
+        };
+
+        // Compute the Montgomery point (s, t) on: K * t^2 = s^3 + J * s^2 + s.
+        let (s, t) = {
+            // Let x1 = -c1 / (1 + Z * u^2).
+            // Note: As -1 is a square, and Z is not a square, (1 + Z * u^2) is never zero, and thus x1 is never zero.
+            let x1 = -&c1 * (&one + &self.z * u.square()).inverse();
+            // Let x2 = -x1 - c1.
+            let x2 = -&x1 - &c1;
+
+            // Determine if g(x1) is a square, with Euler's criterion.
+            let gx1 = g(&x1);
+            let is_square = gx1.pow(modulus_minus_one_div_two).is_one();
+
+            // If g(x1) is a square, select (x1, g(x1)) and the odd square root,
+            // otherwise select (x2, g(x2)) and the even square root.
+            let x = Field::ternary(&is_square, &x1, &x2);
+            let gx = Field::ternary(&is_square, &gx1, &g(&x2));
+            let y = gx.even_square_root();
+            let y = Field::ternary(&is_square, &-&y, &y);
+
+            // Scale (x, y) to the Montgomery point (s, t).
+            (x * &k, y * k)
+        };
+
+        // Compute the twisted Edwards point (v, w), as (s / t, (s - 1) / (s + 1)).
+        // Note: The exceptional case (t == 0 or s == -1) is mapped to the identity (0, 1).
+        let (v, w) = {
+            let tv1 = &s + &one;
+            let tv2 = inv0(&(&tv1 * &t));
+            let v = &tv2 * &tv1 * &s;
+            let w = Field::ternary(&tv2.is_zero(), &one, &(&tv2 * &t * (&s - &one)));
+            (v, w)
+        };
+
+        // Recover the point from the twisted Edwards point (v, w).
+        let point = Group::from_xy_coordinates_unchecked(v, w);
+        // Ensure the point is on the curve.
+        point.enforce_on_curve();
+        point
+    }
+}
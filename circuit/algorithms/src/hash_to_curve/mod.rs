@@ -0,0 +1,80 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+mod expand_message;
+mod hash_to_field;
+mod hash_to_group;
+mod map_to_curve;
+
+#[cfg(test)]
+use snarkvm_utilities::{TestRng, Uniform};
+
+use crate::{Hash, HashToGroup, Sha3_256};
+use snarkvm_circuit_types::{environment::prelude::*, Boolean, Field, Group, Scalar};
+
+/// The number of bytes in the output of SHA3-256, i.e. `b_in_bytes`.
+const B_IN_BYTES: usize = 32;
+/// The number of bytes in the input block of SHA3-256, i.e. `s_in_bytes`.
+const S_IN_BYTES: usize = 136;
+
+/// The hash-to-curve construction of RFC 9380, for the twisted Edwards group.
+/// See the console implementation for the choice of the suite.
+///
+/// Note: The message is given as bits, and is zero-padded to a byte boundary.
+/// The bytes are represented as bits in little-endian order, as in the SHA3-256 circuit.
+#[derive(Clone)]
+pub struct HashToCurve<E: Environment> {
+    /// The domain separation tag, suffixed by its length in bytes, i.e. `DST_prime`.
+    dst_prime: Vec<Boolean<E>>,
+    /// The non-square `Z` of the Elligator 2 map.
+    z: Field<E>,
+    /// The constant `2^DATA_BITS`, where `DATA_BITS` is the number of data bits in a field element.
+    shift: Field<E>,
+    /// The SHA3-256 hash function.
+    sha3: Sha3_256<E>,
+}
+
+#[cfg(console)]
+impl<E: Environment> Inject for HashToCurve<E> {
+    type Primitive = console::HashToCurve<E::Network>;
+
+    fn new(_mode: Mode, hash_to_curve: Self::Primitive) -> Self {
+        // Initialize the domain separation tag.
+        let dst_prime = constant_bits_le(hash_to_curve.dst_prime());
+        // Initialize the non-square Z.
+        let z = Field::constant(hash_to_curve.z());
+        // Initialize the constant `2^DATA_BITS`.
+        let shift = (0..E::BaseField::size_in_data_bits()).fold(console::Field::one(), |shift, _| shift.double());
+
+        Self { dst_prime, z, shift: Field::constant(shift), sha3: Sha3_256::new() }
+    }
+}
+
+/// Returns the given bytes as constant bits, in little-endian order within each byte.
+fn constant_bits_le<E: Environment>(bytes: &[u8]) -> Vec<Boolean<E>> {
+    bytes.iter().flat_map(|byte| (0..8).map(move |i| Boolean::constant((byte >> i) & 1 == 1))).collect()
+}
+
+/// Returns the inverse of the given field element, or zero if the element is zero, i.e. `inv0(x)`.
+fn inv0<E: Environment>(value: &Field<E>) -> Field<E> {
+    // Witness the inverse, which is zero if the value is zero.
+    let inverse: Field<E> = witness!(|value| value.inverse().unwrap_or_else(|_| console::Field::zero()));
+
+    // Ensure `value * inverse == 1 - is_zero`, and `inverse * is_zero == 0`.
+    let is_zero = Field::from_boolean(&value.is_zero());
+    E::enforce(|| (value, &inverse, Field::one() - &is_zero));
+    E::enforce(|| (&inverse, &is_zero, E::zero()));
+
+    inverse
+}
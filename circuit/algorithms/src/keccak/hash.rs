@@ -84,6 +84,14 @@ impl<E: Environment, const TYPE: u8, const VARIANT: usize> Hash for Keccak<E, TY
     }
 }
 
+impl<E: Environment, const TYPE: u8, const VARIANT: usize> Keccak<E, TYPE, VARIANT> {
+    /// Returns the Keccak hash of each of the given inputs as bits.
+    /// Note: Unlike the console counterpart, the inputs are hashed sequentially, in a single circuit.
+    pub fn hash_batch<I: AsRef<[Boolean<E>]>>(&self, inputs: &[I]) -> Vec<Vec<Boolean<E>>> {
+        inputs.iter().map(|input| self.hash(input.as_ref())).collect()
+    }
+}
+
 impl<E: Environment, const TYPE: u8, const VARIANT: usize> Keccak<E, TYPE, VARIANT> {
     /// In Keccak, `pad` is a multi-rate padding, defined as `pad(M) = M || 0x01 || 0x00…0x00 || 0x80`,
     /// where `M` is the input data, and `0x01 || 0x00…0x00 || 0x80` is the padding.
@@ -317,6 +325,30 @@ mod tests {
         };
     }
 
+    macro_rules! check_batch_equivalence {
+        ($console:expr, $circuit:expr) => {
+            let rng = &mut TestRng::default();
+
+            // Prepare the preimages.
+            let native_inputs = [1, 8, 136, 512]
+                .into_iter()
+                .map(|num_inputs| (0..num_inputs).map(|_| Uniform::rand(rng)).collect::<Vec<bool>>())
+                .collect::<Vec<_>>();
+            let inputs = native_inputs
+                .iter()
+                .map(|input| input.iter().map(|v| Boolean::<Circuit>::new(Mode::Private, *v)).collect::<Vec<_>>())
+                .collect::<Vec<_>>();
+
+            // Compute the console hashes.
+            let expected = $console.hash_batch(&native_inputs).expect("Failed to hash console inputs");
+
+            // Compute the circuit hashes.
+            let candidates = $circuit.hash_batch(&inputs);
+            assert_eq!(expected, candidates.iter().map(|candidate| candidate.eject_value()).collect::<Vec<_>>());
+            Circuit::reset();
+        };
+    }
+
     fn check_hash(
         mode: Mode,
         num_inputs: usize,
@@ -447,6 +479,16 @@ mod tests {
         check_equivalence!(console::Sha3_256::default(), Sha3_256::<Circuit>::new());
     }
 
+    #[test]
+    fn test_keccak_256_batch_equivalence() {
+        check_batch_equivalence!(console::Keccak256::default(), Keccak256::<Circuit>::new());
+    }
+
+    #[test]
+    fn test_sha3_256_batch_equivalence() {
+        check_batch_equivalence!(console::Sha3_256::default(), Sha3_256::<Circuit>::new());
+    }
+
     #[test]
     fn test_sha3_384_equivalence() {
         check_equivalence!(console::Sha3_384::default(), Sha3_384::<Circuit>::new());
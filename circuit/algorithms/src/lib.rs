@@ -22,6 +22,9 @@ pub use bhp::*;
 pub mod elligator2;
 pub use elligator2::Elligator2;
 
+pub mod hash_to_curve;
+pub use hash_to_curve::HashToCurve;
+
 pub mod keccak;
 pub use keccak::*;
 
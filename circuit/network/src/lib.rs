@@ -129,6 +129,12 @@ pub trait Aleo: Environment {
     /// Returns the SHA-3 hash with a 512-bit output.
     fn hash_sha3_512(input: &[Boolean<Self>]) -> Vec<Boolean<Self>>;
 
+    /// Returns the Keccak hash with a 256-bit output of each of the given inputs.
+    fn hash_keccak256_batch(inputs: &[Vec<Boolean<Self>>]) -> Vec<Vec<Boolean<Self>>>;
+
+    /// Returns the SHA-3 hash with a 256-bit output of each of the given inputs.
+    fn hash_sha3_256_batch(inputs: &[Vec<Boolean<Self>>]) -> Vec<Vec<Boolean<Self>>>;
+
     /// Returns the extended Poseidon hash with an input rate of 2.
     fn hash_many_psd2(input: &[Field<Self>], num_outputs: u16) -> Vec<Field<Self>>;
 
@@ -165,6 +171,9 @@ pub trait Aleo: Environment {
     /// Returns the Poseidon hash with an input rate of 8 on the affine curve.
     fn hash_to_group_psd8(input: &[Field<Self>]) -> Group<Self>;
 
+    /// Returns the RFC 9380 hash-to-curve of the given input, with `expand_message_xmd` over SHA3-256.
+    fn hash_to_curve(input: &[Boolean<Self>]) -> Group<Self>;
+
     /// Returns the Poseidon hash with an input rate of 2 on the scalar field.
     fn hash_to_scalar_psd2(input: &[Field<Self>]) -> Scalar<Self>;
 
@@ -19,6 +19,7 @@ use snarkvm_circuit_algorithms::{
     CommitUncompressed,
     Hash,
     HashMany,
+    HashToCurve,
     HashToGroup,
     HashToScalar,
     HashUncompressed,
@@ -96,6 +97,9 @@ thread_local! {
     static SHA3_384: Sha3_384<AleoV0> = Sha3_384::<AleoV0>::new();
     /// The SHA-3 hash function, which outputs 512 bits.
     static SHA3_512: Sha3_512<AleoV0> = Sha3_512::<AleoV0>::new();
+
+    /// The hash-to-curve construction of RFC 9380, for the twisted Edwards group.
+    static HASH_TO_CURVE: HashToCurve<AleoV0> = HashToCurve::<AleoV0>::constant(console::HASH_TO_CURVE.clone());
 }
 
 #[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
@@ -123,6 +127,7 @@ impl Aleo for AleoV0 {
         SHA3_256.with(|_| ());
         SHA3_384.with(|_| ());
         SHA3_512.with(|_| ());
+        HASH_TO_CURVE.with(|_| ());
     }
 
     /// Returns the encryption domain as a constant field element.
@@ -286,6 +291,16 @@ impl Aleo for AleoV0 {
         SHA3_512.with(|sha3| sha3.hash(input))
     }
 
+    /// Returns the Keccak hash with a 256-bit output of each of the given inputs.
+    fn hash_keccak256_batch(inputs: &[Vec<Boolean<Self>>]) -> Vec<Vec<Boolean<Self>>> {
+        KECCAK_256.with(|keccak| keccak.hash_batch(inputs))
+    }
+
+    /// Returns the SHA-3 hash with a 256-bit output of each of the given inputs.
+    fn hash_sha3_256_batch(inputs: &[Vec<Boolean<Self>>]) -> Vec<Vec<Boolean<Self>>> {
+        SHA3_256.with(|sha3| sha3.hash_batch(inputs))
+    }
+
     /// Returns the extended Poseidon hash with an input rate of 2.
     fn hash_many_psd2(input: &[Field<Self>], num_outputs: u16) -> Vec<Field<Self>> {
         POSEIDON_2.with(|poseidon| poseidon.hash_many(input, num_outputs))
@@ -346,6 +361,11 @@ impl Aleo for AleoV0 {
         POSEIDON_8.with(|poseidon| poseidon.hash_to_group(input))
     }
 
+    /// Returns the RFC 9380 hash-to-curve of the given input, with `expand_message_xmd` over SHA3-256.
+    fn hash_to_curve(input: &[Boolean<Self>]) -> Group<Self> {
+        HASH_TO_CURVE.with(|hash_to_curve| hash_to_curve.hash_to_group(input))
+    }
+
     /// Returns the Poseidon hash with an input rate of 2 on the scalar field.
     fn hash_to_scalar_psd2(input: &[Field<Self>]) -> Scalar<Self> {
         POSEIDON_2.with(|poseidon| poseidon.hash_to_scalar(input))
@@ -32,6 +32,11 @@ pub mod graph_key;
 #[cfg(feature = "graph_key")]
 pub use graph_key::*;
 
+#[cfg(feature = "outgoing_view_key")]
+pub mod outgoing_view_key;
+#[cfg(feature = "outgoing_view_key")]
+pub use outgoing_view_key::*;
+
 #[cfg(feature = "private_key")]
 pub mod private_key;
 #[cfg(feature = "private_key")]
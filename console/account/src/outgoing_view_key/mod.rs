@@ -0,0 +1,97 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod bytes;
+mod serialize;
+mod string;
+mod try_from;
+
+#[cfg(feature = "view_key")]
+use crate::ViewKey;
+
+use snarkvm_console_network::prelude::*;
+use snarkvm_console_types::{Field, Group};
+
+/// The account outgoing view key, used to decrypt the view keys of the transitions and records an account sends.
+///
+/// The outgoing view key is derived from the account view key. It cannot spend records, nor decrypt
+/// incoming records; it only decrypts the view key material that the sender encrypted under it.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct OutgoingViewKey<N: Network> {
+    /// The outgoing view key `sk_out` := Hash(view_key).
+    sk_out: Field<N>,
+}
+
+impl<N: Network> OutgoingViewKey<N> {
+    /// Returns the outgoing view key.
+    pub const fn sk_out(&self) -> Field<N> {
+        self.sk_out
+    }
+
+    /// Encrypts the given view key material for the given nonce, as `plaintext + Hash(sk_out || nonce)`.
+    /// Note: The nonce must be unique to the view key material, e.g. the transition public key, or the record nonce.
+    pub fn encrypt(&self, nonce: &Group<N>, plaintext: &[Field<N>]) -> Result<Vec<Field<N>>> {
+        let masks = self.to_masks(nonce, plaintext.len())?;
+        Ok(plaintext.iter().zip_eq(masks).map(|(plaintext, mask)| *plaintext + mask).collect())
+    }
+
+    /// Decrypts the given ciphertext for the given nonce, into the view key material.
+    pub fn decrypt(&self, nonce: &Group<N>, ciphertext: &[Field<N>]) -> Result<Vec<Field<N>>> {
+        let masks = self.to_masks(nonce, ciphertext.len())?;
+        Ok(ciphertext.iter().zip_eq(masks).map(|(ciphertext, mask)| *ciphertext - mask).collect())
+    }
+
+    /// Returns the given number of masks for the given nonce, as `Hash(sk_out || nonce)`.
+    fn to_masks(&self, nonce: &Group<N>, num_masks: usize) -> Result<Vec<Field<N>>> {
+        let num_masks = u16::try_from(num_masks).map_err(|_| anyhow!("Too many fields to encrypt"))?;
+        Ok(N::hash_many_psd8(&[N::encryption_domain(), self.sk_out, nonce.to_x_coordinate()], num_masks))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::PrivateKey;
+    use snarkvm_console_network::MainnetV0;
+
+    type CurrentNetwork = MainnetV0;
+
+    const ITERATIONS: u64 = 1000;
+
+    #[test]
+    fn test_encrypt_and_decrypt() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            // Sample a new outgoing view key.
+            let private_key = PrivateKey::<CurrentNetwork>::new(&mut rng)?;
+            let outgoing_view_key = OutgoingViewKey::try_from(ViewKey::try_from(private_key)?)?;
+
+            // Encrypt some view key material.
+            let nonce = Group::rand(&mut rng);
+            let plaintext = [Field::rand(&mut rng), Field::rand(&mut rng)];
+            let ciphertext = outgoing_view_key.encrypt(&nonce, &plaintext)?;
+            assert_ne!(ciphertext, plaintext);
+            assert_ne!(ciphertext[0] - plaintext[0], ciphertext[1] - plaintext[1]);
+
+            // Ensure the view key material is only recovered with the same key and nonce.
+            assert_eq!(plaintext.to_vec(), outgoing_view_key.decrypt(&nonce, &ciphertext)?);
+            assert_ne!(plaintext.to_vec(), outgoing_view_key.decrypt(&Group::rand(&mut rng), &ciphertext)?);
+            let other = OutgoingViewKey::try_from(Field::rand(&mut rng))?;
+            assert_ne!(plaintext.to_vec(), other.decrypt(&nonce, &ciphertext)?);
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,80 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+static OUTGOING_VIEW_KEY_PREFIX: [u8; 10] = [9, 193, 39, 125, 207, 55, 237, 113, 23, 148]; // ASentViewKey1
+
+impl<N: Network> FromStr for OutgoingViewKey<N> {
+    type Err = Error;
+
+    /// Reads in an account outgoing view key from a base58 string.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        // Encode the string into base58.
+        let data = bs58::decode(s).into_vec().map_err(|err| anyhow!("{:?}", err))?;
+        if data.len() != 42 {
+            bail!("Invalid account outgoing view key length: found {}, expected 42", data.len())
+        } else if data[0..10] != OUTGOING_VIEW_KEY_PREFIX {
+            bail!(
+                "Invalid account outgoing view key prefix: found {:?}, expected {:?}",
+                &data[0..10],
+                OUTGOING_VIEW_KEY_PREFIX
+            )
+        }
+        // Output the outgoing view key.
+        Self::try_from(Field::read_le(&data[10..42])?)
+    }
+}
+
+impl<N: Network> fmt::Display for OutgoingViewKey<N> {
+    /// Writes the account outgoing view key as a base58 string.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        // Write the outgoing view key bytes.
+        let mut outgoing_view_key = [0u8; 42];
+        outgoing_view_key[0..10].copy_from_slice(&OUTGOING_VIEW_KEY_PREFIX);
+        self.sk_out.write_le(&mut outgoing_view_key[10..42]).map_err(|_| fmt::Error)?;
+        // Encode the outgoing view key into base58.
+        write!(f, "{}", bs58::encode(outgoing_view_key).into_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::PrivateKey;
+    use snarkvm_console_network::MainnetV0;
+
+    type CurrentNetwork = MainnetV0;
+
+    const ITERATIONS: u64 = 10_000;
+
+    #[test]
+    fn test_string() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            // Sample a new outgoing view key.
+            let private_key = PrivateKey::<CurrentNetwork>::new(&mut rng)?;
+            let view_key = ViewKey::try_from(private_key)?;
+            let expected = OutgoingViewKey::try_from(view_key)?;
+
+            // Check the string representation.
+            let candidate = format!("{expected}");
+            assert_eq!(expected, OutgoingViewKey::from_str(&candidate)?);
+            assert_eq!("ASentViewKey", candidate.split('1').next().unwrap());
+        }
+        Ok(())
+    }
+}
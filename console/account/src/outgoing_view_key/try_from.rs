@@ -0,0 +1,77 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+#[cfg(feature = "view_key")]
+impl<N: Network> TryFrom<ViewKey<N>> for OutgoingViewKey<N> {
+    type Error = Error;
+
+    /// Derives the account outgoing view key from an account view key.
+    fn try_from(view_key: ViewKey<N>) -> Result<Self, Self::Error> {
+        Self::try_from(&view_key)
+    }
+}
+
+#[cfg(feature = "view_key")]
+impl<N: Network> TryFrom<&ViewKey<N>> for OutgoingViewKey<N> {
+    type Error = Error;
+
+    /// Derives the account outgoing view key from an account view key.
+    fn try_from(view_key: &ViewKey<N>) -> Result<Self, Self::Error> {
+        // Compute sk_out := Hash(view_key).
+        let sk_out = N::hash_psd2(&[Field::new_domain_separator("AleoOutgoingViewKey0"), view_key.to_field()?])?;
+        // Output the outgoing view key.
+        Self::try_from(sk_out)
+    }
+}
+
+impl<N: Network> TryFrom<Field<N>> for OutgoingViewKey<N> {
+    type Error = Error;
+
+    /// Derives the account outgoing view key from `sk_out`.
+    fn try_from(sk_out: Field<N>) -> Result<Self> {
+        // Output the outgoing view key.
+        Ok(Self { sk_out })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::PrivateKey;
+    use snarkvm_console_network::MainnetV0;
+
+    type CurrentNetwork = MainnetV0;
+
+    const ITERATIONS: u64 = 1000;
+
+    #[test]
+    fn test_try_from() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            // Sample a new view key.
+            let private_key = PrivateKey::<CurrentNetwork>::new(&mut rng)?;
+            let view_key = ViewKey::try_from(private_key)?;
+
+            // Ensure the outgoing view key is deterministic, and distinct from the graph key.
+            let expected = OutgoingViewKey::try_from(view_key)?;
+            assert_eq!(expected, OutgoingViewKey::try_from(&view_key)?);
+            assert_ne!(expected.sk_out(), crate::GraphKey::try_from(view_key)?.sk_tag());
+        }
+        Ok(())
+    }
+}
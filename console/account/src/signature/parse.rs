@@ -40,16 +40,10 @@ impl<N: Network> FromStr for Signature<N> {
     /// Reads in the signature string.
     fn from_str(signature: &str) -> Result<Self, Self::Err> {
         // Decode the signature string from bech32m.
-        let (hrp, data, variant) = bech32::decode(signature)?;
-        if hrp != SIGNATURE_PREFIX {
-            bail!("Failed to decode signature: '{hrp}' is an invalid prefix")
-        } else if data.is_empty() {
-            bail!("Failed to decode signature: data field is empty")
-        } else if variant != bech32::Variant::Bech32m {
-            bail!("Found an signature that is not bech32m encoded: {signature}");
-        }
-        // Decode the signature data from u5 to u8, and into the signature.
-        Ok(Self::read_le(&Vec::from_base32(&data)?[..])?)
+        let bytes = decode_bech32m(signature, SIGNATURE_PREFIX)
+            .map_err(|error| ParseError::new("signature", signature, 0).with_reason(error))?;
+        // Decode the bytes into the signature.
+        Ok(Self::read_le(&bytes[..])?)
     }
 }
 
@@ -65,8 +59,7 @@ impl<N: Network> Display for Signature<N> {
         // Convert the signature to bytes.
         let bytes = self.to_bytes_le().map_err(|_| fmt::Error)?;
         // Encode the bytes into bech32m.
-        let string =
-            bech32::encode(SIGNATURE_PREFIX, bytes.to_base32(), bech32::Variant::Bech32m).map_err(|_| fmt::Error)?;
+        let string = encode_bech32m(SIGNATURE_PREFIX, &bytes).map_err(|_| fmt::Error)?;
         // Output the string.
         Display::fmt(&string, f)
     }
@@ -24,3 +24,11 @@ impl<E: Environment, const NUM_WINDOWS: u8, const WINDOW_SIZE: u8> Hash for BHP<
         Ok(self.hash_uncompressed(input)?.to_x_coordinate())
     }
 }
+
+impl<E: Environment, const NUM_WINDOWS: u8, const WINDOW_SIZE: u8> BHP<E, NUM_WINDOWS, WINDOW_SIZE> {
+    /// Returns the BHP hash of the given input bits as a field element,
+    /// consuming the bits without materializing the input.
+    pub fn hash_iter(&self, input: impl IntoIterator<Item = bool>) -> Result<Field<E>> {
+        Ok(self.hash_uncompressed_iter(input)?.to_x_coordinate())
+    }
+}
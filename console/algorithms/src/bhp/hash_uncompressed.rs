@@ -26,6 +26,23 @@ impl<E: Environment, const NUM_WINDOWS: u8, const WINDOW_SIZE: u8> HashUncompres
     /// This uncompressed variant of the BHP hash function is provided to support
     /// the BHP commitment scheme, as it is typically not used by applications.
     fn hash_uncompressed(&self, input: &[Self::Input]) -> Result<Self::Output> {
+        self.hash_uncompressed_iter(input.iter().copied())
+    }
+}
+
+impl<E: Environment, const NUM_WINDOWS: u8, const WINDOW_SIZE: u8> BHP<E, NUM_WINDOWS, WINDOW_SIZE> {
+    /// Returns the BHP hash of the given input bits as an affine group element,
+    /// consuming the bits without materializing the input.
+    ///
+    /// The length of the input is hashed in the first iteration, so the iterator must report an exact size.
+    pub fn hash_uncompressed_iter(&self, input: impl IntoIterator<Item = bool>) -> Result<Group<E>> {
+        let input = input.into_iter();
+        // Determine the number of input bits.
+        let num_input_bits = match input.size_hint() {
+            (lower, Some(upper)) if lower == upper => lower,
+            _ => bail!("The input to the BHP hash must have an exact size"),
+        };
+
         // The number of hasher bits to fit.
         let num_hasher_bits = NUM_WINDOWS as usize * WINDOW_SIZE as usize * BHP_CHUNK_SIZE;
         // The number of data bits in the output.
@@ -43,29 +60,37 @@ impl<E: Environment, const NUM_WINDOWS: u8, const WINDOW_SIZE: u8> HashUncompres
         let mut preimage = Vec::with_capacity(num_hasher_bits);
 
         // Compute the hash of the input.
-        for (i, input_bits) in input.chunks(max_input_bits_per_iteration).enumerate() {
+        let mut input = input.peekable();
+        let mut num_hashed_bits = 0;
+        while input.peek().is_some() {
             // Determine if this is the first iteration.
-            match i == 0 {
+            match num_hashed_bits == 0 {
                 // Construct the first iteration as: [ 0...0 || DOMAIN || LENGTH(INPUT) || INPUT[0..BLOCK_SIZE] ].
                 true => {
                     // Initialize a vector for the hash preimage.
                     preimage.extend(&self.domain);
-                    (input.len() as u64).write_bits_le(&mut preimage);
-                    preimage.extend(input_bits);
+                    (num_input_bits as u64).write_bits_le(&mut preimage);
                 }
                 // Construct the subsequent iterations as: [ PREVIOUS_HASH[0..DATA_BITS] || INPUT[I * BLOCK_SIZE..(I + 1) * BLOCK_SIZE] ].
                 false => {
                     // Initialize a vector for the hash preimage.
                     digest.to_x_coordinate().write_bits_le(&mut preimage);
                     preimage.truncate(num_data_bits);
-                    preimage.extend(input_bits);
                 }
             }
+            // Append the input bits for this iteration.
+            let num_preimage_bits = preimage.len();
+            preimage.extend(input.by_ref().take(max_input_bits_per_iteration));
+            num_hashed_bits += preimage.len() - num_preimage_bits;
+
             // Hash the preimage for this iteration.
             digest = self.hasher.hash_uncompressed(&preimage)?;
             preimage.clear();
         }
 
+        // Ensure the iterator yielded the number of bits it reported.
+        ensure!(num_hashed_bits == num_input_bits, "Expected {num_input_bits} input bits, found {num_hashed_bits}");
+
         Ok(digest)
     }
 }
@@ -130,4 +155,24 @@ mod tests {
         }
         Ok(())
     }
+
+    #[test]
+    fn test_hash_uncompressed_iter() -> Result<()> {
+        let bhp = BHP1024::<CurrentEnvironment>::setup("BHPTest")?;
+
+        let mut rng = TestRng::default();
+
+        for num_bits in [0, 1, 64, 1024, 4096] {
+            let input = (0..num_bits).map(|_| bool::rand(&mut rng)).collect::<Vec<_>>();
+            // Ensure the streamed hash matches the hash of the materialized input.
+            let expected = bhp.hash_uncompressed(&input)?;
+            assert_eq!(expected, bhp.hash_uncompressed_iter(input.iter().copied())?);
+            // Ensure a chained iterator with an exact size is accepted.
+            let (left, right) = input.split_at(num_bits / 2);
+            assert_eq!(expected, bhp.hash_uncompressed_iter(left.iter().chain(right).copied())?);
+        }
+        // Ensure an iterator without an exact size is rejected.
+        assert!(bhp.hash_uncompressed_iter((0..64).map(|i| i % 2 == 0).filter(|bit| *bit)).is_err());
+        Ok(())
+    }
 }
@@ -0,0 +1,76 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use super::*;
+
+impl<E: Environment> HashToCurve<E> {
+    /// Returns `len_in_bytes` uniform bytes derived from the given message,
+    /// as `expand_message_xmd` over SHA3-256 in RFC 9380, Section 5.3.1.
+    pub fn expand_message(&self, message: &[u8], len_in_bytes: usize) -> Result<Vec<u8>> {
+        // Ensure the requested number of bytes is within the supported range.
+        let ell = (len_in_bytes + B_IN_BYTES - 1) / B_IN_BYTES;
+        ensure!(ell <= 255, "Cannot expand the message into {len_in_bytes} bytes");
+        let l_i_b_str = u16::try_from(len_in_bytes)?.to_be_bytes();
+
+        // Compute b_0 = H(Z_pad || msg || l_i_b_str || I2OSP(0, 1) || DST_prime).
+        let b_0 = sha3_256(&[&[0u8; S_IN_BYTES], message, &l_i_b_str, &[0u8], &self.dst_prime]);
+
+        let mut uniform_bytes = Vec::with_capacity(ell * B_IN_BYTES);
+        let mut b_i = [0u8; B_IN_BYTES];
+        for i in 1..=ell {
+            // Compute b_i = H(strxor(b_0, b_(i - 1)) || I2OSP(i, 1) || DST_prime), where b_1 = H(b_0 || ...).
+            // Note: The XOR with `b_0` is the identity for b_1, as `b_i` is initialized to zero.
+            b_i.iter_mut().zip_eq(&b_0).for_each(|(byte, b_0)| *byte ^= b_0);
+            b_i = sha3_256(&[&b_i, &[u8::try_from(i)?], &self.dst_prime]);
+            uniform_bytes.extend_from_slice(&b_i);
+        }
+        // Return the first `len_in_bytes` bytes.
+        uniform_bytes.truncate(len_in_bytes);
+        Ok(uniform_bytes)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_types::environment::Console;
+
+    type CurrentEnvironment = Console;
+
+    #[test]
+    fn test_expand_message() -> Result<()> {
+        let hash_to_curve = HashToCurve::<CurrentEnvironment>::setup("QUUX-V01-CS02-with-expander-SHA3-256")?;
+
+        for message in [&b""[..], b"abc", b"abcdef0123456789", &[b'a'; 512]] {
+            for len_in_bytes in [0x20, 0x60, 0x80] {
+                let uniform_bytes = hash_to_curve.expand_message(message, len_in_bytes)?;
+                assert_eq!(uniform_bytes.len(), len_in_bytes);
+                // Ensure the expansion is deterministic.
+                assert_eq!(uniform_bytes, hash_to_curve.expand_message(message, len_in_bytes)?);
+                // Ensure the shorter expansions are not prefixes of the longer ones, as `len_in_bytes` is hashed.
+                let shorter = hash_to_curve.expand_message(message, len_in_bytes - B_IN_BYTES)?;
+                assert_ne!(shorter[..], uniform_bytes[..shorter.len()]);
+            }
+        }
+
+        // Ensure the expansion depends on the domain separation tag.
+        let other = HashToCurve::<CurrentEnvironment>::setup("QUUX-V01-CS02-with-expander-SHA3-256-other")?;
+        assert_ne!(hash_to_curve.expand_message(b"abc", 0x20)?, other.expand_message(b"abc", 0x20)?);
+
+        // Ensure the expansion fails for lengths beyond `255 * b_in_bytes`.
+        assert!(hash_to_curve.expand_message(b"abc", 255 * B_IN_BYTES).is_ok());
+        assert!(hash_to_curve.expand_message(b"abc", 255 * B_IN_BYTES + 1).is_err());
+        Ok(())
+    }
+}
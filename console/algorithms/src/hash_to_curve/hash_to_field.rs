@@ -0,0 +1,89 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use super::*;
+
+impl<E: Environment> HashToCurve<E> {
+    /// Returns `count` base field elements derived from the given message, as `hash_to_field` in RFC 9380, Section 5.2.
+    pub fn hash_to_field(&self, message: &[u8], count: usize) -> Result<Vec<Field<E>>> {
+        // Expand the message into `count * L` uniform bytes.
+        let num_bytes = Self::num_bytes_per_field();
+        let uniform_bytes = self.expand_message(message, count * num_bytes)?;
+        // Map each chunk of `L` bytes to a field element, as `OS2IP(tv) mod p`.
+        Ok(uniform_bytes.chunks(num_bytes).map(Self::from_bytes_be_mod_order).collect())
+    }
+
+    /// Returns the field element for the given big-endian bytes, reduced modulo the field modulus.
+    fn from_bytes_be_mod_order(bytes: &[u8]) -> Field<E> {
+        // Convert the big-endian bytes into little-endian bits.
+        let bits_le = bits_from_bytes_le(&bytes.iter().rev().copied().collect::<Vec<_>>()).collect::<Vec<_>>();
+        // Split the bits at the number of data bits, so that each part is less than the modulus.
+        let (lo, hi) = bits_le.split_at(Field::<E>::size_in_data_bits().min(bits_le.len()));
+        // Recombine the parts as `lo + hi * 2^DATA_BITS`.
+        // Note: The unwraps are safe, as each part is at most `Field::size_in_data_bits()` bits.
+        let lo = Field::from_bits_le(lo).unwrap();
+        let hi = Field::from_bits_le(hi).unwrap();
+        lo + hi * Self::data_bits_shift()
+    }
+
+    /// Returns `2^DATA_BITS`, where `DATA_BITS` is the number of data bits in a field element.
+    fn data_bits_shift() -> Field<E> {
+        (0..Field::<E>::size_in_data_bits()).fold(Field::one(), |shift, _| shift.double())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_types::environment::Console;
+
+    type CurrentEnvironment = Console;
+
+    const ITERATIONS: u64 = 100;
+
+    #[test]
+    fn test_from_bytes_be_mod_order() {
+        let mut rng = TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            // Sample a random field element.
+            let expected = Field::<CurrentEnvironment>::rand(&mut rng);
+            // Encode the field element in big-endian, with zero padding up to `L` bytes.
+            let mut bytes = expected.to_bytes_le().unwrap();
+            bytes.resize(HashToCurve::<CurrentEnvironment>::num_bytes_per_field(), 0u8);
+            bytes.reverse();
+            // Ensure the field element is recovered.
+            assert_eq!(expected, HashToCurve::from_bytes_be_mod_order(&bytes));
+        }
+
+        // Ensure the bytes are reduced modulo the field modulus.
+        let num_bytes = HashToCurve::<CurrentEnvironment>::num_bytes_per_field();
+        let all_ones = HashToCurve::<CurrentEnvironment>::from_bytes_be_mod_order(&vec![0xff; num_bytes]);
+        let expected = (0..num_bytes * 8).fold(Field::zero(), |acc, _| acc.double() + Field::one());
+        assert_eq!(expected, all_ones);
+    }
+
+    #[test]
+    fn test_hash_to_field() -> Result<()> {
+        let hash_to_curve =
+            HashToCurve::<CurrentEnvironment>::setup("QUUX-V01-CS02-with-edwardsbls12_XMD:SHA3-256_ELL2_RO_")?;
+
+        let elements = hash_to_curve.hash_to_field(b"abc", 2)?;
+        assert_eq!(elements.len(), 2);
+        assert_ne!(elements[0], elements[1]);
+        // Ensure the first element is independent of the count, as `len_in_bytes` is hashed.
+        assert_ne!(elements[0], hash_to_curve.hash_to_field(b"abc", 1)?[0]);
+        Ok(())
+    }
+}
@@ -0,0 +1,108 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use super::*;
+
+impl<E: Environment> HashToGroup for HashToCurve<E> {
+    type Input = bool;
+    type Output = Group<E>;
+
+    /// Returns the group element for the given input, as `hash_to_curve` in RFC 9380, Section 3.
+    /// The input bits are packed into bytes in little-endian order, and zero-padded to a byte boundary.
+    fn hash_to_group(&self, input: &[Self::Input]) -> Result<Self::Output> {
+        // Pack the input into the message bytes.
+        let message = bytes_from_bits_le(input);
+        // Derive two field elements from the message.
+        let (u0, u1) = match self.hash_to_field(&message, 2)?.as_slice() {
+            [u0, u1] => (*u0, *u1),
+            _ => bail!("Hash-to-curve failed: expected two field elements"),
+        };
+        // Map each field element to a curve point, and add the points.
+        let point = self.map_to_curve(&u0)? + self.map_to_curve(&u1)?;
+
+        // Clear the cofactor of the point.
+        let group = point.mul_by_cofactor();
+        let affine = group.to_affine();
+        ensure!(affine.is_on_curve(), "Hash-to-curve failed: element is not on curve");
+        ensure!(
+            affine.is_in_correct_subgroup_assuming_on_curve(),
+            "Hash-to-curve failed: element in incorrect subgroup"
+        );
+        Ok(group)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_types::environment::Console;
+
+    type CurrentEnvironment = Console;
+
+    const ITERATIONS: u64 = 100;
+    const DST: &str = "QUUX-V01-CS02-with-edwardsbls12_XMD:SHA3-256_ELL2_RO_";
+
+    #[test]
+    fn test_map_to_curve() -> Result<()> {
+        let mut rng = TestRng::default();
+        let hash_to_curve = HashToCurve::<CurrentEnvironment>::setup(DST)?;
+
+        // Ensure the exceptional input is mapped to a point on the curve.
+        hash_to_curve.map_to_curve(&Field::zero())?;
+
+        for _ in 0..ITERATIONS {
+            // Ensure the map succeeds, and is deterministic.
+            let u = Field::rand(&mut rng);
+            assert_eq!(hash_to_curve.map_to_curve(&u)?, hash_to_curve.map_to_curve(&u)?);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_hash_to_group() -> Result<()> {
+        let mut rng = TestRng::default();
+        let hash_to_curve = HashToCurve::<CurrentEnvironment>::setup(DST)?;
+        let other = HashToCurve::<CurrentEnvironment>::setup("QUUX-V01-CS02-other")?;
+
+        // Ensure the empty input is supported.
+        hash_to_curve.hash_to_group(&[])?;
+
+        for i in 0..ITERATIONS {
+            let input = (0..i * 8).map(|_| bool::rand(&mut rng)).collect::<Vec<_>>();
+
+            // Ensure the output is in the prime-order subgroup, and is deterministic.
+            let group = hash_to_curve.hash_to_group(&input)?;
+            assert!(group.to_affine().is_in_correct_subgroup_assuming_on_curve());
+            assert_ne!(group, Group::zero());
+            assert_eq!(group, hash_to_curve.hash_to_group(&input)?);
+
+            // Ensure the output depends on the domain separation tag.
+            assert_ne!(group, other.hash_to_group(&input)?);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_setup() {
+        // Ensure the domain separation tag is nonempty, and at most 255 bytes.
+        assert!(HashToCurve::<CurrentEnvironment>::setup("").is_err());
+        assert!(HashToCurve::<CurrentEnvironment>::setup(&"a".repeat(255)).is_ok());
+        assert!(HashToCurve::<CurrentEnvironment>::setup(&"a".repeat(256)).is_err());
+
+        // Ensure Z is a non-square.
+        let hash_to_curve = HashToCurve::<CurrentEnvironment>::setup(DST).unwrap();
+        assert!(hash_to_curve.z().legendre().is_qnr());
+        assert_eq!(hash_to_curve.dst_prime().len(), DST.len() + 1);
+    }
+}
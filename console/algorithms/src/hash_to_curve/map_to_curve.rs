@@ -0,0 +1,82 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use super::*;
+
+impl<E: Environment> HashToCurve<E> {
+    /// Returns the curve point for the given field element, as `map_to_curve_elligator2` in RFC 9380, Section 6.7.1,
+    /// followed by the rational map from the Montgomery curve to the twisted Edwards curve in RFC 9380, Section 6.8.2.
+    ///
+    /// Note: The output is on the curve, but is not necessarily in the prime-order subgroup.
+    pub fn map_to_curve(&self, u: &Field<E>) -> Result<Group<E>> {
+        let one = Field::<E>::one();
+
+        // Compute the Montgomery point (s, t) on: K * t^2 = s^3 + J * s^2 + s.
+        let (s, t) = {
+            // Compute the coefficients of the curve: y^2 = x^3 + (J / K) * x^2 + (1 / K^2) * x.
+            let (j, k) = (Group::<E>::MONTGOMERY_A, Group::<E>::MONTGOMERY_B);
+            let k_inverse = k.inverse().map_err(|_| anyhow!("Hash-to-curve failed: K == 0"))?;
+            let (c1, c2) = (j * k_inverse, k_inverse.square());
+            // Returns `g(x) = x^3 + c1 * x^2 + c2 * x`.
+            let g = |x: &Field<E>| ((x.square() + c1 * x) + c2) * x;
+
+            // Let x1 = -c1 * inv0(1 + Z * u^2).
+            // Note: As -1 is a square, and Z is not a square, (1 + Z * u^2) is never zero.
+            let mut x1 = -c1 * inv0(&(one + self.z * u.square()));
+            // If x1 == 0, set x1 = -c1.
+            if x1.is_zero() {
+                x1 = -c1;
+            }
+            // Let x2 = -x1 - c1.
+            let x2 = -x1 - c1;
+
+            // If g(x1) is a square, select (x1, g(x1)) and the odd square root,
+            // otherwise select (x2, g(x2)) and the even square root.
+            let gx1 = g(&x1);
+            let (x, gx, is_square) = match gx1.legendre().is_qr() {
+                true => (x1, gx1, true),
+                false => (x2, g(&x2), false),
+            };
+            // Compute y = sqrt(gx), where sgn0(y) == is_square.
+            let y = gx.even_square_root().map_err(|_| anyhow!("Hash-to-curve failed: g(x) is not a square"))?;
+            let y = match is_square {
+                true => -y,
+                false => y,
+            };
+            ensure!(y.square() == gx, "Hash-to-curve failed: y^2 != g(x)");
+
+            // Scale (x, y) to the Montgomery point (s, t).
+            (x * k, y * k)
+        };
+
+        // Compute the twisted Edwards point (v, w), as (s / t, (s - 1) / (s + 1)).
+        // Note: The exceptional case (t == 0 or s == -1) is mapped to the identity (0, 1).
+        let (v, w) = {
+            let tv1 = s + one;
+            let tv2 = inv0(&(tv1 * t));
+            let v = tv2 * tv1 * s;
+            let w = match tv2.is_zero() {
+                true => one,
+                false => tv2 * t * (s - one),
+            };
+            (v, w)
+        };
+
+        // Recover the point from the twisted Edwards point (v, w).
+        let point = Group::from_xy_coordinates_unchecked(v, w);
+        // Ensure the recovered point is on the curve.
+        ensure!(point.to_affine().is_on_curve(), "Hash-to-curve failed: point is not on the curve");
+        Ok(point)
+    }
+}
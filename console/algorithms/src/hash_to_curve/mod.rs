@@ -0,0 +1,115 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+mod expand_message;
+mod hash_to_field;
+mod hash_to_group;
+mod map_to_curve;
+
+use snarkvm_console_types::prelude::*;
+use snarkvm_utilities::bytes_from_bits_le;
+
+use tiny_keccak::{Hasher, Sha3 as TinySha3};
+
+/// The suite ID of the hash-to-curve construction, following the naming convention of RFC 9380, Section 8.10.
+pub const HASH_TO_CURVE_SUITE_ID: &str = "edwardsbls12_XMD:SHA3-256_ELL2_RO_";
+
+/// The number of bytes in the output of SHA3-256, i.e. `b_in_bytes`.
+const B_IN_BYTES: usize = 32;
+/// The number of bytes in the input block of SHA3-256, i.e. `s_in_bytes`.
+const S_IN_BYTES: usize = 136;
+/// The target security level in bits of the hash-to-field step, i.e. `k`.
+const SECURITY_LEVEL: usize = 128;
+
+/// The hash-to-curve construction of RFC 9380, for the twisted Edwards group.
+///
+/// ## Design
+/// The construction is the random oracle encoding `hash_to_curve` of RFC 9380, Section 3, instantiated with:
+/// - `expand_message_xmd` over SHA3-256 (Section 5.3.1), to expand the message into uniform bytes,
+/// - `hash_to_field` with `k = 128` (Section 5.2), to derive two base field elements from the uniform bytes,
+/// - the Elligator 2 map (Section 6.7.1) followed by the rational map to the twisted Edwards curve (Section 6.8.2),
+///   to map each field element to a curve point, and
+/// - `clear_cofactor` with `h_eff = 4` (Section 7), to map the sum of the curve points into the prime-order subgroup.
+///
+/// Unlike a try-and-increment loop, the construction runs in a fixed number of steps,
+/// and the discrete log of its output is unknown.
+#[derive(Clone, Debug, PartialEq)]
+pub struct HashToCurve<E: Environment> {
+    /// The domain separation tag, suffixed by its length in bytes, i.e. `DST_prime`.
+    dst_prime: Vec<u8>,
+    /// The non-square `Z` of the Elligator 2 map.
+    z: Field<E>,
+}
+
+impl<E: Environment> HashToCurve<E> {
+    /// Initializes a new instance of the hash-to-curve construction, with the given domain separation tag.
+    pub fn setup(dst: &str) -> Result<Self> {
+        // Ensure the domain separation tag is nonempty, and its length fits in a byte.
+        ensure!(!dst.is_empty(), "The domain separation tag cannot be empty");
+        let num_bytes = u8::try_from(dst.len()).map_err(|_| anyhow!("The domain separation tag exceeds 255 bytes"))?;
+
+        // Construct `DST_prime` as `DST || I2OSP(len(DST), 1)`.
+        let mut dst_prime = dst.as_bytes().to_vec();
+        dst_prime.push(num_bytes);
+
+        Ok(Self { dst_prime, z: Self::find_z()? })
+    }
+
+    /// Returns the domain separation tag, suffixed by its length in bytes, i.e. `DST_prime`.
+    pub fn dst_prime(&self) -> &[u8] {
+        &self.dst_prime
+    }
+
+    /// Returns the non-square `Z` of the Elligator 2 map.
+    pub const fn z(&self) -> Field<E> {
+        self.z
+    }
+
+    /// Returns the number of uniform bytes per field element, i.e. `L = ceil((ceil(log2(p)) + k) / 8)`.
+    pub fn num_bytes_per_field() -> usize {
+        (Field::<E>::size_in_bits() + SECURITY_LEVEL + 7) / 8
+    }
+}
+
+impl<E: Environment> HashToCurve<E> {
+    /// Returns the non-square `Z` of the Elligator 2 map, as `find_z_ell2` in RFC 9380, Appendix H.3.
+    /// The candidates are `1, -1, 2, -2, ...`, and the first non-square is selected.
+    fn find_z() -> Result<Field<E>> {
+        let mut candidate = Field::<E>::one();
+        for _ in 0..u8::MAX {
+            for z in [candidate, -candidate] {
+                if z.legendre().is_qnr() {
+                    return Ok(z);
+                }
+            }
+            candidate += Field::one();
+        }
+        bail!("Failed to find a non-square Z for the Elligator 2 map")
+    }
+}
+
+/// Returns the SHA3-256 hash of the concatenation of the given byte strings.
+fn sha3_256(inputs: &[&[u8]]) -> [u8; B_IN_BYTES] {
+    let mut sha3 = TinySha3::v256();
+    inputs.iter().for_each(|input| sha3.update(input));
+
+    let mut hash = [0u8; B_IN_BYTES];
+    sha3.finalize(&mut hash);
+    hash
+}
+
+/// Returns the inverse of the given field element, or zero if the element is zero, i.e. `inv0(x)`.
+fn inv0<E: Environment>(x: &Field<E>) -> Field<E> {
+    x.inverse().unwrap_or_else(|_| Field::zero())
+}
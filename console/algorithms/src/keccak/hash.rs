@@ -38,6 +38,59 @@ impl<const TYPE: u8, const VARIANT: usize> Hash for Keccak<TYPE, VARIANT> {
     }
 }
 
+impl<const TYPE: u8, const VARIANT: usize> Keccak<TYPE, VARIANT> {
+    /// Returns the Keccak hash of the given input bits as bits,
+    /// consuming the bits without materializing the input.
+    #[inline]
+    pub fn hash_iter(&self, input: impl IntoIterator<Item = bool>) -> Result<Vec<bool>> {
+        let input = input.into_iter();
+        let result = match (TYPE, VARIANT) {
+            (0, 224) => hash_native_iter(TinyKeccak::v224(), input, 28),
+            (0, 256) => hash_native_iter(TinyKeccak::v256(), input, 32),
+            (0, 384) => hash_native_iter(TinyKeccak::v384(), input, 48),
+            (0, 512) => hash_native_iter(TinyKeccak::v512(), input, 64),
+            (1, 224) => hash_native_iter(TinySha3::v224(), input, 28),
+            (1, 256) => hash_native_iter(TinySha3::v256(), input, 32),
+            (1, 384) => hash_native_iter(TinySha3::v384(), input, 48),
+            (1, 512) => hash_native_iter(TinySha3::v512(), input, 64),
+            _ => unreachable!("Invalid Keccak type and variant"),
+        };
+        Ok(result)
+    }
+}
+
+/// The number of bytes buffered before they are absorbed, in `hash_native_iter`.
+const ITER_BUFFER_SIZE: usize = 136;
+
+/// Computes the hash of the given preimage bits with the given hasher, as `num_bytes` bytes in bits.
+/// The bits are packed into bytes in little-endian order, as in `bytes_from_bits_le`.
+fn hash_native_iter<H: Hasher>(mut hasher: H, preimage: impl Iterator<Item = bool>, num_bytes: usize) -> Vec<bool> {
+    let mut buffer = Vec::with_capacity(ITER_BUFFER_SIZE);
+    let (mut byte, mut num_bits) = (0u8, 0);
+    for bit in preimage {
+        byte |= (bit as u8) << num_bits;
+        num_bits += 1;
+        if num_bits == 8 {
+            buffer.push(byte);
+            (byte, num_bits) = (0, 0);
+            // Absorb the buffer, if it is full.
+            if buffer.len() == ITER_BUFFER_SIZE {
+                hasher.update(&buffer);
+                buffer.clear();
+            }
+        }
+    }
+    // Pad the last byte with zeros.
+    if num_bits > 0 {
+        buffer.push(byte);
+    }
+    hasher.update(&buffer);
+
+    let mut hash = vec![0u8; num_bytes];
+    hasher.finalize(&mut hash);
+    bits_from_bytes_le(&hash).collect()
+}
+
 /// Computes the Keccak-224 hash of the given preimage as bytes.
 fn keccak_224_native(preimage: &[u8]) -> [u8; 28] {
     let mut keccak = TinyKeccak::v224();
@@ -144,6 +197,10 @@ mod tests {
                 // Compute the console hash.
                 let candidate = $console.hash(&input).unwrap();
                 assert_eq!(expected, candidate);
+
+                // Compute the console hash over an iterator.
+                let candidate = $console.hash_iter(input.iter().copied()).unwrap();
+                assert_eq!(expected, candidate);
             }
         };
     }
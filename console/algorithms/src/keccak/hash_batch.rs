@@ -0,0 +1,73 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use super::*;
+
+#[cfg(not(feature = "serial"))]
+use rayon::prelude::*;
+
+impl<const TYPE: u8, const VARIANT: usize> Keccak<TYPE, VARIANT> {
+    /// Returns the Keccak hash of each of the given inputs as bits.
+    /// The inputs are hashed in parallel, unless the `serial` feature is enabled.
+    pub fn hash_batch<I: AsRef<[bool]> + Sync>(&self, inputs: &[I]) -> Result<Vec<Vec<bool>>> {
+        cfg_iter!(inputs).map(|input| self.hash(input.as_ref())).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Rng;
+
+    macro_rules! check_batch {
+        ($console:expr) => {
+            let rng = &mut TestRng::default();
+
+            // Prepare the preimages.
+            let inputs = (0..32)
+                .map(|_| (0..rng.gen_range(1..1024)).map(|_| Uniform::rand(rng)).collect::<Vec<bool>>())
+                .collect::<Vec<_>>();
+
+            // Ensure the batch hash matches the hash of each input.
+            let candidates = $console.hash_batch(&inputs).unwrap();
+            assert_eq!(candidates.len(), inputs.len());
+            for (input, candidate) in inputs.iter().zip(candidates) {
+                assert_eq!($console.hash(input).unwrap(), candidate);
+            }
+
+            // Ensure an empty batch is hashed to an empty output.
+            assert!($console.hash_batch::<Vec<bool>>(&[]).unwrap().is_empty());
+        };
+    }
+
+    #[test]
+    fn test_keccak_256_batch() {
+        check_batch!(Keccak256::default());
+    }
+
+    #[test]
+    fn test_keccak_512_batch() {
+        check_batch!(Keccak512::default());
+    }
+
+    #[test]
+    fn test_sha3_256_batch() {
+        check_batch!(Sha3_256::default());
+    }
+
+    #[test]
+    fn test_sha3_512_batch() {
+        check_batch!(Sha3_512::default());
+    }
+}
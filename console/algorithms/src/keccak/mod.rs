@@ -14,6 +14,7 @@
 // limitations under the License.
 
 mod hash;
+mod hash_batch;
 
 #[cfg(test)]
 use snarkvm_utilities::Uniform;
@@ -29,6 +29,9 @@ pub use blake2xs::Blake2Xs;
 mod elligator2;
 pub use elligator2::Elligator2;
 
+mod hash_to_curve;
+pub use hash_to_curve::{HashToCurve, HASH_TO_CURVE_SUITE_ID};
+
 mod keccak;
 pub use keccak::*;
 
@@ -25,3 +25,10 @@ impl<E: Environment, const RATE: usize> Hash for Poseidon<E, RATE> {
         Ok(self.hash_many(input, 1)[0])
     }
 }
+
+impl<E: Environment, const RATE: usize> Poseidon<E, RATE> {
+    /// Returns the cryptographic hash for an iterator of field elements as input.
+    pub fn hash_iter(&self, input: impl IntoIterator<Item = Field<E>>) -> Result<Field<E>> {
+        Ok(self.hash_many_iter(input, 1)?[0])
+    }
+}
@@ -35,3 +35,44 @@ impl<E: Environment, const RATE: usize> HashMany for Poseidon<E, RATE> {
         sponge.squeeze(num_outputs).into_vec()
     }
 }
+
+impl<E: Environment, const RATE: usize> Poseidon<E, RATE> {
+    /// Returns the cryptographic hash for an iterator of field elements as input,
+    /// and returns the specified number of field elements as output.
+    ///
+    /// The length of the input is absorbed first, so the iterator must report an exact size.
+    pub fn hash_many_iter(&self, input: impl IntoIterator<Item = Field<E>>, num_outputs: u16) -> Result<Vec<Field<E>>> {
+        let mut input = input.into_iter();
+        // Determine the number of input elements.
+        let num_inputs = match input.size_hint() {
+            (lower, Some(upper)) if lower == upper => lower,
+            _ => bail!("The input to the Poseidon hash must have an exact size"),
+        };
+
+        // Construct the header: [ DOMAIN || LENGTH(INPUT) || [0; RATE-2] ].
+        let mut header = [Field::<E>::zero(); RATE];
+        header[0] = self.domain;
+        header[1] = Field::<E>::from_u128(num_inputs as u128);
+
+        let mut sponge = PoseidonSponge::<E, RATE, CAPACITY>::new(&self.parameters);
+        sponge.absorb(&header);
+
+        // Absorb the input in blocks of RATE elements.
+        let mut block = Vec::with_capacity(RATE);
+        let mut num_absorbed = 0;
+        loop {
+            block.extend(input.by_ref().take(RATE));
+            if block.is_empty() {
+                break;
+            }
+            sponge.absorb(&block);
+            num_absorbed += block.len();
+            block.clear();
+        }
+
+        // Ensure the iterator yielded the number of elements it reported.
+        ensure!(num_absorbed == num_inputs, "Expected {num_inputs} input elements, found {num_absorbed}");
+
+        Ok(sponge.squeeze(num_outputs).into_vec())
+    }
+}
@@ -420,4 +420,23 @@ mod tests {
             .unwrap(),
         ]);
     }
+
+    #[test]
+    fn test_hash_many_iter() -> Result<()> {
+        let rng = &mut TestRng::default();
+        let poseidon = Poseidon4::<CurrentEnvironment>::setup("Poseidon4")?;
+
+        for num_inputs in 0..20 {
+            let input = (0..num_inputs).map(|_| Uniform::rand(rng)).collect::<Vec<Field<CurrentEnvironment>>>();
+            // Ensure the streamed hash matches the hash of the materialized input.
+            for num_outputs in [1, 3] {
+                let expected = poseidon.hash_many(&input, num_outputs);
+                assert_eq!(expected, poseidon.hash_many_iter(input.iter().copied(), num_outputs)?);
+            }
+            assert_eq!(poseidon.hash(&input)?, poseidon.hash_iter(input.iter().copied())?);
+        }
+        // Ensure an iterator without an exact size is rejected.
+        assert!(poseidon.hash_iter((0..8u8).map(Field::from_u8).filter(|field| !field.is_zero())).is_err());
+        Ok(())
+    }
 }
@@ -16,6 +16,8 @@
 #[macro_use]
 extern crate criterion;
 
+use snarkvm_console_algorithms::{BHP1024, BHP512};
+use snarkvm_console_collections::merkle_tree::{MerkleTree, ParallelConfig};
 use snarkvm_console_network::{
     prelude::{TestRng, ToBits, Uniform},
     MainnetV0,
@@ -32,6 +34,8 @@ const MAX_INSTANTIATED_DEPTH: u8 = 16;
 const NUM_LEAVES: &[usize] = &[1, 10, 100, 1_000, 10_000, 100_000];
 const APPEND_SIZES: &[usize] = &[1, 10, 100, 1_000, 10_000, 100_000];
 const UPDATE_SIZES: &[usize] = &[1, 10, 100, 1_000, 10_000];
+const CHUNK_SIZES: &[usize] = &[1, 4, 16, 64, 256];
+const MAX_SERIAL_LENS: &[usize] = &[10, 100, 1_000];
 
 /// Generates the specified number of random Merkle tree leaves.
 macro_rules! generate_leaves {
@@ -52,6 +56,32 @@ fn new(c: &mut Criterion) {
     }
 }
 
+fn new_with_config(c: &mut Criterion) {
+    let mut group = c.benchmark_group("MerkleTree/new_with_config");
+    let mut rng = TestRng::default();
+    // Accumulate leaves in a vector to avoid recomputing across iterations.
+    let leaves = generate_leaves!(*NUM_LEAVES.last().unwrap(), &mut rng);
+    let leaf_hasher = BHP1024::<MainnetV0>::setup("AleoMerkleTreeBench0").unwrap();
+    let path_hasher = BHP512::<MainnetV0>::setup("AleoMerkleTreeBench1").unwrap();
+    // Benchmark the creation of the largest Merkle tree, for each parallel configuration.
+    for max_serial_len in MAX_SERIAL_LENS {
+        for chunk_size in CHUNK_SIZES {
+            let config = ParallelConfig::new(*max_serial_len, *chunk_size).unwrap();
+            group.bench_with_input(
+                BenchmarkId::new(format!("max_serial_len={max_serial_len}"), format!("chunk_size={chunk_size}")),
+                &config,
+                |b, config| {
+                    b.iter(|| {
+                        MerkleTree::<_, _, _, DEPTH>::new_with_config(&leaf_hasher, &path_hasher, &leaves, config)
+                            .unwrap()
+                    })
+                },
+            );
+        }
+    }
+    group.finish();
+}
+
 fn append(c: &mut Criterion) {
     let mut rng = TestRng::default();
     // Accumulate all leaves in a vector to avoid recomputing across iterations.
@@ -179,6 +209,6 @@ fn update_vs_update_many(c: &mut Criterion) {
 criterion_group! {
     name = merkle_tree;
     config = Criterion::default().sample_size(10);
-    targets = new, append, update, update_many, update_vs_update_many
+    targets = new, new_with_config, append, update, update_many, update_vs_update_many
 }
 criterion_main!(merkle_tree);
@@ -13,12 +13,10 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use super::ParallelConfig;
 use snarkvm_console_algorithms::{Poseidon, BHP};
 use snarkvm_console_types::prelude::*;
 
-#[cfg(not(feature = "serial"))]
-use rayon::prelude::*;
-
 /// A trait for a Merkle leaf hash function.
 pub trait LeafHash: Clone + Send + Sync {
     type Hash: FieldTrait;
@@ -29,11 +27,19 @@ pub trait LeafHash: Clone + Send + Sync {
 
     /// Returns the hash for each leaf node.
     fn hash_leaves(&self, leaves: &[Self::Leaf]) -> Result<Vec<Self::Hash>> {
-        match leaves.len() {
-            0 => Ok(vec![]),
-            1..=100 => leaves.iter().map(|leaf| self.hash_leaf(leaf)).collect(),
-            _ => cfg_iter!(leaves).map(|leaf| self.hash_leaf(leaf)).collect(),
-        }
+        let mut hashes = vec![Self::Hash::zero(); leaves.len()];
+        self.hash_leaves_into(leaves, &mut hashes, &ParallelConfig::default())?;
+        Ok(hashes)
+    }
+
+    /// Writes the hash for each leaf node into the given output, using the given parallel configuration.
+    fn hash_leaves_into(
+        &self,
+        leaves: &[Self::Leaf],
+        output: &mut [Self::Hash],
+        config: &ParallelConfig,
+    ) -> Result<()> {
+        config.map_into(leaves, 1, output, |leaf| self.hash_leaf(&leaf[0]))
     }
 }
 
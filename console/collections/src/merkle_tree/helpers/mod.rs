@@ -16,5 +16,8 @@
 mod leaf_hash;
 pub use leaf_hash::*;
 
+mod parallel;
+pub use parallel::*;
+
 mod path_hash;
 pub use path_hash::*;
@@ -0,0 +1,141 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use snarkvm_console_types::prelude::*;
+
+#[cfg(not(feature = "serial"))]
+use rayon::prelude::*;
+
+/// The configuration for computing the hashes of a Merkle tree level in parallel.
+///
+/// A level with at most `max_serial_len` hashes is computed serially, as the hashes do not amortize the
+/// overhead of spawning tasks. Otherwise, the level is split into chunks of `chunk_size` hashes, which are
+/// computed in parallel and written directly into the node layer of the tree.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct ParallelConfig {
+    /// The maximum number of hashes in a level, for the level to be computed serially.
+    max_serial_len: usize,
+    /// The number of hashes computed by each parallel task.
+    chunk_size: usize,
+}
+
+impl ParallelConfig {
+    /// The default number of hashes computed by each parallel task.
+    pub const DEFAULT_CHUNK_SIZE: usize = 16;
+    /// The default maximum number of hashes in a level, for the level to be computed serially.
+    pub const DEFAULT_MAX_SERIAL_LEN: usize = 100;
+
+    /// Initializes a new parallel configuration.
+    pub fn new(max_serial_len: usize, chunk_size: usize) -> Result<Self> {
+        // Ensure the chunk size is nonzero.
+        ensure!(chunk_size > 0, "The chunk size of a parallel configuration must be greater than 0");
+        Ok(Self { max_serial_len, chunk_size })
+    }
+
+    /// Returns a configuration that computes every level serially.
+    pub const fn serial() -> Self {
+        Self { max_serial_len: usize::MAX, chunk_size: Self::DEFAULT_CHUNK_SIZE }
+    }
+
+    /// Returns the maximum number of hashes in a level, for the level to be computed serially.
+    pub const fn max_serial_len(&self) -> usize {
+        self.max_serial_len
+    }
+
+    /// Returns the number of hashes computed by each parallel task.
+    pub const fn chunk_size(&self) -> usize {
+        self.chunk_size
+    }
+
+    /// Writes `f(input[i * stride..(i + 1) * stride])` into `output[i]`, for each index `i` of the output.
+    pub(crate) fn map_into<T: Sync, H: Send>(
+        &self,
+        input: &[T],
+        stride: usize,
+        output: &mut [H],
+        f: impl Fn(&[T]) -> Result<H> + Send + Sync,
+    ) -> Result<()> {
+        // Ensure the input contains `stride` elements for each output.
+        ensure!(stride > 0 && input.len() == output.len() * stride, "Mismatched number of Merkle tree hashes");
+
+        // Computes the hashes for the given chunk of the output.
+        let map_chunk = |(output, input): (&mut [H], &[T])| {
+            for (hash, items) in output.iter_mut().zip(input.chunks(stride)) {
+                *hash = f(items)?;
+            }
+            Ok::<_, Error>(())
+        };
+
+        match output.len() <= self.max_serial_len {
+            true => map_chunk((output, input)),
+            false => cfg_chunks_mut!(output, self.chunk_size)
+                .zip(cfg_chunks!(input, self.chunk_size * stride))
+                .try_for_each(map_chunk),
+        }
+    }
+}
+
+impl Default for ParallelConfig {
+    /// Returns the default parallel configuration.
+    fn default() -> Self {
+        Self { max_serial_len: Self::DEFAULT_MAX_SERIAL_LEN, chunk_size: Self::DEFAULT_CHUNK_SIZE }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::merkle_tree::MerkleTree;
+    use snarkvm_console_algorithms::{BHP1024, BHP512};
+    use snarkvm_console_types::prelude::Console;
+
+    type CurrentEnvironment = Console;
+
+    #[test]
+    fn test_map_into() -> Result<()> {
+        let input = (0..1000u64).collect::<Vec<_>>();
+        // Ensure the serial and parallel configurations compute the same output.
+        for config in [ParallelConfig::serial(), ParallelConfig::default(), ParallelConfig::new(0, 1)?] {
+            let mut output = vec![0u64; input.len() / 2];
+            config.map_into(&input, 2, &mut output, |pair| Ok(pair[0] + pair[1]))?;
+            assert!(output.iter().enumerate().all(|(i, sum)| *sum == 4 * i as u64 + 1));
+        }
+        // Ensure mismatched lengths and a zero chunk size are rejected.
+        assert!(ParallelConfig::default().map_into(&input, 3, &mut [0u64; 10], |items| Ok(items[0])).is_err());
+        assert!(ParallelConfig::new(0, 0).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_new_with_config() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        let leaf_hasher = BHP1024::<CurrentEnvironment>::setup("AleoMerkleTreeTest0")?;
+        let path_hasher = BHP512::<CurrentEnvironment>::setup("AleoMerkleTreeTest1")?;
+
+        for num_leaves in [0, 1, 2, 5, 64, 257] {
+            let leaves =
+                (0..num_leaves).map(|_| Field::<CurrentEnvironment>::rand(rng).to_bits_le()).collect::<Vec<_>>();
+            let expected = MerkleTree::<_, _, _, 16>::new(&leaf_hasher, &path_hasher, &leaves)?;
+            // Ensure the tree is independent of the parallel configuration.
+            for config in [ParallelConfig::serial(), ParallelConfig::new(0, 1)?, ParallelConfig::new(8, 3)?] {
+                let tree = MerkleTree::<_, _, _, 16>::new_with_config(&leaf_hasher, &path_hasher, &leaves, &config)?;
+                assert_eq!(tree.root(), expected.root());
+                assert_eq!(tree.tree(), expected.tree());
+            }
+        }
+        Ok(())
+    }
+}
@@ -13,12 +13,10 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use super::ParallelConfig;
 use snarkvm_console_algorithms::{Poseidon, BHP};
 use snarkvm_console_types::prelude::*;
 
-#[cfg(not(feature = "serial"))]
-use rayon::prelude::*;
-
 /// A trait for a Merkle path hash function.
 pub trait PathHash: Clone + Send + Sync {
     type Hash: FieldTrait;
@@ -33,11 +31,21 @@ pub trait PathHash: Clone + Send + Sync {
 
     /// Returns the hash for each tuple of child nodes.
     fn hash_all_children(&self, child_nodes: &[(Self::Hash, Self::Hash)]) -> Result<Vec<Self::Hash>> {
-        match child_nodes.len() {
-            0 => Ok(vec![]),
-            1..=100 => child_nodes.iter().map(|(left, right)| self.hash_children(left, right)).collect(),
-            _ => cfg_iter!(child_nodes).map(|(left, right)| self.hash_children(left, right)).collect(),
-        }
+        let mut hashes = vec![Self::Hash::zero(); child_nodes.len()];
+        ParallelConfig::default()
+            .map_into(child_nodes, 1, &mut hashes, |children| self.hash_children(&children[0].0, &children[0].1))?;
+        Ok(hashes)
+    }
+
+    /// Writes the hash for each pair of adjacent child nodes into the given output,
+    /// using the given parallel configuration.
+    fn hash_children_into(
+        &self,
+        child_nodes: &[Self::Hash],
+        output: &mut [Self::Hash],
+        config: &ParallelConfig,
+    ) -> Result<()> {
+        config.map_into(child_nodes, 2, output, |children| self.hash_children(&children[0], &children[1]))
     }
 }
 
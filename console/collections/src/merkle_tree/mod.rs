@@ -16,6 +16,9 @@
 mod helpers;
 pub use helpers::*;
 
+mod multi_path;
+pub use multi_path::*;
+
 mod path;
 pub use path::*;
 
@@ -53,6 +56,17 @@ impl<E: Environment, LH: LeafHash<Hash = PH::Hash>, PH: PathHash<Hash = Field<E>
     #[inline]
     /// Initializes a new Merkle tree with the given leaves.
     pub fn new(leaf_hasher: &LH, path_hasher: &PH, leaves: &[LH::Leaf]) -> Result<Self> {
+        Self::new_with_config(leaf_hasher, path_hasher, leaves, &ParallelConfig::default())
+    }
+
+    #[inline]
+    /// Initializes a new Merkle tree with the given leaves, using the given parallel configuration.
+    pub fn new_with_config(
+        leaf_hasher: &LH,
+        path_hasher: &PH,
+        leaves: &[LH::Leaf],
+        config: &ParallelConfig,
+    ) -> Result<Self> {
         let timer = timer!("MerkleTree::new");
 
         // Ensure the Merkle tree depth is greater than 0.
@@ -88,7 +102,7 @@ impl<E: Environment, LH: LeafHash<Hash = PH::Hash>, PH: PathHash<Hash = Field<E>
         let mut tree = vec![empty_hash; minimum_tree_size];
 
         // Compute and store each leaf hash.
-        tree[num_nodes..num_nodes + leaves.len()].copy_from_slice(&leaf_hasher.hash_leaves(leaves)?);
+        leaf_hasher.hash_leaves_into(leaves, &mut tree[num_nodes..num_nodes + leaves.len()], config)?;
         lap!(timer, "Hashed {} leaves", leaves.len());
 
         // Compute and store the hashes for each level, iterating from the penultimate level to the root level.
@@ -97,21 +111,18 @@ impl<E: Environment, LH: LeafHash<Hash = PH::Hash>, PH: PathHash<Hash = Field<E>
         while let Some(start) = parent(start_index) {
             // Compute the end index of the current level.
             let end = left_child(start);
-            // Construct the children for each node in the current level; the leaves are padded, which means
-            // that there either are 2 children, or there are none, at which point we may stop iterating.
-            let tuples = (start..end)
-                .take_while(|&i| tree.get(left_child(i)).is_some())
-                .map(|i| (tree[left_child(i)], tree[right_child(i)]))
-                .collect::<Vec<_>>();
+            // Split the tree at the end of the current level, so the children are hashed directly into the level.
+            let (nodes, children) = tree.split_at_mut(end);
+            // Count the nodes in the current level with children; the leaves are padded, which means
+            // that there either are 2 children, or there are none.
+            let num_full_nodes = std::cmp::min(end - start, children.len() / 2);
             // Compute and store the hashes for each node in the current level.
-            let num_full_nodes = tuples.len();
-            tree[start..][..num_full_nodes].copy_from_slice(&path_hasher.hash_all_children(&tuples)?);
+            let full_nodes = &mut nodes[start..start + num_full_nodes];
+            path_hasher.hash_children_into(&children[..2 * num_full_nodes], full_nodes, config)?;
             // Use the precomputed empty node hash for every empty node, if there are any.
             if start + num_full_nodes < end {
                 let empty_node_hash = path_hasher.hash_children(&empty_hash, &empty_hash)?;
-                for node in tree.iter_mut().take(end).skip(start + num_full_nodes) {
-                    *node = empty_node_hash;
-                }
+                nodes[start + num_full_nodes..end].fill(empty_node_hash);
             }
             // Update the start index for the next level.
             start_index = start;
@@ -223,13 +234,26 @@ impl<E: Environment, LH: LeafHash<Hash = PH::Hash>, PH: PathHash<Hash = Field<E>
 
     #[inline]
     /// Updates the Merkle tree with the given new leaves appended to it.
+    ///
+    /// If the new leaves fit within the current power-of-two capacity of the Merkle tree, only the nodes
+    /// along the paths of the new leaves are recomputed, in place. Otherwise, the Merkle tree is rebuilt.
     pub fn append(&mut self, new_leaves: &[LH::Leaf]) -> Result<()> {
         let timer = timer!("MerkleTree::append");
 
-        // Compute the updated Merkle tree with the new leaves.
-        let updated_tree = self.prepare_append(new_leaves)?;
-        // Update the tree at the very end, so the original tree is not altered in case of failure.
-        *self = updated_tree;
+        // Determine whether the new leaves fit within the current maximum number of leaves.
+        let max_leaves = self.number_of_leaves.checked_next_power_of_two();
+        let new_max_leaves = (self.number_of_leaves + new_leaves.len()).checked_next_power_of_two();
+        let is_within_capacity = max_leaves.is_some() && max_leaves == new_max_leaves;
+
+        match (new_leaves.is_empty(), is_within_capacity) {
+            // If there are no new leaves, the Merkle tree is unchanged.
+            (true, _) => (),
+            // If the capacity is unchanged, recompute the paths of the new leaves.
+            (false, true) => self.append_in_place(new_leaves)?,
+            // Otherwise, compute the updated Merkle tree with the new leaves.
+            // The tree is updated at the very end, so the original tree is not altered in case of failure.
+            (false, false) => *self = self.prepare_append(new_leaves)?,
+        }
 
         finish!(timer);
         Ok(())
@@ -237,13 +261,20 @@ impl<E: Environment, LH: LeafHash<Hash = PH::Hash>, PH: PathHash<Hash = Field<E>
 
     #[inline]
     /// Updates the Merkle tree at the location of the given leaf index with the new leaf.
+    ///
+    /// Only the nodes along the path of the leaf are recomputed, in place.
     pub fn update(&mut self, leaf_index: usize, new_leaf: &LH::Leaf) -> Result<()> {
         let timer = timer!("MerkleTree::update");
 
-        // Compute the updated Merkle tree with the new leaves.
-        let updated_tree = self.prepare_update(leaf_index, new_leaf)?;
-        // Update the tree at the very end, so the original tree is not altered in case of failure.
-        *self = updated_tree;
+        // Compute the new path hashes, so the original tree is not altered in case of failure.
+        let (leaf_position, path_hashes, root_hash) = self.compute_updated_path(leaf_index, new_leaf)?;
+        // Update the nodes along the path from the leaf to the root.
+        let mut index = Some(leaf_position);
+        for path_hash in path_hashes {
+            self.tree[index.unwrap()] = path_hash;
+            index = parent(index.unwrap());
+        }
+        self.root = root_hash;
 
         finish!(timer);
         Ok(())
@@ -254,6 +285,39 @@ impl<E: Environment, LH: LeafHash<Hash = PH::Hash>, PH: PathHash<Hash = Field<E>
     pub fn prepare_update(&self, leaf_index: usize, new_leaf: &LH::Leaf) -> Result<Self> {
         let timer = timer!("MerkleTree::prepare_update");
 
+        // Compute the new path hashes.
+        let (leaf_position, path_hashes, root_hash) = self.compute_updated_path(leaf_index, new_leaf)?;
+
+        // Initialize the Merkle tree.
+        let mut tree = Vec::with_capacity(self.tree.len());
+        // Extend the new Merkle tree with the existing leaf hashes.
+        tree.extend(&self.tree);
+
+        // Update the rest of the tree with the new path hashes.
+        let mut index = Some(leaf_position);
+        for path_hash in path_hashes {
+            tree[index.unwrap()] = path_hash;
+            index = parent(index.unwrap());
+        }
+
+        finish!(timer);
+
+        Ok(Self {
+            leaf_hasher: self.leaf_hasher.clone(),
+            path_hasher: self.path_hasher.clone(),
+            root: root_hash,
+            tree,
+            empty_hash: self.empty_hash,
+            number_of_leaves: self.number_of_leaves,
+        })
+    }
+
+    /// Returns the position of the given leaf index in the tree, the new hashes for the path from the leaf
+    /// to the root (starting with the new leaf hash), and the new root hash.
+    #[inline]
+    fn compute_updated_path(&self, leaf_index: usize, new_leaf: &LH::Leaf) -> Result<(usize, Vec<PH::Hash>, PH::Hash)> {
+        let timer = timer!("MerkleTree::compute_updated_path");
+
         // Check that the leaf index is within the bounds of the Merkle tree.
         ensure!(
             leaf_index < self.number_of_leaves,
@@ -302,28 +366,71 @@ impl<E: Environment, LH: LeafHash<Hash = PH::Hash>, PH: PathHash<Hash = Field<E>
         }
         lap!(timer, "Hashed {} padding levels", padding_depth);
 
-        // Initialize the Merkle tree.
-        let mut tree = Vec::with_capacity(self.tree.len());
-        // Extend the new Merkle tree with the existing leaf hashes.
-        tree.extend(&self.tree);
+        finish!(timer);
 
-        // Update the rest of the tree with the new path hashes.
-        let mut index = Some(start + leaf_index);
-        for path_hash in path_hashes {
-            tree[index.unwrap()] = path_hash;
-            index = parent(index.unwrap());
+        Ok((start + leaf_index, path_hashes, root_hash))
+    }
+
+    /// Appends the given new leaves to the Merkle tree, recomputing only the nodes along the paths of the new leaves.
+    ///
+    /// Note: The caller must ensure there is at least one new leaf, and that the new leaves fit within
+    /// the current power-of-two capacity of the Merkle tree.
+    #[inline]
+    fn append_in_place(&mut self, new_leaves: &[LH::Leaf]) -> Result<()> {
+        let timer = timer!("MerkleTree::append_in_place");
+
+        // Compute the number of nodes, which is the start index of the leaf hashes level.
+        let num_nodes = match self.number_of_leaves.checked_next_power_of_two() {
+            Some(num_leaves) => num_leaves - 1,
+            None => bail!("Integer overflow when computing the Merkle tree start index"),
+        };
+        let new_number_of_leaves = self.number_of_leaves + new_leaves.len();
+
+        // Compute the new hashes for each level, from the new leaf hashes up to the root.
+        // Each entry is the start index of the updated range in its level, and the new hashes in that range.
+        let mut updates = vec![(num_nodes + self.number_of_leaves, self.leaf_hasher.hash_leaves(new_leaves)?)];
+        lap!(timer, "Hashed {} new leaves", new_leaves.len());
+        while let Some((start, hashes)) = updates.last() {
+            // Compute the range of parents of the updated range.
+            let (Some(parent_start), Some(parent_end)) = (parent(*start), parent(start + hashes.len() - 1)) else {
+                break;
+            };
+            // Returns the hash of the given node in the current level, which is empty if the node is leafless.
+            let node = |index: usize| match index.checked_sub(*start).and_then(|offset| hashes.get(offset)) {
+                Some(hash) => *hash,
+                None => self.tree.get(index).copied().unwrap_or(self.empty_hash),
+            };
+            // Compute the hashes of the parents.
+            let tuples =
+                (parent_start..=parent_end).map(|i| (node(left_child(i)), node(right_child(i)))).collect::<Vec<_>>();
+            updates.push((parent_start, self.path_hasher.hash_all_children(&tuples)?));
         }
+        lap!(timer, "Hashed {} levels", updates.len() - 1);
 
-        finish!(timer);
+        // Compute the root hash, by iterating from the root level up to `DEPTH`.
+        let padding_depth = DEPTH - tree_depth::<DEPTH>(2 * num_nodes + 1)?;
+        // Note: This unwrap is safe, as the updates are guaranteed to end at the root.
+        let mut root_hash = updates.last().unwrap().1[0];
+        for _ in 0..padding_depth {
+            // Update the root hash, by hashing the current root hash with the empty hash.
+            root_hash = self.path_hasher.hash_children(&root_hash, &self.empty_hash)?;
+        }
+        lap!(timer, "Hashed {} padding levels", padding_depth);
 
-        Ok(Self {
-            leaf_hasher: self.leaf_hasher.clone(),
-            path_hasher: self.path_hasher.clone(),
-            root: root_hash,
-            tree,
-            empty_hash: self.empty_hash,
-            number_of_leaves: self.number_of_leaves,
-        })
+        // Resize the Merkle tree to exclude leafless nodes, and store the new hashes.
+        let minimum_tree_size = std::cmp::max(
+            1,
+            num_nodes + new_number_of_leaves + if new_number_of_leaves > 1 { new_number_of_leaves % 2 } else { 0 },
+        );
+        self.tree.resize(minimum_tree_size, self.empty_hash);
+        for (start, hashes) in updates {
+            self.tree[start..start + hashes.len()].copy_from_slice(&hashes);
+        }
+        self.root = root_hash;
+        self.number_of_leaves = new_number_of_leaves;
+
+        finish!(timer);
+        Ok(())
     }
 
     #[inline]
@@ -610,6 +717,47 @@ impl<E: Environment, LH: LeafHash<Hash = PH::Hash>, PH: PathHash<Hash = Field<E>
         path.verify(&self.leaf_hasher, &self.path_hasher, root, leaf)
     }
 
+    /// Returns the Merkle multi-path for the given leaves, as a map from leaf index to leaf.
+    pub fn prove_many(&self, leaves: &BTreeMap<usize, LH::Leaf>) -> Result<MerkleMultiPath<E, DEPTH>> {
+        // Ensure there is at least one leaf.
+        ensure!(!leaves.is_empty(), "The Merkle multi-path must contain at least one leaf");
+        // Ensure the leaf indices are valid.
+        ensure!(
+            leaves.keys().all(|leaf_index| *leaf_index < self.number_of_leaves),
+            "The given Merkle leaf index is out of bounds"
+        );
+
+        // Compute the leaf hashes.
+        let leaf_hashes = self.leaf_hasher.hash_leaves(&leaves.values().cloned().collect::<Vec<_>>())?;
+        // Ensure the leaf hashes match the ones in the tree.
+        ensure!(
+            leaves
+                .keys()
+                .zip_eq(&leaf_hashes)
+                .all(|(leaf_index, leaf_hash)| self.node(0, *leaf_index as u64) == *leaf_hash),
+            "The given Merkle leaves do not match the ones in the Merkle tree"
+        );
+
+        // Collect the sibling hashes that cannot be computed from the leaves, from the leaf level to the root level.
+        let mut siblings = Vec::new();
+        compute_multi_root::<_, DEPTH>(
+            leaves.keys().map(|leaf_index| (*leaf_index as u64, ())).collect(),
+            |level, index| {
+                siblings.push(self.node(level, index));
+                Ok(())
+            },
+            |_, _| Ok(()),
+        )?;
+
+        // Return the Merkle multi-path.
+        MerkleMultiPath::try_from((leaves.keys().map(|leaf_index| U64::new(*leaf_index as u64)).collect(), siblings))
+    }
+
+    /// Returns `true` if the given Merkle multi-path is valid for the given root and leaves.
+    pub fn verify_many(&self, path: &MerkleMultiPath<E, DEPTH>, root: &PH::Hash, leaves: &[LH::Leaf]) -> bool {
+        path.verify(&self.leaf_hasher, &self.path_hasher, root, leaves)
+    }
+
     /// Returns the Merkle root of the tree.
     pub const fn root(&self) -> &PH::Hash {
         &self.root
@@ -643,6 +791,20 @@ impl<E: Environment, LH: LeafHash<Hash = PH::Hash>, PH: PathHash<Hash = Field<E>
         self.number_of_leaves
     }
 
+    /// Returns the hash of the node at the given level and index, where the leaves are at level 0.
+    /// The nodes beyond the stored tree are the empty hash.
+    fn node(&self, level: u8, index: u64) -> PH::Hash {
+        // Compute the number of levels in the stored tree.
+        let tree_depth = self.number_of_leaves.next_power_of_two().trailing_zeros();
+        match (level as u32) <= tree_depth && index < (1u64 << (tree_depth - level as u32)) {
+            true => {
+                let position = (1usize << (tree_depth - level as u32)) - 1 + index as usize;
+                self.tree.get(position).copied().unwrap_or(self.empty_hash)
+            }
+            false => self.empty_hash,
+        }
+    }
+
     /// Compute and store the hashes for each level, iterating from the penultimate level to the root level.
     ///
     /// ```ignore
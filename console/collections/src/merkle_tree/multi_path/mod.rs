@@ -0,0 +1,185 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+/// A Merkle multi-path, which proves the inclusion of many leaves with one set of sibling hashes.
+///
+/// The siblings are the hashes that cannot be computed from the leaves, ordered from the leaf level to the root
+/// level, and by index within each level. Siblings shared by the paths of several leaves are included once.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct MerkleMultiPath<E: Environment, const DEPTH: u8> {
+    /// The leaf indices for the path, in increasing order.
+    leaf_indices: Vec<U64<E>>,
+    /// The `siblings` contains a list of sibling hashes from the leaves to the root.
+    siblings: Vec<Field<E>>,
+}
+
+impl<E: Environment, const DEPTH: u8> TryFrom<(Vec<U64<E>>, Vec<Field<E>>)> for MerkleMultiPath<E, DEPTH> {
+    type Error = Error;
+
+    /// Returns a new instance of a Merkle multi-path.
+    fn try_from((leaf_indices, siblings): (Vec<U64<E>>, Vec<Field<E>>)) -> Result<Self> {
+        // Ensure the Merkle tree depth is greater than 0.
+        ensure!(DEPTH > 0, "Merkle tree depth must be greater than 0");
+        // Ensure the Merkle tree depth is less than or equal to 64.
+        ensure!(DEPTH <= 64u8, "Merkle tree depth must be less than or equal to 64");
+        // Ensure there is at least one leaf index.
+        ensure!(!leaf_indices.is_empty(), "Found a Merkle multi-path without leaves");
+        // Ensure the leaf indices are in increasing order, without duplicates.
+        ensure!(leaf_indices.windows(2).all(|pair| pair[0] < pair[1]), "Found unordered Merkle leaf indices");
+        // Ensure the leaf indices are within the tree depth.
+        ensure!(
+            leaf_indices.iter().all(|leaf_index| (**leaf_index as u128) < (1u128 << DEPTH)),
+            "Found an out of bounds Merkle leaf index"
+        );
+        // Ensure the Merkle multi-path has the correct number of siblings.
+        let mut num_siblings = 0usize;
+        compute_multi_root::<_, DEPTH>(
+            leaf_indices.iter().map(|leaf_index| (**leaf_index, ())).collect(),
+            |_, _| {
+                num_siblings += 1;
+                Ok(())
+            },
+            |_, _| Ok(()),
+        )?;
+        ensure!(siblings.len() == num_siblings, "Found an incorrect number of Merkle multi-path siblings");
+        // Return the Merkle multi-path.
+        Ok(Self { leaf_indices, siblings })
+    }
+}
+
+impl<E: Environment, const DEPTH: u8> MerkleMultiPath<E, DEPTH> {
+    /// Returns the leaf indices for the path, in increasing order.
+    pub fn leaf_indices(&self) -> &[U64<E>] {
+        &self.leaf_indices
+    }
+
+    /// Returns the siblings for the path.
+    pub fn siblings(&self) -> &[Field<E>] {
+        &self.siblings
+    }
+
+    /// Returns `true` if the Merkle multi-path is valid for the given root and leaves.
+    /// The leaves must be given in the order of the leaf indices.
+    pub fn verify<LH: LeafHash<Hash = PH::Hash>, PH: PathHash<Hash = Field<E>>>(
+        &self,
+        leaf_hasher: &LH,
+        path_hasher: &PH,
+        root: &PH::Hash,
+        leaves: &[LH::Leaf],
+    ) -> bool {
+        // Ensure there is one leaf for each leaf index.
+        if leaves.len() != self.leaf_indices.len() {
+            eprintln!("Found an incorrect number of Merkle leaves");
+            return false;
+        }
+
+        // Compute the leaf hashes.
+        let leaf_hashes = match leaf_hasher.hash_leaves(leaves) {
+            Ok(leaf_hashes) => leaf_hashes,
+            Err(error) => {
+                eprintln!("Failed to hash the Merkle leaves during verification: {error}");
+                return false;
+            }
+        };
+
+        // Compute the root, by consuming the siblings in order.
+        let mut siblings = self.siblings.iter();
+        let candidate_root = compute_multi_root::<_, DEPTH>(
+            self.leaf_indices.iter().map(|leaf_index| **leaf_index).zip_eq(leaf_hashes).collect(),
+            |_, _| siblings.next().copied().ok_or_else(|| anyhow!("Missing a Merkle multi-path sibling")),
+            |left, right| path_hasher.hash_children(left, right),
+        );
+        match candidate_root {
+            // Ensure all siblings are used, and the final hash matches the given root.
+            Ok(candidate_root) => siblings.next().is_none() && candidate_root == *root,
+            Err(error) => {
+                eprintln!("Failed to hash the Merkle multi-path during verification: {error}");
+                false
+            }
+        }
+    }
+}
+
+/// Returns the root for the given nodes at the leaf level, as `(leaf index, hash)` pairs in increasing order.
+/// The hash of each sibling that cannot be computed from the leaves is returned by `sibling(level, index)`.
+pub(super) fn compute_multi_root<T: Clone, const DEPTH: u8>(
+    mut nodes: Vec<(u64, T)>,
+    mut sibling: impl FnMut(u8, u64) -> Result<T>,
+    mut hash_children: impl FnMut(&T, &T) -> Result<T>,
+) -> Result<T> {
+    for level in 0..DEPTH {
+        let mut parents = Vec::with_capacity(nodes.len());
+        let mut nodes_iter = nodes.into_iter().peekable();
+        while let Some((index, hash)) = nodes_iter.next() {
+            let parent_hash = match index % 2 == 0 {
+                // If the right sibling is also a node, hash the two nodes together.
+                true => match nodes_iter.next_if(|(next_index, _)| *next_index == index + 1) {
+                    Some((_, right)) => hash_children(&hash, &right)?,
+                    None => hash_children(&hash, &sibling(level, index + 1)?)?,
+                },
+                false => hash_children(&sibling(level, index - 1)?, &hash)?,
+            };
+            parents.push((index >> 1, parent_hash));
+        }
+        nodes = parents;
+    }
+    match nodes.as_slice() {
+        [(0, root)] => Ok(root.clone()),
+        _ => bail!("Found an invalid set of Merkle leaf indices"),
+    }
+}
+
+impl<E: Environment, const DEPTH: u8> FromBytes for MerkleMultiPath<E, DEPTH> {
+    /// Reads in a Merkle multi-path from a buffer.
+    #[inline]
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Read the leaf indices.
+        let num_leaves = u32::read_le(&mut reader)?;
+        let leaf_indices =
+            (0..num_leaves).map(|_| Ok(U64::new(u64::read_le(&mut reader)?))).collect::<IoResult<Vec<_>>>()?;
+        // Read the Merkle multi-path siblings.
+        let num_siblings = u32::read_le(&mut reader)?;
+        let siblings = (0..num_siblings).map(|_| Field::read_le(&mut reader)).collect::<IoResult<Vec<_>>>()?;
+        // Return the Merkle multi-path.
+        Self::try_from((leaf_indices, siblings)).map_err(error)
+    }
+}
+
+impl<E: Environment, const DEPTH: u8> ToBytes for MerkleMultiPath<E, DEPTH> {
+    /// Writes the Merkle multi-path to a buffer.
+    #[inline]
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Write the leaf indices.
+        u32::try_from(self.leaf_indices.len()).map_err(error)?.write_le(&mut writer)?;
+        self.leaf_indices.iter().try_for_each(|leaf_index| leaf_index.write_le(&mut writer))?;
+        // Write the Merkle multi-path siblings.
+        u32::try_from(self.siblings.len()).map_err(error)?.write_le(&mut writer)?;
+        self.siblings.iter().try_for_each(|sibling| sibling.write_le(&mut writer))
+    }
+}
+
+impl<E: Environment, const DEPTH: u8> Serialize for MerkleMultiPath<E, DEPTH> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        ToBytesSerializer::serialize_with_size_encoding(self, serializer)
+    }
+}
+
+impl<'de, E: Environment, const DEPTH: u8> Deserialize<'de> for MerkleMultiPath<E, DEPTH> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        FromBytesDeserializer::<Self>::deserialize_with_size_encoding(deserializer, "Merkle multi-path")
+    }
+}
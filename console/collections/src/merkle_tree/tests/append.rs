@@ -465,6 +465,47 @@ fn test_merkle_tree_poseidon() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn test_append_is_consistent_with_new() -> Result<()> {
+    type LH = Poseidon<CurrentEnvironment, 4>;
+    type PH = Poseidon<CurrentEnvironment, 2>;
+    const DEPTH: u8 = 10;
+
+    let leaf_hasher = LH::setup("AleoMerkleTreeTest0")?;
+    let path_hasher = PH::setup("AleoMerkleTreeTest1")?;
+    let mut rng = TestRng::default();
+
+    for num_leaves in 0..18 {
+        for num_additional_leaves in 0..10 {
+            let leaves =
+                (0..num_leaves + num_additional_leaves).map(|_| vec![Uniform::rand(&mut rng)]).collect::<Vec<_>>();
+            let (leaves, additional_leaves) = leaves.split_at(num_leaves);
+
+            // Append the additional leaves, both in place (where possible) and by rebuilding the Merkle tree.
+            let mut merkle_tree =
+                MerkleTree::<CurrentEnvironment, LH, PH, DEPTH>::new(&leaf_hasher, &path_hasher, leaves)?;
+            let prepared_tree = merkle_tree.prepare_append(additional_leaves)?;
+            merkle_tree.append(additional_leaves)?;
+
+            // Ensure both are consistent with the Merkle tree constructed from all of the leaves.
+            let expected_tree = MerkleTree::<CurrentEnvironment, LH, PH, DEPTH>::new(
+                &leaf_hasher,
+                &path_hasher,
+                &[leaves, additional_leaves].concat(),
+            )?;
+            assert_eq!(merkle_tree.root(), expected_tree.root());
+            assert_eq!(merkle_tree.number_of_leaves(), expected_tree.number_of_leaves());
+            assert_eq!(prepared_tree.root(), expected_tree.root());
+            // Ensure the Merkle proof for every leaf is valid, as the inner nodes are updated in place.
+            for (leaf_index, leaf) in leaves.iter().chain(additional_leaves).enumerate() {
+                let proof = merkle_tree.prove(leaf_index, leaf)?;
+                assert!(proof.verify(&leaf_hasher, &path_hasher, expected_tree.root(), leaf));
+            }
+        }
+    }
+    Ok(())
+}
+
 #[test]
 fn test_merkle_tree_depth_2_bhp() -> Result<()> {
     type LH = BHP1024<CurrentEnvironment>;
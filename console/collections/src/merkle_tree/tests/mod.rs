@@ -16,6 +16,7 @@
 use super::*;
 
 mod append;
+mod multi_path;
 mod remove;
 mod update;
 mod update_many;
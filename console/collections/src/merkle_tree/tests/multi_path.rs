@@ -0,0 +1,129 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use snarkvm_console_algorithms::{Poseidon, BHP1024, BHP512};
+use snarkvm_console_types::prelude::Console;
+
+type CurrentEnvironment = Console;
+
+/// Runs the following test:
+/// 1. Construct the Merkle tree for the leaves.
+/// 2. Check that the Merkle multi-path for the selected leaves is valid, and matches the individual paths.
+/// 3. Check that the Merkle multi-path is invalid for a different root or different leaves.
+fn check_merkle_multi_path<
+    E: Environment,
+    LH: LeafHash<Hash = PH::Hash>,
+    PH: PathHash<Hash = Field<E>>,
+    const DEPTH: u8,
+>(
+    leaf_hasher: &LH,
+    path_hasher: &PH,
+    leaves: &[LH::Leaf],
+    leaf_indices: &[usize],
+) -> Result<()> {
+    // Construct the Merkle tree for the given leaves.
+    let merkle_tree = MerkleTree::<E, LH, PH, DEPTH>::new(leaf_hasher, path_hasher, leaves)?;
+    let root = merkle_tree.root();
+
+    // Compute the Merkle multi-path for the selected leaves.
+    let selected = leaf_indices.iter().map(|index| (*index, leaves[*index].clone())).collect::<BTreeMap<_, _>>();
+    let path = merkle_tree.prove_many(&selected)?;
+    let selected_leaves = selected.values().cloned().collect::<Vec<_>>();
+    // Verify the Merkle multi-path succeeds.
+    assert!(merkle_tree.verify_many(&path, root, &selected_leaves));
+    assert!(path.verify(leaf_hasher, path_hasher, root, &selected_leaves));
+    // Ensure the multi-path is no larger than the individual paths.
+    assert!(path.siblings().len() <= selected.len() * DEPTH as usize);
+    // Ensure each individual path still verifies against the same root.
+    for (leaf_index, leaf) in &selected {
+        assert!(merkle_tree.verify(&merkle_tree.prove(*leaf_index, leaf)?, root, leaf));
+    }
+
+    // Verify the Merkle multi-path **fails** on an invalid root.
+    assert!(!path.verify(leaf_hasher, path_hasher, &PH::Hash::zero(), &selected_leaves));
+    assert!(!path.verify(leaf_hasher, path_hasher, &PH::Hash::one(), &selected_leaves));
+    // Verify the Merkle multi-path **fails** on missing or reordered leaves.
+    assert!(!path.verify(leaf_hasher, path_hasher, root, &selected_leaves[1..]));
+    if selected_leaves.len() > 1 && selected_leaves[0] != selected_leaves[1] {
+        let mut reordered_leaves = selected_leaves.clone();
+        reordered_leaves.swap(0, 1);
+        assert!(!path.verify(leaf_hasher, path_hasher, root, &reordered_leaves));
+    }
+
+    // Ensure the Merkle multi-path round-trips through bytes.
+    let bytes = path.to_bytes_le()?;
+    assert_eq!(path, MerkleMultiPath::read_le(&bytes[..])?);
+    Ok(())
+}
+
+#[test]
+fn test_merkle_multi_path_bhp() -> Result<()> {
+    let rng = &mut TestRng::default();
+
+    let leaf_hasher = BHP1024::<CurrentEnvironment>::setup("AleoMerkleTreeTest0")?;
+    let path_hasher = BHP512::<CurrentEnvironment>::setup("AleoMerkleTreeTest1")?;
+
+    for num_leaves in [1, 2, 3, 7, 8, 13, 32] {
+        let leaves = (0..num_leaves).map(|_| Field::<CurrentEnvironment>::rand(rng).to_bits_le()).collect::<Vec<_>>();
+        // Check a single leaf, adjacent leaves, sparse leaves, and all leaves.
+        let all_indices = (0..num_leaves).collect::<Vec<_>>();
+        let sparse_indices = (0..num_leaves).step_by(3).collect::<Vec<_>>();
+        let adjacent_indices = (num_leaves / 2..(num_leaves / 2 + 2).min(num_leaves)).collect::<Vec<_>>();
+        for leaf_indices in [vec![num_leaves - 1], adjacent_indices, sparse_indices, all_indices] {
+            check_merkle_multi_path::<_, _, _, 10>(&leaf_hasher, &path_hasher, &leaves, &leaf_indices)?;
+        }
+    }
+    Ok(())
+}
+
+#[test]
+fn test_merkle_multi_path_poseidon() -> Result<()> {
+    let rng = &mut TestRng::default();
+
+    let leaf_hasher = Poseidon::<CurrentEnvironment, 4>::setup("AleoMerkleTreeTest0")?;
+    let path_hasher = Poseidon::<CurrentEnvironment, 2>::setup("AleoMerkleTreeTest1")?;
+
+    for num_leaves in [1, 5, 16, 21] {
+        let leaves = (0..num_leaves).map(|_| vec![Field::<CurrentEnvironment>::rand(rng)]).collect::<Vec<_>>();
+        let sparse_indices = (0..num_leaves).step_by(4).collect::<Vec<_>>();
+        check_merkle_multi_path::<_, _, _, 32>(&leaf_hasher, &path_hasher, &leaves, &sparse_indices)?;
+    }
+    Ok(())
+}
+
+#[test]
+fn test_merkle_multi_path_is_compact() -> Result<()> {
+    let rng = &mut TestRng::default();
+
+    let leaf_hasher = BHP1024::<CurrentEnvironment>::setup("AleoMerkleTreeTest0")?;
+    let path_hasher = BHP512::<CurrentEnvironment>::setup("AleoMerkleTreeTest1")?;
+
+    // Construct a Merkle tree with 8 leaves, at depth 10.
+    let leaves = (0..8).map(|_| Field::<CurrentEnvironment>::rand(rng).to_bits_le()).collect::<Vec<_>>();
+    let merkle_tree = MerkleTree::<_, _, _, 10>::new(&leaf_hasher, &path_hasher, &leaves)?;
+    // A multi-path for all leaves only needs the padding siblings above the stored tree.
+    let all = leaves.iter().cloned().enumerate().collect::<BTreeMap<_, _>>();
+    assert_eq!(merkle_tree.prove_many(&all)?.siblings().len(), 10 - 3);
+    // A multi-path for two sibling leaves shares all siblings above the leaf level.
+    let pair = leaves.iter().cloned().enumerate().take(2).collect::<BTreeMap<_, _>>();
+    assert_eq!(merkle_tree.prove_many(&pair)?.siblings().len(), 10 - 1);
+
+    // Ensure a multi-path cannot be constructed for no leaves, or for a mismatched leaf.
+    assert!(merkle_tree.prove_many(&BTreeMap::new()).is_err());
+    assert!(merkle_tree.prove_many(&BTreeMap::from([(0, leaves[1].clone())])).is_err());
+    assert!(merkle_tree.prove_many(&BTreeMap::from([(8, leaves[0].clone())])).is_err());
+    Ok(())
+}
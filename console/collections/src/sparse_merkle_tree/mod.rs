@@ -0,0 +1,280 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod path;
+pub use path::*;
+
+use crate::merkle_tree::PathHash;
+use snarkvm_console_types::prelude::*;
+
+use std::collections::{BTreeMap, HashMap};
+
+/// A sparse Merkle tree of depth `DEPTH`, which maps keys to value hashes.
+///
+/// The position of a key in the tree is given by the first `DEPTH` bits of the key. As keys are expected to be
+/// hashes, the keys at a position are stored together in a single leaf, so any set of keys can be represented.
+/// Only the non-empty nodes are stored, and each update recomputes only the nodes along the path of its leaf.
+#[derive(Clone)]
+pub struct SparseMerkleTree<E: Environment, PH: PathHash<Hash = Field<E>>, const DEPTH: u8> {
+    /// The path hasher for the tree.
+    path_hasher: PH,
+    /// The hashes of the empty subtrees, indexed by height (i.e. the empty leaf hash is at height 0).
+    empty_hashes: Vec<Field<E>>,
+    /// The entries of the non-empty leaves, indexed by position.
+    leaves: HashMap<u64, BTreeMap<Field<E>, Field<E>>>,
+    /// The hashes of the non-empty nodes, indexed by height and the position of the node in its level.
+    nodes: HashMap<(u8, u64), Field<E>>,
+    /// The number of entries in the tree.
+    number_of_entries: usize,
+}
+
+impl<E: Environment, PH: PathHash<Hash = Field<E>>, const DEPTH: u8> SparseMerkleTree<E, PH, DEPTH> {
+    /// Initializes a new, empty sparse Merkle tree.
+    pub fn new(path_hasher: &PH) -> Result<Self> {
+        // Ensure the Merkle tree depth is greater than 0.
+        ensure!(DEPTH > 0, "Merkle tree depth must be greater than 0");
+        // Ensure the Merkle tree depth is less than or equal to 64.
+        ensure!(DEPTH <= 64u8, "Merkle tree depth must be less than or equal to 64");
+
+        // Compute the hashes of the empty subtrees, from the empty leaf up to the root.
+        let mut empty_hashes = Vec::with_capacity(DEPTH as usize + 1);
+        empty_hashes.push(path_hasher.hash_empty()?);
+        for height in 0..DEPTH as usize {
+            empty_hashes.push(path_hasher.hash_children(&empty_hashes[height], &empty_hashes[height])?);
+        }
+
+        Ok(Self {
+            path_hasher: path_hasher.clone(),
+            empty_hashes,
+            leaves: Default::default(),
+            nodes: Default::default(),
+            number_of_entries: 0,
+        })
+    }
+
+    /// Returns the root of the tree.
+    pub fn root(&self) -> Field<E> {
+        self.node(DEPTH, 0)
+    }
+
+    /// Returns the number of entries in the tree.
+    pub const fn number_of_entries(&self) -> usize {
+        self.number_of_entries
+    }
+
+    /// Returns the value hash for the given key, if it exists.
+    pub fn get(&self, key: &Field<E>) -> Option<&Field<E>> {
+        self.leaves.get(&position::<E, DEPTH>(key)).and_then(|entries| entries.get(key))
+    }
+
+    /// Inserts the given key with the given value hash, overwriting the existing value hash if the key exists.
+    pub fn insert(&mut self, key: Field<E>, value_hash: Field<E>) -> Result<()> {
+        let position = position::<E, DEPTH>(&key);
+        let mut entries = self.leaves.get(&position).cloned().unwrap_or_default();
+        let is_new = entries.insert(key, value_hash).is_none();
+        // Update the leaf, and the nodes along its path.
+        self.update_leaf(position, entries)?;
+        self.number_of_entries += is_new as usize;
+        Ok(())
+    }
+
+    /// Removes the given key, returning `true` if the key existed.
+    pub fn remove(&mut self, key: &Field<E>) -> Result<bool> {
+        let position = position::<E, DEPTH>(key);
+        let mut entries = match self.leaves.get(&position) {
+            Some(entries) if entries.contains_key(key) => entries.clone(),
+            _ => return Ok(false),
+        };
+        entries.remove(key);
+        // Update the leaf, and the nodes along its path.
+        self.update_leaf(position, entries)?;
+        self.number_of_entries -= 1;
+        Ok(true)
+    }
+
+    /// Returns the sparse Merkle path for the given key, which proves either the inclusion or the exclusion of the key.
+    pub fn prove(&self, key: &Field<E>) -> Result<SparseMerklePath<E, DEPTH>> {
+        let position = position::<E, DEPTH>(key);
+        // Retrieve the entries of the leaf.
+        let entries = match self.leaves.get(&position) {
+            Some(entries) => entries.iter().map(|(key, value_hash)| (*key, *value_hash)).collect(),
+            None => Vec::new(),
+        };
+        // Retrieve the siblings, from the leaf to the root.
+        let siblings = (0..DEPTH).map(|height| self.node(height, shift(position, height) ^ 1)).collect();
+        SparseMerklePath::try_from((entries, siblings))
+    }
+
+    /// Returns `true` if the sparse Merkle path is valid for the given root, key, and value hash.
+    /// If the value hash is `None`, the path must prove that the key does not exist.
+    pub fn verify(
+        &self,
+        path: &SparseMerklePath<E, DEPTH>,
+        root: &Field<E>,
+        key: &Field<E>,
+        value_hash: Option<&Field<E>>,
+    ) -> bool {
+        path.verify(&self.path_hasher, root, key, value_hash)
+    }
+}
+
+impl<E: Environment, PH: PathHash<Hash = Field<E>>, const DEPTH: u8> SparseMerkleTree<E, PH, DEPTH> {
+    /// Returns the hash of the node at the given height and position in its level.
+    fn node(&self, height: u8, index: u64) -> Field<E> {
+        self.nodes.get(&(height, index)).copied().unwrap_or(self.empty_hashes[height as usize])
+    }
+
+    /// Replaces the entries of the leaf at the given position, and recomputes the nodes along its path.
+    fn update_leaf(&mut self, position: u64, entries: BTreeMap<Field<E>, Field<E>>) -> Result<()> {
+        // Compute the new hashes along the path, from the leaf to the root.
+        // Note: The tree is updated at the very end, so the original tree is not altered in case of failure.
+        let mut path_hashes = Vec::with_capacity(DEPTH as usize + 1);
+        path_hashes.push(leaf_hash(&self.path_hasher, self.empty_hashes[0], entries.iter())?);
+        for height in 0..DEPTH {
+            let index = shift(position, height);
+            // Note: This unwrap is safe, as the path hashes vector is guaranteed to have at least one element.
+            let current = path_hashes.last().unwrap();
+            let sibling = self.node(height, index ^ 1);
+            let parent = match index & 1 == 0 {
+                true => self.path_hasher.hash_children(current, &sibling)?,
+                false => self.path_hasher.hash_children(&sibling, current)?,
+            };
+            path_hashes.push(parent);
+        }
+
+        // Update the leaf entries.
+        match entries.is_empty() {
+            true => self.leaves.remove(&position),
+            false => self.leaves.insert(position, entries),
+        };
+        // Update the nodes along the path, storing only the non-empty nodes.
+        for (height, hash) in (0..=DEPTH).zip_eq(path_hashes) {
+            let index = shift(position, height);
+            match hash == self.empty_hashes[height as usize] {
+                true => self.nodes.remove(&(height, index)),
+                false => self.nodes.insert((height, index), hash),
+            };
+        }
+        Ok(())
+    }
+}
+
+/// Returns the position of the given key in a sparse Merkle tree of depth `DEPTH`.
+fn position<E: Environment, const DEPTH: u8>(key: &Field<E>) -> u64 {
+    key.to_bits_le().iter().take(DEPTH as usize).rev().fold(0u64, |position, bit| (position << 1) | *bit as u64)
+}
+
+/// Returns the position of the ancestor at the given height, in its level.
+const fn shift(position: u64, height: u8) -> u64 {
+    match position.checked_shr(height as u32) {
+        Some(index) => index,
+        None => 0,
+    }
+}
+
+/// Returns the hash of a leaf with the given entries, ordered by key.
+fn leaf_hash<'a, E: Environment, PH: PathHash<Hash = Field<E>>>(
+    path_hasher: &PH,
+    empty_hash: Field<E>,
+    entries: impl Iterator<Item = (&'a Field<E>, &'a Field<E>)>,
+) -> Result<Field<E>> {
+    // Compute the leaf hash as `H( ... H( H(empty, H(k_0, v_0)), H(k_1, v_1) ) ... )`.
+    entries.try_fold(empty_hash, |hash, (key, value_hash)| {
+        path_hasher.hash_children(&hash, &path_hasher.hash_children(key, value_hash)?)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_algorithms::Poseidon2;
+    use snarkvm_console_types::prelude::Console;
+
+    type CurrentEnvironment = Console;
+    type PH = Poseidon2<CurrentEnvironment>;
+
+    const ITERATIONS: usize = 32;
+
+    #[test]
+    fn test_insert_remove_prove() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        let path_hasher = PH::setup("AleoSparseMerkleTreeTest")?;
+        let mut tree = SparseMerkleTree::<CurrentEnvironment, PH, 32>::new(&path_hasher)?;
+        let empty_root = tree.root();
+
+        // Insert the entries, checking the proofs of inclusion.
+        let entries = (0..ITERATIONS).map(|_| (Field::rand(rng), Field::rand(rng))).collect::<Vec<_>>();
+        for (key, value_hash) in &entries {
+            tree.insert(*key, *value_hash)?;
+            let path = tree.prove(key)?;
+            assert!(tree.verify(&path, &tree.root(), key, Some(value_hash)));
+            assert!(!tree.verify(&path, &tree.root(), key, None));
+            assert!(!tree.verify(&path, &tree.root(), key, Some(&Field::rand(rng))));
+            assert!(!tree.verify(&path, &Field::rand(rng), key, Some(value_hash)));
+        }
+        assert_eq!(tree.number_of_entries(), ITERATIONS);
+
+        // Ensure the proofs of exclusion are valid for keys that do not exist.
+        let missing_key = Field::rand(rng);
+        let path = tree.prove(&missing_key)?;
+        assert!(tree.verify(&path, &tree.root(), &missing_key, None));
+        assert!(!tree.verify(&path, &tree.root(), &missing_key, Some(&Field::rand(rng))));
+
+        // Ensure the root is independent of the order of insertion, and that overwriting a key updates the root.
+        let mut reordered_tree = SparseMerkleTree::<CurrentEnvironment, PH, 32>::new(&path_hasher)?;
+        for (key, value_hash) in entries.iter().rev() {
+            reordered_tree.insert(*key, Field::rand(rng))?;
+            reordered_tree.insert(*key, *value_hash)?;
+        }
+        assert_eq!(reordered_tree.root(), tree.root());
+        assert_eq!(reordered_tree.number_of_entries(), ITERATIONS);
+
+        // Remove the entries, checking the proofs of exclusion.
+        for (key, value_hash) in &entries {
+            assert!(tree.remove(key)?);
+            assert!(!tree.remove(key)?);
+            let path = tree.prove(key)?;
+            assert!(tree.verify(&path, &tree.root(), key, None));
+            assert!(!tree.verify(&path, &tree.root(), key, Some(value_hash)));
+        }
+        assert_eq!(tree.number_of_entries(), 0);
+        assert_eq!(tree.root(), empty_root);
+        assert!(tree.nodes.is_empty());
+        Ok(())
+    }
+
+    #[test]
+    fn test_colliding_positions() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        let path_hasher = PH::setup("AleoSparseMerkleTreeTest")?;
+        let mut tree = SparseMerkleTree::<CurrentEnvironment, PH, 1>::new(&path_hasher)?;
+
+        // Insert more entries than positions, so that the leaves hold several entries.
+        let entries = (0..ITERATIONS).map(|_| (Field::rand(rng), Field::rand(rng))).collect::<Vec<_>>();
+        for (key, value_hash) in &entries {
+            tree.insert(*key, *value_hash)?;
+        }
+        for (key, value_hash) in &entries {
+            assert_eq!(tree.get(key), Some(value_hash));
+            let path = tree.prove(key)?;
+            assert!(tree.verify(&path, &tree.root(), key, Some(value_hash)));
+            // Ensure the path round-trips through its byte representation.
+            assert_eq!(SparseMerklePath::read_le(&path.to_bytes_le()?[..])?, path);
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,167 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+/// A path in a sparse Merkle tree, which proves either the inclusion or the exclusion of a key.
+///
+/// The path contains the entries of the leaf at the position of the key, and the siblings from the leaf to the root.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct SparseMerklePath<E: Environment, const DEPTH: u8> {
+    /// The entries of the leaf, as `(key, value hash)` pairs ordered by key.
+    entries: Vec<(Field<E>, Field<E>)>,
+    /// The `siblings` contains a list of sibling hashes from the leaf to the root.
+    siblings: Vec<Field<E>>,
+}
+
+impl<E: Environment, const DEPTH: u8> TryFrom<(Vec<(Field<E>, Field<E>)>, Vec<Field<E>>)>
+    for SparseMerklePath<E, DEPTH>
+{
+    type Error = Error;
+
+    /// Returns a new instance of a sparse Merkle path.
+    fn try_from((entries, siblings): (Vec<(Field<E>, Field<E>)>, Vec<Field<E>>)) -> Result<Self> {
+        // Ensure the Merkle tree depth is greater than 0.
+        ensure!(DEPTH > 0, "Merkle tree depth must be greater than 0");
+        // Ensure the Merkle tree depth is less than or equal to 64.
+        ensure!(DEPTH <= 64u8, "Merkle tree depth must be less than or equal to 64");
+        // Ensure the entries are ordered by key, without duplicates.
+        ensure!(entries.windows(2).all(|pair| pair[0].0 < pair[1].0), "Found unordered sparse Merkle leaf entries");
+        // Ensure the Merkle path is the correct length.
+        ensure!(siblings.len() == DEPTH as usize, "Found an incorrect Merkle path length");
+        // Return the sparse Merkle path.
+        Ok(Self { entries, siblings })
+    }
+}
+
+impl<E: Environment, const DEPTH: u8> SparseMerklePath<E, DEPTH> {
+    /// Returns the entries of the leaf, as `(key, value hash)` pairs ordered by key.
+    pub fn entries(&self) -> &[(Field<E>, Field<E>)] {
+        &self.entries
+    }
+
+    /// Returns the siblings for the path.
+    pub fn siblings(&self) -> &[Field<E>] {
+        &self.siblings
+    }
+
+    /// Returns `true` if the sparse Merkle path is valid for the given root, key, and value hash.
+    /// If the value hash is `None`, the path must prove that the key does not exist.
+    pub fn verify<PH: PathHash<Hash = Field<E>>>(
+        &self,
+        path_hasher: &PH,
+        root: &Field<E>,
+        key: &Field<E>,
+        value_hash: Option<&Field<E>>,
+    ) -> bool {
+        // Ensure the path length matches the expected depth.
+        if self.siblings.len() != DEPTH as usize {
+            eprintln!("Found an incorrect Merkle path length");
+            return false;
+        }
+
+        // Ensure the entries are ordered by key, and are located at the position of the key.
+        let position = position::<E, DEPTH>(key);
+        if !self.entries.windows(2).all(|pair| pair[0].0 < pair[1].0)
+            || self.entries.iter().any(|(entry_key, _)| position::<E, DEPTH>(entry_key) != position)
+        {
+            eprintln!("Found invalid sparse Merkle leaf entries");
+            return false;
+        }
+        // Ensure the entry of the key matches the given value hash.
+        if self.entries.iter().find(|(entry_key, _)| entry_key == key).map(|(_, entry_value)| entry_value) != value_hash
+        {
+            return false;
+        }
+
+        // Initialize a tracker for the current hash, by computing the leaf hash to start.
+        let empty_hash = match path_hasher.hash_empty() {
+            Ok(empty_hash) => empty_hash,
+            Err(error) => {
+                eprintln!("Failed to hash the empty Merkle leaf during verification: {error}");
+                return false;
+            }
+        };
+        let mut current_hash = match leaf_hash(path_hasher, empty_hash, self.entries.iter().map(|(k, v)| (k, v))) {
+            Ok(candidate_leaf_hash) => candidate_leaf_hash,
+            Err(error) => {
+                eprintln!("Failed to hash the sparse Merkle leaf during verification: {error}");
+                return false;
+            }
+        };
+
+        // Compute the ordering of the current hash and sibling hash on each level.
+        for (height, sibling) in (0..DEPTH).zip_eq(&self.siblings) {
+            // Compute the next hash for the path.
+            let candidate_hash = match shift(position, height) & 1 == 0 {
+                true => path_hasher.hash_children(&current_hash, sibling),
+                false => path_hasher.hash_children(sibling, &current_hash),
+            };
+            match candidate_hash {
+                Ok(candidate_hash) => current_hash = candidate_hash,
+                Err(error) => {
+                    eprintln!("Failed to hash the sparse Merkle path during verification: {error}");
+                    return false;
+                }
+            }
+        }
+
+        // Ensure the final hash matches the given root.
+        current_hash == *root
+    }
+}
+
+impl<E: Environment, const DEPTH: u8> FromBytes for SparseMerklePath<E, DEPTH> {
+    /// Reads in a sparse Merkle path from a buffer.
+    #[inline]
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Read the leaf entries.
+        let num_entries = u16::read_le(&mut reader)?;
+        let entries = (0..num_entries)
+            .map(|_| Ok((Field::read_le(&mut reader)?, Field::read_le(&mut reader)?)))
+            .collect::<IoResult<Vec<_>>>()?;
+        // Read the sparse Merkle path siblings.
+        let siblings = (0..DEPTH).map(|_| Field::read_le(&mut reader)).collect::<IoResult<Vec<_>>>()?;
+        // Return the sparse Merkle path.
+        Self::try_from((entries, siblings)).map_err(error)
+    }
+}
+
+impl<E: Environment, const DEPTH: u8> ToBytes for SparseMerklePath<E, DEPTH> {
+    /// Writes the sparse Merkle path to a buffer.
+    #[inline]
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Write the leaf entries.
+        u16::try_from(self.entries.len()).map_err(error)?.write_le(&mut writer)?;
+        for (key, value_hash) in &self.entries {
+            key.write_le(&mut writer)?;
+            value_hash.write_le(&mut writer)?;
+        }
+        // Write the sparse Merkle path siblings.
+        self.siblings.iter().try_for_each(|sibling| sibling.write_le(&mut writer))
+    }
+}
+
+impl<E: Environment, const DEPTH: u8> Serialize for SparseMerklePath<E, DEPTH> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        ToBytesSerializer::serialize_with_size_encoding(self, serializer)
+    }
+}
+
+impl<'de, E: Environment, const DEPTH: u8> Deserialize<'de> for SparseMerklePath<E, DEPTH> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        FromBytesDeserializer::<Self>::deserialize_with_size_encoding(deserializer, "sparse Merkle path")
+    }
+}
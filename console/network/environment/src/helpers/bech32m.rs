@@ -0,0 +1,185 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use anyhow::{bail, ensure, Result};
+use bech32::{self, FromBase32, ToBase32};
+
+/// A registered human-readable prefix (HRP) for bech32m-encoded objects.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Bech32mPrefix {
+    /// The human-readable prefix.
+    hrp: &'static str,
+    /// The name of the object, for error messages.
+    name: &'static str,
+    /// The maximum length of the encoded string, if the object has a bounded size.
+    max_length: Option<usize>,
+}
+
+impl Bech32mPrefix {
+    /// Returns the registered prefix for the given HRP, if it exists.
+    pub fn find(hrp: &str) -> Option<&'static Self> {
+        BECH32M_PREFIXES.iter().find(|prefix| prefix.hrp == hrp)
+    }
+
+    /// Returns the human-readable prefix.
+    pub const fn hrp(&self) -> &'static str {
+        self.hrp
+    }
+
+    /// Returns the name of the object.
+    pub const fn name(&self) -> &'static str {
+        self.name
+    }
+
+    /// Returns the maximum length of the encoded string, if the object has a bounded size.
+    pub const fn max_length(&self) -> Option<usize> {
+        self.max_length
+    }
+}
+
+/// The registry of human-readable prefixes for bech32m-encoded objects.
+/// Note: The prefixes of IDs and objects that are parameterized by a const prefix are not registered.
+pub const BECH32M_PREFIXES: &[Bech32mPrefix] = &[
+    Bech32mPrefix { hrp: "aleo", name: "address", max_length: Some(63) },
+    Bech32mPrefix { hrp: "certificate", name: "certificate", max_length: None },
+    Bech32mPrefix { hrp: "ciphertext", name: "ciphertext", max_length: None },
+    Bech32mPrefix { hrp: "data", name: "data", max_length: None },
+    Bech32mPrefix { hrp: "path", name: "state path", max_length: None },
+    Bech32mPrefix { hrp: "proof", name: "proof", max_length: None },
+    Bech32mPrefix { hrp: "prover", name: "proving key", max_length: None },
+    Bech32mPrefix { hrp: "record", name: "record ciphertext", max_length: None },
+    Bech32mPrefix { hrp: "sign", name: "signature", max_length: Some(216) },
+    Bech32mPrefix { hrp: "solution", name: "solution ID", max_length: None },
+    Bech32mPrefix { hrp: "verifier", name: "verifying key", max_length: None },
+];
+
+/// Encodes the given bytes into a bech32m string with the given HRP.
+pub fn encode_bech32m(hrp: &str, bytes: &[u8]) -> Result<String> {
+    Ok(bech32::encode(hrp, bytes.to_base32(), bech32::Variant::Bech32m)?)
+}
+
+/// Decodes the given bech32m string with the expected HRP into bytes.
+///
+/// This enforces the maximum length of the registered prefix, if any,
+/// and accepts a string in either all-lowercase or all-uppercase.
+pub fn decode_bech32m(string: &str, expected_hrp: &str) -> Result<Vec<u8>> {
+    let name = Bech32mPrefix::find(expected_hrp).map_or("bech32m object", |prefix| prefix.name);
+    // Ensure the string does not exceed the maximum length.
+    if let Some(max_length) = Bech32mPrefix::find(expected_hrp).and_then(|prefix| prefix.max_length) {
+        ensure!(
+            string.len() <= max_length,
+            "Failed to decode {name}: found {} characters, expected at most {max_length}",
+            string.len()
+        );
+    }
+    // Decode the string.
+    let (hrp, data, variant) = bech32::decode(string)?;
+    if hrp != expected_hrp {
+        bail!("Failed to decode {name}: '{hrp}' is an invalid prefix")
+    } else if data.is_empty() {
+        bail!("Failed to decode {name}: data field is empty")
+    } else if variant != bech32::Variant::Bech32m {
+        bail!("Found a {name} that is not bech32m encoded: {string}")
+    }
+    // Decode the data from u5 to u8.
+    Ok(Vec::from_base32(&data)?)
+}
+
+/// Decodes the given bech32m string with the expected HRP into bytes, in strict mode.
+///
+/// In addition to the checks of `decode_bech32m`, this ensures the string is in canonical form,
+/// i.e. all-lowercase, and with zero padding bits, such that re-encoding yields the same string.
+pub fn decode_bech32m_strict(string: &str, expected_hrp: &str) -> Result<Vec<u8>> {
+    // Ensure the string is lowercase.
+    ensure!(!string.chars().any(|c| c.is_ascii_uppercase()), "Found a bech32m string that is not lowercase");
+    // Decode the string.
+    let bytes = decode_bech32m(string, expected_hrp)?;
+    // Ensure the string is canonical.
+    ensure!(encode_bech32m(expected_hrp, &bytes)? == string, "Found a bech32m string that is not canonical");
+    Ok(bytes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_utilities::TestRng;
+
+    use rand::Rng;
+
+    const ITERATIONS: usize = 1000;
+
+    #[test]
+    fn test_registry() {
+        // Ensure the prefixes are unique and sorted.
+        for window in BECH32M_PREFIXES.windows(2) {
+            assert!(window[0].hrp < window[1].hrp);
+        }
+        assert_eq!(Bech32mPrefix::find("aleo").unwrap().name(), "address");
+        assert!(Bech32mPrefix::find("unknown").is_none());
+    }
+
+    #[test]
+    fn test_round_trip() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            let bytes = (0..rng.gen_range(1..128)).map(|_| rng.gen()).collect::<Vec<u8>>();
+            let string = encode_bech32m("data", &bytes)?;
+            assert_eq!(decode_bech32m(&string, "data")?, bytes);
+            assert_eq!(decode_bech32m_strict(&string, "data")?, bytes);
+            // Ensure the uppercase form is only accepted in lenient mode.
+            assert_eq!(decode_bech32m(&string.to_uppercase(), "data")?, bytes);
+            assert!(decode_bech32m_strict(&string.to_uppercase(), "data").is_err());
+            // Ensure the wrong prefix is rejected.
+            assert!(decode_bech32m(&string, "proof").is_err());
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_fuzz_decode() {
+        let mut rng = TestRng::default();
+        let charset = b"qpzry9x8gf2tvdw0s3jn54khce6mua7lQPZRY9X8GF2TVDW0S3JN54KHCE6MUA7L1";
+
+        for _ in 0..ITERATIONS {
+            // Mutate a single character of a valid string, and ensure decoding does not panic or succeed.
+            let bytes = (0..rng.gen_range(1..48)).map(|_| rng.gen()).collect::<Vec<u8>>();
+            let mut string = encode_bech32m("data", &bytes).unwrap().into_bytes();
+            let index = rng.gen_range(5..string.len());
+            let replacement = charset[rng.gen_range(0..charset.len())];
+            if string[index].to_ascii_lowercase() == replacement.to_ascii_lowercase() {
+                continue;
+            }
+            string[index] = replacement;
+            let string = String::from_utf8(string).unwrap();
+            assert!(decode_bech32m(&string, "data").is_err());
+            assert!(decode_bech32m_strict(&string, "data").is_err());
+
+            // Ensure arbitrary strings do not panic.
+            let length = rng.gen_range(0..128);
+            let string = (0..length).map(|_| charset[rng.gen_range(0..charset.len())] as char).collect::<String>();
+            let _ = decode_bech32m(&string, "data");
+            let _ = decode_bech32m_strict(&string, "data");
+        }
+    }
+
+    #[test]
+    fn test_max_length() {
+        // Ensure an oversized address is rejected before decoding.
+        let string = encode_bech32m("aleo", &[0u8; 33]).unwrap();
+        assert!(decode_bech32m(&string, "aleo").is_err());
+        assert!(decode_bech32m(&encode_bech32m("aleo", &[0u8; 32]).unwrap(), "aleo").is_ok());
+    }
+}
@@ -13,9 +13,15 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+pub mod bech32m;
+pub use bech32m::{decode_bech32m, decode_bech32m_strict, encode_bech32m, Bech32mPrefix, BECH32M_PREFIXES};
+
 mod or_halt;
 pub use or_halt::OrHalt;
 
+mod parse_error;
+pub use parse_error::{parse_str, ParseError};
+
 mod sanitizer;
 pub use sanitizer::Sanitizer;
 
@@ -0,0 +1,205 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{helpers::Bech32mPrefix, ParserResult};
+
+use core::fmt;
+use nom::Err as NomErr;
+
+/// The maximum number of characters of the offending input to include in a parse error.
+const MAX_FOUND_LENGTH: usize = 32;
+
+/// A parse error, reporting the expected format, the offending position in the input,
+/// and, if the input appears to be bech32-encoded with a prefix of a different type, a hint to that type.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseError {
+    /// The expected format, e.g. `address`.
+    expected: &'static str,
+    /// The position (in bytes) of the offending input.
+    position: usize,
+    /// The offending input, truncated.
+    found: String,
+    /// The reason for the error, if known.
+    reason: Option<String>,
+    /// The hint for the error, if any.
+    hint: Option<String>,
+}
+
+impl ParseError {
+    /// Initializes a new parse error for the given input, at the given position.
+    pub fn new(expected: &'static str, input: &str, position: usize) -> Self {
+        // Clamp the position to a character boundary within the input.
+        let mut position = position.min(input.len());
+        while !input.is_char_boundary(position) {
+            position -= 1;
+        }
+        let found = input[position..].chars().take(MAX_FOUND_LENGTH).collect();
+        Self { expected, position, found, reason: None, hint: Self::bech32_hint(expected, input) }
+    }
+
+    /// Initializes a new parse error for the given input, from the given unparsed remainder.
+    pub fn from_remainder(expected: &'static str, input: &str, remainder: &str) -> Self {
+        Self::new(expected, input, input.len().saturating_sub(remainder.len()))
+    }
+
+    /// Initializes a new parse error for the given input, from the given parser error.
+    pub fn from_parser_error(
+        expected: &'static str,
+        input: &str,
+        error: NomErr<nom::error::VerboseError<&str>>,
+    ) -> Self {
+        match error {
+            NomErr::Incomplete(_) => Self::new(expected, input, input.len()),
+            NomErr::Error(error) | NomErr::Failure(error) => {
+                // Use the deepest position reached by the parser.
+                let remainder =
+                    error.errors.iter().map(|(remainder, _)| *remainder).min_by_key(|remainder| remainder.len());
+                match remainder {
+                    Some(remainder) => Self::from_remainder(expected, input, remainder),
+                    None => Self::new(expected, input, 0),
+                }
+            }
+        }
+    }
+
+    /// Returns the parse error with the given reason.
+    pub fn with_reason(mut self, reason: impl fmt::Display) -> Self {
+        self.reason = Some(reason.to_string());
+        self
+    }
+
+    /// Returns the expected format.
+    pub const fn expected(&self) -> &'static str {
+        self.expected
+    }
+
+    /// Returns the position (in bytes) of the offending input.
+    pub const fn position(&self) -> usize {
+        self.position
+    }
+
+    /// Returns the offending input, truncated.
+    pub fn found(&self) -> &str {
+        &self.found
+    }
+
+    /// Returns the reason for the error, if known.
+    pub fn reason(&self) -> Option<&str> {
+        self.reason.as_deref()
+    }
+
+    /// Returns the hint for the error, if any.
+    pub fn hint(&self) -> Option<&str> {
+        self.hint.as_deref()
+    }
+
+    /// Returns a hint if the input appears to be bech32-encoded with the registered prefix of a different type.
+    fn bech32_hint(expected: &str, input: &str) -> Option<String> {
+        let (hrp, _) = input.trim().rsplit_once('1')?;
+        let prefix = Bech32mPrefix::find(&hrp.to_ascii_lowercase())?;
+        match prefix.name() != expected {
+            true => Some(format!(
+                "The prefix '{}' indicates {}, not {}",
+                prefix.hrp(),
+                with_article(prefix.name()),
+                with_article(expected)
+            )),
+            false => None,
+        }
+    }
+}
+
+/// Returns the given noun with its indefinite article.
+fn with_article(noun: &str) -> String {
+    match noun.starts_with(['a', 'e', 'i', 'o', 'u']) {
+        true => format!("an {noun}"),
+        false => format!("a {noun}"),
+    }
+}
+
+impl fmt::Display for ParseError {
+    /// Prints the parse error.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Failed to parse {} at position {}", self.expected, self.position)?;
+        match self.found.is_empty() {
+            true => write!(f, " (found the end of the input)")?,
+            false => write!(f, " (found \"{}\")", self.found)?,
+        }
+        if let Some(reason) = &self.reason {
+            write!(f, ": {reason}")?;
+        }
+        if let Some(hint) = &self.hint {
+            write!(f, ". {hint}")?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// Parses the given string in full with the given parser, returning a `ParseError` on failure.
+pub fn parse_str<'a, O>(
+    expected: &'static str,
+    string: &'a str,
+    parser: impl FnOnce(&'a str) -> ParserResult<'a, O>,
+) -> Result<O, ParseError> {
+    match parser(string) {
+        // Ensure the remainder is empty.
+        Ok((remainder, object)) => match remainder.is_empty() {
+            true => Ok(object),
+            false => {
+                Err(ParseError::from_remainder(expected, string, remainder).with_reason("found an invalid character"))
+            }
+        },
+        Err(error) => Err(ParseError::from_parser_error(expected, string, error)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use nom::{bytes::complete::tag, character::complete::digit1, sequence::pair};
+
+    fn parse_example(string: &str) -> ParserResult<(&str, &str)> {
+        pair(tag("x"), digit1)(string)
+    }
+
+    #[test]
+    fn test_parse_str() {
+        assert_eq!(parse_str("example", "x12", parse_example).unwrap(), ("x", "12"));
+
+        // Ensure the position of trailing input is reported.
+        let error = parse_str("example", "x12y", parse_example).unwrap_err();
+        assert_eq!(error.position(), 3);
+        assert_eq!(error.found(), "y");
+        assert_eq!(error.reason(), Some("found an invalid character"));
+
+        // Ensure the position of a parser error is reported.
+        let error = parse_str("example", "xy", parse_example).unwrap_err();
+        assert_eq!(error.position(), 1);
+        assert_eq!(error.to_string(), "Failed to parse example at position 1 (found \"y\")");
+    }
+
+    #[test]
+    fn test_bech32_hint() {
+        let signature = "sign1qqqqqqqqqqqqqqqqqqqqqqqqq";
+        let error = ParseError::new("address", signature, 0);
+        assert_eq!(error.hint(), Some("The prefix 'sign' indicates a signature, not an address"));
+        // Ensure there is no hint for the expected type, or for unregistered prefixes.
+        assert!(ParseError::new("signature", signature, 0).hint().is_none());
+        assert!(ParseError::new("address", "foo1bar", 0).hint().is_none());
+    }
+}
@@ -61,6 +61,7 @@ pub mod prelude {
     pub use snarkvm_curves::{AffineCurve, MontgomeryParameters, ProjectiveCurve, TwistedEdwardsParameters};
     pub use snarkvm_fields::{Field as _, PrimeField as _, SquareRootField as _, Zero as _};
     pub use snarkvm_utilities::{
+        bits_from_bytes_le,
         cfg_chunks,
         cfg_find,
         cfg_find_map,
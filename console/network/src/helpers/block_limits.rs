@@ -0,0 +1,38 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+/// The limits on the contents of a block, as configured by the network.
+///
+/// Each limit is additionally bounded by the structural capacity of the corresponding Merkle tree,
+/// so a network may lower these limits freely, but raising them beyond the capacity has no effect.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct BlockLimits {
+    /// The maximum number of transactions in a block.
+    pub max_transactions: usize,
+    /// The maximum number of solutions in a block.
+    pub max_solutions: usize,
+    /// The maximum number of transmissions in a batch.
+    pub max_transmissions_per_batch: usize,
+}
+
+impl BlockLimits {
+    /// Returns the given limit, bounded by the given capacity.
+    pub const fn bounded(limit: usize, capacity: usize) -> usize {
+        match limit < capacity {
+            true => limit,
+            false => capacity,
+        }
+    }
+}
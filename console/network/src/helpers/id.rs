@@ -16,7 +16,7 @@
 use crate::prelude::*;
 
 use anyhow::Result;
-use bech32::{self, FromBase32, ToBase32};
+use bech32::{self, ToBase32};
 use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
 use std::borrow::Borrow;
 
@@ -127,17 +127,7 @@ impl<F: FieldTrait, const PREFIX: u16> FromStr for AleoID<F, PREFIX> {
             bail!("Invalid byte size for a bech32m hash: {} bytes", string.len())
         }
 
-        let (hrp, data, variant) = bech32::decode(string)?;
-        if hrp.as_bytes() != PREFIX.to_le_bytes() {
-            bail!("Invalid prefix for a bech32m hash: {hrp}")
-        };
-        if data.is_empty() {
-            bail!("Bech32m hash data is empty")
-        }
-        if variant != bech32::Variant::Bech32m {
-            bail!("Hash is not a bech32m hash")
-        }
-        Ok(Self::read_le(&*Vec::from_base32(&data)?)?)
+        Ok(Self::read_le(&*decode_bech32m(string, &Self::prefix())?)?)
     }
 }
 
@@ -13,6 +13,9 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod block_limits;
+pub use block_limits::*;
+
 mod id;
 pub use id::*;
 
@@ -16,7 +16,7 @@
 use crate::prelude::*;
 
 use anyhow::Result;
-use bech32::{self, FromBase32, ToBase32};
+use bech32::{self, ToBase32};
 use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
 use std::borrow::Borrow;
 
@@ -97,17 +97,7 @@ impl<T: Clone + Debug + ToBytes + FromBytes + PartialEq + Eq + Sync + Send, cons
     /// Reads in a bech32m string.
     #[inline]
     fn from_str(string: &str) -> Result<Self, Self::Err> {
-        let (hrp, data, variant) = bech32::decode(string)?;
-        if hrp.as_bytes() != PREFIX.to_le_bytes() {
-            bail!("Invalid prefix for a bech32m hash: {hrp}")
-        };
-        if data.is_empty() {
-            bail!("Bech32m hash data is empty")
-        }
-        if variant != bech32::Variant::Bech32m {
-            bail!("Hash is not a bech32m hash")
-        }
-        Ok(Self::read_le(&*Vec::from_base32(&data)?)?)
+        Ok(Self::read_le(&*decode_bech32m(string, &Self::prefix())?)?)
     }
 }
 
@@ -47,7 +47,7 @@ use snarkvm_algorithms::{
     AlgebraicSponge,
 };
 use snarkvm_console_algorithms::{Poseidon2, Poseidon4, BHP1024, BHP512};
-use snarkvm_console_collections::merkle_tree::{MerklePath, MerkleTree};
+use snarkvm_console_collections::merkle_tree::{MerkleMultiPath, MerklePath, MerkleTree};
 use snarkvm_console_types::{Field, Group, Scalar};
 use snarkvm_curves::PairingEngine;
 
@@ -114,6 +114,13 @@ pub trait Network:
     const MAX_SOLUTIONS_AS_POWER_OF_TWO: u8 = 2; // 4 solutions
     /// The maximum number of solutions that can be included per block.
     const MAX_SOLUTIONS: usize = 1 << Self::MAX_SOLUTIONS_AS_POWER_OF_TWO; // 4 solutions
+    /// The limits on the number of transactions, solutions, and transmissions in a block.
+    /// Note: These limits are bounded by the capacity of the respective Merkle trees.
+    const BLOCK_LIMITS: BlockLimits = BlockLimits {
+        max_transactions: (1 << 20) - 1, // 1,048,575 transactions
+        max_solutions: Self::MAX_SOLUTIONS,
+        max_transmissions_per_batch: 50,
+    };
 
     /// The starting supply of Aleo credits.
     const STARTING_SUPPLY: u64 = 1_500_000_000_000_000; // 1.5B credits
@@ -342,6 +349,42 @@ pub trait Network:
     /// Returns the SHA-3 hash with a 512-bit output.
     fn hash_sha3_512(input: &[bool]) -> Result<Vec<bool>>;
 
+    /// Returns the BHP hash with an input hasher of 256-bits, over an iterator of bits with an exact size.
+    fn hash_bhp256_iter(input: impl IntoIterator<Item = bool>) -> Result<Field<Self>>;
+
+    /// Returns the BHP hash with an input hasher of 512-bits, over an iterator of bits with an exact size.
+    fn hash_bhp512_iter(input: impl IntoIterator<Item = bool>) -> Result<Field<Self>>;
+
+    /// Returns the BHP hash with an input hasher of 768-bits, over an iterator of bits with an exact size.
+    fn hash_bhp768_iter(input: impl IntoIterator<Item = bool>) -> Result<Field<Self>>;
+
+    /// Returns the BHP hash with an input hasher of 1024-bits, over an iterator of bits with an exact size.
+    fn hash_bhp1024_iter(input: impl IntoIterator<Item = bool>) -> Result<Field<Self>>;
+
+    /// Returns the Keccak hash with a 256-bit output, over an iterator of bits.
+    fn hash_keccak256_iter(input: impl IntoIterator<Item = bool>) -> Result<Vec<bool>>;
+
+    /// Returns the Keccak hash with a 384-bit output, over an iterator of bits.
+    fn hash_keccak384_iter(input: impl IntoIterator<Item = bool>) -> Result<Vec<bool>>;
+
+    /// Returns the Keccak hash with a 512-bit output, over an iterator of bits.
+    fn hash_keccak512_iter(input: impl IntoIterator<Item = bool>) -> Result<Vec<bool>>;
+
+    /// Returns the Poseidon hash with an input rate of 2, over an iterator of field elements with an exact size.
+    fn hash_psd2_iter(input: impl IntoIterator<Item = Field<Self>>) -> Result<Field<Self>>;
+
+    /// Returns the Poseidon hash with an input rate of 4, over an iterator of field elements with an exact size.
+    fn hash_psd4_iter(input: impl IntoIterator<Item = Field<Self>>) -> Result<Field<Self>>;
+
+    /// Returns the Poseidon hash with an input rate of 8, over an iterator of field elements with an exact size.
+    fn hash_psd8_iter(input: impl IntoIterator<Item = Field<Self>>) -> Result<Field<Self>>;
+
+    /// Returns the Keccak hash with a 256-bit output of each of the given inputs, computed in parallel.
+    fn hash_keccak256_batch(inputs: &[Vec<bool>]) -> Result<Vec<Vec<bool>>>;
+
+    /// Returns the SHA-3 hash with a 256-bit output of each of the given inputs, computed in parallel.
+    fn hash_sha3_256_batch(inputs: &[Vec<bool>]) -> Result<Vec<Vec<bool>>>;
+
     /// Returns the extended Poseidon hash with an input rate of 2.
     fn hash_many_psd2(input: &[Field<Self>], num_outputs: u16) -> Vec<Field<Self>>;
 
@@ -378,6 +421,9 @@ pub trait Network:
     /// Returns the Poseidon hash with an input rate of 8 on the affine curve.
     fn hash_to_group_psd8(input: &[Field<Self>]) -> Result<Group<Self>>;
 
+    /// Returns the RFC 9380 hash-to-curve of the given input, with `expand_message_xmd` over SHA3-256.
+    fn hash_to_curve(input: &[bool]) -> Result<Group<Self>>;
+
     /// Returns the Poseidon hash with an input rate of 2 on the scalar field.
     fn hash_to_scalar_psd2(input: &[Field<Self>]) -> Result<Scalar<Self>>;
 
@@ -408,4 +454,18 @@ pub trait Network:
         root: &Field<Self>,
         leaf: &Vec<Field<Self>>,
     ) -> bool;
+
+    /// Returns `true` if the given Merkle multi-path is valid for the given root and leaves.
+    fn verify_merkle_multi_path_bhp<const DEPTH: u8>(
+        path: &MerkleMultiPath<Self, DEPTH>,
+        root: &Field<Self>,
+        leaves: &[Vec<bool>],
+    ) -> bool;
+
+    /// Returns `true` if the given Merkle multi-path is valid for the given root and leaves.
+    fn verify_merkle_multi_path_psd<const DEPTH: u8>(
+        path: &MerkleMultiPath<Self, DEPTH>,
+        root: &Field<Self>,
+        leaves: &[Vec<Field<Self>>],
+    ) -> bool;
 }
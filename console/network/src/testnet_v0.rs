@@ -17,6 +17,7 @@ use super::*;
 use crate::TRANSACTION_PREFIX;
 use snarkvm_console_algorithms::{
     Blake2Xs,
+    HashToCurve,
     Keccak256,
     Keccak384,
     Keccak512,
@@ -32,6 +33,7 @@ use snarkvm_console_algorithms::{
     BHP256,
     BHP512,
     BHP768,
+    HASH_TO_CURVE_SUITE_ID,
 };
 
 lazy_static! {
@@ -69,6 +71,9 @@ lazy_static! {
     /// The Poseidon hash function, using a rate of 8.
     pub static ref TESTNET_POSEIDON_8: Poseidon8<TestnetV0> = Poseidon8::<TestnetV0>::setup("AleoPoseidon8").expect("Failed to setup Poseidon8");
 
+    /// The hash-to-curve construction of RFC 9380, for the twisted Edwards group.
+    pub static ref TESTNET_HASH_TO_CURVE: HashToCurve<TestnetV0> = HashToCurve::<TestnetV0>::setup(&format!("AleoHashToCurve-V0-CS01-with-{HASH_TO_CURVE_SUITE_ID}")).expect("Failed to setup HashToCurve");
+
     pub static ref TESTNET_CREDITS_PROVING_KEYS: IndexMap<String, Arc<VarunaProvingKey<Console>>> = {
         let mut map = IndexMap::new();
         snarkvm_parameters::insert_testnet_credit_keys!(map, VarunaProvingKey<Console>, Prover);
@@ -380,6 +385,66 @@ impl Network for TestnetV0 {
         Sha3_512::default().hash(input)
     }
 
+    /// Returns the BHP hash with an input hasher of 256-bits, over an iterator of bits with an exact size.
+    fn hash_bhp256_iter(input: impl IntoIterator<Item = bool>) -> Result<Field<Self>> {
+        TESTNET_BHP_256.hash_iter(input)
+    }
+
+    /// Returns the BHP hash with an input hasher of 512-bits, over an iterator of bits with an exact size.
+    fn hash_bhp512_iter(input: impl IntoIterator<Item = bool>) -> Result<Field<Self>> {
+        TESTNET_BHP_512.hash_iter(input)
+    }
+
+    /// Returns the BHP hash with an input hasher of 768-bits, over an iterator of bits with an exact size.
+    fn hash_bhp768_iter(input: impl IntoIterator<Item = bool>) -> Result<Field<Self>> {
+        TESTNET_BHP_768.hash_iter(input)
+    }
+
+    /// Returns the BHP hash with an input hasher of 1024-bits, over an iterator of bits with an exact size.
+    fn hash_bhp1024_iter(input: impl IntoIterator<Item = bool>) -> Result<Field<Self>> {
+        TESTNET_BHP_1024.hash_iter(input)
+    }
+
+    /// Returns the Keccak hash with a 256-bit output, over an iterator of bits.
+    fn hash_keccak256_iter(input: impl IntoIterator<Item = bool>) -> Result<Vec<bool>> {
+        Keccak256::default().hash_iter(input)
+    }
+
+    /// Returns the Keccak hash with a 384-bit output, over an iterator of bits.
+    fn hash_keccak384_iter(input: impl IntoIterator<Item = bool>) -> Result<Vec<bool>> {
+        Keccak384::default().hash_iter(input)
+    }
+
+    /// Returns the Keccak hash with a 512-bit output, over an iterator of bits.
+    fn hash_keccak512_iter(input: impl IntoIterator<Item = bool>) -> Result<Vec<bool>> {
+        Keccak512::default().hash_iter(input)
+    }
+
+    /// Returns the Poseidon hash with an input rate of 2, over an iterator of field elements with an exact size.
+    fn hash_psd2_iter(input: impl IntoIterator<Item = Field<Self>>) -> Result<Field<Self>> {
+        TESTNET_POSEIDON_2.hash_iter(input)
+    }
+
+    /// Returns the Poseidon hash with an input rate of 4, over an iterator of field elements with an exact size.
+    fn hash_psd4_iter(input: impl IntoIterator<Item = Field<Self>>) -> Result<Field<Self>> {
+        TESTNET_POSEIDON_4.hash_iter(input)
+    }
+
+    /// Returns the Poseidon hash with an input rate of 8, over an iterator of field elements with an exact size.
+    fn hash_psd8_iter(input: impl IntoIterator<Item = Field<Self>>) -> Result<Field<Self>> {
+        TESTNET_POSEIDON_8.hash_iter(input)
+    }
+
+    /// Returns the Keccak hash with a 256-bit output of each of the given inputs, computed in parallel.
+    fn hash_keccak256_batch(inputs: &[Vec<bool>]) -> Result<Vec<Vec<bool>>> {
+        Keccak256::default().hash_batch(inputs)
+    }
+
+    /// Returns the SHA-3 hash with a 256-bit output of each of the given inputs, computed in parallel.
+    fn hash_sha3_256_batch(inputs: &[Vec<bool>]) -> Result<Vec<Vec<bool>>> {
+        Sha3_256::default().hash_batch(inputs)
+    }
+
     /// Returns the extended Poseidon hash with an input rate of 2.
     fn hash_many_psd2(input: &[Field<Self>], num_outputs: u16) -> Vec<Field<Self>> {
         TESTNET_POSEIDON_2.hash_many(input, num_outputs)
@@ -440,6 +505,11 @@ impl Network for TestnetV0 {
         TESTNET_POSEIDON_8.hash_to_group(input)
     }
 
+    /// Returns the RFC 9380 hash-to-curve of the given input, with `expand_message_xmd` over SHA3-256.
+    fn hash_to_curve(input: &[bool]) -> Result<Group<Self>> {
+        TESTNET_HASH_TO_CURVE.hash_to_group(input)
+    }
+
     /// Returns the Poseidon hash with an input rate of 2 on the scalar field.
     fn hash_to_scalar_psd2(input: &[Field<Self>]) -> Result<Scalar<Self>> {
         TESTNET_POSEIDON_2.hash_to_scalar(input)
@@ -482,6 +552,24 @@ impl Network for TestnetV0 {
     ) -> bool {
         path.verify(&*TESTNET_POSEIDON_4, &*TESTNET_POSEIDON_2, root, leaf)
     }
+
+    /// Returns `true` if the given Merkle multi-path is valid for the given root and leaves.
+    fn verify_merkle_multi_path_bhp<const DEPTH: u8>(
+        path: &MerkleMultiPath<Self, DEPTH>,
+        root: &Field<Self>,
+        leaves: &[Vec<bool>],
+    ) -> bool {
+        path.verify(&*TESTNET_BHP_1024, &*TESTNET_BHP_512, root, leaves)
+    }
+
+    /// Returns `true` if the given Merkle multi-path is valid for the given root and leaves.
+    fn verify_merkle_multi_path_psd<const DEPTH: u8>(
+        path: &MerkleMultiPath<Self, DEPTH>,
+        root: &Field<Self>,
+        leaves: &[Vec<Field<Self>>],
+    ) -> bool {
+        path.verify(&*TESTNET_POSEIDON_4, &*TESTNET_POSEIDON_2, root, leaves)
+    }
 }
 
 #[cfg(test)]
@@ -0,0 +1,100 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+use once_cell::sync::Lazy;
+use std::{
+    any::{Any, TypeId},
+    collections::HashMap,
+    sync::RwLock,
+};
+
+/// The maximum number of strings retained in the interner, for each interned type.
+/// Beyond this limit, strings are still parsed, but are no longer retained.
+const MAX_INTERNED_STRINGS: usize = 1 << 16;
+
+/// The interner, mapping each interned type to its cache of parsed strings.
+static INTERNER: Lazy<RwLock<HashMap<TypeId, Box<dyn Any + Send + Sync>>>> = Lazy::new(Default::default);
+
+/// Returns the parsed value for the given string, invoking `parse` at most once per string and type.
+pub(crate) fn intern<T: Copy + Send + Sync + 'static>(
+    string: &str,
+    parse: impl FnOnce(&str) -> Result<T>,
+) -> Result<T> {
+    // Return the value, if it has been interned.
+    {
+        let interner = INTERNER.read().unwrap_or_else(|error| error.into_inner());
+        if let Some(value) = interner
+            .get(&TypeId::of::<T>())
+            .and_then(|cache| cache.downcast_ref::<HashMap<String, T>>())
+            .and_then(|cache| cache.get(string))
+        {
+            return Ok(*value);
+        }
+    }
+
+    // Parse the value.
+    let value = parse(string)?;
+
+    // Retain the value, if the cache has capacity.
+    let mut interner = INTERNER.write().unwrap_or_else(|error| error.into_inner());
+    if let Some(cache) = interner
+        .entry(TypeId::of::<T>())
+        .or_insert_with(|| Box::<HashMap<String, T>>::default())
+        .downcast_mut::<HashMap<String, T>>()
+    {
+        if cache.len() < MAX_INTERNED_STRINGS {
+            cache.insert(string.to_string(), value);
+        }
+    }
+    Ok(value)
+}
+
+impl<N: Network> Identifier<N> {
+    /// Returns the identifier for the given string, parsing it at most once.
+    ///
+    /// This is intended for identifiers that are parsed repeatedly, such as well-known mapping and program names.
+    /// Note: The result is identical to `Identifier::from_str`.
+    #[inline]
+    pub fn intern(identifier: &str) -> Result<Self> {
+        intern(identifier, Self::from_str)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::MainnetV0;
+
+    type CurrentNetwork = MainnetV0;
+
+    #[test]
+    fn test_intern() -> Result<()> {
+        for string in ["foo", "bar_baz", "credits", "aleo"] {
+            // Ensure the interned identifier matches the parsed identifier.
+            let expected = Identifier::<CurrentNetwork>::from_str(string)?;
+            assert_eq!(expected, Identifier::intern(string)?);
+            // Ensure the interned identifier is returned on subsequent calls.
+            assert_eq!(expected, Identifier::intern(string)?);
+        }
+        // Ensure invalid identifiers are rejected, and not interned.
+        for string in ["", "1foo", "foo bar", "u8"] {
+            assert!(Identifier::<CurrentNetwork>::intern(string).is_err());
+            assert!(Identifier::<CurrentNetwork>::intern(string).is_err());
+        }
+        Ok(())
+    }
+}
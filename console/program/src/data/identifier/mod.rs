@@ -17,12 +17,15 @@ mod bytes;
 mod equal;
 mod from_bits;
 mod from_field;
+mod intern;
 mod parse;
 mod serialize;
 mod size_in_bits;
 mod to_bits;
 mod to_field;
 
+pub(crate) use intern::intern;
+
 use snarkvm_console_network::Network;
 use snarkvm_console_types::{prelude::*, Field};
 
@@ -98,15 +98,8 @@ impl<N: Network> FromStr for Plaintext<N> {
 
     /// Returns a plaintext from a string literal.
     fn from_str(string: &str) -> Result<Self> {
-        match Self::parse(string) {
-            Ok((remainder, object)) => {
-                // Ensure the remainder is empty.
-                ensure!(remainder.is_empty(), "Failed to parse string. Found invalid character in: \"{remainder}\"");
-                // Return the object.
-                Ok(object)
-            }
-            Err(error) => bail!("Failed to parse string. {error}"),
-        }
+        // Parse the string in full.
+        Ok(parse_str("plaintext", string, Self::parse)?)
     }
 }
 
@@ -29,6 +29,38 @@ impl<N: Network> Record<N, Ciphertext<N>> {
         }
     }
 
+    /// Decrypts `self` into plaintext using the given outgoing view key, and the outgoing ciphertext
+    /// from `Record::encrypt_with_outgoing_view_key`, and checks that the owner matches the ciphertext.
+    pub fn decrypt_with_outgoing_view_key(
+        &self,
+        outgoing_view_key: &OutgoingViewKey<N>,
+        outgoing_ciphertext: &[Field<N>],
+    ) -> Result<Record<N, Plaintext<N>>> {
+        // Ensure the outgoing ciphertext contains the randomizer and owner.
+        ensure!(outgoing_ciphertext.len() == 2, "Expected 2 fields in the outgoing ciphertext");
+        // Decrypt the randomizer and owner.
+        let plaintext = outgoing_view_key.decrypt(&self.nonce, outgoing_ciphertext)?;
+        let randomizer = Scalar::from_field(&plaintext[0])?;
+        let owner = Address::new(Group::from_x_coordinate(plaintext[1])?);
+        // Ensure the randomizer corresponds to the record nonce.
+        ensure!(
+            self.nonce == N::g_scalar_multiply(&randomizer),
+            "Illegal operation: Record::decrypt_with_outgoing_view_key() randomizer does not match the nonce."
+        );
+
+        // Compute the record view key.
+        let record_view_key = (*owner * randomizer).to_x_coordinate();
+        // Decrypt the record.
+        let record = self.decrypt_symmetric_unchecked(&record_view_key)?;
+        // Ensure the record owner matches the outgoing ciphertext.
+        match owner == **record.owner() {
+            true => Ok(record),
+            false => bail!(
+                "Illegal operation: Record::decrypt_with_outgoing_view_key() ciphertext does not match the owner."
+            ),
+        }
+    }
+
     /// Decrypts `self` into plaintext using the given record view key.
     /// Note: This method does not check that the record view key corresponds to the record owner.
     /// Use `Self::decrypt` for the checked variant.
@@ -131,6 +163,17 @@ mod tests {
         // Ensure that decrypting with the incorrect view key fails.
         assert!(ciphertext.decrypt(&incorrect_view_key).is_err());
 
+        // Encrypt the record, with the outgoing view key of the sender.
+        let outgoing_view_key = OutgoingViewKey::try_from(ViewKey::try_from(&PrivateKey::<N>::new(rng)?)?)?;
+        let (candidate, outgoing_ciphertext) = record.encrypt_with_outgoing_view_key(randomizer, &outgoing_view_key)?;
+        assert_eq!(ciphertext, candidate);
+        // Ensure the sender and the owner can decrypt the record.
+        assert_eq!(record, ciphertext.decrypt_with_outgoing_view_key(&outgoing_view_key, &outgoing_ciphertext)?);
+        assert_eq!(record, candidate.decrypt(&view_key)?);
+        // Ensure that decrypting with the incorrect outgoing view key fails.
+        let incorrect_outgoing_view_key = OutgoingViewKey::try_from(incorrect_view_key)?;
+        assert!(ciphertext.decrypt_with_outgoing_view_key(&incorrect_outgoing_view_key, &outgoing_ciphertext).is_err());
+
         Ok(())
     }
 
@@ -29,6 +29,21 @@ impl<N: Network> Record<N, Plaintext<N>> {
         }
     }
 
+    /// Encrypts `self` for the record owner under the given randomizer, and returns the ciphertext of
+    /// the randomizer and owner under the given outgoing view key, which the sender can use to decrypt the record.
+    pub fn encrypt_with_outgoing_view_key(
+        &self,
+        randomizer: Scalar<N>,
+        outgoing_view_key: &OutgoingViewKey<N>,
+    ) -> Result<(Record<N, Ciphertext<N>>, Vec<Field<N>>)> {
+        // Encrypt the record.
+        let record = self.encrypt(randomizer)?;
+        // Encrypt the randomizer and owner, under the outgoing view key.
+        let outgoing_ciphertext =
+            outgoing_view_key.encrypt(&self.nonce, &[randomizer.to_field()?, self.owner.to_x_coordinate()])?;
+        Ok((record, outgoing_ciphertext))
+    }
+
     /// Encrypts `self` under the given record view key.
     /// Note: This method does not check that the record view key corresponds to the record owner.
     /// Use `Self::encrypt` for the checked variant.
@@ -33,4 +33,40 @@ impl<N: Network, Private: Visibility> Record<N, Private> {
         // Compute `serial_number` as `Commit(commitment, sn_nonce)`.
         N::commit_bhp512(&(N::serial_number_domain(), commitment).to_bits_le(), &sn_nonce)
     }
+
+    /// A helper method to derive the serial numbers from the private key and a list of commitments.
+    pub fn serial_numbers(private_key: PrivateKey<N>, commitments: &[Field<N>]) -> Result<Vec<Field<N>>> {
+        commitments.iter().map(|commitment| Self::serial_number(private_key, *commitment)).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::MainnetV0;
+
+    type CurrentNetwork = MainnetV0;
+
+    const ITERATIONS: u64 = 100;
+
+    #[test]
+    fn test_serial_numbers() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            // Sample a private key and a list of commitments.
+            let private_key = PrivateKey::<CurrentNetwork>::new(&mut rng)?;
+            let commitments: Vec<_> = (0..4).map(|_| Field::rand(&mut rng)).collect();
+
+            // Ensure the batch derivation matches the individual derivation.
+            let candidates =
+                Record::<CurrentNetwork, Plaintext<CurrentNetwork>>::serial_numbers(private_key, &commitments)?;
+            for (commitment, candidate) in commitments.iter().zip_eq(candidates) {
+                let expected =
+                    Record::<CurrentNetwork, Plaintext<CurrentNetwork>>::serial_number(private_key, *commitment)?;
+                assert_eq!(expected, candidate);
+            }
+        }
+        Ok(())
+    }
 }
@@ -21,4 +21,61 @@ impl<N: Network, Private: Visibility> Record<N, Private> {
         // Compute the tag as `Hash(sk_tag, commitment)`.
         N::hash_psd2(&[sk_tag, commitment])
     }
+
+    /// A helper method to derive the tag from the view key and commitment.
+    pub fn tag_from_view_key(view_key: &ViewKey<N>, commitment: Field<N>) -> Result<Field<N>> {
+        // Derive `sk_tag` from the graph key.
+        let sk_tag = GraphKey::try_from(view_key)?.sk_tag();
+        // Compute the tag.
+        Self::tag(sk_tag, commitment)
+    }
+
+    /// A helper method to derive the tags from the `sk_tag` and a list of commitments.
+    pub fn tags(sk_tag: Field<N>, commitments: &[Field<N>]) -> Result<Vec<Field<N>>> {
+        commitments.iter().map(|commitment| Self::tag(sk_tag, *commitment)).collect()
+    }
+
+    /// A helper method to derive the tags from the view key and a list of commitments.
+    pub fn tags_from_view_key(view_key: &ViewKey<N>, commitments: &[Field<N>]) -> Result<Vec<Field<N>>> {
+        // Derive `sk_tag` from the graph key.
+        let sk_tag = GraphKey::try_from(view_key)?.sk_tag();
+        // Compute the tags.
+        Self::tags(sk_tag, commitments)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::MainnetV0;
+
+    type CurrentNetwork = MainnetV0;
+
+    const ITERATIONS: u64 = 100;
+
+    #[test]
+    fn test_tags_from_view_key() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            // Sample a view key and a list of commitments.
+            let private_key = PrivateKey::<CurrentNetwork>::new(&mut rng)?;
+            let view_key = ViewKey::try_from(private_key)?;
+            let sk_tag = GraphKey::try_from(view_key)?.sk_tag();
+            let commitments: Vec<_> = (0..4).map(|_| Field::rand(&mut rng)).collect();
+
+            // Ensure the view key derivation matches the `sk_tag` derivation.
+            let candidates =
+                Record::<CurrentNetwork, Plaintext<CurrentNetwork>>::tags_from_view_key(&view_key, &commitments)?;
+            for (commitment, candidate) in commitments.iter().zip_eq(candidates) {
+                let expected = Record::<CurrentNetwork, Plaintext<CurrentNetwork>>::tag(sk_tag, *commitment)?;
+                assert_eq!(expected, candidate);
+                assert_eq!(
+                    expected,
+                    Record::<CurrentNetwork, Plaintext<CurrentNetwork>>::tag_from_view_key(&view_key, *commitment)?
+                );
+            }
+        }
+        Ok(())
+    }
 }
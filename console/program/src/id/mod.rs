@@ -114,7 +114,16 @@ impl<N: Network> ProgramID<N> {
     /// Returns `true` if the network-level domain is `aleo`.
     #[inline]
     pub fn is_aleo(&self) -> bool {
-        self.network() == &Identifier::from_str("aleo").expect("Failed to parse Aleo domain")
+        self.network() == &Identifier::intern("aleo").expect("Failed to parse Aleo domain")
+    }
+
+    /// Returns the program ID for the given string, parsing it at most once.
+    ///
+    /// This is intended for program IDs that are parsed repeatedly, such as `credits.aleo`.
+    /// Note: The result is identical to `ProgramID::from_str`.
+    #[inline]
+    pub fn intern(program_id: &str) -> Result<Self> {
+        crate::data::identifier::intern(program_id, Self::from_str)
     }
 }
 
@@ -43,6 +43,12 @@ pub use locator::*;
 mod owner;
 pub use owner::*;
 
+mod ownership_proof;
+pub use ownership_proof::*;
+
+mod parse_error;
+pub use parse_error::*;
+
 mod request;
 pub use request::*;
 
@@ -51,3 +57,6 @@ pub use response::*;
 
 pub mod state_path;
 pub use state_path::*;
+
+#[cfg(any(test, feature = "prop-tests"))]
+pub mod prop_tests;
@@ -0,0 +1,84 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network> FromBytes for OwnershipProof<N> {
+    /// Reads the ownership proof from a buffer.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Read the version.
+        let version = u8::read_le(&mut reader)?;
+        // Ensure the version is valid.
+        if version != 1 {
+            return Err(error("Invalid ownership proof version"));
+        }
+
+        // Read the program ID.
+        let program_id = ProgramID::read_le(&mut reader)?;
+        // Read the record name.
+        let record_name = Identifier::read_le(&mut reader)?;
+        // Read the record.
+        let record = Record::read_le(&mut reader)?;
+        // Read the gamma.
+        let gamma = Group::read_le(&mut reader)?;
+        // Read the signature.
+        let signature = Signature::read_le(&mut reader)?;
+
+        // Return the ownership proof.
+        Ok(Self { program_id, record_name, record, gamma, signature })
+    }
+}
+
+impl<N: Network> ToBytes for OwnershipProof<N> {
+    /// Writes the ownership proof to a buffer.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Write the version.
+        1u8.write_le(&mut writer)?;
+        // Write the program ID.
+        self.program_id.write_le(&mut writer)?;
+        // Write the record name.
+        self.record_name.write_le(&mut writer)?;
+        // Write the record.
+        self.record.write_le(&mut writer)?;
+        // Write the gamma.
+        self.gamma.write_le(&mut writer)?;
+        // Write the signature.
+        self.signature.write_le(&mut writer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::MainnetV0;
+
+    type CurrentNetwork = MainnetV0;
+
+    #[test]
+    fn test_bytes() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        // Construct a new ownership proof.
+        let (_, expected) = test_helpers::sample_ownership_proof(100, &[], rng);
+
+        // Check the byte representation.
+        let expected_bytes = expected.to_bytes_le()?;
+        let candidate = OwnershipProof::<CurrentNetwork>::read_le(&expected_bytes[..])?;
+        assert_eq!(expected, candidate);
+        assert!(candidate.verify(&[]));
+        assert!(OwnershipProof::<CurrentNetwork>::read_le(&expected_bytes[1..]).is_err());
+        Ok(())
+    }
+}
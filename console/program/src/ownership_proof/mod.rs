@@ -0,0 +1,327 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod bytes;
+
+use crate::{Entry, Identifier, Literal, Plaintext, ProgramID, Record};
+use snarkvm_console_account::{Address, ComputeKey, PrivateKey, Signature};
+use snarkvm_console_network::Network;
+use snarkvm_console_types::prelude::*;
+
+/// A non-interactive proof that an address owns a record, without spending it.
+///
+/// The record is disclosed to the verifier, and the proof binds it to its commitment and serial number.
+/// The signature is a Chaum-Pedersen proof that `pk_sig` and `gamma` share the same `sk_sig`, where:
+///     challenge := HashToScalar(domain, r * G, pk_sig, pr_sig, owner, H, r * H, gamma, commitment, message)
+///     response := r - challenge * sk_sig
+/// As `gamma` determines the serial number, the verifier can check the record is unspent against the ledger.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct OwnershipProof<N: Network> {
+    /// The program ID of the record.
+    program_id: ProgramID<N>,
+    /// The name of the record.
+    record_name: Identifier<N>,
+    /// The record.
+    record: Record<N, Plaintext<N>>,
+    /// The serial number generator, as `sk_sig * H`.
+    gamma: Group<N>,
+    /// The signature of the owner, over the commitment and message.
+    signature: Signature<N>,
+}
+
+impl<N: Network> OwnershipProof<N> {
+    /// Initializes a new ownership proof of the given record, over the given message.
+    /// Note: The message should bind the proof to its context (e.g. a verifier nonce), to prevent replays.
+    pub fn prove<R: Rng + CryptoRng>(
+        private_key: &PrivateKey<N>,
+        program_id: ProgramID<N>,
+        record_name: Identifier<N>,
+        record: Record<N, Plaintext<N>>,
+        message: &[Field<N>],
+        rng: &mut R,
+    ) -> Result<Self> {
+        // Derive the compute key.
+        let compute_key = ComputeKey::try_from(private_key)?;
+        // Derive the address.
+        let address = Address::try_from(compute_key)?;
+        // Ensure the record belongs to the signer.
+        ensure!(**record.owner() == address, "The record for '{program_id}' must belong to the signer");
+
+        // Compute the record commitment.
+        let commitment = record.to_commitment(&program_id, &record_name)?;
+        // Compute the generator `H` as `HashToGroup(commitment)`.
+        let h = N::hash_to_group_psd2(&[N::serial_number_domain(), commitment])?;
+        // Compute `gamma` as `sk_sig * H`.
+        let gamma = h * private_key.sk_sig();
+
+        // Sample a random nonce.
+        let r = Scalar::<N>::rand(rng);
+        // Compute `g_r` as `r * G`.
+        let g_r = N::g_scalar_multiply(&r);
+        // Compute `h_r` as `r * H`.
+        let h_r = h * r;
+
+        // Compute the challenge.
+        let challenge = Self::to_challenge(&compute_key, &address, [g_r, h, h_r, gamma], commitment, message)?;
+        // Compute the response as `r - challenge * sk_sig`.
+        let response = r - challenge * private_key.sk_sig();
+
+        // Return the ownership proof.
+        Ok(Self {
+            program_id,
+            record_name,
+            record,
+            gamma,
+            signature: Signature::from((challenge, response, compute_key)),
+        })
+    }
+
+    /// Returns `true` if the proof is valid for the given message.
+    pub fn verify(&self, message: &[Field<N>]) -> bool {
+        // Retrieve the compute key.
+        let compute_key = self.signature.compute_key();
+        // Derive the address from the compute key, and return `false` if this operation fails.
+        let Ok(address) = Address::try_from(compute_key) else { return false };
+        // Ensure the record belongs to the signer.
+        if **self.record.owner() != address {
+            return false;
+        }
+
+        // Compute the record commitment, and return `false` if this operation fails.
+        let Ok(commitment) = self.commitment() else { return false };
+        // Compute the generator `H`, and return `false` if this operation fails.
+        let Ok(h) = N::hash_to_group_psd2(&[N::serial_number_domain(), commitment]) else { return false };
+
+        // Retrieve the challenge and response.
+        let (challenge, response) = (self.signature.challenge(), self.signature.response());
+        // Compute `g_r` as `(response * G) + (challenge * pk_sig)`.
+        let g_r = N::g_scalar_multiply(&response) + (compute_key.pk_sig() * challenge);
+        // Compute `h_r` as `(response * H) + (challenge * gamma)`.
+        let h_r = (h * response) + (self.gamma * challenge);
+
+        // Return `true` if the candidate challenge is correct.
+        match Self::to_challenge(&compute_key, &address, [g_r, h, h_r, self.gamma], commitment, message) {
+            Ok(candidate_challenge) => challenge == candidate_challenge,
+            Err(_) => false,
+        }
+    }
+
+    /// Ensures the given unsigned integer entry of the record is at least `minimum`.
+    /// Note: This check is only meaningful after the proof is verified.
+    pub fn check_minimum(&self, member: &Identifier<N>, minimum: u128) -> Result<()> {
+        // Retrieve the entry.
+        let value = match self.record.data().get(member) {
+            Some(Entry::Constant(plaintext) | Entry::Public(plaintext) | Entry::Private(plaintext)) => {
+                match plaintext {
+                    Plaintext::Literal(Literal::U8(value), _) => **value as u128,
+                    Plaintext::Literal(Literal::U16(value), _) => **value as u128,
+                    Plaintext::Literal(Literal::U32(value), _) => **value as u128,
+                    Plaintext::Literal(Literal::U64(value), _) => **value as u128,
+                    Plaintext::Literal(Literal::U128(value), _) => **value,
+                    _ => bail!("Record entry '{member}' is not an unsigned integer"),
+                }
+            }
+            None => bail!("Record entry '{member}' not found"),
+        };
+        // Ensure the value is at least the minimum.
+        ensure!(value >= minimum, "Record entry '{member}' is {value}, which is less than {minimum}");
+        Ok(())
+    }
+}
+
+impl<N: Network> OwnershipProof<N> {
+    /// Returns the program ID of the record.
+    pub const fn program_id(&self) -> &ProgramID<N> {
+        &self.program_id
+    }
+
+    /// Returns the name of the record.
+    pub const fn record_name(&self) -> &Identifier<N> {
+        &self.record_name
+    }
+
+    /// Returns the record.
+    pub const fn record(&self) -> &Record<N, Plaintext<N>> {
+        &self.record
+    }
+
+    /// Returns the owner of the record.
+    pub fn owner(&self) -> Address<N> {
+        **self.record.owner()
+    }
+
+    /// Returns the serial number generator `gamma`.
+    pub const fn gamma(&self) -> &Group<N> {
+        &self.gamma
+    }
+
+    /// Returns the signature of the owner.
+    pub const fn signature(&self) -> &Signature<N> {
+        &self.signature
+    }
+
+    /// Returns the record commitment.
+    pub fn commitment(&self) -> Result<Field<N>> {
+        self.record.to_commitment(&self.program_id, &self.record_name)
+    }
+
+    /// Returns the serial number of the record.
+    pub fn serial_number(&self) -> Result<Field<N>> {
+        Record::<N, Plaintext<N>>::serial_number_from_gamma(&self.gamma, self.commitment()?)
+    }
+}
+
+impl<N: Network> OwnershipProof<N> {
+    /// Returns the challenge for the given compute key, address, points, commitment, and message.
+    fn to_challenge(
+        compute_key: &ComputeKey<N>,
+        address: &Address<N>,
+        [g_r, h, h_r, gamma]: [Group<N>; 4],
+        commitment: Field<N>,
+        message: &[Field<N>],
+    ) -> Result<Scalar<N>> {
+        // Ensure the number of field elements does not exceed the maximum allowed size.
+        ensure!(message.len() <= N::MAX_DATA_SIZE_IN_FIELDS as usize, "The message exceeds the maximum allowed size");
+
+        // Construct the hash input as `(domain, r * G, pk_sig, pr_sig, owner, H, r * H, gamma, commitment, message)`.
+        let mut preimage = Vec::with_capacity(9 + message.len());
+        preimage.push(Field::new_domain_separator("AleoOwnershipProof0"));
+        preimage.extend(
+            [g_r, compute_key.pk_sig(), compute_key.pr_sig(), **address, h, h_r, gamma]
+                .map(|point| point.to_x_coordinate()),
+        );
+        preimage.push(commitment);
+        preimage.extend(message);
+        // Hash to derive the challenge.
+        N::hash_to_scalar_psd8(&preimage)
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod test_helpers {
+    use super::*;
+    use snarkvm_console_network::MainnetV0;
+
+    type CurrentNetwork = MainnetV0;
+
+    /// Samples a private key, and an ownership proof of a record with the given amount.
+    pub(crate) fn sample_ownership_proof(
+        amount: u64,
+        message: &[Field<CurrentNetwork>],
+        rng: &mut TestRng,
+    ) -> (PrivateKey<CurrentNetwork>, OwnershipProof<CurrentNetwork>) {
+        // Sample a private key.
+        let private_key = PrivateKey::new(rng).unwrap();
+        let address = Address::try_from(&private_key).unwrap();
+        // Construct a record owned by the private key.
+        let record = Record::from_str(&format!(
+            "{{ owner: {address}.private, amount: {amount}u64.private, _nonce: {}.public }}",
+            Group::<CurrentNetwork>::rand(rng)
+        ))
+        .unwrap();
+        // Prove ownership of the record.
+        let program_id = ProgramID::from_str("token.aleo").unwrap();
+        let record_name = Identifier::from_str("token").unwrap();
+        let proof = OwnershipProof::prove(&private_key, program_id, record_name, record, message, rng).unwrap();
+        (private_key, proof)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::MainnetV0;
+
+    type CurrentNetwork = MainnetV0;
+
+    const ITERATIONS: u64 = 10;
+
+    #[test]
+    fn test_prove_and_verify() {
+        let rng = &mut TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            let message = [Field::rand(rng), Field::rand(rng)];
+            let (private_key, proof) = test_helpers::sample_ownership_proof(100, &message, rng);
+            assert!(proof.verify(&message));
+
+            // Ensure the proof does not verify for a different message.
+            assert!(!proof.verify(&[Field::rand(rng)]));
+            assert!(!proof.verify(&[]));
+
+            // Ensure the serial number matches the one derived from the private key.
+            let expected = Record::<CurrentNetwork, Plaintext<CurrentNetwork>>::serial_number(
+                private_key,
+                proof.commitment().unwrap(),
+            )
+            .unwrap();
+            assert_eq!(proof.serial_number().unwrap(), expected);
+        }
+    }
+
+    #[test]
+    fn test_prove_fails_for_wrong_owner() {
+        let rng = &mut TestRng::default();
+
+        let (_, proof) = test_helpers::sample_ownership_proof(100, &[], rng);
+        // Ensure another private key cannot prove ownership of the record.
+        let private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+        let result = OwnershipProof::prove(
+            &private_key,
+            *proof.program_id(),
+            *proof.record_name(),
+            proof.record().clone(),
+            &[],
+            rng,
+        );
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_verify_fails_for_tampered_proof() {
+        let rng = &mut TestRng::default();
+
+        let (_, proof) = test_helpers::sample_ownership_proof(100, &[], rng);
+        assert!(proof.verify(&[]));
+
+        // Ensure the proof does not verify for a different gamma.
+        let mut tampered = proof.clone();
+        tampered.gamma = Group::rand(rng);
+        assert!(!tampered.verify(&[]));
+
+        // Ensure the proof does not verify for a different record name.
+        let mut tampered = proof.clone();
+        tampered.record_name = Identifier::from_str("credits").unwrap();
+        assert!(!tampered.verify(&[]));
+
+        // Ensure the proof does not verify for a different record.
+        let (_, other) = test_helpers::sample_ownership_proof(100, &[], rng);
+        let mut tampered = proof;
+        tampered.record = other.record().clone();
+        assert!(!tampered.verify(&[]));
+    }
+
+    #[test]
+    fn test_check_minimum() {
+        let rng = &mut TestRng::default();
+
+        let (_, proof) = test_helpers::sample_ownership_proof(100, &[], rng);
+        let amount = Identifier::from_str("amount").unwrap();
+        assert!(proof.check_minimum(&amount, 0).is_ok());
+        assert!(proof.check_minimum(&amount, 100).is_ok());
+        assert!(proof.check_minimum(&amount, 101).is_err());
+        assert!(proof.check_minimum(&Identifier::from_str("balance").unwrap(), 0).is_err());
+    }
+}
@@ -0,0 +1,284 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{FinalizeType, PlaintextType, ProgramID, Register, RegisterType, ValueType};
+use snarkvm_console_network::prelude::*;
+
+/// A position in a program string, where both the line and the column start from 1.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct ProgramSpan {
+    /// The line number.
+    line: usize,
+    /// The column number, in characters.
+    column: usize,
+}
+
+impl ProgramSpan {
+    /// Initializes a new span from a line and column.
+    pub const fn new(line: usize, column: usize) -> Self {
+        Self { line, column }
+    }
+
+    /// Initializes a new span for the given position (in bytes) in the program string.
+    pub fn from_position(program: &str, position: usize) -> Self {
+        let prefix = &program[..floor_char_boundary(program, position)];
+        let line = prefix.matches('\n').count() + 1;
+        let column = prefix.rsplit('\n').next().map_or(0, |line| line.chars().count()) + 1;
+        Self { line, column }
+    }
+
+    /// Returns the line number.
+    pub const fn line(&self) -> usize {
+        self.line
+    }
+
+    /// Returns the column number.
+    pub const fn column(&self) -> usize {
+        self.column
+    }
+}
+
+impl Display for ProgramSpan {
+    /// Prints the span as `line:column`.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "{}:{}", self.line, self.column)
+    }
+}
+
+/// An error from parsing a program, with the span and the offending token of the failure.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ProgramParseError {
+    /// The program exceeds the maximum program size, starting from the token.
+    ProgramTooLarge { span: ProgramSpan, token: String },
+    /// The import is not a valid program ID.
+    InvalidImport { span: ProgramSpan, token: String },
+    /// The program ID is referenced, but is not imported.
+    UnknownImport { span: ProgramSpan, token: String },
+    /// The program declaration is not a valid program ID.
+    InvalidProgramID { span: ProgramSpan, token: String },
+    /// The register is malformed.
+    InvalidRegister { span: ProgramSpan, token: String },
+    /// The type is malformed.
+    InvalidType { span: ProgramSpan, token: String },
+    /// The declaration was parsed, but could not be added to the program.
+    InvalidDeclaration { span: ProgramSpan, token: String, reason: String },
+    /// The token could not be parsed.
+    UnexpectedToken { span: ProgramSpan, token: String },
+}
+
+impl ProgramParseError {
+    /// Initializes an error for a program string that exceeds the given maximum size.
+    pub fn program_too_large(program: &str, max_size: usize) -> Self {
+        let position = word_start(program, max_size);
+        Self::ProgramTooLarge { span: ProgramSpan::from_position(program, position), token: word_at(program, position) }
+    }
+
+    /// Initializes an error for a declaration at the given position, that could not be added to the program.
+    pub fn invalid_declaration(program: &str, position: usize, token: impl Display, reason: impl Display) -> Self {
+        Self::InvalidDeclaration {
+            span: ProgramSpan::from_position(program, position),
+            token: token.to_string(),
+            reason: reason.to_string(),
+        }
+    }
+
+    /// Initializes an error for a program ID at the given position, that is referenced but is not imported.
+    pub fn unknown_import(program: &str, position: usize, program_id: impl Display) -> Self {
+        Self::UnknownImport { span: ProgramSpan::from_position(program, position), token: program_id.to_string() }
+    }
+
+    /// Initializes an error for a program string that failed to parse at the given position.
+    ///
+    /// The statement at the position is scanned for a malformed program ID, register, or type,
+    /// so that the error points at the offending token rather than at the start of the statement.
+    pub fn diagnose<N: Network>(program: &str, position: usize) -> Self {
+        // Move the position to the start of the word it is in.
+        let position = word_start(program, position);
+        // Retrieve the end of the statement at the position.
+        let end = program[position..].find(';').map_or(program.len(), |end| position + end);
+
+        // Scan the words of the statement, keeping track of the preceding word.
+        let mut previous = program[..position].split_whitespace().last();
+        let mut offset = position;
+        for word in program[position..end].split_whitespace() {
+            // Retrieve the position of the word.
+            let start = offset + program[offset..].find(word).unwrap_or_default();
+            offset = start + word.len();
+
+            let span = ProgramSpan::from_position(program, start);
+            let token = word.to_string();
+            match previous {
+                Some("import") if ProgramID::<N>::from_str(word).is_err() => {
+                    return Self::InvalidImport { span, token }
+                }
+                Some("program") if ProgramID::<N>::from_str(word).is_err() => {
+                    return Self::InvalidProgramID { span, token };
+                }
+                Some("as") if !is_type::<N>(word) => return Self::InvalidType { span, token },
+                _ if is_register_like(word) && Register::<N>::from_str(word).is_err() => {
+                    return Self::InvalidRegister { span, token };
+                }
+                _ => previous = Some(word),
+            }
+        }
+
+        Self::UnexpectedToken { span: ProgramSpan::from_position(program, position), token: word_at(program, position) }
+    }
+
+    /// Returns the span of the offending token.
+    pub const fn span(&self) -> ProgramSpan {
+        match self {
+            Self::ProgramTooLarge { span, .. }
+            | Self::InvalidImport { span, .. }
+            | Self::UnknownImport { span, .. }
+            | Self::InvalidProgramID { span, .. }
+            | Self::InvalidRegister { span, .. }
+            | Self::InvalidType { span, .. }
+            | Self::InvalidDeclaration { span, .. }
+            | Self::UnexpectedToken { span, .. } => *span,
+        }
+    }
+
+    /// Returns the offending token.
+    pub fn token(&self) -> &str {
+        match self {
+            Self::ProgramTooLarge { token, .. }
+            | Self::InvalidImport { token, .. }
+            | Self::UnknownImport { token, .. }
+            | Self::InvalidProgramID { token, .. }
+            | Self::InvalidRegister { token, .. }
+            | Self::InvalidType { token, .. }
+            | Self::InvalidDeclaration { token, .. }
+            | Self::UnexpectedToken { token, .. } => token,
+        }
+    }
+}
+
+impl Display for ProgramParseError {
+    /// Prints the parse error.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let span = self.span();
+        match self {
+            Self::ProgramTooLarge { .. } => write!(f, "Program exceeds the maximum program size at {span}"),
+            Self::InvalidImport { token, .. } => write!(f, "Invalid import '{token}' at {span}"),
+            Self::UnknownImport { token, .. } => {
+                write!(f, "Program '{token}' is referenced at {span}, but is not imported")
+            }
+            Self::InvalidProgramID { token, .. } => write!(f, "Invalid program ID '{token}' at {span}"),
+            Self::InvalidRegister { token, .. } => write!(f, "Invalid register '{token}' at {span}"),
+            Self::InvalidType { token, .. } => write!(f, "Invalid type '{token}' at {span}"),
+            Self::InvalidDeclaration { token, reason, .. } => {
+                write!(f, "Invalid declaration '{token}' at {span}: {reason}")
+            }
+            Self::UnexpectedToken { token, .. } => match token.is_empty() {
+                true => write!(f, "Unexpected end of program at {span}"),
+                false => write!(f, "Unexpected token '{token}' at {span}"),
+            },
+        }
+    }
+}
+
+impl std::error::Error for ProgramParseError {}
+
+/// Returns the largest character boundary in the string that is at most the given position.
+fn floor_char_boundary(string: &str, position: usize) -> usize {
+    let mut position = position.min(string.len());
+    while !string.is_char_boundary(position) {
+        position -= 1;
+    }
+    position
+}
+
+/// Returns the start of the word that contains the given position.
+fn word_start(string: &str, position: usize) -> usize {
+    let position = floor_char_boundary(string, position);
+    string[..position].rfind(char::is_whitespace).map_or(0, |start| start + 1)
+}
+
+/// Returns the word starting at the given position, excluding a trailing semicolon.
+fn word_at(string: &str, position: usize) -> String {
+    let word = string[position..].split_whitespace().next().unwrap_or_default();
+    match word.strip_suffix(';') {
+        Some(word) if !word.is_empty() => word.to_string(),
+        _ => word.to_string(),
+    }
+}
+
+/// Returns `true` if the word starts like a register, i.e. `r` followed by a digit.
+fn is_register_like(word: &str) -> bool {
+    let mut characters = word.chars();
+    characters.next() == Some('r') && characters.next().is_some_and(|character| character.is_ascii_digit())
+}
+
+/// Returns `true` if the word is a valid type in any of the positions that a type may appear.
+fn is_type<N: Network>(word: &str) -> bool {
+    matches!(word, "group.x" | "group.y")
+        || PlaintextType::<N>::from_str(word).is_ok()
+        || ValueType::<N>::from_str(word).is_ok()
+        || RegisterType::<N>::from_str(word).is_ok()
+        || FinalizeType::<N>::from_str(word).is_ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::MainnetV0;
+
+    type CurrentNetwork = MainnetV0;
+
+    #[test]
+    fn test_span_from_position() {
+        let program = "program foo.aleo;\n\nfunction bar:\n    input r0 as u8.private;";
+        assert_eq!(ProgramSpan::from_position(program, 0), ProgramSpan::new(1, 1));
+        assert_eq!(ProgramSpan::from_position(program, 8), ProgramSpan::new(1, 9));
+        assert_eq!(ProgramSpan::from_position(program, 19), ProgramSpan::new(3, 1));
+        assert_eq!(ProgramSpan::from_position(program, 37), ProgramSpan::new(4, 5));
+        assert_eq!(ProgramSpan::from_position(program, usize::MAX), ProgramSpan::new(4, 28));
+    }
+
+    #[test]
+    fn test_diagnose() {
+        // Ensure a malformed type is reported at the type.
+        let program = "program foo.aleo;\n\nfunction bar:\n    input r0 as u8.privat;";
+        let error = ProgramParseError::diagnose::<CurrentNetwork>(program, 37);
+        assert_eq!(error, ProgramParseError::InvalidType { span: ProgramSpan::new(4, 17), token: "u8.privat".into() });
+        assert_eq!(error.to_string(), "Invalid type 'u8.privat' at 4:17");
+
+        // Ensure a malformed register is reported at the register.
+        let program = "program foo.aleo;\n\nfunction bar:\n    add r0 r1x into r2;";
+        let error = ProgramParseError::diagnose::<CurrentNetwork>(program, 37);
+        assert_eq!(error, ProgramParseError::InvalidRegister { span: ProgramSpan::new(4, 12), token: "r1x".into() });
+
+        // Ensure a malformed program ID is reported, from a position within the word.
+        let program = "program foo;";
+        let error = ProgramParseError::diagnose::<CurrentNetwork>(program, 11);
+        assert_eq!(error, ProgramParseError::InvalidProgramID { span: ProgramSpan::new(1, 9), token: "foo".into() });
+
+        // Ensure a malformed import is reported at the import.
+        let program = "import foo;\nprogram bar.aleo;";
+        let error = ProgramParseError::diagnose::<CurrentNetwork>(program, 0);
+        assert_eq!(error, ProgramParseError::InvalidImport { span: ProgramSpan::new(1, 8), token: "foo".into() });
+
+        // Ensure an unknown statement is reported as an unexpected token.
+        let program = "program foo.aleo;\n\nfoo bar;";
+        let error = ProgramParseError::diagnose::<CurrentNetwork>(program, 19);
+        assert_eq!(error, ProgramParseError::UnexpectedToken { span: ProgramSpan::new(3, 1), token: "foo".into() });
+        assert_eq!(error.to_string(), "Unexpected token 'foo' at 3:1");
+
+        // Ensure the end of the program is reported as an unexpected token.
+        let error = ProgramParseError::diagnose::<CurrentNetwork>("", 0);
+        assert_eq!(error.to_string(), "Unexpected end of program at 1:1");
+    }
+}
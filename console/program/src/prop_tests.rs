@@ -0,0 +1,185 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Proptest strategies for the program types, enabled by the `prop-tests` feature.
+//!
+//! The sampled values are structurally valid, i.e. they can be serialized, parsed, and encrypted,
+//! but they are not necessarily well-typed with respect to a program.
+
+use super::*;
+
+use indexmap::IndexMap;
+use proptest::{
+    collection::vec,
+    prelude::{any, prop_oneof, Arbitrary, BoxedStrategy, Strategy},
+    sample::select,
+};
+
+/// The maximum number of entries in a sampled struct, array, or record.
+const MAX_SAMPLED_ENTRIES: usize = 4;
+/// The maximum nesting depth of a sampled plaintext.
+const MAX_SAMPLED_DEPTH: u32 = 3;
+
+/// Returns a strategy for identifiers.
+pub fn any_identifier<N: Network>() -> BoxedStrategy<Identifier<N>> {
+    "[a-zA-Z][a-zA-Z0-9_]{0,15}"
+        .prop_filter_map("Invalid identifier", |string| Identifier::from_str(&string).ok())
+        .boxed()
+}
+
+/// Returns a strategy for program IDs.
+pub fn any_program_id<N: Network>() -> BoxedStrategy<ProgramID<N>> {
+    "[a-z][a-z0-9_]{0,15}"
+        .prop_filter_map("Invalid program ID", |name| ProgramID::from_str(&format!("{name}.aleo")).ok())
+        .boxed()
+}
+
+/// Returns a strategy for literals of the given type.
+pub fn any_literal_of_type<N: Network>(literal_type: LiteralType) -> BoxedStrategy<Literal<N>> {
+    any::<u64>().prop_map(move |seed| Literal::sample(literal_type, &mut TestRng::fixed(seed))).boxed()
+}
+
+/// Returns a strategy for literals of any type.
+pub fn any_literal<N: Network>() -> BoxedStrategy<Literal<N>> {
+    (select(enum_iterator::all::<LiteralType>().collect::<Vec<_>>()), any::<u64>())
+        .prop_map(|(literal_type, seed)| Literal::sample(literal_type, &mut TestRng::fixed(seed)))
+        .boxed()
+}
+
+/// Returns a strategy for plaintexts, with literals, structs, and arrays nested up to a fixed depth.
+pub fn any_plaintext<N: Network>() -> BoxedStrategy<Plaintext<N>> {
+    any_literal::<N>()
+        .prop_map(Plaintext::from)
+        .prop_recursive(MAX_SAMPLED_DEPTH, 32, MAX_SAMPLED_ENTRIES as u32, |inner| {
+            prop_oneof![
+                vec((any_identifier::<N>(), inner.clone()), 1..=MAX_SAMPLED_ENTRIES)
+                    .prop_map(|members| Plaintext::Struct(members.into_iter().collect(), Default::default())),
+                vec(inner, 1..=MAX_SAMPLED_ENTRIES).prop_map(|elements| Plaintext::Array(elements, Default::default())),
+            ]
+        })
+        .boxed()
+}
+
+/// Returns a strategy for plaintext records, with public or private owners and entries.
+pub fn any_record<N: Network>() -> BoxedStrategy<Record<N, Plaintext<N>>> {
+    let entry = (any_plaintext::<N>(), any::<bool>()).prop_map(|(plaintext, is_public)| match is_public {
+        true => Entry::Public(plaintext),
+        false => Entry::Private(plaintext),
+    });
+    (any::<u64>(), any::<bool>(), vec((any_identifier::<N>(), entry), 0..=MAX_SAMPLED_ENTRIES))
+        .prop_filter_map("Invalid record", |(seed, is_public, entries)| {
+            let rng = &mut TestRng::fixed(seed);
+            // Sample the owner.
+            let address = Address::rand(rng);
+            let owner = match is_public {
+                true => Owner::Public(address),
+                false => Owner::Private(Plaintext::from(Literal::Address(address))),
+            };
+            // Construct the record, skipping the entries with a duplicate or reserved name.
+            let data = entries.into_iter().collect::<IndexMap<_, _>>();
+            Record::from_plaintext(owner, data, Group::rand(rng)).ok()
+        })
+        .boxed()
+}
+
+/// Returns a strategy for plaintext and record values.
+pub fn any_value<N: Network>() -> BoxedStrategy<Value<N>> {
+    prop_oneof![any_plaintext::<N>().prop_map(Value::Plaintext), any_record::<N>().prop_map(Value::Record)].boxed()
+}
+
+impl<N: Network> Arbitrary for Identifier<N> {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Identifier<N>>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        any_identifier()
+    }
+}
+
+impl<N: Network> Arbitrary for ProgramID<N> {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<ProgramID<N>>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        any_program_id()
+    }
+}
+
+impl<N: Network> Arbitrary for Literal<N> {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Literal<N>>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        any_literal()
+    }
+}
+
+impl<N: Network> Arbitrary for Plaintext<N> {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Plaintext<N>>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        any_plaintext()
+    }
+}
+
+impl<N: Network> Arbitrary for Record<N, Plaintext<N>> {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Record<N, Plaintext<N>>>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        any_record()
+    }
+}
+
+impl<N: Network> Arbitrary for Value<N> {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Value<N>>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        any_value()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::MainnetV0;
+
+    use test_strategy::proptest;
+
+    type CurrentNetwork = MainnetV0;
+
+    #[proptest]
+    fn identifier_roundtrip(identifier: Identifier<CurrentNetwork>) {
+        assert_eq!(Identifier::from_str(&identifier.to_string()).unwrap(), identifier);
+    }
+
+    #[proptest]
+    fn program_id_roundtrip(program_id: ProgramID<CurrentNetwork>) {
+        assert_eq!(ProgramID::from_str(&program_id.to_string()).unwrap(), program_id);
+    }
+
+    #[proptest]
+    fn literal_roundtrip(literal: Literal<CurrentNetwork>) {
+        assert_eq!(Literal::from_bytes_le(&literal.to_bytes_le().unwrap()).unwrap(), literal);
+    }
+
+    #[proptest(cases = 32)]
+    fn value_roundtrip(value: Value<CurrentNetwork>) {
+        assert_eq!(Value::from_str(&value.to_string()).unwrap(), value);
+        assert_eq!(Value::from_bytes_le(&value.to_bytes_le().unwrap()).unwrap(), value);
+    }
+}
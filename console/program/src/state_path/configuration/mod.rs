@@ -13,7 +13,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use snarkvm_console_collections::merkle_tree::MerklePath;
+use snarkvm_console_collections::merkle_tree::{MerkleMultiPath, MerklePath};
 use snarkvm_console_network::BHPMerkleTree;
 
 /// The depth of the Merkle tree for the blocks.
@@ -41,6 +41,8 @@ pub const TRANSITION_DEPTH: u8 = 5;
 pub type BlockTree<N> = BHPMerkleTree<N, BLOCKS_DEPTH>;
 /// The Merkle path for the state tree blocks.
 pub type BlockPath<N> = MerklePath<N, BLOCKS_DEPTH>;
+/// The Merkle multi-path for many blocks in the state tree.
+pub type BlockMultiPath<N> = MerkleMultiPath<N, BLOCKS_DEPTH>;
 
 /// The Merkle tree for the block header.
 pub type HeaderTree<N> = BHPMerkleTree<N, HEADER_DEPTH>;
@@ -56,6 +58,8 @@ pub type RatificationsPath<N> = MerklePath<N, RATIFICATIONS_DEPTH>;
 pub type TransactionsTree<N> = BHPMerkleTree<N, TRANSACTIONS_DEPTH>;
 /// The Merkle path for a transaction in a block.
 pub type TransactionsPath<N> = MerklePath<N, TRANSACTIONS_DEPTH>;
+/// The Merkle multi-path for many transactions in a block.
+pub type TransactionsMultiPath<N> = MerkleMultiPath<N, TRANSACTIONS_DEPTH>;
 
 /// The Merkle tree for the transaction.
 pub type TransactionTree<N> = BHPMerkleTree<N, TRANSACTION_DEPTH>;
@@ -282,7 +282,7 @@ pub mod test_helpers {
 
         let previous_block_hash: N::BlockHash = Field::<N>::rand(rng).into();
         let preimage = (*previous_block_hash).to_bits_le().into_iter().chain(header_root.to_bits_le());
-        let block_hash = N::hash_bhp1024(&preimage.collect::<Vec<_>>())?;
+        let block_hash = N::hash_bhp1024_iter(preimage)?;
 
         // Construct the global state root and block path.
         let block_tree: BlockTree<N> = N::merkle_tree_bhp(&[block_hash.to_bits_le()])?;
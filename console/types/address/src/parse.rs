@@ -41,19 +41,14 @@ impl<E: Environment> FromStr for Address<E> {
     fn from_str(address: &str) -> Result<Self, Self::Err> {
         // Ensure the address string length is 63 characters.
         if address.len() != 63 {
-            bail!("Invalid account address length: found {}, expected 63", address.len())
+            bail!(ParseError::new("address", address, address.len().min(63))
+                .with_reason(format!("Invalid account address length: found {}, expected 63", address.len())))
         }
         // Decode the address string from bech32m.
-        let (hrp, data, variant) = bech32::decode(address)?;
-        if hrp != ADDRESS_PREFIX {
-            bail!("Failed to decode address: '{hrp}' is an invalid prefix")
-        } else if data.is_empty() {
-            bail!("Failed to decode address: data field is empty")
-        } else if variant != bech32::Variant::Bech32m {
-            bail!("Found an address that is not bech32m encoded: {address}");
-        }
-        // Decode the address data from u5 to u8, and into an account address.
-        Ok(Self::read_le(&Vec::from_base32(&data)?[..])?)
+        let bytes = decode_bech32m(address, ADDRESS_PREFIX)
+            .map_err(|error| ParseError::new("address", address, 0).with_reason(error))?;
+        // Decode the bytes into an account address.
+        Ok(Self::read_le(&bytes[..])?)
     }
 }
 
@@ -69,8 +64,7 @@ impl<E: Environment> Display for Address<E> {
         // Convert the address to bytes.
         let bytes = self.to_bytes_le().map_err(|_| fmt::Error)?;
         // Encode the bytes into bech32m.
-        let string =
-            bech32::encode(ADDRESS_PREFIX, bytes.to_base32(), bech32::Variant::Bech32m).map_err(|_| fmt::Error)?;
+        let string = encode_bech32m(ADDRESS_PREFIX, &bytes).map_err(|_| fmt::Error)?;
         // Output the string.
         Display::fmt(&string, f)
     }
@@ -139,4 +133,22 @@ mod tests {
         }
         Ok(())
     }
+
+    #[test]
+    fn test_parse_error() {
+        // Ensure a string with the prefix of a different type reports a hint.
+        let string = encode_bech32m("sign", &[0u8; 128]).unwrap();
+        let error = Address::<CurrentEnvironment>::from_str(&string).unwrap_err();
+        let error = error.downcast_ref::<ParseError>().unwrap();
+        assert_eq!(error.expected(), "address");
+        assert_eq!(error.hint(), Some("The prefix 'sign' indicates a signature, not an address"));
+
+        // Ensure a corrupted address reports the reason.
+        let mut string = Address::<CurrentEnvironment>::rand(&mut TestRng::default()).to_string();
+        string.replace_range(62.., if string.ends_with('q') { "p" } else { "q" });
+        let error = Address::<CurrentEnvironment>::from_str(&string).unwrap_err();
+        let error = error.downcast_ref::<ParseError>().unwrap();
+        assert!(error.reason().is_some());
+        assert!(error.hint().is_none());
+    }
 }
@@ -0,0 +1,257 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! The benchmark suite for tracking performance regressions across releases.
+//!
+//! Run with `cargo bench -p snarkvm-ledger --features benchmarks --bench suite`.
+//!
+//! In addition to the criterion reports, the suite writes a JSON summary of the benchmarks to
+//! the path in `SNARKVM_BENCHMARK_OUTPUT`, or to `suite.json` in the criterion output directory.
+
+use console::{account::Address, network::MainnetV0, prelude::*, program::Value};
+use ledger_block::{Block, Transaction};
+use synthesizer::vm::test_fixtures::TestFixtures;
+
+use snarkvm_algorithms::{
+    fft::{DensePolynomial, EvaluationDomain},
+    msm::VariableBase,
+};
+use snarkvm_curves::bls12_377::{Fr, G1Affine};
+use snarkvm_fields::PrimeField;
+
+use criterion::{BatchSize, BenchmarkId, Criterion};
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+type CurrentNetwork = MainnetV0;
+
+/// The prefix of the benchmark groups in the suite.
+const SUITE_PREFIX: &str = "suite";
+/// The environment variable for the path of the JSON summary.
+const OUTPUT_VAR: &str = "SNARKVM_BENCHMARK_OUTPUT";
+/// The sizes of the MSM benchmarks.
+const MSM_SIZES: [usize; 3] = [1 << 10, 1 << 14, 1 << 16];
+/// The degrees of the FFT benchmarks.
+const FFT_DEGREES: [usize; 3] = [1 << 12, 1 << 16, 1 << 18];
+
+/// Returns the inputs to transfer 1 microcredit to the given address, with `credits.aleo/transfer_public`.
+fn transfer_public_inputs(address: &Address<CurrentNetwork>) -> [Value<CurrentNetwork>; 2] {
+    [Value::from_str(&address.to_string()).unwrap(), Value::from_str("1u64").unwrap()]
+}
+
+/// Benchmarks the execution of `credits.aleo/transfer_public`.
+fn transfer_execution(c: &mut Criterion, fixtures: &TestFixtures<CurrentNetwork>) {
+    let rng = &mut fixtures.rng(100);
+
+    let vm = fixtures.vm_with_genesis_block().unwrap();
+    let private_key = fixtures.private_key();
+    let inputs = transfer_public_inputs(&Address::try_from(private_key).unwrap());
+    let locator = ("credits.aleo", "transfer_public");
+
+    let mut group = c.benchmark_group(format!("{SUITE_PREFIX}/transfer_execution"));
+    group.sample_size(10);
+    group.bench_function("VM::authorize", |b| {
+        b.iter(|| vm.authorize(private_key, locator.0, locator.1, inputs.iter(), rng).unwrap())
+    });
+    group.bench_function("VM::execute", |b| {
+        b.iter(|| vm.execute(private_key, locator, inputs.iter(), None, 0, None, rng).unwrap())
+    });
+    group.finish();
+}
+
+/// Benchmarks the verification of `credits.aleo` transactions.
+fn credits_verification(c: &mut Criterion, fixtures: &TestFixtures<CurrentNetwork>) {
+    let rng = &mut fixtures.rng(101);
+
+    let vm = fixtures.vm_with_genesis_block().unwrap();
+    let transactions = [
+        ("split", fixtures.execution_transaction_without_fee().unwrap()),
+        ("transfer_public (private fee)", fixtures.execution_transaction_with_private_fee().unwrap()),
+        ("transfer_public (public fee)", fixtures.execution_transaction_with_public_fee().unwrap()),
+    ];
+
+    let mut group = c.benchmark_group(format!("{SUITE_PREFIX}/credits_verification"));
+    group.sample_size(10);
+    for (name, transaction) in &transactions {
+        group.bench_with_input(BenchmarkId::new("VM::check_transaction", name), transaction, |b, transaction| {
+            b.iter(|| vm.check_transaction(transaction, None, rng).unwrap())
+        });
+    }
+    group.finish();
+}
+
+/// Benchmarks the finalization of a block.
+fn block_finalize(c: &mut Criterion, fixtures: &TestFixtures<CurrentNetwork>) {
+    let rng = &mut fixtures.rng(102);
+
+    // Construct a block on top of the genesis block.
+    let vm = fixtures.vm_with_genesis_block().unwrap();
+    let transactions = [
+        fixtures.execution_transaction_with_private_fee().unwrap(),
+        fixtures.execution_transaction_with_public_fee().unwrap(),
+    ];
+    let block = fixtures.next_block(&vm, &transactions, rng).unwrap();
+
+    let mut group = c.benchmark_group(format!("{SUITE_PREFIX}/block_finalize"));
+    group.sample_size(10);
+    group.bench_function("VM::add_next_block", |b| {
+        b.iter_batched(
+            || fixtures.vm_with_genesis_block().unwrap(),
+            |vm| vm.add_next_block(&block).unwrap(),
+            BatchSize::PerIteration,
+        )
+    });
+    group.finish();
+}
+
+/// Benchmarks the MSM and FFT kernels on BLS12-377.
+fn kernels(c: &mut Criterion) {
+    let rng = &mut TestRng::fixed(103);
+
+    // Sample the bases and scalars for the largest MSM.
+    let max_size = MSM_SIZES[MSM_SIZES.len() - 1];
+    let bases = (0..max_size).map(|_| G1Affine::rand(rng)).collect::<Vec<_>>();
+    let scalars = (0..max_size).map(|_| Fr::rand(rng).to_bigint()).collect::<Vec<_>>();
+
+    let mut group = c.benchmark_group(format!("{SUITE_PREFIX}/kernels"));
+    for size in MSM_SIZES {
+        group.bench_with_input(BenchmarkId::new("VariableBase::msm", size), &size, |b, size| {
+            b.iter(|| VariableBase::msm(&bases[..*size], &scalars[..*size]))
+        });
+    }
+    for degree in FFT_DEGREES {
+        let domain = EvaluationDomain::<Fr>::new(degree).unwrap();
+        let coeffs = DensePolynomial::<Fr>::rand(degree - 1, rng).coeffs().to_vec();
+        group.bench_with_input(BenchmarkId::new("EvaluationDomain::fft", degree), &coeffs, |b, coeffs| {
+            b.iter(|| domain.fft(coeffs))
+        });
+        group.bench_with_input(BenchmarkId::new("EvaluationDomain::ifft", degree), &coeffs, |b, coeffs| {
+            b.iter(|| domain.ifft(coeffs))
+        });
+    }
+    group.finish();
+}
+
+/// Benchmarks the serialization of the given object.
+fn bench_serialization<T: Serialize + DeserializeOwned + ToBytes + FromBytes>(
+    c: &mut Criterion,
+    name: &str,
+    object: &T,
+) {
+    let bytes = object.to_bytes_le().unwrap();
+    let string = serde_json::to_string(object).unwrap();
+
+    let mut group = c.benchmark_group(format!("{SUITE_PREFIX}/serialization"));
+    group.bench_function(format!("{name}::to_bytes_le"), |b| b.iter(|| object.to_bytes_le().unwrap()));
+    group.bench_function(format!("{name}::from_bytes_le"), |b| b.iter(|| T::from_bytes_le(&bytes).unwrap()));
+    group.bench_function(format!("{name}::to_string (serde_json)"), |b| {
+        b.iter(|| serde_json::to_string(object).unwrap())
+    });
+    group.bench_function(format!("{name}::from_str (serde_json)"), |b| {
+        b.iter(|| serde_json::from_str::<T>(&string).unwrap())
+    });
+    group.finish();
+}
+
+/// Benchmarks the serialization of a transaction and a block.
+fn serialization(c: &mut Criterion, fixtures: &TestFixtures<CurrentNetwork>) {
+    let transaction: Transaction<CurrentNetwork> = fixtures.execution_transaction_with_public_fee().unwrap();
+    let block: &Block<CurrentNetwork> = fixtures.genesis_block();
+
+    bench_serialization(c, "Transaction", &transaction);
+    bench_serialization(c, "Block", block);
+}
+
+/// Returns the criterion output directory.
+fn criterion_directory() -> PathBuf {
+    if let Some(path) = std::env::var_os("CRITERION_HOME") {
+        return PathBuf::from(path);
+    }
+    if let Some(path) = std::env::var_os("CARGO_TARGET_DIR") {
+        return PathBuf::from(path).join("criterion");
+    }
+    // The benchmark binary is located at `target/<profile>/deps/<binary>`.
+    let executable = std::env::current_exe().unwrap();
+    executable.ancestors().nth(3).map(|target| target.join("criterion")).unwrap_or_else(|| "criterion".into())
+}
+
+/// Collects the estimates of the benchmarks in the suite, from the given criterion output directory.
+fn collect_estimates(directory: &Path, summary: &mut serde_json::Map<String, serde_json::Value>) -> Result<()> {
+    let Ok(entries) = fs::read_dir(directory) else { return Ok(()) };
+    for entry in entries {
+        let path = entry?.path();
+        if !path.is_dir() {
+            continue;
+        }
+        // A benchmark directory contains the latest run in `new`.
+        let benchmark = path.join("new").join("benchmark.json");
+        let estimates = path.join("new").join("estimates.json");
+        if benchmark.is_file() && estimates.is_file() {
+            let benchmark: serde_json::Value = serde_json::from_slice(&fs::read(benchmark)?)?;
+            let estimates: serde_json::Value = serde_json::from_slice(&fs::read(estimates)?)?;
+            let (Some(id), Some(group)) = (benchmark["full_id"].as_str(), benchmark["group_id"].as_str()) else {
+                continue;
+            };
+            if group.starts_with(SUITE_PREFIX) {
+                summary.insert(
+                    id.to_string(),
+                    serde_json::json!({
+                        "mean_ns": estimates["mean"]["point_estimate"],
+                        "median_ns": estimates["median"]["point_estimate"],
+                        "std_dev_ns": estimates["std_dev"]["point_estimate"],
+                    }),
+                );
+            }
+        } else {
+            collect_estimates(&path, summary)?;
+        }
+    }
+    Ok(())
+}
+
+/// Writes the JSON summary of the benchmarks in the suite.
+fn write_summary() -> Result<()> {
+    let directory = criterion_directory();
+    let mut benchmarks = serde_json::Map::new();
+    collect_estimates(&directory, &mut benchmarks)?;
+
+    let summary = serde_json::json!({
+        "version": env!("CARGO_PKG_VERSION"),
+        "network": CurrentNetwork::NAME,
+        "benchmarks": benchmarks,
+    });
+    let output = std::env::var_os(OUTPUT_VAR).map(PathBuf::from).unwrap_or_else(|| directory.join("suite.json"));
+    fs::write(&output, serde_json::to_string_pretty(&summary)?)?;
+    println!("Wrote the benchmark summary to '{}'", output.display());
+    Ok(())
+}
+
+fn main() {
+    let mut c = Criterion::default().configure_from_args();
+
+    // Sample the fixtures once, as they are shared across the benchmarks.
+    let fixtures = TestFixtures::<CurrentNetwork>::sample().unwrap();
+
+    transfer_execution(&mut c, &fixtures);
+    credits_verification(&mut c, &fixtures);
+    block_finalize(&mut c, &fixtures);
+    kernels(&mut c);
+    serialization(&mut c, &fixtures);
+
+    c.final_summary();
+    write_summary().unwrap();
+}
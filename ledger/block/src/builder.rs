@@ -0,0 +1,205 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use synthesizer_program::{FinalizeGlobalState, FinalizeOperation};
+
+/// A builder for the next beacon block, which computes the block metadata and roots from the
+/// previous block and the output of `VM::speculate_with_abort_reasons`.
+///
+/// The intended usage is:
+///  1. Initialize the builder with `BlockBuilder::new` (or `BlockBuilder::new_with_solutions`).
+///  2. Speculate using `BlockBuilder::finalize_global_state` and `BlockBuilder::coinbase_reward`.
+///  3. Construct the block with `BlockBuilder::build_beacon`, passing in the speculation output.
+#[derive(Clone)]
+pub struct BlockBuilder<N: Network> {
+    /// The previous block hash.
+    previous_hash: N::BlockHash,
+    /// The previous state root.
+    previous_state_root: N::StateRoot,
+    /// The solutions for the next block.
+    solutions: Solutions<N>,
+    /// The aborted solution IDs for the next block.
+    aborted_solution_ids: Vec<SolutionID<N>>,
+    /// The coinbase reward for the next block.
+    coinbase_reward: u64,
+    /// The metadata for the next block.
+    metadata: Metadata<N>,
+}
+
+impl<N: Network> BlockBuilder<N> {
+    /// Initializes a builder for the block following `previous_block`, without solutions.
+    pub fn new(previous_block: &Block<N>, previous_state_root: N::StateRoot, timestamp: i64) -> Result<Self> {
+        Self::new_with_solutions(previous_block, previous_state_root, timestamp, None, 0, vec![])
+    }
+
+    /// Initializes a builder for the block following `previous_block`, with the given (verified) solutions.
+    ///
+    /// The `combined_proof_target` must be the combined proof target of the given solutions,
+    /// as computed by `Puzzle::get_combined_proof_target`.
+    pub fn new_with_solutions(
+        previous_block: &Block<N>,
+        previous_state_root: N::StateRoot,
+        timestamp: i64,
+        solutions: Option<PuzzleSolutions<N>>,
+        combined_proof_target: u128,
+        aborted_solution_ids: Vec<SolutionID<N>>,
+    ) -> Result<Self> {
+        // Ensure the combined proof target is zero if there are no solutions.
+        ensure!(
+            solutions.is_some() || combined_proof_target == 0,
+            "The combined proof target must be zero if there are no solutions"
+        );
+        // Ensure the timestamp is after the previous block.
+        ensure!(
+            timestamp > previous_block.timestamp(),
+            "The timestamp ({timestamp}) must be after the previous block timestamp ({})",
+            previous_block.timestamp()
+        );
+
+        // Compute the next round and height.
+        let next_round = previous_block.round().saturating_add(1);
+        let next_height = previous_block.height().saturating_add(1);
+
+        // Calculate the next coinbase targets and timestamps.
+        let (
+            next_coinbase_target,
+            next_proof_target,
+            next_cumulative_proof_target,
+            next_cumulative_weight,
+            next_last_coinbase_target,
+            next_last_coinbase_timestamp,
+        ) = to_next_targets::<N>(
+            previous_block.cumulative_proof_target(),
+            combined_proof_target,
+            previous_block.coinbase_target(),
+            previous_block.cumulative_weight(),
+            previous_block.last_coinbase_target(),
+            previous_block.last_coinbase_timestamp(),
+            timestamp,
+        )?;
+
+        // Calculate the coinbase reward.
+        let coinbase_reward = coinbase_reward(
+            next_height,
+            N::STARTING_SUPPLY,
+            N::ANCHOR_HEIGHT,
+            N::BLOCK_TIME,
+            combined_proof_target,
+            u64::try_from(previous_block.cumulative_proof_target())?,
+            previous_block.coinbase_target(),
+        )?;
+
+        // Construct the metadata.
+        let metadata = Metadata::new(
+            N::ID,
+            next_round,
+            next_height,
+            next_cumulative_weight,
+            next_cumulative_proof_target,
+            next_coinbase_target,
+            next_proof_target,
+            next_last_coinbase_target,
+            next_last_coinbase_timestamp,
+            timestamp,
+        )?;
+
+        Ok(Self {
+            previous_hash: previous_block.hash(),
+            previous_state_root,
+            solutions: Solutions::from(solutions),
+            aborted_solution_ids,
+            coinbase_reward,
+            metadata,
+        })
+    }
+
+    /// Returns the metadata for the next block.
+    pub const fn metadata(&self) -> &Metadata<N> {
+        &self.metadata
+    }
+
+    /// Returns the solutions for the next block.
+    pub const fn solutions(&self) -> &Solutions<N> {
+        &self.solutions
+    }
+
+    /// Returns the coinbase reward to speculate with.
+    pub const fn coinbase_reward(&self) -> u64 {
+        self.coinbase_reward
+    }
+
+    /// Returns the finalize global state to speculate with.
+    pub fn finalize_global_state(&self) -> Result<FinalizeGlobalState> {
+        FinalizeGlobalState::new::<N>(
+            self.metadata.round(),
+            self.metadata.height(),
+            self.metadata.cumulative_weight(),
+            self.metadata.cumulative_proof_target(),
+            self.previous_hash,
+        )
+    }
+
+    /// Returns the next beacon block, signed by the given private key, from the given speculation output.
+    ///
+    /// Note: The reasons for the aborted transactions are not recorded, as a beacon block does not commit
+    /// to the order of its candidate transactions, which is required to check the reasons.
+    #[allow(clippy::type_complexity)]
+    pub fn build_beacon<R: Rng + CryptoRng>(
+        self,
+        private_key: &PrivateKey<N>,
+        speculation: (
+            Ratifications<N>,
+            Transactions<N>,
+            Vec<(N::TransactionID, AbortReason)>,
+            Vec<FinalizeOperation<N>>,
+        ),
+        rng: &mut R,
+    ) -> Result<Block<N>> {
+        let (ratifications, transactions, aborted_transactions, ratified_finalize_operations) = speculation;
+
+        // Compute the solutions root.
+        let solutions_root = match self.solutions.deref() {
+            Some(solutions) => solutions.to_accumulator_point()?,
+            None => Field::zero(),
+        };
+        // Retrieve the aborted transaction IDs.
+        let aborted_transaction_ids = aborted_transactions.into_iter().map(|(id, _)| id).collect();
+
+        // Construct the header.
+        let header = Header::from(
+            self.previous_state_root,
+            transactions.to_transactions_root()?,
+            transactions.to_finalize_root(ratified_finalize_operations)?,
+            ratifications.to_ratifications_root()?,
+            solutions_root,
+            Field::zero(),
+            self.metadata,
+        )?;
+
+        // Construct the block.
+        Block::new_beacon(
+            private_key,
+            self.previous_hash,
+            header,
+            ratifications,
+            self.solutions,
+            self.aborted_solution_ids,
+            transactions,
+            aborted_transaction_ids,
+            rng,
+        )
+    }
+}
@@ -22,7 +22,7 @@ impl<N: Network> FromBytes for Block<N> {
         // Read the version.
         let version = u8::read_le(&mut reader)?;
         // Ensure the version is valid.
-        if version != 1 {
+        if version != 1 && version != 2 {
             return Err(error("Invalid block version"));
         }
 
@@ -68,9 +68,24 @@ impl<N: Network> FromBytes for Block<N> {
         for _ in 0..num_aborted_transactions {
             aborted_transaction_ids.push(FromBytes::read_le(&mut reader)?);
         }
+        // Read the aborted transaction reasons, which are only present in version 2.
+        let aborted_transaction_reasons = match version {
+            1 => vec![],
+            _ => {
+                // Ensure there are aborted transactions (this is an early safety check).
+                if num_aborted_transactions == 0 {
+                    return Err(error("Invalid block version for a block without aborted transactions"));
+                }
+                let mut aborted_transaction_reasons = Vec::with_capacity(num_aborted_transactions as usize);
+                for _ in 0..num_aborted_transactions {
+                    aborted_transaction_reasons.push(FromBytes::read_le(&mut reader)?);
+                }
+                aborted_transaction_reasons
+            }
+        };
 
         // Construct the block.
-        let block = Self::from(
+        let block = Self::from_with_aborted_transaction_reasons(
             previous_hash,
             header,
             authority,
@@ -79,6 +94,7 @@ impl<N: Network> FromBytes for Block<N> {
             aborted_solution_ids,
             transactions,
             aborted_transaction_ids,
+            aborted_transaction_reasons,
         )
         .map_err(error)?;
 
@@ -94,8 +110,14 @@ impl<N: Network> ToBytes for Block<N> {
     /// Writes the block to the buffer.
     #[inline]
     fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Determine the version.
+        // Note: The aborted transaction reasons are only encoded if they are recorded, to preserve the version 1 encoding.
+        let version = match self.aborted_transaction_reasons.is_empty() {
+            true => 1u8,
+            false => 2u8,
+        };
         // Write the version.
-        1u8.write_le(&mut writer)?;
+        version.write_le(&mut writer)?;
 
         // Write the block hash.
         self.block_hash.write_le(&mut writer)?;
@@ -122,7 +144,13 @@ impl<N: Network> ToBytes for Block<N> {
 
         // Write the aborted transaction IDs.
         (u32::try_from(self.aborted_transaction_ids.len()).map_err(error))?.write_le(&mut writer)?;
-        self.aborted_transaction_ids.write_le(&mut writer)
+        self.aborted_transaction_ids.write_le(&mut writer)?;
+
+        // Write the aborted transaction reasons.
+        if version == 2 {
+            self.aborted_transaction_reasons.write_le(&mut writer)?;
+        }
+        Ok(())
     }
 }
 
@@ -0,0 +1,77 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use super::*;
+
+impl<N: Network> FromBytes for BlockFilter<N> {
+    /// Reads the block filter from the buffer.
+    #[inline]
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Read the version.
+        let version = u8::read_le(&mut reader)?;
+        // Ensure the version is valid.
+        if version != 1 {
+            return Err(error("Invalid block filter version"));
+        }
+
+        // Read the block hash.
+        let block_hash = N::BlockHash::read_le(&mut reader)?;
+        // Read the number of elements.
+        let num_elements = u32::read_le(&mut reader)?;
+        // Read the encoded data.
+        let num_bytes = u32::read_le(&mut reader)?;
+        let data = (0..num_bytes).map(|_| u8::read_le(&mut reader)).collect::<IoResult<Vec<_>>>()?;
+
+        Ok(Self::from_unchecked(block_hash, num_elements, data))
+    }
+}
+
+impl<N: Network> ToBytes for BlockFilter<N> {
+    /// Writes the block filter to the buffer.
+    #[inline]
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Write the version.
+        1u8.write_le(&mut writer)?;
+
+        // Write the block hash.
+        self.block_hash.write_le(&mut writer)?;
+        // Write the number of elements.
+        self.num_elements.write_le(&mut writer)?;
+        // Write the encoded data.
+        u32::try_from(self.data.len()).map_err(|e| error(e.to_string()))?.write_le(&mut writer)?;
+        self.data.write_le(&mut writer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::MainnetV0;
+
+    type CurrentNetwork = MainnetV0;
+
+    #[test]
+    fn test_bytes() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        let block_hash = <CurrentNetwork as Network>::BlockHash::rand(rng);
+        let elements: Vec<Field<CurrentNetwork>> = (0..10).map(|_| Uniform::rand(rng)).collect();
+        let expected = BlockFilter::<CurrentNetwork>::new(block_hash, elements)?;
+
+        // Check the byte representation.
+        let expected_bytes = expected.to_bytes_le()?;
+        assert_eq!(expected, BlockFilter::read_le(&expected_bytes[..])?);
+        Ok(())
+    }
+}
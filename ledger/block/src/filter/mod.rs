@@ -0,0 +1,357 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+mod bytes;
+
+use crate::{Block, Input, Output};
+use console::{
+    network::prelude::*,
+    program::{Argument, Literal, Owner, Plaintext},
+    types::Field,
+};
+
+/// The Golomb-Rice coding parameter, as specified in BIP-158.
+const FILTER_P: u8 = 19;
+/// The inverse false positive rate, as specified in BIP-158.
+const FILTER_M: u64 = 784_931;
+
+/// A compact (Golomb-coded set) filter over the output commitments, tags, and public addresses of a block.
+///
+/// Light clients may download the filter for a block and test their own commitments, tags,
+/// or addresses against it, in order to decide if the full block needs to be fetched.
+/// The filter may produce false positives, but never produces false negatives.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BlockFilter<N: Network> {
+    /// The hash of the block that the filter commits to.
+    block_hash: N::BlockHash,
+    /// The number of (unique) elements in the filter.
+    num_elements: u32,
+    /// The Golomb-Rice encoded set.
+    data: Vec<u8>,
+}
+
+impl<N: Network> Block<N> {
+    /// Returns the compact filter for this block.
+    pub fn to_filter(&self) -> Result<BlockFilter<N>> {
+        BlockFilter::new(self.hash(), self.filter_elements()?)
+    }
+
+    /// Returns the elements committed to in the compact filter of this block.
+    /// The elements consist of the output commitments, the tags, and the public addresses.
+    fn filter_elements(&self) -> Result<Vec<Field<N>>> {
+        let mut elements = Vec::new();
+        for transition in self.transitions() {
+            // Add the tags.
+            elements.extend(transition.tags().copied());
+            // Add the public addresses from the inputs.
+            for input in transition.inputs() {
+                if let Input::Public(_, Some(plaintext)) = input {
+                    collect_addresses(plaintext, &mut elements)?;
+                }
+            }
+            for output in transition.outputs() {
+                match output {
+                    // Add the output commitment, and the owner if it is public.
+                    Output::Record(commitment, _, record) => {
+                        elements.push(*commitment);
+                        if let Some(Owner::Public(owner)) = record.as_ref().map(|record| record.owner()) {
+                            elements.push(owner.to_field()?);
+                        }
+                    }
+                    // Add the public addresses from the outputs.
+                    Output::Public(_, Some(plaintext)) => collect_addresses(plaintext, &mut elements)?,
+                    // Add the public addresses from the future arguments.
+                    Output::Future(_, Some(future)) => {
+                        let mut futures = vec![future];
+                        while let Some(future) = futures.pop() {
+                            for argument in future.arguments() {
+                                match argument {
+                                    Argument::Plaintext(plaintext) => collect_addresses(plaintext, &mut elements)?,
+                                    Argument::Future(future) => futures.push(future),
+                                }
+                            }
+                        }
+                    }
+                    _ => (),
+                }
+            }
+        }
+        Ok(elements)
+    }
+}
+
+/// Appends the addresses contained in the given plaintext to the list of elements.
+fn collect_addresses<N: Network>(plaintext: &Plaintext<N>, elements: &mut Vec<Field<N>>) -> Result<()> {
+    match plaintext {
+        Plaintext::Literal(Literal::Address(address), _) => elements.push(address.to_field()?),
+        Plaintext::Literal(..) => (),
+        Plaintext::Struct(members, _) => {
+            for member in members.values() {
+                collect_addresses(member, elements)?;
+            }
+        }
+        Plaintext::Array(members, _) => {
+            for member in members {
+                collect_addresses(member, elements)?;
+            }
+        }
+    }
+    Ok(())
+}
+
+impl<N: Network> BlockFilter<N> {
+    /// Initializes a new compact filter for the given block hash and elements.
+    pub fn new(block_hash: N::BlockHash, mut elements: Vec<Field<N>>) -> Result<Self> {
+        // Remove the duplicate elements, as the range is determined by the number of unique elements.
+        elements.sort_unstable();
+        elements.dedup();
+        // Ensure the number of elements is within bounds.
+        let num_elements = u32::try_from(elements.len())?;
+        // Hash the elements into the range `[0, num_elements * M)`.
+        // Note: Colliding values are retained, so the encoded set contains exactly `num_elements` values.
+        let mut values = elements
+            .iter()
+            .map(|element| Self::hash_to_range(&block_hash, element, num_elements))
+            .collect::<Result<Vec<_>>>()?;
+        values.sort_unstable();
+
+        // Encode the sorted differences with Golomb-Rice coding.
+        let mut writer = BitWriter::default();
+        let mut previous = 0u64;
+        for value in &values {
+            writer.write_golomb(value - previous);
+            previous = *value;
+        }
+
+        Ok(Self { block_hash, num_elements, data: writer.finish() })
+    }
+
+    /// Initializes a compact filter from its raw components, without validating the encoding.
+    pub const fn from_unchecked(block_hash: N::BlockHash, num_elements: u32, data: Vec<u8>) -> Self {
+        Self { block_hash, num_elements, data }
+    }
+
+    /// Returns the hash of the block that the filter commits to.
+    pub const fn block_hash(&self) -> N::BlockHash {
+        self.block_hash
+    }
+
+    /// Returns the number of elements in the filter.
+    pub const fn num_elements(&self) -> u32 {
+        self.num_elements
+    }
+
+    /// Returns the encoded filter data.
+    pub fn data(&self) -> &[u8] {
+        &self.data
+    }
+
+    /// Returns `true` if the filter (probabilistically) contains the given element.
+    pub fn contains(&self, element: &Field<N>) -> Result<bool> {
+        self.contains_any(std::slice::from_ref(element))
+    }
+
+    /// Returns `true` if the filter (probabilistically) contains any of the given elements.
+    pub fn contains_any(&self, elements: &[Field<N>]) -> Result<bool> {
+        // If either set is empty, there is no match.
+        if self.num_elements == 0 || elements.is_empty() {
+            return Ok(false);
+        }
+        // Hash the query elements into the range of the filter.
+        let mut targets = elements
+            .iter()
+            .map(|element| Self::hash_to_range(&self.block_hash, element, self.num_elements))
+            .collect::<Result<Vec<_>>>()?;
+        targets.sort_unstable();
+
+        // Walk the encoded set and the sorted targets in lockstep.
+        let mut reader = BitReader::new(&self.data);
+        let mut targets = targets.into_iter().peekable();
+        let mut value = 0u64;
+        for _ in 0..self.num_elements {
+            value += reader.read_golomb()?;
+            while let Some(target) = targets.peek() {
+                match target.cmp(&value) {
+                    Ordering::Less => {
+                        targets.next();
+                    }
+                    Ordering::Equal => return Ok(true),
+                    Ordering::Greater => break,
+                }
+            }
+            if targets.peek().is_none() {
+                break;
+            }
+        }
+        Ok(false)
+    }
+
+    /// Hashes the given element into the range `[0, num_elements * M)`, keyed by the block hash.
+    fn hash_to_range(block_hash: &N::BlockHash, element: &Field<N>, num_elements: u32) -> Result<u64> {
+        // Compute the keyed hash of the element.
+        let hash = N::hash_psd2(&[**block_hash, *element])?;
+        // Take the first 8 bytes of the hash as a 64-bit integer.
+        let bytes = hash.to_bytes_le()?;
+        let hash = u64::from_le_bytes(bytes[..8].try_into()?);
+        // Map the hash into the range with a multiply-and-shift, to avoid a modulo reduction.
+        let range = num_elements as u128 * FILTER_M as u128;
+        Ok(((hash as u128 * range) >> 64) as u64)
+    }
+}
+
+/// A most-significant-bit first bit writer.
+#[derive(Default)]
+struct BitWriter {
+    bytes: Vec<u8>,
+    num_bits: usize,
+}
+
+impl BitWriter {
+    /// Writes a single bit.
+    fn write_bit(&mut self, bit: bool) {
+        if self.num_bits % 8 == 0 {
+            self.bytes.push(0);
+        }
+        if bit {
+            *self.bytes.last_mut().unwrap() |= 1 << (7 - (self.num_bits % 8));
+        }
+        self.num_bits += 1;
+    }
+
+    /// Writes the given value with Golomb-Rice coding.
+    fn write_golomb(&mut self, value: u64) {
+        // Write the quotient in unary.
+        for _ in 0..(value >> FILTER_P) {
+            self.write_bit(true);
+        }
+        self.write_bit(false);
+        // Write the remainder in binary.
+        for i in (0..FILTER_P).rev() {
+            self.write_bit((value >> i) & 1 == 1);
+        }
+    }
+
+    /// Returns the written bytes.
+    fn finish(self) -> Vec<u8> {
+        self.bytes
+    }
+}
+
+/// A most-significant-bit first bit reader.
+struct BitReader<'a> {
+    bytes: &'a [u8],
+    position: usize,
+}
+
+impl<'a> BitReader<'a> {
+    /// Initializes a new bit reader.
+    const fn new(bytes: &'a [u8]) -> Self {
+        Self { bytes, position: 0 }
+    }
+
+    /// Reads a single bit.
+    fn read_bit(&mut self) -> Result<bool> {
+        let byte = match self.bytes.get(self.position / 8) {
+            Some(byte) => byte,
+            None => bail!("Reached the end of the block filter"),
+        };
+        let bit = (byte >> (7 - (self.position % 8))) & 1 == 1;
+        self.position += 1;
+        Ok(bit)
+    }
+
+    /// Reads a Golomb-Rice coded value.
+    fn read_golomb(&mut self) -> Result<u64> {
+        // Read the quotient in unary.
+        let mut quotient = 0u64;
+        while self.read_bit()? {
+            quotient += 1;
+        }
+        // Read the remainder in binary.
+        let mut remainder = 0u64;
+        for _ in 0..FILTER_P {
+            remainder = (remainder << 1) | self.read_bit()? as u64;
+        }
+        Ok((quotient << FILTER_P) | remainder)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::MainnetV0;
+
+    type CurrentNetwork = MainnetV0;
+
+    #[test]
+    fn test_filter() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        // Sample a set of elements.
+        let block_hash = <CurrentNetwork as Network>::BlockHash::rand(rng);
+        let elements: Vec<Field<CurrentNetwork>> = (0..100).map(|_| Uniform::rand(rng)).collect();
+        let filter = BlockFilter::<CurrentNetwork>::new(block_hash, elements.clone())?;
+        assert_eq!(filter.num_elements(), 100);
+
+        // Ensure every element is contained in the filter.
+        for element in &elements {
+            assert!(filter.contains(element)?);
+        }
+        assert!(filter.contains_any(&elements)?);
+
+        // Ensure an empty query does not match.
+        assert!(!filter.contains_any(&[])?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_filter_with_duplicates() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        // Sample a set of elements, each of which appears three times.
+        let block_hash = <CurrentNetwork as Network>::BlockHash::rand(rng);
+        let elements: Vec<Field<CurrentNetwork>> = (0..100).map(|_| Uniform::rand(rng)).collect();
+        let duplicated = elements.iter().chain(&elements).chain(&elements).copied().collect::<Vec<_>>();
+        let filter = BlockFilter::<CurrentNetwork>::new(block_hash, duplicated)?;
+        assert_eq!(filter.num_elements(), 100);
+
+        // Ensure the filter matches the filter without duplicates.
+        assert_eq!(filter, BlockFilter::<CurrentNetwork>::new(block_hash, elements.clone())?);
+
+        // Ensure every element is contained in the filter.
+        for element in &elements {
+            assert!(filter.contains(element)?);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_block_filter() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        // Sample the genesis block.
+        let block = crate::test_helpers::sample_genesis_block(rng);
+        let filter = block.to_filter()?;
+        assert_eq!(filter.block_hash(), block.hash());
+
+        // Ensure the commitments and tags are contained in the filter.
+        for commitment in block.commitments() {
+            assert!(filter.contains(commitment)?);
+        }
+        for tag in block.tags() {
+            assert!(filter.contains(tag)?);
+        }
+        Ok(())
+    }
+}
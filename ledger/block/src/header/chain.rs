@@ -0,0 +1,109 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use super::*;
+use ledger_committee::Committee;
+
+/// A chain of block headers, validated from a trusted checkpoint header.
+///
+/// This allows light clients to track the chain from headers alone, see `Header::verify_chain`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct HeaderChain<N: Network> {
+    /// The block headers, starting from the checkpoint header.
+    headers: Vec<Header<N>>,
+}
+
+impl<N: Network> HeaderChain<N> {
+    /// Initializes a new header chain from the given trusted checkpoint header.
+    pub fn new(checkpoint: Header<N>) -> Result<Self> {
+        // Ensure the checkpoint header is well-formed.
+        ensure!(checkpoint.is_valid(), "Checkpoint header is malformed in block {}", checkpoint.height());
+        Ok(Self { headers: vec![checkpoint] })
+    }
+
+    /// Returns the checkpoint header.
+    pub fn checkpoint(&self) -> &Header<N> {
+        // Note: The header chain is guaranteed to contain the checkpoint header.
+        &self.headers[0]
+    }
+
+    /// Returns the latest header.
+    pub fn latest(&self) -> &Header<N> {
+        // Note: The header chain is guaranteed to contain the checkpoint header.
+        &self.headers[self.headers.len() - 1]
+    }
+
+    /// Returns the latest block height.
+    pub fn latest_height(&self) -> u32 {
+        self.latest().height()
+    }
+
+    /// Returns the header for the given block height, if it is in the chain.
+    pub fn get(&self, height: u32) -> Option<&Header<N>> {
+        let index = height.checked_sub(self.checkpoint().height())?;
+        self.headers.get(index as usize)
+    }
+
+    /// Returns the number of headers in the chain, including the checkpoint header.
+    pub fn len(&self) -> usize {
+        self.headers.len()
+    }
+
+    /// Returns `true` if the chain is empty.
+    /// Note: This is always `false`, as the chain always contains the checkpoint header.
+    pub fn is_empty(&self) -> bool {
+        self.headers.is_empty()
+    }
+
+    /// Returns an iterator over the headers, starting from the checkpoint header.
+    pub fn iter(&self) -> impl '_ + ExactSizeIterator<Item = &Header<N>> {
+        self.headers.iter()
+    }
+
+    /// Appends the given header to the chain, after ensuring it is a valid successor of the latest header.
+    pub fn push(&mut self, header: Header<N>, current_committee_lookback: &Committee<N>) -> Result<()> {
+        // Ensure the header is a valid successor of the latest header.
+        header.verify_chain(self.latest(), current_committee_lookback)?;
+        // Append the header.
+        self.headers.push(header);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_header_chain() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        // Sample the genesis header and a committee.
+        let genesis = *crate::test_helpers::sample_genesis_block(rng).header();
+        let committee = ledger_committee::test_helpers::sample_committee(rng);
+
+        // Initialize the header chain.
+        let mut chain = HeaderChain::new(genesis)?;
+        assert_eq!(chain.len(), 1);
+        assert_eq!(chain.checkpoint(), &genesis);
+        assert_eq!(chain.latest(), &genesis);
+        assert_eq!(chain.get(0), Some(&genesis));
+        assert_eq!(chain.get(1), None);
+
+        // Ensure a header cannot follow itself.
+        assert!(chain.push(genesis, &committee).is_err());
+        assert_eq!(chain.latest_height(), 0);
+        Ok(())
+    }
+}
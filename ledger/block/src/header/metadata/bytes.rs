@@ -22,7 +22,7 @@ impl<N: Network> FromBytes for Metadata<N> {
         // Read the version.
         let version = u8::read_le(&mut reader)?;
         // Ensure the version is valid.
-        if version != 1 {
+        if version != 1 && version != 2 {
             return Err(error("Invalid metadata version"));
         }
 
@@ -37,9 +37,18 @@ impl<N: Network> FromBytes for Metadata<N> {
         let last_coinbase_target = u64::read_le(&mut reader)?;
         let last_coinbase_timestamp = i64::read_le(&mut reader)?;
         let timestamp = i64::read_le(&mut reader)?;
+        // Read the consensus version, which is only present in version 2.
+        let consensus_version = match version {
+            1 => 0u16,
+            _ => u16::read_le(&mut reader)?,
+        };
+        // Ensure the encoding is canonical, as version 2 is only used for metadata that signals a consensus version.
+        if version == 2 && consensus_version == 0 {
+            return Err(error("Invalid metadata - version 2 must signal a consensus version"));
+        }
 
         // Construct the metadata.
-        Self::new(
+        Self::new_with_consensus_version(
             network,
             round,
             height,
@@ -50,6 +59,7 @@ impl<N: Network> FromBytes for Metadata<N> {
             last_coinbase_target,
             last_coinbase_timestamp,
             timestamp,
+            consensus_version,
         )
         .map_err(|e| error(e.to_string()))
     }
@@ -60,7 +70,12 @@ impl<N: Network> ToBytes for Metadata<N> {
     #[inline]
     fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
         // Write the version.
-        1u8.write_le(&mut writer)?;
+        // Note: Version 2 is only used for metadata that signals a consensus version.
+        let version = match self.consensus_version {
+            0 => 1u8,
+            _ => 2u8,
+        };
+        version.write_le(&mut writer)?;
 
         // Write to the buffer.
         self.network.write_le(&mut writer)?;
@@ -72,7 +87,11 @@ impl<N: Network> ToBytes for Metadata<N> {
         self.proof_target.write_le(&mut writer)?;
         self.last_coinbase_target.write_le(&mut writer)?;
         self.last_coinbase_timestamp.write_le(&mut writer)?;
-        self.timestamp.write_le(&mut writer)
+        self.timestamp.write_le(&mut writer)?;
+        if version != 1 {
+            self.consensus_version.write_le(&mut writer)?;
+        }
+        Ok(())
     }
 }
 
@@ -95,4 +114,59 @@ mod tests {
         }
         Ok(())
     }
+
+    #[test]
+    fn test_bytes_with_consensus_version() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        // Sample a block metadata that signals a consensus version.
+        let metadata = crate::header::metadata::test_helpers::sample_block_metadata(rng);
+        let expected = Metadata::<CurrentNetwork>::new_with_consensus_version(
+            metadata.network(),
+            1,
+            1,
+            metadata.cumulative_weight(),
+            metadata.cumulative_proof_target(),
+            metadata.coinbase_target(),
+            metadata.proof_target(),
+            metadata.last_coinbase_target(),
+            metadata.last_coinbase_timestamp(),
+            metadata.timestamp() + 1,
+            1,
+        )?;
+
+        // Check the byte representation.
+        let expected_bytes = expected.to_bytes_le()?;
+        assert_eq!(expected_bytes[0], 2);
+        assert_eq!(expected, Metadata::read_le(&expected_bytes[..])?);
+        assert_eq!(expected_bytes.len(), metadata.to_bytes_le()?.len() + 2);
+        // Check the hash.
+        assert!(expected.to_hash().is_ok());
+
+        // Ensure version 2 without a consensus version is rejected, as it is not canonical.
+        let mut non_canonical_bytes = metadata.to_bytes_le()?;
+        assert_eq!(non_canonical_bytes[0], 1);
+        non_canonical_bytes[0] = 2;
+        non_canonical_bytes.extend_from_slice(&0u16.to_le_bytes());
+        assert!(Metadata::<CurrentNetwork>::read_le(&non_canonical_bytes[..]).is_err());
+
+        // Ensure the consensus version is bounded by the latest consensus version.
+        expected.check_consensus_version(1)?;
+        assert!(expected.check_consensus_version(2).is_err());
+        let unsupported = Metadata::<CurrentNetwork>::new_with_consensus_version(
+            expected.network(),
+            expected.round(),
+            expected.height(),
+            expected.cumulative_weight(),
+            expected.cumulative_proof_target(),
+            expected.coinbase_target(),
+            expected.proof_target(),
+            expected.last_coinbase_target(),
+            expected.last_coinbase_timestamp(),
+            expected.timestamp(),
+            crate::LATEST_CONSENSUS_VERSION + 1,
+        )?;
+        assert!(unsupported.check_consensus_version(1).is_err());
+        Ok(())
+    }
 }
@@ -67,6 +67,8 @@ impl<N: Network> Metadata<N> {
             && self.last_coinbase_timestamp == N::GENESIS_TIMESTAMP
             // Ensure the timestamp in the genesis block is `GENESIS_TIMESTAMP`.
             && self.timestamp == N::GENESIS_TIMESTAMP
+            // Ensure the genesis block does not signal a consensus version.
+            && self.consensus_version == 0u16
     }
 }
 
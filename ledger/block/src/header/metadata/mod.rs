@@ -47,6 +47,8 @@ pub struct Metadata<N: Network> {
     last_coinbase_timestamp: i64,
     /// The Unix timestamp (UTC) for this block - 8 bytes.
     timestamp: i64,
+    /// The consensus version signaled by this block - 2 bytes (omitted if zero).
+    consensus_version: u16,
     /// PhantomData.
     _phantom: PhantomData<N>,
 }
@@ -65,6 +67,36 @@ impl<N: Network> Metadata<N> {
         last_coinbase_target: u64,
         last_coinbase_timestamp: i64,
         timestamp: i64,
+    ) -> Result<Self> {
+        Self::new_with_consensus_version(
+            network,
+            round,
+            height,
+            cumulative_weight,
+            cumulative_proof_target,
+            coinbase_target,
+            proof_target,
+            last_coinbase_target,
+            last_coinbase_timestamp,
+            timestamp,
+            0,
+        )
+    }
+
+    /// Initializes a new metadata with the given inputs, signaling the given consensus version.
+    #[allow(clippy::too_many_arguments)]
+    pub fn new_with_consensus_version(
+        network: u16,
+        round: u64,
+        height: u32,
+        cumulative_weight: u128,
+        cumulative_proof_target: u128,
+        coinbase_target: u64,
+        proof_target: u64,
+        last_coinbase_target: u64,
+        last_coinbase_timestamp: i64,
+        timestamp: i64,
+        consensus_version: u16,
     ) -> Result<Self> {
         // Construct a new metadata.
         let metadata = Self {
@@ -78,6 +110,7 @@ impl<N: Network> Metadata<N> {
             last_coinbase_target,
             last_coinbase_timestamp,
             timestamp,
+            consensus_version,
             _phantom: PhantomData,
         };
         // Ensure the header is valid.
@@ -167,6 +200,12 @@ impl<N: Network> Metadata<N> {
     pub const fn timestamp(&self) -> i64 {
         self.timestamp
     }
+
+    /// Returns the consensus version signaled by this block.
+    /// Note: A consensus version of zero indicates that the block does not signal a consensus version.
+    pub const fn consensus_version(&self) -> u16 {
+        self.consensus_version
+    }
 }
 
 #[cfg(test)]
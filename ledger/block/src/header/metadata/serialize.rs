@@ -31,6 +31,9 @@ impl<N: Network> Serialize for Metadata<N> {
                 metadata.serialize_field("last_coinbase_target", &self.last_coinbase_target)?;
                 metadata.serialize_field("last_coinbase_timestamp", &self.last_coinbase_timestamp)?;
                 metadata.serialize_field("timestamp", &self.timestamp)?;
+                if self.consensus_version != 0 {
+                    metadata.serialize_field("consensus_version", &self.consensus_version)?;
+                }
                 metadata.end()
             }
             false => ToBytesSerializer::serialize_with_size_encoding(self, serializer),
@@ -44,7 +47,12 @@ impl<'de, N: Network> Deserialize<'de> for Metadata<N> {
         match deserializer.is_human_readable() {
             true => {
                 let mut metadata = serde_json::Value::deserialize(deserializer)?;
-                Ok(Self::new(
+                // Recover the consensus version, if it is signaled.
+                let consensus_version = match metadata.get("consensus_version") {
+                    Some(_) => DeserializeExt::take_from_value::<D>(&mut metadata, "consensus_version")?,
+                    None => 0,
+                };
+                Ok(Self::new_with_consensus_version(
                     DeserializeExt::take_from_value::<D>(&mut metadata, "network")?,
                     DeserializeExt::take_from_value::<D>(&mut metadata, "round")?,
                     DeserializeExt::take_from_value::<D>(&mut metadata, "height")?,
@@ -55,6 +63,7 @@ impl<'de, N: Network> Deserialize<'de> for Metadata<N> {
                     DeserializeExt::take_from_value::<D>(&mut metadata, "last_coinbase_target")?,
                     DeserializeExt::take_from_value::<D>(&mut metadata, "last_coinbase_timestamp")?,
                     DeserializeExt::take_from_value::<D>(&mut metadata, "timestamp")?,
+                    consensus_version,
                 )
                 .map_err(de::Error::custom)?)
             }
@@ -30,6 +30,9 @@ impl<N: Network> ToBits for Metadata<N> {
         self.last_coinbase_target.write_bits_le(vec);         // 8 bytes
         self.last_coinbase_timestamp.write_bits_le(vec);      // 8 bytes
         self.timestamp.write_bits_le(vec);                    // 8 bytes
+        if self.consensus_version != 0 {
+            self.consensus_version.write_bits_le(vec);        // 2 bytes
+        }
     }
 
     /// Returns the big-endian bits of the metadata.
@@ -46,6 +49,9 @@ impl<N: Network> ToBits for Metadata<N> {
         self.last_coinbase_target.write_bits_be(vec);         // 8 bytes
         self.last_coinbase_timestamp.write_bits_be(vec);      // 8 bytes
         self.timestamp.write_bits_be(vec);                    // 8 bytes
+        if self.consensus_version != 0 {
+            self.consensus_version.write_bits_be(vec);        // 2 bytes
+        }
     }
 }
 
@@ -19,9 +19,13 @@ impl<N: Network> Metadata<N> {
     /// Returns the metadata hash.
     pub fn to_hash(&self) -> Result<Field<N>> {
         // Construct the metadata bits (the last leaf in the Merkle tree).
-        let metadata_bits = self.to_bits_le(); // 696 bits
-        // Ensure the metadata bits is the correct size.
-        ensure!(metadata_bits.len() == 696, "Incorrect metadata size - {} bits", metadata_bits.len());
+        let metadata_bits = self.to_bits_le();
+        // Ensure the metadata bits is the correct size (696 bits, or 712 bits if a consensus version is signaled).
+        let expected_size = match self.consensus_version {
+            0 => 696,
+            _ => 712,
+        };
+        ensure!(metadata_bits.len() == expected_size, "Incorrect metadata size - {} bits", metadata_bits.len());
         // Hash the metadata bits.
         let metadata_hash = N::hash_bhp1024(&metadata_bits)?;
         // Return the metadata hash.
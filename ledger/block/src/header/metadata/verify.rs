@@ -108,3 +108,24 @@ impl<N: Network> Metadata<N> {
         Ok(())
     }
 }
+
+impl<N: Network> Metadata<N> {
+    /// Ensures the consensus version signaled by the block is at least the given active consensus version,
+    /// and at most the latest consensus version.
+    pub fn check_consensus_version(&self, active_consensus_version: u16) -> Result<()> {
+        ensure!(
+            self.consensus_version >= active_consensus_version,
+            "Consensus version is outdated in block {} (found '{}', expected at least '{active_consensus_version}')",
+            self.height,
+            self.consensus_version
+        );
+        ensure!(
+            self.consensus_version <= crate::LATEST_CONSENSUS_VERSION,
+            "Consensus version is not supported in block {} (found '{}', expected at most '{}')",
+            self.height,
+            self.consensus_version,
+            crate::LATEST_CONSENSUS_VERSION
+        );
+        Ok(())
+    }
+}
@@ -16,6 +16,9 @@
 mod metadata;
 pub use metadata::*;
 
+mod chain;
+pub use chain::*;
+
 mod bytes;
 mod genesis;
 mod merkle;
@@ -181,6 +184,11 @@ impl<N: Network> Header<N> {
     pub const fn timestamp(&self) -> i64 {
         self.metadata.timestamp()
     }
+
+    /// Returns the consensus version signaled by this block.
+    pub const fn consensus_version(&self) -> u16 {
+        self.metadata.consensus_version()
+    }
 }
 
 #[cfg(test)]
@@ -16,6 +16,8 @@
 #![allow(clippy::too_many_arguments)]
 
 use super::*;
+use crate::to_next_targets;
+use ledger_committee::Committee;
 
 impl<N: Network> Header<N> {
     /// Ensures the block header is correct.
@@ -97,3 +99,89 @@ impl<N: Network> Header<N> {
         )
     }
 }
+
+impl<N: Network> Header<N> {
+    /// Ensures the block header is a valid successor of the given previous block header, using only the headers.
+    ///
+    /// This check is intended for light clients, and does not replace full block verification,
+    /// as the roots in the header cannot be recomputed without the contents of the block.
+    pub fn verify_chain(&self, previous_header: &Header<N>, current_committee_lookback: &Committee<N>) -> Result<()> {
+        // Determine the expected height.
+        let expected_height = previous_header.height().saturating_add(1);
+
+        // Ensure the block header is well-formed.
+        ensure!(self.is_valid(), "Header is malformed in block {expected_height}");
+        // Ensure the network ID is correct.
+        ensure!(
+            self.network() == previous_header.network(),
+            "Network ID is incorrect in block {expected_height} (found '{}', expected '{}')",
+            self.network(),
+            previous_header.network()
+        );
+        // Ensure the height is correct.
+        ensure!(
+            self.height() == expected_height,
+            "Height is incorrect in block {expected_height} (found '{}', expected '{expected_height}')",
+            self.height()
+        );
+        // Ensure the round is after the previous round.
+        ensure!(
+            self.round() > previous_header.round(),
+            "Round is not after the previous round in block {expected_height} (found '{}', expected after '{}')",
+            self.round(),
+            previous_header.round()
+        );
+        // Ensure the block round minus the committee lookback range is at least the starting round of the committee lookback.
+        ensure!(
+            self.round().saturating_sub(Committee::<N>::COMMITTEE_LOOKBACK_RANGE)
+                >= current_committee_lookback.starting_round(),
+            "Block {expected_height} has an invalid round (found '{}', expected at least '{}')",
+            self.round().saturating_sub(Committee::<N>::COMMITTEE_LOOKBACK_RANGE),
+            current_committee_lookback.starting_round()
+        );
+
+        // Recover the combined proof target of the block from the change in cumulative weight.
+        let Some(combined_proof_target) = self.cumulative_weight().checked_sub(previous_header.cumulative_weight())
+        else {
+            bail!("Cumulative weight decreased in block {expected_height}")
+        };
+        // Ensure the combined proof target is attainable, as every solution must reach the previous proof target.
+        ensure!(
+            combined_proof_target == 0 || combined_proof_target >= previous_header.proof_target() as u128,
+            "Combined proof target is below the previous proof target in block {expected_height}"
+        );
+
+        // Calculate the next coinbase targets and timestamps.
+        let (
+            expected_coinbase_target,
+            expected_proof_target,
+            expected_cumulative_proof_target,
+            expected_cumulative_weight,
+            expected_last_coinbase_target,
+            expected_last_coinbase_timestamp,
+        ) = to_next_targets::<N>(
+            previous_header.cumulative_proof_target(),
+            combined_proof_target,
+            previous_header.coinbase_target(),
+            previous_header.cumulative_weight(),
+            previous_header.last_coinbase_target(),
+            previous_header.last_coinbase_timestamp(),
+            self.timestamp(),
+        )?;
+
+        // Ensure the block metadata is consistent with the previous block metadata.
+        // Note: The timestamp is checked against itself, as the subdag is required to recompute it.
+        self.metadata.verify(
+            self.round(),
+            expected_height,
+            expected_cumulative_weight,
+            expected_cumulative_proof_target,
+            expected_coinbase_target,
+            expected_proof_target,
+            expected_last_coinbase_target,
+            expected_last_coinbase_timestamp,
+            self.timestamp(),
+            self.timestamp(),
+        )
+    }
+}
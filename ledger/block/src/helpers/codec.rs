@@ -0,0 +1,136 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{Block, Execution, Transaction, Transactions, Transition};
+use console::network::prelude::*;
+
+use base64::{engine::general_purpose::STANDARD, Engine};
+
+/// Stable hex and base64 encodings for ledger types, built on their byte representation.
+///
+/// Decoding checks the length of the input against `MAX_SIZE_IN_BYTES`,
+/// and ensures the input is consumed in full, so that trailing data is rejected.
+pub trait Codec: ToBytes + FromBytes {
+    /// The maximum size of the byte representation.
+    const MAX_SIZE_IN_BYTES: usize;
+
+    /// Returns the lowercase hex encoding of the byte representation.
+    fn to_hex(&self) -> Result<String> {
+        Ok(hex::encode(self.to_bytes_checked()?))
+    }
+
+    /// Returns the object from its hex encoding.
+    fn from_hex(string: &str) -> Result<Self> {
+        ensure!(string.len() <= 2 * Self::MAX_SIZE_IN_BYTES, "The hex string exceeds the maximum length");
+        Self::from_bytes_checked(&hex::decode(string)?)
+    }
+
+    /// Returns the standard (padded) base64 encoding of the byte representation.
+    fn to_base64(&self) -> Result<String> {
+        Ok(STANDARD.encode(self.to_bytes_checked()?))
+    }
+
+    /// Returns the object from its standard (padded) base64 encoding.
+    fn from_base64(string: &str) -> Result<Self> {
+        ensure!(
+            string.len() <= Self::MAX_SIZE_IN_BYTES.div_ceil(3) * 4,
+            "The base64 string exceeds the maximum length"
+        );
+        Self::from_bytes_checked(&STANDARD.decode(string)?)
+    }
+
+    /// Returns the byte representation, ensuring it does not exceed the maximum size.
+    #[doc(hidden)]
+    fn to_bytes_checked(&self) -> Result<Vec<u8>> {
+        let bytes = self.to_bytes_le()?;
+        ensure!(
+            bytes.len() <= Self::MAX_SIZE_IN_BYTES,
+            "The object exceeds the maximum size of {} bytes",
+            Self::MAX_SIZE_IN_BYTES
+        );
+        Ok(bytes)
+    }
+
+    /// Returns the object from its byte representation, ensuring the bytes are consumed in full.
+    #[doc(hidden)]
+    fn from_bytes_checked(bytes: &[u8]) -> Result<Self> {
+        ensure!(
+            bytes.len() <= Self::MAX_SIZE_IN_BYTES,
+            "The bytes exceed the maximum size of {} bytes",
+            Self::MAX_SIZE_IN_BYTES
+        );
+        let mut reader = bytes;
+        let object = Self::read_le(&mut reader)?;
+        ensure!(reader.is_empty(), "Found {} trailing bytes after the object", reader.len());
+        Ok(object)
+    }
+}
+
+impl<N: Network> Codec for Transaction<N> {
+    const MAX_SIZE_IN_BYTES: usize = N::MAX_TRANSACTION_SIZE;
+}
+
+impl<N: Network> Codec for Transition<N> {
+    const MAX_SIZE_IN_BYTES: usize = N::MAX_TRANSACTION_SIZE;
+}
+
+impl<N: Network> Codec for Execution<N> {
+    const MAX_SIZE_IN_BYTES: usize = N::MAX_TRANSACTION_SIZE;
+}
+
+impl<N: Network> Codec for Block<N> {
+    /// Note: This bound allows for a full set of maximum-size transactions,
+    /// and reserves the size of one more transaction for the remaining block data.
+    const MAX_SIZE_IN_BYTES: usize = N::MAX_TRANSACTION_SIZE * (Transactions::<N>::MAX_TRANSACTIONS + 1);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::MainnetV0;
+
+    type CurrentNetwork = MainnetV0;
+
+    /// Checks the hex and base64 round trips for the given object.
+    fn check_codec<T: Codec + Debug + PartialEq>(expected: T) -> Result<()> {
+        // Hex.
+        let hex = expected.to_hex()?;
+        assert_eq!(hex, hex::encode(expected.to_bytes_le()?));
+        assert_eq!(expected, T::from_hex(&hex)?);
+        assert!(T::from_hex(&format!("{hex}00")).is_err());
+        assert!(T::from_hex(&hex[..hex.len() - 2]).is_err());
+        // Base64.
+        let base64 = expected.to_base64()?;
+        assert_eq!(expected, T::from_base64(&base64)?);
+        assert!(T::from_base64(&STANDARD.encode([expected.to_bytes_le()?, vec![0]].concat())).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_codec() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        let transaction = crate::transaction::test_helpers::sample_execution_transaction_with_fee(true, rng);
+        check_codec(transaction.execution().unwrap().clone())?;
+        check_codec(transaction.transitions().next().unwrap().clone())?;
+        check_codec(transaction)?;
+        check_codec(Block::<CurrentNetwork>::read_le(CurrentNetwork::genesis_bytes())?)?;
+
+        // Ensure an oversized input is rejected before decoding.
+        let oversized = "00".repeat(CurrentNetwork::MAX_TRANSACTION_SIZE + 1);
+        assert!(Transaction::<CurrentNetwork>::from_hex(&oversized).is_err());
+        Ok(())
+    }
+}
@@ -0,0 +1,78 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use console::prelude::Network;
+
+/// Returns the epoch number for the given block height.
+pub const fn epoch_number<N: Network>(block_height: u32) -> u32 {
+    block_height / N::NUM_BLOCKS_PER_EPOCH
+}
+
+/// Returns the starting height of the epoch that contains the given block height.
+/// The epoch hash is defined as the previous block hash of the block at this height.
+pub const fn epoch_starting_height<N: Network>(block_height: u32) -> u32 {
+    epoch_number::<N>(block_height).saturating_mul(N::NUM_BLOCKS_PER_EPOCH)
+}
+
+/// Returns the starting height of the epoch after the epoch that contains the given block height.
+pub const fn next_epoch_starting_height<N: Network>(block_height: u32) -> u32 {
+    epoch_starting_height::<N>(block_height).saturating_add(N::NUM_BLOCKS_PER_EPOCH)
+}
+
+/// Returns `true` if the given block height is the first block of an epoch.
+pub const fn is_epoch_boundary<N: Network>(block_height: u32) -> bool {
+    block_height % N::NUM_BLOCKS_PER_EPOCH == 0
+}
+
+/// Returns the block height whose epoch determines the epoch hash and proof target
+/// that solutions must satisfy, in order to be included in the block at the given height.
+///
+/// Note: Solutions are produced against the latest block, so the solutions in the first block
+/// of an epoch must still use the epoch hash of the previous epoch.
+pub const fn solution_epoch_height(block_height: u32) -> u32 {
+    block_height.saturating_sub(1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::MainnetV0;
+
+    type CurrentNetwork = MainnetV0;
+
+    const EPOCH: u32 = CurrentNetwork::NUM_BLOCKS_PER_EPOCH;
+
+    #[test]
+    fn test_epoch_boundaries() {
+        for height in [0, 1, EPOCH - 1, EPOCH, EPOCH + 1, 2 * EPOCH - 1, 2 * EPOCH] {
+            let epoch = epoch_number::<CurrentNetwork>(height);
+            let start = epoch_starting_height::<CurrentNetwork>(height);
+            let next = next_epoch_starting_height::<CurrentNetwork>(height);
+
+            assert_eq!(epoch, height / EPOCH);
+            assert!(start <= height && height < next);
+            assert_eq!(next - start, EPOCH);
+            assert_eq!(is_epoch_boundary::<CurrentNetwork>(height), start == height);
+        }
+        // Ensure the solutions in the first block of an epoch use the previous epoch.
+        assert_eq!(epoch_number::<CurrentNetwork>(solution_epoch_height(EPOCH)), 0);
+        assert_eq!(epoch_number::<CurrentNetwork>(solution_epoch_height(EPOCH + 1)), 1);
+        assert_eq!(solution_epoch_height(0), 0);
+        // Ensure the helpers saturate at the maximum height.
+        assert!(
+            next_epoch_starting_height::<CurrentNetwork>(u32::MAX) >= epoch_starting_height::<CurrentNetwork>(u32::MAX)
+        );
+    }
+}
@@ -13,5 +13,17 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod codec;
+pub use codec::*;
+
+mod epoch;
+pub use epoch::*;
+
+mod signaling;
+pub use signaling::*;
+
+mod simulation;
+pub use simulation::*;
+
 mod target;
 pub use target::*;
@@ -0,0 +1,78 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+/// The latest consensus version supported by this release.
+pub const LATEST_CONSENSUS_VERSION: u16 = 1;
+/// The consensus version from which quorum blocks record the reasons for their aborted transactions,
+/// using the version 2 block encoding.
+pub const ABORTED_TRANSACTION_REASONS_CONSENSUS_VERSION: u16 = 1;
+/// The consensus version from which the batches in a subdag may have a priority lane,
+/// using the version 2 batch header encoding.
+pub const PRIORITY_LANES_CONSENSUS_VERSION: u16 = 1;
+
+/// The number of most recent blocks that are considered when computing the active consensus version.
+pub const CONSENSUS_VERSION_SIGNALING_WINDOW: u32 = 100;
+/// The percentage of blocks in the signaling window that must signal a consensus version for it to activate.
+pub const CONSENSUS_VERSION_ACTIVATION_THRESHOLD: u32 = 75; // 75%
+
+/// Returns the active consensus version, given the consensus versions signaled by the most recent blocks.
+///
+/// A consensus version is active if at least `CONSENSUS_VERSION_ACTIVATION_THRESHOLD` percent of the
+/// `CONSENSUS_VERSION_SIGNALING_WINDOW` most recent blocks signal that version or a later version.
+/// As every block must signal at least the active consensus version, an active version remains active.
+pub fn active_consensus_version(signals: &[u16]) -> u16 {
+    // If the signaling window is not full, then no consensus version is active.
+    if signals.len() < CONSENSUS_VERSION_SIGNALING_WINDOW as usize {
+        return 0;
+    }
+    // Select the signals within the signaling window.
+    let mut window = signals[signals.len() - CONSENSUS_VERSION_SIGNALING_WINDOW as usize..].to_vec();
+    // Sort the signals in decreasing order.
+    window.sort_unstable_by(|a, b| b.cmp(a));
+    // Compute the number of blocks that must signal a consensus version for it to activate.
+    let threshold = (CONSENSUS_VERSION_SIGNALING_WINDOW * CONSENSUS_VERSION_ACTIVATION_THRESHOLD).div_ceil(100);
+    // The active consensus version is the highest version signaled by at least `threshold` blocks.
+    window[threshold as usize - 1]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const WINDOW: usize = CONSENSUS_VERSION_SIGNALING_WINDOW as usize;
+
+    #[test]
+    fn test_active_consensus_version() {
+        // Ensure an incomplete window does not activate a consensus version.
+        assert_eq!(active_consensus_version(&[]), 0);
+        assert_eq!(active_consensus_version(&vec![1; WINDOW - 1]), 0);
+
+        // Ensure a full window activates the signaled consensus version.
+        assert_eq!(active_consensus_version(&vec![1; WINDOW]), 1);
+
+        // Ensure the activation threshold is respected.
+        let threshold = WINDOW * CONSENSUS_VERSION_ACTIVATION_THRESHOLD as usize / 100;
+        let mut signals = vec![0; WINDOW - threshold];
+        signals.extend(vec![2; threshold]);
+        assert_eq!(active_consensus_version(&signals), 2);
+        signals[WINDOW - 1] = 1;
+        assert_eq!(active_consensus_version(&signals), 1);
+
+        // Ensure only the most recent blocks are considered.
+        let mut signals = vec![3; WINDOW];
+        signals.extend(vec![0; WINDOW]);
+        assert_eq!(active_consensus_version(&signals), 0);
+    }
+}
@@ -0,0 +1,224 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use super::{coinbase_reward, to_next_targets};
+use crate::Metadata;
+use console::prelude::{ensure, Network, Result};
+
+/// The conditions under which a block is produced, in a retarget simulation.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct RetargetStep {
+    /// The number of seconds elapsed since the previous block.
+    block_interval: i64,
+    /// The combined proof target of the solutions in the block.
+    combined_proof_target: u128,
+}
+
+impl RetargetStep {
+    /// Initializes a new step with the given block interval (in seconds) and combined proof target.
+    pub const fn new(block_interval: i64, combined_proof_target: u128) -> Self {
+        Self { block_interval, combined_proof_target }
+    }
+
+    /// Initializes a new step with the given block interval (in seconds), for provers with the given hashrate,
+    /// in units of proof target per second, against the given proof target.
+    ///
+    /// This approximates the combined proof target as the work done over the block interval,
+    /// which is zero if no solution meets the proof target, and is bounded by `N::MAX_SOLUTIONS` solutions.
+    pub fn from_hashrate<N: Network>(block_interval: i64, hashrate: u128, proof_target: u64) -> Self {
+        // Compute the work done over the block interval.
+        let work = hashrate.saturating_mul(block_interval.max(0) as u128);
+        // Compute the combined proof target, bounded by the maximum number of solutions.
+        let combined_proof_target = match work < proof_target as u128 {
+            true => 0,
+            false => work.min((proof_target as u128).saturating_mul(N::MAX_SOLUTIONS as u128)),
+        };
+        Self::new(block_interval, combined_proof_target)
+    }
+
+    /// Returns the number of seconds elapsed since the previous block.
+    pub const fn block_interval(&self) -> i64 {
+        self.block_interval
+    }
+
+    /// Returns the combined proof target of the solutions in the block.
+    pub const fn combined_proof_target(&self) -> u128 {
+        self.combined_proof_target
+    }
+}
+
+/// A block in a retarget simulation.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct RetargetSample<N: Network> {
+    /// The metadata of the block.
+    metadata: Metadata<N>,
+    /// The combined proof target of the solutions in the block.
+    combined_proof_target: u128,
+    /// The coinbase reward of the block.
+    coinbase_reward: u64,
+}
+
+impl<N: Network> RetargetSample<N> {
+    /// Returns the metadata of the block, which contains its targets and timestamps.
+    pub const fn metadata(&self) -> &Metadata<N> {
+        &self.metadata
+    }
+
+    /// Returns the combined proof target of the solutions in the block.
+    pub const fn combined_proof_target(&self) -> u128 {
+        self.combined_proof_target
+    }
+
+    /// Returns the coinbase reward of the block.
+    pub const fn coinbase_reward(&self) -> u64 {
+        self.coinbase_reward
+    }
+}
+
+/// Simulates the evolution of the coinbase and proof targets over `num_blocks` blocks after the given metadata,
+/// using the same retargeting and reward functions as the ledger, and returns the time series of the blocks.
+///
+/// The scenario returns the conditions of each block, given the metadata of the previous block.
+/// Note: Each block advances the round by 2, as an anchor is committed every other round.
+pub fn simulate_retargeting<N: Network>(
+    start: &Metadata<N>,
+    num_blocks: u32,
+    mut scenario: impl FnMut(&Metadata<N>) -> RetargetStep,
+) -> Result<Vec<RetargetSample<N>>> {
+    let mut samples = Vec::with_capacity(num_blocks as usize);
+    let mut latest = *start;
+    for _ in 0..num_blocks {
+        // Determine the conditions of the next block.
+        let step = scenario(&latest);
+        ensure!(step.block_interval() > 0, "The block interval must be positive");
+        let combined_proof_target = step.combined_proof_target();
+        let next_height = latest.height().saturating_add(1);
+        let next_timestamp = latest.timestamp().saturating_add(step.block_interval());
+
+        // Calculate the next coinbase targets and timestamps.
+        let (
+            next_coinbase_target,
+            next_proof_target,
+            next_cumulative_proof_target,
+            next_cumulative_weight,
+            next_last_coinbase_target,
+            next_last_coinbase_timestamp,
+        ) = to_next_targets::<N>(
+            latest.cumulative_proof_target(),
+            combined_proof_target,
+            latest.coinbase_target(),
+            latest.cumulative_weight(),
+            latest.last_coinbase_target(),
+            latest.last_coinbase_timestamp(),
+            next_timestamp,
+        )?;
+
+        // Calculate the coinbase reward.
+        let coinbase_reward = coinbase_reward(
+            next_height,
+            N::STARTING_SUPPLY,
+            N::ANCHOR_HEIGHT,
+            N::BLOCK_TIME,
+            combined_proof_target,
+            u64::try_from(latest.cumulative_proof_target())?,
+            latest.coinbase_target(),
+        )?;
+
+        // Construct the metadata of the next block.
+        let metadata = Metadata::new(
+            N::ID,
+            latest.round().saturating_add(2),
+            next_height,
+            next_cumulative_weight,
+            next_cumulative_proof_target,
+            next_coinbase_target,
+            next_proof_target,
+            next_last_coinbase_target,
+            next_last_coinbase_timestamp,
+            next_timestamp,
+        )?;
+        samples.push(RetargetSample { metadata, combined_proof_target, coinbase_reward });
+        latest = metadata;
+    }
+    Ok(samples)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::MainnetV0;
+
+    type CurrentNetwork = MainnetV0;
+
+    const NUM_BLOCKS: u32 = 500;
+
+    #[test]
+    fn test_simulate_retargeting_without_provers() {
+        let genesis = Metadata::<CurrentNetwork>::genesis().unwrap();
+
+        // Simulate blocks at the anchor time, without any solutions.
+        let anchor_time = CurrentNetwork::ANCHOR_TIME as i64;
+        let samples = simulate_retargeting(&genesis, NUM_BLOCKS, |_| RetargetStep::new(anchor_time, 0)).unwrap();
+        assert_eq!(samples.len(), NUM_BLOCKS as usize);
+
+        for (i, sample) in samples.iter().enumerate() {
+            let metadata = sample.metadata();
+            assert_eq!(metadata.height(), i as u32 + 1);
+            assert_eq!(metadata.timestamp(), genesis.timestamp() + (i as i64 + 1) * anchor_time);
+            assert_eq!(metadata.cumulative_weight(), 0);
+            assert_eq!(metadata.cumulative_proof_target(), 0);
+            assert_eq!(sample.coinbase_reward(), 0);
+            // Ensure the coinbase target does not fall below the genesis target.
+            assert!(metadata.coinbase_target() >= CurrentNetwork::GENESIS_COINBASE_TARGET);
+        }
+    }
+
+    #[test]
+    fn test_simulate_retargeting_with_provers() {
+        let genesis = Metadata::<CurrentNetwork>::genesis().unwrap();
+        let block_time = CurrentNetwork::BLOCK_TIME as i64;
+
+        // Simulate provers that produce the latest coinbase target every epoch.
+        let hashrate = CurrentNetwork::GENESIS_COINBASE_TARGET as u128 * 4;
+        let samples = simulate_retargeting(&genesis, NUM_BLOCKS, |latest| {
+            RetargetStep::from_hashrate::<CurrentNetwork>(block_time, hashrate, latest.proof_target())
+        })
+        .unwrap();
+
+        // Ensure the coinbase is produced, and rewarded.
+        assert!(samples.iter().any(|sample| sample.metadata().last_coinbase_timestamp() > genesis.timestamp()));
+        assert!(samples.iter().any(|sample| sample.coinbase_reward() > 0));
+        // Ensure the coinbase target increases under the increased hashrate.
+        let last = samples.last().unwrap().metadata();
+        assert!(last.coinbase_target() > CurrentNetwork::GENESIS_COINBASE_TARGET);
+        assert!(last.cumulative_weight() > 0);
+    }
+
+    #[test]
+    fn test_retarget_step_from_hashrate() {
+        let proof_target = 1_000;
+        let max_solutions = CurrentNetwork::MAX_SOLUTIONS as u128;
+
+        // Ensure no solution is found below the proof target.
+        let step = RetargetStep::from_hashrate::<CurrentNetwork>(10, 99, proof_target);
+        assert_eq!(step.combined_proof_target(), 0);
+        // Ensure the work is accumulated over the block interval.
+        let step = RetargetStep::from_hashrate::<CurrentNetwork>(10, 100, proof_target);
+        assert_eq!(step.combined_proof_target(), 1_000);
+        // Ensure the combined proof target is bounded by the maximum number of solutions.
+        let step = RetargetStep::from_hashrate::<CurrentNetwork>(10, u128::MAX, proof_target);
+        assert_eq!(step.combined_proof_target(), max_solutions * 1_000);
+        assert_eq!(step.block_interval(), 10);
+    }
+}
@@ -83,7 +83,12 @@ pub fn coinbase_reward(
 ///     H_R = Remaining number of blocks until year 10.
 ///     H_Y10 = Expected block height at year 10.
 ///     R_Y9 = Reward at year 9.
-fn anchor_block_reward_at_height(block_height: u32, starting_supply: u64, anchor_height: u32, block_time: u16) -> u128 {
+pub fn anchor_block_reward_at_height(
+    block_height: u32,
+    starting_supply: u64,
+    anchor_height: u32,
+    block_time: u16,
+) -> u128 {
     // A helper function to calculate the reward at a given block height, without the year 9 baseline.
     const fn block_reward_at_height(height: u32, starting_supply: u64, anchor_height: u32, block_time: u16) -> u128 {
         // Calculate the block height at year 10.
@@ -151,7 +156,10 @@ pub fn proof_target(coinbase_target: u64, genesis_proof_target: u64, max_solutio
 ///     A = Anchor timestamp, defined as expected number of seconds elapsed.
 ///     TAU = Rate of doubling (or half-life) in seconds.
 ///     INV = {-1, 1} depending on whether the target is increasing or decreasing.
-fn retarget(
+///
+/// The coinbase target is retargeted with `is_inverse` set, so that it increases when blocks are faster than
+/// the anchor time. The proof target is derived from the coinbase target, with `proof_target`.
+pub fn retarget(
     previous_target: u64,
     previous_block_timestamp: i64,
     block_timestamp: i64,
@@ -997,3 +1005,109 @@ mod tests {
         }
     }
 }
+
+#[cfg(test)]
+mod prop_tests {
+    use super::*;
+    use console::network::MainnetV0;
+
+    use core::cmp::Ordering;
+    use test_strategy::proptest;
+
+    type CurrentNetwork = MainnetV0;
+
+    /// The block height at year 10, after which the anchor block reward is constant.
+    const BLOCK_HEIGHT_AT_YEAR_10: u32 = block_height_at_year(CurrentNetwork::BLOCK_TIME, 10);
+
+    #[proptest]
+    fn block_reward_adds_transaction_fees(
+        total_supply: u64,
+        #[strategy(0..=MAX_COINBASE_REWARD)] coinbase_reward: u64,
+        #[strategy(0..=u64::MAX / 2)] transaction_fees: u64,
+    ) {
+        let block_time = CurrentNetwork::BLOCK_TIME;
+        let reward = block_reward(total_supply, block_time, coinbase_reward, transaction_fees);
+        assert_eq!(reward, block_reward(total_supply, block_time, coinbase_reward, 0) + transaction_fees);
+    }
+
+    #[proptest]
+    fn coinbase_reward_split_does_not_exceed_coinbase_reward(
+        #[strategy(0..=MAX_COINBASE_REWARD)] coinbase_reward: u64,
+    ) {
+        // The coinbase reward is split between the provers (2/3) and the validators (1/3).
+        let validator_share = block_reward(0, CurrentNetwork::BLOCK_TIME, coinbase_reward, 0);
+        let prover_share = puzzle_reward(coinbase_reward);
+        assert!(validator_share + prover_share <= coinbase_reward);
+        assert!(coinbase_reward - (validator_share + prover_share) <= 2);
+    }
+
+    #[proptest]
+    fn anchor_block_reward_is_non_increasing(
+        #[strategy(1..BLOCK_HEIGHT_AT_YEAR_10 + 1000)] block_height: u32,
+        #[strategy(1..1000u32)] delta: u32,
+    ) {
+        let reward_at = |height| {
+            anchor_block_reward_at_height(
+                height,
+                CurrentNetwork::STARTING_SUPPLY,
+                CurrentNetwork::ANCHOR_HEIGHT,
+                CurrentNetwork::BLOCK_TIME,
+            )
+        };
+        assert!(reward_at(block_height + delta) <= reward_at(block_height));
+        assert!(reward_at(block_height) <= MAX_COINBASE_REWARD as u128);
+    }
+
+    #[proptest]
+    fn coinbase_reward_is_bounded(
+        #[strategy(1..BLOCK_HEIGHT_AT_YEAR_10 + 1000)] block_height: u32,
+        combined_proof_target: u128,
+        cumulative_proof_target: u64,
+        #[strategy(1..=u64::MAX)] coinbase_target: u64,
+    ) {
+        let reward = coinbase_reward(
+            block_height,
+            CurrentNetwork::STARTING_SUPPLY,
+            CurrentNetwork::ANCHOR_HEIGHT,
+            CurrentNetwork::BLOCK_TIME,
+            combined_proof_target,
+            cumulative_proof_target,
+            coinbase_target,
+        )
+        .unwrap();
+        assert!(reward <= MAX_COINBASE_REWARD);
+        // Ensure there is no reward once the coinbase target is reached.
+        if cumulative_proof_target >= coinbase_target {
+            assert_eq!(reward, 0);
+        }
+    }
+
+    #[proptest]
+    fn coinbase_target_follows_block_time(
+        #[strategy(CurrentNetwork::GENESIS_COINBASE_TARGET..=u64::MAX / 2)] previous_target: u64,
+        #[strategy(0..i64::MAX / 2)] previous_block_timestamp: i64,
+        #[strategy(0..1000i64)] block_time_elapsed: i64,
+    ) {
+        let anchor_time = CurrentNetwork::ANCHOR_TIME;
+        let target = coinbase_target(
+            previous_target,
+            previous_block_timestamp,
+            previous_block_timestamp + block_time_elapsed,
+            anchor_time,
+            CurrentNetwork::NUM_BLOCKS_PER_EPOCH,
+            CurrentNetwork::GENESIS_COINBASE_TARGET,
+        )
+        .unwrap();
+        // Ensure the target increases for faster blocks, and decreases for slower blocks.
+        match block_time_elapsed.max(1).cmp(&(anchor_time as i64)) {
+            Ordering::Less => assert!(target >= previous_target),
+            Ordering::Equal => assert_eq!(target, previous_target),
+            Ordering::Greater => assert!(target <= previous_target),
+        }
+        assert!(target >= CurrentNetwork::GENESIS_COINBASE_TARGET);
+        // Ensure the proof target is derived from the coinbase target.
+        let proof_target =
+            proof_target(target, CurrentNetwork::GENESIS_PROOF_TARGET, CurrentNetwork::MAX_SOLUTIONS_AS_POWER_OF_TWO);
+        assert_eq!(proof_target, (target >> CurrentNetwork::MAX_SOLUTIONS_AS_POWER_OF_TWO) + 1);
+    }
+}
@@ -0,0 +1,136 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+use core::marker::PhantomData;
+use std::io::{BufReader, BufWriter, Read, Write};
+
+impl<N: Network> Block<N> {
+    /// Writes the block as JSON into the given writer, without materializing the JSON string.
+    pub fn write_json<W: Write>(&self, writer: W) -> Result<()> {
+        let mut writer = BufWriter::new(writer);
+        serde_json::to_writer(&mut writer, self)?;
+        writer.flush()?;
+        Ok(())
+    }
+
+    /// Reads the block as JSON from the given reader.
+    ///
+    /// Unlike `Block::from_str`, the block is parsed field by field as the input is read,
+    /// without materializing the JSON string or an intermediate JSON value.
+    pub fn read_json<R: Read>(reader: R) -> Result<Self> {
+        let mut deserializer = serde_json::Deserializer::from_reader(BufReader::new(reader));
+        let block = deserializer.deserialize_map(BlockVisitor(PhantomData))?;
+        deserializer.end()?;
+        Ok(block)
+    }
+}
+
+/// A visitor that parses a block from a JSON object, field by field.
+struct BlockVisitor<N: Network>(PhantomData<N>);
+
+impl<'de, N: Network> Visitor<'de> for BlockVisitor<N> {
+    type Value = Block<N>;
+
+    fn expecting(&self, formatter: &mut Formatter) -> fmt::Result {
+        formatter.write_str("a block")
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+        let mut block_hash: Option<N::BlockHash> = None;
+        let mut previous_hash = None;
+        let mut header = None;
+        let mut authority = None;
+        let mut ratifications = None;
+        let mut solutions = None;
+        let mut aborted_solution_ids = None;
+        let mut transactions = None;
+        let mut aborted_transaction_ids = None;
+        let mut aborted_transaction_reasons = None;
+
+        while let Some(key) = map.next_key::<String>()? {
+            match key.as_str() {
+                "block_hash" => block_hash = Some(map.next_value()?),
+                "previous_hash" => previous_hash = Some(map.next_value()?),
+                "header" => header = Some(map.next_value()?),
+                "authority" => authority = Some(map.next_value()?),
+                "ratifications" => ratifications = Some(map.next_value()?),
+                "solutions" => solutions = Some(map.next_value()?),
+                "aborted_solution_ids" => aborted_solution_ids = Some(map.next_value()?),
+                "transactions" => transactions = Some(map.next_value()?),
+                "aborted_transaction_ids" => aborted_transaction_ids = Some(map.next_value()?),
+                "aborted_transaction_reasons" => aborted_transaction_reasons = Some(map.next_value()?),
+                _ => {
+                    map.next_value::<de::IgnoredAny>()?;
+                }
+            }
+        }
+
+        /// Returns the given field, or an error if it is missing.
+        fn required<T, E: de::Error>(field: Option<T>, name: &'static str) -> Result<T, E> {
+            field.ok_or_else(|| E::missing_field(name))
+        }
+
+        // Recover the block.
+        let block = Block::from_with_aborted_transaction_reasons(
+            required(previous_hash, "previous_hash")?,
+            required(header, "header")?,
+            required(authority, "authority")?,
+            required(ratifications, "ratifications")?,
+            required(solutions, "solutions")?,
+            required(aborted_solution_ids, "aborted_solution_ids")?,
+            required(transactions, "transactions")?,
+            required(aborted_transaction_ids, "aborted_transaction_ids")?,
+            aborted_transaction_reasons.unwrap_or_default(),
+        )
+        .map_err(de::Error::custom)?;
+
+        // Ensure the block hash matches.
+        match required(block_hash, "block_hash")? == block.hash() {
+            true => Ok(block),
+            false => Err(de::Error::custom("Mismatching block hash, possible data corruption")),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::MainnetV0;
+
+    type CurrentNetwork = MainnetV0;
+
+    #[test]
+    fn test_json_stream() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        for expected in [
+            crate::test_helpers::sample_genesis_block(rng),
+            Block::<CurrentNetwork>::read_le(CurrentNetwork::genesis_bytes())?,
+        ] {
+            // Ensure the streamed JSON matches the JSON string.
+            let mut buffer = Vec::new();
+            expected.write_json(&mut buffer)?;
+            assert_eq!(buffer, expected.to_string().into_bytes());
+
+            // Ensure the block is recovered.
+            assert_eq!(expected, Block::read_json(&buffer[..])?);
+            // Ensure a truncated block is rejected.
+            assert!(Block::<CurrentNetwork>::read_json(&buffer[..buffer.len() - 1]).is_err());
+        }
+        Ok(())
+    }
+}
@@ -18,6 +18,12 @@
 // #![warn(clippy::cast_possible_truncation)]
 #![cfg_attr(test, allow(clippy::single_element_loop))]
 
+mod builder;
+pub use builder::*;
+
+pub mod filter;
+pub use filter::*;
+
 pub mod header;
 pub use header::*;
 
@@ -30,6 +36,12 @@ pub use ratifications::*;
 pub mod ratify;
 pub use ratify::*;
 
+pub mod receipt;
+pub use receipt::*;
+
+pub mod receipt_batch;
+pub use receipt_batch::*;
+
 pub mod solutions;
 pub use solutions::*;
 
@@ -44,6 +56,7 @@ pub use transition::*;
 
 mod bytes;
 mod genesis;
+mod json;
 mod serialize;
 mod string;
 mod verify;
@@ -81,6 +94,9 @@ pub struct Block<N: Network> {
     transactions: Transactions<N>,
     /// The aborted transaction IDs in this block.
     aborted_transaction_ids: Vec<N::TransactionID>,
+    /// The reasons for the aborted transactions in this block (empty if none are recorded).
+    /// Note: The reasons are reported by the block producer, and are not committed to by the block hash.
+    aborted_transaction_reasons: Vec<AbortReason>,
 }
 
 impl<N: Network> Block<N> {
@@ -96,13 +112,41 @@ impl<N: Network> Block<N> {
         transactions: Transactions<N>,
         aborted_transaction_ids: Vec<N::TransactionID>,
         rng: &mut R,
+    ) -> Result<Self> {
+        Self::new_beacon_with_aborted_transaction_reasons(
+            private_key,
+            previous_hash,
+            header,
+            ratifications,
+            solutions,
+            aborted_solution_ids,
+            transactions,
+            aborted_transaction_ids,
+            vec![],
+            rng,
+        )
+    }
+
+    /// Initializes a new beacon block from the given previous block hash, block header,
+    /// ratifications, solutions, transactions, and aborted transaction IDs with their reasons.
+    pub fn new_beacon_with_aborted_transaction_reasons<R: Rng + CryptoRng>(
+        private_key: &PrivateKey<N>,
+        previous_hash: N::BlockHash,
+        header: Header<N>,
+        ratifications: Ratifications<N>,
+        solutions: Solutions<N>,
+        aborted_solution_ids: Vec<SolutionID<N>>,
+        transactions: Transactions<N>,
+        aborted_transaction_ids: Vec<N::TransactionID>,
+        aborted_transaction_reasons: Vec<AbortReason>,
+        rng: &mut R,
     ) -> Result<Self> {
         // Compute the block hash.
         let block_hash = N::hash_bhp1024(&to_bits_le![previous_hash, header.to_root()?])?;
         // Construct the beacon authority.
         let authority = Authority::new_beacon(private_key, block_hash, rng)?;
         // Construct the block.
-        Self::from(
+        Self::from_with_aborted_transaction_reasons(
             previous_hash,
             header,
             authority,
@@ -111,6 +155,7 @@ impl<N: Network> Block<N> {
             aborted_solution_ids,
             transactions,
             aborted_transaction_ids,
+            aborted_transaction_reasons,
         )
     }
 
@@ -125,11 +170,37 @@ impl<N: Network> Block<N> {
         aborted_solution_ids: Vec<SolutionID<N>>,
         transactions: Transactions<N>,
         aborted_transaction_ids: Vec<N::TransactionID>,
+    ) -> Result<Self> {
+        Self::new_quorum_with_aborted_transaction_reasons(
+            previous_hash,
+            header,
+            subdag,
+            ratifications,
+            solutions,
+            aborted_solution_ids,
+            transactions,
+            aborted_transaction_ids,
+            vec![],
+        )
+    }
+
+    /// Initializes a new quorum block from the given previous block hash, block header,
+    /// subdag, ratifications, solutions, transactions, and aborted transaction IDs with their reasons.
+    pub fn new_quorum_with_aborted_transaction_reasons(
+        previous_hash: N::BlockHash,
+        header: Header<N>,
+        subdag: Subdag<N>,
+        ratifications: Ratifications<N>,
+        solutions: Solutions<N>,
+        aborted_solution_ids: Vec<SolutionID<N>>,
+        transactions: Transactions<N>,
+        aborted_transaction_ids: Vec<N::TransactionID>,
+        aborted_transaction_reasons: Vec<AbortReason>,
     ) -> Result<Self> {
         // Construct the beacon authority.
         let authority = Authority::new_quorum(subdag);
         // Construct the block.
-        Self::from(
+        Self::from_with_aborted_transaction_reasons(
             previous_hash,
             header,
             authority,
@@ -138,6 +209,7 @@ impl<N: Network> Block<N> {
             aborted_solution_ids,
             transactions,
             aborted_transaction_ids,
+            aborted_transaction_reasons,
         )
     }
 
@@ -152,6 +224,32 @@ impl<N: Network> Block<N> {
         aborted_solution_ids: Vec<SolutionID<N>>,
         transactions: Transactions<N>,
         aborted_transaction_ids: Vec<N::TransactionID>,
+    ) -> Result<Self> {
+        Self::from_with_aborted_transaction_reasons(
+            previous_hash,
+            header,
+            authority,
+            ratifications,
+            solutions,
+            aborted_solution_ids,
+            transactions,
+            aborted_transaction_ids,
+            vec![],
+        )
+    }
+
+    /// Initializes a new block from the given previous block hash, block header,
+    /// authority, ratifications, solutions, transactions, and aborted transaction IDs with their reasons.
+    pub fn from_with_aborted_transaction_reasons(
+        previous_hash: N::BlockHash,
+        header: Header<N>,
+        authority: Authority<N>,
+        ratifications: Ratifications<N>,
+        solutions: Solutions<N>,
+        aborted_solution_ids: Vec<SolutionID<N>>,
+        transactions: Transactions<N>,
+        aborted_transaction_ids: Vec<N::TransactionID>,
+        aborted_transaction_reasons: Vec<AbortReason>,
     ) -> Result<Self> {
         // Ensure the number of aborted solutions IDs is within the allowed range.
         if aborted_solution_ids.len() > Solutions::<N>::MAX_ABORTED_SOLUTIONS {
@@ -177,6 +275,9 @@ impl<N: Network> Block<N> {
             );
         }
 
+        // Ensure the aborted transaction reasons correspond to the aborted transaction IDs.
+        Self::check_aborted_transaction_reasons_for(&header, &aborted_transaction_ids, &aborted_transaction_reasons)?;
+
         // Compute the block hash.
         let block_hash = N::hash_bhp1024(&to_bits_le![previous_hash, header.to_root()?])?;
 
@@ -215,7 +316,7 @@ impl<N: Network> Block<N> {
         }
 
         // Return the block.
-        Self::from_unchecked(
+        Self::from_unchecked_with_aborted_transaction_reasons(
             block_hash.into(),
             previous_hash,
             header,
@@ -225,6 +326,7 @@ impl<N: Network> Block<N> {
             aborted_solution_ids,
             transactions,
             aborted_transaction_ids,
+            aborted_transaction_reasons,
         )
     }
 
@@ -240,6 +342,34 @@ impl<N: Network> Block<N> {
         aborted_solution_ids: Vec<SolutionID<N>>,
         transactions: Transactions<N>,
         aborted_transaction_ids: Vec<N::TransactionID>,
+    ) -> Result<Self> {
+        Self::from_unchecked_with_aborted_transaction_reasons(
+            block_hash,
+            previous_hash,
+            header,
+            authority,
+            ratifications,
+            solutions,
+            aborted_solution_ids,
+            transactions,
+            aborted_transaction_ids,
+            vec![],
+        )
+    }
+
+    /// Initializes a new block from the given block hash, previous block hash, block header,
+    /// authority, ratifications, solutions, transactions, and aborted transaction IDs with their reasons.
+    pub fn from_unchecked_with_aborted_transaction_reasons(
+        block_hash: N::BlockHash,
+        previous_hash: N::BlockHash,
+        header: Header<N>,
+        authority: Authority<N>,
+        ratifications: Ratifications<N>,
+        solutions: Solutions<N>,
+        aborted_solution_ids: Vec<SolutionID<N>>,
+        transactions: Transactions<N>,
+        aborted_transaction_ids: Vec<N::TransactionID>,
+        aborted_transaction_reasons: Vec<AbortReason>,
     ) -> Result<Self> {
         // Return the block.
         Ok(Self {
@@ -252,6 +382,7 @@ impl<N: Network> Block<N> {
             aborted_solution_ids,
             transactions,
             aborted_transaction_ids,
+            aborted_transaction_reasons,
         })
     }
 }
@@ -296,6 +427,22 @@ impl<N: Network> Block<N> {
     pub const fn aborted_transaction_ids(&self) -> &Vec<N::TransactionID> {
         &self.aborted_transaction_ids
     }
+
+    /// Returns the reasons for the aborted transactions in this block, if they are recorded.
+    ///
+    /// Note: The reasons are not part of consensus, as they are not committed to by the block hash.
+    /// They are only checked by a node that verifies the block with the aborted transactions.
+    pub const fn aborted_transaction_reasons(&self) -> &Vec<AbortReason> {
+        &self.aborted_transaction_reasons
+    }
+
+    /// Returns the reason for the given aborted transaction ID, if it is recorded in this block.
+    pub fn aborted_transaction_reason(&self, transaction_id: &N::TransactionID) -> Option<AbortReason> {
+        self.aborted_transaction_ids
+            .iter()
+            .position(|id| id == transaction_id)
+            .and_then(|index| self.aborted_transaction_reasons.get(index).copied())
+    }
 }
 
 impl<N: Network> Block<N> {
@@ -388,6 +535,11 @@ impl<N: Network> Block<N> {
     pub const fn timestamp(&self) -> i64 {
         self.header.timestamp()
     }
+
+    /// Returns the consensus version signaled by this block.
+    pub const fn consensus_version(&self) -> u16 {
+        self.header.consensus_version()
+    }
 }
 
 impl<N: Network> Block<N> {
@@ -16,6 +16,7 @@ mod bytes;
 mod merkle;
 mod serialize;
 mod string;
+mod verify;
 
 use crate::Ratify;
 use console::{
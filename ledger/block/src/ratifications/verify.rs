@@ -0,0 +1,76 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network> Ratifications<N> {
+    /// Ensures the ratifications are well-formed.
+    ///
+    /// The structure of the ratifications is checked first, as it is inexpensive.
+    /// The contents of each ratification are then checked in parallel.
+    pub fn verify(&self) -> Result<()> {
+        // Ensure the ratifications are structurally well-formed.
+        self.verify_structure()?;
+        // Ensure each ratification is well-formed.
+        cfg_iter!(self.ratifications).try_for_each(|(_, ratify)| ratify.verify())
+    }
+
+    /// Ensures the ratifications are structurally well-formed, without checking the contents of each ratification.
+    pub fn verify_structure(&self) -> Result<()> {
+        // Ensure the number of ratifications is within the allowed range.
+        ensure!(
+            self.ratifications.len() <= Self::MAX_RATIFICATIONS,
+            "Ratifications cannot exceed {} ratifications, found {}",
+            Self::MAX_RATIFICATIONS,
+            self.ratifications.len()
+        );
+        // Count the number of each type of ratification.
+        let (mut num_genesis, mut num_block_rewards, mut num_puzzle_rewards) = (0usize, 0usize, 0usize);
+        for ratify in self.iter() {
+            match ratify {
+                Ratify::Genesis(..) => num_genesis += 1,
+                Ratify::BlockReward(..) => num_block_rewards += 1,
+                Ratify::PuzzleReward(..) => num_puzzle_rewards += 1,
+            }
+        }
+        // Ensure there is at most one of each type of ratification.
+        ensure!(num_genesis <= 1, "Ratifications contain {num_genesis} genesis ratifications");
+        ensure!(num_block_rewards <= 1, "Ratifications contain {num_block_rewards} block reward ratifications");
+        ensure!(num_puzzle_rewards <= 1, "Ratifications contain {num_puzzle_rewards} puzzle reward ratifications");
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::MainnetV0;
+
+    type CurrentNetwork = MainnetV0;
+
+    #[test]
+    fn test_verify() {
+        let rng = &mut TestRng::default();
+
+        // Ensure the sample ratifications are well-formed.
+        let ratifications = crate::ratify::test_helpers::sample_ratifications(rng);
+        assert!(Ratifications::<CurrentNetwork>::try_from(&ratifications).unwrap().verify_structure().is_ok());
+
+        // Ensure duplicate reward ratifications are rejected.
+        let mut duplicate = ratifications.clone();
+        duplicate.push(Ratify::BlockReward(u64::MAX));
+        assert!(Ratifications::<CurrentNetwork>::try_from(duplicate).unwrap().verify_structure().is_err());
+    }
+}
@@ -16,6 +16,7 @@
 mod bytes;
 mod serialize;
 mod string;
+mod verify;
 
 use console::{network::prelude::*, types::Address};
 use ledger_committee::Committee;
@@ -43,7 +44,7 @@ pub enum Ratify<N: Network> {
 impl<N: Network> Ratify<N> {
     /// Returns the ratification ID.
     pub fn to_id(&self) -> Result<N::RatificationID> {
-        Ok(N::hash_bhp1024(&self.to_bytes_le()?.to_bits_le())?.into())
+        Ok(N::hash_bhp1024_iter(bits_from_bytes_le(&self.to_bytes_le()?))?.into())
     }
 }
 
@@ -0,0 +1,111 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use ledger_committee::{MAX_DELEGATORS, MIN_DELEGATOR_STAKE, MIN_VALIDATOR_SELF_STAKE};
+
+#[cfg(not(feature = "serial"))]
+use rayon::prelude::*;
+
+impl<N: Network> Ratify<N> {
+    /// Ensures the ratification is well-formed.
+    /// Note: This check does not depend on the state of the ledger.
+    pub fn verify(&self) -> Result<()> {
+        match self {
+            Self::Genesis(committee, _, bonded_balances) => Self::verify_genesis(committee, bonded_balances),
+            Self::BlockReward(..) | Self::PuzzleReward(..) => Ok(()),
+        }
+    }
+
+    /// Ensures the genesis committee is consistent with the bonded balances.
+    /// Note: The bonded balances are checked in parallel, as they may be large.
+    fn verify_genesis(committee: &Committee<N>, bonded_balances: &BondedBalances<N>) -> Result<()> {
+        // Ensure the genesis committee round is 0.
+        ensure!(committee.starting_round() == 0, "Ratify::Genesis(..) expected a genesis committee round of 0");
+        // Ensure that the number of members in the committee does not exceed the maximum.
+        ensure!(
+            committee.members().len() <= Committee::<N>::MAX_COMMITTEE_SIZE as usize,
+            "Ratify::Genesis(..) exceeds the maximum number of committee members"
+        );
+        // Ensure that the number of delegators does not exceed the maximum.
+        ensure!(
+            bonded_balances.len().saturating_sub(committee.members().len()) <= MAX_DELEGATORS as usize,
+            "Ratify::Genesis(..) exceeds the maximum number of delegators"
+        );
+
+        // Ensure each bonded balance is well-formed.
+        cfg_iter!(bonded_balances).try_for_each(|(address, (validator_address, _, amount))| {
+            // Check that the amount meets the minimum requirement, depending on whether the address is a validator.
+            if address == validator_address {
+                ensure!(
+                    *amount >= MIN_VALIDATOR_SELF_STAKE,
+                    "Ratify::Genesis(..) the validator {address} must stake at least {MIN_VALIDATOR_SELF_STAKE}",
+                );
+            } else {
+                ensure!(
+                    *amount >= MIN_DELEGATOR_STAKE,
+                    "Ratify::Genesis(..) the delegator {address} must stake at least {MIN_DELEGATOR_STAKE}",
+                );
+                // If the address is a delegator to a committee member, check that the corresponding validator is open.
+                ensure!(
+                    !committee.is_committee_member(*validator_address)
+                        || committee.is_committee_member_open(*validator_address),
+                    "Ratify::Genesis(..) the delegator {address} is delegating to a closed validator {validator_address}",
+                );
+            }
+            Ok(())
+        })?;
+
+        // Calculate the stake per validator using the bonded balances.
+        //
+        // Note: There is no need to check the `delegated` mapping in the genesis block,
+        // because the design of `bonded_balances` by definition does not support
+        // delegating to a non-bonded validator. Thus, the assumption is that the
+        // `delegated` mapping will be correct by construction.
+        let mut stake_per_validator = IndexMap::with_capacity(committee.members().len());
+        for (address, (validator_address, _, amount)) in bonded_balances.iter() {
+            // If the address is a delegator, and the corresponding validator is not a committee member yet, then continue.
+            if address != validator_address && !committee.is_committee_member(*validator_address) {
+                continue;
+            }
+            // Accumulate the staked amount per validator.
+            let total = stake_per_validator.entry(validator_address).or_insert(0u64);
+            *total = total.saturating_add(*amount);
+        }
+        // Ensure the stake per validator matches the committee.
+        ensure!(
+            stake_per_validator.len() == committee.members().len(),
+            "Ratify::Genesis(..) the number of validators in the committee does not match the number of validators in the bonded balances",
+        );
+        // Check that the committee is consistent with the stake per validator.
+        for (validator_address, amount) in &stake_per_validator {
+            // Retrieve the expected validator stake from the committee.
+            let Some((expected_amount, _, _)) = committee.members().get(*validator_address) else {
+                bail!("Ratify::Genesis(..) found a validator in the bonded balances that is not in the committee")
+            };
+            // Ensure the staked amount matches the committee.
+            ensure!(
+                *expected_amount == *amount,
+                "Ratify::Genesis(..) inconsistent staked amount for validator {validator_address}",
+            );
+        }
+        // Ensure that the total stake matches the sum of the staked amounts.
+        ensure!(
+            committee.total_stake() == stake_per_validator.values().sum::<u64>(),
+            "Ratify::Genesis(..) incorrect total total stake for the committee"
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,73 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network> FromBytes for Receipt<N> {
+    /// Reads the receipt from the buffer.
+    #[inline]
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Read the version.
+        let version = u8::read_le(&mut reader)?;
+        // Ensure the version is valid.
+        if version != 1 {
+            return Err(error("Invalid receipt version"));
+        }
+
+        // Read the receipt.
+        let transaction_id = N::TransactionID::read_le(&mut reader)?;
+        let block_height = u32::read_le(&mut reader)?;
+        let block_hash = N::BlockHash::read_le(&mut reader)?;
+        let previous_block_hash = N::BlockHash::read_le(&mut reader)?;
+        let header_root = Field::read_le(&mut reader)?;
+        let header_leaf = HeaderLeaf::read_le(&mut reader)?;
+        let header_path = HeaderPath::read_le(&mut reader)?;
+        let transactions_path = TransactionsPath::read_le(&mut reader)?;
+        let block_path = BlockPath::read_le(&mut reader)?;
+
+        Ok(Self::from(
+            transaction_id,
+            block_height,
+            block_hash,
+            previous_block_hash,
+            header_root,
+            header_leaf,
+            header_path,
+            transactions_path,
+            block_path,
+        ))
+    }
+}
+
+impl<N: Network> ToBytes for Receipt<N> {
+    /// Writes the receipt to the buffer.
+    #[inline]
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Write the version.
+        1u8.write_le(&mut writer)?;
+
+        // Write the receipt.
+        self.transaction_id.write_le(&mut writer)?;
+        self.block_height.write_le(&mut writer)?;
+        self.block_hash.write_le(&mut writer)?;
+        self.previous_block_hash.write_le(&mut writer)?;
+        self.header_root.write_le(&mut writer)?;
+        self.header_leaf.write_le(&mut writer)?;
+        self.header_path.write_le(&mut writer)?;
+        self.transactions_path.write_le(&mut writer)?;
+        self.block_path.write_le(&mut writer)?;
+        Ok(())
+    }
+}
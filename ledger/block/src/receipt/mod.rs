@@ -0,0 +1,235 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod bytes;
+
+use crate::Block;
+use console::{
+    network::prelude::*,
+    program::{BlockPath, HeaderLeaf, HeaderPath, TransactionsPath},
+    types::Field,
+};
+
+/// A receipt is a portable proof that a transaction was included in a block on-chain.
+///
+/// The receipt binds the transaction ID to the block's transactions root (via the transactions path),
+/// the transactions root to the block header root (via the header path), the header root to the block hash,
+/// and the block hash to a global state root (via the block path).
+#[derive(Clone, PartialEq, Eq)]
+pub struct Receipt<N: Network> {
+    /// The transaction ID.
+    transaction_id: N::TransactionID,
+    /// The block height.
+    block_height: u32,
+    /// The block hash.
+    block_hash: N::BlockHash,
+    /// The previous block hash.
+    previous_block_hash: N::BlockHash,
+    /// The block header root.
+    header_root: Field<N>,
+    /// The header leaf for the transactions root.
+    header_leaf: HeaderLeaf<N>,
+    /// The Merkle path from the header leaf to the header root.
+    header_path: HeaderPath<N>,
+    /// The Merkle path from the transaction ID to the transactions root.
+    transactions_path: TransactionsPath<N>,
+    /// The Merkle path from the block hash to the global state root.
+    block_path: BlockPath<N>,
+}
+
+impl<N: Network> Receipt<N> {
+    /// Initializes a new receipt.
+    #[allow(clippy::too_many_arguments)]
+    pub fn from(
+        transaction_id: N::TransactionID,
+        block_height: u32,
+        block_hash: N::BlockHash,
+        previous_block_hash: N::BlockHash,
+        header_root: Field<N>,
+        header_leaf: HeaderLeaf<N>,
+        header_path: HeaderPath<N>,
+        transactions_path: TransactionsPath<N>,
+        block_path: BlockPath<N>,
+    ) -> Self {
+        Self {
+            transaction_id,
+            block_height,
+            block_hash,
+            previous_block_hash,
+            header_root,
+            header_leaf,
+            header_path,
+            transactions_path,
+            block_path,
+        }
+    }
+}
+
+impl<N: Network> Receipt<N> {
+    /// Returns the transaction ID.
+    pub const fn transaction_id(&self) -> &N::TransactionID {
+        &self.transaction_id
+    }
+
+    /// Returns the block height.
+    pub const fn block_height(&self) -> u32 {
+        self.block_height
+    }
+
+    /// Returns the block hash.
+    pub const fn block_hash(&self) -> N::BlockHash {
+        self.block_hash
+    }
+
+    /// Returns the previous block hash.
+    pub const fn previous_block_hash(&self) -> N::BlockHash {
+        self.previous_block_hash
+    }
+
+    /// Returns the block header root.
+    pub const fn header_root(&self) -> Field<N> {
+        self.header_root
+    }
+
+    /// Returns the header leaf.
+    pub const fn header_leaf(&self) -> &HeaderLeaf<N> {
+        &self.header_leaf
+    }
+
+    /// Returns the header path.
+    pub const fn header_path(&self) -> &HeaderPath<N> {
+        &self.header_path
+    }
+
+    /// Returns the transactions path.
+    pub const fn transactions_path(&self) -> &TransactionsPath<N> {
+        &self.transactions_path
+    }
+
+    /// Returns the block path.
+    pub const fn block_path(&self) -> &BlockPath<N> {
+        &self.block_path
+    }
+}
+
+impl<N: Network> Receipt<N> {
+    /// Checks that the receipt proves the inclusion of its transaction under the given global state root.
+    pub fn verify(&self, global_state_root: N::StateRoot) -> Result<()> {
+        // Ensure the header leaf index is 1 (Header::transactions_root).
+        ensure!(self.header_leaf.index() == 1, "Header leaf index must be 1 (Header::transactions_root)");
+        // Ensure the transactions path is valid.
+        ensure!(
+            N::verify_merkle_path_bhp(
+                &self.transactions_path,
+                &self.header_leaf.id(),
+                &self.transaction_id.to_bits_le()
+            ),
+            "Transaction '{}' does not belong to '{}' (a header leaf)",
+            self.transaction_id,
+            self.header_leaf
+        );
+        // Ensure the header path is valid.
+        ensure!(
+            N::verify_merkle_path_bhp(&self.header_path, &self.header_root, &self.header_leaf.to_bits_le()),
+            "'{}' (a header leaf) does not belong to '{}' (a block header)",
+            self.header_leaf,
+            self.block_hash
+        );
+        // Ensure the block hash is correct.
+        ensure!(
+            *self.block_hash == N::hash_bhp1024(&to_bits_le![(*self.previous_block_hash), self.header_root])?,
+            "Block hash '{}' is incorrect. Double-check the previous block hash and block header root.",
+            self.block_hash
+        );
+        // Ensure the block path corresponds to the block height.
+        ensure!(
+            *self.block_path.leaf_index() == self.block_height as u64,
+            "Block path for '{}' does not correspond to block height {}",
+            self.block_hash,
+            self.block_height
+        );
+        // Ensure the global state root is correct.
+        ensure!(
+            N::verify_merkle_path_bhp(&self.block_path, &global_state_root, &self.block_hash.to_bits_le()),
+            "'{}' (a block hash) does not belong to '{global_state_root}' (a global state root)",
+            self.block_hash
+        );
+        Ok(())
+    }
+}
+
+impl<N: Network> Block<N> {
+    /// Returns a receipt for the given transaction ID, using the given path from this block to the global state root.
+    pub fn to_receipt(&self, transaction_id: N::TransactionID, block_path: BlockPath<N>) -> Result<Receipt<N>> {
+        // Construct the transactions path.
+        let transactions_path = match self.transactions.to_path(transaction_id) {
+            Ok(transactions_path) => transactions_path,
+            Err(_) => bail!("The transaction '{transaction_id}' is not in block '{}'", self.block_hash),
+        };
+        // Construct the block header path.
+        let header_root = self.header.to_root()?;
+        let header_leaf = HeaderLeaf::<N>::new(1, self.header.transactions_root());
+        let header_path = self.header.to_path(&header_leaf)?;
+
+        Ok(Receipt::from(
+            transaction_id,
+            self.height(),
+            self.block_hash,
+            self.previous_hash,
+            header_root,
+            header_leaf,
+            header_path,
+            transactions_path,
+            block_path,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::{network::MainnetV0, program::BLOCKS_DEPTH};
+
+    type CurrentNetwork = MainnetV0;
+
+    #[test]
+    fn test_receipt_verify() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        // Sample the genesis block.
+        let block = crate::test_helpers::sample_genesis_block(rng);
+        // Construct the block tree.
+        let block_tree = CurrentNetwork::merkle_tree_bhp::<BLOCKS_DEPTH>(&[block.hash().to_bits_le()])?;
+        let global_state_root = (*block_tree.root()).into();
+        let block_path = block_tree.prove(0, &block.hash().to_bits_le())?;
+
+        for transaction_id in block.transaction_ids() {
+            // Construct the receipt.
+            let receipt = block.to_receipt(*transaction_id, block_path.clone())?;
+            // Ensure the receipt is valid.
+            receipt.verify(global_state_root)?;
+            // Ensure the receipt is not valid for a different global state root.
+            assert!(receipt.verify(Field::<CurrentNetwork>::rand(rng).into()).is_err());
+
+            // Ensure the receipt round-trips through bytes.
+            let expected_bytes = receipt.to_bytes_le()?;
+            assert_eq!(receipt, Receipt::read_le(&expected_bytes[..])?);
+        }
+
+        // Ensure a receipt cannot be constructed for a missing transaction.
+        assert!(block.to_receipt(Field::<CurrentNetwork>::rand(rng).into(), block_path).is_err());
+        Ok(())
+    }
+}
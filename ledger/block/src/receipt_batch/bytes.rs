@@ -0,0 +1,112 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network> FromBytes for BlockReceipt<N> {
+    /// Reads the block receipt from the buffer.
+    #[inline]
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Read the version.
+        let version = u8::read_le(&mut reader)?;
+        // Ensure the version is valid.
+        if version != 1 {
+            return Err(error("Invalid block receipt version"));
+        }
+
+        // Read the transaction IDs.
+        let num_transactions = u32::read_le(&mut reader)?;
+        let transaction_ids =
+            (0..num_transactions).map(|_| N::TransactionID::read_le(&mut reader)).collect::<IoResult<Vec<_>>>()?;
+        // Read the block receipt.
+        let block_height = u32::read_le(&mut reader)?;
+        let block_hash = N::BlockHash::read_le(&mut reader)?;
+        let previous_block_hash = N::BlockHash::read_le(&mut reader)?;
+        let header_root = Field::read_le(&mut reader)?;
+        let header_leaf = HeaderLeaf::read_le(&mut reader)?;
+        let header_path = HeaderPath::read_le(&mut reader)?;
+        let transactions_path = TransactionsMultiPath::read_le(&mut reader)?;
+
+        Ok(Self::from(
+            transaction_ids,
+            block_height,
+            block_hash,
+            previous_block_hash,
+            header_root,
+            header_leaf,
+            header_path,
+            transactions_path,
+        ))
+    }
+}
+
+impl<N: Network> ToBytes for BlockReceipt<N> {
+    /// Writes the block receipt to the buffer.
+    #[inline]
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Write the version.
+        1u8.write_le(&mut writer)?;
+
+        // Write the transaction IDs.
+        u32::try_from(self.transaction_ids.len()).map_err(error)?.write_le(&mut writer)?;
+        self.transaction_ids.write_le(&mut writer)?;
+        // Write the block receipt.
+        self.block_height.write_le(&mut writer)?;
+        self.block_hash.write_le(&mut writer)?;
+        self.previous_block_hash.write_le(&mut writer)?;
+        self.header_root.write_le(&mut writer)?;
+        self.header_leaf.write_le(&mut writer)?;
+        self.header_path.write_le(&mut writer)?;
+        self.transactions_path.write_le(&mut writer)?;
+        Ok(())
+    }
+}
+
+impl<N: Network> FromBytes for ReceiptBatch<N> {
+    /// Reads the receipt batch from the buffer.
+    #[inline]
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Read the version.
+        let version = u8::read_le(&mut reader)?;
+        // Ensure the version is valid.
+        if version != 1 {
+            return Err(error("Invalid receipt batch version"));
+        }
+
+        // Read the block receipts.
+        let num_blocks = u32::read_le(&mut reader)?;
+        let block_receipts =
+            (0..num_blocks).map(|_| BlockReceipt::read_le(&mut reader)).collect::<IoResult<Vec<_>>>()?;
+        // Read the block multi-path.
+        let block_path = BlockMultiPath::read_le(&mut reader)?;
+
+        Ok(Self::from(block_receipts, block_path))
+    }
+}
+
+impl<N: Network> ToBytes for ReceiptBatch<N> {
+    /// Writes the receipt batch to the buffer.
+    #[inline]
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Write the version.
+        1u8.write_le(&mut writer)?;
+
+        // Write the block receipts.
+        u32::try_from(self.block_receipts.len()).map_err(error)?.write_le(&mut writer)?;
+        self.block_receipts.write_le(&mut writer)?;
+        // Write the block multi-path.
+        self.block_path.write_le(&mut writer)
+    }
+}
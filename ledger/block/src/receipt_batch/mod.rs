@@ -0,0 +1,274 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod bytes;
+
+use crate::Block;
+use console::{
+    network::prelude::*,
+    program::{BlockMultiPath, HeaderLeaf, HeaderPath, TransactionsMultiPath},
+    types::Field,
+};
+
+use std::collections::HashSet;
+
+/// A block receipt is a proof that a set of transactions was included in a block.
+///
+/// The block receipt binds the transaction IDs to the block's transactions root (via one transactions multi-path),
+/// the transactions root to the block header root (via the header path), and the header root to the block hash.
+#[derive(Clone, PartialEq, Eq)]
+pub struct BlockReceipt<N: Network> {
+    /// The transaction IDs, in the order of the block transactions.
+    transaction_ids: Vec<N::TransactionID>,
+    /// The block height.
+    block_height: u32,
+    /// The block hash.
+    block_hash: N::BlockHash,
+    /// The previous block hash.
+    previous_block_hash: N::BlockHash,
+    /// The block header root.
+    header_root: Field<N>,
+    /// The header leaf for the transactions root.
+    header_leaf: HeaderLeaf<N>,
+    /// The Merkle path from the header leaf to the header root.
+    header_path: HeaderPath<N>,
+    /// The Merkle multi-path from the transaction IDs to the transactions root.
+    transactions_path: TransactionsMultiPath<N>,
+}
+
+/// A receipt batch is a portable proof that a set of transactions was included in a set of blocks on-chain.
+///
+/// The receipt batch contains one block receipt per block, and binds the block hashes to a global state root
+/// (via one block multi-path), so siblings shared by the transactions and blocks are only included once.
+#[derive(Clone, PartialEq, Eq)]
+pub struct ReceiptBatch<N: Network> {
+    /// The block receipts, in increasing order of block height.
+    block_receipts: Vec<BlockReceipt<N>>,
+    /// The Merkle multi-path from the block hashes to the global state root.
+    block_path: BlockMultiPath<N>,
+}
+
+impl<N: Network> BlockReceipt<N> {
+    /// Initializes a new block receipt.
+    #[allow(clippy::too_many_arguments)]
+    pub fn from(
+        transaction_ids: Vec<N::TransactionID>,
+        block_height: u32,
+        block_hash: N::BlockHash,
+        previous_block_hash: N::BlockHash,
+        header_root: Field<N>,
+        header_leaf: HeaderLeaf<N>,
+        header_path: HeaderPath<N>,
+        transactions_path: TransactionsMultiPath<N>,
+    ) -> Self {
+        Self {
+            transaction_ids,
+            block_height,
+            block_hash,
+            previous_block_hash,
+            header_root,
+            header_leaf,
+            header_path,
+            transactions_path,
+        }
+    }
+
+    /// Returns the transaction IDs, in the order of the block transactions.
+    pub fn transaction_ids(&self) -> &[N::TransactionID] {
+        &self.transaction_ids
+    }
+
+    /// Returns the block height.
+    pub const fn block_height(&self) -> u32 {
+        self.block_height
+    }
+
+    /// Returns the block hash.
+    pub const fn block_hash(&self) -> N::BlockHash {
+        self.block_hash
+    }
+
+    /// Returns the previous block hash.
+    pub const fn previous_block_hash(&self) -> N::BlockHash {
+        self.previous_block_hash
+    }
+
+    /// Returns the block header root.
+    pub const fn header_root(&self) -> Field<N> {
+        self.header_root
+    }
+
+    /// Returns the header leaf.
+    pub const fn header_leaf(&self) -> &HeaderLeaf<N> {
+        &self.header_leaf
+    }
+
+    /// Returns the header path.
+    pub const fn header_path(&self) -> &HeaderPath<N> {
+        &self.header_path
+    }
+
+    /// Returns the transactions multi-path.
+    pub const fn transactions_path(&self) -> &TransactionsMultiPath<N> {
+        &self.transactions_path
+    }
+
+    /// Checks that the block receipt proves the inclusion of its transactions in its block hash.
+    pub fn verify(&self) -> Result<()> {
+        // Ensure the header leaf index is 1 (Header::transactions_root).
+        ensure!(self.header_leaf.index() == 1, "Header leaf index must be 1 (Header::transactions_root)");
+        // Ensure the transactions multi-path is valid.
+        let leaves = self.transaction_ids.iter().map(|transaction_id| transaction_id.to_bits_le()).collect::<Vec<_>>();
+        ensure!(
+            N::verify_merkle_multi_path_bhp(&self.transactions_path, &self.header_leaf.id(), &leaves),
+            "The transactions do not belong to '{}' (a header leaf)",
+            self.header_leaf
+        );
+        // Ensure the header path is valid.
+        ensure!(
+            N::verify_merkle_path_bhp(&self.header_path, &self.header_root, &self.header_leaf.to_bits_le()),
+            "'{}' (a header leaf) does not belong to '{}' (a block header)",
+            self.header_leaf,
+            self.block_hash
+        );
+        // Ensure the block hash is correct.
+        ensure!(
+            *self.block_hash == N::hash_bhp1024(&to_bits_le![(*self.previous_block_hash), self.header_root])?,
+            "Block hash '{}' is incorrect. Double-check the previous block hash and block header root.",
+            self.block_hash
+        );
+        Ok(())
+    }
+}
+
+impl<N: Network> ReceiptBatch<N> {
+    /// Initializes a new receipt batch.
+    pub fn from(block_receipts: Vec<BlockReceipt<N>>, block_path: BlockMultiPath<N>) -> Self {
+        Self { block_receipts, block_path }
+    }
+
+    /// Returns the block receipts, in increasing order of block height.
+    pub fn block_receipts(&self) -> &[BlockReceipt<N>] {
+        &self.block_receipts
+    }
+
+    /// Returns the block multi-path.
+    pub const fn block_path(&self) -> &BlockMultiPath<N> {
+        &self.block_path
+    }
+
+    /// Returns the transaction IDs in the receipt batch.
+    pub fn transaction_ids(&self) -> impl '_ + Iterator<Item = &N::TransactionID> {
+        self.block_receipts.iter().flat_map(|block_receipt| block_receipt.transaction_ids())
+    }
+
+    /// Checks that the receipt batch proves the inclusion of its transactions under the given global state root.
+    pub fn verify(&self, global_state_root: N::StateRoot) -> Result<()> {
+        // Ensure each block receipt is valid.
+        for block_receipt in &self.block_receipts {
+            block_receipt.verify()?;
+        }
+        // Ensure the block multi-path corresponds to the block heights.
+        ensure!(
+            self.block_path.leaf_indices().len() == self.block_receipts.len()
+                && self
+                    .block_path
+                    .leaf_indices()
+                    .iter()
+                    .zip_eq(&self.block_receipts)
+                    .all(|(leaf_index, block_receipt)| **leaf_index == block_receipt.block_height as u64),
+            "Block multi-path does not correspond to the block heights"
+        );
+        // Ensure the global state root is correct.
+        let leaves =
+            self.block_receipts.iter().map(|block_receipt| block_receipt.block_hash.to_bits_le()).collect::<Vec<_>>();
+        ensure!(
+            N::verify_merkle_multi_path_bhp(&self.block_path, &global_state_root, &leaves),
+            "The block hashes do not belong to '{global_state_root}' (a global state root)"
+        );
+        Ok(())
+    }
+}
+
+impl<N: Network> Block<N> {
+    /// Returns a block receipt for the given transaction IDs, which must be unique and in this block.
+    pub fn to_block_receipt(&self, transaction_ids: &[N::TransactionID]) -> Result<BlockReceipt<N>> {
+        // Construct the transactions multi-path.
+        let transactions_path = self.transactions.to_multi_path(transaction_ids)?;
+        // Order the transaction IDs as in the block transactions.
+        let requested = transaction_ids.iter().collect::<HashSet<_>>();
+        let transaction_ids =
+            self.transactions.transaction_ids().filter(|id| requested.contains(id)).copied().collect();
+        // Construct the block header path.
+        let header_root = self.header.to_root()?;
+        let header_leaf = HeaderLeaf::<N>::new(1, self.header.transactions_root());
+        let header_path = self.header.to_path(&header_leaf)?;
+
+        Ok(BlockReceipt::from(
+            transaction_ids,
+            self.height(),
+            self.block_hash,
+            self.previous_hash,
+            header_root,
+            header_leaf,
+            header_path,
+            transactions_path,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::{network::MainnetV0, program::BLOCKS_DEPTH};
+
+    use std::collections::BTreeMap;
+
+    type CurrentNetwork = MainnetV0;
+
+    #[test]
+    fn test_receipt_batch_verify() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        // Sample the genesis block.
+        let block = crate::test_helpers::sample_genesis_block(rng);
+        let transaction_ids = block.transaction_ids().copied().collect::<Vec<_>>();
+        // Construct the block tree, with the genesis block and a sampled block hash.
+        let leaves = [block.hash().to_bits_le(), Field::<CurrentNetwork>::rand(rng).to_bits_le()];
+        let block_tree = CurrentNetwork::merkle_tree_bhp::<BLOCKS_DEPTH>(&leaves)?;
+        let global_state_root = (*block_tree.root()).into();
+        let block_path = block_tree.prove_many(&BTreeMap::from([(0, leaves[0].clone())]))?;
+
+        // Construct the receipt batch, for the transactions in reverse order.
+        let reversed_ids = transaction_ids.iter().rev().copied().collect::<Vec<_>>();
+        let receipt_batch = ReceiptBatch::from(vec![block.to_block_receipt(&reversed_ids)?], block_path.clone());
+        // Ensure the transaction IDs are in block order.
+        assert_eq!(receipt_batch.transaction_ids().copied().collect::<Vec<_>>(), transaction_ids);
+        // Ensure the receipt batch is valid.
+        receipt_batch.verify(global_state_root)?;
+        // Ensure the receipt batch is not valid for a different global state root.
+        assert!(receipt_batch.verify(Field::<CurrentNetwork>::rand(rng).into()).is_err());
+        // Ensure the receipt batch round-trips through bytes.
+        let expected_bytes = receipt_batch.to_bytes_le()?;
+        assert_eq!(receipt_batch, ReceiptBatch::read_le(&expected_bytes[..])?);
+
+        // Ensure a receipt batch without the block receipt is not valid.
+        assert!(ReceiptBatch::from(vec![], block_path).verify(global_state_root).is_err());
+        // Ensure a block receipt cannot be constructed for a missing or duplicate transaction.
+        assert!(block.to_block_receipt(&[Field::<CurrentNetwork>::rand(rng).into()]).is_err());
+        assert!(block.to_block_receipt(&[transaction_ids[0], transaction_ids[0]]).is_err());
+        Ok(())
+    }
+}
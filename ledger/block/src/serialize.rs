@@ -30,6 +30,9 @@ impl<N: Network> Serialize for Block<N> {
                 block.serialize_field("aborted_solution_ids", &self.aborted_solution_ids)?;
                 block.serialize_field("transactions", &self.transactions)?;
                 block.serialize_field("aborted_transaction_ids", &self.aborted_transaction_ids)?;
+                if !self.aborted_transaction_reasons.is_empty() {
+                    block.serialize_field("aborted_transaction_reasons", &self.aborted_transaction_reasons)?;
+                }
                 block.end()
             }
             false => ToBytesSerializer::serialize_with_size_encoding(self, serializer),
@@ -44,9 +47,14 @@ impl<'de, N: Network> Deserialize<'de> for Block<N> {
             true => {
                 let mut block = serde_json::Value::deserialize(deserializer)?;
                 let block_hash: N::BlockHash = DeserializeExt::take_from_value::<D>(&mut block, "block_hash")?;
+                // Recover the aborted transaction reasons, if they are recorded.
+                let aborted_transaction_reasons = match block.get("aborted_transaction_reasons") {
+                    Some(_) => DeserializeExt::take_from_value::<D>(&mut block, "aborted_transaction_reasons")?,
+                    None => vec![],
+                };
 
                 // Recover the block.
-                let block = Self::from(
+                let block = Self::from_with_aborted_transaction_reasons(
                     DeserializeExt::take_from_value::<D>(&mut block, "previous_hash")?,
                     DeserializeExt::take_from_value::<D>(&mut block, "header")?,
                     DeserializeExt::take_from_value::<D>(&mut block, "authority")?,
@@ -55,6 +63,7 @@ impl<'de, N: Network> Deserialize<'de> for Block<N> {
                     DeserializeExt::take_from_value::<D>(&mut block, "aborted_solution_ids")?,
                     DeserializeExt::take_from_value::<D>(&mut block, "transactions")?,
                     DeserializeExt::take_from_value::<D>(&mut block, "aborted_transaction_ids")?,
+                    aborted_transaction_reasons,
                 )
                 .map_err(de::Error::custom)?;
 
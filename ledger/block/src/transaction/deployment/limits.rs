@@ -0,0 +1,217 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+/// The usage of a single deployment limit.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct DeploymentLimit {
+    /// The name of the limit.
+    name: &'static str,
+    /// The value used by the deployment.
+    value: u64,
+    /// The maximum value allowed by the network.
+    limit: u64,
+}
+
+impl DeploymentLimit {
+    /// Initializes a new deployment limit.
+    pub const fn new(name: &'static str, value: u64, limit: u64) -> Self {
+        Self { name, value, limit }
+    }
+
+    /// Returns the name of the limit.
+    pub const fn name(&self) -> &'static str {
+        self.name
+    }
+
+    /// Returns the value used by the deployment.
+    pub const fn value(&self) -> u64 {
+        self.value
+    }
+
+    /// Returns the maximum value allowed by the network.
+    pub const fn limit(&self) -> u64 {
+        self.limit
+    }
+
+    /// Returns the value as a percentage of the limit.
+    pub fn percentage(&self) -> f64 {
+        match self.limit {
+            0 => f64::INFINITY,
+            limit => (self.value as f64 / limit as f64) * 100.0,
+        }
+    }
+
+    /// Returns `true` if the value exceeds the limit.
+    pub const fn is_exceeded(&self) -> bool {
+        self.value > self.limit
+    }
+}
+
+impl Display for DeploymentLimit {
+    /// Prints the limit as `name: value / limit (percentage%)`.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "{}: {} / {} ({:.2}%)", self.name, self.value, self.limit, self.percentage())
+    }
+}
+
+/// A pre-flight report of the size and resource usage of a deployment, relative to the network limits.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct DeploymentLimitsReport {
+    /// The size of the program in bytes.
+    program_size: DeploymentLimit,
+    /// The size of the deployment in bytes.
+    deployment_size: DeploymentLimit,
+    /// The number of functions in the program.
+    num_functions: DeploymentLimit,
+    /// The number of variables across all functions.
+    num_variables: DeploymentLimit,
+    /// The number of constraints across all functions.
+    num_constraints: DeploymentLimit,
+}
+
+impl DeploymentLimitsReport {
+    /// Returns the usage of the program size.
+    pub const fn program_size(&self) -> &DeploymentLimit {
+        &self.program_size
+    }
+
+    /// Returns the usage of the deployment size.
+    pub const fn deployment_size(&self) -> &DeploymentLimit {
+        &self.deployment_size
+    }
+
+    /// Returns the usage of the number of functions.
+    pub const fn num_functions(&self) -> &DeploymentLimit {
+        &self.num_functions
+    }
+
+    /// Returns the usage of the number of variables.
+    pub const fn num_variables(&self) -> &DeploymentLimit {
+        &self.num_variables
+    }
+
+    /// Returns the usage of the number of constraints.
+    pub const fn num_constraints(&self) -> &DeploymentLimit {
+        &self.num_constraints
+    }
+
+    /// Returns an iterator over all limits in the report.
+    pub fn limits(&self) -> impl Iterator<Item = &DeploymentLimit> {
+        [&self.program_size, &self.deployment_size, &self.num_functions, &self.num_variables, &self.num_constraints]
+            .into_iter()
+    }
+
+    /// Returns an iterator over the limits that are exceeded.
+    pub fn exceeded(&self) -> impl Iterator<Item = &DeploymentLimit> {
+        self.limits().filter(|limit| limit.is_exceeded())
+    }
+
+    /// Returns `true` if all limits are respected.
+    pub fn is_within_limits(&self) -> bool {
+        self.exceeded().next().is_none()
+    }
+}
+
+impl Display for DeploymentLimitsReport {
+    /// Prints each limit on a separate line.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        for limit in self.limits() {
+            match limit.is_exceeded() {
+                true => writeln!(f, "{limit} [exceeded]")?,
+                false => writeln!(f, "{limit}")?,
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<N: Network> Deployment<N> {
+    /// Returns a report of the size and resource usage of this deployment, relative to the network limits.
+    ///
+    /// Note: The report does not reject a deployment that exceeds a limit,
+    /// so that all violations can be surfaced before the deployment is broadcast.
+    pub fn limits_report(&self) -> Result<DeploymentLimitsReport> {
+        Ok(DeploymentLimitsReport {
+            program_size: DeploymentLimit::new(
+                "program size (bytes)",
+                u64::try_from(self.program.to_string().len())?,
+                u64::try_from(N::MAX_PROGRAM_SIZE)?,
+            ),
+            deployment_size: DeploymentLimit::new(
+                "deployment size (bytes)",
+                self.size_in_bytes()?,
+                u64::try_from(N::MAX_TRANSACTION_SIZE)?,
+            ),
+            num_functions: DeploymentLimit::new(
+                "functions",
+                u64::try_from(self.program.functions().len())?,
+                u64::try_from(N::MAX_FUNCTIONS)?,
+            ),
+            num_variables: DeploymentLimit::new(
+                "variables",
+                self.num_combined_variables()?,
+                N::MAX_DEPLOYMENT_VARIABLES,
+            ),
+            num_constraints: DeploymentLimit::new(
+                "constraints",
+                self.num_combined_constraints()?,
+                N::MAX_DEPLOYMENT_CONSTRAINTS,
+            ),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::MainnetV0;
+
+    type CurrentNetwork = MainnetV0;
+
+    #[test]
+    fn test_deployment_limit() {
+        let limit = DeploymentLimit::new("functions", 8, 32);
+        assert_eq!(limit.percentage(), 25.0);
+        assert!(!limit.is_exceeded());
+        assert_eq!(limit.to_string(), "functions: 8 / 32 (25.00%)");
+
+        let limit = DeploymentLimit::new("functions", 33, 32);
+        assert!(limit.is_exceeded());
+        assert!(limit.percentage() > 100.0);
+    }
+
+    #[test]
+    fn test_limits_report() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        // Construct a new deployment.
+        let deployment = test_helpers::sample_deployment(rng);
+        let report = deployment.limits_report()?;
+
+        // Check the report.
+        assert!(report.is_within_limits());
+        assert_eq!(report.num_functions().value(), 1);
+        assert_eq!(report.num_functions().limit(), CurrentNetwork::MAX_FUNCTIONS as u64);
+        assert_eq!(report.program_size().value(), deployment.program().to_string().len() as u64);
+        assert_eq!(report.deployment_size().value(), deployment.size_in_bytes()?);
+        assert_eq!(report.num_variables().value(), deployment.num_combined_variables()?);
+        assert_eq!(report.num_constraints().value(), deployment.num_combined_constraints()?);
+        assert!(report.limits().all(|limit| limit.percentage() < 100.0));
+        assert_eq!(report.to_string().lines().count(), 5);
+        Ok(())
+    }
+}
@@ -0,0 +1,285 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+use console::account::{Address, PrivateKey, Signature};
+
+/// An entry of an import manifest, recording the source and checksum of an imported program.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ImportManifestEntry<N: Network> {
+    /// The program ID of the import.
+    program_id: ProgramID<N>,
+    /// The source of the import, e.g. a network name, registry URL, or path.
+    source: String,
+    /// The checksum of the imported program.
+    checksum: Field<N>,
+}
+
+impl<N: Network> ImportManifestEntry<N> {
+    /// The maximum number of bytes in the source of an import.
+    pub const MAX_SOURCE_SIZE: usize = 256;
+
+    /// Initializes a new manifest entry for the given imported program, from the given source.
+    pub fn new(program: &Program<N>, source: impl Into<String>) -> Result<Self> {
+        let source = source.into();
+        ensure!(source.len() <= Self::MAX_SOURCE_SIZE, "The import source exceeds {} bytes", Self::MAX_SOURCE_SIZE);
+        Ok(Self { program_id: *program.id(), source, checksum: Self::compute_checksum(program)? })
+    }
+
+    /// Initializes a new manifest entry for the given program ID, pinned to the given checksum, from the given source.
+    /// The manifest is only verified if the imported program in the process matches the pinned checksum.
+    pub fn from_checksum(program_id: ProgramID<N>, source: impl Into<String>, checksum: Field<N>) -> Result<Self> {
+        let source = source.into();
+        ensure!(source.len() <= Self::MAX_SOURCE_SIZE, "The import source exceeds {} bytes", Self::MAX_SOURCE_SIZE);
+        Ok(Self { program_id, source, checksum })
+    }
+
+    /// Returns the checksum of the given program.
+    pub fn compute_checksum(program: &Program<N>) -> Result<Field<N>> {
+        program.to_checksum()
+    }
+
+    /// Returns the program ID of the import.
+    pub const fn program_id(&self) -> &ProgramID<N> {
+        &self.program_id
+    }
+
+    /// Returns the source of the import.
+    pub fn source(&self) -> &str {
+        &self.source
+    }
+
+    /// Returns the checksum of the imported program.
+    pub const fn checksum(&self) -> &Field<N> {
+        &self.checksum
+    }
+}
+
+impl<N: Network> FromBytes for ImportManifestEntry<N> {
+    /// Reads the manifest entry from a buffer.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Read the program ID.
+        let program_id = ProgramID::read_le(&mut reader)?;
+        // Read the source.
+        let source_size = u16::read_le(&mut reader)? as usize;
+        if source_size > Self::MAX_SOURCE_SIZE {
+            return Err(error(format!("The import source exceeds {} bytes", Self::MAX_SOURCE_SIZE)));
+        }
+        let mut source = vec![0u8; source_size];
+        reader.read_exact(&mut source)?;
+        let source = String::from_utf8(source).map_err(error)?;
+        // Read the checksum.
+        let checksum = Field::read_le(&mut reader)?;
+        Ok(Self { program_id, source, checksum })
+    }
+}
+
+impl<N: Network> ToBytes for ImportManifestEntry<N> {
+    /// Writes the manifest entry to a buffer.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Write the program ID.
+        self.program_id.write_le(&mut writer)?;
+        // Write the source.
+        u16::try_from(self.source.len()).map_err(error)?.write_le(&mut writer)?;
+        writer.write_all(self.source.as_bytes())?;
+        // Write the checksum.
+        self.checksum.write_le(&mut writer)
+    }
+}
+
+/// An owner-signed manifest for a deployment, enumerating the exact source and checksum
+/// of each imported program at deploy time.
+///
+/// The manifest is a companion to the deployment, and is not part of the deployment transaction.
+/// It can be verified against a process with `Process::verify_import_manifest`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ImportManifest<N: Network> {
+    /// The deployment ID.
+    deployment_id: Field<N>,
+    /// The program ID of the deployment.
+    program_id: ProgramID<N>,
+    /// The manifest entries, in the order of the program imports.
+    imports: Vec<ImportManifestEntry<N>>,
+    /// The address of the program owner.
+    owner: Address<N>,
+    /// The signature of the program owner, over the manifest ID.
+    signature: Signature<N>,
+}
+
+impl<N: Network> ImportManifest<N> {
+    /// Initializes a new import manifest for the given deployment, signed by the given private key.
+    pub fn new<R: Rng + CryptoRng>(
+        private_key: &PrivateKey<N>,
+        deployment: &Deployment<N>,
+        imports: Vec<ImportManifestEntry<N>>,
+        rng: &mut R,
+    ) -> Result<Self> {
+        // Ensure the entries correspond to the program imports.
+        let program_imports = deployment.program().imports().keys();
+        ensure!(
+            program_imports.len() == imports.len()
+                && program_imports.zip_eq(&imports).all(|(program_id, entry)| program_id == entry.program_id()),
+            "The import manifest entries do not match the imports of '{}'",
+            deployment.program_id()
+        );
+        // Compute the manifest ID.
+        let deployment_id = deployment.to_deployment_id()?;
+        let program_id = *deployment.program_id();
+        let owner = Address::try_from(private_key)?;
+        let manifest_id = Self::compute_manifest_id(deployment_id, program_id, &imports, owner)?;
+        // Sign the manifest ID.
+        let signature = Signature::sign(private_key, &[manifest_id], rng)?;
+        Ok(Self { deployment_id, program_id, imports, owner, signature })
+    }
+
+    /// Returns the deployment ID.
+    pub const fn deployment_id(&self) -> &Field<N> {
+        &self.deployment_id
+    }
+
+    /// Returns the program ID of the deployment.
+    pub const fn program_id(&self) -> &ProgramID<N> {
+        &self.program_id
+    }
+
+    /// Returns the manifest entries.
+    pub fn imports(&self) -> &[ImportManifestEntry<N>] {
+        &self.imports
+    }
+
+    /// Returns the address of the program owner.
+    pub const fn owner(&self) -> &Address<N> {
+        &self.owner
+    }
+
+    /// Returns the signature of the program owner.
+    pub const fn signature(&self) -> &Signature<N> {
+        &self.signature
+    }
+
+    /// Returns the manifest ID, which is the message signed by the program owner.
+    pub fn to_manifest_id(&self) -> Result<Field<N>> {
+        Self::compute_manifest_id(self.deployment_id, self.program_id, &self.imports, self.owner)
+    }
+
+    /// Returns `true` if the signature of the program owner is valid.
+    pub fn verify_signature(&self) -> bool {
+        match self.to_manifest_id() {
+            Ok(manifest_id) => self.signature.verify(&self.owner, &[manifest_id]),
+            Err(_) => false,
+        }
+    }
+
+    /// Computes the manifest ID from the given components.
+    fn compute_manifest_id(
+        deployment_id: Field<N>,
+        program_id: ProgramID<N>,
+        imports: &[ImportManifestEntry<N>],
+        owner: Address<N>,
+    ) -> Result<Field<N>> {
+        let mut preimage = Vec::new();
+        deployment_id.write_le(&mut preimage)?;
+        program_id.write_le(&mut preimage)?;
+        u8::try_from(imports.len())?.write_le(&mut preimage)?;
+        for entry in imports {
+            entry.write_le(&mut preimage)?;
+        }
+        owner.write_le(&mut preimage)?;
+        N::hash_bhp1024(&preimage.to_bits_le())
+    }
+}
+
+impl<N: Network> FromBytes for ImportManifest<N> {
+    /// Reads the import manifest from a buffer.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Read the version.
+        let version = u8::read_le(&mut reader)?;
+        // Ensure the version is valid.
+        if version != 1 {
+            return Err(error("Invalid import manifest version"));
+        }
+        // Read the deployment ID.
+        let deployment_id = Field::read_le(&mut reader)?;
+        // Read the program ID.
+        let program_id = ProgramID::read_le(&mut reader)?;
+        // Read the manifest entries.
+        let num_imports = u8::read_le(&mut reader)?;
+        let imports = (0..num_imports).map(|_| ImportManifestEntry::read_le(&mut reader)).collect::<IoResult<_>>()?;
+        // Read the owner.
+        let owner = Address::read_le(&mut reader)?;
+        // Read the signature.
+        let signature = Signature::read_le(&mut reader)?;
+        Ok(Self { deployment_id, program_id, imports, owner, signature })
+    }
+}
+
+impl<N: Network> ToBytes for ImportManifest<N> {
+    /// Writes the import manifest to a buffer.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Write the version.
+        1u8.write_le(&mut writer)?;
+        // Write the deployment ID.
+        self.deployment_id.write_le(&mut writer)?;
+        // Write the program ID.
+        self.program_id.write_le(&mut writer)?;
+        // Write the manifest entries.
+        u8::try_from(self.imports.len()).map_err(error)?.write_le(&mut writer)?;
+        for entry in &self.imports {
+            entry.write_le(&mut writer)?;
+        }
+        // Write the owner.
+        self.owner.write_le(&mut writer)?;
+        // Write the signature.
+        self.signature.write_le(&mut writer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::MainnetV0;
+
+    type CurrentNetwork = MainnetV0;
+
+    #[test]
+    fn test_import_manifest() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        // Sample a deployment, with no imports.
+        let deployment = test_helpers::sample_deployment(rng);
+        let private_key = PrivateKey::<CurrentNetwork>::new(rng)?;
+
+        // Ensure entries that do not match the imports are rejected.
+        let entry = ImportManifestEntry::new(deployment.program(), "local")?;
+        assert!(ImportManifest::new(&private_key, &deployment, vec![entry], rng).is_err());
+
+        // Sign the manifest.
+        let manifest = ImportManifest::new(&private_key, &deployment, vec![], rng)?;
+        assert_eq!(manifest.deployment_id(), &deployment.to_deployment_id()?);
+        assert_eq!(manifest.owner(), &Address::try_from(&private_key)?);
+        assert!(manifest.verify_signature());
+
+        // Ensure the manifest round trips through bytes.
+        let recovered = ImportManifest::read_le(&manifest.to_bytes_le()?[..])?;
+        assert_eq!(manifest, recovered);
+        assert!(recovered.verify_signature());
+
+        // Ensure a tampered manifest fails verification.
+        let tampered = ImportManifest { owner: Address::try_from(PrivateKey::new(rng)?)?, ..manifest };
+        assert!(!tampered.verify_signature());
+        Ok(())
+    }
+}
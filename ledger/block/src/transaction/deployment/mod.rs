@@ -16,6 +16,10 @@
 #![allow(clippy::type_complexity)]
 
 mod bytes;
+mod limits;
+pub use limits::*;
+mod manifest;
+pub use manifest::*;
 mod serialize;
 mod string;
 
@@ -0,0 +1,298 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use super::*;
+use crate::{Fee, Input, Output};
+use console::{
+    program::{Argument, Future, Literal, Plaintext},
+    types::Address,
+};
+
+use indexmap::IndexSet;
+
+/// The publicly-visible footprint of a transition.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TransitionAudit<N: Network> {
+    /// The ID of the transition.
+    transition_id: N::TransitionID,
+    /// The program ID of the transition.
+    program_id: ProgramID<N>,
+    /// The function name of the transition.
+    function_name: Identifier<N>,
+    /// The constant and public inputs, with their index in the inputs of the transition.
+    public_inputs: Vec<(usize, Plaintext<N>)>,
+    /// The constant and public outputs, with their index in the outputs of the transition.
+    public_outputs: Vec<(usize, Plaintext<N>)>,
+    /// The futures, whose arguments are passed to the finalize scope in the clear.
+    futures: Vec<Future<N>>,
+    /// The number of private inputs.
+    num_private_inputs: usize,
+    /// The number of private outputs.
+    num_private_outputs: usize,
+    /// The number of records that are consumed.
+    num_records_consumed: usize,
+    /// The number of records that are created.
+    num_records_created: usize,
+    /// The number of external records that flow through the transition.
+    num_external_records: usize,
+}
+
+impl<N: Network> TransitionAudit<N> {
+    /// Returns the ID of the transition.
+    pub const fn transition_id(&self) -> &N::TransitionID {
+        &self.transition_id
+    }
+
+    /// Returns the program ID of the transition.
+    pub const fn program_id(&self) -> &ProgramID<N> {
+        &self.program_id
+    }
+
+    /// Returns the function name of the transition.
+    pub const fn function_name(&self) -> &Identifier<N> {
+        &self.function_name
+    }
+
+    /// Returns the constant and public inputs, with their index in the inputs of the transition.
+    pub fn public_inputs(&self) -> &[(usize, Plaintext<N>)] {
+        &self.public_inputs
+    }
+
+    /// Returns the constant and public outputs, with their index in the outputs of the transition.
+    pub fn public_outputs(&self) -> &[(usize, Plaintext<N>)] {
+        &self.public_outputs
+    }
+
+    /// Returns the futures, whose arguments are passed to the finalize scope in the clear.
+    pub fn futures(&self) -> &[Future<N>] {
+        &self.futures
+    }
+
+    /// Returns the number of private inputs.
+    pub const fn num_private_inputs(&self) -> usize {
+        self.num_private_inputs
+    }
+
+    /// Returns the number of private outputs.
+    pub const fn num_private_outputs(&self) -> usize {
+        self.num_private_outputs
+    }
+
+    /// Returns the number of records that are consumed.
+    pub const fn num_records_consumed(&self) -> usize {
+        self.num_records_consumed
+    }
+
+    /// Returns the number of records that are created.
+    pub const fn num_records_created(&self) -> usize {
+        self.num_records_created
+    }
+
+    /// Returns the number of external records that flow through the transition.
+    pub const fn num_external_records(&self) -> usize {
+        self.num_external_records
+    }
+
+    /// Returns `true` if the transition reveals no inputs, outputs, or finalize arguments.
+    pub fn is_fully_private(&self) -> bool {
+        self.public_inputs.is_empty() && self.public_outputs.is_empty() && self.futures.is_empty()
+    }
+}
+
+/// The publicly-visible footprint of a fee.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct FeeAudit<N: Network> {
+    /// The payer, if the fee is public.
+    payer: Option<Address<N>>,
+    /// The fee amount in microcredits, which is public for both public and private fees.
+    amount: u64,
+}
+
+impl<N: Network> FeeAudit<N> {
+    /// Returns the payer, if the fee is public.
+    pub const fn payer(&self) -> Option<Address<N>> {
+        self.payer
+    }
+
+    /// Returns the fee amount in microcredits, which is public for both public and private fees.
+    pub const fn amount(&self) -> u64 {
+        self.amount
+    }
+}
+
+/// The publicly-visible footprint of an execution, and its fee.
+///
+/// Note: The program ID and function name of every transition, and the number of its inputs and outputs,
+/// are always public, as are the serial numbers of the consumed records and the commitments of the created records.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ExecutionAudit<N: Network> {
+    /// The footprint of each transition, in the order of the execution.
+    transitions: Vec<TransitionAudit<N>>,
+    /// The footprint of the fee, if there is one.
+    fee: Option<FeeAudit<N>>,
+}
+
+impl<N: Network> ExecutionAudit<N> {
+    /// Returns the footprint of each transition, in the order of the execution.
+    pub fn transitions(&self) -> &[TransitionAudit<N>] {
+        &self.transitions
+    }
+
+    /// Returns the footprint of the fee, if there is one.
+    pub const fn fee(&self) -> Option<&FeeAudit<N>> {
+        self.fee.as_ref()
+    }
+
+    /// Returns the number of records that are consumed.
+    pub fn num_records_consumed(&self) -> usize {
+        self.transitions.iter().map(TransitionAudit::num_records_consumed).sum()
+    }
+
+    /// Returns the number of records that are created.
+    pub fn num_records_created(&self) -> usize {
+        self.transitions.iter().map(TransitionAudit::num_records_created).sum()
+    }
+
+    /// Returns the addresses that are revealed in the public inputs, public outputs, and finalize arguments,
+    /// and the fee payer, deduplicated in the order of their first occurrence.
+    pub fn revealed_addresses(&self) -> Vec<Address<N>> {
+        let mut addresses = IndexSet::new();
+        for transition in &self.transitions {
+            for (_, plaintext) in transition.public_inputs.iter().chain(&transition.public_outputs) {
+                collect_addresses(plaintext, &mut addresses);
+            }
+            for future in &transition.futures {
+                collect_future_addresses(future, &mut addresses);
+            }
+        }
+        if let Some(payer) = self.fee.and_then(|fee| fee.payer) {
+            addresses.insert(payer);
+        }
+        addresses.into_iter().collect()
+    }
+
+    /// Returns `true` if no transition reveals inputs, outputs, or finalize arguments, and the fee is private.
+    pub fn is_fully_private(&self) -> bool {
+        self.transitions.iter().all(TransitionAudit::is_fully_private) && self.fee.and_then(|fee| fee.payer).is_none()
+    }
+}
+
+impl<N: Network> Execution<N> {
+    /// Returns the publicly-visible footprint of the execution, and of the given fee.
+    ///
+    /// Note: Only the inputs and outputs that are included in the execution are reported,
+    /// so the audit should be performed on the execution as it will be broadcast.
+    pub fn audit(&self, fee: Option<&Fee<N>>) -> Result<ExecutionAudit<N>> {
+        let transitions = self.transitions.values().map(audit_transition).collect();
+        let fee = match fee {
+            Some(fee) => Some(FeeAudit { payer: fee.payer(), amount: *fee.amount()? }),
+            None => None,
+        };
+        Ok(ExecutionAudit { transitions, fee })
+    }
+}
+
+/// Returns the publicly-visible footprint of the given transition.
+fn audit_transition<N: Network>(transition: &Transition<N>) -> TransitionAudit<N> {
+    let mut audit = TransitionAudit {
+        transition_id: *transition.id(),
+        program_id: *transition.program_id(),
+        function_name: *transition.function_name(),
+        public_inputs: vec![],
+        public_outputs: vec![],
+        futures: vec![],
+        num_private_inputs: 0,
+        num_private_outputs: 0,
+        num_records_consumed: 0,
+        num_records_created: 0,
+        num_external_records: 0,
+    };
+    for (index, input) in transition.inputs().iter().enumerate() {
+        match input {
+            Input::Constant(_, Some(plaintext)) | Input::Public(_, Some(plaintext)) => {
+                audit.public_inputs.push((index, plaintext.clone()))
+            }
+            Input::Constant(_, None) | Input::Public(_, None) => (),
+            Input::Private(..) => audit.num_private_inputs += 1,
+            Input::Record(..) => audit.num_records_consumed += 1,
+            Input::ExternalRecord(..) => audit.num_external_records += 1,
+        }
+    }
+    for (index, output) in transition.outputs().iter().enumerate() {
+        match output {
+            Output::Constant(_, Some(plaintext)) | Output::Public(_, Some(plaintext)) => {
+                audit.public_outputs.push((index, plaintext.clone()))
+            }
+            Output::Constant(_, None) | Output::Public(_, None) => (),
+            Output::Private(..) => audit.num_private_outputs += 1,
+            Output::Record(..) => audit.num_records_created += 1,
+            Output::ExternalRecord(..) => audit.num_external_records += 1,
+            Output::Future(_, Some(future)) => audit.futures.push(future.clone()),
+            Output::Future(_, None) => (),
+        }
+    }
+    audit
+}
+
+/// Collects the addresses in the given plaintext.
+fn collect_addresses<N: Network>(plaintext: &Plaintext<N>, addresses: &mut IndexSet<Address<N>>) {
+    match plaintext {
+        Plaintext::Literal(Literal::Address(address), _) => {
+            addresses.insert(*address);
+        }
+        Plaintext::Literal(..) => (),
+        Plaintext::Struct(members, _) => members.values().for_each(|member| collect_addresses(member, addresses)),
+        Plaintext::Array(elements, _) => elements.iter().for_each(|element| collect_addresses(element, addresses)),
+    }
+}
+
+/// Collects the addresses in the arguments of the given future, including its nested futures.
+fn collect_future_addresses<N: Network>(future: &Future<N>, addresses: &mut IndexSet<Address<N>>) {
+    for argument in future.arguments() {
+        match argument {
+            Argument::Plaintext(plaintext) => collect_addresses(plaintext, addresses),
+            Argument::Future(future) => collect_future_addresses(future, addresses),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_audit() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        // Sample an execution, and a public fee.
+        let execution = test_helpers::sample_execution(rng);
+        let fee = crate::transaction::fee::test_helpers::sample_fee_public_hardcoded(rng);
+
+        // Ensure the audit covers every transition, and counts every record.
+        let audit = execution.audit(None)?;
+        assert_eq!(audit.transitions().len(), execution.len());
+        assert_eq!(audit.num_records_consumed(), execution.transitions().map(|t| t.serial_numbers().count()).sum());
+        assert_eq!(audit.num_records_created(), execution.transitions().map(|t| t.commitments().count()).sum());
+        assert!(audit.fee().is_none());
+
+        // Ensure the public fee reveals its payer and amount.
+        let audit = execution.audit(Some(&fee))?;
+        let payer = fee.payer().unwrap();
+        assert_eq!(audit.fee().unwrap().payer(), Some(payer));
+        assert_eq!(audit.fee().unwrap().amount(), *fee.amount()?);
+        assert!(audit.revealed_addresses().contains(&payer));
+        assert!(!audit.is_fully_private());
+        Ok(())
+    }
+}
@@ -0,0 +1,160 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use crate::{Input, Output};
+
+/// Whether an external record is an input or an output of a transition.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ExternalRecordKind {
+    /// The external record is an input of the transition.
+    Input,
+    /// The external record is an output of the transition.
+    Output,
+}
+
+/// An external record in an execution, i.e. a record of another program that flows through a transition.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct ExternalRecordEntry<N: Network> {
+    /// The ID of the transition that the external record flows through.
+    transition_id: N::TransitionID,
+    /// The program ID of the transition.
+    program_id: ProgramID<N>,
+    /// The function name of the transition.
+    function_name: Identifier<N>,
+    /// Whether the external record is an input or an output.
+    kind: ExternalRecordKind,
+    /// The index of the external record in the inputs or outputs of the transition.
+    index: usize,
+    /// The hash of the external record.
+    hash: Field<N>,
+}
+
+impl<N: Network> ExternalRecordEntry<N> {
+    /// Returns the ID of the transition that the external record flows through.
+    pub const fn transition_id(&self) -> &N::TransitionID {
+        &self.transition_id
+    }
+
+    /// Returns the program ID of the transition.
+    pub const fn program_id(&self) -> &ProgramID<N> {
+        &self.program_id
+    }
+
+    /// Returns the function name of the transition.
+    pub const fn function_name(&self) -> &Identifier<N> {
+        &self.function_name
+    }
+
+    /// Returns whether the external record is an input or an output.
+    pub const fn kind(&self) -> ExternalRecordKind {
+        self.kind
+    }
+
+    /// Returns the index of the external record in the inputs or outputs of the transition.
+    pub const fn index(&self) -> usize {
+        self.index
+    }
+
+    /// Returns the hash of the external record.
+    pub const fn hash(&self) -> &Field<N> {
+        &self.hash
+    }
+}
+
+impl<N: Network> Execution<N> {
+    /// Returns the external record inputs and outputs of the transitions, in the order of the execution.
+    pub fn external_records(&self) -> Vec<ExternalRecordEntry<N>> {
+        let mut entries = Vec::new();
+        for transition in self.transitions.values() {
+            // Construct an entry for the given external record.
+            let entry = |kind, index, hash: &Field<N>| ExternalRecordEntry {
+                transition_id: *transition.id(),
+                program_id: *transition.program_id(),
+                function_name: *transition.function_name(),
+                kind,
+                index,
+                hash: *hash,
+            };
+            for (index, input) in transition.inputs().iter().enumerate() {
+                if let Input::ExternalRecord(hash) = input {
+                    entries.push(entry(ExternalRecordKind::Input, index, hash));
+                }
+            }
+            for (index, output) in transition.outputs().iter().enumerate() {
+                if let Output::ExternalRecord(hash) = output {
+                    entries.push(entry(ExternalRecordKind::Output, index, hash));
+                }
+            }
+        }
+        entries
+    }
+
+    /// Returns the record outputs of the transitions of the given program that precede the given transition,
+    /// as `(transition, output index, commitment)`.
+    ///
+    /// Note: A transition is preceded by the transitions of the functions it calls,
+    /// so these are the candidates that may have produced an external record of the given transition.
+    pub fn preceding_record_outputs(
+        &self,
+        transition_id: &N::TransitionID,
+        program_id: &ProgramID<N>,
+    ) -> Result<Vec<(&Transition<N>, usize, &Field<N>)>> {
+        // Retrieve the position of the transition.
+        let position = self
+            .transitions
+            .get_index_of(transition_id)
+            .ok_or_else(|| anyhow!("Transition '{transition_id}' does not exist in the execution"))?;
+        // Collect the record outputs of the preceding transitions of the program.
+        let mut outputs = Vec::new();
+        for transition in self.transitions.values().take(position).filter(|t| t.program_id() == program_id) {
+            for (index, output) in transition.outputs().iter().enumerate() {
+                if let Output::Record(commitment, ..) = output {
+                    outputs.push((transition, index, commitment));
+                }
+            }
+        }
+        Ok(outputs)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_external_records() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        // Sample an execution.
+        let execution = test_helpers::sample_execution(rng);
+        // Ensure the entries match the external record inputs and outputs.
+        let expected = execution
+            .transitions()
+            .map(|transition| {
+                transition.inputs().iter().filter(|input| matches!(input, Input::ExternalRecord(..))).count()
+                    + transition.outputs().iter().filter(|output| matches!(output, Output::ExternalRecord(..))).count()
+            })
+            .sum::<usize>();
+        assert_eq!(execution.external_records().len(), expected);
+
+        // Ensure the first transition has no preceding record outputs.
+        let transition = execution.get(0)?;
+        assert!(execution.preceding_record_outputs(transition.id(), transition.program_id())?.is_empty());
+        // Ensure an unknown transition is rejected.
+        assert!(execution.preceding_record_outputs(&Default::default(), transition.program_id()).is_err());
+        Ok(())
+    }
+}
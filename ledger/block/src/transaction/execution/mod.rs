@@ -13,12 +13,20 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod audit;
+pub use audit::*;
 mod bytes;
+mod external_records;
+pub use external_records::*;
 mod serialize;
 mod string;
 
 use crate::{Transaction, Transition};
-use console::{account::Field, network::prelude::*, program::ProgramID};
+use console::{
+    account::Field,
+    network::prelude::*,
+    program::{Identifier, ProgramID},
+};
 use synthesizer_snark::Proof;
 
 use indexmap::IndexMap;
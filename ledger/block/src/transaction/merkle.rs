@@ -40,7 +40,7 @@ impl<N: Network> Transaction<N> {
                 // Iterate through the functions in the deployment.
                 for (index, function) in deployment.program().functions().values().enumerate() {
                     // Check if the function hash matches the given ID.
-                    if *id == N::hash_bhp1024(&function.to_bytes_le()?.to_bits_le())? {
+                    if *id == N::hash_bhp1024_iter(bits_from_bytes_le(&function.to_bytes_le()?))? {
                         // Return the transaction leaf.
                         return Ok(TransactionLeaf::new_deployment(u16::try_from(index)?, *id));
                     }
@@ -22,6 +22,9 @@ pub use execution::*;
 mod fee;
 pub use fee::*;
 
+mod weight;
+pub use weight::*;
+
 mod bytes;
 mod merkle;
 mod serialize;
@@ -0,0 +1,128 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use crate::Output;
+
+/// The weight of a transaction, as measured by the mempool limits.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct TransactionWeight {
+    /// The size of the serialized transaction, in bytes.
+    size_in_bytes: usize,
+    /// The number of transitions, including the fee transition.
+    num_transitions: usize,
+    /// The estimated number of finalize operations, i.e. the number of futures output by the transitions.
+    num_finalize_operations: usize,
+    /// The number of proofs, including the deployment certificates.
+    num_proofs: usize,
+}
+
+impl TransactionWeight {
+    /// Returns the size of the serialized transaction, in bytes.
+    pub const fn size_in_bytes(&self) -> usize {
+        self.size_in_bytes
+    }
+
+    /// Returns the number of transitions, including the fee transition.
+    pub const fn num_transitions(&self) -> usize {
+        self.num_transitions
+    }
+
+    /// Returns the estimated number of finalize operations, i.e. the number of futures output by the transitions.
+    pub const fn num_finalize_operations(&self) -> usize {
+        self.num_finalize_operations
+    }
+
+    /// Returns the number of proofs, including the deployment certificates.
+    pub const fn num_proofs(&self) -> usize {
+        self.num_proofs
+    }
+
+    /// Returns `true` if the weight is within the transaction size and transition limits of the network.
+    pub fn is_within_limits<N: Network>(&self) -> bool {
+        self.size_in_bytes <= N::MAX_TRANSACTION_SIZE && self.num_transitions <= Transaction::<N>::MAX_TRANSITIONS
+    }
+}
+
+impl<N: Network> Transaction<N> {
+    /// Returns the weight of the transaction.
+    pub fn weight(&self) -> Result<TransactionWeight> {
+        // Compute the number of proofs.
+        let num_proofs = match self {
+            Self::Deploy(_, _, deployment, fee) => deployment.verifying_keys().len() + fee.proof().is_some() as usize,
+            Self::Execute(_, execution, fee) => {
+                execution.proof().is_some() as usize + fee.as_ref().map_or(0, |fee| fee.proof().is_some() as usize)
+            }
+            Self::Fee(_, fee) => fee.proof().is_some() as usize,
+        };
+        // Compute the number of finalize operations.
+        let num_finalize_operations = self
+            .transitions()
+            .flat_map(Transition::outputs)
+            .filter(|output| matches!(output, Output::Future(..)))
+            .count();
+
+        Ok(TransactionWeight {
+            size_in_bytes: self.to_bytes_le()?.len(),
+            num_transitions: self.transitions().count(),
+            num_finalize_operations,
+            num_proofs,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::MainnetV0;
+
+    type CurrentNetwork = MainnetV0;
+
+    #[test]
+    fn test_weight() {
+        let rng = &mut TestRng::default();
+
+        // Check the deployment transaction.
+        let transaction = crate::transaction::test_helpers::sample_deployment_transaction(true, rng);
+        let weight = transaction.weight().unwrap();
+        assert_eq!(weight.size_in_bytes(), transaction.to_bytes_le().unwrap().len());
+        assert_eq!(weight.num_transitions(), 1);
+        assert_eq!(weight.num_finalize_operations(), 0);
+        assert_eq!(weight.num_proofs(), transaction.deployment().unwrap().verifying_keys().len() + 1);
+        assert!(weight.is_within_limits::<CurrentNetwork>());
+
+        // Check the execution transaction, with a public fee.
+        let transaction = crate::transaction::test_helpers::sample_execution_transaction_with_fee(false, rng);
+        let weight = transaction.weight().unwrap();
+        assert_eq!(weight.size_in_bytes(), transaction.to_bytes_le().unwrap().len());
+        assert_eq!(weight.num_transitions(), transaction.transitions().count());
+        assert!(weight.num_finalize_operations() >= 1);
+        assert_eq!(weight.num_proofs(), 2);
+        assert!(weight.is_within_limits::<CurrentNetwork>());
+
+        // Check the fee transactions.
+        let transaction = crate::transaction::test_helpers::sample_private_fee_transaction(rng);
+        let weight = transaction.weight().unwrap();
+        assert_eq!(weight.num_transitions(), 1);
+        assert_eq!(weight.num_finalize_operations(), 0);
+        assert_eq!(weight.num_proofs(), 1);
+
+        let transaction = crate::transaction::test_helpers::sample_fee_public_transaction(rng);
+        let weight = transaction.weight().unwrap();
+        assert_eq!(weight.num_transitions(), 1);
+        assert_eq!(weight.num_finalize_operations(), 1);
+        assert_eq!(weight.num_proofs(), 1);
+    }
+}
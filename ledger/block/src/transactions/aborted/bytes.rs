@@ -0,0 +1,46 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl FromBytes for AbortReason {
+    /// Reads the abort reason from a buffer.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        Self::from_code(u8::read_le(&mut reader)?).map_err(error)
+    }
+}
+
+impl ToBytes for AbortReason {
+    /// Writes the abort reason to a buffer.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        self.code().write_le(&mut writer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bytes() {
+        for code in 0..10 {
+            let expected = AbortReason::from_code(code).unwrap();
+            let expected_bytes = expected.to_bytes_le().unwrap();
+            assert_eq!(expected_bytes, vec![code]);
+            assert_eq!(expected, AbortReason::read_le(&expected_bytes[..]).unwrap());
+        }
+        assert!(AbortReason::read_le(&[10u8][..]).is_err());
+    }
+}
@@ -0,0 +1,94 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod bytes;
+mod serialize;
+mod string;
+
+use super::*;
+
+/// The reason code for an aborted transaction.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum AbortReason {
+    /// The transaction aborted for an unspecified reason.
+    Unspecified,
+    /// The transaction is a standalone fee transaction.
+    FeeTransaction,
+    /// The transaction failed verification.
+    InvalidTransaction,
+    /// The transaction produces a transition that already exists.
+    DuplicateTransition,
+    /// The transaction spends an input that is already spent.
+    DoubleSpend,
+    /// The transaction produces an output that already exists.
+    DuplicateOutput,
+    /// The transaction produces a transition public key that already exists.
+    DuplicateTransitionPublicKey,
+    /// The transaction is another deployment in the block from the same public fee payer.
+    DuplicateDeploymentPayer,
+    /// The transaction exceeds the maximum number of confirmed transactions in the block.
+    ExceedsBlockLimit,
+    /// The fee of the transaction failed to finalize.
+    FeeFinalizeFailure,
+}
+
+impl AbortReason {
+    /// Returns the reason code for the aborted transaction.
+    pub const fn code(&self) -> u8 {
+        match self {
+            Self::Unspecified => 0,
+            Self::FeeTransaction => 1,
+            Self::InvalidTransaction => 2,
+            Self::DuplicateTransition => 3,
+            Self::DoubleSpend => 4,
+            Self::DuplicateOutput => 5,
+            Self::DuplicateTransitionPublicKey => 6,
+            Self::DuplicateDeploymentPayer => 7,
+            Self::ExceedsBlockLimit => 8,
+            Self::FeeFinalizeFailure => 9,
+        }
+    }
+
+    /// Returns the abort reason for the given reason code.
+    pub fn from_code(code: u8) -> Result<Self> {
+        match code {
+            0 => Ok(Self::Unspecified),
+            1 => Ok(Self::FeeTransaction),
+            2 => Ok(Self::InvalidTransaction),
+            3 => Ok(Self::DuplicateTransition),
+            4 => Ok(Self::DoubleSpend),
+            5 => Ok(Self::DuplicateOutput),
+            6 => Ok(Self::DuplicateTransitionPublicKey),
+            7 => Ok(Self::DuplicateDeploymentPayer),
+            8 => Ok(Self::ExceedsBlockLimit),
+            9 => Ok(Self::FeeFinalizeFailure),
+            10.. => bail!("Invalid abort reason code '{code}'"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_code_round_trip() {
+        for code in 0..10 {
+            let reason = AbortReason::from_code(code).unwrap();
+            assert_eq!(reason.code(), code);
+        }
+        assert!(AbortReason::from_code(10).is_err());
+    }
+}
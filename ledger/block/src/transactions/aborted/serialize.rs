@@ -0,0 +1,60 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl Serialize for AbortReason {
+    /// Serializes the abort reason into a string or as bytes.
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match serializer.is_human_readable() {
+            true => serializer.collect_str(self),
+            false => ToBytesSerializer::serialize(self, serializer),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for AbortReason {
+    /// Deserializes the abort reason from a string or bytes.
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        match deserializer.is_human_readable() {
+            true => FromStr::from_str(&String::deserialize(deserializer)?).map_err(de::Error::custom),
+            false => FromBytesDeserializer::<Self>::deserialize(deserializer, "abort reason", 1),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_serde_json() {
+        for code in 0..10 {
+            let expected = AbortReason::from_code(code).unwrap();
+            let candidate = serde_json::to_string(&expected).unwrap();
+            assert_eq!(candidate, format!("\"{expected}\""));
+            assert_eq!(expected, serde_json::from_str(&candidate).unwrap());
+        }
+    }
+
+    #[test]
+    fn test_bincode() {
+        for code in 0..10 {
+            let expected = AbortReason::from_code(code).unwrap();
+            let candidate = bincode::serialize(&expected).unwrap();
+            assert_eq!(expected, bincode::deserialize(&candidate).unwrap());
+        }
+    }
+}
@@ -0,0 +1,56 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl FromStr for AbortReason {
+    type Err = Error;
+
+    /// Initializes the abort reason from a string.
+    fn from_str(reason: &str) -> Result<Self, Self::Err> {
+        match reason {
+            "unspecified" => Ok(Self::Unspecified),
+            "fee_transaction" => Ok(Self::FeeTransaction),
+            "invalid_transaction" => Ok(Self::InvalidTransaction),
+            "duplicate_transition" => Ok(Self::DuplicateTransition),
+            "double_spend" => Ok(Self::DoubleSpend),
+            "duplicate_output" => Ok(Self::DuplicateOutput),
+            "duplicate_transition_public_key" => Ok(Self::DuplicateTransitionPublicKey),
+            "duplicate_deployment_payer" => Ok(Self::DuplicateDeploymentPayer),
+            "exceeds_block_limit" => Ok(Self::ExceedsBlockLimit),
+            "fee_finalize_failure" => Ok(Self::FeeFinalizeFailure),
+            _ => bail!("Invalid abort reason '{reason}'"),
+        }
+    }
+}
+
+impl Display for AbortReason {
+    /// Displays the abort reason as a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let reason = match self {
+            Self::Unspecified => "unspecified",
+            Self::FeeTransaction => "fee_transaction",
+            Self::InvalidTransaction => "invalid_transaction",
+            Self::DuplicateTransition => "duplicate_transition",
+            Self::DoubleSpend => "double_spend",
+            Self::DuplicateOutput => "duplicate_output",
+            Self::DuplicateTransitionPublicKey => "duplicate_transition_public_key",
+            Self::DuplicateDeploymentPayer => "duplicate_deployment_payer",
+            Self::ExceedsBlockLimit => "exceeds_block_limit",
+            Self::FeeFinalizeFailure => "fee_finalize_failure",
+        };
+        write!(f, "{reason}")
+    }
+}
@@ -0,0 +1,114 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+use core::marker::PhantomData;
+use std::io::{BufReader, BufWriter, Read, Write};
+
+impl<N: Network> Transactions<N> {
+    /// Writes the transactions as JSON into the given writer, without materializing the JSON string.
+    pub fn write_json<W: Write>(&self, writer: W) -> Result<()> {
+        let mut writer = BufWriter::new(writer);
+        serde_json::to_writer(&mut writer, self)?;
+        writer.flush()?;
+        Ok(())
+    }
+
+    /// Reads the transactions as JSON from the given reader, without materializing the JSON string.
+    pub fn read_json<R: Read>(reader: R) -> Result<Self> {
+        let mut transactions = Vec::new();
+        Self::visit_json(reader, |transaction| {
+            transactions.push(transaction);
+            Ok(())
+        })?;
+        Ok(Self::from_iter(transactions))
+    }
+
+    /// Reads the transactions as JSON from the given reader, calling `visit` on each transaction as it is parsed,
+    /// and returns the number of transactions visited. The transactions are not retained in memory.
+    pub fn visit_json<R: Read>(reader: R, visit: impl FnMut(ConfirmedTransaction<N>) -> Result<()>) -> Result<usize> {
+        let mut deserializer = serde_json::Deserializer::from_reader(BufReader::new(reader));
+        let num_transactions = deserializer.deserialize_seq(TransactionsVisitor(visit, PhantomData))?;
+        deserializer.end()?;
+        Ok(num_transactions)
+    }
+}
+
+/// A visitor that calls the given function on each transaction in a sequence.
+pub(crate) struct TransactionsVisitor<N: Network, F: FnMut(ConfirmedTransaction<N>) -> Result<()>>(
+    pub(crate) F,
+    pub(crate) PhantomData<N>,
+);
+
+impl<'de, N: Network, F: FnMut(ConfirmedTransaction<N>) -> Result<()>> Visitor<'de> for TransactionsVisitor<N, F> {
+    type Value = usize;
+
+    fn expecting(&self, formatter: &mut Formatter) -> fmt::Result {
+        formatter.write_str("a sequence of confirmed transactions")
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(mut self, mut seq: A) -> Result<Self::Value, A::Error> {
+        let mut num_transactions = 0;
+        while let Some(transaction) = seq.next_element()? {
+            // Ensure the number of transactions is within the allowed range.
+            if num_transactions >= Transactions::<N>::MAX_TRANSACTIONS {
+                return Err(de::Error::custom(format!(
+                    "Cannot read more than {} transactions",
+                    Transactions::<N>::MAX_TRANSACTIONS
+                )));
+            }
+            (self.0)(transaction).map_err(de::Error::custom)?;
+            num_transactions += 1;
+        }
+        Ok(num_transactions)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_json_stream() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        let expected: Transactions<console::network::MainnetV0> = [
+            crate::transactions::confirmed::test_helpers::sample_accepted_execute(0, true, rng),
+            crate::transactions::confirmed::test_helpers::sample_rejected_deploy(1, false, rng),
+        ]
+        .into_iter()
+        .collect();
+
+        // Ensure the streamed JSON matches the JSON string.
+        let mut buffer = Vec::new();
+        expected.write_json(&mut buffer)?;
+        assert_eq!(buffer, serde_json::to_vec(&expected)?);
+
+        // Ensure the transactions are recovered.
+        assert_eq!(expected, Transactions::read_json(&buffer[..])?);
+        // Ensure each transaction is visited in order.
+        let mut ids = Vec::new();
+        assert_eq!(
+            Transactions::visit_json(&buffer[..], |transaction| {
+                ids.push(transaction.id());
+                Ok(())
+            })?,
+            2
+        );
+        assert_eq!(ids, expected.transaction_ids().copied().collect::<Vec<_>>());
+        Ok(())
+    }
+}
@@ -52,6 +52,23 @@ impl<N: Network> Transactions<N> {
         }
     }
 
+    /// Returns the Merkle multi-path for the given transaction IDs, which must be unique.
+    pub fn to_multi_path(&self, transaction_ids: &[N::TransactionID]) -> Result<TransactionsMultiPath<N>> {
+        let mut leaves = BTreeMap::new();
+        for transaction_id in transaction_ids {
+            match self.transactions.get_index_of(transaction_id) {
+                Some(transaction_index) => {
+                    ensure!(
+                        leaves.insert(transaction_index, transaction_id.to_bits_le()).is_none(),
+                        "The transaction '{transaction_id}' is duplicated"
+                    );
+                }
+                None => bail!("The transaction '{transaction_id}' is not in the block transactions"),
+            }
+        }
+        self.to_tree()?.prove_many(&leaves)
+    }
+
     /// The Merkle tree of transaction IDs for the block.
     pub fn to_tree(&self) -> Result<TransactionsTree<N>> {
         Self::transactions_tree(&self.transactions)
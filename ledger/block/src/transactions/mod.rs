@@ -13,6 +13,9 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+pub mod aborted;
+pub use aborted::*;
+
 pub mod confirmed;
 pub use confirmed::*;
 
@@ -20,17 +23,19 @@ pub mod rejected;
 pub use rejected::*;
 
 mod bytes;
+mod json;
 mod merkle;
 mod serialize;
 mod string;
 
 use crate::{Transaction, Transition};
 use console::{
-    network::prelude::*,
+    network::{prelude::*, BlockLimits},
     program::{
         Ciphertext,
         ProgramOwner,
         Record,
+        TransactionsMultiPath,
         TransactionsPath,
         TransactionsTree,
         FINALIZE_ID_DEPTH,
@@ -44,6 +49,7 @@ use ledger_narwhal_batch_header::BatchHeader;
 use synthesizer_program::FinalizeOperation;
 
 use indexmap::IndexMap;
+use std::collections::BTreeMap;
 
 #[cfg(not(feature = "serial"))]
 use rayon::prelude::*;
@@ -175,7 +181,11 @@ impl<N: Network> Transactions<N> {
         * BatchHeader::<N>::MAX_GC_ROUNDS
         * Committee::<N>::MAX_COMMITTEE_SIZE as usize;
     /// The maximum number of transactions allowed in a block.
-    pub const MAX_TRANSACTIONS: usize = usize::pow(2, TRANSACTIONS_DEPTH as u32).saturating_sub(1);
+    /// Note: This is the network limit, bounded by the capacity of the transactions tree.
+    pub const MAX_TRANSACTIONS: usize = BlockLimits::bounded(
+        N::BLOCK_LIMITS.max_transactions,
+        usize::pow(2, TRANSACTIONS_DEPTH as u32).saturating_sub(1),
+    );
 
     /// Returns an iterator over all transactions, for all transactions in `self`.
     pub fn iter(&self) -> impl '_ + ExactSizeIterator<Item = &ConfirmedTransaction<N>> {
@@ -370,4 +380,16 @@ mod tests {
             "The maximum number of transmissions in a block is too large"
         );
     }
+
+    #[test]
+    fn test_block_limits() {
+        // Ensure the limits match the network configuration.
+        let limits = CurrentNetwork::BLOCK_LIMITS;
+        assert_eq!(Transactions::<CurrentNetwork>::MAX_TRANSACTIONS, limits.max_transactions);
+        assert_eq!(BatchHeader::<CurrentNetwork>::MAX_TRANSMISSIONS_PER_BATCH, limits.max_transmissions_per_batch);
+
+        // Ensure the limits are bounded by the capacity.
+        assert_eq!(BlockLimits::bounded(10, 20), 10);
+        assert_eq!(BlockLimits::bounded(30, 20), 20);
+    }
 }
@@ -56,7 +56,7 @@ impl<'de, N: Network> Deserialize<'de> for Transactions<N> {
                     }
                 }
 
-                Ok(Self::from(&deserializer.deserialize_seq(TransactionsDeserializer(PhantomData))?))
+                Ok(Self::from_iter(deserializer.deserialize_seq(TransactionsDeserializer(PhantomData))?))
             }
             false => FromBytesDeserializer::<Self>::deserialize_with_size_encoding(deserializer, "transactions"),
         }
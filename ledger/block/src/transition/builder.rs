@@ -0,0 +1,192 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+/// A builder for constructing and mutating transitions in tests.
+///
+/// The transition ID is recomputed from the inputs, outputs, and transition commitment on `build`,
+/// so that a mutated transition is well-formed and only fails the checks that the test targets.
+///
+/// The intended usage is:
+///  1. Initialize the builder with `TransitionBuilder::new` or `TransitionBuilder::from_transition`.
+///  2. Mutate the fields with the `with_*`, `push_*`, `replace_*`, and `remove_*` methods.
+///  3. Construct the transition with `TransitionBuilder::build`.
+#[derive(Clone)]
+pub struct TransitionBuilder<N: Network> {
+    /// The program ID.
+    program_id: ProgramID<N>,
+    /// The function name.
+    function_name: Identifier<N>,
+    /// The transition inputs.
+    inputs: Vec<Input<N>>,
+    /// The transition outputs.
+    outputs: Vec<Output<N>>,
+    /// The transition public key.
+    tpk: Group<N>,
+    /// The transition commitment.
+    tcm: Field<N>,
+    /// The transition signer commitment.
+    scm: Field<N>,
+}
+
+impl<N: Network> TransitionBuilder<N> {
+    /// Initializes a builder for a transition of the given function, without inputs or outputs.
+    pub fn new(
+        program_id: ProgramID<N>,
+        function_name: Identifier<N>,
+        tpk: Group<N>,
+        tcm: Field<N>,
+        scm: Field<N>,
+    ) -> Self {
+        Self { program_id, function_name, inputs: vec![], outputs: vec![], tpk, tcm, scm }
+    }
+
+    /// Initializes a builder from the given transition.
+    pub fn from_transition(transition: &Transition<N>) -> Self {
+        Self {
+            program_id: *transition.program_id(),
+            function_name: *transition.function_name(),
+            inputs: transition.inputs().to_vec(),
+            outputs: transition.outputs().to_vec(),
+            tpk: *transition.tpk(),
+            tcm: *transition.tcm(),
+            scm: *transition.scm(),
+        }
+    }
+
+    /// Sets the program ID.
+    pub fn with_program_id(mut self, program_id: ProgramID<N>) -> Self {
+        self.program_id = program_id;
+        self
+    }
+
+    /// Sets the function name.
+    pub fn with_function_name(mut self, function_name: Identifier<N>) -> Self {
+        self.function_name = function_name;
+        self
+    }
+
+    /// Sets the transition inputs.
+    pub fn with_inputs(mut self, inputs: Vec<Input<N>>) -> Self {
+        self.inputs = inputs;
+        self
+    }
+
+    /// Sets the transition outputs.
+    pub fn with_outputs(mut self, outputs: Vec<Output<N>>) -> Self {
+        self.outputs = outputs;
+        self
+    }
+
+    /// Sets the transition public key.
+    pub fn with_tpk(mut self, tpk: Group<N>) -> Self {
+        self.tpk = tpk;
+        self
+    }
+
+    /// Sets the transition commitment.
+    pub fn with_tcm(mut self, tcm: Field<N>) -> Self {
+        self.tcm = tcm;
+        self
+    }
+
+    /// Sets the transition signer commitment.
+    pub fn with_scm(mut self, scm: Field<N>) -> Self {
+        self.scm = scm;
+        self
+    }
+
+    /// Appends the given input.
+    pub fn push_input(mut self, input: Input<N>) -> Self {
+        self.inputs.push(input);
+        self
+    }
+
+    /// Appends the given output.
+    pub fn push_output(mut self, output: Output<N>) -> Self {
+        self.outputs.push(output);
+        self
+    }
+
+    /// Replaces the input at the given index.
+    pub fn replace_input(mut self, index: usize, input: Input<N>) -> Result<Self> {
+        ensure!(index < self.inputs.len(), "Input index {index} is out of bounds ({})", self.inputs.len());
+        self.inputs[index] = input;
+        Ok(self)
+    }
+
+    /// Replaces the output at the given index.
+    pub fn replace_output(mut self, index: usize, output: Output<N>) -> Result<Self> {
+        ensure!(index < self.outputs.len(), "Output index {index} is out of bounds ({})", self.outputs.len());
+        self.outputs[index] = output;
+        Ok(self)
+    }
+
+    /// Removes the input at the given index.
+    pub fn remove_input(mut self, index: usize) -> Result<Self> {
+        ensure!(index < self.inputs.len(), "Input index {index} is out of bounds ({})", self.inputs.len());
+        self.inputs.remove(index);
+        Ok(self)
+    }
+
+    /// Removes the output at the given index.
+    pub fn remove_output(mut self, index: usize) -> Result<Self> {
+        ensure!(index < self.outputs.len(), "Output index {index} is out of bounds ({})", self.outputs.len());
+        self.outputs.remove(index);
+        Ok(self)
+    }
+
+    /// Returns the transition, recomputing the transition ID.
+    pub fn build(self) -> Result<Transition<N>> {
+        Transition::new(self.program_id, self.function_name, self.inputs, self.outputs, self.tpk, self.tcm, self.scm)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_transition_builder() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        // Sample a transition.
+        let transition = crate::transition::test_helpers::sample_transition(rng);
+
+        // Ensure the builder reconstructs the transition.
+        assert_eq!(TransitionBuilder::from_transition(&transition).build()?, transition);
+
+        // Mutate the outputs, and ensure the transition ID is recomputed.
+        let mutated = TransitionBuilder::from_transition(&transition)
+            .push_output(Output::ExternalRecord(Field::zero()))
+            .build()?;
+        assert_eq!(mutated.outputs().len(), transition.outputs().len() + 1);
+        assert_ne!(mutated.id(), transition.id());
+        assert_eq!(mutated.tcm(), transition.tcm());
+
+        // Ensure the removal restores the transition.
+        let restored =
+            TransitionBuilder::from_transition(&mutated).remove_output(transition.outputs().len())?.build()?;
+        assert_eq!(restored, transition);
+
+        // Ensure out-of-bounds mutations are rejected.
+        assert!(TransitionBuilder::from_transition(&transition)
+            .replace_input(transition.inputs().len(), Input::Constant(Field::zero(), None))
+            .is_err());
+        assert!(TransitionBuilder::from_transition(&transition).remove_output(transition.outputs().len()).is_err());
+        Ok(())
+    }
+}
@@ -0,0 +1,226 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+use console::program::{Argument, Future, Literal, Plaintext};
+
+/// The source of a transition event.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum EventSource {
+    /// A constant input.
+    ConstantInput,
+    /// A public input.
+    PublicInput,
+    /// A constant output.
+    ConstantOutput,
+    /// A public output.
+    PublicOutput,
+    /// An argument of a future output.
+    FutureOutput,
+}
+
+/// A flattened, typed key/value pair for a public input or output of a transition.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TransitionEvent<N: Network> {
+    /// The transition ID.
+    transition_id: N::TransitionID,
+    /// The program ID.
+    program_id: ProgramID<N>,
+    /// The function name.
+    function_name: Identifier<N>,
+    /// The source of the event.
+    source: EventSource,
+    /// The index of the input or output in the transition.
+    index: usize,
+    /// The path to the literal within the input or output, e.g. `owner.balances[2]`.
+    /// The path is empty if the input or output is itself a literal.
+    path: String,
+    /// The literal value.
+    value: Literal<N>,
+}
+
+impl<N: Network> TransitionEvent<N> {
+    /// Returns the transition ID.
+    pub const fn transition_id(&self) -> &N::TransitionID {
+        &self.transition_id
+    }
+
+    /// Returns the program ID.
+    pub const fn program_id(&self) -> &ProgramID<N> {
+        &self.program_id
+    }
+
+    /// Returns the function name.
+    pub const fn function_name(&self) -> &Identifier<N> {
+        &self.function_name
+    }
+
+    /// Returns the source of the event.
+    pub const fn source(&self) -> EventSource {
+        self.source
+    }
+
+    /// Returns the index of the input or output in the transition.
+    pub const fn index(&self) -> usize {
+        self.index
+    }
+
+    /// Returns the path to the literal within the input or output.
+    pub fn path(&self) -> &str {
+        &self.path
+    }
+
+    /// Returns the literal value.
+    pub const fn value(&self) -> &Literal<N> {
+        &self.value
+    }
+}
+
+impl<N: Network> Transition<N> {
+    /// Returns the flattened events for every public input and output of the transition.
+    ///
+    /// Each constant or public plaintext, and each plaintext argument of a future output, is flattened into
+    /// one event per literal, with the path to the literal (e.g. `owner.balances[2]`) and its typed value.
+    /// Private inputs, private outputs, and records are omitted.
+    pub fn events(&self) -> impl '_ + Iterator<Item = TransitionEvent<N>> {
+        let inputs = self.inputs.iter().enumerate().flat_map(move |(index, input)| {
+            let mut literals = Vec::new();
+            let source = match input {
+                Input::Constant(_, Some(plaintext)) => {
+                    flatten_plaintext(plaintext, String::new(), &mut literals);
+                    EventSource::ConstantInput
+                }
+                Input::Public(_, Some(plaintext)) => {
+                    flatten_plaintext(plaintext, String::new(), &mut literals);
+                    EventSource::PublicInput
+                }
+                _ => EventSource::PublicInput,
+            };
+            literals.into_iter().map(move |(path, value)| self.to_event(source, index, path, value))
+        });
+        let outputs = self.outputs.iter().enumerate().flat_map(move |(index, output)| {
+            let mut literals = Vec::new();
+            let source = match output {
+                Output::Constant(_, Some(plaintext)) => {
+                    flatten_plaintext(plaintext, String::new(), &mut literals);
+                    EventSource::ConstantOutput
+                }
+                Output::Public(_, Some(plaintext)) => {
+                    flatten_plaintext(plaintext, String::new(), &mut literals);
+                    EventSource::PublicOutput
+                }
+                Output::Future(_, Some(future)) => {
+                    flatten_future(future, String::new(), &mut literals);
+                    EventSource::FutureOutput
+                }
+                _ => EventSource::PublicOutput,
+            };
+            literals.into_iter().map(move |(path, value)| self.to_event(source, index, path, value))
+        });
+        inputs.chain(outputs)
+    }
+
+    /// Returns a transition event for the given source, index, path, and value.
+    fn to_event(&self, source: EventSource, index: usize, path: String, value: Literal<N>) -> TransitionEvent<N> {
+        TransitionEvent {
+            transition_id: self.id,
+            program_id: self.program_id,
+            function_name: self.function_name,
+            source,
+            index,
+            path,
+            value,
+        }
+    }
+}
+
+/// Flattens the given plaintext into `(path, literal)` pairs.
+fn flatten_plaintext<N: Network>(plaintext: &Plaintext<N>, path: String, literals: &mut Vec<(String, Literal<N>)>) {
+    match plaintext {
+        Plaintext::Literal(literal, _) => literals.push((path, literal.clone())),
+        Plaintext::Struct(members, _) => {
+            for (name, member) in members {
+                let path = if path.is_empty() { name.to_string() } else { format!("{path}.{name}") };
+                flatten_plaintext(member, path, literals);
+            }
+        }
+        Plaintext::Array(elements, _) => {
+            for (i, element) in elements.iter().enumerate() {
+                flatten_plaintext(element, format!("{path}[{i}]"), literals);
+            }
+        }
+    }
+}
+
+/// Flattens the plaintext arguments of the given future into `(path, literal)` pairs.
+/// The arguments of a future are indexed like an array, e.g. `[1].amount`.
+fn flatten_future<N: Network>(future: &Future<N>, path: String, literals: &mut Vec<(String, Literal<N>)>) {
+    for (i, argument) in future.arguments().iter().enumerate() {
+        match argument {
+            Argument::Plaintext(plaintext) => flatten_plaintext(plaintext, format!("{path}[{i}]"), literals),
+            Argument::Future(future) => flatten_future(future, format!("{path}[{i}]"), literals),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type CurrentNetwork = console::network::MainnetV0;
+
+    #[test]
+    fn test_flatten_plaintext() {
+        let plaintext = Plaintext::<CurrentNetwork>::from_str(
+            "{ owner: aleo1d5hg2z3ma00382pngntdp68e74zv54jdxy249qhaujhks9c72yrs33ddah, balances: [1u64, 2u64], inner: { flag: true } }",
+        )
+        .unwrap();
+        let mut literals = Vec::new();
+        flatten_plaintext(&plaintext, String::new(), &mut literals);
+        let paths = literals.iter().map(|(path, _)| path.as_str()).collect::<Vec<_>>();
+        assert_eq!(paths, ["owner", "balances[0]", "balances[1]", "inner.flag"]);
+        assert_eq!(literals[2].1, Literal::from_str("2u64").unwrap());
+    }
+
+    #[test]
+    fn test_transition_events() {
+        let rng = &mut TestRng::default();
+
+        // Sample a transition.
+        let transition = crate::transition::test_helpers::sample_transition(rng);
+        // Ensure there is one event per public literal.
+        let num_literals = transition
+            .inputs()
+            .iter()
+            .filter_map(|input| match input {
+                Input::Constant(_, Some(plaintext)) | Input::Public(_, Some(plaintext)) => {
+                    let mut literals = Vec::new();
+                    flatten_plaintext(plaintext, String::new(), &mut literals);
+                    Some(literals.len())
+                }
+                _ => None,
+            })
+            .sum::<usize>();
+        let events = transition.events().collect::<Vec<_>>();
+        assert_eq!(events.iter().filter(|event| event.source() == EventSource::PublicInput).count(), num_literals);
+        // Ensure the events are attributed to the transition.
+        for event in &events {
+            assert_eq!(event.transition_id(), transition.id());
+            assert_eq!(event.program_id(), transition.program_id());
+            assert_eq!(event.function_name(), transition.function_name());
+        }
+    }
+}
@@ -19,8 +19,17 @@ pub use input::Input;
 pub mod output;
 pub use output::Output;
 
+mod event;
+pub use event::*;
+
+#[cfg(any(test, feature = "test-utilities"))]
+mod builder;
+#[cfg(any(test, feature = "test-utilities"))]
+pub use builder::*;
+
 mod bytes;
 mod merkle;
+mod outgoing;
 mod serialize;
 mod string;
 
@@ -309,58 +318,47 @@ impl<N: Network> Transition<N> {
 }
 
 impl<N: Network> Transition<N> {
+    /// Returns `true` if this is a `credits.aleo` transition for the given function name.
+    #[inline]
+    fn is_credits_function(&self, function_name: &str) -> bool {
+        ProgramID::intern("credits.aleo").is_ok_and(|program_id| self.program_id == program_id)
+            && Identifier::intern(function_name).is_ok_and(|name| self.function_name == name)
+    }
+
     /// Returns `true` if this is a `bond_public` transition.
     #[inline]
     pub fn is_bond_public(&self) -> bool {
-        self.inputs.len() == 3
-            && self.outputs.len() == 1
-            && self.program_id.to_string() == "credits.aleo"
-            && self.function_name.to_string() == "bond_public"
+        self.inputs.len() == 3 && self.outputs.len() == 1 && self.is_credits_function("bond_public")
     }
 
     /// Returns `true` if this is a `bond_validator` transition.
     #[inline]
     pub fn is_bond_validator(&self) -> bool {
-        self.inputs.len() == 3
-            && self.outputs.len() == 1
-            && self.program_id.to_string() == "credits.aleo"
-            && self.function_name.to_string() == "bond_validator"
+        self.inputs.len() == 3 && self.outputs.len() == 1 && self.is_credits_function("bond_validator")
     }
 
     /// Returns `true` if this is an `unbond_public` transition.
     #[inline]
     pub fn is_unbond_public(&self) -> bool {
-        self.inputs.len() == 2
-            && self.outputs.len() == 1
-            && self.program_id.to_string() == "credits.aleo"
-            && self.function_name.to_string() == "unbond_public"
+        self.inputs.len() == 2 && self.outputs.len() == 1 && self.is_credits_function("unbond_public")
     }
 
     /// Returns `true` if this is a `fee_private` transition.
     #[inline]
     pub fn is_fee_private(&self) -> bool {
-        self.inputs.len() == 4
-            && self.outputs.len() == 1
-            && self.program_id.to_string() == "credits.aleo"
-            && self.function_name.to_string() == "fee_private"
+        self.inputs.len() == 4 && self.outputs.len() == 1 && self.is_credits_function("fee_private")
     }
 
     /// Returns `true` if this is a `fee_public` transition.
     #[inline]
     pub fn is_fee_public(&self) -> bool {
-        self.inputs.len() == 3
-            && self.outputs.len() == 1
-            && self.program_id.to_string() == "credits.aleo"
-            && self.function_name.to_string() == "fee_public"
+        self.inputs.len() == 3 && self.outputs.len() == 1 && self.is_credits_function("fee_public")
     }
 
     /// Returns `true` if this is a `split` transition.
     #[inline]
     pub fn is_split(&self) -> bool {
-        self.inputs.len() == 2
-            && self.outputs.len() == 2
-            && self.program_id.to_string() == "credits.aleo"
-            && self.function_name.to_string() == "split"
+        self.inputs.len() == 2 && self.outputs.len() == 2 && self.is_credits_function("split")
     }
 }
 
@@ -0,0 +1,148 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use console::{
+    account::{OutgoingViewKey, ViewKey},
+    program::Plaintext,
+    types::U16,
+};
+
+impl<N: Network> Transition<N> {
+    /// Returns the ciphertext of the transition view key under the given outgoing view key,
+    /// which an auditor can use to decrypt the private inputs and outputs of the transition.
+    /// Note: The view key must belong to the signer of the transition.
+    pub fn to_outgoing_ciphertext(
+        &self,
+        view_key: &ViewKey<N>,
+        outgoing_view_key: &OutgoingViewKey<N>,
+    ) -> Result<Field<N>> {
+        // Compute the transition view key `tvk` as `view_key * tpk`.
+        let tvk = (self.tpk * **view_key).to_x_coordinate();
+        // Ensure the transition view key corresponds to the transition commitment.
+        ensure!(N::hash_psd2(&[tvk])? == self.tcm, "The view key is not the signer of transition '{}'", self.id);
+        // Encrypt the transition view key, under the outgoing view key.
+        outgoing_view_key.encrypt(&self.tpk, &[tvk])?.pop().ok_or_else(|| anyhow!("Missing the outgoing ciphertext"))
+    }
+
+    /// Returns the transition view key, from the given outgoing view key and ciphertext.
+    pub fn decrypt_transition_view_key(
+        &self,
+        outgoing_view_key: &OutgoingViewKey<N>,
+        outgoing_ciphertext: Field<N>,
+    ) -> Result<Field<N>> {
+        // Decrypt the transition view key.
+        let tvk = outgoing_view_key
+            .decrypt(&self.tpk, &[outgoing_ciphertext])?
+            .pop()
+            .ok_or_else(|| anyhow!("Missing the transition view key"))?;
+        // Ensure the transition view key corresponds to the transition commitment.
+        ensure!(N::hash_psd2(&[tvk])? == self.tcm, "Invalid outgoing ciphertext for transition '{}'", self.id);
+        Ok(tvk)
+    }
+
+    /// Returns the decrypted private inputs of the transition, as pairs of input ID and plaintext,
+    /// for the given transition view key.
+    pub fn decrypt_private_inputs(&self, tvk: Field<N>) -> Result<Vec<(Field<N>, Plaintext<N>)>> {
+        let function_id = compute_function_id(&U16::new(N::ID), &self.program_id, &self.function_name)?;
+        self.inputs
+            .iter()
+            .enumerate()
+            .filter_map(|(index, input)| match input {
+                Input::Private(id, Some(ciphertext)) => Some((index, *id, ciphertext)),
+                _ => None,
+            })
+            .map(|(index, id, ciphertext)| Ok((id, Self::decrypt_private(function_id, tvk, index, ciphertext)?)))
+            .collect()
+    }
+
+    /// Returns the decrypted private outputs of the transition, as pairs of output ID and plaintext,
+    /// for the given transition view key.
+    pub fn decrypt_private_outputs(&self, tvk: Field<N>) -> Result<Vec<(Field<N>, Plaintext<N>)>> {
+        let function_id = compute_function_id(&U16::new(N::ID), &self.program_id, &self.function_name)?;
+        let num_inputs = self.inputs.len();
+        self.outputs
+            .iter()
+            .enumerate()
+            .filter_map(|(index, output)| match output {
+                Output::Private(id, Some(ciphertext)) => Some((num_inputs + index, *id, ciphertext)),
+                _ => None,
+            })
+            .map(|(index, id, ciphertext)| Ok((id, Self::decrypt_private(function_id, tvk, index, ciphertext)?)))
+            .collect()
+    }
+}
+
+impl<N: Network> Transition<N> {
+    /// Decrypts the private input or output at the given index, as `Decrypt(Hash(function ID || tvk || index))`.
+    fn decrypt_private(
+        function_id: Field<N>,
+        tvk: Field<N>,
+        index: usize,
+        ciphertext: &Ciphertext<N>,
+    ) -> Result<Plaintext<N>> {
+        // Construct the (console) index as a field element.
+        let index = Field::from_u16(u16::try_from(index).map_err(|_| anyhow!("Index exceeds u16"))?);
+        // Compute the view key as `Hash(function ID || tvk || index)`.
+        let view_key = N::hash_psd4(&[function_id, tvk, index])?;
+        // Decrypt the ciphertext.
+        ciphertext.decrypt_symmetric(view_key)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::{
+        account::{Address, PrivateKey},
+        network::MainnetV0,
+        program::Literal,
+    };
+
+    type CurrentNetwork = MainnetV0;
+
+    #[test]
+    fn test_outgoing_ciphertext() {
+        let rng = &mut TestRng::default();
+
+        // Sample the genesis transaction, which calls `credits.aleo/transfer_public_to_private`.
+        let (_, transaction, private_key) = crate::test_helpers::sample_genesis_block_and_components(rng);
+        let transition = transaction.transitions().next().unwrap();
+
+        // Derive the outgoing view key of the signer.
+        let view_key = ViewKey::<CurrentNetwork>::try_from(private_key).unwrap();
+        let outgoing_view_key = OutgoingViewKey::try_from(view_key).unwrap();
+
+        // Ensure the transition view key is recovered from the outgoing ciphertext.
+        let ciphertext = transition.to_outgoing_ciphertext(&view_key, &outgoing_view_key).unwrap();
+        let tvk = transition.decrypt_transition_view_key(&outgoing_view_key, ciphertext).unwrap();
+        assert_eq!(CurrentNetwork::hash_psd2(&[tvk]).unwrap(), *transition.tcm());
+
+        // Ensure the private input is the recipient of the transfer.
+        let address = Address::try_from(private_key).unwrap();
+        let inputs = transition.decrypt_private_inputs(tvk).unwrap();
+        assert_eq!(inputs.len(), 1);
+        assert_eq!(inputs[0].0, *transition.inputs()[0].id());
+        assert_eq!(inputs[0].1, Plaintext::from(Literal::Address(address)));
+        assert!(transition.decrypt_private_outputs(tvk).unwrap().is_empty());
+
+        // Ensure another outgoing view key cannot recover the transition view key.
+        let other_view_key = ViewKey::try_from(PrivateKey::<CurrentNetwork>::new(rng).unwrap()).unwrap();
+        let other_outgoing_view_key = OutgoingViewKey::try_from(other_view_key).unwrap();
+        assert!(transition.decrypt_transition_view_key(&other_outgoing_view_key, ciphertext).is_err());
+        // Ensure another view key cannot produce an outgoing ciphertext.
+        assert!(transition.to_outgoing_ciphertext(&other_view_key, &other_outgoing_view_key).is_err());
+    }
+}
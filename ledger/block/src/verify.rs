@@ -37,6 +37,36 @@ impl<N: Network> Block<N> {
         current_epoch_hash: N::BlockHash,
         current_timestamp: i64,
         ratified_finalize_operations: Vec<FinalizeOperation<N>>,
+    ) -> Result<(Vec<SolutionID<N>>, Vec<N::TransactionID>)> {
+        // Ensure the block is correct, up to the finalize root.
+        let expected_existing_ids = self.verify_without_finalize_root(
+            previous_block,
+            current_state_root,
+            previous_committee_lookback,
+            current_committee_lookback,
+            current_puzzle,
+            current_epoch_hash,
+            current_timestamp,
+        )?;
+        // Ensure the finalize root is correct.
+        self.verify_finalize_root(ratified_finalize_operations)?;
+        // Return the expected existing solution IDs and transaction IDs.
+        Ok(expected_existing_ids)
+    }
+
+    /// Ensures the block is correct, except for the finalize root, which requires the ratified finalize operations
+    /// from speculation. This allows the block to be verified concurrently with its transactions.
+    ///
+    /// Note: To ensure the block is correct, use `Block::verify_finalize_root` after speculation.
+    pub fn verify_without_finalize_root(
+        &self,
+        previous_block: &Block<N>,
+        current_state_root: N::StateRoot,
+        previous_committee_lookback: &Committee<N>,
+        current_committee_lookback: &Committee<N>,
+        current_puzzle: &Puzzle<N>,
+        current_epoch_hash: N::BlockHash,
+        current_timestamp: i64,
     ) -> Result<(Vec<SolutionID<N>>, Vec<N::TransactionID>)> {
         // Ensure the block hash is correct.
         self.verify_hash(previous_block.height(), previous_block.hash())?;
@@ -77,8 +107,8 @@ impl<N: Network> Block<N> {
         let expected_previous_state_root = current_state_root;
         // Compute the expected transactions root.
         let expected_transactions_root = self.compute_transactions_root()?;
-        // Compute the expected finalize root.
-        let expected_finalize_root = self.compute_finalize_root(ratified_finalize_operations)?;
+        // Defer the finalize root to `Block::verify_finalize_root`.
+        let expected_finalize_root = self.header.finalize_root();
         // Compute the expected ratifications root.
         let expected_ratifications_root = self.compute_ratifications_root()?;
         // Compute the expected solutions root.
@@ -109,6 +139,21 @@ impl<N: Network> Block<N> {
         // Return the expected existing solution IDs and transaction IDs.
         Ok((expected_existing_solution_ids, expected_existing_transaction_ids))
     }
+
+    /// Ensures the finalize root of the block is correct, for the given ratified finalize operations.
+    pub fn verify_finalize_root(&self, ratified_finalize_operations: Vec<FinalizeOperation<N>>) -> Result<()> {
+        // Compute the expected finalize root.
+        let expected_finalize_root = self.compute_finalize_root(ratified_finalize_operations)?;
+        // Ensure the finalize root is correct.
+        ensure!(
+            self.header.finalize_root() == expected_finalize_root,
+            "Finalize root is incorrect in block {} (found '{}', expected '{}')",
+            self.height(),
+            self.header.finalize_root(),
+            expected_finalize_root
+        );
+        Ok(())
+    }
 }
 
 impl<N: Network> Block<N> {
@@ -282,6 +327,8 @@ impl<N: Network> Block<N> {
 
         // Ensure there are sufficient ratifications.
         ensure!(self.ratifications.len() >= 2, "Block {height} must contain at least 2 ratifications");
+        // Ensure the ratifications are well-formed.
+        self.ratifications.verify()?;
 
         // Initialize a ratifications iterator.
         let mut ratifications_iter = self.ratifications.iter();
@@ -322,10 +369,10 @@ impl<N: Network> Block<N> {
 
         // Ensure the number of solutions is within the allowed range.
         ensure!(
-            self.solutions.len() <= N::MAX_SOLUTIONS,
+            self.solutions.len() <= PuzzleSolutions::<N>::MAX_SOLUTIONS,
             "Block {height} contains too many prover solutions (found '{}', expected '{}')",
             self.solutions.len(),
-            N::MAX_SOLUTIONS
+            PuzzleSolutions::<N>::MAX_SOLUTIONS
         );
         // Ensure the number of aborted solution IDs is within the allowed range.
         ensure!(
@@ -445,6 +492,9 @@ impl<N: Network> Block<N> {
             bail!("Found a duplicate transaction in block {height}");
         }
 
+        // Ensure the aborted transaction reasons are consistent with the block.
+        self.check_aborted_transaction_reasons()?;
+
         // Ensure there are no duplicate transition IDs.
         if has_duplicates(self.transition_ids()) {
             bail!("Found a duplicate transition in block {height}");
@@ -538,6 +588,35 @@ impl<N: Network> Block<N> {
         }
     }
 
+    /// Ensures the aborted transaction reasons correspond to the aborted transaction IDs.
+    pub fn check_aborted_transaction_reasons(&self) -> Result<()> {
+        Self::check_aborted_transaction_reasons_for(
+            &self.header,
+            &self.aborted_transaction_ids,
+            &self.aborted_transaction_reasons,
+        )
+    }
+
+    /// Ensures the given aborted transaction reasons correspond to the given aborted transaction IDs.
+    ///
+    /// Note: The reasons are not committed to by the block hash, so they are not part of consensus.
+    pub(super) fn check_aborted_transaction_reasons_for(
+        header: &Header<N>,
+        aborted_transaction_ids: &[N::TransactionID],
+        aborted_transaction_reasons: &[AbortReason],
+    ) -> Result<()> {
+        // Ensure the reasons are either omitted, or there is exactly one reason per aborted transaction ID.
+        ensure!(
+            aborted_transaction_reasons.is_empty()
+                || aborted_transaction_reasons.len() == aborted_transaction_ids.len(),
+            "Block {} has {} aborted transaction reasons for {} aborted transaction IDs",
+            header.height(),
+            aborted_transaction_reasons.len(),
+            aborted_transaction_ids.len()
+        );
+        Ok(())
+    }
+
     /// Checks that the transmission IDs in the given subdag matches the solutions and transactions in the block.
     /// Returns the IDs of the transactions and solutions that should already exist in the ledger.
     pub(super) fn check_subdag_transmissions(
@@ -0,0 +1,142 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use super::*;
+
+/// The number of basis points in 100%.
+const BASIS_POINTS: u128 = 10_000;
+
+impl<N: Network> Committee<N> {
+    /// Returns the stakes of the committee members, sorted in decreasing order.
+    fn sorted_stakes(&self) -> Vec<u64> {
+        let mut stakes = self.members.values().map(|(stake, _, _)| *stake).collect::<Vec<_>>();
+        stakes.sort_unstable_by(|a, b| b.cmp(a));
+        stakes
+    }
+
+    /// Returns the combined stake of the `k` largest members in the committee.
+    /// If `k` exceeds the number of members, the total stake is returned.
+    pub fn largest_k_stake(&self, k: usize) -> u64 {
+        self.sorted_stakes().into_iter().take(k).fold(0u64, |total, stake| total.saturating_add(stake))
+    }
+
+    /// Returns the share of the total stake held by the `k` largest members, in basis points.
+    pub fn largest_k_share_bps(&self, k: usize) -> u64 {
+        Self::to_share_bps(self.largest_k_stake(k), self.total_stake())
+    }
+
+    /// Returns the share of the total stake held by the given address, in basis points.
+    pub fn stake_share_bps(&self, address: Address<N>) -> u64 {
+        Self::to_share_bps(self.get_stake(address), self.total_stake())
+    }
+
+    /// Returns the contribution of the given address towards the quorum threshold, in basis points.
+    /// Note: A single member may contribute more than 100% if its stake exceeds the quorum threshold.
+    pub fn quorum_contribution_bps(&self, address: Address<N>) -> u64 {
+        Self::to_share_bps(self.get_stake(address), self.quorum_threshold())
+    }
+
+    /// Returns the minimum number of members whose combined stake reaches the quorum threshold.
+    pub fn num_members_to_reach_quorum(&self) -> usize {
+        let quorum_threshold = self.quorum_threshold();
+        let mut stake = 0u64;
+        for (index, member_stake) in self.sorted_stakes().into_iter().enumerate() {
+            stake = stake.saturating_add(member_stake);
+            if stake >= quorum_threshold {
+                return index + 1;
+            }
+        }
+        self.num_members()
+    }
+
+    /// Returns the minimum number of members that can halt the committee, by withholding their stake
+    /// such that the remaining members are unable to reach the quorum threshold.
+    pub fn num_members_to_halt(&self) -> usize {
+        let total_stake = self.total_stake();
+        let quorum_threshold = self.quorum_threshold();
+        let mut stake = 0u64;
+        for (index, member_stake) in self.sorted_stakes().into_iter().enumerate() {
+            stake = stake.saturating_add(member_stake);
+            if total_stake.saturating_sub(stake) < quorum_threshold {
+                return index + 1;
+            }
+        }
+        self.num_members()
+    }
+
+    /// Returns the given amount as a share of the given total, in basis points.
+    fn to_share_bps(amount: u64, total: u64) -> u64 {
+        match total {
+            0 => 0,
+            total => u64::try_from(amount as u128 * BASIS_POINTS / total as u128).unwrap_or(u64::MAX),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::prelude::TestRng;
+
+    type CurrentNetwork = console::network::MainnetV0;
+
+    #[test]
+    fn test_equal_stake_analytics() {
+        let rng = &mut TestRng::default();
+
+        // Sample a committee with 4 members of equal stake.
+        let members = (0..4).map(|_| (Address::new(rng.gen()), (MIN_VALIDATOR_STAKE, false, 0))).collect();
+        let committee = Committee::<CurrentNetwork>::new(1, members).unwrap();
+        let address = *committee.members().keys().next().unwrap();
+
+        // Check the stake shares.
+        assert_eq!(committee.largest_k_share_bps(0), 0);
+        assert_eq!(committee.largest_k_share_bps(1), 2_500);
+        assert_eq!(committee.largest_k_share_bps(4), 10_000);
+        assert_eq!(committee.largest_k_stake(10), committee.total_stake());
+        assert_eq!(committee.stake_share_bps(address), 2_500);
+        assert_eq!(committee.stake_share_bps(Address::new(rng.gen())), 0);
+
+        // With 4 equal members, 3 are required for quorum, and 2 can halt the committee.
+        assert_eq!(committee.num_members_to_reach_quorum(), 3);
+        assert_eq!(committee.num_members_to_halt(), 2);
+    }
+
+    #[test]
+    fn test_analytics_consistency() {
+        let rng = &mut TestRng::default();
+
+        for _ in 0..10 {
+            // Sample a committee.
+            let num_members = rng.gen_range(3..=Committee::<CurrentNetwork>::MAX_COMMITTEE_SIZE);
+            let committee = crate::test_helpers::sample_committee_custom(num_members, rng);
+
+            // Ensure the largest-k stakes are monotonically increasing.
+            let mut previous = 0;
+            for k in 0..=committee.num_members() {
+                let stake = committee.largest_k_stake(k);
+                assert!(stake >= previous);
+                previous = stake;
+            }
+
+            // Ensure the quorum and halting counts are consistent with the thresholds.
+            let quorum = committee.num_members_to_reach_quorum();
+            assert!(committee.largest_k_stake(quorum) >= committee.quorum_threshold());
+            assert!(committee.largest_k_stake(quorum - 1) < committee.quorum_threshold());
+            let halt = committee.num_members_to_halt();
+            assert!(committee.total_stake() - committee.largest_k_stake(halt) < committee.quorum_threshold());
+            assert!(halt <= quorum);
+        }
+    }
+}
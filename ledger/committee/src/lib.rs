@@ -16,6 +16,7 @@
 #![forbid(unsafe_code)]
 #![warn(clippy::cast_possible_truncation)]
 
+mod analytics;
 mod bytes;
 mod serialize;
 mod string;
@@ -31,6 +32,7 @@ use console::{
 };
 use ledger_narwhal_batch_header::BatchHeader;
 
+use core::ops::Range;
 use indexmap::IndexMap;
 use std::collections::HashSet;
 
@@ -63,6 +65,9 @@ impl<N: Network> Committee<N> {
     pub const COMMITTEE_LOOKBACK_RANGE: u64 = BatchHeader::<N>::MAX_GC_ROUNDS as u64;
     /// The maximum number of members that may be in a committee.
     pub const MAX_COMMITTEE_SIZE: u16 = BatchHeader::<N>::MAX_CERTIFICATES;
+    /// The maximum number of rounds in a leader schedule.
+    /// Note: This limit bounds the memory allocated for a caller-supplied range of rounds.
+    pub const MAX_LEADER_SCHEDULE_ROUNDS: u64 = 1000;
 
     /// Initializes a new `Committee` instance.
     pub fn new_genesis(members: IndexMap<Address<N>, (u64, bool, u8)>) -> Result<Self> {
@@ -222,6 +227,21 @@ impl<N: Network> Committee<N> {
         Ok(leader.unwrap())
     }
 
+    /// Returns the leader addresses for the given range of rounds.
+    /// The range is inclusive of the start and exclusive of the end, and spans at most `MAX_LEADER_SCHEDULE_ROUNDS`.
+    pub fn leader_schedule(&self, rounds: Range<u64>) -> Result<Vec<(u64, Address<N>)>> {
+        // Ensure the range starts at or after the starting round.
+        ensure!(rounds.start >= self.starting_round, "The round range must start at or after the starting round");
+        // Ensure the range is within bounds.
+        ensure!(
+            rounds.end.saturating_sub(rounds.start) <= Self::MAX_LEADER_SCHEDULE_ROUNDS,
+            "The round range must span at most {} rounds",
+            Self::MAX_LEADER_SCHEDULE_ROUNDS
+        );
+        // Compute the leader for each round.
+        cfg_into_iter!(rounds).map(|round| Ok((round, self.get_leader(round)?))).collect()
+    }
+
     /// Returns the committee members sorted by their address' x-coordinate in decreasing order.
     /// Note: This ensures the method returns a deterministic result that is SNARK-friendly.
     fn sorted_members(&self) -> indexmap::map::IntoIter<Address<N>, (u64, bool, u8)> {
@@ -442,6 +462,31 @@ mod tests {
         check_leader_distribution(committee, NUM_ROUNDS, 5.0);
     }
 
+    #[test]
+    fn test_leader_schedule() {
+        // Initialize the RNG.
+        let rng = &mut TestRng::default();
+        // Sample a committee.
+        let committee = crate::test_helpers::sample_committee_for_round(10, rng);
+
+        // Ensure the schedule matches the individual leader election.
+        let schedule = committee.leader_schedule(10..74).unwrap();
+        assert_eq!(schedule.len(), 64);
+        for (round, leader) in schedule {
+            assert_eq!(leader, committee.get_leader(round).unwrap());
+        }
+
+        // Ensure an empty range yields an empty schedule.
+        assert!(committee.leader_schedule(20..20).unwrap().is_empty());
+        // Ensure a range starting before the starting round fails.
+        assert!(committee.leader_schedule(9..20).is_err());
+        // Ensure a range exceeding the maximum number of rounds fails.
+        let max_rounds = Committee::<CurrentNetwork>::MAX_LEADER_SCHEDULE_ROUNDS;
+        assert_eq!(committee.leader_schedule(10..10 + max_rounds).unwrap().len() as u64, max_rounds);
+        assert!(committee.leader_schedule(10..11 + max_rounds).is_err());
+        assert!(committee.leader_schedule(10..u64::MAX).is_err());
+    }
+
     #[test]
     fn test_sorted_members() {
         // Initialize the RNG.
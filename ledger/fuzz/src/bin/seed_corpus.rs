@@ -0,0 +1,29 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Writes the seed corpus of the fuzz targets, from the sampled test fixtures.
+//!
+//! Run with `cargo run --release --bin seed_corpus [directory]` from `ledger/fuzz`.
+//! The directory defaults to `corpus`, which is the corpus directory of `cargo fuzz run`.
+
+use snarkvm_console::network::MainnetV0;
+use snarkvm_ledger::fuzz::write_seed_corpus;
+
+fn main() -> anyhow::Result<()> {
+    let directory = std::env::args().nth(1).unwrap_or_else(|| "corpus".to_string());
+    write_seed_corpus::<MainnetV0>(directory.as_ref())?;
+    println!("Wrote the seed corpus to '{directory}'");
+    Ok(())
+}
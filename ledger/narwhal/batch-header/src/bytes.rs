@@ -21,7 +21,7 @@ impl<N: Network> FromBytes for BatchHeader<N> {
         // Read the version.
         let version = u8::read_le(&mut reader)?;
         // Ensure the version is valid.
-        if version != 1 {
+        if version != 1 && version != 2 {
             return Err(error("Invalid batch header version"));
         }
 
@@ -51,6 +51,15 @@ impl<N: Network> FromBytes for BatchHeader<N> {
             // Insert the transmission ID.
             transmission_ids.insert(TransmissionID::read_le(&mut reader)?);
         }
+        // Read the number of priority transmissions.
+        let num_priority_transmissions = match version {
+            1 => 0,
+            _ => u16::read_le(&mut reader)?,
+        };
+        // Ensure the encoding is canonical, as version 2 is only used for batches with a priority lane.
+        if version == 2 && num_priority_transmissions == 0 {
+            return Err(error("Invalid batch header - version 2 must have a priority lane"));
+        }
 
         // Read the number of previous certificate IDs.
         let num_previous_certificate_ids = u16::read_le(&mut reader)?;
@@ -75,9 +84,17 @@ impl<N: Network> FromBytes for BatchHeader<N> {
         let signature = Signature::read_le(&mut reader)?;
 
         // Construct the batch.
-        let batch =
-            Self::from(author, round, timestamp, committee_id, transmission_ids, previous_certificate_ids, signature)
-                .map_err(error)?;
+        let batch = Self::from_with_priority_lane(
+            author,
+            round,
+            timestamp,
+            committee_id,
+            transmission_ids,
+            num_priority_transmissions,
+            previous_certificate_ids,
+            signature,
+        )
+        .map_err(error)?;
 
         // Return the batch.
         match batch.batch_id == batch_id {
@@ -91,7 +108,12 @@ impl<N: Network> ToBytes for BatchHeader<N> {
     /// Writes the batch header to the buffer.
     fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
         // Write the version.
-        1u8.write_le(&mut writer)?;
+        // Note: Version 2 is only used for batches with a priority lane.
+        let version = match self.num_priority_transmissions {
+            0 => 1u8,
+            _ => 2u8,
+        };
+        version.write_le(&mut writer)?;
         // Write the batch ID.
         self.batch_id.write_le(&mut writer)?;
         // Write the author.
@@ -109,6 +131,10 @@ impl<N: Network> ToBytes for BatchHeader<N> {
             // Write the transmission ID.
             transmission_id.write_le(&mut writer)?;
         }
+        // Write the number of priority transmissions.
+        if version != 1 {
+            self.num_priority_transmissions.write_le(&mut writer)?;
+        }
         // Write the number of previous certificate IDs.
         u16::try_from(self.previous_certificate_ids.len()).map_err(|e| error(e.to_string()))?.write_le(&mut writer)?;
         // Write the previous certificate IDs.
@@ -124,6 +150,9 @@ impl<N: Network> ToBytes for BatchHeader<N> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use console::network::MainnetV0;
+
+    type CurrentNetwork = MainnetV0;
 
     #[test]
     fn test_bytes() {
@@ -135,4 +164,24 @@ mod tests {
             assert_eq!(expected, BatchHeader::read_le(&expected_bytes[..]).unwrap());
         }
     }
+
+    #[test]
+    fn test_bytes_without_priority_lane() {
+        let rng = &mut TestRng::default();
+
+        for expected in crate::test_helpers::sample_batch_headers(rng) {
+            // Encode the batch header as version 2, without a priority lane.
+            let mut bytes = expected.to_bytes_le().unwrap();
+            assert_eq!(bytes[0], 1);
+            bytes[0] = 2;
+            // Note: The number of priority transmissions follows the version, batch ID, author, round, timestamp,
+            // committee ID, and transmission IDs.
+            let num_transmission_id_bytes =
+                expected.transmission_ids().iter().map(|id| id.to_bytes_le().unwrap().len()).sum::<usize>();
+            let offset = 1 + 32 + 32 + 8 + 8 + 32 + 4 + num_transmission_id_bytes;
+            bytes.splice(offset..offset, 0u16.to_le_bytes());
+            // Ensure the non-canonical encoding is rejected.
+            assert!(BatchHeader::<CurrentNetwork>::read_le(&bytes[..]).is_err());
+        }
+    }
 }
@@ -0,0 +1,141 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use super::*;
+
+/// The lane of a transmission in a batch.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Lane {
+    /// The lane for time-sensitive transmissions.
+    Priority,
+    /// The lane for all other transmissions.
+    Bulk,
+}
+
+impl Lane {
+    /// Returns the maximum number of transmissions in this lane, for a batch.
+    /// Note: The bulk lane is only bounded by the maximum number of transmissions in the batch.
+    pub const fn max_transmissions<N: Network>(&self) -> usize {
+        match self {
+            Self::Priority => BatchHeader::<N>::MAX_PRIORITY_TRANSMISSIONS_PER_BATCH,
+            Self::Bulk => BatchHeader::<N>::MAX_TRANSMISSIONS_PER_BATCH,
+        }
+    }
+}
+
+impl<N: Network> BatchHeader<N> {
+    /// Returns the transmission IDs in the priority lane.
+    pub fn priority_transmission_ids(&self) -> impl '_ + ExactSizeIterator<Item = &TransmissionID<N>> {
+        self.transmission_ids.iter().take(self.num_priority_transmissions as usize)
+    }
+
+    /// Returns the transmission IDs in the bulk lane.
+    pub fn bulk_transmission_ids(&self) -> impl '_ + ExactSizeIterator<Item = &TransmissionID<N>> {
+        self.transmission_ids.iter().skip(self.num_priority_transmissions as usize)
+    }
+
+    /// Returns the lane of the given `transmission ID`, if it is in the batch.
+    pub fn lane(&self, transmission_id: impl Into<TransmissionID<N>>) -> Option<Lane> {
+        match self.transmission_ids.get_index_of(&transmission_id.into()) {
+            Some(index) if index < self.num_priority_transmissions as usize => Some(Lane::Priority),
+            Some(_) => Some(Lane::Bulk),
+            None => None,
+        }
+    }
+
+    /// Ensures the number of transmissions in the priority lane is within bounds.
+    /// Note: The bulk lane is bounded by the number of transmission IDs, which is checked separately.
+    pub(crate) fn check_lanes(
+        transmission_ids: &IndexSet<TransmissionID<N>>,
+        num_priority_transmissions: u16,
+    ) -> Result<()> {
+        let num_priority_transmissions = num_priority_transmissions as usize;
+        // Ensure the priority lane is a subset of the transmission IDs.
+        ensure!(
+            num_priority_transmissions <= transmission_ids.len(),
+            "Invalid number of priority transmissions ({num_priority_transmissions}), exceeds the number of transmission IDs ({})",
+            transmission_ids.len()
+        );
+        // Ensure the priority lane is within bounds.
+        ensure!(
+            num_priority_transmissions <= Lane::Priority.max_transmissions::<N>(),
+            "Invalid number of priority transmissions ({num_priority_transmissions})"
+        );
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::MainnetV0;
+
+    type CurrentNetwork = MainnetV0;
+
+    #[test]
+    fn test_lanes() {
+        let rng = &mut TestRng::default();
+
+        // Sample a batch header with a priority lane.
+        let private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+        let transmission_ids =
+            narwhal_transmission_id::test_helpers::sample_transmission_ids(rng).into_iter().collect::<IndexSet<_>>();
+        let num_priority = 2u16.min(u16::try_from(transmission_ids.len()).unwrap());
+        let header = BatchHeader::new_with_priority_lane(
+            &private_key,
+            1,
+            0,
+            Field::rand(rng),
+            transmission_ids.clone(),
+            num_priority,
+            Default::default(),
+            rng,
+        )
+        .unwrap();
+
+        // Check the lanes.
+        assert_eq!(header.num_priority_transmissions(), num_priority);
+        assert_eq!(header.priority_transmission_ids().len(), num_priority as usize);
+        assert_eq!(header.bulk_transmission_ids().len(), transmission_ids.len() - num_priority as usize);
+        for (index, transmission_id) in transmission_ids.iter().enumerate() {
+            let expected = if index < num_priority as usize { Lane::Priority } else { Lane::Bulk };
+            assert_eq!(header.lane(*transmission_id), Some(expected));
+        }
+
+        // Ensure the batch ID commits to the priority lane.
+        let committee_id = header.committee_id();
+        let plain =
+            BatchHeader::new(&private_key, 1, 0, committee_id, transmission_ids, Default::default(), rng).unwrap();
+        assert_ne!(plain.batch_id(), header.batch_id());
+        assert_eq!(header.to_id().unwrap(), header.batch_id());
+    }
+
+    #[test]
+    fn test_check_lanes() {
+        let rng = &mut TestRng::default();
+
+        let transmission_ids =
+            narwhal_transmission_id::test_helpers::sample_transmission_ids(rng).into_iter().collect::<IndexSet<_>>();
+        let num_transmissions = u16::try_from(transmission_ids.len()).unwrap();
+
+        // Ensure the priority lane may not exceed the transmission IDs.
+        assert!(BatchHeader::<CurrentNetwork>::check_lanes(&transmission_ids, num_transmissions).is_ok());
+        assert!(BatchHeader::<CurrentNetwork>::check_lanes(&transmission_ids, num_transmissions + 1).is_err());
+        // Ensure the priority lane may not exceed its limit.
+        let max_priority = u16::try_from(BatchHeader::<CurrentNetwork>::MAX_PRIORITY_TRANSMISSIONS_PER_BATCH).unwrap();
+        if num_transmissions > max_priority {
+            assert!(BatchHeader::<CurrentNetwork>::check_lanes(&transmission_ids, max_priority + 1).is_err());
+        }
+    }
+}
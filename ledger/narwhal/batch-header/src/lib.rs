@@ -18,6 +18,9 @@
 #![allow(clippy::too_many_arguments)]
 
 mod bytes;
+mod lane;
+pub use lane::*;
+
 mod serialize;
 mod string;
 mod to_id;
@@ -49,6 +52,8 @@ pub struct BatchHeader<N: Network> {
     committee_id: Field<N>,
     /// The set of `transmission IDs`.
     transmission_ids: IndexSet<TransmissionID<N>>,
+    /// The number of leading `transmission IDs` that belong to the priority lane.
+    num_priority_transmissions: u16,
     /// The batch certificate IDs of the previous round.
     previous_certificate_ids: IndexSet<Field<N>>,
     /// The signature of the batch ID from the creator.
@@ -65,11 +70,13 @@ impl<N: Network> BatchHeader<N> {
     pub const MAX_CERTIFICATES: u16 = 100;
     /// The maximum number of rounds to store before garbage collecting.
     pub const MAX_GC_ROUNDS: usize = 100;
+    /// The maximum number of transmissions in the priority lane of a batch.
+    pub const MAX_PRIORITY_TRANSMISSIONS_PER_BATCH: usize = 10;
     /// The maximum number of transmissions in a batch.
     /// Note: This limit is set to 50 as part of safety measures to prevent DoS attacks.
     /// This limit can be increased in the future as performance improves. Alternatively,
     /// the rate of block production can be sped up to compensate for the limit set here.
-    pub const MAX_TRANSMISSIONS_PER_BATCH: usize = 50;
+    pub const MAX_TRANSMISSIONS_PER_BATCH: usize = N::BLOCK_LIMITS.max_transmissions_per_batch;
 }
 
 impl<N: Network> BatchHeader<N> {
@@ -82,6 +89,30 @@ impl<N: Network> BatchHeader<N> {
         transmission_ids: IndexSet<TransmissionID<N>>,
         previous_certificate_ids: IndexSet<Field<N>>,
         rng: &mut R,
+    ) -> Result<Self> {
+        Self::new_with_priority_lane(
+            private_key,
+            round,
+            timestamp,
+            committee_id,
+            transmission_ids,
+            0,
+            previous_certificate_ids,
+            rng,
+        )
+    }
+
+    /// Initializes a new batch header, where the first `num_priority_transmissions` transmission IDs
+    /// belong to the priority lane, and the remaining transmission IDs belong to the bulk lane.
+    pub fn new_with_priority_lane<R: Rng + CryptoRng>(
+        private_key: &PrivateKey<N>,
+        round: u64,
+        timestamp: i64,
+        committee_id: Field<N>,
+        transmission_ids: IndexSet<TransmissionID<N>>,
+        num_priority_transmissions: u16,
+        previous_certificate_ids: IndexSet<Field<N>>,
+        rng: &mut R,
     ) -> Result<Self> {
         match round {
             0 | 1 => {
@@ -98,6 +129,8 @@ impl<N: Network> BatchHeader<N> {
             "Invalid number of transmission IDs ({})",
             transmission_ids.len()
         );
+        // Ensure that the number of transmissions in the priority lane is within bounds.
+        Self::check_lanes(&transmission_ids, num_priority_transmissions)?;
         // Ensure that the number of previous certificate IDs is within bounds.
         ensure!(
             previous_certificate_ids.len() <= Self::MAX_CERTIFICATES as usize,
@@ -108,12 +141,13 @@ impl<N: Network> BatchHeader<N> {
         // Retrieve the address.
         let author = Address::try_from(private_key)?;
         // Compute the batch ID.
-        let batch_id = Self::compute_batch_id(
+        let batch_id = Self::compute_batch_id_with_priority_lane(
             author,
             round,
             timestamp,
             committee_id,
             &transmission_ids,
+            num_priority_transmissions,
             &previous_certificate_ids,
         )?;
         // Sign the preimage.
@@ -126,6 +160,7 @@ impl<N: Network> BatchHeader<N> {
             timestamp,
             committee_id,
             transmission_ids,
+            num_priority_transmissions,
             previous_certificate_ids,
             signature,
         })
@@ -140,6 +175,30 @@ impl<N: Network> BatchHeader<N> {
         transmission_ids: IndexSet<TransmissionID<N>>,
         previous_certificate_ids: IndexSet<Field<N>>,
         signature: Signature<N>,
+    ) -> Result<Self> {
+        Self::from_with_priority_lane(
+            author,
+            round,
+            timestamp,
+            committee_id,
+            transmission_ids,
+            0,
+            previous_certificate_ids,
+            signature,
+        )
+    }
+
+    /// Initializes a new batch header, where the first `num_priority_transmissions` transmission IDs
+    /// belong to the priority lane, and the remaining transmission IDs belong to the bulk lane.
+    pub fn from_with_priority_lane(
+        author: Address<N>,
+        round: u64,
+        timestamp: i64,
+        committee_id: Field<N>,
+        transmission_ids: IndexSet<TransmissionID<N>>,
+        num_priority_transmissions: u16,
+        previous_certificate_ids: IndexSet<Field<N>>,
+        signature: Signature<N>,
     ) -> Result<Self> {
         match round {
             0 | 1 => {
@@ -156,6 +215,8 @@ impl<N: Network> BatchHeader<N> {
             "Invalid number of transmission IDs ({})",
             transmission_ids.len()
         );
+        // Ensure that the number of transmissions in the priority lane is within bounds.
+        Self::check_lanes(&transmission_ids, num_priority_transmissions)?;
         // Ensure that the number of previous certificate IDs is within bounds.
         ensure!(
             previous_certificate_ids.len() <= Self::MAX_CERTIFICATES as usize,
@@ -164,12 +225,13 @@ impl<N: Network> BatchHeader<N> {
         );
 
         // Compute the batch ID.
-        let batch_id = Self::compute_batch_id(
+        let batch_id = Self::compute_batch_id_with_priority_lane(
             author,
             round,
             timestamp,
             committee_id,
             &transmission_ids,
+            num_priority_transmissions,
             &previous_certificate_ids,
         )?;
         // Verify the signature.
@@ -184,6 +246,7 @@ impl<N: Network> BatchHeader<N> {
             timestamp,
             committee_id,
             transmission_ids,
+            num_priority_transmissions,
             previous_certificate_ids,
             signature,
         })
@@ -221,6 +284,11 @@ impl<N: Network> BatchHeader<N> {
         &self.transmission_ids
     }
 
+    /// Returns the number of transmissions in the priority lane.
+    pub const fn num_priority_transmissions(&self) -> u16 {
+        self.num_priority_transmissions
+    }
+
     /// Returns the batch certificate IDs for the previous round.
     pub const fn previous_certificate_ids(&self) -> &IndexSet<Field<N>> {
         &self.previous_certificate_ids
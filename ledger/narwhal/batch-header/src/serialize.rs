@@ -27,6 +27,9 @@ impl<N: Network> Serialize for BatchHeader<N> {
                 header.serialize_field("timestamp", &self.timestamp)?;
                 header.serialize_field("committee_id", &self.committee_id)?;
                 header.serialize_field("transmission_ids", &self.transmission_ids)?;
+                if self.num_priority_transmissions > 0 {
+                    header.serialize_field("num_priority_transmissions", &self.num_priority_transmissions)?;
+                }
                 header.serialize_field("previous_certificate_ids", &self.previous_certificate_ids)?;
                 header.serialize_field("signature", &self.signature)?;
                 header.end()
@@ -43,14 +46,20 @@ impl<'de, N: Network> Deserialize<'de> for BatchHeader<N> {
             true => {
                 let mut header = serde_json::Value::deserialize(deserializer)?;
                 let batch_id: Field<N> = DeserializeExt::take_from_value::<D>(&mut header, "batch_id")?;
+                // Recover the number of priority transmissions, if there is a priority lane.
+                let num_priority_transmissions = match header.get("num_priority_transmissions") {
+                    Some(_) => DeserializeExt::take_from_value::<D>(&mut header, "num_priority_transmissions")?,
+                    None => 0,
+                };
 
                 // Recover the header.
-                let batch_header = Self::from(
+                let batch_header = Self::from_with_priority_lane(
                     DeserializeExt::take_from_value::<D>(&mut header, "author")?,
                     DeserializeExt::take_from_value::<D>(&mut header, "round")?,
                     DeserializeExt::take_from_value::<D>(&mut header, "timestamp")?,
                     DeserializeExt::take_from_value::<D>(&mut header, "committee_id")?,
                     DeserializeExt::take_from_value::<D>(&mut header, "transmission_ids")?,
+                    num_priority_transmissions,
                     DeserializeExt::take_from_value::<D>(&mut header, "previous_certificate_ids")?,
                     DeserializeExt::take_from_value::<D>(&mut header, "signature")?,
                 )
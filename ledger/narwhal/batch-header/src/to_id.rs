@@ -18,12 +18,13 @@ use super::*;
 impl<N: Network> BatchHeader<N> {
     /// Returns the batch ID.
     pub fn to_id(&self) -> Result<Field<N>> {
-        Self::compute_batch_id(
+        Self::compute_batch_id_with_priority_lane(
             self.author,
             self.round,
             self.timestamp,
             self.committee_id,
             &self.transmission_ids,
+            self.num_priority_transmissions,
             &self.previous_certificate_ids,
         )
     }
@@ -38,6 +39,27 @@ impl<N: Network> BatchHeader<N> {
         committee_id: Field<N>,
         transmission_ids: &IndexSet<TransmissionID<N>>,
         previous_certificate_ids: &IndexSet<Field<N>>,
+    ) -> Result<Field<N>> {
+        Self::compute_batch_id_with_priority_lane(
+            author,
+            round,
+            timestamp,
+            committee_id,
+            transmission_ids,
+            0,
+            previous_certificate_ids,
+        )
+    }
+
+    /// Returns the batch ID, for a batch with the given number of priority transmissions.
+    pub fn compute_batch_id_with_priority_lane(
+        author: Address<N>,
+        round: u64,
+        timestamp: i64,
+        committee_id: Field<N>,
+        transmission_ids: &IndexSet<TransmissionID<N>>,
+        num_priority_transmissions: u16,
+        previous_certificate_ids: &IndexSet<Field<N>>,
     ) -> Result<Field<N>> {
         let mut preimage = Vec::new();
         // Insert the author.
@@ -61,6 +83,11 @@ impl<N: Network> BatchHeader<N> {
             // Insert the certificate ID.
             certificate_id.write_le(&mut preimage)?;
         }
+        // Insert the number of priority transmissions, if there is a priority lane.
+        // Note: This preserves the batch ID of batches without a priority lane.
+        if num_priority_transmissions > 0 {
+            num_priority_transmissions.write_le(&mut preimage)?;
+        }
         // Hash the preimage.
         N::hash_bhp1024(&preimage.to_bits_le())
     }
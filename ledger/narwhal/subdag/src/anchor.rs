@@ -0,0 +1,239 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use super::*;
+
+use std::collections::HashSet;
+
+impl<N: Network> Subdag<N> {
+    /// Returns `true` if the given round is an anchor round, i.e. a round that elects a leader.
+    pub const fn is_anchor_round(round: u64) -> bool {
+        round % 2 == 0
+    }
+
+    /// Initializes the subdag to commit for the given leader certificate, by traversing its causal history in the DAG.
+    /// Certificates that are at or below the `gc_round`, or that satisfy `is_committed`, are excluded from the subdag.
+    pub fn from_leader_certificate(
+        leader_certificate: BatchCertificate<N>,
+        dag: &BTreeMap<u64, IndexSet<BatchCertificate<N>>>,
+        gc_round: u64,
+        is_committed: impl Fn(&BatchCertificate<N>) -> bool,
+    ) -> Result<Self> {
+        // Ensure the leader certificate is from an anchor round.
+        ensure!(Self::is_anchor_round(leader_certificate.round()), "The leader certificate must be in an anchor round");
+        // Ensure the leader certificate is above the GC round.
+        ensure!(leader_certificate.round() > gc_round, "The leader certificate must be above the GC round");
+
+        // Initialize a map for the certificates to commit.
+        let mut commit = BTreeMap::<u64, IndexSet<_>>::new();
+        // Initialize a set for the already ordered certificates.
+        let mut already_ordered = HashSet::new();
+        // Initialize a buffer for the certificates to order, starting with the leader certificate.
+        let mut buffer = vec![leader_certificate];
+        // Iterate over the certificates to order.
+        while let Some(certificate) = buffer.pop() {
+            // Retrieve the previous round.
+            let previous_round = certificate.round().saturating_sub(1);
+            // Iterate over the previous certificate IDs.
+            for previous_certificate_id in certificate.previous_certificate_ids() {
+                // If the previous round is at or below the GC round, skip it.
+                if previous_round <= gc_round {
+                    continue;
+                }
+                let Some(previous_certificate) = dag
+                    .get(&previous_round)
+                    .and_then(|map| map.iter().find(|certificate| certificate.id() == *previous_certificate_id))
+                else {
+                    // It is either ordered or below the GC round.
+                    continue;
+                };
+                // If the previous certificate is already committed, skip it.
+                if is_committed(previous_certificate) {
+                    continue;
+                }
+                // Insert the previous certificate into the set of already ordered certificates.
+                if !already_ordered.insert(previous_certificate.id()) {
+                    // If the previous certificate is already ordered, continue.
+                    continue;
+                }
+                // Insert the previous certificate into the buffer.
+                buffer.push(previous_certificate.clone());
+            }
+            // Insert the certificate into the map.
+            commit.entry(certificate.round()).or_default().insert(certificate);
+        }
+        // Initialize the subdag.
+        Self::from(commit)
+    }
+
+    /// Returns the sequence of subdags committed by the given DAG, in commit order, using the given committee
+    /// to elect the leaders. Certificates at or below the `gc_round` are excluded from the sequence.
+    ///
+    /// A leader certificate is committed once the certificates of the following round that reference it
+    /// reach the availability threshold. Upon committing a leader certificate, any earlier uncommitted leader
+    /// certificates that are linked to it are committed first.
+    pub fn commit_sequence(
+        dag: &BTreeMap<u64, IndexSet<BatchCertificate<N>>>,
+        committee: &Committee<N>,
+        gc_round: u64,
+    ) -> Result<Vec<Self>> {
+        // Initialize the list of subdags.
+        let mut subdags = Vec::new();
+        // Initialize the set of committed certificate IDs.
+        let mut committed = HashSet::new();
+        // Initialize the last committed anchor round.
+        let mut last_committed_round = gc_round;
+
+        // Iterate over the anchor rounds in the DAG.
+        for round in dag.keys().copied().filter(|round| *round > gc_round && Self::is_anchor_round(*round)) {
+            // Retrieve the leader certificate for the round.
+            let Some(leader_certificate) = Self::leader_certificate_for_round(dag, committee, round)? else {
+                continue;
+            };
+            // Collect the authors of the certificates in the next round that reference the leader certificate.
+            let votes = dag
+                .get(&(round + 1))
+                .map(|certificates| {
+                    certificates
+                        .iter()
+                        .filter(|certificate| certificate.previous_certificate_ids().contains(&leader_certificate.id()))
+                        .map(|certificate| certificate.author())
+                        .collect::<HashSet<_>>()
+                })
+                .unwrap_or_default();
+            // If the leader certificate does not have enough support, skip it.
+            if !committee.is_availability_threshold_reached(&votes) {
+                continue;
+            }
+
+            // Collect the leader certificates to commit, from the latest to the earliest.
+            let mut leader_certificates = vec![leader_certificate.clone()];
+            let mut current_certificate = leader_certificate;
+            let previous_rounds = (last_committed_round + 1..round).rev().filter(|round| Self::is_anchor_round(*round));
+            for previous_round in previous_rounds {
+                // Retrieve the leader certificate for the previous round.
+                let Some(previous_certificate) = Self::leader_certificate_for_round(dag, committee, previous_round)?
+                else {
+                    continue;
+                };
+                // If the previous leader certificate is linked, commit it first.
+                if Self::is_linked(&previous_certificate, &current_certificate, dag) {
+                    leader_certificates.push(previous_certificate.clone());
+                    current_certificate = previous_certificate;
+                }
+            }
+
+            // Commit the leader certificates, from the earliest to the latest.
+            for leader_certificate in leader_certificates.into_iter().rev() {
+                let subdag = Self::from_leader_certificate(leader_certificate, dag, gc_round, |certificate| {
+                    committed.contains(&certificate.id())
+                })?;
+                committed.extend(subdag.certificate_ids());
+                subdags.push(subdag);
+            }
+            // Update the last committed anchor round.
+            last_committed_round = round;
+        }
+        Ok(subdags)
+    }
+
+    /// Returns the certificate authored by the elected leader of the given round, if it exists in the DAG.
+    fn leader_certificate_for_round(
+        dag: &BTreeMap<u64, IndexSet<BatchCertificate<N>>>,
+        committee: &Committee<N>,
+        round: u64,
+    ) -> Result<Option<BatchCertificate<N>>> {
+        // Compute the leader for the round.
+        let leader = committee.get_leader(round)?;
+        // Retrieve the leader certificate.
+        Ok(dag.get(&round).and_then(|certificates| certificates.iter().find(|c| c.author() == leader)).cloned())
+    }
+
+    /// Returns `true` if there is a path in the DAG from the `current` certificate to the `previous` certificate.
+    pub fn is_linked(
+        previous: &BatchCertificate<N>,
+        current: &BatchCertificate<N>,
+        dag: &BTreeMap<u64, IndexSet<BatchCertificate<N>>>,
+    ) -> bool {
+        // A certificate can only be linked to a certificate from an earlier round.
+        if previous.round() >= current.round() {
+            return previous == current;
+        }
+        // Initialize the frontier with the current certificate's parents.
+        let mut frontier = current.previous_certificate_ids().clone();
+        // Traverse the DAG down to the round after the previous certificate.
+        for round in (previous.round() + 1..current.round()).rev() {
+            // Retrieve the certificates for the round.
+            let Some(certificates) = dag.get(&round) else {
+                return false;
+            };
+            // Update the frontier to the parents of the certificates in the frontier.
+            frontier = certificates
+                .iter()
+                .filter(|certificate| frontier.contains(&certificate.id()))
+                .flat_map(|certificate| certificate.previous_certificate_ids())
+                .copied()
+                .collect();
+        }
+        // Return `true` if the previous certificate is in the frontier.
+        frontier.contains(&previous.id())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_leader_certificate() {
+        let rng = &mut TestRng::default();
+
+        for expected in crate::test_helpers::sample_subdags(rng) {
+            // Reconstruct the subdag from the DAG and the leader certificate.
+            let leader_certificate = expected.leader_certificate().clone();
+            let candidate =
+                Subdag::from_leader_certificate(leader_certificate.clone(), &expected, 0, |_| false).unwrap();
+            assert_eq!(expected, candidate);
+
+            // Ensure the committed certificates are excluded.
+            let earliest_round = *expected.keys().next().unwrap();
+            let candidate = Subdag::from_leader_certificate(leader_certificate.clone(), &expected, 0, |certificate| {
+                certificate.round() == earliest_round
+            })
+            .unwrap();
+            assert_eq!(candidate.len(), expected.len() - 1);
+
+            // Ensure the certificates at or below the GC round are excluded.
+            let candidate =
+                Subdag::from_leader_certificate(leader_certificate, &expected, earliest_round, |_| false).unwrap();
+            assert_eq!(candidate.len(), expected.len() - 1);
+        }
+    }
+
+    #[test]
+    fn test_is_linked() {
+        let rng = &mut TestRng::default();
+
+        for subdag in crate::test_helpers::sample_subdags(rng) {
+            let leader_certificate = subdag.leader_certificate();
+            // Ensure every certificate in the subdag is linked to the leader certificate.
+            for certificate in subdag.values().flatten() {
+                assert!(Subdag::is_linked(certificate, leader_certificate, &subdag));
+            }
+            // Ensure the leader certificate is not linked to its own history.
+            let earliest_certificate = subdag.values().next().unwrap().first().unwrap();
+            assert!(!Subdag::is_linked(leader_certificate, earliest_certificate, &subdag));
+        }
+    }
+}
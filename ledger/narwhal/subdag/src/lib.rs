@@ -16,6 +16,7 @@
 #![forbid(unsafe_code)]
 #![warn(clippy::cast_possible_truncation)]
 
+mod anchor;
 mod bytes;
 mod serialize;
 mod string;
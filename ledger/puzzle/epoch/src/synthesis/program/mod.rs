@@ -26,14 +26,15 @@ use console::{
     program::{Field, Identifier, Literal, LiteralType, Value},
 };
 use snarkvm_synthesizer_process::{CallStack, Process, Registers, Stack, StackProgramTypes};
-use snarkvm_synthesizer_program::{Instruction, Program, RegistersStoreCircuit, StackProgram};
+use snarkvm_synthesizer_program::{Instruction, Opcode, Program, RegistersStoreCircuit, StackProgram};
 
 use aleo_std::prelude::{finish, lap, timer};
 use anyhow::{anyhow, bail, ensure, Result};
+use indexmap::IndexMap;
 use rand::Rng;
 use rand_chacha::ChaChaRng;
 use std::{
-    fmt::{self, Debug, Formatter},
+    fmt::{self, Debug, Display, Formatter},
     ops::Deref,
     str::FromStr,
 };
@@ -41,6 +42,10 @@ use std::{
 /// The arity of the Merkle tree.
 const ARITY: u8 = 8;
 
+/// The synthesis program for an epoch, which defines the workload of the puzzle for all solutions in the epoch.
+///
+/// The program is deterministically derived from the epoch hash, so it can be generated and analyzed
+/// ahead of time. Its string representation is a valid Aleo program, which parses back into a `Program`.
 #[derive(Clone)]
 pub struct EpochProgram<N: Network> {
     /// The program stack for the epoch.
@@ -61,6 +66,13 @@ impl<N: Network> Debug for EpochProgram<N> {
     }
 }
 
+impl<N: Network> Display for EpochProgram<N> {
+    /// Prints the epoch program as an Aleo program.
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        Display::fmt(self.stack.program(), f)
+    }
+}
+
 impl<N: Network> PartialEq for EpochProgram<N> {
     /// Returns `true` if the epoch programs are equal.
     fn eq(&self, other: &Self) -> bool {
@@ -73,7 +85,8 @@ impl<N: Network> Eq for EpochProgram<N> {}
 impl<N: Network> EpochProgram<N> {
     /// Initializes a new epoch program, given an epoch.
     ///
-    /// This method deterministically synthesizes a new program.
+    /// This method deterministically synthesizes a new program, i.e. the same epoch hash always yields
+    /// the same instructions, on all platforms.
     pub fn new(epoch_hash: N::BlockHash) -> Result<Self> {
         // Initialize the register table.
         let mut register_table = RegisterTable::new();
@@ -119,6 +132,12 @@ impl<N: Network> EpochProgram<N> {
         &self.stack
     }
 
+    /// Returns the program.
+    #[inline]
+    pub fn program(&self) -> &Program<N> {
+        self.stack.program()
+    }
+
     /// Returns the register table.
     #[inline]
     pub const fn register_table(&self) -> &RegisterTable {
@@ -136,6 +155,15 @@ impl<N: Network> EpochProgram<N> {
     pub fn instructions(&self) -> Result<&[Instruction<N>]> {
         Ok(self.stack.program().get_function_ref(&Identifier::from_str("synthesize")?)?.instructions())
     }
+
+    /// Returns the number of instructions for each opcode in the program, in order of first occurrence.
+    pub fn opcode_counts(&self) -> Result<IndexMap<Opcode, usize>> {
+        let mut counts = IndexMap::new();
+        for instruction in self.instructions()? {
+            *counts.entry(instruction.opcode()).or_default() += 1;
+        }
+        Ok(counts)
+    }
 }
 
 impl<N: Network> Deref for EpochProgram<N> {
@@ -180,4 +208,29 @@ mod tests {
         // Ensure the instructions are not empty.
         assert!(!instructions.is_empty());
     }
+
+    #[test]
+    fn test_program_string_roundtrip() {
+        let mut rng = TestRng::default();
+
+        // Initialize a new epoch program.
+        let epoch_program = EpochProgram::<CurrentNetwork>::new(rng.gen()).unwrap();
+        // Ensure the program string parses back into the program.
+        let program = Program::<CurrentNetwork>::from_str(&epoch_program.to_string()).unwrap();
+        assert_eq!(&program, epoch_program.program());
+        // Ensure a different epoch hash yields a different program.
+        let other_program = EpochProgram::<CurrentNetwork>::new(rng.gen()).unwrap();
+        assert_ne!(other_program.to_string(), epoch_program.to_string());
+    }
+
+    #[test]
+    fn test_opcode_counts() {
+        let mut rng = TestRng::default();
+
+        // Initialize a new epoch program.
+        let epoch_program = EpochProgram::<CurrentNetwork>::new(rng.gen()).unwrap();
+        // Ensure the counts cover all of the instructions.
+        let counts = epoch_program.opcode_counts().unwrap();
+        assert_eq!(counts.values().sum::<usize>(), epoch_program.instructions().unwrap().len());
+    }
 }
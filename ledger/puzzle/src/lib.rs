@@ -26,6 +26,9 @@ pub use solution::*;
 mod solution_id;
 pub use solution_id::*;
 
+mod solution_pool;
+pub use solution_pool::*;
+
 mod solutions;
 pub use solutions::*;
 
@@ -245,6 +248,33 @@ impl<N: Network> Puzzle<N> {
         Ok(Solution::new(partial_solution, proof_target))
     }
 
+    /// Searches the given counters, in order, for a solution that meets the minimum proof target.
+    /// Returns the first such solution, after verifying it, or `None` if the counters are exhausted.
+    ///
+    /// The counters define the search strategy, e.g. `0..1000` for a sequential search,
+    /// or `std::iter::repeat_with(|| rng.gen())` for an unbounded random search.
+    pub fn solve(
+        &self,
+        epoch_hash: N::BlockHash,
+        address: Address<N>,
+        minimum_proof_target: u64,
+        counters: impl IntoIterator<Item = u64>,
+    ) -> Result<Option<Solution<N>>> {
+        for counter in counters {
+            // Construct the partial solution.
+            let partial_solution = PartialSolution::new(epoch_hash, address, counter)?;
+            // Compute the proof target.
+            let proof_target = self.get_proof_target_from_partial_solution(&partial_solution)?;
+            // If the minimum proof target is met, construct and verify the solution.
+            if proof_target >= minimum_proof_target {
+                let solution = Solution::new(partial_solution, proof_target);
+                self.check_solution(&solution, epoch_hash, minimum_proof_target)?;
+                return Ok(Some(solution));
+            }
+        }
+        Ok(None)
+    }
+
     /// Returns `Ok(())` if the solution is valid.
     pub fn check_solution(
         &self,
@@ -307,8 +337,12 @@ impl<N: Network> Puzzle<N> {
         // Ensure the solutions are not empty.
         ensure!(!solutions.is_empty(), "The solutions are empty");
         // Ensure the number of solutions does not exceed `MAX_SOLUTIONS`.
-        if solutions.len() > N::MAX_SOLUTIONS {
-            bail!("Exceed the maximum number of solutions ({} > {})", solutions.len(), N::MAX_SOLUTIONS)
+        if solutions.len() > PuzzleSolutions::<N>::MAX_SOLUTIONS {
+            bail!(
+                "Exceed the maximum number of solutions ({} > {})",
+                solutions.len(),
+                PuzzleSolutions::<N>::MAX_SOLUTIONS
+            )
         }
         // Ensure the solution IDs are unique.
         if has_duplicates(solutions.solution_ids()) {
@@ -486,6 +520,45 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_solve() {
+        let mut rng = TestRng::default();
+
+        // Initialize a new puzzle.
+        let puzzle = sample_puzzle();
+
+        // Initialize an epoch hash and an address.
+        let epoch_hash = rng.gen();
+        let address = Address::try_from(PrivateKey::<CurrentNetwork>::new(&mut rng).unwrap()).unwrap();
+
+        // Compute the proof targets for a range of counters.
+        let proof_targets = (0..ITERATIONS)
+            .map(|counter| puzzle.prove(epoch_hash, address, counter, None).unwrap().target())
+            .collect::<Vec<_>>();
+        let (best_counter, best_target) =
+            proof_targets.iter().enumerate().max_by_key(|(_, target)| **target).map(|(i, t)| (i as u64, *t)).unwrap();
+
+        // Ensure the sequential search finds the best solution.
+        let solution = puzzle.solve(epoch_hash, address, best_target, 0..ITERATIONS).unwrap().unwrap();
+        assert_eq!(solution.counter(), best_counter);
+        assert_eq!(solution.target(), best_target);
+        assert!(puzzle.check_solution(&solution, epoch_hash, best_target).is_ok());
+
+        // Ensure the search returns the first counter that meets the minimum proof target.
+        let solution = puzzle.solve(epoch_hash, address, 0, 0..ITERATIONS).unwrap().unwrap();
+        assert_eq!(solution.counter(), 0);
+
+        // Ensure a custom search strategy is used.
+        let solution = puzzle.solve(epoch_hash, address, best_target, (0..ITERATIONS).rev()).unwrap().unwrap();
+        assert_eq!(solution.target(), best_target);
+
+        // Ensure the search returns `None` if the counters are exhausted.
+        assert!(puzzle.solve(epoch_hash, address, u64::MAX, 0..0).unwrap().is_none());
+        if best_target < u64::MAX {
+            assert!(puzzle.solve(epoch_hash, address, best_target + 1, 0..ITERATIONS).unwrap().is_none());
+        }
+    }
+
     #[test]
     fn test_prove_with_no_minimum_proof_target() {
         let mut rng = rand::thread_rng();
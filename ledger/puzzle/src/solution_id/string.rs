@@ -22,17 +22,8 @@ impl<N: Network> FromStr for SolutionID<N> {
 
     /// Reads in the solution ID string.
     fn from_str(solution_id: &str) -> Result<Self, Self::Err> {
-        // Decode the solution ID string from bech32m.
-        let (hrp, data, variant) = bech32::decode(solution_id)?;
-        if hrp != SOLUTION_ID_PREFIX {
-            bail!("Failed to decode solution ID: '{hrp}' is an invalid prefix")
-        } else if data.is_empty() {
-            bail!("Failed to decode solution ID: data field is empty")
-        } else if variant != bech32::Variant::Bech32m {
-            bail!("Found a solution ID that is not bech32m encoded: {solution_id}");
-        }
-        // Decode the solution ID data from u5 to u8, and into the solution ID.
-        Ok(Self::read_le(&Vec::from_base32(&data)?[..])?)
+        // Decode the solution ID string from bech32m, and into the solution ID.
+        Ok(Self::read_le(&decode_bech32m(solution_id, SOLUTION_ID_PREFIX)?[..])?)
     }
 }
 
@@ -48,8 +39,7 @@ impl<N: Network> Display for SolutionID<N> {
         // Convert the solution ID to bytes.
         let bytes = self.to_bytes_le().map_err(|_| fmt::Error)?;
         // Encode the bytes into bech32m.
-        let string =
-            bech32::encode(SOLUTION_ID_PREFIX, bytes.to_base32(), bech32::Variant::Bech32m).map_err(|_| fmt::Error)?;
+        let string = encode_bech32m(SOLUTION_ID_PREFIX, &bytes).map_err(|_| fmt::Error)?;
         // Output the string.
         Display::fmt(&string, f)
     }
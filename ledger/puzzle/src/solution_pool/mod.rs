@@ -0,0 +1,279 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{Puzzle, PuzzleSolutions, Solution, SolutionID};
+use console::{account::Address, network::prelude::*};
+use indexmap::IndexMap;
+
+/// A pool of candidate solutions for a single epoch.
+///
+/// The pool deduplicates solutions by ID, discards solutions below the minimum proof target,
+/// and retains at most `capacity` solutions, preferring those with the highest target.
+#[derive(Clone)]
+pub struct SolutionPool<N: Network> {
+    /// The epoch hash that solutions must be computed for.
+    epoch_hash: N::BlockHash,
+    /// The minimum proof target that solutions must meet.
+    minimum_proof_target: u64,
+    /// The (optional) prover address that solutions must be computed for.
+    prover: Option<Address<N>>,
+    /// The maximum number of solutions retained in the pool.
+    capacity: usize,
+    /// The solutions in the pool.
+    solutions: IndexMap<SolutionID<N>, Solution<N>>,
+}
+
+impl<N: Network> SolutionPool<N> {
+    /// Initializes a new solution pool for the given epoch hash and minimum proof target.
+    pub fn new(epoch_hash: N::BlockHash, minimum_proof_target: u64, capacity: usize) -> Result<Self> {
+        // Ensure the capacity is nonzero.
+        ensure!(capacity > 0, "The solution pool capacity must be nonzero");
+        // Return the solution pool.
+        Ok(Self { epoch_hash, minimum_proof_target, prover: None, capacity, solutions: Default::default() })
+    }
+
+    /// Restricts the pool to solutions computed for the given prover address.
+    pub fn with_prover(mut self, prover: Address<N>) -> Self {
+        self.prover = Some(prover);
+        self.solutions.retain(|_, solution| solution.address() == prover);
+        self
+    }
+
+    /// Returns the epoch hash of the pool.
+    pub const fn epoch_hash(&self) -> N::BlockHash {
+        self.epoch_hash
+    }
+
+    /// Returns the minimum proof target of the pool.
+    pub const fn minimum_proof_target(&self) -> u64 {
+        self.minimum_proof_target
+    }
+
+    /// Returns the prover address of the pool, if one is set.
+    pub const fn prover(&self) -> Option<Address<N>> {
+        self.prover
+    }
+
+    /// Returns the capacity of the pool.
+    pub const fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Returns the number of solutions in the pool.
+    pub fn len(&self) -> usize {
+        self.solutions.len()
+    }
+
+    /// Returns `true` if there are no solutions in the pool.
+    pub fn is_empty(&self) -> bool {
+        self.solutions.is_empty()
+    }
+
+    /// Returns `true` if the pool contains the given solution ID.
+    pub fn contains(&self, solution_id: &SolutionID<N>) -> bool {
+        self.solutions.contains_key(solution_id)
+    }
+
+    /// Returns the solution for the given solution ID, if it exists.
+    pub fn get(&self, solution_id: &SolutionID<N>) -> Option<&Solution<N>> {
+        self.solutions.get(solution_id)
+    }
+
+    /// Returns an iterator over the solutions in the pool.
+    pub fn iter(&self) -> impl '_ + Iterator<Item = &Solution<N>> {
+        self.solutions.values()
+    }
+
+    /// Returns the lowest target of the solutions in the pool, if any.
+    pub fn lowest_target(&self) -> Option<u64> {
+        self.solutions.values().map(Solution::target).min()
+    }
+
+    /// Returns `Ok(())` if the solution passes the inexpensive checks of the pool.
+    ///
+    /// This checks the epoch hash, the prover address, and the *claimed* target of the solution.
+    /// It does **not** recompute the proof target, which requires full verification via [`Puzzle::check_solution`].
+    pub fn pre_validate(&self, solution: &Solution<N>) -> Result<()> {
+        // Ensure the epoch hash matches.
+        if solution.epoch_hash() != self.epoch_hash {
+            bail!(
+                "Solution '{}' does not match the expected epoch hash (found '{}', expected '{}')",
+                solution.id(),
+                solution.epoch_hash(),
+                self.epoch_hash
+            )
+        }
+        // Ensure the prover address matches, if one is set.
+        if let Some(prover) = self.prover {
+            if solution.address() != prover {
+                bail!("Solution '{}' does not match the expected prover address '{prover}'", solution.id())
+            }
+        }
+        // Ensure the claimed target meets the minimum proof target.
+        if solution.target() < self.minimum_proof_target {
+            bail!(
+                "Solution '{}' does not meet the minimum proof target ({} < {})",
+                solution.id(),
+                solution.target(),
+                self.minimum_proof_target
+            )
+        }
+        Ok(())
+    }
+
+    /// Inserts the given solution into the pool, returning `true` if it was retained.
+    ///
+    /// Solutions that fail [`Self::pre_validate`] are rejected with an error. Duplicate solutions,
+    /// and solutions with a lower target than every solution in a full pool, are not retained.
+    pub fn insert(&mut self, solution: Solution<N>) -> Result<bool> {
+        // Perform the inexpensive checks.
+        self.pre_validate(&solution)?;
+        // Skip the solution, if it is already in the pool.
+        if self.solutions.contains_key(&solution.id()) {
+            return Ok(false);
+        }
+        // If the pool is full, evict the solution with the lowest target, if it is lower than the new solution.
+        if self.solutions.len() >= self.capacity {
+            let lowest = self
+                .solutions
+                .iter()
+                .min_by_key(|(_, candidate)| candidate.target())
+                .map(|(solution_id, candidate)| (*solution_id, candidate.target()));
+            match lowest {
+                Some((solution_id, target)) if target < solution.target() => {
+                    self.solutions.shift_remove(&solution_id);
+                }
+                _ => return Ok(false),
+            }
+        }
+        // Insert the solution.
+        self.solutions.insert(solution.id(), solution);
+        Ok(true)
+    }
+
+    /// Removes the solution with the given solution ID from the pool, returning it if it existed.
+    pub fn remove(&mut self, solution_id: &SolutionID<N>) -> Option<Solution<N>> {
+        self.solutions.shift_remove(solution_id)
+    }
+
+    /// Resets the pool for a new epoch hash and minimum proof target, discarding solutions that no longer apply.
+    pub fn advance(&mut self, epoch_hash: N::BlockHash, minimum_proof_target: u64) {
+        self.epoch_hash = epoch_hash;
+        self.minimum_proof_target = minimum_proof_target;
+        self.solutions
+            .retain(|_, solution| solution.epoch_hash() == epoch_hash && solution.target() >= minimum_proof_target);
+    }
+
+    /// Performs full verification of the solutions in the pool, removing any invalid solutions.
+    /// Returns the IDs of the removed solutions.
+    pub fn retain_valid(&mut self, puzzle: &Puzzle<N>) -> Vec<SolutionID<N>> {
+        // Determine the invalid solutions.
+        let invalid = self
+            .solutions
+            .iter()
+            .filter(|(_, solution)| {
+                puzzle.check_solution(solution, self.epoch_hash, self.minimum_proof_target).is_err()
+            })
+            .map(|(solution_id, _)| *solution_id)
+            .collect::<Vec<_>>();
+        // Remove the invalid solutions.
+        for solution_id in &invalid {
+            self.solutions.shift_remove(solution_id);
+        }
+        invalid
+    }
+
+    /// Returns up to `num_solutions` solutions from the pool, in descending order of target.
+    pub fn best(&self, num_solutions: usize) -> Vec<Solution<N>> {
+        let mut solutions = self.solutions.values().copied().collect::<Vec<_>>();
+        // Sort by descending target, breaking ties by solution ID insertion order.
+        solutions.sort_by(|a, b| b.target().cmp(&a.target()));
+        solutions.truncate(num_solutions);
+        solutions
+    }
+
+    /// Returns the best solutions from the pool, up to `PuzzleSolutions::MAX_SOLUTIONS`, as puzzle solutions.
+    /// Returns `None` if the pool is empty.
+    pub fn to_puzzle_solutions(&self) -> Result<Option<PuzzleSolutions<N>>> {
+        match self.is_empty() {
+            true => Ok(None),
+            false => PuzzleSolutions::new(self.best(PuzzleSolutions::<N>::MAX_SOLUTIONS)).map(Some),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::PartialSolution;
+    use console::{account::PrivateKey, network::MainnetV0};
+
+    type CurrentNetwork = MainnetV0;
+
+    /// Samples a solution with the given epoch hash, address, and target.
+    fn sample_solution(
+        epoch_hash: <CurrentNetwork as Network>::BlockHash,
+        address: Address<CurrentNetwork>,
+        target: u64,
+        rng: &mut TestRng,
+    ) -> Solution<CurrentNetwork> {
+        Solution::new(PartialSolution::new(epoch_hash, address, rng.gen()).unwrap(), target)
+    }
+
+    #[test]
+    fn test_solution_pool() {
+        let rng = &mut TestRng::default();
+
+        let epoch_hash = rng.gen();
+        let address = Address::try_from(PrivateKey::<CurrentNetwork>::new(rng).unwrap()).unwrap();
+        let mut pool = SolutionPool::<CurrentNetwork>::new(epoch_hash, 10, 3).unwrap().with_prover(address);
+
+        // Ensure solutions below the minimum proof target are rejected.
+        assert!(pool.insert(sample_solution(epoch_hash, address, 9, rng)).is_err());
+        // Ensure solutions for a different epoch hash are rejected.
+        assert!(pool.insert(sample_solution(rng.gen(), address, 20, rng)).is_err());
+        // Ensure solutions for a different prover are rejected.
+        let other = Address::try_from(PrivateKey::<CurrentNetwork>::new(rng).unwrap()).unwrap();
+        assert!(pool.insert(sample_solution(epoch_hash, other, 20, rng)).is_err());
+
+        // Ensure duplicate solutions are not retained.
+        let solution = sample_solution(epoch_hash, address, 15, rng);
+        assert!(pool.insert(solution).unwrap());
+        assert!(!pool.insert(solution).unwrap());
+        assert_eq!(pool.len(), 1);
+
+        // Fill the pool.
+        assert!(pool.insert(sample_solution(epoch_hash, address, 30, rng)).unwrap());
+        assert!(pool.insert(sample_solution(epoch_hash, address, 20, rng)).unwrap());
+        assert_eq!(pool.lowest_target(), Some(15));
+
+        // Ensure a lower solution is not retained in a full pool.
+        assert!(!pool.insert(sample_solution(epoch_hash, address, 12, rng)).unwrap());
+        // Ensure a higher solution evicts the lowest solution.
+        assert!(pool.insert(sample_solution(epoch_hash, address, 40, rng)).unwrap());
+        assert!(!pool.contains(&solution.id()));
+        assert_eq!(pool.len(), 3);
+
+        // Ensure the best solutions are in descending order of target.
+        let targets = pool.best(2).iter().map(Solution::target).collect::<Vec<_>>();
+        assert_eq!(targets, vec![40, 30]);
+        assert_eq!(pool.to_puzzle_solutions().unwrap().unwrap().len(), 3);
+
+        // Ensure advancing the epoch discards the stale solutions.
+        pool.advance(rng.gen(), 10);
+        assert!(pool.is_empty());
+        assert!(pool.to_puzzle_solutions().unwrap().is_none());
+    }
+}
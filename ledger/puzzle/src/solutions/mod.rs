@@ -18,7 +18,11 @@ mod serialize;
 mod string;
 
 use crate::{Solution, SolutionID};
-use console::{network::prelude::*, prelude::DeserializeExt, types::Field};
+use console::{
+    network::{prelude::*, BlockLimits},
+    prelude::DeserializeExt,
+    types::Field,
+};
 use indexmap::IndexMap;
 
 /// The individual solutions.
@@ -29,13 +33,17 @@ pub struct PuzzleSolutions<N: Network> {
 }
 
 impl<N: Network> PuzzleSolutions<N> {
+    /// The maximum number of solutions allowed in a block.
+    /// Note: This is the network limit, bounded by `N::MAX_SOLUTIONS`.
+    pub const MAX_SOLUTIONS: usize = BlockLimits::bounded(N::BLOCK_LIMITS.max_solutions, N::MAX_SOLUTIONS);
+
     /// Initializes a new instance of the solutions.
     pub fn new(solutions: Vec<Solution<N>>) -> Result<Self> {
         // Ensure the solutions are not empty.
         ensure!(!solutions.is_empty(), "There are no solutions to verify for the puzzle");
         // Ensure the number of solutions does not exceed `MAX_SOLUTIONS`.
-        if solutions.len() > N::MAX_SOLUTIONS {
-            bail!("Exceeded the maximum number of solutions ({} > {})", solutions.len(), N::MAX_SOLUTIONS);
+        if solutions.len() > Self::MAX_SOLUTIONS {
+            bail!("Exceeded the maximum number of solutions ({} > {})", solutions.len(), Self::MAX_SOLUTIONS);
         }
         // Ensure the solution IDs are unique.
         if has_duplicates(solutions.iter().map(Solution::id)) {
@@ -31,11 +31,19 @@ impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
         // Currently, we do not support ratifications from the memory pool.
         ensure!(ratifications.is_empty(), "Ratifications are currently unsupported from the memory pool");
         // Construct the block template.
-        let (header, ratifications, solutions, aborted_solution_ids, transactions, aborted_transaction_ids) =
+        let (
+            header,
+            ratifications,
+            solutions,
+            aborted_solution_ids,
+            transactions,
+            aborted_transaction_ids,
+            aborted_transaction_reasons,
+        ) =
             self.construct_block_template(&previous_block, Some(&subdag), ratifications, solutions, transactions, rng)?;
 
         // Construct the new quorum block.
-        Block::new_quorum(
+        Block::new_quorum_with_aborted_transaction_reasons(
             previous_block.hash(),
             header,
             subdag,
@@ -44,6 +52,7 @@ impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
             aborted_solution_ids,
             transactions,
             aborted_transaction_ids,
+            aborted_transaction_reasons,
         )
     }
 
@@ -63,15 +72,16 @@ impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
         let previous_block = self.latest_block();
 
         // Construct the block template.
-        let (header, ratifications, solutions, aborted_solution_ids, transactions, aborted_transaction_ids) = self
+        // Note: A beacon block does not record the reasons for its aborted transactions.
+        let (header, ratifications, solutions, aborted_solution_ids, transactions, aborted_transaction_ids, _) = self
             .construct_block_template(
-                &previous_block,
-                None,
-                candidate_ratifications,
-                candidate_solutions,
-                candidate_transactions,
-                rng,
-            )?;
+            &previous_block,
+            None,
+            candidate_ratifications,
+            candidate_solutions,
+            candidate_transactions,
+            rng,
+        )?;
 
         // Construct the new beacon block.
         Block::new_beacon(
@@ -95,6 +105,13 @@ impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
         self.vm.add_next_block(block)?;
         // Update the current block.
         *current_block = block.clone();
+        // Update the consensus versions signaled within the signaling window.
+        let mut signals = self.consensus_version_signals.write();
+        signals.push_back(block.consensus_version());
+        while signals.len() > CONSENSUS_VERSION_SIGNALING_WINDOW as usize {
+            signals.pop_front();
+        }
+        drop(signals);
         // Drop the write lock on the current block.
         drop(current_block);
 
@@ -104,7 +121,7 @@ impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
         }
 
         // If the block is the start of a new epoch, or the epoch hash has not been set, update the current epoch hash.
-        if block.height() % N::NUM_BLOCKS_PER_EPOCH == 0 || self.current_epoch_hash.read().is_none() {
+        if is_epoch_boundary::<N>(block.height()) || self.current_epoch_hash.read().is_none() {
             // Update and log the current epoch hash.
             match self.get_epoch_hash(block.height()).ok() {
                 Some(epoch_hash) => {
@@ -184,8 +201,15 @@ impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
         candidate_solutions: Vec<Solution<N>>,
         candidate_transactions: Vec<Transaction<N>>,
         rng: &mut R,
-    ) -> Result<(Header<N>, Ratifications<N>, Solutions<N>, Vec<SolutionID<N>>, Transactions<N>, Vec<N::TransactionID>)>
-    {
+    ) -> Result<(
+        Header<N>,
+        Ratifications<N>,
+        Solutions<N>,
+        Vec<SolutionID<N>>,
+        Transactions<N>,
+        Vec<N::TransactionID>,
+        Vec<AbortReason>,
+    )> {
         // Construct the solutions.
         let (solutions, aborted_solutions, solutions_root, combined_proof_target) = match candidate_solutions.is_empty()
         {
@@ -197,7 +221,7 @@ impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
                 let latest_proof_target = self.latest_proof_target();
                 // Separate the candidate solutions into valid and aborted solutions.
                 let (valid_candidate_solutions, aborted_candidate_solutions) =
-                    split_candidate_solutions(candidate_solutions, N::MAX_SOLUTIONS, |solution| {
+                    split_candidate_solutions(candidate_solutions, PuzzleSolutions::<N>::MAX_SOLUTIONS, |solution| {
                         self.puzzle().check_solution_mut(solution, latest_epoch_hash, latest_proof_target).is_ok()
                     });
 
@@ -307,14 +331,15 @@ impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
             previous_block.hash(),
         )?;
         // Speculate over the ratifications, solutions, and transactions.
-        let (ratifications, transactions, aborted_transaction_ids, ratified_finalize_operations) = self.vm.speculate(
-            state,
-            Some(coinbase_reward),
-            candidate_ratifications,
-            &solutions,
-            candidate_transactions.iter(),
-            rng,
-        )?;
+        let (ratifications, transactions, aborted_transactions, ratified_finalize_operations) =
+            self.vm.speculate_with_abort_reasons(
+                state,
+                Some(coinbase_reward),
+                candidate_ratifications,
+                &solutions,
+                candidate_transactions.iter(),
+                rng,
+            )?;
 
         // Compute the ratifications root.
         let ratifications_root = ratifications.to_ratifications_root()?;
@@ -326,7 +351,7 @@ impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
         };
 
         // Construct the metadata.
-        let metadata = Metadata::new(
+        let metadata = Metadata::new_with_consensus_version(
             N::ID,
             next_round,
             next_height,
@@ -337,8 +362,19 @@ impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
             next_last_coinbase_target,
             next_last_coinbase_timestamp,
             next_timestamp,
+            self.next_signaled_consensus_version(),
         )?;
 
+        // Separate the aborted transaction IDs and their reasons.
+        let (aborted_transaction_ids, mut aborted_transaction_reasons): (Vec<_>, Vec<_>) =
+            aborted_transactions.into_iter().unzip();
+        // Record the aborted transaction reasons only in a quorum block, once they are active.
+        // Note: A beacon block does not commit to the order of its candidate transactions,
+        // which is required to check the reasons.
+        if subdag.is_none() || !self.records_aborted_transaction_reasons(next_height)? {
+            aborted_transaction_reasons.clear();
+        }
+
         // Construct the header.
         let header = Header::from(
             latest_state_root,
@@ -351,6 +387,14 @@ impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
         )?;
 
         // Return the block template.
-        Ok((header, ratifications, solutions, aborted_solution_ids, transactions, aborted_transaction_ids))
+        Ok((
+            header,
+            ratifications,
+            solutions,
+            aborted_solution_ids,
+            transactions,
+            aborted_transaction_ids,
+            aborted_transaction_reasons,
+        ))
     }
 }
@@ -15,9 +15,98 @@
 
 use super::*;
 
+use rand::{rngs::StdRng, SeedableRng};
+
 impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
     /// Checks the given block is valid next block.
     pub fn check_next_block<R: CryptoRng + Rng>(&self, block: &Block<N>, rng: &mut R) -> Result<()> {
+        self.check_next_block_with_options(block, &BlockVerificationOptions::default(), rng)
+    }
+
+    /// Checks the given block is valid next block, skipping the stages in the given options.
+    ///
+    /// The block is checked in the stages of `BlockVerificationStage`. The authority and transactions stages
+    /// are independent, and are performed in parallel, before speculating over the transactions.
+    pub fn check_next_block_with_options<R: CryptoRng + Rng>(
+        &self,
+        block: &Block<N>,
+        options: &BlockVerificationOptions<N>,
+        rng: &mut R,
+    ) -> Result<()> {
+        let timer = timer!("Ledger::check_next_block");
+
+        // Check the block header, and retrieve the committee lookbacks.
+        let (previous_committee_lookback, committee_lookback) = self.check_next_block_header(block)?;
+        lap!(timer, "Check the block header");
+
+        // Check the block authority and the transactions in parallel.
+        let check_authority =
+            || self.check_next_block_authority(block, &previous_committee_lookback, &committee_lookback);
+        let transactions_seed = rng.gen();
+        let check_transactions = || match options.is_skipped(BlockVerificationStage::Transactions) {
+            true => Ok(()),
+            false => self.vm.check_confirmed_transactions(
+                block.transactions(),
+                options.verified_transactions(),
+                &mut StdRng::from_seed(transactions_seed),
+            ),
+        };
+        #[cfg(not(feature = "serial"))]
+        let (authority_result, transactions_result) = rayon::join(check_authority, check_transactions);
+        #[cfg(feature = "serial")]
+        let (authority_result, transactions_result) = (check_authority(), check_transactions());
+        transactions_result?;
+        authority_result?;
+        lap!(timer, "Check the block authority and transactions");
+
+        // Construct the finalize state.
+        let state = FinalizeGlobalState::new::<N>(
+            block.round(),
+            block.height(),
+            block.cumulative_weight(),
+            block.cumulative_proof_target(),
+            block.previous_hash(),
+        )?;
+        // Reconstruct the candidate transactions to check the aborted transaction reasons, if the aborted transactions
+        // are given and the stage is not skipped.
+        // Note: The reasons are not committed to by the block hash, so they are only checked when they can be.
+        let candidate_transactions = match block.aborted_transaction_reasons().is_empty()
+            || options.aborted_transactions().is_empty()
+            || options.is_skipped(BlockVerificationStage::AbortReasons)
+        {
+            true => None,
+            false => Some(self.candidate_transactions(block, options.aborted_transactions())?),
+        };
+        let candidate_transactions = candidate_transactions.as_ref().map(|c| c.iter().collect::<Vec<_>>());
+        let aborted_transaction_reasons = match candidate_transactions {
+            Some(_) => block
+                .aborted_transaction_ids()
+                .iter()
+                .copied()
+                .zip(block.aborted_transaction_reasons().iter().copied())
+                .collect(),
+            None => vec![],
+        };
+        // Ensure speculation over the unconfirmed transactions is correct.
+        let ratified_finalize_operations = self.vm.check_speculate_without_verification(
+            state,
+            block.ratifications(),
+            block.solutions(),
+            block.transactions(),
+            candidate_transactions.as_deref(),
+            &aborted_transaction_reasons,
+            rng,
+        )?;
+        // Ensure the finalize root is correct.
+        block.verify_finalize_root(ratified_finalize_operations)?;
+
+        finish!(timer, "Check the speculation");
+        Ok(())
+    }
+
+    /// Checks the header of the given block, i.e. that it is unique, and signals the active consensus version.
+    /// Returns the previous committee lookback and the committee lookback of the block.
+    fn check_next_block_header(&self, block: &Block<N>) -> Result<(Committee<N>, Committee<N>)> {
         let height = block.height();
 
         // Ensure the block hash does not already exist.
@@ -60,18 +149,17 @@ impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
             // }
         }
 
-        // Construct the finalize state.
-        let state = FinalizeGlobalState::new::<N>(
-            block.round(),
-            block.height(),
-            block.cumulative_weight(),
-            block.cumulative_proof_target(),
-            block.previous_hash(),
-        )?;
-
-        // Ensure speculation over the unconfirmed transactions is correct and ensure each transaction is well-formed and unique.
-        let ratified_finalize_operations =
-            self.vm.check_speculate(state, block.ratifications(), block.solutions(), block.transactions(), rng)?;
+        // Ensure the aborted transaction reasons are consistent with the aborted transaction IDs.
+        block.check_aborted_transaction_reasons()?;
+        // Ensure the aborted transaction reasons are only recorded by a quorum block,
+        // once the consensus version that records them is active.
+        if !block.aborted_transaction_reasons().is_empty() {
+            ensure!(
+                matches!(block.authority(), Authority::Quorum(_))
+                    && self.records_aborted_transaction_reasons(height)?,
+                "Block {height} can not record the reasons for its aborted transactions"
+            );
+        }
 
         // Retrieve the committee lookback.
         let committee_lookback = {
@@ -106,21 +194,45 @@ impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
                 .ok_or(anyhow!("Failed to fetch committee for round {penultimate_committee_lookback_round}"))?
         };
 
+        // Ensure the block signals at least the active consensus version, and at most the latest consensus version.
+        block.header().metadata().check_consensus_version(self.get_active_consensus_version(height)?)?;
+        // Ensure the batches in the block subdag only have a priority lane, once priority lanes are active.
+        if let Authority::Quorum(subdag) = block.authority() {
+            if !self.supports_priority_lanes(height)? {
+                ensure!(
+                    subdag
+                        .values()
+                        .flatten()
+                        .all(|certificate| certificate.batch_header().num_priority_transmissions() == 0),
+                    "Block {height} contains a batch with a priority lane, before priority lanes are active"
+                );
+            }
+        }
+
+        // Determine if the block subdag is correctly constructed and is not a combination of multiple subdags.
+        self.check_block_subdag_atomicity(block)?;
+
+        Ok((previous_committee_lookback, committee_lookback))
+    }
+
+    /// Checks the authority, solutions, ratifications, and header of the given block, except for the finalize root.
+    fn check_next_block_authority(
+        &self,
+        block: &Block<N>,
+        previous_committee_lookback: &Committee<N>,
+        committee_lookback: &Committee<N>,
+    ) -> Result<()> {
         // Ensure the block is correct.
-        let (expected_existing_solution_ids, expected_existing_transaction_ids) = block.verify(
+        let (expected_existing_solution_ids, expected_existing_transaction_ids) = block.verify_without_finalize_root(
             &self.latest_block(),
             self.latest_state_root(),
-            &previous_committee_lookback,
-            &committee_lookback,
+            previous_committee_lookback,
+            committee_lookback,
             self.puzzle(),
             self.latest_epoch_hash()?,
             OffsetDateTime::now_utc().unix_timestamp(),
-            ratified_finalize_operations,
         )?;
 
-        // Determine if the block subdag is correctly constructed and is not a combination of multiple subdags.
-        self.check_block_subdag_atomicity(block)?;
-
         // Ensure that each existing solution ID from the block exists in the ledger.
         for existing_solution_id in expected_existing_solution_ids {
             if !self.contains_solution_id(&existing_solution_id)? {
@@ -138,6 +250,55 @@ impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
         Ok(())
     }
 
+    /// Returns the candidate transactions of the given quorum block, i.e. its confirmed and aborted transactions,
+    /// in the order of the transmissions in its subdag, which is the order they were speculated on.
+    fn candidate_transactions(
+        &self,
+        block: &Block<N>,
+        aborted_transactions: &[Transaction<N>],
+    ) -> Result<Vec<Transaction<N>>> {
+        // Retrieve the subdag.
+        let Authority::Quorum(subdag) = block.authority() else {
+            bail!("Block {} is not a quorum block, so its candidate transactions are unordered", block.height())
+        };
+        // Retrieve the unconfirmed transactions.
+        let mut unconfirmed_transactions = block
+            .transactions()
+            .iter()
+            .map(|confirmed| confirmed.to_unconfirmed_transaction().map(|transaction| (transaction.id(), transaction)))
+            .collect::<Result<IndexMap<_, _>>>()?;
+        // Retrieve the aborted transactions.
+        let mut aborted_transactions = aborted_transactions
+            .iter()
+            .filter(|transaction| block.aborted_transaction_ids().contains(&transaction.id()))
+            .map(|transaction| (transaction.id(), transaction))
+            .collect::<IndexMap<_, _>>();
+        // Ensure every aborted transaction is given.
+        ensure!(
+            aborted_transactions.len() == block.aborted_transaction_ids().len(),
+            "The aborted transactions of block {} are required to check the reasons they aborted",
+            block.height()
+        );
+
+        // Order the transactions by their transmission IDs.
+        // Note: A transaction that is neither confirmed nor aborted already exists in the ledger,
+        // and is not a candidate.
+        let mut candidate_transactions =
+            Vec::with_capacity(unconfirmed_transactions.len() + aborted_transactions.len());
+        for transmission_id in subdag.transmission_ids() {
+            if let TransmissionID::Transaction(transaction_id, _) = transmission_id {
+                if let Some(transaction) = unconfirmed_transactions.swap_remove(transaction_id) {
+                    candidate_transactions.push(transaction);
+                } else if let Some(transaction) = aborted_transactions.swap_remove(transaction_id) {
+                    candidate_transactions.push(transaction.clone());
+                }
+            }
+        }
+        // Ensure every confirmed transaction is ordered.
+        ensure!(unconfirmed_transactions.is_empty(), "Block {} has transactions outside of its subdag", block.height());
+        Ok(candidate_transactions)
+    }
+
     /// Checks that the block subdag can not be split into multiple valid subdags.
     fn check_block_subdag_atomicity(&self, block: &Block<N>) -> Result<()> {
         // Returns `true` if there is a path from the previous certificate to the current certificate.
@@ -0,0 +1,164 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Fuzz targets for the parsers and deserializers that accept network input, enabled by the `fuzz` feature.
+//!
+//! Each target accepts arbitrary bytes, and panics only if a successfully-parsed value does not round-trip.
+//! The `cargo-fuzz` harness in `ledger/fuzz` wraps each target, and seeds its corpus with `write_seed_corpus`.
+
+use super::*;
+use console::account::Signature;
+use synthesizer::vm::test_fixtures::TestFixtures;
+
+use std::{fs, path::Path};
+
+/// The names of the fuzz targets, which are also the names of the corpus directories.
+pub const FUZZ_TARGETS: [&str; 5] =
+    ["program_from_str", "transaction_read_le", "block_read_le", "plaintext_from_str", "bech32"];
+
+/// Fuzzes `Program::from_str`.
+pub fn fuzz_program_from_str<N: Network>(data: &[u8]) {
+    let Ok(string) = core::str::from_utf8(data) else { return };
+    if let Ok(program) = Program::<N>::from_str(string) {
+        assert_eq!(Program::<N>::from_str(&program.to_string()).unwrap(), program);
+    }
+}
+
+/// Fuzzes `Transaction::read_le`.
+pub fn fuzz_transaction_read_le<N: Network>(data: &[u8]) {
+    if let Ok(transaction) = Transaction::<N>::read_le(data) {
+        assert_eq!(Transaction::<N>::read_le(&transaction.to_bytes_le().unwrap()[..]).unwrap(), transaction);
+    }
+}
+
+/// Fuzzes `Block::read_le`.
+pub fn fuzz_block_read_le<N: Network>(data: &[u8]) {
+    if let Ok(block) = Block::<N>::read_le(data) {
+        assert_eq!(Block::<N>::read_le(&block.to_bytes_le().unwrap()[..]).unwrap(), block);
+    }
+}
+
+/// Fuzzes `Plaintext::from_str`.
+pub fn fuzz_plaintext_from_str<N: Network>(data: &[u8]) {
+    let Ok(string) = core::str::from_utf8(data) else { return };
+    if let Ok(plaintext) = Plaintext::<N>::from_str(string) {
+        assert_eq!(Plaintext::<N>::from_str(&plaintext.to_string()).unwrap(), plaintext);
+    }
+}
+
+/// Fuzzes the bech32 decoders of the address, signature, and ID types.
+pub fn fuzz_bech32<N: Network>(data: &[u8]) {
+    /// Ensures the given type round-trips, if the string decodes successfully.
+    fn roundtrip<T: FromStr + Display + PartialEq + Debug>(string: &str) {
+        if let Ok(value) = T::from_str(string) {
+            assert_eq!(T::from_str(&value.to_string()).ok(), Some(value));
+        }
+    }
+
+    let Ok(string) = core::str::from_utf8(data) else { return };
+    roundtrip::<Address<N>>(string);
+    roundtrip::<Signature<N>>(string);
+    roundtrip::<N::BlockHash>(string);
+    roundtrip::<N::TransactionID>(string);
+    roundtrip::<N::TransitionID>(string);
+    roundtrip::<SolutionID<N>>(string);
+}
+
+/// Writes the seed corpus of each fuzz target to `directory/<target>`, from the sampled test fixtures.
+pub fn write_seed_corpus<N: Network>(directory: &Path) -> Result<()> {
+    let fixtures = TestFixtures::<N>::sample()?;
+    let transactions = [
+        ("deployment", fixtures.deployment_transaction()?),
+        ("execution", fixtures.execution_transaction_without_fee()?),
+        ("execution_with_private_fee", fixtures.execution_transaction_with_private_fee()?),
+        ("execution_with_public_fee", fixtures.execution_transaction_with_public_fee()?),
+    ];
+    let genesis_block = fixtures.genesis_block();
+    let address = Address::try_from(fixtures.private_key())?;
+
+    // Writes the given seed to the corpus of the given target.
+    let write = |target: &str, name: &str, seed: &[u8]| -> Result<()> {
+        let path = directory.join(target);
+        fs::create_dir_all(&path)?;
+        fs::write(path.join(name), seed)?;
+        Ok(())
+    };
+
+    // Seed the programs.
+    write("program_from_str", "sample", fixtures.program()?.to_string().as_bytes())?;
+    write("program_from_str", "credits", Program::<N>::credits()?.to_string().as_bytes())?;
+    // Seed the transactions.
+    for (name, transaction) in &transactions {
+        write("transaction_read_le", name, &transaction.to_bytes_le()?)?;
+    }
+    // Seed the blocks.
+    write("block_read_le", "genesis", &genesis_block.to_bytes_le()?)?;
+    // Seed the plaintexts, from the decrypted genesis record.
+    let record = fixtures.genesis_record()?;
+    write("plaintext_from_str", "address", address.to_string().as_bytes())?;
+    for (name, entry) in record.data() {
+        let plaintext = match entry {
+            Entry::Constant(plaintext) | Entry::Public(plaintext) | Entry::Private(plaintext) => plaintext,
+        };
+        write("plaintext_from_str", &name.to_string(), plaintext.to_string().as_bytes())?;
+    }
+    let plaintext = format!("{{ owner: {address}, amount: 1u64, inner: {{ flag: true, values: [1field, 2field] }} }}");
+    write("plaintext_from_str", "struct", plaintext.as_bytes())?;
+    // Seed the bech32 strings.
+    write("bech32", "address", address.to_string().as_bytes())?;
+    write("bech32", "block_hash", genesis_block.hash().to_string().as_bytes())?;
+    for (name, transaction) in &transactions {
+        write("bech32", &format!("transaction_id_{name}"), transaction.id().to_string().as_bytes())?;
+    }
+    for transition in genesis_block.transitions() {
+        write("bech32", &format!("transition_id_{}", transition.id()), transition.id().to_string().as_bytes())?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::MainnetV0;
+
+    type CurrentNetwork = MainnetV0;
+
+    #[test]
+    fn test_fuzz_targets_on_seed_corpus() -> Result<()> {
+        let directory = std::env::temp_dir().join("snarkvm_fuzz_seed_corpus");
+        write_seed_corpus::<CurrentNetwork>(&directory)?;
+
+        // Ensure each target accepts its seeds, and arbitrary bytes.
+        for target in FUZZ_TARGETS {
+            let fuzz = match target {
+                "program_from_str" => fuzz_program_from_str::<CurrentNetwork>,
+                "transaction_read_le" => fuzz_transaction_read_le::<CurrentNetwork>,
+                "block_read_le" => fuzz_block_read_le::<CurrentNetwork>,
+                "plaintext_from_str" => fuzz_plaintext_from_str::<CurrentNetwork>,
+                "bech32" => fuzz_bech32::<CurrentNetwork>,
+                _ => unreachable!(),
+            };
+            let seeds = fs::read_dir(directory.join(target))?;
+            assert!(seeds.count() > 0);
+            for seed in fs::read_dir(directory.join(target))? {
+                fuzz(&fs::read(seed?.path())?);
+            }
+            fuzz(&[]);
+            fuzz(&[0xff; 64]);
+        }
+        fs::remove_dir_all(directory)?;
+        Ok(())
+    }
+}
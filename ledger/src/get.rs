@@ -52,18 +52,53 @@ impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
         self.vm.block_store().get_state_path_for_commitment(commitment)
     }
 
+    /// Returns a receipt proving the inclusion of the given transaction ID on-chain.
+    pub fn get_receipt(&self, transaction_id: &N::TransactionID) -> Result<Receipt<N>> {
+        self.vm.block_store().get_receipt(transaction_id)
+    }
+
+    /// Returns a receipt batch proving the inclusion of the given transaction IDs on-chain.
+    pub fn get_receipt_batch(&self, transaction_ids: &[N::TransactionID]) -> Result<ReceiptBatch<N>> {
+        self.vm.block_store().get_receipt_batch(transaction_ids)
+    }
+
     /// Returns the epoch hash for the given block height.
     pub fn get_epoch_hash(&self, block_height: u32) -> Result<N::BlockHash> {
-        // Compute the epoch number from the current block height.
-        let epoch_number = block_height.saturating_div(N::NUM_BLOCKS_PER_EPOCH);
         // Compute the epoch starting height (a multiple of `NUM_BLOCKS_PER_EPOCH`).
-        let epoch_starting_height = epoch_number.saturating_mul(N::NUM_BLOCKS_PER_EPOCH);
+        let epoch_starting_height = epoch_starting_height::<N>(block_height);
         // Retrieve the epoch hash, defined as the 'previous block hash' from the epoch starting height.
         let epoch_hash = self.get_previous_hash(epoch_starting_height)?;
         // Construct the epoch hash.
         Ok(epoch_hash)
     }
 
+    /// Returns the epoch hash that solutions must use, in order to be included in the block at the given height.
+    pub fn get_solution_epoch_hash(&self, block_height: u32) -> Result<N::BlockHash> {
+        self.get_epoch_hash(solution_epoch_height(block_height))
+    }
+
+    /// Returns the proof target that solutions must meet, in order to be included in the block at the given height.
+    pub fn get_solution_proof_target(&self, block_height: u32) -> Result<u64> {
+        Ok(self.get_header(solution_epoch_height(block_height))?.proof_target())
+    }
+
+    /// Returns `Ok(())` if the given solution is valid for inclusion in the block at the given height.
+    /// This checks the solution against the epoch hash and the proof target for the given height.
+    pub fn check_solution_for_height(&self, solution: &Solution<N>, block_height: u32) -> Result<()> {
+        // Ensure the block height is at most the next block height.
+        let next_height = self.latest_height().saturating_add(1);
+        ensure!(
+            block_height <= next_height,
+            "Cannot check a solution for block {block_height}, as the next block is {next_height}"
+        );
+        // Retrieve the expected epoch hash.
+        let expected_epoch_hash = self.get_solution_epoch_hash(block_height)?;
+        // Retrieve the expected proof target.
+        let expected_proof_target = self.get_solution_proof_target(block_height)?;
+        // Check the solution.
+        self.puzzle().check_solution(solution, expected_epoch_hash, expected_proof_target)
+    }
+
     /// Returns the block for the given block height.
     pub fn get_block(&self, height: u32) -> Result<Block<N>> {
         // If the height is 0, return the genesis block.
@@ -147,6 +182,35 @@ impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
         }
     }
 
+    /// Returns the active consensus version for the block at the given block height,
+    /// as determined by the consensus versions signaled in the preceding blocks.
+    pub fn get_active_consensus_version(&self, height: u32) -> Result<u16> {
+        // If the block is the next block, return the active consensus version from the signaling window.
+        if height == self.latest_height().saturating_add(1) {
+            return Ok(self.latest_active_consensus_version());
+        }
+        // Determine the starting height of the signaling window.
+        let start_height = height.saturating_sub(CONSENSUS_VERSION_SIGNALING_WINDOW);
+        // Retrieve the consensus versions signaled within the signaling window.
+        let signals = (start_height..height)
+            .map(|height| self.get_header(height).map(|header| header.consensus_version()))
+            .collect::<Result<Vec<_>>>()?;
+        // Compute the active consensus version.
+        Ok(active_consensus_version(&signals))
+    }
+
+    /// Returns `true` if a quorum block at the given block height records the reasons for its aborted transactions,
+    /// i.e. if the consensus version that records them is active.
+    pub fn records_aborted_transaction_reasons(&self, height: u32) -> Result<bool> {
+        Ok(self.get_active_consensus_version(height)? >= ABORTED_TRANSACTION_REASONS_CONSENSUS_VERSION)
+    }
+
+    /// Returns `true` if the batches of a block at the given block height may have a priority lane,
+    /// i.e. if the consensus version that supports them is active.
+    pub fn supports_priority_lanes(&self, height: u32) -> Result<bool> {
+        Ok(self.get_active_consensus_version(height)? >= PRIORITY_LANES_CONSENSUS_VERSION)
+    }
+
     /// Returns the block transactions for the given block height.
     pub fn get_transactions(&self, height: u32) -> Result<Transactions<N>> {
         // If the height is 0, return the genesis block transactions.
@@ -0,0 +1,134 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use console::network::Network;
+use ledger_block::Transaction;
+use synthesizer::vm::VerifiedTransactions;
+
+use anyhow::{ensure, Result};
+use indexmap::IndexSet;
+
+/// The stages of `Ledger::check_next_block`, in the order they are performed.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum BlockVerificationStage {
+    /// Checks the block is unique and signals the active consensus version, and resolves its committees.
+    Header,
+    /// Verifies the block authority, solutions, ratifications, and header, except for the finalize root.
+    Authority,
+    /// Verifies each transaction is well-formed and unique.
+    Transactions,
+    /// Speculates over the transactions, and ensures the block matches the outcome, including its finalize root.
+    Speculation,
+    /// Speculates over the aborted transactions with the transactions, and ensures the reasons recorded
+    /// in the block match the outcome. This stage only runs if the aborted transactions of the block are given,
+    /// as the reasons are not committed to by the block hash.
+    AbortReasons,
+}
+
+impl BlockVerificationStage {
+    /// Returns `true` if the stage may be skipped, because it may already be performed by consensus.
+    /// Note: The remaining stages determine the validity of the block, and can not be skipped.
+    pub const fn is_skippable(&self) -> bool {
+        matches!(self, Self::Transactions | Self::AbortReasons)
+    }
+}
+
+/// The options for `Ledger::check_next_block_with_options`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BlockVerificationOptions<N: Network> {
+    /// The stages to skip.
+    skipped_stages: IndexSet<BlockVerificationStage>,
+    /// The transactions that consensus has already fully verified.
+    verified_transactions: VerifiedTransactions<N>,
+    /// The aborted transactions of the block, to check the reasons they aborted.
+    aborted_transactions: Vec<Transaction<N>>,
+}
+
+impl<N: Network> Default for BlockVerificationOptions<N> {
+    /// Returns the options to perform every stage, and to verify each transaction in full.
+    fn default() -> Self {
+        Self {
+            skipped_stages: Default::default(),
+            verified_transactions: Default::default(),
+            aborted_transactions: Default::default(),
+        }
+    }
+}
+
+impl<N: Network> BlockVerificationOptions<N> {
+    /// Skips the given stage, if it is skippable.
+    /// For example, the transactions stage may be skipped if each transaction was verified in the mempool,
+    /// and the abort reasons stage may be skipped if the aborted transactions of the block are unavailable.
+    pub fn skip(mut self, stage: BlockVerificationStage) -> Result<Self> {
+        ensure!(stage.is_skippable(), "The block verification stage '{stage:?}' can not be skipped");
+        self.skipped_stages.insert(stage);
+        Ok(self)
+    }
+
+    /// Returns the options with the given transactions, that consensus has already fully verified.
+    /// Under the policy of `VerificationPolicy::TrustVerified`, their proofs are not verified again
+    /// in the transactions stage.
+    pub fn with_verified_transactions(mut self, verified_transactions: VerifiedTransactions<N>) -> Self {
+        self.verified_transactions = verified_transactions;
+        self
+    }
+
+    /// Returns the options with the aborted transactions of the block, e.g. from the transmissions of its subdag.
+    /// If given, the reasons recorded for them are checked, unless the abort reasons stage is skipped.
+    pub fn with_aborted_transactions(mut self, aborted_transactions: Vec<Transaction<N>>) -> Self {
+        self.aborted_transactions = aborted_transactions;
+        self
+    }
+
+    /// Returns `true` if the given stage is skipped.
+    pub fn is_skipped(&self, stage: BlockVerificationStage) -> bool {
+        self.skipped_stages.contains(&stage)
+    }
+
+    /// Returns the transactions that consensus has already fully verified.
+    pub const fn verified_transactions(&self) -> &VerifiedTransactions<N> {
+        &self.verified_transactions
+    }
+
+    /// Returns the aborted transactions of the block.
+    pub fn aborted_transactions(&self) -> &[Transaction<N>] {
+        &self.aborted_transactions
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::MainnetV0;
+
+    type CurrentNetwork = MainnetV0;
+
+    #[test]
+    fn test_skip_stages() {
+        let options = BlockVerificationOptions::<CurrentNetwork>::default();
+        assert!(!options.is_skipped(BlockVerificationStage::Transactions));
+
+        // Ensure only the transactions and abort reasons stages can be skipped.
+        let options = options.skip(BlockVerificationStage::Transactions).unwrap();
+        assert!(options.is_skipped(BlockVerificationStage::Transactions));
+        let options = options.skip(BlockVerificationStage::AbortReasons).unwrap();
+        assert!(options.is_skipped(BlockVerificationStage::AbortReasons));
+        for stage in
+            [BlockVerificationStage::Header, BlockVerificationStage::Authority, BlockVerificationStage::Speculation]
+        {
+            assert!(options.clone().skip(stage).is_err());
+            assert!(!options.is_skipped(stage));
+        }
+    }
+}
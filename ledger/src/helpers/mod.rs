@@ -16,5 +16,11 @@
 mod bft;
 pub use bft::*;
 
+mod block_verification;
+pub use block_verification::*;
+
 mod supply;
 pub use supply::*;
+
+mod unbonding;
+pub use unbonding::*;
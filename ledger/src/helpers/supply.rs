@@ -18,6 +18,83 @@ use ledger_block::Transactions;
 
 use anyhow::{anyhow, Result};
 
+/// The supply and emission statistics of the ledger, in microcredits.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct SupplyStatistics {
+    /// The block height at which the statistics were computed.
+    block_height: u32,
+    /// The total supply.
+    total_supply: u64,
+    /// The total amount bonded to validators, including delegated amounts.
+    total_bonded: u64,
+    /// The total amount that is currently unbonding.
+    total_unbonding: u64,
+    /// The cumulative block rewards since genesis.
+    cumulative_block_rewards: u64,
+    /// The cumulative puzzle rewards since genesis.
+    cumulative_puzzle_rewards: u64,
+    /// The cumulative transaction fees burned since genesis.
+    cumulative_fees: u64,
+}
+
+impl SupplyStatistics {
+    /// Initializes new supply statistics.
+    pub const fn new(
+        block_height: u32,
+        total_supply: u64,
+        total_bonded: u64,
+        total_unbonding: u64,
+        cumulative_block_rewards: u64,
+        cumulative_puzzle_rewards: u64,
+        cumulative_fees: u64,
+    ) -> Self {
+        Self {
+            block_height,
+            total_supply,
+            total_bonded,
+            total_unbonding,
+            cumulative_block_rewards,
+            cumulative_puzzle_rewards,
+            cumulative_fees,
+        }
+    }
+
+    /// Returns the block height at which the statistics were computed.
+    pub const fn block_height(&self) -> u32 {
+        self.block_height
+    }
+
+    /// Returns the total supply.
+    pub const fn total_supply(&self) -> u64 {
+        self.total_supply
+    }
+
+    /// Returns the total amount bonded to validators, including delegated amounts.
+    pub const fn total_bonded(&self) -> u64 {
+        self.total_bonded
+    }
+
+    /// Returns the total amount that is currently unbonding.
+    pub const fn total_unbonding(&self) -> u64 {
+        self.total_unbonding
+    }
+
+    /// Returns the cumulative block rewards since genesis.
+    pub const fn cumulative_block_rewards(&self) -> u64 {
+        self.cumulative_block_rewards
+    }
+
+    /// Returns the cumulative puzzle rewards since genesis.
+    pub const fn cumulative_puzzle_rewards(&self) -> u64 {
+        self.cumulative_puzzle_rewards
+    }
+
+    /// Returns the cumulative transaction fees burned since genesis.
+    pub const fn cumulative_fees(&self) -> u64 {
+        self.cumulative_fees
+    }
+}
+
 /// Returns the next total supply in microcredits, given the starting total supply and newly-confirmed transactions.
 pub fn update_total_supply<N: Network>(
     starting_total_supply_in_microcredits: u64,
@@ -0,0 +1,134 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use console::{network::Network, types::Address};
+
+use indexmap::IndexMap;
+
+/// The state of an unbonding staker in the `credits.aleo` `unbonding` mapping.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct UnbondingState {
+    /// The amount of microcredits that is unbonding.
+    microcredits: u64,
+    /// The block height at which the microcredits can be claimed.
+    unlock_height: u32,
+}
+
+impl UnbondingState {
+    /// Initializes a new unbonding state.
+    pub const fn new(microcredits: u64, unlock_height: u32) -> Self {
+        Self { microcredits, unlock_height }
+    }
+
+    /// Returns the amount of microcredits that is unbonding.
+    pub const fn microcredits(&self) -> u64 {
+        self.microcredits
+    }
+
+    /// Returns the block height at which the microcredits can be claimed.
+    pub const fn unlock_height(&self) -> u32 {
+        self.unlock_height
+    }
+
+    /// Returns `true` if a `claim_unbond_public` in a block at the given height would succeed.
+    pub const fn is_claimable_at(&self, block_height: u32) -> bool {
+        block_height >= self.unlock_height
+    }
+}
+
+/// The stakers that withdraw to a given address, and their unbonding states.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct WithdrawalSchedule<N: Network> {
+    /// The withdrawal address.
+    withdrawal_address: Address<N>,
+    /// The block height at which the schedule was computed.
+    block_height: u32,
+    /// The stakers that withdraw to the address, with their unbonding state, if they are unbonding.
+    stakers: IndexMap<Address<N>, Option<UnbondingState>>,
+}
+
+impl<N: Network> WithdrawalSchedule<N> {
+    /// Initializes a new withdrawal schedule.
+    pub const fn new(
+        withdrawal_address: Address<N>,
+        block_height: u32,
+        stakers: IndexMap<Address<N>, Option<UnbondingState>>,
+    ) -> Self {
+        Self { withdrawal_address, block_height, stakers }
+    }
+
+    /// Returns the withdrawal address.
+    pub const fn withdrawal_address(&self) -> &Address<N> {
+        &self.withdrawal_address
+    }
+
+    /// Returns the block height at which the schedule was computed.
+    pub const fn block_height(&self) -> u32 {
+        self.block_height
+    }
+
+    /// Returns the stakers that withdraw to the address, with their unbonding state, if they are unbonding.
+    pub const fn stakers(&self) -> &IndexMap<Address<N>, Option<UnbondingState>> {
+        &self.stakers
+    }
+
+    /// Returns the unbonding stakers, and their unbonding states.
+    pub fn unbonding(&self) -> impl '_ + Iterator<Item = (&Address<N>, &UnbondingState)> {
+        self.stakers.iter().filter_map(|(staker, state)| state.as_ref().map(|state| (staker, state)))
+    }
+
+    /// Returns the unbonding stakers that can be claimed in the next block, and their unbonding states.
+    pub fn claimable(&self) -> impl '_ + Iterator<Item = (&Address<N>, &UnbondingState)> {
+        let next_height = self.block_height.saturating_add(1);
+        self.unbonding().filter(move |(_, state)| state.is_claimable_at(next_height))
+    }
+
+    /// Returns the total amount of microcredits that is unbonding to the address.
+    pub fn total_unbonding(&self) -> u64 {
+        self.unbonding().fold(0u64, |total, (_, state)| total.saturating_add(state.microcredits()))
+    }
+
+    /// Returns the total amount of microcredits that can be claimed to the address in the next block.
+    pub fn total_claimable(&self) -> u64 {
+        self.claimable().fold(0u64, |total, (_, state)| total.saturating_add(state.microcredits()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::{network::MainnetV0, prelude::*};
+
+    type CurrentNetwork = MainnetV0;
+
+    #[test]
+    fn test_withdrawal_schedule() {
+        let rng = &mut TestRng::default();
+
+        let [staker_a, staker_b, staker_c] = [(); 3].map(|_| Address::<CurrentNetwork>::rand(rng));
+        let stakers = IndexMap::from([
+            (staker_a, Some(UnbondingState::new(100, 10))),
+            (staker_b, Some(UnbondingState::new(50, 12))),
+            (staker_c, None),
+        ]);
+        let schedule = WithdrawalSchedule::new(Address::rand(rng), 9, stakers);
+
+        // Ensure only the unbonding stakers are included.
+        assert_eq!(schedule.unbonding().count(), 2);
+        assert_eq!(schedule.total_unbonding(), 150);
+        // Ensure only the stakers that unlock by the next block are claimable.
+        assert_eq!(schedule.claimable().map(|(staker, _)| *staker).collect::<Vec<_>>(), vec![staker_a]);
+        assert_eq!(schedule.total_claimable(), 100);
+    }
+}
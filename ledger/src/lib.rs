@@ -35,6 +35,14 @@ pub use ledger_test_helpers;
 mod helpers;
 pub use helpers::*;
 
+pub mod standards;
+
+#[cfg(feature = "fuzz")]
+pub mod fuzz;
+
+#[cfg(feature = "test-vectors")]
+pub mod test_vectors;
+
 mod advance;
 mod check_next_block;
 mod check_transaction_basic;
@@ -42,6 +50,9 @@ mod contains;
 mod find;
 mod get;
 mod iterators;
+mod ownership;
+mod statistics;
+mod unbonding;
 
 #[cfg(test)]
 mod tests;
@@ -60,7 +71,7 @@ use ledger_query::Query;
 use ledger_store::{ConsensusStorage, ConsensusStore};
 use synthesizer::{
     program::{FinalizeGlobalState, Program},
-    vm::VM,
+    vm::{bonded_map_into_stakers, VM},
 };
 
 use aleo_std::{
@@ -72,7 +83,7 @@ use core::ops::Range;
 use indexmap::IndexMap;
 use parking_lot::RwLock;
 use rand::{prelude::IteratorRandom, rngs::OsRng};
-use std::{borrow::Cow, sync::Arc};
+use std::{borrow::Cow, collections::VecDeque, sync::Arc};
 use time::OffsetDateTime;
 
 #[cfg(not(feature = "serial"))]
@@ -106,6 +117,10 @@ pub struct Ledger<N: Network, C: ConsensusStorage<N>> {
     current_committee: Arc<RwLock<Option<Committee<N>>>>,
     /// The current block.
     current_block: Arc<RwLock<Block<N>>>,
+    /// The consensus versions signaled by the most recent blocks, within the signaling window.
+    consensus_version_signals: Arc<RwLock<VecDeque<u16>>>,
+    /// The consensus version signaled by the blocks that this ledger produces (zero if none is signaled).
+    signaled_consensus_version: Arc<RwLock<u16>>,
 }
 
 impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
@@ -167,6 +182,8 @@ impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
             current_epoch_hash: Default::default(),
             current_committee: Arc::new(RwLock::new(current_committee)),
             current_block: Arc::new(RwLock::new(genesis_block.clone())),
+            consensus_version_signals: Default::default(),
+            signaled_consensus_version: Default::default(),
         };
 
         // If the block store is empty, initialize the genesis block.
@@ -190,6 +207,12 @@ impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
         ledger.current_committee = Arc::new(RwLock::new(Some(ledger.latest_committee()?)));
         // Set the current epoch hash.
         ledger.current_epoch_hash = Arc::new(RwLock::new(Some(ledger.get_epoch_hash(latest_height)?)));
+        // Set the consensus versions signaled within the signaling window.
+        let signals = (latest_height.saturating_add(1).saturating_sub(CONSENSUS_VERSION_SIGNALING_WINDOW)
+            ..=latest_height)
+            .map(|height| ledger.get_header(height).map(|header| header.consensus_version()))
+            .collect::<Result<VecDeque<_>>>()?;
+        ledger.consensus_version_signals = Arc::new(RwLock::new(signals));
 
         finish!(timer, "Initialize ledger");
         Ok(ledger)
@@ -220,7 +243,7 @@ impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
 
     /// Returns the latest epoch number.
     pub fn latest_epoch_number(&self) -> u32 {
-        self.current_block.read().height() / N::NUM_BLOCKS_PER_EPOCH
+        epoch_number::<N>(self.current_block.read().height())
     }
 
     /// Returns the latest epoch hash.
@@ -231,6 +254,30 @@ impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
         }
     }
 
+    /// Returns the active consensus version for the next block.
+    pub fn latest_active_consensus_version(&self) -> u16 {
+        active_consensus_version(&self.consensus_version_signals.read().iter().copied().collect::<Vec<_>>())
+    }
+
+    /// Returns the consensus version to signal in the next block that this ledger produces.
+    /// Note: A block must signal at least the active consensus version.
+    pub fn next_signaled_consensus_version(&self) -> u16 {
+        (*self.signaled_consensus_version.read()).max(self.latest_active_consensus_version())
+    }
+
+    /// Sets the consensus version to signal in the blocks that this ledger produces.
+    ///
+    /// Note: A block that signals a consensus version uses the version 2 metadata encoding,
+    /// so a consensus version should only be signaled once the network runs a release that supports it.
+    pub fn signal_consensus_version(&self, consensus_version: u16) -> Result<()> {
+        ensure!(
+            consensus_version <= LATEST_CONSENSUS_VERSION,
+            "Consensus version '{consensus_version}' is not supported (latest: '{LATEST_CONSENSUS_VERSION}')"
+        );
+        *self.signaled_consensus_version.write() = consensus_version;
+        Ok(())
+    }
+
     /// Returns the latest block.
     pub fn latest_block(&self) -> Block<N> {
         self.current_block.read().clone()
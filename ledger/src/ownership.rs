@@ -0,0 +1,72 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use console::program::OwnershipProof;
+
+impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
+    /// Verifies the given ownership proof over the given message, and ensures the record exists and is unspent.
+    pub fn verify_ownership_proof(&self, proof: &OwnershipProof<N>, message: &[Field<N>]) -> Result<()> {
+        // Ensure the proof is valid.
+        ensure!(proof.verify(message), "Invalid ownership proof for a '{}' record", proof.program_id());
+        // Ensure the record exists in the ledger.
+        let commitment = proof.commitment()?;
+        ensure!(self.contains_commitment(&commitment)?, "Record commitment '{commitment}' does not exist");
+        // Ensure the record is unspent.
+        let serial_number = proof.serial_number()?;
+        ensure!(!self.contains_serial_number(&serial_number)?, "Record commitment '{commitment}' is already spent");
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helpers::{sample_test_env, CurrentNetwork, TestEnv};
+
+    #[test]
+    fn test_verify_ownership_proof() {
+        let rng = &mut TestRng::default();
+
+        // Sample the test environment.
+        let TestEnv { ledger, private_key, view_key, .. } = sample_test_env(rng);
+
+        // Retrieve a genesis record.
+        let (_, record) = ledger.find_records(&view_key, RecordsFilter::Unspent).unwrap().next().unwrap();
+
+        // Prove ownership of the record.
+        let message = [Field::rand(rng)];
+        let program_id = ProgramID::from_str("credits.aleo").unwrap();
+        let record_name = Identifier::from_str("credits").unwrap();
+        let proof = OwnershipProof::prove(&private_key, program_id, record_name, record, &message, rng).unwrap();
+
+        // Ensure the proof verifies against the ledger.
+        assert!(ledger.verify_ownership_proof(&proof, &message).is_ok());
+        assert!(proof.check_minimum(&Identifier::from_str("microcredits").unwrap(), 1).is_ok());
+        // Ensure the proof does not verify for a different message.
+        assert!(ledger.verify_ownership_proof(&proof, &[]).is_err());
+
+        // Ensure the proof does not verify for a record that does not exist in the ledger.
+        let record = Record::from_str(&format!(
+            "{{ owner: {}.private, microcredits: 1u64.private, _nonce: {}.public }}",
+            proof.owner(),
+            Group::<CurrentNetwork>::rand(rng)
+        ))
+        .unwrap();
+        let proof = OwnershipProof::prove(&private_key, program_id, record_name, record, &message, rng).unwrap();
+        assert!(proof.verify(&message));
+        assert!(ledger.verify_ownership_proof(&proof, &message).is_err());
+    }
+}
@@ -0,0 +1,70 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//! Helpers for programs that implement a known interface, so that wallets can interact with them uniformly.
+
+mod token;
+pub use token::*;
+
+use crate::Ledger;
+use console::{
+    network::prelude::*,
+    program::{Literal, Plaintext, ProgramID},
+    types::Address,
+};
+use ledger_store::ConsensusStorage;
+
+impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
+    /// Returns the token interface of the given program, or `None` if the program does not implement it.
+    pub fn get_token_program(&self, program_id: &ProgramID<N>) -> Result<Option<TokenProgram<N>>> {
+        let program = self.vm.process().read().get_program(*program_id)?;
+        Ok(TokenProgram::detect(&program))
+    }
+
+    /// Returns the public balance of the given address in the given token program.
+    pub fn get_token_balance(&self, token: &TokenProgram<N>, address: &Address<N>) -> Result<u128> {
+        // Retrieve the balance from the balance mapping, defaulting to zero.
+        let key = Plaintext::from(Literal::Address(*address));
+        match self.vm.finalize_store().get_value_confirmed(*token.program_id(), token.balance_mapping()?, &key)? {
+            Some(value) => token.balance_from_value(&value),
+            None => Ok(0),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helpers::CurrentLedger;
+    use aleo_std::StorageMode;
+    use console::network::MainnetV0;
+    use ledger_block::Block;
+
+    type CurrentNetwork = MainnetV0;
+
+    #[test]
+    fn test_get_token_balance() {
+        let rng = &mut TestRng::default();
+
+        // Load the genesis block.
+        let genesis = Block::from_bytes_le(CurrentNetwork::genesis_bytes()).unwrap();
+        // Initialize a new ledger.
+        let ledger = CurrentLedger::load(genesis, StorageMode::Production).unwrap();
+
+        // Ensure `credits.aleo` is detected as a token program.
+        let token = ledger.get_token_program(&ProgramID::from_str("credits.aleo").unwrap()).unwrap().unwrap();
+        // Ensure the balance of a new address is zero.
+        assert_eq!(ledger.get_token_balance(&token, &Address::rand(rng)).unwrap(), 0);
+    }
+}
@@ -0,0 +1,387 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use console::{
+    network::prelude::*,
+    program::{
+        Entry,
+        EntryType,
+        Identifier,
+        Literal,
+        LiteralType,
+        Plaintext,
+        PlaintextType,
+        ProgramID,
+        Record,
+        Value,
+        ValueType,
+    },
+    types::{Address, U128, U64},
+};
+use synthesizer::program::Program;
+
+/// The name of the mapping from an address to its public balance.
+const BALANCE_MAPPING: &str = "account";
+/// The name of the function that transfers a public balance to a public balance.
+const TRANSFER_PUBLIC: &str = "transfer_public";
+/// The name of the function that transfers a record to a new record.
+const TRANSFER_PRIVATE: &str = "transfer_private";
+/// The name of the function that transfers a record to a public balance.
+const TRANSFER_PRIVATE_TO_PUBLIC: &str = "transfer_private_to_public";
+/// The name of the function that transfers a public balance to a new record.
+const TRANSFER_PUBLIC_TO_PRIVATE: &str = "transfer_public_to_private";
+
+/// A program that implements the token interface of `credits.aleo`, i.e. it has:
+///   - an `account` mapping from an `address` to a `u64` or `u128` public balance,
+///   - a `transfer_public(address.public, amount.public)` function,
+///   - a `transfer_private(token.record, address.private, amount.private)` function,
+///     where the record has exactly one member of the amount type,
+///   - and optionally, the `transfer_private_to_public` and `transfer_public_to_private` functions.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TokenProgram<N: Network> {
+    /// The program ID.
+    program_id: ProgramID<N>,
+    /// The type of the amounts, which is either `u64` or `u128`.
+    amount_type: LiteralType,
+    /// The name of the token record.
+    record_name: Identifier<N>,
+    /// The name of the amount member in the token record.
+    amount_member: Identifier<N>,
+    /// Whether the program converts between records and public balances.
+    supports_conversions: bool,
+}
+
+impl<N: Network> TokenProgram<N> {
+    /// Returns the token interface of the given program, or `None` if the program does not implement it.
+    pub fn detect(program: &Program<N>) -> Option<Self> {
+        // Ensure the balance mapping is from an address to an amount.
+        let mapping = program.get_mapping(&Identifier::from_str(BALANCE_MAPPING).ok()?).ok()?;
+        if *mapping.key().plaintext_type() != PlaintextType::Literal(LiteralType::Address) {
+            return None;
+        }
+        let amount_type = match mapping.value().plaintext_type() {
+            PlaintextType::Literal(LiteralType::U64) => LiteralType::U64,
+            PlaintextType::Literal(LiteralType::U128) => LiteralType::U128,
+            _ => return None,
+        };
+        let address = PlaintextType::Literal(LiteralType::Address);
+        let amount = PlaintextType::Literal(amount_type);
+
+        // Returns the input types of the given function, if it exists.
+        let input_types = |name: &str| -> Option<Vec<ValueType<N>>> {
+            Some(program.get_function_ref(&Identifier::from_str(name).ok()?).ok()?.input_types())
+        };
+
+        // Ensure `transfer_public` has the expected signature.
+        let expected = [ValueType::Public(address.clone()), ValueType::Public(amount.clone())];
+        if input_types(TRANSFER_PUBLIC)? != expected {
+            return None;
+        }
+        // Ensure `transfer_private` has the expected signature, and retrieve the record name.
+        let record_name = match input_types(TRANSFER_PRIVATE)?.as_slice() {
+            [ValueType::Record(record_name), ValueType::Private(recipient), ValueType::Private(value)]
+                if *recipient == address && *value == amount =>
+            {
+                *record_name
+            }
+            _ => return None,
+        };
+        // Ensure the record has exactly one member of the amount type.
+        let mut amount_members = program.get_record(&record_name).ok()?.entries().iter().filter(|(_, entry)| {
+            matches!(entry, EntryType::Constant(t) | EntryType::Public(t) | EntryType::Private(t) if *t == amount)
+        });
+        let (amount_member, _) = amount_members.next()?;
+        if amount_members.next().is_some() {
+            return None;
+        }
+
+        // Determine whether the program converts between records and public balances.
+        let supports_conversions = input_types(TRANSFER_PRIVATE_TO_PUBLIC).as_deref()
+            == Some(&[
+                ValueType::Record(record_name),
+                ValueType::Public(address.clone()),
+                ValueType::Public(amount.clone()),
+            ])
+            && input_types(TRANSFER_PUBLIC_TO_PRIVATE).as_deref()
+                == Some(&[ValueType::Private(address), ValueType::Public(amount)]);
+
+        Some(Self {
+            program_id: *program.id(),
+            amount_type,
+            record_name,
+            amount_member: *amount_member,
+            supports_conversions,
+        })
+    }
+
+    /// Returns the program ID.
+    pub const fn program_id(&self) -> &ProgramID<N> {
+        &self.program_id
+    }
+
+    /// Returns the type of the amounts, which is either `u64` or `u128`.
+    pub const fn amount_type(&self) -> LiteralType {
+        self.amount_type
+    }
+
+    /// Returns the name of the token record.
+    pub const fn record_name(&self) -> &Identifier<N> {
+        &self.record_name
+    }
+
+    /// Returns the name of the amount member in the token record.
+    pub const fn amount_member(&self) -> &Identifier<N> {
+        &self.amount_member
+    }
+
+    /// Returns `true` if the program converts between records and public balances.
+    pub const fn supports_conversions(&self) -> bool {
+        self.supports_conversions
+    }
+
+    /// Returns the name of the balance mapping.
+    pub fn balance_mapping(&self) -> Result<Identifier<N>> {
+        Identifier::from_str(BALANCE_MAPPING)
+    }
+
+    /// Returns the amount of the given balance mapping value.
+    pub fn balance_from_value(&self, value: &Value<N>) -> Result<u128> {
+        match value {
+            Value::Plaintext(Plaintext::Literal(literal, _)) => self.amount_from_literal(literal),
+            _ => bail!("Invalid balance in '{}' - {value}", self.program_id),
+        }
+    }
+
+    /// Returns the amount of the given token record.
+    pub fn record_amount(&self, record: &Record<N, Plaintext<N>>) -> Result<u128> {
+        match record.find(&[self.amount_member])? {
+            Entry::Constant(Plaintext::Literal(literal, _))
+            | Entry::Public(Plaintext::Literal(literal, _))
+            | Entry::Private(Plaintext::Literal(literal, _)) => self.amount_from_literal(&literal),
+            _ => bail!("Invalid '{}' member in the '{}' record", self.amount_member, self.program_id),
+        }
+    }
+
+    /// Returns a transfer of the given amount from the public balance of the caller to the recipient.
+    pub fn transfer_public(&self, recipient: &Address<N>, amount: u128) -> Result<TokenTransfer<N>> {
+        self.to_transfer(TRANSFER_PUBLIC, vec![Value::from(Literal::Address(*recipient)), self.to_amount(amount)?])
+    }
+
+    /// Returns a transfer of the given amount from the given record to a new record for the recipient.
+    pub fn transfer_private(
+        &self,
+        record: Record<N, Plaintext<N>>,
+        recipient: &Address<N>,
+        amount: u128,
+    ) -> Result<TokenTransfer<N>> {
+        self.check_record(&record, amount)?;
+        let inputs = vec![Value::Record(record), Value::from(Literal::Address(*recipient)), self.to_amount(amount)?];
+        self.to_transfer(TRANSFER_PRIVATE, inputs)
+    }
+
+    /// Returns a transfer of the given amount from the given record to the public balance of the recipient.
+    pub fn transfer_private_to_public(
+        &self,
+        record: Record<N, Plaintext<N>>,
+        recipient: &Address<N>,
+        amount: u128,
+    ) -> Result<TokenTransfer<N>> {
+        ensure!(self.supports_conversions, "'{}' does not support '{TRANSFER_PRIVATE_TO_PUBLIC}'", self.program_id);
+        self.check_record(&record, amount)?;
+        let inputs = vec![Value::Record(record), Value::from(Literal::Address(*recipient)), self.to_amount(amount)?];
+        self.to_transfer(TRANSFER_PRIVATE_TO_PUBLIC, inputs)
+    }
+
+    /// Returns a transfer of the given amount from the public balance of the caller to a new record for the recipient.
+    pub fn transfer_public_to_private(&self, recipient: &Address<N>, amount: u128) -> Result<TokenTransfer<N>> {
+        ensure!(self.supports_conversions, "'{}' does not support '{TRANSFER_PUBLIC_TO_PRIVATE}'", self.program_id);
+        let inputs = vec![Value::from(Literal::Address(*recipient)), self.to_amount(amount)?];
+        self.to_transfer(TRANSFER_PUBLIC_TO_PRIVATE, inputs)
+    }
+}
+
+impl<N: Network> TokenProgram<N> {
+    /// Returns the amount of the given literal, which must be of the amount type.
+    fn amount_from_literal(&self, literal: &Literal<N>) -> Result<u128> {
+        match literal {
+            Literal::U64(amount) if self.amount_type == LiteralType::U64 => Ok(**amount as u128),
+            Literal::U128(amount) if self.amount_type == LiteralType::U128 => Ok(**amount),
+            _ => bail!("Expected an amount of type '{}', found '{literal}'", self.amount_type),
+        }
+    }
+
+    /// Returns the given amount as a value of the amount type.
+    fn to_amount(&self, amount: u128) -> Result<Value<N>> {
+        ensure!(amount > 0, "The transfer amount must be nonzero");
+        match self.amount_type {
+            LiteralType::U64 => match u64::try_from(amount) {
+                Ok(amount) => Ok(Value::from(Literal::U64(U64::new(amount)))),
+                Err(_) => bail!("The transfer amount {amount} exceeds the maximum 'u64' amount"),
+            },
+            _ => Ok(Value::from(Literal::U128(U128::new(amount)))),
+        }
+    }
+
+    /// Ensures the given record is a token record with at least the given amount.
+    fn check_record(&self, record: &Record<N, Plaintext<N>>, amount: u128) -> Result<()> {
+        let balance = self.record_amount(record)?;
+        ensure!(amount <= balance, "The '{}' record has only {balance}, but {amount} was requested", self.program_id);
+        Ok(())
+    }
+
+    /// Returns a transfer with the given function name and inputs.
+    fn to_transfer(&self, function_name: &str, inputs: Vec<Value<N>>) -> Result<TokenTransfer<N>> {
+        Ok(TokenTransfer { program_id: self.program_id, function_name: Identifier::from_str(function_name)?, inputs })
+    }
+}
+
+/// A call to a transfer function of a token program, which can be authorized or executed on the VM.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TokenTransfer<N: Network> {
+    /// The program ID.
+    program_id: ProgramID<N>,
+    /// The function name.
+    function_name: Identifier<N>,
+    /// The function inputs.
+    inputs: Vec<Value<N>>,
+}
+
+impl<N: Network> TokenTransfer<N> {
+    /// Returns the program ID.
+    pub const fn program_id(&self) -> &ProgramID<N> {
+        &self.program_id
+    }
+
+    /// Returns the function name.
+    pub const fn function_name(&self) -> &Identifier<N> {
+        &self.function_name
+    }
+
+    /// Returns the function inputs.
+    pub fn inputs(&self) -> &[Value<N>] {
+        &self.inputs
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::{network::MainnetV0, program::Owner, types::Group};
+
+    use indexmap::IndexMap;
+
+    type CurrentNetwork = MainnetV0;
+
+    /// A token program with `u128` amounts, and without conversions.
+    const TOKEN_PROGRAM: &str = r"
+program token.aleo;
+
+record token:
+    owner as address.private;
+    amount as u128.private;
+
+mapping account:
+    key as address.public;
+    value as u128.public;
+
+function transfer_public:
+    input r0 as address.public;
+    input r1 as u128.public;
+    async transfer_public self.caller r0 r1 into r2;
+    output r2 as token.aleo/transfer_public.future;
+
+finalize transfer_public:
+    input r0 as address.public;
+    input r1 as address.public;
+    input r2 as u128.public;
+    get account[r0] into r3;
+    sub r3 r2 into r4;
+    set r4 into account[r0];
+    get.or_use account[r1] 0u128 into r5;
+    add r5 r2 into r6;
+    set r6 into account[r1];
+
+function transfer_private:
+    input r0 as token.record;
+    input r1 as address.private;
+    input r2 as u128.private;
+    sub r0.amount r2 into r3;
+    cast r1 r2 into r4 as token.record;
+    cast r0.owner r3 into r5 as token.record;
+    output r4 as token.record;
+    output r5 as token.record;";
+
+    #[test]
+    fn test_detect_credits() {
+        let token = TokenProgram::detect(&Program::<CurrentNetwork>::credits().unwrap()).unwrap();
+        assert_eq!(token.program_id().to_string(), "credits.aleo");
+        assert_eq!(token.amount_type(), LiteralType::U64);
+        assert_eq!(token.record_name().to_string(), "credits");
+        assert_eq!(token.amount_member().to_string(), "microcredits");
+        assert!(token.supports_conversions());
+    }
+
+    #[test]
+    fn test_detect_token() {
+        let program = Program::<CurrentNetwork>::from_str(TOKEN_PROGRAM).unwrap();
+        let token = TokenProgram::detect(&program).unwrap();
+        assert_eq!(token.amount_type(), LiteralType::U128);
+        assert_eq!(token.record_name().to_string(), "token");
+        assert_eq!(token.amount_member().to_string(), "amount");
+        assert!(!token.supports_conversions());
+
+        // Ensure a program with a mismatched signature is not detected.
+        let program = Program::<CurrentNetwork>::from_str(
+            &TOKEN_PROGRAM.replace("input r1 as u128.public;", "input r1 as u64.public;"),
+        );
+        assert!(TokenProgram::detect(&program.unwrap()).is_none());
+        // Ensure a program without the balance mapping is not detected.
+        let program = Program::<CurrentNetwork>::from_str(&TOKEN_PROGRAM.replace("account", "balances"));
+        assert!(TokenProgram::detect(&program.unwrap()).is_none());
+    }
+
+    #[test]
+    fn test_token_transfers() {
+        let rng = &mut TestRng::default();
+
+        let program = Program::<CurrentNetwork>::from_str(TOKEN_PROGRAM).unwrap();
+        let token = TokenProgram::detect(&program).unwrap();
+        let recipient = Address::rand(rng);
+
+        // Construct a token record with 100 tokens.
+        let data = IndexMap::from([(
+            Identifier::from_str("amount").unwrap(),
+            Entry::Private(Plaintext::from(Literal::U128(U128::new(100)))),
+        )]);
+        let record = Record::from_plaintext(
+            Owner::Private(Plaintext::from(Literal::Address(recipient))),
+            data,
+            Group::rand(rng),
+        )
+        .unwrap();
+        assert_eq!(token.record_amount(&record).unwrap(), 100);
+
+        // Ensure the public transfer has the expected inputs.
+        let transfer = token.transfer_public(&recipient, 5).unwrap();
+        assert_eq!(transfer.function_name().to_string(), TRANSFER_PUBLIC);
+        assert_eq!(transfer.inputs()[1], Value::from_str("5u128").unwrap());
+        // Ensure the private transfer checks the record amount.
+        assert!(token.transfer_private(record.clone(), &recipient, 100).is_ok());
+        assert!(token.transfer_private(record.clone(), &recipient, 101).is_err());
+        // Ensure zero amounts, and unsupported conversions, are rejected.
+        assert!(token.transfer_public(&recipient, 0).is_err());
+        assert!(token.transfer_private_to_public(record, &recipient, 1).is_err());
+        assert!(token.transfer_public_to_private(&recipient, 1).is_err());
+    }
+}
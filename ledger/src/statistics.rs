@@ -0,0 +1,131 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use super::*;
+
+impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
+    /// Returns the supply and emission statistics of the ledger, as of the latest block.
+    ///
+    /// Note: This method iterates over the ratifications and transactions of every block in the ledger.
+    pub fn supply_statistics(&self) -> Result<SupplyStatistics> {
+        // Retrieve the latest block height.
+        let latest_height = self.latest_height();
+
+        // Initialize the cumulative amounts.
+        let mut total_supply = N::STARTING_SUPPLY;
+        let mut cumulative_block_rewards = 0u64;
+        let mut cumulative_puzzle_rewards = 0u64;
+        let mut cumulative_fees = 0u64;
+
+        // Note: The genesis block distributes the starting supply, and is skipped.
+        for height in 1..=latest_height {
+            // Retrieve the block hash.
+            let Some(block_hash) = self.vm.block_store().get_block_hash(height)? else {
+                bail!("Block {height} does not exist in storage");
+            };
+            // Retrieve the block ratifications.
+            let Some(ratifications) = self.vm.block_store().get_block_ratifications(&block_hash)? else {
+                bail!("Missing ratifications for block {height}");
+            };
+            // Retrieve the block transactions.
+            let transactions = self.get_transactions(height)?;
+
+            // Extract the rewards from the ratifications.
+            let (mut block_reward, mut puzzle_reward) = (0u64, 0u64);
+            for ratify in ratifications.iter() {
+                match ratify {
+                    Ratify::Genesis(..) => bail!("Found a genesis ratification in block {height}"),
+                    Ratify::BlockReward(reward) => block_reward = block_reward.saturating_add(*reward),
+                    Ratify::PuzzleReward(reward) => puzzle_reward = puzzle_reward.saturating_add(*reward),
+                }
+            }
+            // Accumulate the fees.
+            for confirmed in transactions.iter() {
+                cumulative_fees = cumulative_fees.saturating_add(*confirmed.fee_amount()?);
+            }
+
+            // Update the cumulative amounts.
+            cumulative_block_rewards = cumulative_block_rewards.saturating_add(block_reward);
+            cumulative_puzzle_rewards = cumulative_puzzle_rewards.saturating_add(puzzle_reward);
+            total_supply = update_total_supply(total_supply, block_reward, puzzle_reward, &transactions)?;
+        }
+
+        // Construct the credits.aleo program ID.
+        let credits_program_id = ProgramID::from_str("credits.aleo")?;
+
+        // Compute the total bonded amount from the `bonded` mapping.
+        let bonded_map =
+            self.vm.finalize_store().get_mapping_confirmed(credits_program_id, Identifier::from_str("bonded")?)?;
+        let total_bonded = bonded_map_into_stakers(bonded_map)?
+            .values()
+            .try_fold(0u64, |total, (_, microcredits)| total.checked_add(*microcredits))
+            .ok_or_else(|| anyhow!("The total bonded amount overflows"))?;
+
+        // Compute the total unbonding amount from the `unbonding` mapping.
+        let microcredits_identifier = Identifier::from_str("microcredits")?;
+        let unbonding_map =
+            self.vm.finalize_store().get_mapping_confirmed(credits_program_id, Identifier::from_str("unbonding")?)?;
+        let total_unbonding = unbonding_map.iter().try_fold(0u64, |total, (_, unbond_state)| {
+            // Extract the microcredits from the unbond state.
+            let microcredits = match unbond_state {
+                Value::Plaintext(Plaintext::Struct(state, _)) => match state.get(&microcredits_identifier) {
+                    Some(Plaintext::Literal(Literal::U64(microcredits), _)) => **microcredits,
+                    _ => bail!("Invalid unbond state (missing microcredits) - {unbond_state}"),
+                },
+                _ => bail!("Invalid unbonding value (missing struct) - {unbond_state}"),
+            };
+            total.checked_add(microcredits).ok_or_else(|| anyhow!("The total unbonding amount overflows"))
+        })?;
+
+        Ok(SupplyStatistics::new(
+            latest_height,
+            total_supply,
+            total_bonded,
+            total_unbonding,
+            cumulative_block_rewards,
+            cumulative_puzzle_rewards,
+            cumulative_fees,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helpers::CurrentLedger;
+    use console::network::MainnetV0;
+
+    type CurrentNetwork = MainnetV0;
+
+    #[test]
+    fn test_supply_statistics_at_genesis() {
+        // Load the genesis block.
+        let genesis = Block::from_bytes_le(CurrentNetwork::genesis_bytes()).unwrap();
+
+        // Initialize a new ledger.
+        let ledger = CurrentLedger::load(genesis, StorageMode::Production).unwrap();
+        // Compute the supply statistics.
+        let statistics = ledger.supply_statistics().unwrap();
+
+        // Ensure the statistics match the genesis state.
+        assert_eq!(statistics.block_height(), 0);
+        assert_eq!(statistics.total_supply(), CurrentNetwork::STARTING_SUPPLY);
+        assert_eq!(statistics.cumulative_block_rewards(), 0);
+        assert_eq!(statistics.cumulative_puzzle_rewards(), 0);
+        assert_eq!(statistics.cumulative_fees(), 0);
+        assert_eq!(statistics.total_unbonding(), 0);
+        assert!(statistics.total_bonded() > 0);
+        assert!(statistics.total_bonded() <= statistics.total_supply());
+    }
+}
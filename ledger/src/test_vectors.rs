@@ -0,0 +1,186 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Canonical test vectors, which SDKs in other languages can use to validate their serializers.
+//!
+//! Each test vector records the canonical JSON and little-endian byte encodings of an object, as produced by this crate.
+//! The vectors are generated from the given RNG, so a seeded RNG yields reproducible fixtures.
+
+use super::*;
+use console::account::Signature;
+use ledger_store::helpers::memory::ConsensusMemory;
+
+use std::{fs, path::Path};
+
+/// A test vector, with the canonical JSON and byte encodings of an object.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TestVector {
+    /// The name of the test vector.
+    name: String,
+    /// The ID of the network.
+    network_id: u16,
+    /// The input used to derive the object, if any.
+    input: Option<String>,
+    /// The canonical JSON encoding of the object.
+    json: String,
+    /// The little-endian byte encoding of the object.
+    bytes: Vec<u8>,
+}
+
+impl TestVector {
+    /// Initializes a new test vector for the given object.
+    pub fn new<N: Network, T: Serialize + ToBytes>(name: &str, input: Option<String>, object: &T) -> Result<Self> {
+        Ok(Self {
+            name: name.to_string(),
+            network_id: N::ID,
+            input,
+            json: serde_json::to_string(object)?,
+            bytes: object.to_bytes_le()?,
+        })
+    }
+
+    /// Returns the name of the test vector.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the ID of the network.
+    pub const fn network_id(&self) -> u16 {
+        self.network_id
+    }
+
+    /// Returns the input used to derive the object, if any.
+    pub fn input(&self) -> Option<&str> {
+        self.input.as_deref()
+    }
+
+    /// Returns the canonical JSON encoding of the object.
+    pub fn json(&self) -> &str {
+        &self.json
+    }
+
+    /// Returns the little-endian byte encoding of the object.
+    pub fn bytes(&self) -> &[u8] {
+        &self.bytes
+    }
+
+    /// Returns the test vector as a JSON fixture, with the bytes encoded in hex.
+    pub fn to_fixture(&self) -> Result<serde_json::Value> {
+        Ok(serde_json::json!({
+            "name": self.name,
+            "network_id": self.network_id,
+            "input": self.input,
+            "json": serde_json::from_str::<serde_json::Value>(&self.json)?,
+            "bytes": self.bytes.iter().map(|byte| format!("{byte:02x}")).collect::<String>(),
+        }))
+    }
+
+    /// Writes the test vector as a JSON fixture to `{directory}/{network_id}/{name}.json`.
+    pub fn save(&self, directory: &Path) -> Result<()> {
+        let directory = directory.join(self.network_id.to_string());
+        fs::create_dir_all(&directory)?;
+        fs::write(directory.join(format!("{}.json", self.name)), serde_json::to_string_pretty(&self.to_fixture()?)?)?;
+        Ok(())
+    }
+}
+
+/// Generates the test vectors for accounts, signatures, hashes, transactions, blocks, and state paths on the given network.
+/// Note: This method computes a genesis block, which requires proving, and is therefore expensive.
+pub fn generate_test_vectors<N: Network, R: Rng + CryptoRng>(rng: &mut R) -> Result<Vec<TestVector>> {
+    let mut vectors = Vec::new();
+
+    // Sample an account.
+    let private_key = PrivateKey::<N>::new(rng)?;
+    let view_key = ViewKey::try_from(&private_key)?;
+    let address = Address::try_from(&private_key)?;
+    vectors.push(TestVector::new::<N, _>("private_key", None, &private_key)?);
+    vectors.push(TestVector::new::<N, _>("view_key", Some(private_key.to_string()), &view_key)?);
+    vectors.push(TestVector::new::<N, _>("address", Some(private_key.to_string()), &address)?);
+
+    // Sign a message.
+    let message = (0..4).map(|_| Uniform::rand(rng)).collect::<Vec<Field<N>>>();
+    let signature = Signature::sign(&private_key, &message, rng)?;
+    ensure!(signature.verify(&address, &message), "Failed to verify the sampled signature");
+    let message_string = format!("[{}]", message.iter().map(|field| field.to_string()).join(", "));
+    vectors.push(TestVector::new::<N, _>("signature", Some(message_string.clone()), &signature)?);
+
+    // Compute the hashes of the message.
+    let message_bits = message.to_bits_le();
+    vectors.push(TestVector::new::<N, _>(
+        "hash_bhp256",
+        Some(message_string.clone()),
+        &N::hash_bhp256(&message_bits)?,
+    )?);
+    vectors.push(TestVector::new::<N, _>(
+        "hash_bhp512",
+        Some(message_string.clone()),
+        &N::hash_bhp512(&message_bits)?,
+    )?);
+    vectors.push(TestVector::new::<N, _>("hash_psd2", Some(message_string.clone()), &N::hash_psd2(&message)?)?);
+    vectors.push(TestVector::new::<N, _>("hash_psd4", Some(message_string), &N::hash_psd4(&message)?)?);
+
+    // Compute a genesis block, and add it to a VM.
+    let vm = VM::from(ConsensusStore::<N, ConsensusMemory<N>>::open(None)?)?;
+    let block = vm.genesis_beacon(&private_key, rng)?;
+    vm.add_next_block(&block)?;
+    vectors.push(TestVector::new::<N, _>("block", None, &block)?);
+    vectors.push(TestVector::new::<N, _>("block_header", None, block.header())?);
+
+    // Add each transaction in the block.
+    for (index, transaction) in block.transactions().iter().enumerate() {
+        vectors.push(TestVector::new::<N, _>(&format!("transaction_{index}"), None, transaction.deref())?);
+    }
+
+    // Add the state path for the first commitment in the block.
+    if let Some(commitment) = block.commitments().next() {
+        let state_path = vm.block_store().get_state_path_for_commitment(commitment)?;
+        vectors.push(TestVector::new::<N, _>("state_path", Some(commitment.to_string()), &state_path)?);
+    }
+
+    Ok(vectors)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::MainnetV0;
+
+    type CurrentNetwork = MainnetV0;
+
+    #[test]
+    fn test_generate_test_vectors() {
+        // Ensure the test vectors are reproducible with the same seed.
+        let vectors = generate_test_vectors::<CurrentNetwork, _>(&mut TestRng::fixed(1)).unwrap();
+        let candidate = generate_test_vectors::<CurrentNetwork, _>(&mut TestRng::fixed(1)).unwrap();
+        assert_eq!(vectors, candidate);
+
+        let names = vectors.iter().map(|vector| vector.name()).collect::<Vec<_>>();
+        for name in ["private_key", "signature", "hash_bhp256", "block", "transaction_0", "state_path"] {
+            assert!(names.contains(&name), "Missing test vector '{name}'");
+        }
+
+        for vector in &vectors {
+            assert_eq!(vector.network_id(), CurrentNetwork::ID);
+            // Ensure the fixture encodes the bytes in hex.
+            let fixture = vector.to_fixture().unwrap();
+            assert_eq!(fixture["bytes"].as_str().unwrap().len(), 2 * vector.bytes().len());
+        }
+
+        // Ensure the decoded test vectors match their encodings.
+        let block = vectors.iter().find(|vector| vector.name() == "block").unwrap();
+        let expected = Block::<CurrentNetwork>::from_bytes_le(block.bytes()).unwrap();
+        assert_eq!(serde_json::to_string(&expected).unwrap(), block.json());
+    }
+}
@@ -16,6 +16,8 @@
 use crate::{
     advance::split_candidate_solutions,
     test_helpers::{CurrentAleo, CurrentLedger, CurrentNetwork},
+    BlockVerificationOptions,
+    BlockVerificationStage,
     Ledger,
     RecordsFilter,
 };
@@ -27,12 +29,16 @@ use console::{
     types::U16,
 };
 use ledger_authority::Authority;
-use ledger_block::{Block, ConfirmedTransaction, Execution, Ratify, Rejected, Transaction};
+use ledger_block::{AbortReason, Block, BlockBuilder, ConfirmedTransaction, Execution, Ratify, Rejected, Transaction};
 use ledger_committee::{Committee, MIN_VALIDATOR_STAKE};
 use ledger_narwhal::{BatchCertificate, BatchHeader, Data, Subdag, Transmission, TransmissionID};
 use ledger_store::{helpers::memory::ConsensusMemory, ConsensusStore};
 use snarkvm_utilities::try_vm_runtime;
-use synthesizer::{program::Program, vm::VM, Stack};
+use synthesizer::{
+    program::Program,
+    vm::{VerificationPolicy, VerifiedTransactions, VM},
+    Stack,
+};
 
 use indexmap::{IndexMap, IndexSet};
 use rand::seq::SliceRandom;
@@ -230,6 +236,206 @@ fn test_state_path() {
     let _state_path = ledger.get_state_path_for_commitment(commitment).unwrap();
 }
 
+#[test]
+fn test_receipt() {
+    let rng = &mut TestRng::default();
+
+    // Initialize the ledger.
+    let ledger = crate::test_helpers::sample_ledger(PrivateKey::<CurrentNetwork>::new(rng).unwrap(), rng);
+    // Retrieve the genesis block.
+    let block = ledger.get_block(0).unwrap();
+
+    // Ensure a receipt can be constructed and verified for each transaction.
+    for transaction_id in block.transaction_ids() {
+        let receipt = ledger.get_receipt(transaction_id).unwrap();
+        assert_eq!(receipt.transaction_id(), transaction_id);
+        assert_eq!(receipt.block_height(), 0);
+        receipt.verify(ledger.latest_state_root()).unwrap();
+    }
+}
+
+#[test]
+fn test_receipt_batch() {
+    let rng = &mut TestRng::default();
+
+    // Initialize the ledger.
+    let ledger = crate::test_helpers::sample_ledger(PrivateKey::<CurrentNetwork>::new(rng).unwrap(), rng);
+    // Retrieve the genesis block.
+    let block = ledger.get_block(0).unwrap();
+    let transaction_ids = block.transaction_ids().copied().collect::<Vec<_>>();
+
+    // Ensure a receipt batch can be constructed and verified for all transactions.
+    let receipt_batch = ledger.get_receipt_batch(&transaction_ids).unwrap();
+    assert_eq!(receipt_batch.block_receipts().len(), 1);
+    assert_eq!(receipt_batch.transaction_ids().copied().collect::<Vec<_>>(), transaction_ids);
+    receipt_batch.verify(ledger.latest_state_root()).unwrap();
+
+    // Ensure the receipt batch is smaller than the individual receipts.
+    let receipts_size = transaction_ids
+        .iter()
+        .map(|transaction_id| ledger.get_receipt(transaction_id).unwrap().to_bytes_le().unwrap().len())
+        .sum::<usize>();
+    assert!(transaction_ids.len() == 1 || receipt_batch.to_bytes_le().unwrap().len() < receipts_size);
+}
+
+#[test]
+fn test_block_builder() {
+    let rng = &mut TestRng::default();
+
+    // Initialize the test environment.
+    let crate::test_helpers::TestEnv { ledger, private_key, address, .. } = crate::test_helpers::sample_test_env(rng);
+
+    // Prepare a transaction.
+    let inputs = [Value::from_str(&format!("{address}")).unwrap(), Value::from_str("10u64").unwrap()];
+    let transaction = ledger
+        .vm
+        .execute(&private_key, ("credits.aleo", "transfer_public"), inputs.into_iter(), None, 0, None, rng)
+        .unwrap();
+
+    // Initialize the block builder.
+    let previous_block = ledger.latest_block();
+    let builder =
+        BlockBuilder::new(&previous_block, ledger.latest_state_root(), OffsetDateTime::now_utc().unix_timestamp())
+            .unwrap();
+    // Speculate on the transaction.
+    let speculation = ledger
+        .vm
+        .speculate_with_abort_reasons(
+            builder.finalize_global_state().unwrap(),
+            Some(builder.coinbase_reward()),
+            vec![],
+            builder.solutions(),
+            [transaction].iter(),
+            rng,
+        )
+        .unwrap();
+    // Construct the block.
+    let block = builder.build_beacon(&private_key, speculation, rng).unwrap();
+
+    // Ensure the block is valid, and advance the ledger.
+    ledger.check_next_block(&block, rng).unwrap();
+    ledger.advance_to_next_block(&block).unwrap();
+    assert_eq!(ledger.latest_height(), previous_block.height() + 1);
+    assert_eq!(block.transactions().len(), 1);
+}
+
+#[test]
+fn test_check_next_block_with_options() {
+    let rng = &mut TestRng::default();
+
+    // Initialize the test environment.
+    let crate::test_helpers::TestEnv { ledger, private_key, address, .. } = crate::test_helpers::sample_test_env(rng);
+
+    // Prepare a transaction, and verify it, as in the mempool.
+    let inputs = [Value::from_str(&format!("{address}")).unwrap(), Value::from_str("10u64").unwrap()];
+    let transaction = ledger
+        .vm
+        .execute(&private_key, ("credits.aleo", "transfer_public"), inputs.into_iter(), None, 0, None, rng)
+        .unwrap();
+    ledger.vm.check_transaction(&transaction, None, rng).unwrap();
+    let mut verified_transactions = VerifiedTransactions::new(VerificationPolicy::TrustVerified);
+    verified_transactions.insert_transaction(&transaction).unwrap();
+
+    // Construct the block.
+    let block =
+        ledger.prepare_advance_to_next_beacon_block(&private_key, vec![], vec![], vec![transaction], rng).unwrap();
+
+    // Ensure the block is valid, without re-verifying the transaction proofs.
+    let options = BlockVerificationOptions::default().with_verified_transactions(verified_transactions);
+    ledger.check_next_block_with_options(&block, &options, rng).unwrap();
+    // Ensure the block is valid, without re-verifying the transactions.
+    let options = BlockVerificationOptions::default().skip(BlockVerificationStage::Transactions).unwrap();
+    ledger.check_next_block_with_options(&block, &options, rng).unwrap();
+    // Ensure the block is valid, with all stages.
+    ledger.check_next_block(&block, rng).unwrap();
+    ledger.advance_to_next_block(&block).unwrap();
+    assert_eq!(block.transactions().len(), 1);
+
+    // Ensure the block is rejected once it exists in the ledger, even if the transactions are skipped.
+    assert!(ledger.check_next_block_with_options(&block, &options, rng).is_err());
+}
+
+#[test]
+fn test_signal_consensus_version() {
+    let rng = &mut TestRng::default();
+
+    // Initialize the test environment.
+    let crate::test_helpers::TestEnv { ledger, private_key, .. } = crate::test_helpers::sample_test_env(rng);
+
+    // Ensure an unsupported consensus version can not be signaled.
+    assert!(ledger.signal_consensus_version(crate::LATEST_CONSENSUS_VERSION + 1).is_err());
+    // Ensure no consensus version is active, as the signaling window is not full.
+    assert_eq!(ledger.latest_active_consensus_version(), 0);
+
+    // Signal the latest consensus version.
+    ledger.signal_consensus_version(crate::LATEST_CONSENSUS_VERSION).unwrap();
+    assert_eq!(ledger.next_signaled_consensus_version(), crate::LATEST_CONSENSUS_VERSION);
+
+    // Ensure the next block signals the consensus version, and is valid.
+    let block = ledger.prepare_advance_to_next_beacon_block(&private_key, vec![], vec![], vec![], rng).unwrap();
+    assert_eq!(block.consensus_version(), crate::LATEST_CONSENSUS_VERSION);
+    ledger.check_next_block(&block, rng).unwrap();
+    ledger.advance_to_next_block(&block).unwrap();
+
+    // Ensure the signaling window matches the consensus versions signaled in storage.
+    let height = ledger.latest_height();
+    let expected =
+        (0..=height).map(|height| ledger.get_header(height).unwrap().consensus_version()).collect::<Vec<_>>();
+    assert_eq!(ledger.consensus_version_signals.read().iter().copied().collect::<Vec<_>>(), expected);
+    assert_eq!(ledger.get_active_consensus_version(height + 1).unwrap(), crate::active_consensus_version(&expected));
+}
+
+#[test]
+fn test_aborted_transaction_reasons_before_activation() {
+    let rng = &mut TestRng::default();
+
+    // Initialize the test environment.
+    let crate::test_helpers::TestEnv { ledger, private_key, address, .. } = crate::test_helpers::sample_test_env(rng);
+
+    // Sample a transaction, and a fee transaction of its fee, which is aborted.
+    let inputs = [Value::from_str(&format!("{address}")).unwrap(), Value::from_str("1u64").unwrap()];
+    let transaction = ledger
+        .vm
+        .execute(&private_key, ("credits.aleo", "transfer_public"), inputs.iter(), None, 0, None, rng)
+        .unwrap();
+    let fee_transaction = Transaction::from_fee(transaction.fee_transition().unwrap()).unwrap();
+
+    // Ensure the block does not record the reason for the aborted transaction, as the reasons are not active.
+    ledger.signal_consensus_version(crate::ABORTED_TRANSACTION_REASONS_CONSENSUS_VERSION).unwrap();
+    let block = ledger
+        .prepare_advance_to_next_beacon_block(
+            &private_key,
+            vec![],
+            vec![],
+            vec![fee_transaction.clone(), transaction],
+            rng,
+        )
+        .unwrap();
+    assert!(!ledger.records_aborted_transaction_reasons(block.height()).unwrap());
+    assert_eq!(block.aborted_transaction_ids(), &[fee_transaction.id()]);
+    assert!(block.aborted_transaction_reasons().is_empty());
+    ledger.check_next_block(&block, rng).unwrap();
+
+    // Ensure a block that records the reason for the aborted transaction is rejected.
+    // Note: The reasons are not committed to by the block hash, so the block hash is unchanged.
+    let aborted_transaction_reasons = vec![AbortReason::FeeTransaction];
+    let invalid_block = Block::new_beacon_with_aborted_transaction_reasons(
+        &private_key,
+        block.previous_hash(),
+        *block.header(),
+        block.ratifications().clone(),
+        block.solutions().clone(),
+        block.aborted_solution_ids().clone(),
+        block.transactions().clone(),
+        block.aborted_transaction_ids().clone(),
+        aborted_transaction_reasons,
+        rng,
+    )
+    .unwrap();
+    assert_eq!(invalid_block.hash(), block.hash());
+    assert!(ledger.check_next_block(&invalid_block, rng).is_err());
+}
+
 #[test]
 fn test_insufficient_private_fees() {
     let rng = &mut TestRng::default();
@@ -1023,6 +1229,9 @@ finalize foo:
     execution_ids.push(mutated_transaction.id());
     executions.push(mutated_transaction);
 
+    // Track the transactions, to check the partially-verified cache.
+    let transactions: HashMap<_, _> = executions.iter().chain(&deployments).map(|tx| (tx.id(), tx.clone())).collect();
+
     // Create a block.
     let block = ledger
         .prepare_advance_to_next_beacon_block(
@@ -1061,15 +1270,15 @@ finalize foo:
     ]);
 
     // Ensure that verification was not run on aborted deployments.
-    let partially_verified_transaction = ledger.vm().partially_verified_transactions().read().clone();
-
-    assert!(partially_verified_transaction.contains(&execution_ids[2]));
-    assert!(partially_verified_transaction.contains(&deployment_ids[2]));
-    assert!(!partially_verified_transaction.contains(&execution_ids[1]));
-    assert!(!partially_verified_transaction.contains(&deployment_ids[1]));
-    assert!(!partially_verified_transaction.contains(&execution_ids[3]));
-    assert!(!partially_verified_transaction.contains(&execution_ids[4])); // Verification was run, but the execution was invalid.
-    assert!(!partially_verified_transaction.contains(&execution_ids[5]));
+    let is_partially_verified =
+        |id: &<CurrentNetwork as Network>::TransactionID| ledger.vm().is_partially_verified(&transactions[id]);
+    assert!(is_partially_verified(&execution_ids[2]));
+    assert!(is_partially_verified(&deployment_ids[2]));
+    assert!(!is_partially_verified(&execution_ids[1]));
+    assert!(!is_partially_verified(&deployment_ids[1]));
+    assert!(!is_partially_verified(&execution_ids[3]));
+    assert!(!is_partially_verified(&execution_ids[4])); // Verification was run, but the execution was invalid.
+    assert!(!is_partially_verified(&execution_ids[5]));
 
     // Prepare a transfer that will succeed for the subsequent block.
     let inputs = [Value::from_str(&format!("{address}")).unwrap(), Value::from_str("1000u64").unwrap()];
@@ -1085,7 +1294,7 @@ finalize foo:
             &private_key,
             vec![],
             vec![],
-            vec![executions.pop().unwrap(), deployments.pop().unwrap(), transfer],
+            vec![executions.pop().unwrap(), deployments.pop().unwrap(), transfer.clone()],
             rng,
         )
         .unwrap();
@@ -1102,10 +1311,9 @@ finalize foo:
     assert_eq!(block.aborted_transaction_ids(), &vec![execution_ids[0], deployment_ids[0]]);
 
     // Ensure that verification was not run on transactions aborted in a previous block.
-    let partially_verified_transaction = ledger.vm().partially_verified_transactions().read().clone();
-    assert!(partially_verified_transaction.contains(&transfer_id));
-    assert!(!partially_verified_transaction.contains(&execution_ids[0]));
-    assert!(!partially_verified_transaction.contains(&deployment_ids[0]));
+    assert!(ledger.vm().is_partially_verified(&transfer));
+    assert!(!is_partially_verified(&execution_ids[0]));
+    assert!(!is_partially_verified(&deployment_ids[0]));
 }
 
 #[test]
@@ -1291,7 +1499,7 @@ function create_duplicate_record:
             &private_key,
             vec![],
             vec![],
-            vec![transfer_1, transfer_2, deployment_1, deployment_2],
+            vec![transfer_1.clone(), transfer_2.clone(), deployment_1.clone(), deployment_2.clone()],
             rng,
         )
         .unwrap();
@@ -1308,11 +1516,10 @@ function create_duplicate_record:
     assert_eq!(block.aborted_transaction_ids(), &vec![transfer_2_id, deployment_2_id]);
 
     // Ensure that verification was not run on aborted deployments.
-    let partially_verified_transaction = ledger.vm().partially_verified_transactions().read().clone();
-    assert!(partially_verified_transaction.contains(&transfer_1_id));
-    assert!(partially_verified_transaction.contains(&deployment_1_id));
-    assert!(!partially_verified_transaction.contains(&transfer_2_id));
-    assert!(!partially_verified_transaction.contains(&deployment_2_id));
+    assert!(ledger.vm().is_partially_verified(&transfer_1));
+    assert!(ledger.vm().is_partially_verified(&deployment_1));
+    assert!(!ledger.vm().is_partially_verified(&transfer_2));
+    assert!(!ledger.vm().is_partially_verified(&deployment_2));
 
     // Prepare a transfer that will succeed for the subsequent block.
     let inputs = [Value::from_str(&format!("{address}")).unwrap(), Value::from_str("1000u64").unwrap()];
@@ -1328,7 +1535,7 @@ function create_duplicate_record:
             &private_key,
             vec![],
             vec![],
-            vec![transfer_3, transfer_4, deployment_3],
+            vec![transfer_3.clone(), transfer_4.clone(), deployment_3.clone()],
             rng,
         )
         .unwrap();
@@ -1345,10 +1552,9 @@ function create_duplicate_record:
     assert_eq!(block.aborted_transaction_ids(), &vec![transfer_3_id, deployment_3_id]);
 
     // Ensure that verification was not run on transactions aborted in a previous block.
-    let partially_verified_transaction = ledger.vm().partially_verified_transactions().read().clone();
-    assert!(partially_verified_transaction.contains(&transfer_4_id));
-    assert!(!partially_verified_transaction.contains(&transfer_3_id));
-    assert!(!partially_verified_transaction.contains(&deployment_3_id));
+    assert!(ledger.vm().is_partially_verified(&transfer_4));
+    assert!(!ledger.vm().is_partially_verified(&transfer_3));
+    assert!(!ledger.vm().is_partially_verified(&deployment_3));
 }
 
 #[test]
@@ -1445,7 +1651,13 @@ function empty_function:
 
     // Create a block.
     let block = ledger
-        .prepare_advance_to_next_beacon_block(&private_key, vec![], vec![], vec![transaction_1, transaction_2], rng)
+        .prepare_advance_to_next_beacon_block(
+            &private_key,
+            vec![],
+            vec![],
+            vec![transaction_1.clone(), transaction_2.clone()],
+            rng,
+        )
         .unwrap();
 
     // Check that the next block is valid.
@@ -1459,10 +1671,9 @@ function empty_function:
     assert_eq!(block.transactions().transaction_ids().collect::<Vec<_>>(), vec![&transaction_1_id]);
     assert_eq!(block.aborted_transaction_ids(), &vec![transaction_2_id]);
 
-    // Ensure that verification was not run on aborted transactions.
-    let partially_verified_transaction = ledger.vm().partially_verified_transactions().read().clone();
-    assert!(partially_verified_transaction.contains(&transaction_1_id));
-    assert!(!partially_verified_transaction.contains(&transaction_2_id));
+    // Ensure that the aborted transaction is considered partially-verified, as it re-uses the verified execution.
+    assert!(ledger.vm().is_partially_verified(&transaction_1));
+    assert!(ledger.vm().is_partially_verified(&transaction_2));
 
     // Prepare a transfer that will succeed for the subsequent block.
     let inputs = [Value::from_str(&format!("{address}")).unwrap(), Value::from_str("1000u64").unwrap()];
@@ -1478,7 +1689,7 @@ function empty_function:
             &private_key,
             vec![],
             vec![],
-            vec![transaction_3, transfer_transaction],
+            vec![transaction_3.clone(), transfer_transaction.clone()],
             rng,
         )
         .unwrap();
@@ -1494,10 +1705,9 @@ function empty_function:
     assert_eq!(block.transactions().transaction_ids().collect::<Vec<_>>(), vec![&transfer_transaction_id]);
     assert_eq!(block.aborted_transaction_ids(), &vec![transaction_3_id]);
 
-    // Ensure that verification was not run on transactions aborted in a previous block.
-    let partially_verified_transaction = ledger.vm().partially_verified_transactions().read().clone();
-    assert!(partially_verified_transaction.contains(&transfer_transaction_id));
-    assert!(!partially_verified_transaction.contains(&transaction_3_id));
+    // Ensure that the aborted transaction is considered partially-verified, as it re-uses the verified execution.
+    assert!(ledger.vm().is_partially_verified(&transfer_transaction));
+    assert!(ledger.vm().is_partially_verified(&transaction_3));
 }
 
 #[test]
@@ -1595,7 +1805,13 @@ function simple_output:
 
     // Create a block.
     let block = ledger
-        .prepare_advance_to_next_beacon_block(&private_key, vec![], vec![], vec![transaction_1, transaction_2], rng)
+        .prepare_advance_to_next_beacon_block(
+            &private_key,
+            vec![],
+            vec![],
+            vec![transaction_1.clone(), transaction_2.clone()],
+            rng,
+        )
         .unwrap();
 
     // Check that the next block is valid.
@@ -1610,9 +1826,8 @@ function simple_output:
     assert_eq!(block.aborted_transaction_ids(), &vec![transaction_2_id]);
 
     // Ensure that verification was not run on aborted transactions.
-    let partially_verified_transaction = ledger.vm().partially_verified_transactions().read().clone();
-    assert!(partially_verified_transaction.contains(&transaction_1_id));
-    assert!(!partially_verified_transaction.contains(&transaction_2_id));
+    assert!(ledger.vm().is_partially_verified(&transaction_1));
+    assert!(!ledger.vm().is_partially_verified(&transaction_2));
 
     // Prepare a transfer that will succeed for the subsequent block.
     let inputs = [Value::from_str(&format!("{address}")).unwrap(), Value::from_str("1000u64").unwrap()];
@@ -1628,7 +1843,7 @@ function simple_output:
             &private_key,
             vec![],
             vec![],
-            vec![transaction_3, transfer_transaction],
+            vec![transaction_3.clone(), transfer_transaction.clone()],
             rng,
         )
         .unwrap();
@@ -1645,9 +1860,8 @@ function simple_output:
     assert_eq!(block.aborted_transaction_ids(), &vec![transaction_3_id]);
 
     // Ensure that verification was not run on transactions aborted in a previous block.
-    let partially_verified_transaction = ledger.vm().partially_verified_transactions().read().clone();
-    assert!(partially_verified_transaction.contains(&transfer_transaction_id));
-    assert!(!partially_verified_transaction.contains(&transaction_3_id));
+    assert!(ledger.vm().is_partially_verified(&transfer_transaction));
+    assert!(!ledger.vm().is_partially_verified(&transaction_3));
 }
 
 #[test]
@@ -1686,7 +1900,13 @@ function empty_function:
 
     // Create a block.
     let block = ledger
-        .prepare_advance_to_next_beacon_block(&private_key, vec![], vec![], vec![deployment_1, deployment_2], rng)
+        .prepare_advance_to_next_beacon_block(
+            &private_key,
+            vec![],
+            vec![],
+            vec![deployment_1.clone(), deployment_2.clone()],
+            rng,
+        )
         .unwrap();
 
     // Check that the next block is valid.
@@ -1705,9 +1925,8 @@ function empty_function:
     assert!(ledger.vm.block_store().contains_rejected_or_aborted_transaction_id(&deployment_2_id).unwrap());
 
     // Ensure that verification was not run on aborted transactions.
-    let partially_verified_transaction = ledger.vm().partially_verified_transactions().read().clone();
-    assert!(partially_verified_transaction.contains(&deployment_1_id));
-    assert!(!partially_verified_transaction.contains(&deployment_2_id));
+    assert!(ledger.vm().is_partially_verified(&deployment_1));
+    assert!(!ledger.vm().is_partially_verified(&deployment_2));
 }
 
 #[test]
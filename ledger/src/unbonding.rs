@@ -0,0 +1,145 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use super::*;
+
+impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
+    /// Returns the withdrawal address of the given staker, if the staker is bonded or unbonding.
+    pub fn get_withdrawal_address(&self, staker: &Address<N>) -> Result<Option<Address<N>>> {
+        // Retrieve the withdrawal address from the `withdraw` mapping.
+        match self.get_credits_value("withdraw", staker)? {
+            Some(value) => Ok(Some(withdraw_value_into_address(value)?)),
+            None => Ok(None),
+        }
+    }
+
+    /// Returns the unbonding state of the given staker, if the staker is unbonding.
+    pub fn get_unbonding_state(&self, staker: &Address<N>) -> Result<Option<UnbondingState>> {
+        // Retrieve the unbonding state from the `unbonding` mapping.
+        match self.get_credits_value("unbonding", staker)? {
+            Some(value) => Ok(Some(unbonding_value_into_state(value)?)),
+            None => Ok(None),
+        }
+    }
+
+    /// Returns the unbonding states of all unbonding stakers.
+    pub fn get_unbonding_states(&self) -> Result<IndexMap<Address<N>, UnbondingState>> {
+        // Retrieve the `unbonding` mapping.
+        let unbonding_map = self
+            .vm
+            .finalize_store()
+            .get_mapping_confirmed(ProgramID::from_str("credits.aleo")?, Identifier::from_str("unbonding")?)?;
+        // Convert the entries into unbonding states.
+        unbonding_map
+            .into_iter()
+            .map(|(key, value)| Ok((staker_key_into_address(key)?, unbonding_value_into_state(value)?)))
+            .collect()
+    }
+
+    /// Returns the withdrawal schedule of the given address, which consists of every staker that withdraws to
+    /// the address, along with its unbonding state, if the staker is unbonding.
+    ///
+    /// Note: This method iterates over the `withdraw` mapping.
+    pub fn get_withdrawal_schedule(&self, withdrawal_address: &Address<N>) -> Result<WithdrawalSchedule<N>> {
+        // Retrieve the `withdraw` mapping.
+        let withdraw_map = self
+            .vm
+            .finalize_store()
+            .get_mapping_confirmed(ProgramID::from_str("credits.aleo")?, Identifier::from_str("withdraw")?)?;
+
+        // Select the stakers that withdraw to the given address, and retrieve their unbonding states.
+        let mut stakers = IndexMap::new();
+        for (key, value) in withdraw_map {
+            if withdraw_value_into_address(value)? == *withdrawal_address {
+                let staker = staker_key_into_address(key)?;
+                stakers.insert(staker, self.get_unbonding_state(&staker)?);
+            }
+        }
+        Ok(WithdrawalSchedule::new(*withdrawal_address, self.latest_height(), stakers))
+    }
+}
+
+impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
+    /// Returns the confirmed value for the given address in the given `credits.aleo` mapping.
+    fn get_credits_value(&self, mapping: &str, address: &Address<N>) -> Result<Option<Value<N>>> {
+        self.vm.finalize_store().get_value_confirmed(
+            ProgramID::from_str("credits.aleo")?,
+            Identifier::from_str(mapping)?,
+            &Plaintext::from(Literal::Address(*address)),
+        )
+    }
+}
+
+/// Returns the staker, given a key of the `withdraw` or `unbonding` mapping.
+fn staker_key_into_address<N: Network>(key: Plaintext<N>) -> Result<Address<N>> {
+    match key {
+        Plaintext::Literal(Literal::Address(staker), _) => Ok(staker),
+        _ => bail!("Invalid staker key in finalize storage - {key}"),
+    }
+}
+
+/// Returns the withdrawal address, given a value of the `withdraw` mapping.
+fn withdraw_value_into_address<N: Network>(value: Value<N>) -> Result<Address<N>> {
+    match value {
+        Value::Plaintext(Plaintext::Literal(Literal::Address(address), _)) => Ok(address),
+        _ => bail!("Invalid withdraw value (missing address) - {value}"),
+    }
+}
+
+/// Returns the unbonding state, given a value of the `unbonding` mapping.
+fn unbonding_value_into_state<N: Network>(value: Value<N>) -> Result<UnbondingState> {
+    let Value::Plaintext(Plaintext::Struct(state, _)) = &value else {
+        bail!("Invalid unbonding value (missing struct) - {value}")
+    };
+    // Extract the microcredits and the unlock height from the unbond state.
+    match (state.get(&Identifier::from_str("microcredits")?), state.get(&Identifier::from_str("height")?)) {
+        (
+            Some(Plaintext::Literal(Literal::U64(microcredits), _)),
+            Some(Plaintext::Literal(Literal::U32(height), _)),
+        ) => Ok(UnbondingState::new(**microcredits, **height)),
+        _ => bail!("Invalid unbond state (missing microcredits or height) - {value}"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helpers::CurrentLedger;
+    use console::network::MainnetV0;
+
+    type CurrentNetwork = MainnetV0;
+
+    #[test]
+    fn test_withdrawal_queries_at_genesis() {
+        // Load the genesis block.
+        let genesis = Block::from_bytes_le(CurrentNetwork::genesis_bytes()).unwrap();
+
+        // Initialize a new ledger.
+        let ledger = CurrentLedger::load(genesis, StorageMode::Production).unwrap();
+
+        // Ensure no staker is unbonding at genesis.
+        assert!(ledger.get_unbonding_states().unwrap().is_empty());
+
+        // Ensure each committee member has a withdrawal address, with a schedule that includes the member.
+        let committee = ledger.latest_committee().unwrap();
+        for member in committee.members().keys() {
+            assert!(ledger.get_unbonding_state(member).unwrap().is_none());
+            let withdrawal_address = ledger.get_withdrawal_address(member).unwrap().unwrap();
+            let schedule = ledger.get_withdrawal_schedule(&withdrawal_address).unwrap();
+            assert_eq!(schedule.block_height(), 0);
+            assert_eq!(schedule.stakers().get(member), Some(&None));
+            assert_eq!(schedule.total_unbonding(), 0);
+        }
+    }
+}
@@ -33,10 +33,13 @@ use console::{
 };
 use ledger_authority::Authority;
 use ledger_block::{
+    AbortReason,
     Block,
     ConfirmedTransaction,
     Header,
     Ratifications,
+    Receipt,
+    ReceiptBatch,
     Rejected,
     Solutions,
     Transaction,
@@ -49,7 +52,11 @@ use synthesizer_program::{FinalizeOperation, Program};
 use aleo_std_storage::StorageMode;
 use anyhow::Result;
 use parking_lot::RwLock;
-use std::{borrow::Cow, sync::Arc};
+use std::{
+    borrow::Cow,
+    collections::{btree_map, BTreeMap},
+    sync::Arc,
+};
 
 #[cfg(not(feature = "serial"))]
 use rayon::prelude::*;
@@ -134,10 +141,16 @@ pub trait BlockStorage<N: Network>: 'static + Clone + Send + Sync {
     type TransactionsMap: for<'a> Map<'a, N::BlockHash, Vec<N::TransactionID>>;
     /// The mapping of `block hash` to `[aborted transaction ID]`.
     type AbortedTransactionIDsMap: for<'a> Map<'a, N::BlockHash, Vec<N::TransactionID>>;
+    /// The mapping of `block hash` to `[aborted transaction reason]`.
+    type AbortedTransactionReasonsMap: for<'a> Map<'a, N::BlockHash, Vec<AbortReason>>;
     /// The mapping of rejected or aborted `transaction ID` to `block hash`.
     type RejectedOrAbortedTransactionIDMap: for<'a> Map<'a, N::TransactionID, N::BlockHash>;
     /// The mapping of `transaction ID` to `(block hash, confirmed tx type, finalize operations)`.
-    type ConfirmedTransactionsMap: for<'a> Map<'a, N::TransactionID, (N::BlockHash, ConfirmedTxType<N>, Vec<FinalizeOperation<N>>)>;
+    type ConfirmedTransactionsMap: for<'a> Map<
+        'a,
+        N::TransactionID,
+        (N::BlockHash, ConfirmedTxType<N>, Vec<FinalizeOperation<N>>),
+    >;
     /// The rejected deployment or execution map.
     type RejectedDeploymentOrExecutionMap: for<'a> Map<'a, Field<N>, Rejected<N>>;
     /// The transaction storage.
@@ -176,6 +189,8 @@ pub trait BlockStorage<N: Network>: 'static + Clone + Send + Sync {
     fn transactions_map(&self) -> &Self::TransactionsMap;
     /// Returns the aborted transaction IDs map.
     fn aborted_transaction_ids_map(&self) -> &Self::AbortedTransactionIDsMap;
+    /// Returns the aborted transaction reasons map.
+    fn aborted_transaction_reasons_map(&self) -> &Self::AbortedTransactionReasonsMap;
     /// Returns the rejected or aborted transaction ID map.
     fn rejected_or_aborted_transaction_id_map(&self) -> &Self::RejectedOrAbortedTransactionIDMap;
     /// Returns the confirmed transactions map.
@@ -211,6 +226,7 @@ pub trait BlockStorage<N: Network>: 'static + Clone + Send + Sync {
         self.aborted_solution_heights_map().start_atomic();
         self.transactions_map().start_atomic();
         self.aborted_transaction_ids_map().start_atomic();
+        self.aborted_transaction_reasons_map().start_atomic();
         self.rejected_or_aborted_transaction_id_map().start_atomic();
         self.confirmed_transactions_map().start_atomic();
         self.rejected_deployment_or_execution_map().start_atomic();
@@ -233,6 +249,7 @@ pub trait BlockStorage<N: Network>: 'static + Clone + Send + Sync {
             || self.aborted_solution_heights_map().is_atomic_in_progress()
             || self.transactions_map().is_atomic_in_progress()
             || self.aborted_transaction_ids_map().is_atomic_in_progress()
+            || self.aborted_transaction_reasons_map().is_atomic_in_progress()
             || self.rejected_or_aborted_transaction_id_map().is_atomic_in_progress()
             || self.confirmed_transactions_map().is_atomic_in_progress()
             || self.rejected_deployment_or_execution_map().is_atomic_in_progress()
@@ -255,6 +272,7 @@ pub trait BlockStorage<N: Network>: 'static + Clone + Send + Sync {
         self.aborted_solution_heights_map().atomic_checkpoint();
         self.transactions_map().atomic_checkpoint();
         self.aborted_transaction_ids_map().atomic_checkpoint();
+        self.aborted_transaction_reasons_map().atomic_checkpoint();
         self.rejected_or_aborted_transaction_id_map().atomic_checkpoint();
         self.confirmed_transactions_map().atomic_checkpoint();
         self.rejected_deployment_or_execution_map().atomic_checkpoint();
@@ -277,6 +295,7 @@ pub trait BlockStorage<N: Network>: 'static + Clone + Send + Sync {
         self.aborted_solution_heights_map().clear_latest_checkpoint();
         self.transactions_map().clear_latest_checkpoint();
         self.aborted_transaction_ids_map().clear_latest_checkpoint();
+        self.aborted_transaction_reasons_map().clear_latest_checkpoint();
         self.rejected_or_aborted_transaction_id_map().clear_latest_checkpoint();
         self.confirmed_transactions_map().clear_latest_checkpoint();
         self.rejected_deployment_or_execution_map().clear_latest_checkpoint();
@@ -299,6 +318,7 @@ pub trait BlockStorage<N: Network>: 'static + Clone + Send + Sync {
         self.aborted_solution_heights_map().atomic_rewind();
         self.transactions_map().atomic_rewind();
         self.aborted_transaction_ids_map().atomic_rewind();
+        self.aborted_transaction_reasons_map().atomic_rewind();
         self.rejected_or_aborted_transaction_id_map().atomic_rewind();
         self.confirmed_transactions_map().atomic_rewind();
         self.rejected_deployment_or_execution_map().atomic_rewind();
@@ -321,6 +341,7 @@ pub trait BlockStorage<N: Network>: 'static + Clone + Send + Sync {
         self.aborted_solution_heights_map().abort_atomic();
         self.transactions_map().abort_atomic();
         self.aborted_transaction_ids_map().abort_atomic();
+        self.aborted_transaction_reasons_map().abort_atomic();
         self.rejected_or_aborted_transaction_id_map().abort_atomic();
         self.confirmed_transactions_map().abort_atomic();
         self.rejected_deployment_or_execution_map().abort_atomic();
@@ -343,6 +364,7 @@ pub trait BlockStorage<N: Network>: 'static + Clone + Send + Sync {
         self.aborted_solution_heights_map().finish_atomic()?;
         self.transactions_map().finish_atomic()?;
         self.aborted_transaction_ids_map().finish_atomic()?;
+        self.aborted_transaction_reasons_map().finish_atomic()?;
         self.rejected_or_aborted_transaction_id_map().finish_atomic()?;
         self.confirmed_transactions_map().finish_atomic()?;
         self.rejected_deployment_or_execution_map().finish_atomic()?;
@@ -438,6 +460,12 @@ pub trait BlockStorage<N: Network>: 'static + Clone + Send + Sync {
                 self.rejected_or_aborted_transaction_id_map().insert(*aborted_transaction_id, block.hash())?;
             }
 
+            // Store the aborted transaction reasons, if they are recorded.
+            if !block.aborted_transaction_reasons().is_empty() {
+                self.aborted_transaction_reasons_map()
+                    .insert(block.hash(), block.aborted_transaction_reasons().clone())?;
+            }
+
             // Store the rejected transactions IDs.
             for rejected_transaction_id in rejected_transaction_ids {
                 self.rejected_or_aborted_transaction_id_map().insert(rejected_transaction_id, block.hash())?;
@@ -569,6 +597,9 @@ pub trait BlockStorage<N: Network>: 'static + Clone + Send + Sync {
                 self.rejected_or_aborted_transaction_id_map().remove(&aborted_transaction_id)?;
             }
 
+            // Remove the aborted transaction reasons.
+            self.aborted_transaction_reasons_map().remove(block_hash)?;
+
             // Remove the rejected state.
             for (rejected_transaction_id, rejected_id) in rejected_transaction_ids_and_deployment_or_execution_id {
                 // Remove the rejected transaction ID.
@@ -879,6 +910,15 @@ pub trait BlockStorage<N: Network>: 'static + Clone + Send + Sync {
         }
     }
 
+    /// Returns the block aborted transaction reasons for the given `block hash`.
+    /// Note: The reasons are empty if they were not recorded in the block.
+    fn get_block_aborted_transaction_reasons(&self, block_hash: &N::BlockHash) -> Result<Vec<AbortReason>> {
+        match self.aborted_transaction_reasons_map().get_confirmed(block_hash)? {
+            Some(aborted_transaction_reasons) => Ok(cow_to_cloned!(aborted_transaction_reasons)),
+            None => Ok(vec![]),
+        }
+    }
+
     /// Returns the transaction for the given `transaction ID`.
     fn get_transaction(&self, transaction_id: &N::TransactionID) -> Result<Option<Transaction<N>>> {
         // Check if the transaction was rejected or aborted.
@@ -983,9 +1023,11 @@ pub trait BlockStorage<N: Network>: 'static + Clone + Send + Sync {
         let Some(aborted_transaction_ids) = self.get_block_aborted_transaction_ids(block_hash)? else {
             bail!("Missing aborted transaction IDs for block {height} ('{block_hash}')");
         };
+        // Retrieve the block aborted transaction reasons.
+        let aborted_transaction_reasons = self.get_block_aborted_transaction_reasons(block_hash)?;
 
         // Return the block.
-        Ok(Some(Block::from(
+        Ok(Some(Block::from_with_aborted_transaction_reasons(
             previous_hash,
             header,
             authority,
@@ -994,6 +1036,7 @@ pub trait BlockStorage<N: Network>: 'static + Clone + Send + Sync {
             aborted_solution_ids,
             transactions,
             aborted_transaction_ids,
+            aborted_transaction_reasons,
         )?))
     }
 }
@@ -1039,16 +1082,18 @@ impl<N: Network, B: BlockStorage<N>> BlockStore<N, B> {
     pub fn insert(&self, block: &Block<N>) -> Result<()> {
         // Acquire the write lock on the block tree.
         let mut tree = self.tree.write();
-        // Prepare an updated Merkle tree containing the new block hash.
-        let updated_tree = tree.prepare_append(&[block.hash().to_bits_le()])?;
         // Ensure the next block height is correct.
-        if block.height() != u32::try_from(updated_tree.number_of_leaves())? - 1 {
+        if block.height() != u32::try_from(tree.number_of_leaves())? {
             bail!("Attempted to insert a block at the incorrect height into storage")
         }
+        // Append the new block hash to the block tree, which only recomputes the path of the new leaf.
+        tree.append(&[block.hash().to_bits_le()])?;
         // Insert the (state root, block height) pair.
-        self.storage.insert((*updated_tree.root()).into(), block)?;
-        // Update the block tree.
-        *tree = updated_tree;
+        if let Err(error) = self.storage.insert((*tree.root()).into(), block) {
+            // Revert the block tree, as the block was not inserted.
+            tree.remove_last_n(1)?;
+            return Err(error);
+        }
         // Return success.
         Ok(())
     }
@@ -1205,16 +1250,90 @@ impl<N: Network, B: BlockStorage<N>> BlockStore<N, B> {
         u32::try_from(self.tree.read().number_of_leaves()).unwrap() - 1
     }
 
+    /// Returns the current block height, or `None` if the block store is empty.
+    pub fn try_current_block_height(&self) -> Option<u32> {
+        u32::try_from(self.tree.read().number_of_leaves()).ok()?.checked_sub(1)
+    }
+
     /// Returns the state root that contains the given `block height`.
     pub fn get_state_root(&self, block_height: u32) -> Result<Option<N::StateRoot>> {
         self.storage.get_state_root(block_height)
     }
 
+    /// Returns the state roots of the `n` most recent blocks, from the latest block to the oldest.
+    pub fn recent_state_roots(&self, n: u32) -> Result<Vec<N::StateRoot>> {
+        let Some(latest_height) = self.try_current_block_height() else { return Ok(vec![]) };
+        (0..n.min(latest_height.saturating_add(1)))
+            .map(|offset| {
+                let height = latest_height - offset;
+                self.get_state_root(height)?.ok_or_else(|| anyhow!("Missing the state root for block {height}"))
+            })
+            .collect()
+    }
+
     /// Returns a state path for the given `commitment`.
     pub fn get_state_path_for_commitment(&self, commitment: &Field<N>) -> Result<StatePath<N>> {
         self.storage.get_state_path_for_commitment(commitment, &self.tree.read())
     }
 
+    /// Returns a receipt proving the inclusion of the given `transaction ID` under the current state root.
+    pub fn get_receipt(&self, transaction_id: &N::TransactionID) -> Result<Receipt<N>> {
+        // Find the block that contains the transaction.
+        let block_hash = match self.find_block_hash(transaction_id)? {
+            Some(block_hash) => block_hash,
+            None => bail!("The block hash for transaction '{transaction_id}' is missing in storage"),
+        };
+        // Retrieve the block.
+        let block = match self.get_block(&block_hash)? {
+            Some(block) => block,
+            None => bail!("The block '{block_hash}' for transaction '{transaction_id}' is missing in storage"),
+        };
+        // Construct the block path.
+        let block_path = self.tree.read().prove(block.height() as usize, &block_hash.to_bits_le())?;
+        // Construct the receipt.
+        block.to_receipt(*transaction_id, block_path)
+    }
+
+    /// Returns a receipt batch proving the inclusion of the given `transaction IDs` under the current state root.
+    pub fn get_receipt_batch(&self, transaction_ids: &[N::TransactionID]) -> Result<ReceiptBatch<N>> {
+        // Group the transaction IDs by the height of the block that contains them.
+        let mut blocks = BTreeMap::<u32, (Block<N>, Vec<N::TransactionID>)>::new();
+        for transaction_id in transaction_ids {
+            // Find the block that contains the transaction.
+            let block_hash = match self.find_block_hash(transaction_id)? {
+                Some(block_hash) => block_hash,
+                None => bail!("The block hash for transaction '{transaction_id}' is missing in storage"),
+            };
+            let block_height = match self.get_block_height(&block_hash)? {
+                Some(block_height) => block_height,
+                None => bail!("The block height for transaction '{transaction_id}' is missing in storage"),
+            };
+            match blocks.entry(block_height) {
+                btree_map::Entry::Occupied(mut entry) => entry.get_mut().1.push(*transaction_id),
+                btree_map::Entry::Vacant(entry) => {
+                    // Retrieve the block.
+                    let block = match self.get_block(&block_hash)? {
+                        Some(block) => block,
+                        None => {
+                            bail!("The block '{block_hash}' for transaction '{transaction_id}' is missing in storage")
+                        }
+                    };
+                    entry.insert((block, vec![*transaction_id]));
+                }
+            }
+        }
+        // Construct the block receipts.
+        let block_receipts = blocks
+            .values()
+            .map(|(block, transaction_ids)| block.to_block_receipt(transaction_ids))
+            .collect::<Result<Vec<_>>>()?;
+        // Construct the block multi-path.
+        let leaves = blocks.iter().map(|(height, (block, _))| (*height as usize, block.hash().to_bits_le())).collect();
+        let block_path = self.tree.read().prove_many(&leaves)?;
+        // Construct the receipt batch.
+        Ok(ReceiptBatch::from(block_receipts, block_path))
+    }
+
     /// Returns the previous block hash of the given `block height`.
     pub fn get_previous_block_hash(&self, height: u32) -> Result<Option<N::BlockHash>> {
         self.storage.get_previous_block_hash(height)
@@ -1268,6 +1387,11 @@ impl<N: Network, B: BlockStorage<N>> BlockStore<N, B> {
         self.storage.get_block_aborted_transaction_ids(block_hash)
     }
 
+    /// Returns the block aborted transaction reasons for the given `block hash`.
+    pub fn get_block_aborted_transaction_reasons(&self, block_hash: &N::BlockHash) -> Result<Vec<AbortReason>> {
+        self.storage.get_block_aborted_transaction_reasons(block_hash)
+    }
+
     /// Returns the transaction for the given `transaction ID`.
     pub fn get_transaction(&self, transaction_id: &N::TransactionID) -> Result<Option<Transaction<N>>> {
         self.storage.get_transaction(transaction_id)
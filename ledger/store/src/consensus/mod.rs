@@ -30,6 +30,9 @@ use anyhow::Result;
 use core::marker::PhantomData;
 
 /// A trait for consensus storage.
+///
+/// A storage backend implements this trait (along with the storage traits of its stores) on top of
+/// maps implementing `Map` and `NestedMap`; see `ConsensusMemory`, `ConsensusDB`, and `ConsensusSled`.
 pub trait ConsensusStorage<N: Network>: 'static + Clone + Send + Sync {
     /// The finalize storage.
     type FinalizeStorage: FinalizeStorage<N>;
@@ -13,7 +13,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-/// The RocksDB map prefix broken down into the entry category and the specific type of the entry.
+/// The database map prefix broken down into the entry category and the specific type of the entry.
 // Note: the order of these variants can be changed at any point in time.
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
 #[repr(u16)]
@@ -53,7 +53,7 @@ impl From<MapID> for u16 {
     }
 }
 
-/// The RocksDB map prefix for BFT-related entries.
+/// The database map prefix for BFT-related entries.
 // Note: the order of these variants can be changed at any point in time,
 // as long as the corresponding DataID values remain the same.
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
@@ -63,7 +63,7 @@ pub enum BFTMap {
     AbortedTransmissionIDs = DataID::BFTAbortedTransmissionIDsMap as u16,
 }
 
-/// The RocksDB map prefix for block-related entries.
+/// The database map prefix for block-related entries.
 // Note: the order of these variants can be changed at any point in time,
 // as long as the corresponding DataID values remain the same.
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
@@ -86,9 +86,10 @@ pub enum BlockMap {
     RejectedOrAbortedTransactionID = DataID::BlockRejectedOrAbortedTransactionIDMap as u16,
     ConfirmedTransactions = DataID::BlockConfirmedTransactionsMap as u16,
     RejectedDeploymentOrExecution = DataID::BlockRejectedDeploymentOrExecutionMap as u16,
+    AbortedTransactionReasons = DataID::BlockAbortedTransactionReasonsMap as u16,
 }
 
-/// The RocksDB map prefix for committee-related entries.
+/// The database map prefix for committee-related entries.
 // Note: the order of these variants can be changed at any point in time,
 // as long as the corresponding DataID values remain the same.
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
@@ -99,7 +100,7 @@ pub enum CommitteeMap {
     Committee = DataID::CommitteeMap as u16,
 }
 
-/// The RocksDB map prefix for deployment-related entries.
+/// The database map prefix for deployment-related entries.
 // Note: the order of these variants can be changed at any point in time,
 // as long as the corresponding DataID values remain the same.
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
@@ -114,7 +115,7 @@ pub enum DeploymentMap {
     Certificate = DataID::DeploymentCertificateMap as u16,
 }
 
-/// The RocksDB map prefix for execution-related entries.
+/// The database map prefix for execution-related entries.
 // Note: the order of these variants can be changed at any point in time,
 // as long as the corresponding DataID values remain the same.
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
@@ -125,7 +126,7 @@ pub enum ExecutionMap {
     Inclusion = DataID::ExecutionInclusionMap as u16,
 }
 
-/// The RocksDB map prefix for fee-related entries.
+/// The database map prefix for fee-related entries.
 // Note: the order of these variants can be changed at any point in time,
 // as long as the corresponding DataID values remain the same.
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
@@ -135,7 +136,7 @@ pub enum FeeMap {
     ReverseFee = DataID::FeeReverseFeeMap as u16,
 }
 
-/// The RocksDB map prefix for transition input entries.
+/// The database map prefix for transition input entries.
 // Note: the order of these variants can be changed at any point in time,
 // as long as the corresponding DataID values remain the same.
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
@@ -151,7 +152,7 @@ pub enum TransitionInputMap {
     ExternalRecord = DataID::InputExternalRecordMap as u16,
 }
 
-/// The RocksDB map prefix for transition output entries.
+/// The database map prefix for transition output entries.
 // Note: the order of these variants can be changed at any point in time,
 // as long as the corresponding DataID values remain the same.
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
@@ -168,7 +169,7 @@ pub enum TransitionOutputMap {
     Future = DataID::OutputFutureMap as u16,
 }
 
-/// The RocksDB map prefix for transaction-related entries.
+/// The database map prefix for transaction-related entries.
 // Note: the order of these variants can be changed at any point in time,
 // as long as the corresponding DataID values remain the same.
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
@@ -177,7 +178,7 @@ pub enum TransactionMap {
     ID = DataID::TransactionIDMap as u16,
 }
 
-/// The RocksDB map prefix for transition-related entries.
+/// The database map prefix for transition-related entries.
 // Note: the order of these variants can be changed at any point in time,
 // as long as the corresponding DataID values remain the same.
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
@@ -191,7 +192,7 @@ pub enum TransitionMap {
     SCM = DataID::TransitionSCMMap as u16,
 }
 
-/// The RocksDB map prefix for program-related entries.
+/// The database map prefix for program-related entries.
 // Note: the order of these variants can be changed at any point in time,
 // as long as the corresponding DataID values remain the same.
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
@@ -201,7 +202,7 @@ pub enum ProgramMap {
     KeyValueID = DataID::KeyValueMap as u16,
 }
 
-/// The RocksDB map prefix for test-related entries.
+/// The database map prefix for test-related entries.
 // Note: the order of these variants can be changed at any point in time.
 #[cfg(test)]
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
@@ -214,7 +215,7 @@ pub enum TestMap {
     Test5 = DataID::Test5 as u16,
 }
 
-/// The RocksDB map prefix.
+/// The database map prefix.
 // Note: the order of these variants can NOT be changed once the database is populated:
 // - any new variant MUST be added as the last one (ignoring the Test one)
 // - any deprecated variant MUST remain in its position (it can't be removed)
@@ -293,6 +294,8 @@ enum DataID {
     // Program
     ProgramIDMap,
     KeyValueMap,
+    // Block (continued)
+    BlockAbortedTransactionReasonsMap,
 
     // Testing
     #[cfg(test)]
@@ -22,7 +22,7 @@ use crate::{
 };
 use console::{prelude::*, types::Field};
 use ledger_authority::Authority;
-use ledger_block::{Header, Ratifications, Rejected, Solutions};
+use ledger_block::{AbortReason, Header, Ratifications, Rejected, Solutions};
 use ledger_puzzle::SolutionID;
 use synthesizer_program::FinalizeOperation;
 
@@ -60,6 +60,8 @@ pub struct BlockMemory<N: Network> {
     transactions_map: MemoryMap<N::BlockHash, Vec<N::TransactionID>>,
     /// The aborted transaction IDs map.
     aborted_transaction_ids_map: MemoryMap<N::BlockHash, Vec<N::TransactionID>>,
+    /// The aborted transaction reasons map.
+    aborted_transaction_reasons_map: MemoryMap<N::BlockHash, Vec<AbortReason>>,
     /// The rejected transaction ID or aborted transaction ID map.
     rejected_or_aborted_transaction_id_map: MemoryMap<N::TransactionID, N::BlockHash>,
     /// The confirmed transactions map.
@@ -87,6 +89,7 @@ impl<N: Network> BlockStorage<N> for BlockMemory<N> {
     type AbortedSolutionHeightsMap = MemoryMap<SolutionID<N>, u32>;
     type TransactionsMap = MemoryMap<N::BlockHash, Vec<N::TransactionID>>;
     type AbortedTransactionIDsMap = MemoryMap<N::BlockHash, Vec<N::TransactionID>>;
+    type AbortedTransactionReasonsMap = MemoryMap<N::BlockHash, Vec<AbortReason>>;
     type RejectedOrAbortedTransactionIDMap = MemoryMap<N::TransactionID, N::BlockHash>;
     type ConfirmedTransactionsMap = MemoryMap<N::TransactionID, (N::BlockHash, ConfirmedTxType<N>, Vec<FinalizeOperation<N>>)>;
     type RejectedDeploymentOrExecutionMap = MemoryMap<Field<N>, Rejected<N>>;
@@ -115,6 +118,7 @@ impl<N: Network> BlockStorage<N> for BlockMemory<N> {
             aborted_solution_heights_map: MemoryMap::default(),
             transactions_map: MemoryMap::default(),
             aborted_transaction_ids_map: MemoryMap::default(),
+            aborted_transaction_reasons_map: MemoryMap::default(),
             rejected_or_aborted_transaction_id_map: MemoryMap::default(),
             confirmed_transactions_map: MemoryMap::default(),
             rejected_deployment_or_execution_map: MemoryMap::default(),
@@ -192,6 +196,11 @@ impl<N: Network> BlockStorage<N> for BlockMemory<N> {
         &self.aborted_transaction_ids_map
     }
 
+    /// Returns the aborted transaction reasons map.
+    fn aborted_transaction_reasons_map(&self) -> &Self::AbortedTransactionReasonsMap {
+        &self.aborted_transaction_reasons_map
+    }
+
     /// Returns the rejected transaction ID or aborted transaction ID map.
     fn rejected_or_aborted_transaction_id_map(&self) -> &Self::RejectedOrAbortedTransactionIDMap {
         &self.rejected_or_aborted_transaction_id_map
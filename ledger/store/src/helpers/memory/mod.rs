@@ -22,6 +22,8 @@ pub use block::*;
 mod consensus;
 pub use consensus::*;
 
+mod persist;
+
 mod program;
 pub use program::*;
 
@@ -0,0 +1,181 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{helpers::memory::ConsensusMemory, ConsensusStore};
+use console::{
+    prelude::*,
+    program::{Identifier, Plaintext, ProgramID, Value},
+};
+use ledger_block::Block;
+use ledger_committee::Committee;
+
+use sha2::{Digest, Sha256};
+use std::path::Path;
+
+/// The magic bytes at the start of a persisted in-memory ledger file.
+const MAGIC: [u8; 8] = *b"aleo.mem";
+/// The version of the persisted in-memory ledger file.
+const VERSION: u8 = 1;
+
+/// The contents of a persisted in-memory ledger.
+#[derive(Serialize, Deserialize)]
+#[serde(bound = "")]
+struct PersistedState<N: Network> {
+    /// The network ID.
+    network_id: u16,
+    /// The blocks, in order of block height.
+    blocks: Vec<Block<N>>,
+    /// The committees, in order of block height.
+    committees: Vec<(u32, Committee<N>)>,
+    /// The mappings, as `(program ID, mapping name, entries)`.
+    mappings: Vec<(ProgramID<N>, Identifier<N>, Vec<(Plaintext<N>, Value<N>)>)>,
+}
+
+impl<N: Network> ConsensusStore<N, ConsensusMemory<N>> {
+    /// Writes the state of the in-memory consensus store to a single checksummed file at the given path.
+    ///
+    /// The file is written to a temporary path first and then renamed, so an existing file is never left partially written.
+    pub fn persist<P: AsRef<Path>>(&self, path: P) -> Result<()> {
+        ensure!(!self.is_atomic_in_progress(), "Cannot persist the consensus store during an atomic batch");
+
+        // Retrieve the blocks and committees, in order of block height.
+        let num_blocks = self.block_store().heights().max().map_or(0, |height| *height + 1);
+        let mut blocks = Vec::with_capacity(num_blocks as usize);
+        let mut committees = Vec::with_capacity(num_blocks as usize);
+        for height in 0..num_blocks {
+            let block_hash = match self.block_store().get_block_hash(height)? {
+                Some(block_hash) => block_hash,
+                None => bail!("The block hash for height {height} is missing in storage"),
+            };
+            match self.block_store().get_block(&block_hash)? {
+                Some(block) => blocks.push(block),
+                None => bail!("The block '{block_hash}' for height {height} is missing in storage"),
+            }
+            if let Some(committee) = self.finalize_store().committee_store().get_committee(height)? {
+                committees.push((height, committee));
+            }
+        }
+
+        // Retrieve the mappings.
+        let mut mappings = Vec::new();
+        for program_id in self.finalize_store().get_program_ids_confirmed()? {
+            for mapping_name in self.finalize_store().get_mapping_names_confirmed(&program_id)?.unwrap_or_default() {
+                let entries = self.finalize_store().get_mapping_confirmed(program_id, mapping_name)?;
+                mappings.push((program_id, mapping_name, entries));
+            }
+        }
+
+        // Serialize the state, and compute its checksum.
+        let state = PersistedState::<N> { network_id: N::ID, blocks, committees, mappings };
+        let payload = bincode::serialize(&state)?;
+        let checksum = Sha256::digest(&payload);
+
+        // Write the file to a temporary path, and then move it into place.
+        let path = path.as_ref();
+        let temp_path = path.with_extension("tmp");
+        let mut bytes = Vec::with_capacity(MAGIC.len() + 1 + checksum.len() + payload.len());
+        bytes.extend_from_slice(&MAGIC);
+        bytes.push(VERSION);
+        bytes.extend_from_slice(&checksum);
+        bytes.extend_from_slice(&payload);
+        std::fs::write(&temp_path, bytes)?;
+        std::fs::rename(&temp_path, path)?;
+        Ok(())
+    }
+
+    /// Loads an in-memory consensus store from a file written by `ConsensusStore::persist`.
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Self> {
+        // Read the file.
+        let bytes = std::fs::read(path.as_ref())?;
+        let header_size = MAGIC.len() + 1 + 32;
+        ensure!(bytes.len() >= header_size, "The persisted ledger file is truncated");
+        ensure!(bytes[..MAGIC.len()] == MAGIC, "The file is not a persisted ledger");
+        ensure!(bytes[MAGIC.len()] == VERSION, "Unsupported persisted ledger version {}", bytes[MAGIC.len()]);
+
+        // Ensure the checksum is correct.
+        let (checksum, payload) = bytes[MAGIC.len() + 1..].split_at(32);
+        ensure!(Sha256::digest(payload).as_slice() == checksum, "The persisted ledger file is corrupted");
+
+        // Deserialize the state.
+        let state: PersistedState<N> = bincode::deserialize(payload)?;
+        ensure!(state.network_id == N::ID, "Expected network ID {}, found {}", N::ID, state.network_id);
+
+        // Initialize the consensus store.
+        let store = Self::open(None)?;
+        // Restore the blocks.
+        for block in &state.blocks {
+            store.block_store().insert(block)?;
+        }
+        // Restore the committees.
+        for (height, committee) in state.committees {
+            store.finalize_store().committee_store().insert(height, committee)?;
+        }
+        // Restore the mappings.
+        for (program_id, mapping_name, entries) in state.mappings {
+            store.finalize_store().initialize_mapping(program_id, mapping_name)?;
+            store.finalize_store().replace_mapping(program_id, mapping_name, entries)?;
+        }
+        Ok(store)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::MainnetV0;
+    use synthesizer_program::FinalizeStoreTrait;
+
+    type CurrentNetwork = MainnetV0;
+
+    #[test]
+    fn test_persist_load() {
+        let rng = &mut TestRng::default();
+
+        // Initialize a store with the sample genesis block, a committee, and a mapping.
+        let store = ConsensusStore::<CurrentNetwork, ConsensusMemory<CurrentNetwork>>::open(None).unwrap();
+        let block: Block<CurrentNetwork> = ledger_test_helpers::sample_genesis_block(rng);
+        store.block_store().insert(&block).unwrap();
+        let committee = ledger_committee::test_helpers::sample_committee_for_round(0, rng);
+        store.finalize_store().committee_store().insert(0, committee.clone()).unwrap();
+        let program_id = ProgramID::from_str("hello.aleo").unwrap();
+        let mapping_name = Identifier::from_str("account").unwrap();
+        let key = Plaintext::from_str("123field").unwrap();
+        let value = Value::from_str("456u64").unwrap();
+        store.finalize_store().initialize_mapping(program_id, mapping_name).unwrap();
+        store.finalize_store().insert_key_value(program_id, mapping_name, key.clone(), value.clone()).unwrap();
+
+        // Persist the store.
+        let directory = tempfile::tempdir().unwrap();
+        let path = directory.path().join("ledger.mem");
+        store.persist(&path).unwrap();
+
+        // Load the store, and ensure it matches.
+        let loaded = ConsensusStore::<CurrentNetwork, ConsensusMemory<CurrentNetwork>>::load(&path).unwrap();
+        assert_eq!(loaded.block_store().current_state_root(), store.block_store().current_state_root());
+        assert_eq!(loaded.block_store().get_block(&block.hash()).unwrap(), Some(block));
+        assert_eq!(loaded.finalize_store().committee_store().get_committee(0).unwrap(), Some(committee));
+        assert_eq!(loaded.finalize_store().get_value_confirmed(program_id, mapping_name, &key).unwrap(), Some(value));
+        assert_eq!(
+            loaded.finalize_store().get_checksum_confirmed().unwrap(),
+            store.finalize_store().get_checksum_confirmed().unwrap()
+        );
+
+        // Ensure a corrupted file is rejected.
+        let mut bytes = std::fs::read(&path).unwrap();
+        *bytes.last_mut().unwrap() ^= 1;
+        std::fs::write(&path, bytes).unwrap();
+        assert!(ConsensusStore::<CurrentNetwork, ConsensusMemory<CurrentNetwork>>::load(&path).is_err());
+    }
+}
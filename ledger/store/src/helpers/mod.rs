@@ -16,6 +16,13 @@
 pub mod memory;
 #[cfg(feature = "rocks")]
 pub mod rocksdb;
+#[cfg(feature = "sled")]
+pub mod sled;
+
+#[cfg(any(feature = "rocks", feature = "sled"))]
+mod map_id;
+#[cfg(any(feature = "rocks", feature = "sled"))]
+pub use map_id::*;
 
 #[cfg(test)]
 pub(crate) mod test_helpers;
@@ -28,7 +28,7 @@ use crate::{
 };
 use console::{prelude::*, types::Field};
 use ledger_authority::Authority;
-use ledger_block::{Header, Ratifications, Rejected, Solutions};
+use ledger_block::{AbortReason, Header, Ratifications, Rejected, Solutions};
 use ledger_puzzle::SolutionID;
 use synthesizer_program::FinalizeOperation;
 
@@ -66,6 +66,8 @@ pub struct BlockDB<N: Network> {
     transactions_map: DataMap<N::BlockHash, Vec<N::TransactionID>>,
     /// The aborted transaction IDs map.
     aborted_transaction_ids_map: DataMap<N::BlockHash, Vec<N::TransactionID>>,
+    /// The aborted transaction reasons map.
+    aborted_transaction_reasons_map: DataMap<N::BlockHash, Vec<AbortReason>>,
     /// The rejected or aborted transaction ID map.
     rejected_or_aborted_transaction_id_map: DataMap<N::TransactionID, N::BlockHash>,
     /// The confirmed transactions map.
@@ -93,6 +95,7 @@ impl<N: Network> BlockStorage<N> for BlockDB<N> {
     type AbortedSolutionHeightsMap = DataMap<SolutionID<N>, u32>;
     type TransactionsMap = DataMap<N::BlockHash, Vec<N::TransactionID>>;
     type AbortedTransactionIDsMap = DataMap<N::BlockHash, Vec<N::TransactionID>>;
+    type AbortedTransactionReasonsMap = DataMap<N::BlockHash, Vec<AbortReason>>;
     type RejectedOrAbortedTransactionIDMap = DataMap<N::TransactionID, N::BlockHash>;
     type ConfirmedTransactionsMap = DataMap<N::TransactionID, (N::BlockHash, ConfirmedTxType<N>, Vec<FinalizeOperation<N>>)>;
     type RejectedDeploymentOrExecutionMap = DataMap<Field<N>, Rejected<N>>;
@@ -121,6 +124,7 @@ impl<N: Network> BlockStorage<N> for BlockDB<N> {
             aborted_solution_heights_map: internal::RocksDB::open_map(N::ID, storage.clone(), MapID::Block(BlockMap::AbortedSolutionHeights))?,
             transactions_map: internal::RocksDB::open_map(N::ID, storage.clone(), MapID::Block(BlockMap::Transactions))?,
             aborted_transaction_ids_map: internal::RocksDB::open_map(N::ID, storage.clone(), MapID::Block(BlockMap::AbortedTransactionIDs))?,
+            aborted_transaction_reasons_map: internal::RocksDB::open_map(N::ID, storage.clone(), MapID::Block(BlockMap::AbortedTransactionReasons))?,
             rejected_or_aborted_transaction_id_map: internal::RocksDB::open_map(N::ID, storage.clone(), MapID::Block(BlockMap::RejectedOrAbortedTransactionID))?,
             confirmed_transactions_map: internal::RocksDB::open_map(N::ID, storage.clone(), MapID::Block(BlockMap::ConfirmedTransactions))?,
             rejected_deployment_or_execution_map: internal::RocksDB::open_map(N::ID, storage, MapID::Block(BlockMap::RejectedDeploymentOrExecution))?,
@@ -198,6 +202,11 @@ impl<N: Network> BlockStorage<N> for BlockDB<N> {
         &self.aborted_transaction_ids_map
     }
 
+    /// Returns the aborted transaction reasons map.
+    fn aborted_transaction_reasons_map(&self) -> &Self::AbortedTransactionReasonsMap {
+        &self.aborted_transaction_reasons_map
+    }
+
     /// Returns the rejected transaction ID or aborted transaction ID map.
     fn rejected_or_aborted_transaction_id_map(&self) -> &Self::RejectedOrAbortedTransactionIDMap {
         &self.rejected_or_aborted_transaction_id_map
@@ -13,8 +13,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-mod id;
-pub use id::*;
+pub use crate::helpers::map_id::*;
 
 mod map;
 pub use map::*;
@@ -0,0 +1,297 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+pub use crate::helpers::map_id::*;
+
+mod map;
+pub use map::*;
+
+mod nested_map;
+pub use nested_map::*;
+
+#[cfg(test)]
+mod tests;
+
+use aleo_std_storage::StorageMode;
+use anyhow::{bail, ensure, Result};
+use once_cell::sync::OnceCell;
+use parking_lot::Mutex;
+use serde::{de::DeserializeOwned, Serialize};
+use std::{
+    borrow::Borrow,
+    marker::PhantomData,
+    mem,
+    ops::Deref,
+    sync::{
+        atomic::{AtomicBool, AtomicUsize, Ordering},
+        Arc,
+    },
+};
+
+pub const PREFIX_LEN: usize = 4; // N::ID (u16) + DataID (u16)
+
+/// A queued write to the database; a value of `None` removes the key.
+pub(super) type RawOperation = (Vec<u8>, Option<Vec<u8>>);
+
+/// An instance of a sled database.
+///
+/// All maps are stored in a single tree, with keys prefixed by the network ID and the map ID,
+/// so that the writes of an atomic batch across many maps are applied with a single `sled::Batch`.
+#[derive(Clone)]
+pub struct SledDB {
+    /// The sled instance.
+    sled: ::sled::Db,
+    /// The network ID.
+    network_id: u16,
+    /// The storage mode.
+    storage_mode: StorageMode,
+    /// The low-level database transaction that gets executed atomically at the end
+    /// of a real-run `atomic_finalize` or the outermost `atomic_batch_scope`.
+    pub(super) atomic_batch: Arc<Mutex<Vec<RawOperation>>>,
+    /// The depth of the current atomic write batch; it gets incremented with every call
+    /// to `start_atomic` and decremented with each call to `finish_atomic`.
+    pub(super) atomic_depth: Arc<AtomicUsize>,
+    /// A flag indicating whether the atomic writes are currently paused.
+    pub(super) atomic_writes_paused: Arc<AtomicBool>,
+}
+
+impl Deref for SledDB {
+    type Target = ::sled::Db;
+
+    fn deref(&self) -> &Self::Target {
+        &self.sled
+    }
+}
+
+impl SledDB {
+    /// Opens the database.
+    ///
+    /// In production mode, the database opens directory `~/.aleo/storage/ledger-{network}`.
+    /// In development mode, the database opens directory `/path/to/repo/.ledger-{network}-{id}`.
+    pub fn open<S: Clone + Into<StorageMode>>(network_id: u16, storage: S) -> Result<Self> {
+        static DB: OnceCell<SledDB> = OnceCell::new();
+
+        // Retrieve the database.
+        let database = DB
+            .get_or_try_init(|| {
+                let primary = aleo_std_storage::aleo_ledger_dir(network_id, storage.clone().into());
+                Ok::<_, anyhow::Error>(SledDB {
+                    sled: ::sled::open(primary)?,
+                    network_id,
+                    storage_mode: storage.clone().into(),
+                    atomic_batch: Default::default(),
+                    atomic_depth: Default::default(),
+                    atomic_writes_paused: Default::default(),
+                })
+            })?
+            .clone();
+
+        // Ensure the database network ID and storage mode match.
+        match database.network_id == network_id && database.storage_mode == storage.into() {
+            true => Ok(database),
+            false => bail!("Mismatching network ID or storage mode in the database"),
+        }
+    }
+
+    /// Opens the map with the given `network_id`, `storage mode`, and `map_id` from storage.
+    pub fn open_map<
+        S: Clone + Into<StorageMode>,
+        K: Serialize + DeserializeOwned,
+        V: Serialize + DeserializeOwned,
+        T: Into<u16>,
+    >(
+        network_id: u16,
+        storage: S,
+        map_id: T,
+    ) -> Result<SledMap<K, V>> {
+        Ok(SledMap::new(Self::open(network_id, storage)?, map_id))
+    }
+
+    /// Opens the nested map with the given `network_id`, `storage mode`, and `map_id` from storage.
+    pub fn open_nested_map<
+        S: Clone + Into<StorageMode>,
+        M: Serialize + DeserializeOwned,
+        K: Serialize + DeserializeOwned,
+        V: Serialize + DeserializeOwned,
+        T: Into<u16>,
+    >(
+        network_id: u16,
+        storage: S,
+        map_id: T,
+    ) -> Result<NestedSledMap<M, K, V>> {
+        Ok(NestedSledMap::new(Self::open(network_id, storage)?, map_id))
+    }
+
+    /// Returns the prefix of the map with the given `map_id`.
+    fn map_context<T: Into<u16>>(&self, map_id: T) -> Vec<u8> {
+        // Combine contexts to create a new scope.
+        let mut context = self.network_id.to_le_bytes().to_vec();
+        context.extend_from_slice(&(map_id.into()).to_le_bytes());
+        context
+    }
+
+    /// Performs the given operations as a single atomic write.
+    fn write(&self, operations: Vec<RawOperation>) -> Result<()> {
+        // Prepare the batch; later operations on the same key replace the earlier ones.
+        let mut batch = ::sled::Batch::default();
+        for (raw_key, raw_value) in operations {
+            match raw_value {
+                Some(raw_value) => batch.insert(raw_key, raw_value),
+                None => batch.remove(raw_key),
+            }
+        }
+        // Execute all the operations atomically.
+        Ok(self.sled.apply_batch(batch)?)
+    }
+
+    /// Enqueues the given operations in the database-wide batch, and executes the batch if this
+    /// is the final call to `finish_atomic` and the atomic writes are not paused.
+    pub(super) fn finish_atomic(&self, operations: Vec<RawOperation>) -> Result<()> {
+        // Enqueue all the operations from the map in the database-wide batch.
+        self.atomic_batch.lock().extend(operations);
+
+        // Subtract the atomic depth index.
+        let previous_atomic_depth = self.atomic_depth.fetch_sub(1, Ordering::SeqCst);
+
+        // Ensure that the value of `atomic_depth` doesn't overflow, meaning that all the
+        // calls to `start_atomic` have corresponding calls to `finish_atomic`.
+        assert!(previous_atomic_depth != 0);
+
+        // If we're at depth 0, it is the final call to `finish_atomic` and the
+        // atomic write batch can be physically executed. This is skipped if the
+        // atomic writes are paused.
+        if previous_atomic_depth == 1 && !self.are_atomic_writes_paused() {
+            // Empty the collection of pending operations.
+            let batch = mem::take(&mut *self.atomic_batch.lock());
+            // Execute all the operations atomically.
+            self.write(batch)?;
+            // Ensure that the database atomic batch is empty.
+            assert!(self.atomic_batch.lock().is_empty());
+        }
+
+        Ok(())
+    }
+
+    /// Increments the atomic depth, and ensures the database-wide batch is empty
+    /// (unless the atomic writes are paused, as there may be pending operations).
+    pub(super) fn start_atomic(&self) {
+        // Increment the atomic depth index.
+        self.atomic_depth.fetch_add(1, Ordering::SeqCst);
+        // Ensure that the database atomic batch is empty.
+        if !self.are_atomic_writes_paused() {
+            assert!(self.atomic_batch.lock().is_empty());
+        }
+    }
+
+    /// Clears the database-wide batch and resets the atomic depth.
+    pub(super) fn abort_atomic(&self) {
+        // Clear the database-wide atomic batch.
+        self.atomic_batch.lock().clear();
+        // Reset the atomic batch depth.
+        self.atomic_depth.store(0, Ordering::SeqCst);
+    }
+
+    /// Pause the execution of atomic writes for the entire database.
+    fn pause_atomic_writes(&self) -> Result<()> {
+        // This operation is only intended to be performed before or after
+        // atomic batches - never in the middle of them.
+        assert_eq!(self.atomic_depth.load(Ordering::SeqCst), 0);
+
+        // Set the flag indicating that the pause is in effect.
+        let already_paused = self.atomic_writes_paused.swap(true, Ordering::SeqCst);
+        // Make sure that we haven't already paused atomic writes (which would
+        // indicate a logic bug).
+        assert!(!already_paused);
+
+        Ok(())
+    }
+
+    /// Unpause the execution of atomic writes for the entire database; this
+    /// executes all the writes that have been queued since they were paused.
+    fn unpause_atomic_writes<const DISCARD_BATCH: bool>(&self) -> Result<()> {
+        // Ensure the call to unpause is only performed before or after an atomic batch scope
+        // - and never in the middle of one (otherwise there is a fundamental logic bug).
+        ensure!(self.atomic_depth.load(Ordering::SeqCst) == 0, "Atomic depth must be 0 to unpause atomic writes");
+
+        // Ensure the database is paused (otherwise there is a fundamental logic bug).
+        ensure!(self.atomic_writes_paused.load(Ordering::SeqCst), "Atomic writes must be paused to unpause them");
+
+        // Every atomic storage operation that has accumulated from the moment
+        // the writes have been paused is executed as a single atomic batch.
+        let batch = mem::take(&mut *self.atomic_batch.lock());
+        if !DISCARD_BATCH {
+            self.write(batch)?;
+        }
+
+        // Unset the flag indicating that the pause is in effect.
+        self.atomic_writes_paused.store(false, Ordering::SeqCst);
+
+        Ok(())
+    }
+
+    /// Checks whether the atomic writes are currently paused.
+    fn are_atomic_writes_paused(&self) -> bool {
+        self.atomic_writes_paused.load(Ordering::SeqCst)
+    }
+
+    /// Opens the test database.
+    #[cfg(any(test, feature = "test"))]
+    pub fn open_testing(temp_dir: std::path::PathBuf, dev: Option<u16>) -> Result<Self> {
+        use console::prelude::{Rng, TestRng};
+
+        // Ensure the `temp_dir` is unique.
+        let temp_dir = temp_dir.join(Rng::gen::<u64>(&mut TestRng::default()).to_string());
+
+        // Construct the directory for the test database.
+        let primary = match dev {
+            Some(dev) => temp_dir.join(dev.to_string()),
+            None => temp_dir,
+        };
+
+        Ok(SledDB {
+            sled: ::sled::open(&primary)?,
+            network_id: u16::MAX,
+            storage_mode: StorageMode::from(primary),
+            atomic_batch: Default::default(),
+            atomic_depth: Default::default(),
+            atomic_writes_paused: Default::default(),
+        })
+    }
+
+    /// Opens the test map.
+    #[cfg(any(test, feature = "test"))]
+    pub fn open_map_testing<K: Serialize + DeserializeOwned, V: Serialize + DeserializeOwned, T: Into<u16>>(
+        temp_dir: std::path::PathBuf,
+        dev: Option<u16>,
+        map_id: T,
+    ) -> Result<SledMap<K, V>> {
+        Ok(SledMap::new(Self::open_testing(temp_dir, dev)?, map_id))
+    }
+
+    /// Opens the test nested map.
+    #[cfg(any(test, feature = "test"))]
+    pub fn open_nested_map_testing<
+        M: Serialize + DeserializeOwned,
+        K: Serialize + DeserializeOwned,
+        V: Serialize + DeserializeOwned,
+        T: Into<u16>,
+    >(
+        temp_dir: std::path::PathBuf,
+        dev: Option<u16>,
+        map_id: T,
+    ) -> Result<NestedSledMap<M, K, V>> {
+        Ok(NestedSledMap::new(Self::open_testing(temp_dir, dev)?, map_id))
+    }
+}
@@ -0,0 +1,93 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::helpers::{
+    sled::{MapID, SledDB, TestMap as TestMapID},
+    Map,
+    MapRead,
+};
+
+use serial_test::serial;
+
+pub(crate) fn temp_dir() -> std::path::PathBuf {
+    tempfile::tempdir().expect("Failed to open temporary directory").into_path()
+}
+
+#[test]
+#[serial]
+fn test_open() {
+    let _storage = SledDB::open_testing(temp_dir(), None).expect("Failed to open storage");
+}
+
+#[test]
+#[serial]
+fn test_insert_and_get() {
+    let map =
+        SledDB::open_map_testing(temp_dir(), None, MapID::Test(TestMapID::Test)).expect("Failed to open data map");
+
+    map.insert(123456789, "123456789".to_string()).expect("Failed to insert");
+    assert_eq!(
+        Some("123456789".to_string()),
+        map.get_confirmed(&123456789).expect("Failed to get").map(|v| v.to_string())
+    );
+
+    assert_eq!(None, map.get_confirmed(&000000000).expect("Failed to get"));
+}
+
+#[test]
+#[serial]
+fn test_insert_and_remove() {
+    let map =
+        SledDB::open_map_testing(temp_dir(), None, MapID::Test(TestMapID::Test)).expect("Failed to open data map");
+
+    map.insert(123456789, "123456789".to_string()).expect("Failed to insert");
+    assert_eq!(
+        map.get_confirmed(&123456789).expect("Failed to get").map(|v| v.to_string()),
+        Some("123456789".to_string())
+    );
+
+    map.remove(&123456789).expect("Failed to remove");
+    assert!(map.get_confirmed(&123456789).expect("Failed to get").is_none());
+}
+
+#[test]
+#[serial]
+fn test_pause_and_unpause_atomic_writes() {
+    let map =
+        SledDB::open_map_testing(temp_dir(), None, MapID::Test(TestMapID::Test)).expect("Failed to open data map");
+
+    // Pause the atomic writes, and write in two separate atomic batches.
+    map.pause_atomic_writes().unwrap();
+    for i in 0..2usize {
+        map.start_atomic();
+        map.insert(i, i.to_string()).unwrap();
+        map.finish_atomic().unwrap();
+    }
+
+    // Nothing is written while the atomic writes are paused.
+    assert_eq!(map.len_confirmed(), 0);
+
+    // Both batches are written once the atomic writes are unpaused.
+    map.unpause_atomic_writes::<false>().unwrap();
+    assert_eq!(map.len_confirmed(), 2);
+
+    // The queued batches are dropped if the unpause discards them.
+    map.pause_atomic_writes().unwrap();
+    map.start_atomic();
+    map.insert(2, "2".to_string()).unwrap();
+    map.finish_atomic().unwrap();
+    map.unpause_atomic_writes::<true>().unwrap();
+    assert_eq!(map.len_confirmed(), 2);
+}
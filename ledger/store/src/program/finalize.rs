@@ -18,7 +18,14 @@ use crate::{
     cow_to_cloned,
     cow_to_copied,
     helpers::{Map, MapRead, NestedMap, NestedMapRead},
-    program::{CommitteeStorage, CommitteeStore},
+    program::{
+        CommitteeStorage,
+        CommitteeStore,
+        FinalizeStateHasher,
+        FinalizeStatePath,
+        FinalizeStateTracker,
+        FinalizeStateTree,
+    },
 };
 use console::{
     network::prelude::*,
@@ -31,6 +38,8 @@ use aleo_std_storage::StorageMode;
 use anyhow::Result;
 use core::marker::PhantomData;
 use indexmap::IndexSet;
+use parking_lot::RwLock;
+use std::sync::Arc;
 
 /// TODO (howardwu): Remove this.
 /// Returns the mapping ID for the given `program ID` and `mapping name`.
@@ -45,7 +54,7 @@ fn to_mapping_id<N: Network>(program_id: &ProgramID<N>, mapping_name: &Identifie
 }
 
 /// Returns the key ID for the given `program ID`, `mapping name`, and `key`.
-fn to_key_id<N: Network>(
+pub(super) fn to_key_id<N: Network>(
     program_id: &ProgramID<N>,
     mapping_name: &Identifier<N>,
     key: &Plaintext<N>,
@@ -61,6 +70,12 @@ fn to_key_id<N: Network>(
     N::hash_bhp1024(&preimage)
 }
 
+/// Returns the value ID for the given `key ID` and `value`.
+pub(super) fn to_value_id<N: Network>(key_id: &Field<N>, value: &Value<N>) -> Result<Field<N>> {
+    // Compute the value ID.
+    N::hash_bhp1024(&(*key_id, N::hash_bhp1024(&value.to_bits_le())?).to_bits_le())
+}
+
 /// A trait for program state storage. Note: For the program logic, see `DeploymentStorage`.
 ///
 /// We define the `key ID := Hash ( program ID || mapping name || Hash(key) )`
@@ -203,7 +218,7 @@ pub trait FinalizeStorage<N: Network>: 'static + Clone + Send + Sync {
         // Compute the key ID.
         let key_id = to_key_id(&program_id, &mapping_name, &key)?;
         // Compute the value ID.
-        let value_id = N::hash_bhp1024(&(key_id, N::hash_bhp1024(&value.to_bits_le())?).to_bits_le())?;
+        let value_id = to_value_id(&key_id, &value)?;
 
         atomic_batch_scope!(self, {
             // Update the key-value map with the new key-value.
@@ -235,7 +250,7 @@ pub trait FinalizeStorage<N: Network>: 'static + Clone + Send + Sync {
         // Compute the key ID.
         let key_id = to_key_id(&program_id, &mapping_name, &key)?;
         // Compute the value ID.
-        let value_id = N::hash_bhp1024(&(key_id, N::hash_bhp1024(&value.to_bits_le())?).to_bits_le())?;
+        let value_id = to_value_id(&key_id, &value)?;
 
         atomic_batch_scope!(self, {
             // Update the key-value map with the new key-value.
@@ -529,6 +544,8 @@ pub trait FinalizeStorage<N: Network>: 'static + Clone + Send + Sync {
 pub struct FinalizeStore<N: Network, P: FinalizeStorage<N>> {
     /// The finalize storage.
     storage: P,
+    /// The finalize state tree, which is initialized on first use.
+    state: Arc<RwLock<FinalizeStateTracker<N>>>,
     /// PhantomData.
     _phantom: PhantomData<N>,
 }
@@ -548,12 +565,13 @@ impl<N: Network, P: FinalizeStorage<N>> FinalizeStore<N, P> {
     /// Initializes a finalize store from storage.
     pub fn from(storage: P) -> Result<Self> {
         // Return the finalize store.
-        Ok(Self { storage, _phantom: PhantomData })
+        Ok(Self { storage, state: Default::default(), _phantom: PhantomData })
     }
 
     /// Starts an atomic batch write operation.
     pub fn start_atomic(&self) {
         self.storage.start_atomic();
+        self.state.write().start_atomic();
     }
 
     /// Checks if an atomic batch is in progress.
@@ -564,26 +582,32 @@ impl<N: Network, P: FinalizeStorage<N>> FinalizeStore<N, P> {
     /// Checkpoints the atomic batch.
     pub fn atomic_checkpoint(&self) {
         self.storage.atomic_checkpoint();
+        self.state.write().atomic_checkpoint();
     }
 
     /// Clears the latest atomic batch checkpoint.
     pub fn clear_latest_checkpoint(&self) {
         self.storage.clear_latest_checkpoint();
+        self.state.write().clear_latest_checkpoint();
     }
 
     /// Rewinds the atomic batch to the previous checkpoint.
     pub fn atomic_rewind(&self) {
         self.storage.atomic_rewind();
+        self.state.write().atomic_rewind();
     }
 
     /// Aborts an atomic batch write operation.
     pub fn abort_atomic(&self) {
         self.storage.abort_atomic();
+        self.state.write().abort_atomic();
     }
 
     /// Finishes an atomic batch write operation.
     pub fn finish_atomic(&self) -> Result<()> {
-        self.storage.finish_atomic()
+        let result = self.storage.finish_atomic();
+        self.state.write().finish_atomic(result.is_ok());
+        result
     }
 
     /// Returns the storage mode.
@@ -635,7 +659,9 @@ impl<N: Network, P: FinalizeStorage<N>> FinalizeStoreTrait<N> for FinalizeStore<
         key: Plaintext<N>,
         value: Value<N>,
     ) -> Result<FinalizeOperation<N>> {
-        self.storage.insert_key_value(program_id, mapping_name, key, value)
+        let operation = self.storage.insert_key_value(program_id, mapping_name, key, value)?;
+        self.record_finalize_operation(&operation);
+        Ok(operation)
     }
 
     /// Stores the given `(key, value)` pair at the given `program ID` and `mapping name` in storage.
@@ -649,7 +675,9 @@ impl<N: Network, P: FinalizeStorage<N>> FinalizeStoreTrait<N> for FinalizeStore<
         key: Plaintext<N>,
         value: Value<N>,
     ) -> Result<FinalizeOperation<N>> {
-        self.storage.update_key_value(program_id, mapping_name, key, value)
+        let operation = self.storage.update_key_value(program_id, mapping_name, key, value)?;
+        self.record_finalize_operation(&operation);
+        Ok(operation)
     }
 
     /// Removes the key-value pair for the given `program ID`, `mapping name`, and `key` from storage.
@@ -659,7 +687,11 @@ impl<N: Network, P: FinalizeStorage<N>> FinalizeStoreTrait<N> for FinalizeStore<
         mapping_name: Identifier<N>,
         key: &Plaintext<N>,
     ) -> Result<Option<FinalizeOperation<N>>> {
-        self.storage.remove_key_value(program_id, mapping_name, key)
+        let operation = self.storage.remove_key_value(program_id, mapping_name, key)?;
+        if let Some(operation) = &operation {
+            self.record_finalize_operation(operation);
+        }
+        Ok(operation)
     }
 }
 
@@ -682,7 +714,17 @@ impl<N: Network, P: FinalizeStorage<N>> FinalizeStore<N, P> {
         mapping_name: Identifier<N>,
         entries: Vec<(Plaintext<N>, Value<N>)>,
     ) -> Result<FinalizeOperation<N>> {
-        self.storage.replace_mapping(program_id, mapping_name, entries)
+        // Remove the existing entries from the finalize state tree, before inserting the new entries.
+        let mut updates = self.to_mapping_removals(&program_id, [mapping_name])?;
+        if self.state.read().is_tracking() {
+            for (key, value) in &entries {
+                let key_id = to_key_id(&program_id, &mapping_name, key)?;
+                updates.push((key_id, Some(to_value_id(&key_id, value)?)));
+            }
+        }
+        let operation = self.storage.replace_mapping(program_id, mapping_name, entries)?;
+        self.state.write().record(updates);
+        Ok(operation)
     }
 
     /// Removes the mapping for the given `program ID` and `mapping name` from storage,
@@ -692,13 +734,98 @@ impl<N: Network, P: FinalizeStorage<N>> FinalizeStore<N, P> {
         program_id: ProgramID<N>,
         mapping_name: Identifier<N>,
     ) -> Result<FinalizeOperation<N>> {
-        self.storage.remove_mapping(program_id, mapping_name)
+        let updates = self.to_mapping_removals(&program_id, [mapping_name])?;
+        let operation = self.storage.remove_mapping(program_id, mapping_name)?;
+        self.state.write().record(updates);
+        Ok(operation)
     }
 
     /// Removes the program for the given `program ID` from storage,
     /// along with all associated mappings and key-value pairs in storage.
     pub fn remove_program(&self, program_id: &ProgramID<N>) -> Result<()> {
-        self.storage.remove_program(program_id)
+        let mapping_names = self.storage.get_mapping_names_speculative(program_id)?.unwrap_or_default();
+        let updates = self.to_mapping_removals(program_id, mapping_names)?;
+        self.storage.remove_program(program_id)?;
+        self.state.write().record(updates);
+        Ok(())
+    }
+}
+
+impl<N: Network, P: FinalizeStorage<N>> FinalizeStore<N, P> {
+    /// Initializes the finalize state tree from the confirmed finalize state, if it is not yet initialized.
+    /// Once initialized, the tree is maintained incrementally as the finalize state is updated.
+    pub fn initialize_state_tree(&self) -> Result<()> {
+        self.with_state_tree(|_| Ok(()))
+    }
+
+    /// Returns the root of the finalize state tree, for the confirmed finalize state.
+    pub fn get_state_root_confirmed(&self) -> Result<Field<N>> {
+        self.with_state_tree(|tree| Ok(*tree.root()))
+    }
+
+    /// Returns the path in the finalize state tree for the given `program ID`, `mapping name`, and `key`,
+    /// which proves the inclusion (or exclusion) of the key in the confirmed finalize state.
+    pub fn prove_key_confirmed(
+        &self,
+        program_id: &ProgramID<N>,
+        mapping_name: &Identifier<N>,
+        key: &Plaintext<N>,
+    ) -> Result<FinalizeStatePath<N>> {
+        let key_id = to_key_id(program_id, mapping_name, key)?;
+        self.with_state_tree(|tree| tree.prove(&key_id))
+    }
+}
+
+impl<N: Network, P: FinalizeStorage<N>> FinalizeStore<N, P> {
+    /// Calls the given closure on the finalize state tree, initializing the tree if it is not yet initialized.
+    fn with_state_tree<T>(&self, f: impl FnOnce(&FinalizeStateTree<N>) -> Result<T>) -> Result<T> {
+        let mut state = self.state.write();
+        if state.tree().is_none() {
+            // Ensure the confirmed finalize state is not being updated.
+            ensure!(!self.is_atomic_in_progress(), "Cannot initialize the finalize state tree during an atomic batch");
+            // Construct the tree from the confirmed key-value pairs.
+            let mut tree = FinalizeStateTree::<N>::new(&FinalizeStateHasher::new())?;
+            for (m, k, v) in self.storage.key_value_map().iter_confirmed() {
+                let (program_id, mapping_name) = cow_to_copied!(m);
+                let key_id = to_key_id(&program_id, &mapping_name, &k)?;
+                tree.insert(key_id, to_value_id(&key_id, &v)?)?;
+            }
+            state.initialize(tree);
+        }
+        match state.tree() {
+            Some(tree) => f(tree),
+            None => bail!("The finalize state tree is not initialized"),
+        }
+    }
+
+    /// Records the given finalize operation in the finalize state tree.
+    fn record_finalize_operation(&self, operation: &FinalizeOperation<N>) {
+        match operation {
+            FinalizeOperation::InsertKeyValue(_, key_id, value_id)
+            | FinalizeOperation::UpdateKeyValue(_, key_id, value_id) => {
+                self.state.write().record([(*key_id, Some(*value_id))])
+            }
+            FinalizeOperation::RemoveKeyValue(_, key_id) => self.state.write().record([(*key_id, None)]),
+            _ => (),
+        }
+    }
+
+    /// Returns the updates to the finalize state tree that remove the speculative entries of the given mappings.
+    /// If the finalize state tree is not initialized, no updates are returned.
+    fn to_mapping_removals(
+        &self,
+        program_id: &ProgramID<N>,
+        mapping_names: impl IntoIterator<Item = Identifier<N>>,
+    ) -> Result<Vec<(Field<N>, Option<Field<N>>)>> {
+        let mut updates = Vec::new();
+        if self.state.read().is_tracking() {
+            for mapping_name in mapping_names {
+                for (key, _) in self.storage.get_mapping_speculative(*program_id, mapping_name)? {
+                    updates.push((to_key_id(program_id, &mapping_name, &key)?, None));
+                }
+            }
+        }
+        Ok(updates)
     }
 }
 
@@ -720,6 +847,11 @@ impl<N: Network, P: FinalizeStorage<N>> FinalizeStore<N, P> {
 }
 
 impl<N: Network, P: FinalizeStorage<N>> FinalizeStore<N, P> {
+    /// Returns the confirmed program IDs.
+    pub fn get_program_ids_confirmed(&self) -> Result<Vec<ProgramID<N>>> {
+        Ok(self.storage.program_id_map().keys_confirmed().map(|program_id| cow_to_copied!(program_id)).collect())
+    }
+
     /// Returns the confirmed mapping names for the given `program ID`.
     pub fn get_mapping_names_confirmed(&self, program_id: &ProgramID<N>) -> Result<Option<IndexSet<Identifier<N>>>> {
         self.storage.get_mapping_names_confirmed(program_id)
@@ -772,7 +904,7 @@ impl<N: Network, P: FinalizeStorage<N>> FinalizeStore<N, P> {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::helpers::memory::FinalizeMemory;
+    use crate::{helpers::memory::FinalizeMemory, program::verify_finalize_state_path};
     use console::{network::MainnetV0, program::Literal, types::U64};
 
     type CurrentNetwork = MainnetV0;
@@ -1269,6 +1401,72 @@ mod tests {
         check_initialize_update_remove(&finalize_store, program_id, mapping_name);
     }
 
+    #[test]
+    fn test_finalize_state_tree() {
+        // Initialize a program ID and mapping name.
+        let program_id = ProgramID::<CurrentNetwork>::from_str("hello.aleo").unwrap();
+        let mapping_name = Identifier::from_str("account").unwrap();
+
+        // Initialize a new finalize store, with a tracked finalize state tree.
+        let finalize_store = FinalizeStore::from(FinalizeMemory::open(None).unwrap()).unwrap();
+        finalize_store.initialize_state_tree().unwrap();
+        finalize_store.initialize_mapping(program_id, mapping_name).unwrap();
+        let empty_root = finalize_store.get_state_root_confirmed().unwrap();
+
+        // Returns the root of the finalize state tree, when reconstructed from storage.
+        let reconstructed_root = |finalize_store: &FinalizeStore<CurrentNetwork, FinalizeMemory<CurrentNetwork>>| {
+            FinalizeStore::from(finalize_store.storage.clone()).unwrap().get_state_root_confirmed().unwrap()
+        };
+
+        // Insert and update the keys, and ensure the tree matches the reconstructed tree.
+        for item in 0..10u64 {
+            let key = Plaintext::from_str(&format!("{item}field")).unwrap();
+            let value = Value::from_str(&format!("{item}u64")).unwrap();
+            finalize_store.insert_key_value(program_id, mapping_name, key.clone(), value).unwrap();
+            let value = Value::from_str(&format!("{}u64", item + 100)).unwrap();
+            finalize_store.update_key_value(program_id, mapping_name, key, value).unwrap();
+        }
+        let root = finalize_store.get_state_root_confirmed().unwrap();
+        assert_ne!(root, empty_root);
+        assert_eq!(root, reconstructed_root(&finalize_store));
+
+        // Ensure the inclusion and exclusion proofs verify.
+        let key = Plaintext::from_str("3field").unwrap();
+        let value = Value::from_str("103u64").unwrap();
+        let path = finalize_store.prove_key_confirmed(&program_id, &mapping_name, &key).unwrap();
+        assert!(verify_finalize_state_path(&root, &path, &program_id, &mapping_name, &key, Some(&value)).unwrap());
+        assert!(!verify_finalize_state_path(&root, &path, &program_id, &mapping_name, &key, None).unwrap());
+        let missing_key = Plaintext::from_str("11field").unwrap();
+        let path = finalize_store.prove_key_confirmed(&program_id, &mapping_name, &missing_key).unwrap();
+        assert!(verify_finalize_state_path(&root, &path, &program_id, &mapping_name, &missing_key, None).unwrap());
+
+        // Ensure an aborted atomic batch does not change the root.
+        finalize_store.start_atomic();
+        finalize_store.remove_key_value(program_id, mapping_name, &key).unwrap();
+        assert_eq!(finalize_store.get_state_root_confirmed().unwrap(), root);
+        finalize_store.abort_atomic();
+        assert_eq!(finalize_store.get_state_root_confirmed().unwrap(), root);
+
+        // Ensure a rewound update is not applied, when the atomic batch is finished.
+        finalize_store.start_atomic();
+        finalize_store.remove_key_value(program_id, mapping_name, &key).unwrap();
+        finalize_store.atomic_checkpoint();
+        finalize_store.remove_key_value(program_id, mapping_name, &Plaintext::from_str("4field").unwrap()).unwrap();
+        finalize_store.atomic_rewind();
+        finalize_store.finish_atomic().unwrap();
+        let root = finalize_store.get_state_root_confirmed().unwrap();
+        assert_eq!(root, reconstructed_root(&finalize_store));
+        let path = finalize_store.prove_key_confirmed(&program_id, &mapping_name, &key).unwrap();
+        assert!(verify_finalize_state_path(&root, &path, &program_id, &mapping_name, &key, None).unwrap());
+
+        // Ensure replacing and removing the mapping updates the tree.
+        let entries = vec![(Plaintext::from_str("3field").unwrap(), Value::from_str("3u64").unwrap())];
+        finalize_store.replace_mapping(program_id, mapping_name, entries).unwrap();
+        assert_eq!(finalize_store.get_state_root_confirmed().unwrap(), reconstructed_root(&finalize_store));
+        finalize_store.remove_program(&program_id).unwrap();
+        assert_eq!(finalize_store.get_state_root_confirmed().unwrap(), empty_root);
+    }
+
     /// If you want to customize the DB size, run:
     /// ```ignore
     /// NUM_ITEMS=100000 cargo test test_finalize_timings -- --nocapture
@@ -18,3 +18,6 @@ pub use committee::*;
 
 mod finalize;
 pub use finalize::*;
+
+mod state_tree;
+pub use state_tree::*;
@@ -0,0 +1,194 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::finalize::{to_key_id, to_value_id};
+use console::{
+    collections::{
+        merkle_tree::PathHash,
+        sparse_merkle_tree::{SparseMerklePath, SparseMerkleTree},
+    },
+    network::prelude::*,
+    program::{Identifier, Plaintext, ProgramID, Value},
+    types::Field,
+};
+
+use core::marker::PhantomData;
+
+/// The depth of the finalize state tree.
+pub const FINALIZE_STATE_DEPTH: u8 = 32;
+
+/// The sparse Merkle tree over the finalize state, which maps each key ID to its value ID.
+pub type FinalizeStateTree<N> = SparseMerkleTree<N, FinalizeStateHasher<N>, FINALIZE_STATE_DEPTH>;
+/// The path in the finalize state tree, which proves the inclusion or exclusion of a mapping key.
+pub type FinalizeStatePath<N> = SparseMerklePath<N, FINALIZE_STATE_DEPTH>;
+
+/// The path hasher of the finalize state tree, which uses the Poseidon hash function of the network.
+#[derive(Copy, Clone, Debug)]
+pub struct FinalizeStateHasher<N: Network>(PhantomData<N>);
+
+impl<N: Network> FinalizeStateHasher<N> {
+    /// Initializes the path hasher of the finalize state tree.
+    pub const fn new() -> Self {
+        Self(PhantomData)
+    }
+}
+
+impl<N: Network> PathHash for FinalizeStateHasher<N> {
+    type Hash = Field<N>;
+
+    /// Returns the hash of the given child nodes.
+    fn hash_children(&self, left: &Self::Hash, right: &Self::Hash) -> Result<Self::Hash> {
+        // Prepend the nodes with a `1field` byte.
+        N::hash_psd2(&[Field::one(), *left, *right])
+    }
+}
+
+/// Returns `true` if the given path proves that the given key maps to the given value in the finalize state
+/// with the given root. If the value is `None`, the path must prove that the key does not exist.
+pub fn verify_finalize_state_path<N: Network>(
+    root: &Field<N>,
+    path: &FinalizeStatePath<N>,
+    program_id: &ProgramID<N>,
+    mapping_name: &Identifier<N>,
+    key: &Plaintext<N>,
+    value: Option<&Value<N>>,
+) -> Result<bool> {
+    // Compute the key ID and value ID.
+    let key_id = to_key_id(program_id, mapping_name, key)?;
+    let value_id = value.map(|value| to_value_id(&key_id, value)).transpose()?;
+    // Verify the path.
+    Ok(path.verify(&FinalizeStateHasher::<N>::new(), root, &key_id, value_id.as_ref()))
+}
+
+/// The finalize state tree of a finalize store, which is maintained incrementally once it is initialized.
+///
+/// The tree reflects the confirmed finalize state. The updates of an atomic batch are applied to the tree
+/// when the batch is finished, and are discarded when the batch is aborted.
+pub(crate) struct FinalizeStateTracker<N: Network> {
+    /// The finalize state tree, if it is initialized.
+    tree: Option<FinalizeStateTree<N>>,
+    /// The pending updates of the atomic batch in progress, as `(key ID, value ID)` pairs, if a batch is in progress.
+    /// A value ID of `None` removes the key ID.
+    pending: Option<Vec<(Field<N>, Option<Field<N>>)>>,
+    /// The number of pending updates at each atomic checkpoint.
+    checkpoints: Vec<usize>,
+}
+
+impl<N: Network> Default for FinalizeStateTracker<N> {
+    /// Initializes a tracker without a finalize state tree.
+    fn default() -> Self {
+        Self { tree: None, pending: None, checkpoints: Vec::new() }
+    }
+}
+
+impl<N: Network> FinalizeStateTracker<N> {
+    /// Returns the finalize state tree, if it is initialized.
+    pub(crate) const fn tree(&self) -> Option<&FinalizeStateTree<N>> {
+        self.tree.as_ref()
+    }
+
+    /// Returns `true` if the finalize state tree is initialized, and updates are being tracked.
+    pub(crate) const fn is_tracking(&self) -> bool {
+        self.tree.is_some()
+    }
+
+    /// Initializes the tracker with the given finalize state tree.
+    pub(crate) fn initialize(&mut self, tree: FinalizeStateTree<N>) {
+        *self = Self { tree: Some(tree), pending: None, checkpoints: Vec::new() };
+    }
+
+    /// Invalidates the finalize state tree, which is reinitialized from storage on next use.
+    pub(crate) fn invalidate(&mut self) {
+        *self = Self::default();
+    }
+
+    /// Records the given updates, which are applied immediately if there is no atomic batch in progress.
+    pub(crate) fn record(&mut self, updates: impl IntoIterator<Item = (Field<N>, Option<Field<N>>)>) {
+        match (&mut self.tree, &mut self.pending) {
+            (None, _) => (),
+            (Some(_), Some(pending)) => pending.extend(updates),
+            (Some(tree), None) => {
+                if Self::apply(tree, updates).is_err() {
+                    self.invalidate();
+                }
+            }
+        }
+    }
+
+    /// Starts tracking the updates of an atomic batch.
+    pub(crate) fn start_atomic(&mut self) {
+        if self.is_tracking() {
+            self.pending = Some(Vec::new());
+            self.checkpoints.clear();
+        }
+    }
+
+    /// Checkpoints the pending updates.
+    pub(crate) fn atomic_checkpoint(&mut self) {
+        if let Some(pending) = &self.pending {
+            self.checkpoints.push(pending.len());
+        }
+    }
+
+    /// Removes the latest checkpoint of the pending updates.
+    pub(crate) fn clear_latest_checkpoint(&mut self) {
+        self.checkpoints.pop();
+    }
+
+    /// Removes the pending updates since the latest checkpoint (or since the start of the batch).
+    pub(crate) fn atomic_rewind(&mut self) {
+        let checkpoint = self.checkpoints.pop().unwrap_or(0);
+        if let Some(pending) = &mut self.pending {
+            pending.truncate(checkpoint);
+        }
+    }
+
+    /// Discards the pending updates.
+    ///
+    /// If there is no atomic batch in progress, the latest batch may already have been applied to the tree
+    /// (e.g. if it was aborted after being finished with paused atomic writes), so the tree is invalidated.
+    pub(crate) fn abort_atomic(&mut self) {
+        match self.pending.take() {
+            Some(_) => self.checkpoints.clear(),
+            None => self.invalidate(),
+        }
+    }
+
+    /// Applies the pending updates if the atomic batch succeeded, and invalidates the tree otherwise.
+    pub(crate) fn finish_atomic(&mut self, is_success: bool) {
+        self.checkpoints.clear();
+        if let (Some(tree), Some(pending)) = (&mut self.tree, self.pending.take()) {
+            if !is_success || Self::apply(tree, pending).is_err() {
+                self.invalidate();
+            }
+        }
+    }
+
+    /// Applies the given updates to the given tree.
+    fn apply(
+        tree: &mut FinalizeStateTree<N>,
+        updates: impl IntoIterator<Item = (Field<N>, Option<Field<N>>)>,
+    ) -> Result<()> {
+        for (key_id, value_id) in updates {
+            match value_id {
+                Some(value_id) => tree.insert(key_id, value_id)?,
+                None => {
+                    tree.remove(&key_id)?;
+                }
+            }
+        }
+        Ok(())
+    }
+}
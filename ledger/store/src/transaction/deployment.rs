@@ -262,7 +262,7 @@ pub trait DeploymentStorage<N: Network>: Clone + Send + Sync {
         // Check if the program ID is for 'credits.aleo'.
         // This case is handled separately, as it is a default program of the VM.
         // TODO (howardwu): After we update 'fee' rules and 'Ratify' in genesis, we can remove this.
-        if program_id == &ProgramID::from_str("credits.aleo")? {
+        if program_id == &ProgramID::intern("credits.aleo")? {
             return Ok(None);
         }
 
@@ -300,7 +300,7 @@ pub trait DeploymentStorage<N: Network>: Clone + Send + Sync {
         // Check if the program ID is for 'credits.aleo'.
         // This case is handled separately, as it is a default program of the VM.
         // TODO (howardwu): After we update 'fee' rules and 'Ratify' in genesis, we can remove this.
-        if program_id == &ProgramID::from_str("credits.aleo")? {
+        if program_id == &ProgramID::intern("credits.aleo")? {
             return Ok(None);
         }
 
@@ -315,7 +315,7 @@ pub trait DeploymentStorage<N: Network>: Clone + Send + Sync {
         // Check if the program ID is for 'credits.aleo'.
         // This case is handled separately, as it is a default program of the VM.
         // TODO (howardwu): After we update 'fee' rules and 'Ratify' in genesis, we can remove this.
-        if program_id == &ProgramID::from_str("credits.aleo")? {
+        if program_id == &ProgramID::intern("credits.aleo")? {
             return Ok(Some(Program::credits()?));
         }
 
@@ -340,7 +340,7 @@ pub trait DeploymentStorage<N: Network>: Clone + Send + Sync {
         // Check if the program ID is for 'credits.aleo'.
         // This case is handled separately, as it is a default program of the VM.
         // TODO (howardwu): After we update 'fee' rules and 'Ratify' in genesis, we can remove this.
-        if program_id == &ProgramID::from_str("credits.aleo")? {
+        if program_id == &ProgramID::intern("credits.aleo")? {
             // Load the verifying key.
             let verifying_key = N::get_credits_verifying_key(function_name.to_string())?;
             // Retrieve the number of public and private variables.
@@ -372,7 +372,7 @@ pub trait DeploymentStorage<N: Network>: Clone + Send + Sync {
         // Check if the program ID is for 'credits.aleo'.
         // This case is handled separately, as it is a default program of the VM.
         // TODO (howardwu): After we update 'fee' rules and 'Ratify' in genesis, we can remove this.
-        if program_id == &ProgramID::from_str("credits.aleo")? {
+        if program_id == &ProgramID::intern("credits.aleo")? {
             return Ok(None);
         }
 
@@ -439,7 +439,7 @@ pub trait DeploymentStorage<N: Network>: Clone + Send + Sync {
         // Check if the program ID is for 'credits.aleo'.
         // This case is handled separately, as it is a default program of the VM.
         // TODO (howardwu): After we update 'fee' rules and 'Ratify' in genesis, we can remove this.
-        if program_id == &ProgramID::from_str("credits.aleo")? {
+        if program_id == &ProgramID::intern("credits.aleo")? {
             return Ok(None);
         }
 
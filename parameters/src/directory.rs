@@ -0,0 +1,162 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::errors::ParameterError;
+
+use parking_lot::RwLock;
+use std::path::{Path, PathBuf};
+
+/// The environment variable that overrides the directory of the parameter files.
+pub const PARAMETERS_DIR_ENV: &str = "SNARKVM_PARAMETERS_DIR";
+
+lazy_static! {
+    /// The directory of the parameter files, once it is set or resolved.
+    static ref PARAMETERS_DIR: RwLock<Option<PathBuf>> = RwLock::new(None);
+}
+
+/// Sets the directory of the parameter files for this process, in place of the default `~/.aleo` directory.
+///
+/// This must be called before any parameter file is loaded. Once the directory is set or resolved,
+/// it is fixed for the lifetime of the process, so setting a different directory returns an error.
+pub fn set_parameters_dir(directory: impl Into<PathBuf>) -> Result<(), ParameterError> {
+    let directory = directory.into();
+    let mut current = PARAMETERS_DIR.write();
+    match &*current {
+        Some(current) if *current != directory => Err(ParameterError::Message(format!(
+            "The parameters directory is already set to {current:?}, and cannot be changed to {directory:?}"
+        ))),
+        _ => {
+            *current = Some(directory);
+            Ok(())
+        }
+    }
+}
+
+/// Returns the directory of the parameter files for this process.
+///
+/// The directory is, in order of precedence, the one given to `set_parameters_dir`, the one in the
+/// `SNARKVM_PARAMETERS_DIR` environment variable, or the default `~/.aleo` directory.
+pub fn parameters_dir() -> PathBuf {
+    // Return the directory, if it is already set or resolved.
+    if let Some(directory) = &*PARAMETERS_DIR.read() {
+        return directory.clone();
+    }
+    // Otherwise, resolve the directory, and fix it for the lifetime of the process.
+    PARAMETERS_DIR
+        .write()
+        .get_or_insert_with(|| {
+            std::env::var_os(PARAMETERS_DIR_ENV)
+                .filter(|directory| !directory.is_empty())
+                .map(PathBuf::from)
+                .unwrap_or_else(aleo_std::aleo_dir)
+        })
+        .clone()
+}
+
+/// A lock on a parameter file, held while the file is downloaded and stored,
+/// so that processes sharing the parameters directory do not download the same file concurrently.
+///
+/// The lock is a `.lock` file next to the parameter file, and is released when dropped.
+#[cfg(not(feature = "wasm"))]
+pub struct ParameterFileLock {
+    /// The path of the lock file.
+    path: PathBuf,
+}
+
+#[cfg(not(feature = "wasm"))]
+impl ParameterFileLock {
+    /// The interval at which a held lock is polled.
+    const POLL_INTERVAL: std::time::Duration = std::time::Duration::from_millis(100);
+    /// The age after which a lock is considered abandoned (e.g. its holder was killed mid-download).
+    const STALE_AFTER: std::time::Duration = std::time::Duration::from_secs(30 * 60);
+
+    /// Acquires the lock on the given parameter file, waiting while another process holds it.
+    /// Returns `None` if the lock file cannot be created (e.g. on a read-only filesystem).
+    pub fn acquire(file_path: &Path) -> Option<Self> {
+        // Compose the path of the lock file.
+        let mut path = file_path.as_os_str().to_owned();
+        path.push(".lock");
+        let path = PathBuf::from(path);
+
+        // Ensure the folders up to the lock file all exist.
+        if let Some(directory) = path.parent() {
+            let _ = std::fs::create_dir_all(directory);
+        }
+
+        loop {
+            match std::fs::OpenOptions::new().write(true).create_new(true).open(&path) {
+                Ok(_) => return Some(Self { path }),
+                Err(error) if error.kind() == std::io::ErrorKind::AlreadyExists => {
+                    // If the lock is abandoned, remove it and retry.
+                    let is_stale = std::fs::metadata(&path)
+                        .and_then(|metadata| metadata.modified())
+                        .map(|modified| modified.elapsed().map_or(false, |age| age > Self::STALE_AFTER))
+                        .unwrap_or(false);
+                    match is_stale {
+                        true => {
+                            let _ = std::fs::remove_file(&path);
+                        }
+                        false => std::thread::sleep(Self::POLL_INTERVAL),
+                    }
+                }
+                Err(_) => return None,
+            }
+        }
+    }
+}
+
+#[cfg(not(feature = "wasm"))]
+impl Drop for ParameterFileLock {
+    /// Releases the lock.
+    fn drop(&mut self) {
+        let _ = std::fs::remove_file(&self.path);
+    }
+}
+
+#[cfg(all(test, not(feature = "wasm")))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parameter_file_lock() {
+        // Compose a unique parameter file path.
+        let directory = std::env::temp_dir().join(format!("snarkvm-parameters-lock-{}", std::process::id()));
+        let file_path = directory.join("resources").join("test.prover");
+        let lock_path = directory.join("resources").join("test.prover.lock");
+
+        // Acquire the lock.
+        let lock = ParameterFileLock::acquire(&file_path).unwrap();
+        assert!(lock_path.exists());
+
+        // Ensure another holder waits until the lock is released.
+        let (sender, receiver) = std::sync::mpsc::channel();
+        let waiter = {
+            let file_path = file_path.clone();
+            std::thread::spawn(move || {
+                let _lock = ParameterFileLock::acquire(&file_path).unwrap();
+                sender.send(()).unwrap();
+            })
+        };
+        assert!(receiver.recv_timeout(std::time::Duration::from_millis(300)).is_err());
+
+        // Release the lock, and ensure the other holder acquires it.
+        drop(lock);
+        receiver.recv_timeout(std::time::Duration::from_secs(10)).unwrap();
+        waiter.join().unwrap();
+        assert!(!lock_path.exists());
+
+        std::fs::remove_dir_all(directory).unwrap();
+    }
+}
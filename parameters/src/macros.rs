@@ -60,9 +60,15 @@ macro_rules! impl_store_and_remote_fetch {
             directory_path.pop();
             let _ = std::fs::create_dir_all(directory_path)?;
 
-            // Attempt to write the parameter buffer to a file.
-            match std::fs::File::create(file_path) {
-                Ok(mut file) => file.write_all(&buffer)?,
+            // Attempt to write the parameter buffer to a temporary file, and move it into place,
+            // so that processes sharing the parameters directory never read a partially-written file.
+            let mut temporary_path = file_path.as_os_str().to_owned();
+            temporary_path.push(format!(".{}.tmp", std::process::id()));
+            match std::fs::File::create(&temporary_path) {
+                Ok(mut file) => {
+                    file.write_all(&buffer)?;
+                    std::fs::rename(&temporary_path, file_path)?;
+                }
                 Err(error) => eprintln!("{}", error),
             }
             Ok(())
@@ -170,7 +176,7 @@ macro_rules! impl_load_bytes_logic_local {
 macro_rules! impl_load_bytes_logic_remote {
     ($remote_url: expr, $local_dir: expr, $filename: expr, $metadata: expr, $expected_checksum: expr, $expected_size: expr) => {
         // Compose the correct file path for the parameter file.
-        let mut file_path = aleo_std::aleo_dir();
+        let mut file_path = $crate::parameters_dir();
         file_path.push($local_dir);
         file_path.push($filename);
 
@@ -195,23 +201,33 @@ macro_rules! impl_load_bytes_logic_remote {
             // Load remote file
             cfg_if::cfg_if! {
                 if #[cfg(not(feature = "wasm"))] {
-                    let mut buffer = vec![];
-                    Self::remote_fetch(&mut buffer, &url)?;
-
-                    // Ensure the checksum matches.
-                    let candidate_checksum = checksum!(&buffer);
-                    if $expected_checksum != candidate_checksum {
-                        return checksum_error!($expected_checksum, candidate_checksum)
-                    }
+                    // Lock the parameter file, so that processes sharing the parameters directory download it once.
+                    // Note: If the lock cannot be created (e.g. on a read-only filesystem), the file is
+                    // downloaded without it.
+                    let _lock = $crate::ParameterFileLock::acquire(&file_path);
+
+                    // Load the parameter file, if another process stored it while this process waited for the lock.
+                    if file_path.exists() {
+                        std::fs::read(&file_path)?
+                    } else {
+                        let mut buffer = vec![];
+                        Self::remote_fetch(&mut buffer, &url)?;
+
+                        // Ensure the checksum matches.
+                        let candidate_checksum = checksum!(&buffer);
+                        if $expected_checksum != candidate_checksum {
+                            return checksum_error!($expected_checksum, candidate_checksum)
+                        }
 
-                    match Self::store_bytes(&buffer, &file_path) {
-                        Ok(()) => buffer,
-                        Err(_) => {
-                            eprintln!(
-                                "\n❗ Error - Failed to store \"{}\" locally. Please download this file manually and ensure it is stored in {:?}.\n",
-                                $filename, file_path
-                            );
-                            buffer
+                        match Self::store_bytes(&buffer, &file_path) {
+                            Ok(()) => buffer,
+                            Err(_) => {
+                                eprintln!(
+                                    "\n❗ Error - Failed to store \"{}\" locally. Please download this file manually and ensure it is stored in {:?}.\n",
+                                    $filename, file_path
+                                );
+                                buffer
+                            }
                         }
                     }
                 } else if #[cfg(feature = "wasm")] {
@@ -19,6 +19,9 @@ pub use genesis::*;
 pub mod powers;
 pub use powers::*;
 
+pub mod stored_powers;
+pub use stored_powers::*;
+
 /// The restrictions list as a JSON-compatible string.
 pub const RESTRICTIONS_LIST: &str = include_str!("./resources/restrictions.json");
 
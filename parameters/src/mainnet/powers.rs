@@ -48,7 +48,7 @@ const NUM_POWERS_27: usize = 1 << 27;
 const NUM_POWERS_28: usize = 1 << 28;
 
 /// The maximum degree supported by the SRS.
-const MAX_NUM_POWERS: usize = NUM_POWERS_28;
+pub(super) const MAX_NUM_POWERS: usize = NUM_POWERS_28;
 
 lazy_static::lazy_static! {
     static ref POWERS_OF_BETA_G_15: Vec<u8> = Degree15::load_bytes().expect("Failed to load powers of beta in universal SRS");
@@ -423,6 +423,10 @@ impl<E: PairingEngine> PowersOfBetaG<E> {
             let additional_powers = Vec::deserialize_uncompressed_unchecked(&*additional_bytes)?;
             // Extend the powers.
             self.powers_of_beta_g.extend(&additional_powers);
+            // Record that the powers file is loaded, if it is one of the files stored in the parameters directory.
+            if let Ok(file) = PowersFile::new(*num_powers, false) {
+                file.set_loaded();
+            }
         }
         ensure!(self.powers_of_beta_g.len() == final_power_of_two, "Loaded an incorrect number of powers");
         Ok(())
@@ -500,6 +504,10 @@ impl<E: PairingEngine> PowersOfBetaG<E> {
             let additional_powers = Vec::deserialize_uncompressed_unchecked(&*additional_bytes)?;
 
             final_powers.extend(additional_powers.iter());
+            // Record that the powers file is loaded, if it is one of the files stored in the parameters directory.
+            if let Ok(file) = PowersFile::new(*num_powers, true) {
+                file.set_loaded();
+            }
         }
         final_powers.extend(self.shifted_powers_of_beta_g.iter());
         self.shifted_powers_of_beta_g = final_powers;
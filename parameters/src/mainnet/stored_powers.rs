@@ -0,0 +1,278 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+use anyhow::{anyhow, bail, ensure, Result};
+use parking_lot::RwLock;
+use std::ops::Range;
+#[cfg(not(feature = "wasm"))]
+use std::path::PathBuf;
+
+lazy_static::lazy_static! {
+    /// The powers files that have been loaded into an SRS by this process.
+    static ref LOADED_POWERS_FILES: RwLock<Vec<PowersFile>> = RwLock::new(Vec::new());
+}
+
+/// A file of powers of beta G of the universal SRS, which is downloaded on demand
+/// into the parameters directory, and is never removed automatically.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct PowersFile {
+    /// The number of powers of beta G that the SRS holds once this file is loaded.
+    num_powers: usize,
+    /// Whether the file holds the shifted powers of beta G.
+    is_shifted: bool,
+}
+
+impl PowersFile {
+    /// The smallest number of powers of a file downloaded on demand.
+    const MIN_NUM_POWERS: usize = 1 << 17;
+
+    /// Initializes a new powers file, for the given number of (shifted) powers.
+    pub fn new(num_powers: usize, is_shifted: bool) -> Result<Self> {
+        // Ensure the number of powers corresponds to a powers file.
+        let max_num_powers = match is_shifted {
+            true => powers::MAX_NUM_POWERS / 2,
+            false => powers::MAX_NUM_POWERS,
+        };
+        ensure!(num_powers.is_power_of_two(), "The number of powers must be a power of two, found {num_powers}");
+        ensure!(
+            (Self::MIN_NUM_POWERS..=max_num_powers).contains(&num_powers),
+            "There is no powers file for {num_powers} powers"
+        );
+        Ok(Self { num_powers, is_shifted })
+    }
+
+    /// Returns every powers file, from the smallest to the largest, starting with the non-shifted files.
+    pub fn all() -> impl Iterator<Item = Self> {
+        let files = |is_shifted, max_num_powers: usize| {
+            (Self::MIN_NUM_POWERS.trailing_zeros()..=max_num_powers.trailing_zeros())
+                .map(move |degree| Self { num_powers: 1 << degree, is_shifted })
+        };
+        files(false, powers::MAX_NUM_POWERS).chain(files(true, powers::MAX_NUM_POWERS / 2))
+    }
+
+    /// Returns the number of powers of beta G that the SRS holds once this file is loaded.
+    pub const fn num_powers(&self) -> usize {
+        self.num_powers
+    }
+
+    /// Returns `true` if the file holds the shifted powers of beta G.
+    pub const fn is_shifted(&self) -> bool {
+        self.is_shifted
+    }
+
+    /// Returns the range of the powers of beta G held in the file.
+    pub const fn range(&self) -> Range<usize> {
+        match self.is_shifted {
+            true => (powers::MAX_NUM_POWERS - self.num_powers)..(powers::MAX_NUM_POWERS - self.num_powers / 2),
+            false => (self.num_powers / 2)..self.num_powers,
+        }
+    }
+
+    /// Returns `true` if the file has been loaded into an SRS by this process.
+    pub fn is_loaded(&self) -> bool {
+        LOADED_POWERS_FILES.read().contains(self)
+    }
+
+    /// Records that the file has been loaded into an SRS by this process.
+    pub(super) fn set_loaded(&self) {
+        let mut loaded = LOADED_POWERS_FILES.write();
+        if !loaded.contains(self) {
+            loaded.push(*self);
+        }
+    }
+
+    /// Returns the metadata of the file.
+    fn metadata(&self) -> Result<&'static str> {
+        Ok(match (self.is_shifted, self.num_powers.trailing_zeros()) {
+            (false, 17) => Degree17::METADATA,
+            (false, 18) => Degree18::METADATA,
+            (false, 19) => Degree19::METADATA,
+            (false, 20) => Degree20::METADATA,
+            (false, 21) => Degree21::METADATA,
+            (false, 22) => Degree22::METADATA,
+            (false, 23) => Degree23::METADATA,
+            (false, 24) => Degree24::METADATA,
+            (false, 25) => Degree25::METADATA,
+            (false, 26) => Degree26::METADATA,
+            (false, 27) => Degree27::METADATA,
+            (false, 28) => Degree28::METADATA,
+            (true, 17) => ShiftedDegree17::METADATA,
+            (true, 18) => ShiftedDegree18::METADATA,
+            (true, 19) => ShiftedDegree19::METADATA,
+            (true, 20) => ShiftedDegree20::METADATA,
+            (true, 21) => ShiftedDegree21::METADATA,
+            (true, 22) => ShiftedDegree22::METADATA,
+            (true, 23) => ShiftedDegree23::METADATA,
+            (true, 24) => ShiftedDegree24::METADATA,
+            (true, 25) => ShiftedDegree25::METADATA,
+            (true, 26) => ShiftedDegree26::METADATA,
+            (true, 27) => ShiftedDegree27::METADATA,
+            _ => bail!("There is no powers file for {} powers", self.num_powers),
+        })
+    }
+
+    /// Returns the expected checksum and size of the file.
+    fn expected_checksum_and_size(&self) -> Result<(String, usize)> {
+        let metadata: serde_json::Value = serde_json::from_str(self.metadata()?)?;
+        let checksum = metadata["checksum"].as_str().ok_or_else(|| anyhow!("Failed to parse the checksum"))?;
+        let size = metadata["size"].as_u64().ok_or_else(|| anyhow!("Failed to parse the size"))?;
+        Ok((checksum.to_string(), size as usize))
+    }
+
+    /// Returns the name of the file, without its version.
+    fn unversioned_file_name(&self) -> String {
+        match self.is_shifted {
+            true => format!("shifted-powers-of-beta-{}.usrs", self.num_powers.trailing_zeros()),
+            false => format!("powers-of-beta-{}.usrs", self.num_powers.trailing_zeros()),
+        }
+    }
+}
+
+#[cfg(not(feature = "wasm"))]
+impl PowersFile {
+    /// Returns the path of the file in the parameters directory.
+    pub fn path(&self) -> Result<PathBuf> {
+        let (checksum, _) = self.expected_checksum_and_size()?;
+        let version = checksum.get(0..7).ok_or_else(|| anyhow!("The checksum is too short"))?;
+        Ok(Self::directory().join(format!("{}.{version}", self.unversioned_file_name())))
+    }
+
+    /// Returns `true` if the file is stored in the parameters directory.
+    pub fn is_stored(&self) -> bool {
+        self.path().map_or(false, |path| path.exists())
+    }
+
+    /// Ensures the stored file has the expected size and checksum.
+    ///
+    /// Note: This reads the entire file, which spans several gigabytes for the largest files.
+    pub fn validate(&self) -> Result<()> {
+        let (expected_checksum, expected_size) = self.expected_checksum_and_size()?;
+        let bytes = std::fs::read(self.path()?)?;
+        ensure!(bytes.len() == expected_size, crate::errors::ParameterError::SizeMismatch(expected_size, bytes.len()));
+        let candidate_checksum = checksum!(&bytes);
+        ensure!(
+            candidate_checksum == expected_checksum,
+            crate::errors::ParameterError::ChecksumMismatch(expected_checksum, candidate_checksum)
+        );
+        Ok(())
+    }
+
+    /// Removes the file, along with any outdated version of it, from the parameters directory.
+    /// Returns the paths of the removed files.
+    ///
+    /// This fails if the file has been loaded into an SRS by this process, as the provers
+    /// of this process may need it again, e.g. once the process restarts.
+    pub fn evict(&self) -> Result<Vec<PathBuf>> {
+        // Ensure the file is not in use by this process.
+        ensure!(!self.is_loaded(), "Cannot evict the powers file for {} powers, as it is loaded", self.num_powers);
+
+        // Collect the paths of every version of the file.
+        let prefix = format!("{}.", self.unversioned_file_name());
+        let paths = match std::fs::read_dir(Self::directory()) {
+            Ok(entries) => entries
+                .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+                .filter(|path| {
+                    path.file_name().and_then(|name| name.to_str()).map_or(false, |name| {
+                        // Skip the lock files and the temporary files of ongoing downloads.
+                        name.strip_prefix(&prefix)
+                            .map_or(false, |version| version.chars().all(|c| c.is_ascii_hexdigit()))
+                    })
+                })
+                .collect::<Vec<_>>(),
+            Err(error) if error.kind() == std::io::ErrorKind::NotFound => return Ok(vec![]),
+            Err(error) => return Err(error.into()),
+        };
+
+        // Remove each version of the file, while holding its lock, so that no other process is storing it.
+        for path in &paths {
+            let _lock = crate::ParameterFileLock::acquire(path);
+            std::fs::remove_file(path)?;
+        }
+        Ok(paths)
+    }
+
+    /// Returns the directory of the powers files.
+    fn directory() -> PathBuf {
+        crate::parameters_dir().join("resources")
+    }
+}
+
+/// Returns the powers files stored in the parameters directory.
+#[cfg(not(feature = "wasm"))]
+pub fn stored_powers_files() -> Vec<PowersFile> {
+    PowersFile::all().filter(PowersFile::is_stored).collect()
+}
+
+/// Removes the stored powers files (and their outdated versions) that are not needed to serve
+/// circuits of up to `max_num_powers` powers, and returns the paths of the removed files.
+///
+/// The files that have been loaded into an SRS by this process are retained.
+#[cfg(not(feature = "wasm"))]
+pub fn evict_stored_powers(max_num_powers: usize) -> Result<Vec<PathBuf>> {
+    let mut evicted = Vec::new();
+    for file in PowersFile::all() {
+        // Determine if the file may be needed to serve circuits of up to `max_num_powers` powers.
+        let range = file.range();
+        let is_needed = match file.is_shifted() {
+            true => range.end > powers::MAX_NUM_POWERS.saturating_sub(max_num_powers),
+            false => range.start < max_num_powers,
+        };
+        if !is_needed && !file.is_loaded() {
+            evicted.extend(file.evict()?);
+        }
+    }
+    Ok(evicted)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_powers_file_ranges() {
+        let (normal, shifted): (Vec<_>, Vec<_>) = PowersFile::all().partition(|file| !file.is_shifted());
+        assert_eq!(normal.len(), 12);
+        assert_eq!(shifted.len(), 11);
+
+        // Ensure the ranges of the files are contiguous, and span the powers downloaded on demand.
+        let mut end = PowersFile::MIN_NUM_POWERS / 2;
+        for file in &normal {
+            assert_eq!(file.range().start, end);
+            end = file.range().end;
+            // Ensure the file has metadata.
+            assert!(file.expected_checksum_and_size().is_ok());
+        }
+        assert_eq!(end, powers::MAX_NUM_POWERS);
+
+        let mut start = powers::MAX_NUM_POWERS - PowersFile::MIN_NUM_POWERS / 2;
+        for file in &shifted {
+            assert_eq!(file.range().end, start);
+            start = file.range().start;
+            // Ensure the file has metadata.
+            assert!(file.expected_checksum_and_size().is_ok());
+        }
+        assert_eq!(start, powers::MAX_NUM_POWERS / 2);
+
+        // Ensure only the files downloaded on demand can be constructed.
+        assert!(PowersFile::new(1 << 17, false).is_ok());
+        assert!(PowersFile::new(1 << 28, false).is_ok());
+        assert!(PowersFile::new(1 << 27, true).is_ok());
+        assert!(PowersFile::new(1 << 16, false).is_err());
+        assert!(PowersFile::new(1 << 28, true).is_err());
+        assert!(PowersFile::new(3 << 17, false).is_err());
+    }
+}
@@ -0,0 +1,127 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! An audit mode for nondeterminism in consensus-critical code paths.
+//!
+//! Finalize and verification results must be identical on every platform. When the `audit` feature is enabled,
+//! these code paths run inside a [`DeterministicScope`], and any platform-dependent operation (floating-point math,
+//! hash map iteration order, or wall-clock time) that is [`record`]ed within a scope is reported to the installed
+//! [`AuditHook`], or panics if no hook is installed. Scopes are tracked per thread.
+
+use std::{cell::Cell, sync::RwLock};
+
+/// A source of platform-dependent behavior.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum NondeterminismSource {
+    /// A floating-point operation.
+    Float,
+    /// An iteration over a hash map or hash set, whose order is unspecified.
+    HashIteration,
+    /// A read of the wall-clock or monotonic time.
+    Time,
+}
+
+/// A hook that is invoked with the scope, source, and context of a nondeterministic operation.
+pub type AuditHook = fn(scope: &'static str, source: NondeterminismSource, context: &str);
+
+/// The installed audit hook.
+static AUDIT_HOOK: RwLock<Option<AuditHook>> = RwLock::new(None);
+
+thread_local! {
+    /// The innermost deterministic scope on this thread.
+    static CURRENT_SCOPE: Cell<Option<&'static str>> = const { Cell::new(None) };
+}
+
+/// Installs the given audit hook, replacing any existing hook. If `None`, violations panic.
+pub fn set_audit_hook(hook: Option<AuditHook>) {
+    *AUDIT_HOOK.write().unwrap_or_else(|error| error.into_inner()) = hook;
+}
+
+/// Returns the innermost deterministic scope on this thread, if any.
+pub fn current_scope() -> Option<&'static str> {
+    CURRENT_SCOPE.with(|scope| scope.get())
+}
+
+/// Records a nondeterministic operation. If it occurs within a deterministic scope,
+/// the audit hook is invoked, or this function panics if no hook is installed.
+pub fn record(source: NondeterminismSource, context: &str) {
+    let Some(scope) = current_scope() else {
+        return;
+    };
+    match *AUDIT_HOOK.read().unwrap_or_else(|error| error.into_inner()) {
+        Some(hook) => hook(scope, source, context),
+        None => panic!("Nondeterministic operation ({source:?}) in '{scope}' - {context}"),
+    }
+}
+
+/// A guard that marks the current thread as executing a deterministic code path, until it is dropped.
+pub struct DeterministicScope {
+    /// The enclosing scope, which is restored when this scope is dropped.
+    previous: Option<&'static str>,
+}
+
+impl DeterministicScope {
+    /// Enters the deterministic scope with the given name.
+    pub fn enter(name: &'static str) -> Self {
+        Self { previous: CURRENT_SCOPE.with(|scope| scope.replace(Some(name))) }
+    }
+}
+
+impl Drop for DeterministicScope {
+    /// Restores the enclosing scope.
+    fn drop(&mut self) {
+        CURRENT_SCOPE.with(|scope| scope.set(self.previous));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    static NUM_VIOLATIONS: AtomicUsize = AtomicUsize::new(0);
+
+    fn count_violation(_scope: &'static str, _source: NondeterminismSource, _context: &str) {
+        NUM_VIOLATIONS.fetch_add(1, Ordering::SeqCst);
+    }
+
+    #[test]
+    fn test_deterministic_scope() {
+        set_audit_hook(Some(count_violation));
+
+        // Ensure operations outside of a scope are not reported.
+        assert_eq!(current_scope(), None);
+        record(NondeterminismSource::Time, "outside");
+        assert_eq!(NUM_VIOLATIONS.load(Ordering::SeqCst), 0);
+
+        {
+            // Ensure operations inside a scope are reported.
+            let _outer = DeterministicScope::enter("outer");
+            record(NondeterminismSource::Float, "inside");
+            assert_eq!(NUM_VIOLATIONS.load(Ordering::SeqCst), 1);
+
+            // Ensure nested scopes restore the enclosing scope.
+            {
+                let _inner = DeterministicScope::enter("inner");
+                assert_eq!(current_scope(), Some("inner"));
+            }
+            assert_eq!(current_scope(), Some("outer"));
+        }
+        assert_eq!(current_scope(), None);
+
+        set_audit_hook(None);
+    }
+}
@@ -45,7 +45,7 @@ impl<N: Network> Process<N> {
         let timer = timer!("Process::authorize_fee_private");
 
         // Ensure the fee has the correct program ID.
-        let program_id = ProgramID::from_str("credits.aleo")?;
+        let program_id = ProgramID::intern("credits.aleo")?;
         // Ensure the fee has the correct function.
         let function_name = Identifier::from_str("fee_private")?;
 
@@ -86,7 +86,7 @@ impl<N: Network> Process<N> {
         let timer = timer!("Process::authorize_fee_public");
 
         // Ensure the fee has the correct program ID.
-        let program_id = ProgramID::from_str("credits.aleo")?;
+        let program_id = ProgramID::intern("credits.aleo")?;
         // Ensure the fee has the correct function.
         let function_name = Identifier::from_str("fee_public")?;
 
@@ -0,0 +1,230 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{Assignments, CallStack, Process, Stack, StackExecute};
+
+use aleo_std::prelude::{finish, lap, timer};
+use console::{
+    account::{Address, PrivateKey},
+    prelude::*,
+    program::{Identifier, ProgramID, Request, ValueType},
+};
+use indexmap::IndexMap;
+use rand::{rngs::StdRng, SeedableRng};
+use synthesizer_program::{Program, StackProgram};
+
+#[cfg(not(feature = "serial"))]
+use rayon::prelude::*;
+
+/// The result of checking a program for deployment, without generating the proving keys and certificates.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DeploymentCheck<N: Network> {
+    /// The program ID.
+    program_id: ProgramID<N>,
+    /// The mapping of function names to their (number of constraints, number of variables).
+    functions: IndexMap<Identifier<N>, (u64, u64)>,
+}
+
+impl<N: Network> DeploymentCheck<N> {
+    /// Returns the program ID.
+    pub const fn program_id(&self) -> &ProgramID<N> {
+        &self.program_id
+    }
+
+    /// Returns the mapping of function names to their (number of constraints, number of variables).
+    pub const fn functions(&self) -> &IndexMap<Identifier<N>, (u64, u64)> {
+        &self.functions
+    }
+
+    /// Returns the number of constraints for the given function, if it exists.
+    pub fn num_constraints(&self, function_name: &Identifier<N>) -> Option<u64> {
+        self.functions.get(function_name).map(|(num_constraints, _)| *num_constraints)
+    }
+
+    /// Returns the number of variables for the given function, if it exists.
+    pub fn num_variables(&self, function_name: &Identifier<N>) -> Option<u64> {
+        self.functions.get(function_name).map(|(_, num_variables)| *num_variables)
+    }
+
+    /// Returns the total number of constraints across all functions.
+    pub fn num_combined_constraints(&self) -> Result<u64> {
+        self.functions.values().try_fold(0u64, |acc, (num_constraints, _)| {
+            acc.checked_add(*num_constraints)
+                .ok_or_else(|| anyhow!("Overflow when counting constraints for '{}'", self.program_id))
+        })
+    }
+
+    /// Returns the total number of variables across all functions.
+    pub fn num_combined_variables(&self) -> Result<u64> {
+        self.functions.values().try_fold(0u64, |acc, (_, num_variables)| {
+            acc.checked_add(*num_variables)
+                .ok_or_else(|| anyhow!("Overflow when counting variables for '{}'", self.program_id))
+        })
+    }
+
+    /// Returns the synthesis cost in microcredits, as it would be charged for the deployment.
+    pub fn synthesis_cost(&self) -> Result<u64> {
+        self.num_combined_variables()?
+            .checked_add(self.num_combined_constraints()?)
+            .and_then(|num_combined| num_combined.checked_mul(N::SYNTHESIS_FEE_MULTIPLIER))
+            .ok_or_else(|| anyhow!("The synthesis cost computation overflowed for '{}'", self.program_id))
+    }
+}
+
+impl<N: Network> Process<N> {
+    /// Checks that the given program is deployable, without generating the proving keys and certificates.
+    ///
+    /// This ensures the program is well-formed, its imports resolve, and each function synthesizes
+    /// within the deployment limits. On success, returns the constraint and variable counts per function.
+    #[inline]
+    pub fn check_deployment_syntactic<A: circuit::Aleo<Network = N>, R: Rng + CryptoRng>(
+        &self,
+        program: &Program<N>,
+        rng: &mut R,
+    ) -> Result<DeploymentCheck<N>> {
+        let timer = timer!("Process::check_deployment_syntactic");
+
+        // Retrieve the program ID.
+        let program_id = program.id();
+        // Ensure the program does not already exist in the process.
+        ensure!(!self.contains_program(program_id), "Program '{program_id}' already exists");
+
+        // Ensure the program is well-formed and its imports resolve, by computing the stack.
+        let stack = Stack::new(self, program)?;
+        lap!(timer, "Compute the stack");
+
+        // Synthesize the circuits of the program.
+        let check = stack.check_deployment_syntactic::<A, R>(rng);
+        finish!(timer, "Synthesize the circuits");
+
+        check
+    }
+}
+
+impl<N: Network> Stack<N> {
+    /// Synthesizes each function in the program, and returns the constraint and variable counts per function.
+    /// Unlike `Stack::deploy`, this does not generate the proving keys, verifying keys, or certificates.
+    #[inline]
+    pub fn check_deployment_syntactic<A: circuit::Aleo<Network = N>, R: Rng + CryptoRng>(
+        &self,
+        rng: &mut R,
+    ) -> Result<DeploymentCheck<N>> {
+        // Retrieve the program ID.
+        let program_id = *self.program_id();
+
+        // Synthesize the circuits.
+        let functions = self
+            .synthesize_assignments::<A, R>(rng)?
+            .into_iter()
+            .map(|(function_name, assignment)| {
+                // Note: The hiding constraint added by Varuna is included, to match the verifying key.
+                (function_name, (assignment.num_constraints().saturating_add(1), assignment.num_variables()))
+            })
+            .collect();
+
+        // Construct the deployment check.
+        let check = DeploymentCheck { program_id, functions };
+
+        // Ensure the number of combined variables does not exceed the deployment limit.
+        let num_combined_variables = check.num_combined_variables()?;
+        ensure!(
+            num_combined_variables <= N::MAX_DEPLOYMENT_VARIABLES,
+            "Program '{program_id}' exceeds the variable limit ({num_combined_variables} > {})",
+            N::MAX_DEPLOYMENT_VARIABLES
+        );
+        // Ensure the number of combined constraints does not exceed the deployment limit.
+        let num_combined_constraints = check.num_combined_constraints()?;
+        ensure!(
+            num_combined_constraints <= N::MAX_DEPLOYMENT_CONSTRAINTS,
+            "Program '{program_id}' exceeds the constraint limit ({num_combined_constraints} > {})",
+            N::MAX_DEPLOYMENT_CONSTRAINTS
+        );
+
+        Ok(check)
+    }
+
+    /// Synthesizes the circuit of each function in the program, on sampled inputs, and returns the assignments.
+    pub(crate) fn synthesize_assignments<A: circuit::Aleo<Network = N>, R: Rng + CryptoRng>(
+        &self,
+        rng: &mut R,
+    ) -> Result<IndexMap<Identifier<N>, circuit::Assignment<N::Field>>> {
+        // Retrieve the program ID.
+        let program_id = *self.program_id();
+        // Ensure the program contains functions.
+        ensure!(!self.program().functions().is_empty(), "Program '{program_id}' has no functions");
+
+        // The `root_tvk` and `caller` are `None` when synthesizing an individual circuit.
+        let (root_tvk, caller) = (None, None);
+
+        // Construct the call stacks and assignments used to synthesize the circuits.
+        let mut call_stacks = Vec::with_capacity(self.program().functions().len());
+        for function in self.program().functions().values() {
+            // Initialize a burner private key.
+            let burner_private_key = PrivateKey::new(rng)?;
+            // Compute the burner address.
+            let burner_address = Address::try_from(&burner_private_key)?;
+            // Retrieve the input types.
+            let input_types = function.input_types();
+            // Sample the inputs.
+            let inputs = input_types
+                .iter()
+                .map(|input_type| match input_type {
+                    ValueType::ExternalRecord(locator) => {
+                        // Retrieve the external stack.
+                        let stack = self.get_external_stack(locator.program_id())?;
+                        // Sample the input.
+                        stack.sample_value(&burner_address, &ValueType::Record(*locator.resource()), rng)
+                    }
+                    _ => self.sample_value(&burner_address, input_type, rng),
+                })
+                .collect::<Result<Vec<_>>>()?;
+            // Compute the request, with a burner private key.
+            let request = Request::sign(
+                &burner_private_key,
+                program_id,
+                *function.name(),
+                inputs.into_iter(),
+                &input_types,
+                root_tvk,
+                true,
+                rng,
+            )?;
+            // Initialize the assignments.
+            let assignments = Assignments::<N>::default();
+            // Initialize the call stack, without a constraint limit or variable limit.
+            let call_stack =
+                CallStack::CheckDeployment(vec![request], burner_private_key, assignments.clone(), None, None);
+            // Append the function name, call stack, and assignments.
+            call_stacks.push((*function.name(), call_stack, assignments));
+        }
+
+        // Synthesize the circuits.
+        let rngs = (0..call_stacks.len()).map(|_| StdRng::from_seed(rng.gen())).collect::<Vec<_>>();
+        cfg_into_iter!(call_stacks)
+            .zip_eq(rngs)
+            .map(|((function_name, call_stack, assignments), mut rng)| {
+                // Synthesize the circuit.
+                if let Err(err) = self.execute_function::<A, _>(call_stack, caller, root_tvk, &mut rng) {
+                    bail!("Failed to synthesize the circuit for '{function_name}': {err}")
+                }
+                // Retrieve the assignment.
+                match assignments.read().last() {
+                    None => bail!("The assignment for function '{function_name}' is missing in '{program_id}'"),
+                    Some((assignment, _metrics)) => Ok((function_name, assignment.clone())),
+                }
+            })
+            .collect()
+    }
+}
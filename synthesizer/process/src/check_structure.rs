@@ -0,0 +1,173 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use console::program::ValueType;
+use ledger_block::Output;
+use synthesizer_program::StackMatches;
+
+impl<N: Network> Process<N> {
+    /// Checks that the inputs and outputs of the given transition match the signature of its function.
+    ///
+    /// The execution proof binds the transition to its inputs and outputs, but the verifier pads
+    /// the public inputs with zeros. As such, this check recomputes the expected number and types
+    /// of the inputs and outputs from the function, and rejects mismatches before the proof is checked.
+    pub fn check_transition_structure(&self, transition: &Transition<N>) -> Result<()> {
+        // Retrieve the stack.
+        let stack = self.get_stack(transition.program_id())?;
+        // Retrieve the function from the stack.
+        let function = stack.get_function_ref(transition.function_name())?;
+        // Retrieve the locator, for the error messages.
+        let locator = Locator::new(*transition.program_id(), *transition.function_name());
+
+        // Ensure the number of inputs matches the function.
+        let input_types = function.input_types();
+        ensure!(
+            transition.inputs().len() == input_types.len(),
+            "Transition '{}' for '{locator}' has {} inputs, but the function expects {}",
+            transition.id(),
+            transition.inputs().len(),
+            input_types.len()
+        );
+        // Ensure each input matches its type.
+        for (index, (input, input_type)) in transition.inputs().iter().zip_eq(&input_types).enumerate() {
+            let is_valid = match (input, input_type) {
+                (Input::Constant(_, plaintext), ValueType::Constant(plaintext_type))
+                | (Input::Public(_, plaintext), ValueType::Public(plaintext_type)) => match plaintext {
+                    Some(plaintext) => stack.matches_plaintext(plaintext, plaintext_type).is_ok(),
+                    None => true,
+                },
+                (Input::Private(..), ValueType::Private(..))
+                | (Input::Record(..), ValueType::Record(..))
+                | (Input::ExternalRecord(..), ValueType::ExternalRecord(..)) => true,
+                _ => false,
+            };
+            ensure!(is_valid, "Input {index} of transition '{}' does not match '{input_type}'", transition.id());
+        }
+
+        // Ensure the number of outputs matches the function.
+        let output_types = function.output_types();
+        ensure!(
+            transition.outputs().len() == output_types.len(),
+            "Transition '{}' for '{locator}' has {} outputs, but the function expects {}",
+            transition.id(),
+            transition.outputs().len(),
+            output_types.len()
+        );
+        // Ensure each output matches its type.
+        for (index, (output, output_type)) in transition.outputs().iter().zip_eq(&output_types).enumerate() {
+            let is_valid = match (output, output_type) {
+                (Output::Constant(_, plaintext), ValueType::Constant(plaintext_type))
+                | (Output::Public(_, plaintext), ValueType::Public(plaintext_type)) => match plaintext {
+                    Some(plaintext) => stack.matches_plaintext(plaintext, plaintext_type).is_ok(),
+                    None => true,
+                },
+                (Output::Future(_, future), ValueType::Future(future_locator)) => match future {
+                    Some(future) => stack.matches_future(future, future_locator).is_ok(),
+                    None => true,
+                },
+                (Output::Private(..), ValueType::Private(..))
+                | (Output::Record(..), ValueType::Record(..))
+                | (Output::ExternalRecord(..), ValueType::ExternalRecord(..)) => true,
+                _ => false,
+            };
+            ensure!(is_valid, "Output {index} of transition '{}' does not match '{output_type}'", transition.id());
+        }
+        Ok(())
+    }
+
+    /// Checks that the inputs and outputs of each transition in the given execution match its function.
+    pub fn check_execution_structure(&self, execution: &Execution<N>) -> Result<()> {
+        execution.transitions().try_for_each(|transition| self.check_transition_structure(transition))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::{account::Address, network::MainnetV0};
+
+    type CurrentNetwork = MainnetV0;
+    type CurrentAleo = circuit::network::AleoV0;
+
+    #[test]
+    fn test_check_transition_structure() {
+        let rng = &mut TestRng::default();
+
+        // Initialize a program with public, private, and record inputs and outputs.
+        let program = Program::<CurrentNetwork>::from_str(
+            r"
+program structure.aleo;
+
+record token:
+    owner as address.private;
+    amount as u64.private;
+
+function compute:
+    input r0 as u32.public;
+    input r1 as u32.private;
+    input r2 as address.private;
+    add r0 r1 into r3;
+    cast r2 1u64 into r4 as token.record;
+    output r3 as u32.public;
+    output r3 as u32.private;
+    output r4 as token.record;",
+        )
+        .unwrap();
+        let process = crate::test_helpers::sample_process(&program);
+
+        // Execute the function.
+        let private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+        let address = Address::try_from(&private_key).unwrap();
+        let inputs = ["1u32".to_string(), "2u32".to_string(), address.to_string()];
+        let authorization =
+            process.authorize::<CurrentAleo, _>(&private_key, program.id(), "compute", inputs.iter(), rng).unwrap();
+        let (_, trace) = process.execute::<CurrentAleo, _>(authorization, rng).unwrap();
+        let transition = trace.transitions()[0].clone();
+
+        // Ensure the transition matches the function.
+        process.check_transition_structure(&transition).unwrap();
+
+        // Reconstructs the transition with the given inputs and outputs.
+        let rebuild = |inputs: Vec<Input<CurrentNetwork>>, outputs: Vec<Output<CurrentNetwork>>| {
+            Transition::new(
+                *transition.program_id(),
+                *transition.function_name(),
+                inputs,
+                outputs,
+                *transition.tpk(),
+                *transition.tcm(),
+                *transition.scm(),
+            )
+            .unwrap()
+        };
+        let (inputs, outputs) = (transition.inputs().to_vec(), transition.outputs().to_vec());
+
+        // Ensure an extra output is rejected.
+        let mut padded = outputs.clone();
+        padded.push(Output::ExternalRecord(Field::zero()));
+        assert!(process.check_transition_structure(&rebuild(inputs.clone(), padded)).is_err());
+        // Ensure a missing input is rejected.
+        assert!(process.check_transition_structure(&rebuild(inputs[..2].to_vec(), outputs.clone())).is_err());
+        // Ensure an output of the wrong variant is rejected.
+        let mut rebound = outputs.clone();
+        rebound[1] = Output::Public(*outputs[1].id(), None);
+        assert!(process.check_transition_structure(&rebuild(inputs.clone(), rebound)).is_err());
+        // Ensure a public input of the wrong type is rejected.
+        let mut retyped = inputs.clone();
+        retyped[0] = Input::Public(*inputs[0].id(), Some(Plaintext::from_str("1u64").unwrap()));
+        assert!(process.check_transition_structure(&rebuild(retyped, outputs)).is_err());
+    }
+}
@@ -0,0 +1,241 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use console::prelude::*;
+use synthesizer_snark::{ProvingKey, VerifyingKey};
+
+use sha2::{Digest, Sha256};
+use std::sync::Arc;
+
+/// The bytes of a circuit key file, along with the expected SHA-256 checksum of the bytes.
+///
+/// The bytes are in the format of the published parameter files (a version byte, followed by the key),
+/// and the checksum is the hex-encoded checksum published in the corresponding `.metadata` file.
+#[derive(Copy, Clone, Debug)]
+pub struct CircuitKeyBytes<'a> {
+    /// The bytes of the circuit key file.
+    bytes: &'a [u8],
+    /// The expected hex-encoded SHA-256 checksum of the bytes.
+    checksum: &'a str,
+}
+
+impl<'a> CircuitKeyBytes<'a> {
+    /// Initializes new circuit key bytes, with the expected hex-encoded SHA-256 checksum of the bytes.
+    pub const fn new(bytes: &'a [u8], checksum: &'a str) -> Self {
+        Self { bytes, checksum }
+    }
+
+    /// Returns the bytes of the circuit key file.
+    pub const fn bytes(&self) -> &'a [u8] {
+        self.bytes
+    }
+
+    /// Returns the expected hex-encoded SHA-256 checksum of the bytes.
+    pub const fn checksum(&self) -> &'a str {
+        self.checksum
+    }
+
+    /// Returns the proving key, after checking the bytes against the expected checksum.
+    pub fn to_proving_key<N: Network>(&self) -> Result<ProvingKey<N>> {
+        // Check the bytes against the checksum, and skip the version byte.
+        let bytes = self.check("proving")?;
+        // Recover the proving key.
+        Ok(ProvingKey::new(Arc::new(FromBytes::from_bytes_le(bytes)?)))
+    }
+
+    /// Returns the verifying key, after checking the bytes against the expected checksum.
+    pub fn to_verifying_key<N: Network>(&self) -> Result<VerifyingKey<N>> {
+        // Check the bytes against the checksum, and skip the version byte.
+        let bytes = self.check("verifying")?;
+        // Recover the verifying key.
+        let verifying_key = Arc::new(FromBytes::from_bytes_le(bytes)?);
+        // Retrieve the number of public and private variables.
+        // Note: This number does *NOT* include the number of constants. This is safe because
+        // the circuit keys given to a process are for circuits that are never deployed.
+        let num_variables =
+            VerifyingKey::<N>::new(verifying_key.clone(), 0).circuit_info.num_public_and_private_variables as u64;
+        // Return the verifying key.
+        Ok(VerifyingKey::new(verifying_key, num_variables))
+    }
+
+    /// Ensures the bytes match the expected checksum, and returns the bytes without the version byte.
+    fn check(&self, kind: &str) -> Result<&'a [u8]> {
+        // Compute the checksum of the bytes.
+        let candidate: String = Sha256::digest(self.bytes).iter().map(|byte| format!("{byte:02x}")).collect();
+        // Ensure the checksum matches.
+        ensure!(
+            candidate.eq_ignore_ascii_case(self.checksum),
+            "Mismatching checksum for the {kind} key - expected '{}', found '{candidate}'",
+            self.checksum
+        );
+        // Ensure the version byte is present.
+        ensure!(!self.bytes.is_empty(), "The {kind} key bytes are empty");
+        // Skip the version byte.
+        Ok(&self.bytes[1..])
+    }
+}
+
+/// The inclusion and fee circuit keys for a process, supplied by the caller.
+///
+/// A process that is loaded with these keys uses them in place of the network's lazily-loaded keys,
+/// so it never reads or downloads the inclusion and fee proving parameters.
+///
+/// The verifying keys must match the network's verifying keys, which are bundled with `snarkvm-parameters`
+/// and checked against its published checksums. The proving keys are only checked against the given
+/// checksums, as a proof from an incorrect proving key does not verify under the network's verifying key.
+#[derive(Clone, Debug)]
+pub struct ProcessKeys<N: Network> {
+    /// The inclusion proving key.
+    inclusion_proving_key: ProvingKey<N>,
+    /// The inclusion verifying key.
+    inclusion_verifying_key: VerifyingKey<N>,
+    /// The proving key for `credits.aleo/fee_public`.
+    fee_public_proving_key: ProvingKey<N>,
+    /// The verifying key for `credits.aleo/fee_public`.
+    fee_public_verifying_key: VerifyingKey<N>,
+    /// The proving key for `credits.aleo/fee_private`.
+    fee_private_proving_key: ProvingKey<N>,
+    /// The verifying key for `credits.aleo/fee_private`.
+    fee_private_verifying_key: VerifyingKey<N>,
+}
+
+impl<N: Network> ProcessKeys<N> {
+    /// Initializes the process keys from the given key bytes, checking each against its expected checksum,
+    /// and checking the verifying keys against the network's verifying keys.
+    pub fn from_bytes(
+        inclusion_proving_key: CircuitKeyBytes,
+        inclusion_verifying_key: CircuitKeyBytes,
+        fee_public_proving_key: CircuitKeyBytes,
+        fee_public_verifying_key: CircuitKeyBytes,
+        fee_private_proving_key: CircuitKeyBytes,
+        fee_private_verifying_key: CircuitKeyBytes,
+    ) -> Result<Self> {
+        // Recover the verifying keys, and ensure they match the network's verifying keys.
+        let inclusion_verifying_key = inclusion_verifying_key.to_verifying_key()?;
+        Self::check_verifying_key("inclusion", &inclusion_verifying_key, N::inclusion_verifying_key())?;
+        let fee_public_verifying_key = fee_public_verifying_key.to_verifying_key()?;
+        let expected = N::get_credits_verifying_key("fee_public".to_string())?;
+        Self::check_verifying_key("fee_public", &fee_public_verifying_key, expected)?;
+        let fee_private_verifying_key = fee_private_verifying_key.to_verifying_key()?;
+        let expected = N::get_credits_verifying_key("fee_private".to_string())?;
+        Self::check_verifying_key("fee_private", &fee_private_verifying_key, expected)?;
+
+        Ok(Self {
+            inclusion_proving_key: inclusion_proving_key.to_proving_key()?,
+            inclusion_verifying_key,
+            fee_public_proving_key: fee_public_proving_key.to_proving_key()?,
+            fee_public_verifying_key,
+            fee_private_proving_key: fee_private_proving_key.to_proving_key()?,
+            fee_private_verifying_key,
+        })
+    }
+
+    /// Ensures the given verifying key matches the network's verifying key for the circuit with the given name.
+    fn check_verifying_key(
+        name: &str,
+        verifying_key: &VerifyingKey<N>,
+        expected: &<VerifyingKey<N> as Deref>::Target,
+    ) -> Result<()> {
+        ensure!(**verifying_key == *expected, "The {name} verifying key does not match the network's verifying key");
+        Ok(())
+    }
+
+    /// Returns the inclusion proving key.
+    pub const fn inclusion_proving_key(&self) -> &ProvingKey<N> {
+        &self.inclusion_proving_key
+    }
+
+    /// Returns the inclusion verifying key.
+    pub const fn inclusion_verifying_key(&self) -> &VerifyingKey<N> {
+        &self.inclusion_verifying_key
+    }
+
+    /// Returns the proving key for `credits.aleo/fee_public`.
+    pub const fn fee_public_proving_key(&self) -> &ProvingKey<N> {
+        &self.fee_public_proving_key
+    }
+
+    /// Returns the verifying key for `credits.aleo/fee_public`.
+    pub const fn fee_public_verifying_key(&self) -> &VerifyingKey<N> {
+        &self.fee_public_verifying_key
+    }
+
+    /// Returns the proving key for `credits.aleo/fee_private`.
+    pub const fn fee_private_proving_key(&self) -> &ProvingKey<N> {
+        &self.fee_private_proving_key
+    }
+
+    /// Returns the verifying key for `credits.aleo/fee_private`.
+    pub const fn fee_private_verifying_key(&self) -> &VerifyingKey<N> {
+        &self.fee_private_verifying_key
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::MainnetV0;
+
+    type CurrentNetwork = MainnetV0;
+
+    /// Returns the bytes of the inclusion verifying key, in the format of the published parameter file.
+    fn inclusion_verifying_key_bytes() -> Vec<u8> {
+        let mut bytes = vec![1u8];
+        bytes.extend(CurrentNetwork::inclusion_verifying_key().to_bytes_le().unwrap());
+        bytes
+    }
+
+    #[test]
+    fn test_check_verifying_key() {
+        let bytes = inclusion_verifying_key_bytes();
+        let checksum: String = Sha256::digest(&bytes).iter().map(|byte| format!("{byte:02x}")).collect();
+        let verifying_key = CircuitKeyBytes::new(&bytes, &checksum).to_verifying_key::<CurrentNetwork>().unwrap();
+
+        // Ensure the inclusion verifying key matches the network's inclusion verifying key.
+        let expected = CurrentNetwork::inclusion_verifying_key();
+        assert!(ProcessKeys::<CurrentNetwork>::check_verifying_key("inclusion", &verifying_key, expected).is_ok());
+
+        // Ensure the inclusion verifying key does not match the network's fee verifying key.
+        let expected = CurrentNetwork::get_credits_verifying_key("fee_public".to_string()).unwrap();
+        assert!(ProcessKeys::<CurrentNetwork>::check_verifying_key("fee_public", &verifying_key, expected).is_err());
+    }
+
+    #[test]
+    fn test_circuit_key_bytes_checksum() {
+        let bytes = inclusion_verifying_key_bytes();
+        let checksum: String = Sha256::digest(&bytes).iter().map(|byte| format!("{byte:02x}")).collect();
+
+        // Ensure the verifying key is recovered with the correct checksum.
+        let verifying_key = CircuitKeyBytes::new(&bytes, &checksum).to_verifying_key::<CurrentNetwork>().unwrap();
+        assert_eq!(*verifying_key, **CurrentNetwork::inclusion_verifying_key());
+        assert_eq!(
+            verifying_key.num_variables(),
+            CurrentNetwork::inclusion_verifying_key().circuit_info.num_public_and_private_variables as u64
+        );
+
+        // Ensure the checksum is case-insensitive.
+        let uppercase = checksum.to_uppercase();
+        assert!(CircuitKeyBytes::new(&bytes, &uppercase).to_verifying_key::<CurrentNetwork>().is_ok());
+
+        // Ensure an incorrect checksum is rejected.
+        let incorrect = format!("{:0>64}", "");
+        assert!(CircuitKeyBytes::new(&bytes, &incorrect).to_verifying_key::<CurrentNetwork>().is_err());
+
+        // Ensure tampered bytes are rejected.
+        let mut tampered = bytes.clone();
+        *tampered.last_mut().unwrap() ^= 1;
+        assert!(CircuitKeyBytes::new(&tampered, &checksum).to_verifying_key::<CurrentNetwork>().is_err());
+    }
+}
@@ -17,7 +17,7 @@ use crate::{Process, Stack, StackProgramTypes};
 
 use console::{
     prelude::*,
-    program::{FinalizeType, Identifier, LiteralType, PlaintextType},
+    program::{FinalizeType, Identifier, LiteralType, PlaintextType, ProgramID},
 };
 use ledger_block::{Deployment, Execution};
 use synthesizer_program::{CastType, Command, Finalize, Instruction, Operand, StackProgram};
@@ -60,14 +60,26 @@ pub fn deployment_cost<N: Network>(deployment: &Deployment<N>) -> Result<(u64, (
 
 /// Returns the *minimum* cost in microcredits to publish the given execution (total cost, (storage cost, finalize cost)).
 pub fn execution_cost<N: Network>(process: &Process<N>, execution: &Execution<N>) -> Result<(u64, (u64, u64))> {
-    // Compute the storage cost in microcredits.
-    let storage_cost = execution_storage_cost::<N>(execution.size_in_bytes()?);
-
     // Get the root transition.
     let transition = execution.peek()?;
 
-    // Get the finalize cost for the root transition.
-    let finalize_cost = process.get_stack(transition.program_id())?.get_finalize_cost(transition.function_name())?;
+    // Compute the minimum execution cost.
+    execution_cost_for_size(process, transition.program_id(), transition.function_name(), execution.size_in_bytes()?)
+}
+
+/// Returns the *minimum* cost in microcredits to publish an execution of the given size, with the given root function
+/// (total cost, (storage cost, finalize cost)).
+pub fn execution_cost_for_size<N: Network>(
+    process: &Process<N>,
+    program_id: &ProgramID<N>,
+    function_name: &Identifier<N>,
+    size_in_bytes: u64,
+) -> Result<(u64, (u64, u64))> {
+    // Compute the storage cost in microcredits.
+    let storage_cost = execution_storage_cost::<N>(size_in_bytes);
+
+    // Get the finalize cost for the root function.
+    let finalize_cost = process.get_stack(program_id)?.get_finalize_cost(function_name)?;
 
     // Compute the total cost in microcredits.
     let total_cost = storage_cost
@@ -217,6 +229,24 @@ pub fn cost_per_command<N: Network>(stack: &Stack<N>, finalize: &Finalize<N>, co
         Command::Instruction(Instruction::CommitPED128(commit)) => {
             cost_in_size(stack, finalize, commit.operands(), HASH_PER_BYTE_COST, HASH_BASE_COST)
         }
+        Command::Instruction(Instruction::CommitVerifyBHP256(commit)) => {
+            cost_in_size(stack, finalize, commit.operands(), HASH_BHP_PER_BYTE_COST, HASH_BHP_BASE_COST)
+        }
+        Command::Instruction(Instruction::CommitVerifyBHP512(commit)) => {
+            cost_in_size(stack, finalize, commit.operands(), HASH_BHP_PER_BYTE_COST, HASH_BHP_BASE_COST)
+        }
+        Command::Instruction(Instruction::CommitVerifyBHP768(commit)) => {
+            cost_in_size(stack, finalize, commit.operands(), HASH_BHP_PER_BYTE_COST, HASH_BHP_BASE_COST)
+        }
+        Command::Instruction(Instruction::CommitVerifyBHP1024(commit)) => {
+            cost_in_size(stack, finalize, commit.operands(), HASH_BHP_PER_BYTE_COST, HASH_BHP_BASE_COST)
+        }
+        Command::Instruction(Instruction::CommitVerifyPED64(commit)) => {
+            cost_in_size(stack, finalize, commit.operands(), HASH_PER_BYTE_COST, HASH_BASE_COST)
+        }
+        Command::Instruction(Instruction::CommitVerifyPED128(commit)) => {
+            cost_in_size(stack, finalize, commit.operands(), HASH_PER_BYTE_COST, HASH_BASE_COST)
+        }
         Command::Instruction(Instruction::Div(div)) => {
             // Ensure `div` has exactly two operands.
             ensure!(div.operands().len() == 2, "'div' must contain exactly 2 operands");
@@ -289,6 +319,9 @@ pub fn cost_per_command<N: Network>(stack: &Stack<N>, finalize: &Finalize<N>, co
         Command::Instruction(Instruction::HashManyPSD8(_)) => {
             bail!("`hash_many.psd8` is not supported in finalize")
         }
+        Command::Instruction(Instruction::HashToCurveSha3_256(hash)) => {
+            cost_in_size(stack, finalize, hash.operands(), HASH_PER_BYTE_COST, HASH_BASE_COST)
+        }
         Command::Instruction(Instruction::Inv(_)) => Ok(2_500),
         Command::Instruction(Instruction::IsEq(_)) => Ok(500),
         Command::Instruction(Instruction::IsNeq(_)) => Ok(500),
@@ -0,0 +1,227 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{Authorization, Process};
+use console::{
+    account::{Address, PrivateKey},
+    prelude::*,
+    program::{Identifier, ProgramID, Response, Value},
+};
+use synthesizer_program::StackProgram;
+
+/// The outcome of running a function through both the console evaluator and the circuit synthesizer.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum DifferentialOutcome<N: Network> {
+    /// Both accepted the inputs, with identical responses.
+    Accepted(Response<N>),
+    /// Both rejected the inputs.
+    Rejected {
+        /// The error of the evaluator.
+        evaluate_error: String,
+        /// The error of the synthesizer.
+        execute_error: String,
+    },
+    /// The evaluator and the synthesizer diverged, for the given reason.
+    Diverged(String),
+}
+
+impl<N: Network> DifferentialOutcome<N> {
+    /// Returns `true` if the evaluator and the synthesizer diverged.
+    pub const fn is_diverged(&self) -> bool {
+        matches!(self, Self::Diverged(..))
+    }
+}
+
+/// A divergence between the console evaluator and the circuit synthesizer.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Divergence<N: Network> {
+    /// The inputs of the function.
+    inputs: Vec<Value<N>>,
+    /// The reason for the divergence.
+    reason: String,
+}
+
+impl<N: Network> Divergence<N> {
+    /// Returns the inputs of the function.
+    pub fn inputs(&self) -> &[Value<N>] {
+        &self.inputs
+    }
+
+    /// Returns the reason for the divergence.
+    pub fn reason(&self) -> &str {
+        &self.reason
+    }
+}
+
+/// The report of a differential test of a function, over a number of sampled inputs.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DifferentialReport<N: Network> {
+    /// The number of sampled inputs that both accepted.
+    num_accepted: usize,
+    /// The number of sampled inputs that both rejected.
+    num_rejected: usize,
+    /// The divergences between the evaluator and the synthesizer.
+    divergences: Vec<Divergence<N>>,
+}
+
+impl<N: Network> DifferentialReport<N> {
+    /// Returns the number of sampled inputs that both accepted.
+    pub const fn num_accepted(&self) -> usize {
+        self.num_accepted
+    }
+
+    /// Returns the number of sampled inputs that both rejected.
+    pub const fn num_rejected(&self) -> usize {
+        self.num_rejected
+    }
+
+    /// Returns the divergences between the evaluator and the synthesizer.
+    pub fn divergences(&self) -> &[Divergence<N>] {
+        &self.divergences
+    }
+
+    /// Returns `true` if the evaluator and the synthesizer agreed on all sampled inputs.
+    pub fn is_consistent(&self) -> bool {
+        self.divergences.is_empty()
+    }
+}
+
+impl<N: Network> Process<N> {
+    /// Runs the given authorization through both the console evaluator and the circuit synthesizer,
+    /// and compares their responses.
+    ///
+    /// Note: The finalize operations of a function are determined by the futures in its outputs,
+    /// so identical outputs imply identical finalize operations for the same finalize state.
+    pub fn differential_check<A: circuit::Aleo<Network = N>, R: Rng + CryptoRng>(
+        &self,
+        authorization: &Authorization<N>,
+        rng: &mut R,
+    ) -> DifferentialOutcome<N> {
+        // Run the evaluator and the synthesizer on independent replicas of the authorization.
+        let evaluated = self.evaluate::<A>(authorization.replicate());
+        let executed = self.execute::<A, R>(authorization.replicate(), rng).map(|(response, _)| response);
+
+        match (evaluated, executed) {
+            (Ok(evaluated), Ok(executed)) => {
+                if evaluated.outputs() != executed.outputs() {
+                    DifferentialOutcome::Diverged(format!(
+                        "Mismatching outputs - evaluate: [{}], execute: [{}]",
+                        evaluated.outputs().iter().join(", "),
+                        executed.outputs().iter().join(", ")
+                    ))
+                } else if evaluated.output_ids() != executed.output_ids() {
+                    DifferentialOutcome::Diverged("Mismatching output IDs".to_string())
+                } else {
+                    DifferentialOutcome::Accepted(executed)
+                }
+            }
+            (Err(evaluate_error), Err(execute_error)) => DifferentialOutcome::Rejected {
+                evaluate_error: evaluate_error.to_string(),
+                execute_error: execute_error.to_string(),
+            },
+            (Ok(_), Err(error)) => {
+                DifferentialOutcome::Diverged(format!("Evaluate succeeded, execute failed - {error}"))
+            }
+            (Err(error), Ok(_)) => {
+                DifferentialOutcome::Diverged(format!("Execute succeeded, evaluate failed - {error}"))
+            }
+        }
+    }
+
+    /// Runs the given function through both the console evaluator and the circuit synthesizer,
+    /// on `num_iterations` randomly-sampled inputs, and returns a report of the divergences.
+    ///
+    /// Note: The sampled records are owned by the caller, so that they can be spent in the request.
+    pub fn differential_test<A: circuit::Aleo<Network = N>, R: Rng + CryptoRng>(
+        &self,
+        private_key: &PrivateKey<N>,
+        program_id: impl TryInto<ProgramID<N>>,
+        function_name: impl TryInto<Identifier<N>>,
+        num_iterations: usize,
+        rng: &mut R,
+    ) -> Result<DifferentialReport<N>> {
+        // Prepare the program ID and function name.
+        let program_id = program_id.try_into().map_err(|_| anyhow!("Invalid program ID"))?;
+        let function_name = function_name.try_into().map_err(|_| anyhow!("Invalid function name"))?;
+
+        // Retrieve the input types of the function.
+        let stack = self.get_stack(program_id)?;
+        let input_types = stack.get_function_ref(&function_name)?.input_types();
+        let caller = Address::try_from(private_key)?;
+
+        let mut report = DifferentialReport { num_accepted: 0, num_rejected: 0, divergences: vec![] };
+        for _ in 0..num_iterations {
+            // Sample the inputs.
+            let inputs = input_types
+                .iter()
+                .map(|input_type| stack.sample_value(&caller, input_type, rng))
+                .collect::<Result<Vec<_>>>()?;
+            // Authorize the request.
+            let authorization = self.authorize::<A, R>(private_key, program_id, function_name, inputs.iter(), rng)?;
+            // Compare the evaluator and the synthesizer.
+            match self.differential_check::<A, R>(&authorization, rng) {
+                DifferentialOutcome::Accepted(..) => report.num_accepted += 1,
+                DifferentialOutcome::Rejected { .. } => report.num_rejected += 1,
+                DifferentialOutcome::Diverged(reason) => report.divergences.push(Divergence { inputs, reason }),
+            }
+        }
+        Ok(report)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helpers::sample_process;
+    use console::network::MainnetV0;
+    use synthesizer_program::Program;
+
+    type CurrentNetwork = MainnetV0;
+    type CurrentAleo = circuit::network::AleoV0;
+
+    #[test]
+    fn test_differential_test() {
+        let rng = &mut TestRng::default();
+
+        // Initialize a program with a function that rejects some inputs.
+        let program = Program::<CurrentNetwork>::from_str(
+            r"
+program differential.aleo;
+
+record token:
+    owner as address.private;
+    amount as u64.private;
+
+function compute:
+    input r0 as u8.private;
+    input r1 as u8.public;
+    input r2 as token.record;
+    add r0 r1 into r3;
+    hash.bhp256 r3 into r4 as field;
+    cast r2.owner r2.amount into r5 as token.record;
+    output r4 as field.public;
+    output r5 as token.record;",
+        )
+        .unwrap();
+        let process = sample_process(&program);
+        let private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+
+        // Ensure the evaluator and the synthesizer agree on the sampled inputs.
+        let report =
+            process.differential_test::<CurrentAleo, _>(&private_key, program.id(), "compute", 4, rng).unwrap();
+        assert!(report.is_consistent(), "{:?}", report.divergences());
+        assert_eq!(report.num_accepted() + report.num_rejected(), 4);
+    }
+}
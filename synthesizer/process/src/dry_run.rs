@@ -0,0 +1,145 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use console::program::{Future, OutputID};
+
+/// The result of a dry run of an authorization, which evaluates the call stack without synthesis or proving.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DryRun<N: Network> {
+    /// The response of the top-level function.
+    response: Response<N>,
+    /// The transitions of the call stack, in execution order.
+    transitions: Vec<Transition<N>>,
+}
+
+impl<N: Network> DryRun<N> {
+    /// Returns the response of the top-level function.
+    pub const fn response(&self) -> &Response<N> {
+        &self.response
+    }
+
+    /// Returns the transitions of the call stack, in execution order.
+    pub fn transitions(&self) -> &[Transition<N>] {
+        &self.transitions
+    }
+
+    /// Returns the futures output by the top-level function.
+    /// Note: The futures of the child functions are nested in the arguments of these futures.
+    pub fn futures(&self) -> impl '_ + Iterator<Item = &Future<N>> {
+        self.response.outputs().iter().filter_map(|output| match output {
+            Value::Future(future) => Some(future),
+            _ => None,
+        })
+    }
+}
+
+impl<N: Network> Process<N> {
+    /// Evaluates the given authorization, and returns its response and transitions, without synthesizing
+    /// the circuits or computing the proofs. The result matches the outputs of a subsequent `execute`.
+    ///
+    /// The transitions, including their outputs and futures, are computed by `authorize`.
+    /// This method re-evaluates the call stack on the console, and ensures it agrees with the transitions,
+    /// so that the outputs and finalize operands can be shown before the authorization is proven.
+    pub fn evaluate_authorization<A: circuit::Aleo<Network = N>>(
+        &self,
+        authorization: &Authorization<N>,
+    ) -> Result<DryRun<N>> {
+        let timer = timer!("Process::evaluate_authorization");
+
+        // Retrieve the transitions, which are inserted as each function in the call stack returns.
+        let transitions = authorization.transitions().into_values().collect::<Vec<_>>();
+        ensure!(!transitions.is_empty(), "The authorization does not contain any transitions");
+        ensure!(
+            transitions.len() == authorization.len(),
+            "The authorization contains {} requests, but {} transitions",
+            authorization.len(),
+            transitions.len()
+        );
+        // Retrieve the top-level request.
+        let request = authorization.peek_next()?;
+
+        // Evaluate the call stack, on a replica of the authorization.
+        let response = self.evaluate::<A>(authorization.replicate())?;
+        lap!(timer, "Evaluate the authorization");
+
+        // Ensure the response matches the top-level transition, which is the last to return.
+        let Some(transition) = transitions.last() else { bail!("The authorization does not contain any transitions") };
+        ensure!(
+            transition.program_id() == request.program_id() && transition.function_name() == request.function_name(),
+            "The last transition in the authorization is not for '{}/{}'",
+            request.program_id(),
+            request.function_name()
+        );
+        let output_ids = response.output_ids().iter().map(|output_id| match output_id {
+            OutputID::Constant(id)
+            | OutputID::Public(id)
+            | OutputID::Private(id)
+            | OutputID::Record(id, _)
+            | OutputID::ExternalRecord(id)
+            | OutputID::Future(id) => id,
+        });
+        ensure!(
+            output_ids.eq(transition.outputs().iter().map(|output| output.id())),
+            "The evaluated outputs of '{}/{}' do not match the authorization",
+            request.program_id(),
+            request.function_name()
+        );
+
+        finish!(timer);
+        Ok(DryRun { response, transitions })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::account::Address;
+
+    type CurrentNetwork = console::network::MainnetV0;
+    type CurrentAleo = circuit::AleoV0;
+
+    #[test]
+    fn test_evaluate_authorization() {
+        let rng = &mut TestRng::default();
+
+        // Initialize the process.
+        let process = Process::<CurrentNetwork>::load().unwrap();
+        // Sample a private key.
+        let private_key = PrivateKey::new(rng).unwrap();
+        let address = Address::try_from(&private_key).unwrap();
+
+        // Authorize a public transfer.
+        let inputs = [Value::from_str(&address.to_string()).unwrap(), Value::from_str("1u64").unwrap()];
+        let authorization = process
+            .authorize::<CurrentAleo, _>(&private_key, "credits.aleo", "transfer_public", inputs.iter(), rng)
+            .unwrap();
+
+        // Dry run the authorization.
+        let dry_run = process.evaluate_authorization::<CurrentAleo>(&authorization).unwrap();
+        assert_eq!(dry_run.transitions().len(), 1);
+        let futures = dry_run.futures().collect::<Vec<_>>();
+        assert_eq!(futures.len(), 1);
+        assert_eq!(futures[0].function_name(), &Identifier::from_str("transfer_public").unwrap());
+        assert_eq!(futures[0].arguments().len(), 3);
+        // Ensure the authorization is unmodified.
+        assert_eq!(authorization.len(), 1);
+
+        // Ensure the dry run matches the execution.
+        let (response, trace) = process.execute::<CurrentAleo, _>(authorization, rng).unwrap();
+        assert_eq!(dry_run.response().outputs(), response.outputs());
+        assert_eq!(dry_run.transitions(), trace.transitions());
+    }
+}
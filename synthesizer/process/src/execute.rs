@@ -38,7 +38,8 @@ impl<N: Network> Process<N> {
         // This is the root request and we do not have a root_tvk to pass on.
         let root_tvk = None;
         // Initialize the trace.
-        let trace = Arc::new(RwLock::new(Trace::new()));
+        let trace =
+            Arc::new(RwLock::new(Trace::new().with_inclusion_proving_key(self.inclusion_proving_key().cloned())));
         // Initialize the call stack.
         let call_stack = CallStack::execute(authorization, trace.clone())?;
         lap!(timer, "Initialize call stack");
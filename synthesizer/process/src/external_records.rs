@@ -0,0 +1,208 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::Process;
+use console::{
+    prelude::*,
+    program::{Identifier, Locator, ValueType},
+    types::Field,
+};
+use ledger_block::{Execution, ExternalRecordEntry, ExternalRecordKind, Input, Output};
+use synthesizer_program::StackProgram;
+
+/// A possible origin of an external record in an execution.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum ExternalRecordOrigin<N: Network> {
+    /// The record was output by a preceding transition of the record's program.
+    Transition {
+        /// The ID of the transition that output the record.
+        transition_id: N::TransitionID,
+        /// The function name of the transition that output the record.
+        function_name: Identifier<N>,
+        /// The index of the record in the outputs of the transition.
+        index: usize,
+        /// The commitment of the record.
+        commitment: Field<N>,
+    },
+    /// The record was passed through from the external record input at the given index of the same transition.
+    PassThrough {
+        /// The index of the external record input.
+        index: usize,
+    },
+    /// The record was supplied from outside of the execution, i.e. by the caller or the user.
+    External,
+}
+
+/// An external record in an execution, resolved against the programs in the process.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ResolvedExternalRecord<N: Network> {
+    /// The external record entry.
+    entry: ExternalRecordEntry<N>,
+    /// The locator of the record type, as `program_id/record_name`.
+    record_type: Locator<N>,
+    /// The possible origins of the external record.
+    origins: Vec<ExternalRecordOrigin<N>>,
+}
+
+impl<N: Network> ResolvedExternalRecord<N> {
+    /// Returns the external record entry.
+    pub const fn entry(&self) -> &ExternalRecordEntry<N> {
+        &self.entry
+    }
+
+    /// Returns the locator of the record type, as `program_id/record_name`.
+    pub const fn record_type(&self) -> &Locator<N> {
+        &self.record_type
+    }
+
+    /// Returns the possible origins of the external record.
+    pub fn origins(&self) -> &[ExternalRecordOrigin<N>] {
+        &self.origins
+    }
+}
+
+impl<N: Network> Process<N> {
+    /// Returns the external records of the given execution, along with their record type and possible origins.
+    ///
+    /// Note: The external record hashes are bound to the transition view key of the transition,
+    /// so an origin cannot be matched by hash outside of the circuit. Instead, the origins are the
+    /// record outputs of the preceding transitions that match the record type, and the external record
+    /// inputs of the same transition that match the record type.
+    pub fn resolve_external_records(&self, execution: &Execution<N>) -> Result<Vec<ResolvedExternalRecord<N>>> {
+        let mut resolved = Vec::new();
+        for entry in execution.external_records() {
+            // Retrieve the function of the transition.
+            let stack = self.get_stack(entry.program_id())?;
+            let function = stack.get_function_ref(entry.function_name())?;
+            // Retrieve the record type of the external record.
+            let value_type = match entry.kind() {
+                ExternalRecordKind::Input => function.inputs().get_index(entry.index()).map(|input| input.value_type()),
+                ExternalRecordKind::Output => {
+                    function.outputs().get_index(entry.index()).map(|output| output.value_type())
+                }
+            };
+            let record_type = match value_type {
+                Some(ValueType::ExternalRecord(locator)) => *locator,
+                _ => bail!(
+                    "The {:?} at index {} of '{}/{}' is not an external record",
+                    entry.kind(),
+                    entry.index(),
+                    entry.program_id(),
+                    entry.function_name()
+                ),
+            };
+
+            // Collect the record outputs of the preceding transitions that match the record type.
+            let mut origins = Vec::new();
+            for (transition, index, commitment) in
+                execution.preceding_record_outputs(entry.transition_id(), record_type.program_id())?
+            {
+                let producer = self.get_stack(transition.program_id())?;
+                let producer_function = producer.get_function_ref(transition.function_name())?;
+                if let Some(ValueType::Record(record_name)) =
+                    producer_function.outputs().get_index(index).map(|output| output.value_type())
+                {
+                    if record_name == record_type.resource() {
+                        origins.push(ExternalRecordOrigin::Transition {
+                            transition_id: *transition.id(),
+                            function_name: *transition.function_name(),
+                            index,
+                            commitment: *commitment,
+                        });
+                    }
+                }
+            }
+            match entry.kind() {
+                // An external record input may always be supplied from outside of the execution.
+                ExternalRecordKind::Input => origins.push(ExternalRecordOrigin::External),
+                // An external record output may be passed through from an external record input of the same type.
+                ExternalRecordKind::Output => {
+                    for (index, input) in function.inputs().iter().enumerate() {
+                        if let ValueType::ExternalRecord(locator) = input.value_type() {
+                            if *locator == record_type {
+                                origins.push(ExternalRecordOrigin::PassThrough { index });
+                            }
+                        }
+                    }
+                }
+            }
+
+            resolved.push(ResolvedExternalRecord { entry, record_type, origins });
+        }
+        Ok(resolved)
+    }
+
+    /// Checks the cross-program record flow of the given execution, outside of the circuit.
+    ///
+    /// This ensures that:
+    /// 1. The external record inputs and outputs of each transition match the function signature.
+    /// 2. The program of each external record type is imported by the program of the transition.
+    /// 3. Each external record output has at least one possible origin in the execution.
+    ///
+    /// Note: These are necessary conditions for a valid execution, and do not replace the proof verification.
+    pub fn check_external_record_flow(&self, execution: &Execution<N>) -> Result<()> {
+        // Ensure the external record inputs and outputs match the function signatures.
+        for transition in execution.transitions() {
+            let stack = self.get_stack(transition.program_id())?;
+            let function = stack.get_function_ref(transition.function_name())?;
+            ensure!(
+                transition.inputs().len() == function.inputs().len()
+                    && transition.outputs().len() == function.outputs().len(),
+                "The number of inputs and outputs of '{}/{}' do not match the function signature",
+                transition.program_id(),
+                transition.function_name()
+            );
+            for (input, input_type) in transition.inputs().iter().zip_eq(function.inputs()) {
+                ensure!(
+                    matches!(input, Input::ExternalRecord(..))
+                        == matches!(input_type.value_type(), ValueType::ExternalRecord(..)),
+                    "The external record inputs of '{}/{}' do not match the function signature",
+                    transition.program_id(),
+                    transition.function_name()
+                );
+            }
+            for (output, output_type) in transition.outputs().iter().zip_eq(function.outputs()) {
+                ensure!(
+                    matches!(output, Output::ExternalRecord(..))
+                        == matches!(output_type.value_type(), ValueType::ExternalRecord(..)),
+                    "The external record outputs of '{}/{}' do not match the function signature",
+                    transition.program_id(),
+                    transition.function_name()
+                );
+            }
+        }
+
+        // Ensure each external record is imported and has a possible origin.
+        for resolved in self.resolve_external_records(execution)? {
+            let entry = resolved.entry();
+            let stack = self.get_stack(entry.program_id())?;
+            ensure!(
+                stack.program().contains_import(resolved.record_type().program_id()),
+                "'{}' does not import '{}' for the external record '{}'",
+                entry.program_id(),
+                resolved.record_type().program_id(),
+                resolved.record_type()
+            );
+            ensure!(
+                !resolved.origins().is_empty(),
+                "The external record output at index {} of '{}/{}' has no origin in the execution",
+                entry.index(),
+                entry.program_id(),
+                entry.function_name()
+            );
+        }
+        Ok(())
+    }
+}
@@ -15,7 +15,7 @@
 
 use super::*;
 use console::program::{FinalizeType, Future, Register};
-use synthesizer_program::{Await, FinalizeRegistersState, Operand};
+use synthesizer_program::{Await, CallOperator, FinalizeRegistersState, Operand};
 use utilities::try_vm_runtime;
 
 use std::collections::HashSet;
@@ -54,7 +54,7 @@ impl<N: Network> Process<N> {
             // Retrieve the fee stack.
             let fee_stack = self.get_stack(fee.program_id())?;
             // Finalize the fee transition.
-            finalize_operations.extend(finalize_fee_transition(state, store, fee_stack, fee)?);
+            finalize_operations.extend(finalize_fee_transition(state, store, &fee_stack, fee)?);
             lap!(timer, "Finalize transition for '{}/{}'", fee.program_id(), fee.function_name());
 
             /* Finalize the deployment. */
@@ -84,6 +84,22 @@ impl<N: Network> Process<N> {
         execution: &Execution<N>,
         fee: Option<&Fee<N>>,
     ) -> Result<Vec<FinalizeOperation<N>>> {
+        self.finalize_execution_metered(state, store, execution, fee)
+            .map(|(finalize_operations, _)| finalize_operations)
+    }
+
+    /// Finalizes the execution and fee, returning the finalize operations and the finalize cost consumed (in microcredits).
+    /// Each command is metered against the cost model as it is evaluated, so the consumed amount only includes
+    /// the commands that were evaluated, and never exceeds the static (worst-case) finalize cost paid for by the fee.
+    /// This method assumes the given execution **is valid**.
+    #[inline]
+    pub fn finalize_execution_metered<P: FinalizeStorage<N>>(
+        &self,
+        state: FinalizeGlobalState,
+        store: &FinalizeStore<N, P>,
+        execution: &Execution<N>,
+        fee: Option<&Fee<N>>,
+    ) -> Result<(Vec<FinalizeOperation<N>>, u64)> {
         let timer = timer!("Program::finalize_execution");
 
         // Ensure the execution contains transitions.
@@ -110,7 +126,8 @@ impl<N: Network> Process<N> {
             // Finalize the root transition.
             // Note that this will result in all the remaining transitions being finalized, since the number
             // of calls matches the number of transitions.
-            let mut finalize_operations = finalize_transition(state, store, stack, transition, call_graph)?;
+            let (mut finalize_operations, consumed) =
+                finalize_transition(state, store, &stack, transition, call_graph)?;
 
             /* Finalize the fee. */
 
@@ -118,13 +135,13 @@ impl<N: Network> Process<N> {
                 // Retrieve the fee stack.
                 let fee_stack = self.get_stack(fee.program_id())?;
                 // Finalize the fee transition.
-                finalize_operations.extend(finalize_fee_transition(state, store, fee_stack, fee)?);
+                finalize_operations.extend(finalize_fee_transition(state, store, &fee_stack, fee)?);
                 lap!(timer, "Finalize transition for '{}/{}'", fee.program_id(), fee.function_name());
             }
 
             finish!(timer);
-            // Return the finalize operations.
-            Ok(finalize_operations)
+            // Return the finalize operations and the consumed finalize cost.
+            Ok((finalize_operations, consumed))
         })
     }
 
@@ -144,7 +161,7 @@ impl<N: Network> Process<N> {
             // Retrieve the stack.
             let stack = self.get_stack(fee.program_id())?;
             // Finalize the fee transition.
-            let result = finalize_fee_transition(state, store, stack, fee);
+            let result = finalize_fee_transition(state, store, &stack, fee);
             finish!(timer, "Finalize transition for '{}/{}'", fee.program_id(), fee.function_name());
             // Return the result.
             result
@@ -165,27 +182,32 @@ fn finalize_fee_transition<N: Network, P: FinalizeStorage<N>>(
     call_graph.insert(*fee.transition_id(), Vec::new());
 
     // Finalize the transition.
+    // Note: The finalize cost of the fee transition is not reported, as it is not paid for by the fee.
     match finalize_transition(state, store, stack, fee, call_graph) {
         // If the evaluation succeeds, return the finalize operations.
-        Ok(finalize_operations) => Ok(finalize_operations),
+        Ok((finalize_operations, _)) => Ok(finalize_operations),
         // If the evaluation fails, bail and return the error.
         Err(error) => bail!("'finalize' failed on '{}/{}' - {error}", fee.program_id(), fee.function_name()),
     }
 }
 
-/// Finalizes the given transition.
+/// Finalizes the given transition, returning the finalize operations and the finalize cost consumed (in microcredits).
 fn finalize_transition<N: Network, P: FinalizeStorage<N>>(
     state: FinalizeGlobalState,
     store: &FinalizeStore<N, P>,
     stack: &Stack<N>,
     transition: &Transition<N>,
     call_graph: HashMap<N::TransitionID, Vec<N::TransitionID>>,
-) -> Result<Vec<FinalizeOperation<N>>> {
+) -> Result<(Vec<FinalizeOperation<N>>, u64)> {
     // Retrieve the program ID.
     let program_id = transition.program_id();
     // Retrieve the function name.
     let function_name = transition.function_name();
 
+    // Ensure no platform-dependent operations influence the finalize result.
+    #[cfg(feature = "audit")]
+    let _scope = crate::audit::DeterministicScope::enter("finalize");
+
     #[cfg(debug_assertions)]
     println!("Finalizing transition for {}/{function_name}...", transition.program_id());
     debug_assert_eq!(stack.program_id(), transition.program_id());
@@ -193,7 +215,7 @@ fn finalize_transition<N: Network, P: FinalizeStorage<N>>(
     // If the last output of the transition is a future, retrieve and finalize it. Otherwise, there are no operations to finalize.
     let future = match transition.outputs().last().and_then(|output| output.future()) {
         Some(future) => future,
-        _ => return Ok(Vec::new()),
+        _ => return Ok((Vec::new(), 0)),
     };
 
     // Check that the program ID and function name of the transition match those in the future.
@@ -204,6 +226,8 @@ fn finalize_transition<N: Network, P: FinalizeStorage<N>>(
 
     // Initialize a list for finalize operations.
     let mut finalize_operations = Vec::new();
+    // Initialize the consumed finalize cost.
+    let mut consumed = 0u64;
 
     // Initialize a stack of active finalize states.
     let mut states = Vec::new();
@@ -225,12 +249,22 @@ fn finalize_transition<N: Network, P: FinalizeStorage<N>>(
         while counter < finalize.commands().len() {
             // Retrieve the command.
             let command = &finalize.commands()[counter];
+            // Meter the command.
+            consumed = consumed
+                .checked_add(cost_per_command(stack, finalize, command)?)
+                .ok_or_else(|| anyhow!("Finalize cost overflowed"))?;
             // Finalize the command.
             match &command {
                 Command::BranchEq(branch_eq) => {
                     let result = try_vm_runtime!(|| branch_to(counter, branch_eq, finalize, stack, &registers));
                     match result {
                         Ok(Ok(new_counter)) => {
+                            // Record the branch, if a finalize recorder is active.
+                            record_finalize_event(|| FinalizeEvent::Branch {
+                                opcode: "branch.eq".to_string(),
+                                position: branch_eq.position().to_string(),
+                                taken: new_counter != counter + 1,
+                            });
                             counter = new_counter;
                         }
                         // If the evaluation fails, bail and return the error.
@@ -243,6 +277,12 @@ fn finalize_transition<N: Network, P: FinalizeStorage<N>>(
                     let result = try_vm_runtime!(|| branch_to(counter, branch_neq, finalize, stack, &registers));
                     match result {
                         Ok(Ok(new_counter)) => {
+                            // Record the branch, if a finalize recorder is active.
+                            record_finalize_event(|| FinalizeEvent::Branch {
+                                opcode: "branch.neq".to_string(),
+                                position: branch_neq.position().to_string(),
+                                taken: new_counter != counter + 1,
+                            });
                             counter = new_counter;
                         }
                         // If the evaluation fails, bail and return the error.
@@ -303,6 +343,10 @@ fn finalize_transition<N: Network, P: FinalizeStorage<N>>(
                 }
                 _ => {
                     let result = try_vm_runtime!(|| command.finalize(stack, store, &mut registers));
+                    // Record the mapping access or random draw, if a finalize recorder is active.
+                    if matches!(result, Ok(Ok(_))) && is_recording_finalize() {
+                        record_command(stack, command, &registers);
+                    }
                     match result {
                         // If the evaluation succeeds with an operation, add it to the list.
                         Ok(Ok(Some(finalize_operation))) => finalize_operations.push(finalize_operation),
@@ -331,8 +375,8 @@ fn finalize_transition<N: Network, P: FinalizeStorage<N>>(
         );
     }
 
-    // Return the finalize operations.
-    Ok(finalize_operations)
+    // Return the finalize operations and the consumed finalize cost.
+    Ok((finalize_operations, consumed))
 }
 
 // A helper struct to track the execution of a finalize block.
@@ -375,6 +419,13 @@ fn initialize_finalize_state<'a, N: Network>(
             future.function_name()
         ),
     };
+    // Record the start of the finalize logic, if a finalize recorder is active.
+    record_finalize_event(|| FinalizeEvent::Finalize {
+        transition_id: transition_id.to_string(),
+        program_id: future.program_id().to_string(),
+        function_name: future.function_name().to_string(),
+    });
+
     // Initialize the registers.
     let mut registers = FinalizeRegisters::new(
         state,
@@ -412,6 +463,58 @@ fn setup_await<'a, N: Network>(
     initialize_finalize_state(state, &future, stack, transition_id)
 }
 
+// A helper function that records the mapping access or random draw of the given command, after it is evaluated.
+// Note: The operands and destination are loaded from the registers, which hold the values the command used.
+fn record_command<N: Network>(stack: &Stack<N>, command: &Command<N>, registers: &FinalizeRegisters<N>) {
+    // A helper to load the given operand as a string.
+    let load = |operand: &Operand<N>| match registers.load(stack, operand) {
+        Ok(value) => value.to_string(),
+        Err(error) => format!("<error: {error}>"),
+    };
+    // A helper to load the given register as a string.
+    let load_register = |register: &Register<N>| load(&Operand::Register(register.clone()));
+    // A helper to name the given mapping, qualified by its program ID.
+    let mapping_name = |mapping: &CallOperator<N>| match mapping {
+        CallOperator::Locator(locator) => locator.to_string(),
+        CallOperator::Resource(mapping_name) => format!("{}/{mapping_name}", stack.program_id()),
+    };
+
+    // Construct the finalize event.
+    let event = match command {
+        Command::Contains(contains) => FinalizeEvent::Read {
+            opcode: "contains".to_string(),
+            mapping: mapping_name(contains.mapping()),
+            key: load(contains.key()),
+            value: load_register(contains.destination()),
+        },
+        Command::Get(get) => FinalizeEvent::Read {
+            opcode: "get".to_string(),
+            mapping: mapping_name(get.mapping()),
+            key: load(get.key()),
+            value: load_register(get.destination()),
+        },
+        Command::GetOrUse(get_or_use) => FinalizeEvent::Read {
+            opcode: "get.or_use".to_string(),
+            mapping: mapping_name(get_or_use.mapping()),
+            key: load(get_or_use.key()),
+            value: load_register(get_or_use.destination()),
+        },
+        Command::Set(set) => FinalizeEvent::Write {
+            mapping: format!("{}/{}", stack.program_id(), set.mapping_name()),
+            key: load(set.key()),
+            value: Some(load(set.value())),
+        },
+        Command::Remove(remove) => FinalizeEvent::Write {
+            mapping: format!("{}/{}", stack.program_id(), remove.mapping_name()),
+            key: load(remove.key()),
+            value: None,
+        },
+        Command::RandChaCha(rand_chacha) => FinalizeEvent::Random { value: load_register(rand_chacha.destination()) },
+        _ => return,
+    };
+    record_finalize_event(|| event);
+}
+
 // A helper function that returns the index to branch to.
 #[inline]
 fn branch_to<N: Network, const VARIANT: u8>(
@@ -496,7 +599,7 @@ function compute:
         .unwrap();
 
         // Initialize a new process.
-        let mut process = Process::load().unwrap();
+        let process = Process::load().unwrap();
         // Deploy the program.
         let deployment = process.deploy::<CurrentAleo, _>(&program, rng).unwrap();
 
@@ -0,0 +1,322 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! A recorder for the trace of a finalize execution.
+//!
+//! When a [`FinalizeRecorder`] is active on a thread, the finalize logic on that thread records every mapping read
+//! and write, `rand.chacha` draw, and branch taken, in order. The resulting [`FinalizeTrace`] is serializable to JSON,
+//! so the traces of two nodes that diverge on the same block can be compared with [`FinalizeTrace::first_divergence`].
+//! Recording is opt-in, and has no effect on the result of finalize.
+
+use console::prelude::*;
+
+use serde_json::json;
+use std::cell::RefCell;
+
+thread_local! {
+    /// The events recorded by the active finalize recorder on this thread, if any.
+    static RECORDED_EVENTS: RefCell<Option<Vec<FinalizeEvent>>> = const { RefCell::new(None) };
+}
+
+/// Returns `true` if a finalize recorder is active on this thread.
+pub fn is_recording_finalize() -> bool {
+    RECORDED_EVENTS.with(|events| events.borrow().is_some())
+}
+
+/// Records the given event, if a finalize recorder is active on this thread.
+/// The event is only constructed when it is recorded.
+pub(crate) fn record_finalize_event(event: impl FnOnce() -> FinalizeEvent) {
+    RECORDED_EVENTS.with(|events| {
+        if let Some(events) = events.borrow_mut().as_mut() {
+            events.push(event());
+        }
+    })
+}
+
+/// An event in the trace of a finalize execution.
+/// Program IDs, mappings, keys, and values are recorded in their string representation.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum FinalizeEvent {
+    /// The start of the finalize logic of a transition, including the finalize logic of an awaited future.
+    Finalize { transition_id: String, program_id: String, function_name: String },
+    /// A read from a mapping by `contains`, `get`, or `get.or_use`, with the resulting value.
+    Read { opcode: String, mapping: String, key: String, value: String },
+    /// A write to a mapping by `set`, or a removal from a mapping by `remove`, in which case the value is `None`.
+    Write { mapping: String, key: String, value: Option<String> },
+    /// A draw from `rand.chacha`.
+    Random { value: String },
+    /// A `branch.eq` or `branch.neq` to the given position, and whether the branch was taken.
+    Branch { opcode: String, position: String, taken: bool },
+}
+
+impl Serialize for FinalizeEvent {
+    /// Serializes the finalize event into a JSON object, tagged by the `event` field.
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let event = match self {
+            Self::Finalize { transition_id, program_id, function_name } => json!({
+                "event": "finalize",
+                "transition_id": transition_id,
+                "program_id": program_id,
+                "function_name": function_name,
+            }),
+            Self::Read { opcode, mapping, key, value } => {
+                json!({ "event": "read", "opcode": opcode, "mapping": mapping, "key": key, "value": value })
+            }
+            Self::Write { mapping, key, value } => {
+                json!({ "event": "write", "mapping": mapping, "key": key, "value": value })
+            }
+            Self::Random { value } => json!({ "event": "random", "value": value }),
+            Self::Branch { opcode, position, taken } => {
+                json!({ "event": "branch", "opcode": opcode, "position": position, "taken": taken })
+            }
+        };
+        event.serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for FinalizeEvent {
+    /// Deserializes the finalize event from a JSON object, tagged by the `event` field.
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        // Parse the finalize event from a string into a value.
+        let mut event = serde_json::Value::deserialize(deserializer)?;
+        // Retrieve the event kind.
+        let kind: String = DeserializeExt::take_from_value::<D>(&mut event, "event")?;
+        // Recover the finalize event.
+        match kind.as_str() {
+            "finalize" => Ok(Self::Finalize {
+                transition_id: DeserializeExt::take_from_value::<D>(&mut event, "transition_id")?,
+                program_id: DeserializeExt::take_from_value::<D>(&mut event, "program_id")?,
+                function_name: DeserializeExt::take_from_value::<D>(&mut event, "function_name")?,
+            }),
+            "read" => Ok(Self::Read {
+                opcode: DeserializeExt::take_from_value::<D>(&mut event, "opcode")?,
+                mapping: DeserializeExt::take_from_value::<D>(&mut event, "mapping")?,
+                key: DeserializeExt::take_from_value::<D>(&mut event, "key")?,
+                value: DeserializeExt::take_from_value::<D>(&mut event, "value")?,
+            }),
+            "write" => Ok(Self::Write {
+                mapping: DeserializeExt::take_from_value::<D>(&mut event, "mapping")?,
+                key: DeserializeExt::take_from_value::<D>(&mut event, "key")?,
+                value: DeserializeExt::take_from_value::<D>(&mut event, "value")?,
+            }),
+            "random" => Ok(Self::Random { value: DeserializeExt::take_from_value::<D>(&mut event, "value")? }),
+            "branch" => Ok(Self::Branch {
+                opcode: DeserializeExt::take_from_value::<D>(&mut event, "opcode")?,
+                position: DeserializeExt::take_from_value::<D>(&mut event, "position")?,
+                taken: DeserializeExt::take_from_value::<D>(&mut event, "taken")?,
+            }),
+            _ => Err(de::Error::custom(format!("Invalid finalize event '{kind}'"))),
+        }
+    }
+}
+
+/// The trace of a finalize execution, as the ordered list of finalize events.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct FinalizeTrace {
+    /// The finalize events, in the order they occurred.
+    events: Vec<FinalizeEvent>,
+}
+
+impl FinalizeTrace {
+    /// Initializes a new finalize trace from the given events.
+    pub const fn new(events: Vec<FinalizeEvent>) -> Self {
+        Self { events }
+    }
+
+    /// Returns the finalize events, in the order they occurred.
+    pub fn events(&self) -> &[FinalizeEvent] {
+        &self.events
+    }
+
+    /// Returns the number of finalize events.
+    pub fn len(&self) -> usize {
+        self.events.len()
+    }
+
+    /// Returns `true` if there are no finalize events.
+    pub fn is_empty(&self) -> bool {
+        self.events.is_empty()
+    }
+
+    /// Returns the index of the first event at which this trace and the given trace diverge,
+    /// or `None` if the traces are identical.
+    pub fn first_divergence(&self, other: &Self) -> Option<usize> {
+        match self.events.iter().zip(&other.events).position(|(a, b)| a != b) {
+            Some(index) => Some(index),
+            None if self.len() != other.len() => Some(self.len().min(other.len())),
+            None => None,
+        }
+    }
+}
+
+impl Serialize for FinalizeTrace {
+    /// Serializes the finalize trace into a JSON object.
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut trace = serializer.serialize_struct("FinalizeTrace", 1)?;
+        trace.serialize_field("events", &self.events)?;
+        trace.end()
+    }
+}
+
+impl<'de> Deserialize<'de> for FinalizeTrace {
+    /// Deserializes the finalize trace from a JSON object.
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        // Parse the finalize trace from a string into a value.
+        let mut trace = serde_json::Value::deserialize(deserializer)?;
+        // Recover the finalize trace.
+        Ok(Self::new(DeserializeExt::take_from_value::<D>(&mut trace, "events")?))
+    }
+}
+
+impl FromStr for FinalizeTrace {
+    type Err = Error;
+
+    /// Initializes the finalize trace from a JSON-string.
+    fn from_str(trace: &str) -> Result<Self, Self::Err> {
+        Ok(serde_json::from_str(trace)?)
+    }
+}
+
+impl Display for FinalizeTrace {
+    /// Displays the finalize trace as a JSON-string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "{}", serde_json::to_string(self).map_err::<fmt::Error, _>(ser::Error::custom)?)
+    }
+}
+
+/// A guard that records the finalize events on the current thread, from when it is started
+/// until it is finished. If another recorder is active, it is suspended until this recorder
+/// is finished or dropped.
+pub struct FinalizeRecorder {
+    /// The events of the enclosing recorder, which are restored when this recorder is finished.
+    previous: Option<Vec<FinalizeEvent>>,
+    /// Whether the recorder has been finished.
+    is_finished: bool,
+}
+
+impl FinalizeRecorder {
+    /// Starts recording the finalize events on the current thread.
+    pub fn start() -> Self {
+        let previous = RECORDED_EVENTS.with(|events| events.replace(Some(Vec::new())));
+        Self { previous, is_finished: false }
+    }
+
+    /// Stops recording, and returns the trace of the recorded finalize events.
+    pub fn finish(mut self) -> FinalizeTrace {
+        self.is_finished = true;
+        let events = RECORDED_EVENTS.with(|events| events.replace(self.previous.take()));
+        FinalizeTrace::new(events.unwrap_or_default())
+    }
+}
+
+impl Drop for FinalizeRecorder {
+    /// Discards the recorded events, and restores the enclosing recorder, if the recorder was not finished.
+    fn drop(&mut self) {
+        if !self.is_finished {
+            RECORDED_EVENTS.with(|events| events.replace(self.previous.take()));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample_events() -> Vec<FinalizeEvent> {
+        vec![
+            FinalizeEvent::Finalize {
+                transition_id: "au1transition".to_string(),
+                program_id: "token.aleo".to_string(),
+                function_name: "mint".to_string(),
+            },
+            FinalizeEvent::Read {
+                opcode: "get.or_use".to_string(),
+                mapping: "token.aleo/account".to_string(),
+                key: "aleo1owner".to_string(),
+                value: "0u64".to_string(),
+            },
+            FinalizeEvent::Random { value: "7u64".to_string() },
+            FinalizeEvent::Branch { opcode: "branch.eq".to_string(), position: "end".to_string(), taken: false },
+            FinalizeEvent::Write {
+                mapping: "token.aleo/account".to_string(),
+                key: "aleo1owner".to_string(),
+                value: Some("7u64".to_string()),
+            },
+            FinalizeEvent::Write {
+                mapping: "token.aleo/account".to_string(),
+                key: "aleo1other".to_string(),
+                value: None,
+            },
+        ]
+    }
+
+    #[test]
+    fn test_finalize_recorder() {
+        // Ensure events are not recorded without a recorder.
+        assert!(!is_recording_finalize());
+        record_finalize_event(|| panic!("The event must not be constructed without a recorder"));
+
+        // Ensure events are recorded, in order.
+        let recorder = FinalizeRecorder::start();
+        assert!(is_recording_finalize());
+        for event in sample_events() {
+            record_finalize_event(|| event);
+        }
+
+        // Ensure a nested recorder suspends the enclosing recorder.
+        let nested = FinalizeRecorder::start();
+        record_finalize_event(|| FinalizeEvent::Random { value: "1u8".to_string() });
+        assert_eq!(nested.finish().len(), 1);
+
+        // Ensure a dropped recorder restores the enclosing recorder.
+        drop(FinalizeRecorder::start());
+
+        let trace = recorder.finish();
+        assert_eq!(trace.events(), sample_events().as_slice());
+        assert!(!is_recording_finalize());
+    }
+
+    #[test]
+    fn test_serde_json() {
+        let expected = FinalizeTrace::new(sample_events());
+
+        // Serialize
+        let candidate = expected.to_string();
+        assert_eq!(candidate, serde_json::to_string(&expected).unwrap());
+        assert!(
+            candidate.contains(r#"{"event":"write","mapping":"token.aleo/account","key":"aleo1other","value":null}"#)
+        );
+
+        // Deserialize
+        assert_eq!(expected, FinalizeTrace::from_str(&candidate).unwrap());
+        assert_eq!(expected, serde_json::from_str(&candidate).unwrap());
+    }
+
+    #[test]
+    fn test_first_divergence() {
+        let trace = FinalizeTrace::new(sample_events());
+        assert_eq!(trace.first_divergence(&trace), None);
+
+        // Ensure a differing event is found.
+        let mut events = sample_events();
+        events[3] = FinalizeEvent::Branch { opcode: "branch.eq".to_string(), position: "end".to_string(), taken: true };
+        assert_eq!(trace.first_divergence(&FinalizeTrace::new(events)), Some(3));
+
+        // Ensure a truncated trace diverges at its end.
+        let truncated = FinalizeTrace::new(sample_events()[..2].to_vec());
+        assert_eq!(trace.first_divergence(&truncated), Some(2));
+        assert_eq!(truncated.first_divergence(&trace), Some(2));
+    }
+}
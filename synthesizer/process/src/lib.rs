@@ -19,23 +19,55 @@
 // TODO (howardwu): Update the return type on `execute` after stabilizing the interface.
 #![allow(clippy::type_complexity)]
 
+#[cfg(feature = "audit")]
+pub mod audit;
+
+mod check_deployment;
+pub use check_deployment::*;
+
+mod circuit_keys;
+pub use circuit_keys::*;
+
 mod cost;
 pub use cost::*;
 
+mod differential;
+pub use differential::*;
+
+mod dry_run;
+pub use dry_run::*;
+
+mod external_records;
+pub use external_records::*;
+
+mod finalize_trace;
+pub use finalize_trace::*;
+
+mod snapshot;
+pub use snapshot::*;
+
 mod stack;
 pub use stack::*;
 
+mod synthesis_digest;
+pub use synthesis_digest::*;
+
 mod trace;
 pub use trace::*;
 
 mod traits;
 pub use traits::*;
 
+mod verification_report;
+pub use verification_report::*;
+
 mod authorize;
+mod check_structure;
 mod deploy;
 mod evaluate;
 mod execute;
 mod finalize;
+mod replace_verifying_key;
 mod verify_deployment;
 mod verify_execution;
 mod verify_fee;
@@ -46,10 +78,21 @@ mod tests;
 use console::{
     account::PrivateKey,
     network::prelude::*,
-    program::{compute_function_id, Identifier, Literal, Locator, Plaintext, ProgramID, Record, Response, Value},
+    program::{
+        compute_function_id,
+        Identifier,
+        Literal,
+        Locator,
+        Plaintext,
+        ProgramID,
+        ProgramOwner,
+        Record,
+        Response,
+        Value,
+    },
     types::{Field, U16, U64},
 };
-use ledger_block::{Deployment, Execution, Fee, Input, Transition};
+use ledger_block::{Deployment, Execution, Fee, ImportManifest, ImportManifestEntry, Input, Transition};
 use ledger_store::{atomic_batch_scope, FinalizeStorage, FinalizeStore};
 use synthesizer_program::{
     Branch,
@@ -74,12 +117,30 @@ use std::{collections::HashMap, sync::Arc};
 #[cfg(feature = "aleo-cli")]
 use colored::Colorize;
 
-#[derive(Clone)]
 pub struct Process<N: Network> {
     /// The universal SRS.
     universal_srs: Arc<UniversalSRS<N>>,
     /// The mapping of program IDs to stacks.
-    stacks: IndexMap<ProgramID<N>, Arc<Stack<N>>>,
+    /// Note: The lock is only held to read or update the mapping, so stacks can be computed and added concurrently.
+    stacks: RwLock<IndexMap<ProgramID<N>, Arc<Stack<N>>>>,
+    /// The shared base process, if this process is an overlay.
+    /// Note: Programs in the base process are read-only, and newly added programs are only added to `stacks`.
+    base: Option<Arc<Process<N>>>,
+    /// The inclusion proving and verifying keys, if they were supplied when loading the process.
+    /// Note: If these are not supplied, the network's inclusion keys are used.
+    inclusion_keys: Option<(ProvingKey<N>, VerifyingKey<N>)>,
+}
+
+impl<N: Network> Clone for Process<N> {
+    /// Returns a copy of the process, with its own mapping of program IDs to stacks.
+    fn clone(&self) -> Self {
+        Self {
+            universal_srs: self.universal_srs.clone(),
+            stacks: RwLock::new(self.stacks.read().clone()),
+            base: self.base.clone(),
+            inclusion_keys: self.inclusion_keys.clone(),
+        }
+    }
 }
 
 impl<N: Network> Process<N> {
@@ -89,7 +150,12 @@ impl<N: Network> Process<N> {
         let timer = timer!("Process:setup");
 
         // Initialize the process.
-        let mut process = Self { universal_srs: Arc::new(UniversalSRS::load()?), stacks: IndexMap::new() };
+        let process = Self {
+            universal_srs: Arc::new(UniversalSRS::load()?),
+            stacks: Default::default(),
+            base: None,
+            inclusion_keys: None,
+        };
         lap!(timer, "Initialize process");
 
         // Initialize the 'credits.aleo' program.
@@ -117,10 +183,11 @@ impl<N: Network> Process<N> {
 
     /// Adds a new program to the process.
     /// If you intend to `execute` the program, use `deploy` and `finalize_deployment` instead.
+    /// Note: The program stack is computed without holding the lock, so programs can be added concurrently.
     #[inline]
-    pub fn add_program(&mut self, program: &Program<N>) -> Result<()> {
+    pub fn add_program(&self, program: &Program<N>) -> Result<()> {
         // Initialize the 'credits.aleo' program ID.
-        let credits_program_id = ProgramID::<N>::from_str("credits.aleo")?;
+        let credits_program_id = ProgramID::<N>::intern("credits.aleo")?;
         // If the program is not 'credits.aleo', compute the program stack, and add it to the process.
         if program.id() != &credits_program_id {
             self.add_stack(Stack::new(self, program)?);
@@ -131,20 +198,67 @@ impl<N: Network> Process<N> {
     /// Adds a new stack to the process.
     /// If you intend to `execute` the program, use `deploy` and `finalize_deployment` instead.
     #[inline]
-    pub fn add_stack(&mut self, stack: Stack<N>) {
+    pub fn add_stack(&self, stack: Stack<N>) {
         // Add the stack to the process.
-        self.stacks.insert(*stack.program_id(), Arc::new(stack));
+        self.stacks.write().insert(*stack.program_id(), Arc::new(stack));
+    }
+
+    /// Removes the stack for the given program ID from the process, returning it if it existed.
+    /// Note: The 'credits.aleo' stack cannot be removed. Stacks that import the removed program retain their reference to it.
+    /// Note: If this process is an overlay, only stacks in the overlay can be removed.
+    #[inline]
+    pub fn remove_stack(&self, program_id: &ProgramID<N>) -> Option<Arc<Stack<N>>> {
+        match program_id == &ProgramID::intern("credits.aleo").expect("Failed to parse 'credits.aleo'") {
+            true => None,
+            false => self.stacks.write().shift_remove(program_id),
+        }
     }
 }
 
 impl<N: Network> Process<N> {
+    /// Initializes a new process that shares the programs of the given base process.
+    ///
+    /// Programs are looked up in this process first, and then in the base process.
+    /// Newly added programs are only added to this process, so many overlays (e.g. one per ledger in a test framework)
+    /// can share a single base process without copying its stacks.
+    #[inline]
+    pub fn overlay(base: &Arc<Self>) -> Self {
+        Self {
+            universal_srs: base.universal_srs.clone(),
+            stacks: Default::default(),
+            base: Some(base.clone()),
+            inclusion_keys: base.inclusion_keys.clone(),
+        }
+    }
+
+    /// Returns the shared base process, if this process is an overlay.
+    #[inline]
+    pub const fn base(&self) -> Option<&Arc<Self>> {
+        self.base.as_ref()
+    }
+
+    /// Returns the program IDs and stacks in the process, including those in the base process (if any).
+    #[inline]
+    pub fn stacks(&self) -> IndexMap<ProgramID<N>, Arc<Stack<N>>> {
+        // Retrieve the stacks from the base process.
+        let mut stacks = self.base.as_ref().map(|base| base.stacks()).unwrap_or_default();
+        // Add the stacks from this process.
+        stacks.extend(self.stacks.read().iter().map(|(program_id, stack)| (*program_id, stack.clone())));
+        stacks
+    }
+
     /// Initializes a new process.
     #[inline]
     pub fn load() -> Result<Self> {
         let timer = timer!("Process::load");
 
         // Initialize the process.
-        let mut process = Self { universal_srs: Arc::new(UniversalSRS::load()?), stacks: IndexMap::new() };
+        let process = Self {
+            universal_srs: Arc::new(UniversalSRS::load()?),
+            stacks: Default::default(),
+            base: None,
+            inclusion_keys: None,
+        };
         lap!(timer, "Initialize process");
 
         // Initialize the 'credits.aleo' program.
@@ -177,12 +291,75 @@ impl<N: Network> Process<N> {
         Ok(process)
     }
 
+    /// Initializes a new process with the given inclusion and fee circuit keys.
+    ///
+    /// The supplied keys are used in place of the network's lazily-loaded keys, so the process never reads
+    /// or downloads the inclusion and fee proving parameters (e.g. for immutable deployments that ship their own keys).
+    /// The supplied verifying keys are checked against the network's bundled verifying keys in `ProcessKeys`,
+    /// so the process verifies proofs exactly as a process from `Process::load` does.
+    /// The remaining 'credits.aleo' proving keys are still loaded on demand.
+    #[inline]
+    pub fn load_with_keys(keys: ProcessKeys<N>) -> Result<Self> {
+        let timer = timer!("Process::load_with_keys");
+
+        // Initialize the process.
+        let process = Self {
+            universal_srs: Arc::new(UniversalSRS::load()?),
+            stacks: Default::default(),
+            base: None,
+            inclusion_keys: Some((keys.inclusion_proving_key().clone(), keys.inclusion_verifying_key().clone())),
+        };
+        lap!(timer, "Initialize process");
+
+        // Initialize the 'credits.aleo' program.
+        let program = Program::credits()?;
+        lap!(timer, "Load credits program");
+
+        // Compute the 'credits.aleo' program stack.
+        let stack = Stack::new(&process, &program)?;
+        lap!(timer, "Initialize stack");
+
+        // Insert the supplied fee circuit keys.
+        let fee_public = Identifier::from_str("fee_public")?;
+        let fee_private = Identifier::from_str("fee_private")?;
+        stack.insert_proving_key(&fee_public, keys.fee_public_proving_key().clone())?;
+        stack.insert_verifying_key(&fee_public, keys.fee_public_verifying_key().clone())?;
+        stack.insert_proving_key(&fee_private, keys.fee_private_proving_key().clone())?;
+        stack.insert_verifying_key(&fee_private, keys.fee_private_verifying_key().clone())?;
+        lap!(timer, "Insert fee circuit keys");
+
+        // Load the remaining 'credits.aleo' verifying keys.
+        for function_name in program.functions().keys().filter(|name| **name != fee_public && **name != fee_private) {
+            // Load the verifying key.
+            let verifying_key = N::get_credits_verifying_key(function_name.to_string())?;
+            // Retrieve the number of public and private variables.
+            // Note: This number does *NOT* include the number of constants. This is safe because
+            // this program is never deployed, as it is a first-class citizen of the protocol.
+            let num_variables = verifying_key.circuit_info.num_public_and_private_variables as u64;
+            // Insert the verifying key.
+            stack.insert_verifying_key(function_name, VerifyingKey::new(verifying_key.clone(), num_variables))?;
+        }
+        lap!(timer, "Load circuit keys");
+
+        // Add the stack to the process.
+        process.add_stack(stack);
+
+        finish!(timer, "Process::load_with_keys");
+        // Return the process.
+        Ok(process)
+    }
+
     /// Initializes a new process without downloading the 'credits.aleo' circuit keys (for web contexts).
     #[inline]
     #[cfg(feature = "wasm")]
     pub fn load_web() -> Result<Self> {
         // Initialize the process.
-        let mut process = Self { universal_srs: Arc::new(UniversalSRS::load()?), stacks: IndexMap::new() };
+        let process = Self {
+            universal_srs: Arc::new(UniversalSRS::load()?),
+            stacks: Default::default(),
+            base: None,
+            inclusion_keys: None,
+        };
 
         // Initialize the 'credits.aleo' program.
         let program = Program::credits()?;
@@ -203,19 +380,38 @@ impl<N: Network> Process<N> {
         &self.universal_srs
     }
 
+    /// Returns the supplied inclusion proving key, if the process was loaded with one.
+    #[inline]
+    pub fn inclusion_proving_key(&self) -> Option<&ProvingKey<N>> {
+        self.inclusion_keys.as_ref().map(|(proving_key, _)| proving_key)
+    }
+
+    /// Returns the supplied inclusion verifying key, if the process was loaded with one.
+    #[inline]
+    pub fn inclusion_verifying_key(&self) -> Option<&VerifyingKey<N>> {
+        self.inclusion_keys.as_ref().map(|(_, verifying_key)| verifying_key)
+    }
+
     /// Returns `true` if the process contains the program with the given ID.
     #[inline]
     pub fn contains_program(&self, program_id: &ProgramID<N>) -> bool {
-        self.stacks.contains_key(program_id)
+        self.stacks.read().contains_key(program_id)
+            || self.base.as_ref().map_or(false, |base| base.contains_program(program_id))
     }
 
     /// Returns the stack for the given program ID.
     #[inline]
-    pub fn get_stack(&self, program_id: impl TryInto<ProgramID<N>>) -> Result<&Arc<Stack<N>>> {
+    pub fn get_stack(&self, program_id: impl TryInto<ProgramID<N>>) -> Result<Arc<Stack<N>>> {
         // Prepare the program ID.
         let program_id = program_id.try_into().map_err(|_| anyhow!("Invalid program ID"))?;
-        // Retrieve the stack.
-        let stack = self.stacks.get(&program_id).ok_or_else(|| anyhow!("Program '{program_id}' does not exist"))?;
+        // Retrieve the stack, falling back to the base process (if any).
+        let stack = match self.stacks.read().get(&program_id).cloned() {
+            Some(stack) => stack,
+            None => match &self.base {
+                Some(base) => base.get_stack(program_id)?,
+                None => bail!("Program '{program_id}' does not exist"),
+            },
+        };
         // Ensure the program ID matches.
         ensure!(stack.program_id() == &program_id, "Expected program '{}', found '{program_id}'", stack.program_id());
         // Return the stack.
@@ -224,8 +420,8 @@ impl<N: Network> Process<N> {
 
     /// Returns the program for the given program ID.
     #[inline]
-    pub fn get_program(&self, program_id: impl TryInto<ProgramID<N>>) -> Result<&Program<N>> {
-        Ok(self.get_stack(program_id)?.program())
+    pub fn get_program(&self, program_id: impl TryInto<ProgramID<N>>) -> Result<Program<N>> {
+        Ok(self.get_stack(program_id)?.program().clone())
     }
 
     /// Returns the proving key for the given program ID and function name.
@@ -287,6 +483,23 @@ impl<N: Network> Process<N> {
         // Synthesize the proving and verifying key.
         self.get_stack(program_id)?.synthesize_key::<A, R>(function_name, rng)
     }
+
+    /// Synthesizes and returns the proving and verifying key for the given function in the given program.
+    /// The program does not need to be deployed or added to the process, however its imports must exist in the process.
+    #[inline]
+    pub fn synthesize_key_pair<A: circuit::Aleo<Network = N>, R: Rng + CryptoRng>(
+        &self,
+        program: &Program<N>,
+        function_name: &Identifier<N>,
+        rng: &mut R,
+    ) -> Result<(ProvingKey<N>, VerifyingKey<N>)> {
+        // Compute a standalone stack for the program, so the process is left unchanged.
+        let stack = Stack::new(self, program)?;
+        // Synthesize the proving and verifying key.
+        stack.synthesize_key::<A, R>(function_name, rng)?;
+        // Return the proving and verifying key.
+        Ok((stack.get_proving_key(function_name)?, stack.get_verifying_key(function_name)?))
+    }
 }
 
 #[cfg(any(test, feature = "test"))]
@@ -450,7 +663,7 @@ function compute:
     /// Initializes a new process with the given program.
     pub(crate) fn sample_process(program: &Program<CurrentNetwork>) -> Process<CurrentNetwork> {
         // Construct a new process.
-        let mut process = Process::load().unwrap();
+        let process = Process::load().unwrap();
         // Add the program to the process.
         process.add_program(program).unwrap();
         // Return the process.
@@ -0,0 +1,146 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+use synthesizer_snark::Certificate;
+
+impl<N: Network> Process<N> {
+    /// Replaces the verifying key for the given program ID and function name,
+    /// after checking the given certificate against the program in the process.
+    ///
+    /// This allows a network to rotate the keys of a deployed program (e.g. after regenerating parameters),
+    /// without redeploying the program. As the proving key no longer matches, it is removed from the process.
+    /// Note: The keys of 'credits.aleo' are defined by the network parameters, and cannot be replaced.
+    #[inline]
+    pub fn replace_verifying_key<A: circuit::Aleo<Network = N>, R: Rng + CryptoRng>(
+        &self,
+        program_id: &ProgramID<N>,
+        function_name: &Identifier<N>,
+        verifying_key: VerifyingKey<N>,
+        certificate: &Certificate<N>,
+        rng: &mut R,
+    ) -> Result<()> {
+        let timer = timer!("Process::replace_verifying_key");
+
+        // Ensure the program is not 'credits.aleo'.
+        ensure!(program_id != &ProgramID::intern("credits.aleo")?, "Cannot replace a verifying key of 'credits.aleo'");
+        // Retrieve the stack.
+        let stack = self.get_stack(program_id)?;
+        // Ensure the function has an existing verifying key.
+        ensure!(
+            stack.contains_verifying_key(function_name),
+            "Function '{program_id}/{function_name}' does not have a verifying key to replace"
+        );
+
+        // Compute the number of combined constraints and variables, with the new verifying key.
+        let (mut num_combined_constraints, mut num_combined_variables) = (0u64, 0u64);
+        for name in stack.program().functions().keys() {
+            let (num_constraints, num_variables) = match name == function_name {
+                true => (verifying_key.circuit_info.num_constraints as u64, verifying_key.num_variables()),
+                false => {
+                    let existing = stack.get_verifying_key(name)?;
+                    (existing.circuit_info.num_constraints as u64, existing.num_variables())
+                }
+            };
+            num_combined_constraints = num_combined_constraints
+                .checked_add(num_constraints)
+                .ok_or_else(|| anyhow!("Overflow when counting constraints for '{program_id}'"))?;
+            num_combined_variables = num_combined_variables
+                .checked_add(num_variables)
+                .ok_or_else(|| anyhow!("Overflow when counting variables for '{program_id}'"))?;
+        }
+        // Ensure the number of combined constraints and variables do not exceed the deployment limits.
+        ensure!(
+            num_combined_constraints <= N::MAX_DEPLOYMENT_CONSTRAINTS,
+            "Program '{program_id}' exceeds the constraint limit"
+        );
+        ensure!(
+            num_combined_variables <= N::MAX_DEPLOYMENT_VARIABLES,
+            "Program '{program_id}' exceeds the variable limit"
+        );
+        lap!(timer, "Check the deployment limits");
+
+        // Ensure the certificate is valid for the new verifying key.
+        stack.verify_certificate::<A, R>(function_name, &verifying_key, certificate, rng)?;
+        lap!(timer, "Verify the certificate");
+
+        // Replace the verifying key, and remove the stale proving key.
+        stack.insert_verifying_key(function_name, verifying_key)?;
+        stack.remove_proving_key(function_name);
+        finish!(timer, "Replace the verifying key");
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::MainnetV0;
+
+    type CurrentNetwork = MainnetV0;
+    type CurrentAleo = circuit::network::AleoV0;
+
+    #[test]
+    fn test_replace_verifying_key() {
+        let rng = &mut TestRng::default();
+
+        // Initialize a program with two distinct functions.
+        let program = Program::<CurrentNetwork>::from_str(
+            r"
+program rotate_keys.aleo;
+
+function foo:
+    input r0 as u8.private;
+    add r0 r0 into r1;
+    output r1 as u8.public;
+
+function bar:
+    input r0 as field.private;
+    mul r0 r0 into r1;
+    mul r1 r0 into r2;
+    output r2 as field.public;",
+        )
+        .unwrap();
+        let (foo, bar) = (Identifier::from_str("foo").unwrap(), Identifier::from_str("bar").unwrap());
+
+        // Initialize the process, and deploy the program.
+        let process = Process::<CurrentNetwork>::load().unwrap();
+        let deployment = process.deploy::<CurrentAleo, _>(&program, rng).unwrap();
+        process.load_deployment(&deployment).unwrap();
+
+        // Generate new keys, by deploying the program again.
+        let rotated = Process::<CurrentNetwork>::load().unwrap().deploy::<CurrentAleo, _>(&program, rng).unwrap();
+        let (_, (foo_key, foo_certificate)) = &rotated.verifying_keys()[0];
+        let (_, (_, bar_certificate)) = &rotated.verifying_keys()[1];
+
+        // Ensure a mismatched certificate is rejected.
+        assert!(process
+            .replace_verifying_key::<CurrentAleo, _>(program.id(), &foo, foo_key.clone(), bar_certificate, rng)
+            .is_err());
+        // Ensure 'credits.aleo' is rejected.
+        let credits = ProgramID::from_str("credits.aleo").unwrap();
+        assert!(process
+            .replace_verifying_key::<CurrentAleo, _>(&credits, &foo, foo_key.clone(), foo_certificate, rng)
+            .is_err());
+        // Ensure a valid certificate is accepted, and the verifying key is replaced.
+        process
+            .replace_verifying_key::<CurrentAleo, _>(program.id(), &foo, foo_key.clone(), foo_certificate, rng)
+            .unwrap();
+        assert!(process.get_verifying_key(program.id(), foo).unwrap() == *foo_key);
+        assert!(process.get_verifying_key(program.id(), bar).is_ok());
+    }
+}
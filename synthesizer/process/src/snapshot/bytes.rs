@@ -0,0 +1,78 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network> FromBytes for ProcessSnapshot<N> {
+    /// Reads the process snapshot from a buffer.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Read the version.
+        let version = u8::read_le(&mut reader)?;
+        // Ensure the version is valid.
+        if version != 1 {
+            return Err(error("Invalid process snapshot version"));
+        }
+
+        // Read the number of programs.
+        let num_programs = u32::read_le(&mut reader)?;
+        // Read the programs.
+        let mut programs = Vec::with_capacity(num_programs as usize);
+        for _ in 0..num_programs {
+            // Read the program.
+            let program = Program::read_le(&mut reader)?;
+            // Read the number of verifying keys.
+            let num_verifying_keys = u16::read_le(&mut reader)?;
+            // Read the verifying keys.
+            let mut verifying_keys = Vec::with_capacity(num_verifying_keys as usize);
+            for _ in 0..num_verifying_keys {
+                // Read the function name.
+                let function_name = Identifier::<N>::read_le(&mut reader)?;
+                // Read the verifying key.
+                let verifying_key = VerifyingKey::<N>::read_le(&mut reader)?;
+                // Add the entry.
+                verifying_keys.push((function_name, verifying_key));
+            }
+            programs.push((program, verifying_keys));
+        }
+
+        // Return the process snapshot.
+        Self::new(programs).map_err(|err| error(format!("{err}")))
+    }
+}
+
+impl<N: Network> ToBytes for ProcessSnapshot<N> {
+    /// Writes the process snapshot to a buffer.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Write the version.
+        1u8.write_le(&mut writer)?;
+        // Write the number of programs.
+        (u32::try_from(self.programs.len()).map_err(|e| error(e.to_string()))?).write_le(&mut writer)?;
+        // Write each program.
+        for (program, verifying_keys) in self.programs.values() {
+            // Write the program.
+            program.write_le(&mut writer)?;
+            // Write the number of verifying keys.
+            (u16::try_from(verifying_keys.len()).map_err(|e| error(e.to_string()))?).write_le(&mut writer)?;
+            // Write each verifying key.
+            for (function_name, verifying_key) in verifying_keys {
+                // Write the function name.
+                function_name.write_le(&mut writer)?;
+                // Write the verifying key.
+                verifying_key.write_le(&mut writer)?;
+            }
+        }
+        Ok(())
+    }
+}
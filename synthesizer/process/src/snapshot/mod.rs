@@ -0,0 +1,229 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod bytes;
+
+use crate::{Process, Stack};
+
+use aleo_std::prelude::{finish, lap, timer};
+use console::{
+    network::prelude::*,
+    program::{Identifier, ProgramID},
+};
+use indexmap::IndexMap;
+use synthesizer_program::{Program, StackProgram};
+use synthesizer_snark::VerifyingKey;
+
+/// A snapshot of the programs and verifying keys in a process.
+///
+/// The programs are stored in the order they were added to the process,
+/// which guarantees each program is listed after all of its imports.
+/// Note: The `credits.aleo` program is not included, as it is always loaded by the process.
+#[derive(Clone, PartialEq, Eq)]
+pub struct ProcessSnapshot<N: Network> {
+    /// The mapping of program IDs to their program and verifying keys.
+    programs: IndexMap<ProgramID<N>, (Program<N>, Vec<(Identifier<N>, VerifyingKey<N>)>)>,
+}
+
+impl<N: Network> ProcessSnapshot<N> {
+    /// Initializes a new process snapshot, ensuring each program is listed after its imports.
+    pub fn new(programs: Vec<(Program<N>, Vec<(Identifier<N>, VerifyingKey<N>)>)>) -> Result<Self> {
+        // Initialize the 'credits.aleo' program ID.
+        let credits_program_id = ProgramID::<N>::intern("credits.aleo")?;
+
+        let mut snapshot = IndexMap::with_capacity(programs.len());
+        for (program, verifying_keys) in programs {
+            let program_id = *program.id();
+            // Ensure the program is not 'credits.aleo'.
+            ensure!(program_id != credits_program_id, "The snapshot must not contain 'credits.aleo'");
+            // Ensure the program is unique.
+            ensure!(!snapshot.contains_key(&program_id), "The snapshot contains a duplicate program '{program_id}'");
+            // Ensure the imports are listed before the program.
+            for import_id in program.imports().keys() {
+                ensure!(
+                    *import_id == credits_program_id || snapshot.contains_key(import_id),
+                    "The snapshot lists program '{program_id}' before its import '{import_id}'"
+                );
+            }
+            // Ensure there is exactly one verifying key per function, in order.
+            ensure!(
+                program.functions().keys().eq(verifying_keys.iter().map(|(function_name, _)| function_name)),
+                "The snapshot verifying keys do not match the functions in '{program_id}'"
+            );
+            snapshot.insert(program_id, (program, verifying_keys));
+        }
+        Ok(Self { programs: snapshot })
+    }
+
+    /// Returns the number of programs in the snapshot.
+    pub fn len(&self) -> usize {
+        self.programs.len()
+    }
+
+    /// Returns `true` if the snapshot contains no programs.
+    pub fn is_empty(&self) -> bool {
+        self.programs.is_empty()
+    }
+
+    /// Returns `true` if the snapshot contains the given program ID.
+    pub fn contains_program(&self, program_id: &ProgramID<N>) -> bool {
+        self.programs.contains_key(program_id)
+    }
+
+    /// Returns an iterator over the program IDs in the snapshot.
+    pub fn program_ids(&self) -> impl '_ + Iterator<Item = &ProgramID<N>> {
+        self.programs.keys()
+    }
+
+    /// Returns an iterator over the programs and verifying keys in the snapshot.
+    pub fn programs(&self) -> impl '_ + Iterator<Item = &(Program<N>, Vec<(Identifier<N>, VerifyingKey<N>)>)> {
+        self.programs.values()
+    }
+}
+
+impl<N: Network> Process<N> {
+    /// Returns a snapshot of the programs and verifying keys in the process.
+    #[inline]
+    pub fn snapshot(&self) -> Result<ProcessSnapshot<N>> {
+        // Initialize the 'credits.aleo' program ID.
+        let credits_program_id = ProgramID::<N>::intern("credits.aleo")?;
+
+        let programs = self
+            .stacks()
+            .iter()
+            .filter(|(program_id, _)| **program_id != credits_program_id)
+            .map(|(_, stack)| {
+                // Retrieve the verifying keys, in function order.
+                let verifying_keys = stack
+                    .program()
+                    .functions()
+                    .keys()
+                    .map(|function_name| Ok((*function_name, stack.get_verifying_key(function_name)?)))
+                    .collect::<Result<Vec<_>>>()?;
+                Ok((stack.program().clone(), verifying_keys))
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        ProcessSnapshot::new(programs)
+    }
+
+    /// Initializes a new process from the given snapshot.
+    /// This method assumes the programs and verifying keys in the snapshot **are valid**.
+    #[inline]
+    pub fn from_snapshot(snapshot: &ProcessSnapshot<N>) -> Result<Self> {
+        let timer = timer!("Process::from_snapshot");
+
+        // Initialize the process.
+        let process = Self::load()?;
+        lap!(timer, "Initialize process");
+
+        // Add each program, in order.
+        for (program, verifying_keys) in snapshot.programs() {
+            // Compute the program stack.
+            let stack = Stack::new(&process, program)?;
+            // Insert the verifying keys.
+            for (function_name, verifying_key) in verifying_keys {
+                stack.insert_verifying_key(function_name, verifying_key.clone())?;
+            }
+            // Add the stack to the process.
+            process.add_stack(stack);
+        }
+        finish!(timer, "Restore the programs");
+
+        Ok(process)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::MainnetV0;
+
+    type CurrentNetwork = MainnetV0;
+    type CurrentAleo = circuit::network::AleoV0;
+
+    /// Returns a sample program, and a sample program that imports it.
+    fn sample_programs() -> (Program<CurrentNetwork>, Program<CurrentNetwork>) {
+        let program0 = Program::from_str(
+            r"
+program snapshot_a.aleo;
+
+function foo:
+    input r0 as u8.private;
+    output r0 as u8.public;",
+        )
+        .unwrap();
+        let program1 = Program::from_str(
+            r"
+import snapshot_a.aleo;
+
+program snapshot_b.aleo;
+
+function bar:
+    input r0 as u8.private;
+    output r0 as u8.public;",
+        )
+        .unwrap();
+        (program0, program1)
+    }
+
+    #[test]
+    fn test_snapshot_new() {
+        let (program0, program1) = sample_programs();
+
+        // Ensure an empty snapshot is valid.
+        assert!(ProcessSnapshot::<CurrentNetwork>::new(vec![]).unwrap().is_empty());
+        // Ensure the verifying keys must match the functions.
+        assert!(ProcessSnapshot::new(vec![(program0, vec![])]).is_err());
+        // Ensure an import must be listed before the program.
+        assert!(ProcessSnapshot::new(vec![(program1, vec![])]).is_err());
+        // Ensure 'credits.aleo' is rejected.
+        assert!(ProcessSnapshot::new(vec![(Program::credits().unwrap(), vec![])]).is_err());
+    }
+
+    #[test]
+    fn test_snapshot_restore() {
+        let rng = &mut TestRng::default();
+        let (program0, program1) = sample_programs();
+
+        // Initialize the process, and deploy the programs.
+        let process = Process::<CurrentNetwork>::load().unwrap();
+        for program in [&program0, &program1] {
+            let deployment = process.deploy::<CurrentAleo, _>(program, rng).unwrap();
+            process.load_deployment(&deployment).unwrap();
+        }
+
+        // Take a snapshot of the process.
+        let snapshot = process.snapshot().unwrap();
+        assert_eq!(snapshot.program_ids().collect::<Vec<_>>(), vec![program0.id(), program1.id()]);
+
+        // Check the byte representation.
+        let bytes = snapshot.to_bytes_le().unwrap();
+        let candidate = ProcessSnapshot::<CurrentNetwork>::read_le(&bytes[..]).unwrap();
+        assert!(snapshot == candidate);
+
+        // Restore the process from the snapshot.
+        let restored = Process::from_snapshot(&candidate).unwrap();
+        for program in [&program0, &program1] {
+            assert!(&restored.get_program(program.id()).unwrap() == program);
+            for function_name in program.functions().keys() {
+                assert!(
+                    restored.get_verifying_key(program.id(), function_name).unwrap()
+                        == process.get_verifying_key(program.id(), function_name).unwrap()
+                );
+            }
+        }
+    }
+}
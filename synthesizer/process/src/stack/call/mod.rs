@@ -18,7 +18,7 @@ use aleo_std::prelude::{finish, lap, timer};
 use console::{
     account::Field,
     network::prelude::*,
-    program::{Register, Request, Value, ValueType},
+    program::{Identifier, ProgramID, Register, Request, Value, ValueType},
 };
 use synthesizer_program::{
     Call,
@@ -46,13 +46,11 @@ pub trait CallTrait<N: Network> {
     fn execute<A: circuit::Aleo<Network = N>, R: CryptoRng + Rng>(
         &self,
         stack: &(impl StackEvaluate<N> + StackExecute<N> + StackMatches<N> + StackProgram<N>),
-        registers: &mut (
-                 impl RegistersCall<N>
-                 + RegistersSigner<N>
-                 + RegistersSignerCircuit<N, A>
-                 + RegistersLoadCircuit<N, A>
-                 + RegistersStoreCircuit<N, A>
-             ),
+        registers: &mut (impl RegistersCall<N>
+                  + RegistersSigner<N>
+                  + RegistersSignerCircuit<N, A>
+                  + RegistersLoadCircuit<N, A>
+                  + RegistersStoreCircuit<N, A>),
         rng: &mut R,
     ) -> Result<()>;
 }
@@ -139,13 +137,11 @@ impl<N: Network> CallTrait<N> for Call<N> {
     fn execute<A: circuit::Aleo<Network = N>, R: Rng + CryptoRng>(
         &self,
         stack: &(impl StackEvaluate<N> + StackExecute<N> + StackMatches<N> + StackProgram<N>),
-        registers: &mut (
-                 impl RegistersCall<N>
-                 + RegistersSigner<N>
-                 + RegistersSignerCircuit<N, A>
-                 + RegistersLoadCircuit<N, A>
-                 + RegistersStoreCircuit<N, A>
-             ),
+        registers: &mut (impl RegistersCall<N>
+                  + RegistersSigner<N>
+                  + RegistersSignerCircuit<N, A>
+                  + RegistersLoadCircuit<N, A>
+                  + RegistersStoreCircuit<N, A>),
         rng: &mut R,
     ) -> Result<()> {
         let timer = timer!("Call::execute");
@@ -159,10 +155,9 @@ impl<N: Network> CallTrait<N> for Call<N> {
             // Retrieve the call stack and resource from the locator.
             CallOperator::Locator(locator) => {
                 // Check the external call locator.
-                let function_name = locator.name().to_string();
-                let is_credits_program = &locator.program_id().to_string() == "credits.aleo";
-                let is_fee_private = &function_name == "fee_private";
-                let is_fee_public = &function_name == "fee_public";
+                let is_credits_program = locator.program_id() == &ProgramID::intern("credits.aleo")?;
+                let is_fee_private = locator.name() == &Identifier::intern("fee_private")?;
+                let is_fee_public = locator.name() == &Identifier::intern("fee_public")?;
 
                 // Ensure the external call is not to 'credits.aleo/fee_private' or 'credits.aleo/fee_public'.
                 if is_credits_program && (is_fee_private || is_fee_public) {
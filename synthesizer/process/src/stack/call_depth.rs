@@ -0,0 +1,235 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+use std::{
+    cell::Cell,
+    sync::atomic::{AtomicUsize, Ordering},
+};
+
+/// The default maximum number of nested function and closure calls.
+/// Note: Closures cannot call other closures or functions, so a call chain is bounded by
+/// `Transaction::MAX_TRANSITIONS` functions followed by a single closure, which is well below this limit.
+pub const DEFAULT_MAX_CALL_DEPTH: usize = 64;
+
+/// The configured maximum number of nested function and closure calls.
+static MAX_CALL_DEPTH: AtomicUsize = AtomicUsize::new(DEFAULT_MAX_CALL_DEPTH);
+
+thread_local! {
+    /// The number of function and closure calls that are currently being evaluated or executed on this thread.
+    /// Note: The depth is tracked per thread, as it bounds the recursion on the native stack of the thread.
+    static CALL_DEPTH: Cell<usize> = const { Cell::new(0) };
+}
+
+/// Sets the maximum number of nested function and closure calls, returning the previous limit.
+pub fn set_max_call_depth(max_call_depth: usize) -> usize {
+    MAX_CALL_DEPTH.swap(max_call_depth, Ordering::SeqCst)
+}
+
+/// Returns the maximum number of nested function and closure calls.
+pub fn max_call_depth() -> usize {
+    MAX_CALL_DEPTH.load(Ordering::SeqCst)
+}
+
+/// Returns the number of function and closure calls that are currently active on this thread.
+pub fn current_call_depth() -> usize {
+    CALL_DEPTH.with(|depth| depth.get())
+}
+
+/// The error returned when a call exceeds the maximum call depth.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CallDepthError {
+    /// The locator of the function or closure that exceeded the limit.
+    locator: String,
+    /// The call depth that was reached.
+    depth: usize,
+    /// The maximum call depth.
+    limit: usize,
+}
+
+impl CallDepthError {
+    /// Returns the locator of the function or closure that exceeded the limit.
+    pub fn locator(&self) -> &str {
+        &self.locator
+    }
+
+    /// Returns the call depth that was reached.
+    pub const fn depth(&self) -> usize {
+        self.depth
+    }
+
+    /// Returns the maximum call depth.
+    pub const fn limit(&self) -> usize {
+        self.limit
+    }
+}
+
+impl Display for CallDepthError {
+    /// Prints the call depth error.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(
+            f,
+            "Call to '{}' reached a depth of {}, which exceeds the maximum call depth of {}",
+            self.locator, self.depth, self.limit
+        )
+    }
+}
+
+impl std::error::Error for CallDepthError {}
+
+/// A guard that accounts for one level of call depth, released when the guard is dropped.
+#[must_use]
+pub struct CallDepthGuard(());
+
+impl Drop for CallDepthGuard {
+    /// Releases one level of call depth.
+    fn drop(&mut self) {
+        CALL_DEPTH.with(|depth| depth.set(depth.get().saturating_sub(1)));
+    }
+}
+
+impl<N: Network> CallStack<N> {
+    /// Enters a call to the given function or closure, returning a guard that releases the call on drop.
+    ///
+    /// # Errors
+    /// This method returns a `CallDepthError` if the call exceeds the maximum call depth.
+    pub fn enter(&self, program_id: &ProgramID<N>, resource: &Identifier<N>) -> Result<CallDepthGuard> {
+        // Compute the new call depth.
+        let depth = current_call_depth().saturating_add(1);
+        // Ensure the call depth does not exceed the limit.
+        let limit = max_call_depth();
+        if depth > limit {
+            return Err(CallDepthError { locator: format!("{program_id}/{resource}"), depth, limit }.into());
+        }
+        // Update the call depth.
+        CALL_DEPTH.with(|current| current.set(depth));
+        Ok(CallDepthGuard(()))
+    }
+}
+
+impl<N: Network> Stack<N> {
+    /// Returns the maximum depth of nested calls for the given function or closure, including itself.
+    pub fn get_max_call_depth(&self, name: &Identifier<N>) -> Result<usize> {
+        // Closures cannot perform calls.
+        if self.program.contains_closure(name) {
+            return Ok(1);
+        }
+        // Retrieve the function.
+        let function = self.program.get_function_ref(name)?;
+        // Compute the maximum call depth of the callees.
+        let mut max_callee_depth = 0;
+        for instruction in function.instructions() {
+            if let Instruction::Call(call) = instruction {
+                let callee_depth = match call.operator() {
+                    CallOperator::Locator(locator) => {
+                        self.get_external_stack(locator.program_id())?.get_max_call_depth(locator.resource())?
+                    }
+                    CallOperator::Resource(resource) => self.get_max_call_depth(resource)?,
+                };
+                max_callee_depth = std::cmp::max(max_callee_depth, callee_depth);
+            }
+        }
+        Ok(max_callee_depth + 1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::MainnetV0;
+
+    type CurrentNetwork = MainnetV0;
+
+    #[test]
+    fn test_call_depth_guard() {
+        let rng = &mut TestRng::default();
+
+        // Initialize a call stack.
+        let private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+        let call_stack = CallStack::PackageRun(vec![], private_key, Default::default());
+        let program_id = ProgramID::<CurrentNetwork>::from_str("test.aleo").unwrap();
+        let function_name = Identifier::from_str("foo").unwrap();
+
+        // Enter and release calls.
+        let start = current_call_depth();
+        {
+            let _first = call_stack.enter(&program_id, &function_name).unwrap();
+            let _second = call_stack.enter(&program_id, &function_name).unwrap();
+            assert_eq!(current_call_depth(), start + 2);
+        }
+        assert_eq!(current_call_depth(), start);
+
+        // Ensure the limit is enforced.
+        let guards = (start..max_call_depth())
+            .map(|_| call_stack.enter(&program_id, &function_name).unwrap())
+            .collect::<Vec<_>>();
+        let error = call_stack.enter(&program_id, &function_name).unwrap_err();
+        let error = error.downcast_ref::<CallDepthError>().unwrap();
+        assert_eq!(error.depth(), max_call_depth() + 1);
+        assert_eq!(error.locator(), "test.aleo/foo");
+        drop(guards);
+        assert_eq!(current_call_depth(), start);
+    }
+
+    #[test]
+    fn test_get_max_call_depth() {
+        // Initialize the programs.
+        let child = Program::<CurrentNetwork>::from_str(
+            r"
+program child.aleo;
+
+closure double:
+    input r0 as u32;
+    add r0 r0 into r1;
+    output r1 as u32;
+
+function compute:
+    input r0 as u32.private;
+    call double r0 into r1;
+    output r1 as u32.private;",
+        )
+        .unwrap();
+        let parent = Program::<CurrentNetwork>::from_str(
+            r"
+import child.aleo;
+
+program parent.aleo;
+
+function compute:
+    input r0 as u32.private;
+    call child.aleo/compute r0 into r1;
+    output r1 as u32.private;
+
+function noop:
+    input r0 as u32.private;
+    output r0 as u32.private;",
+        )
+        .unwrap();
+
+        // Construct the process.
+        let process = Process::<CurrentNetwork>::load().unwrap();
+        process.add_program(&child).unwrap();
+        process.add_program(&parent).unwrap();
+
+        // Check the maximum call depths.
+        let stack = process.get_stack(parent.id()).unwrap();
+        assert_eq!(stack.get_max_call_depth(&Identifier::from_str("compute").unwrap()).unwrap(), 3);
+        assert_eq!(stack.get_max_call_depth(&Identifier::from_str("noop").unwrap()).unwrap(), 1);
+        let stack = process.get_stack(child.id()).unwrap();
+        assert_eq!(stack.get_max_call_depth(&Identifier::from_str("compute").unwrap()).unwrap(), 2);
+        assert_eq!(stack.get_max_call_depth(&Identifier::from_str("double").unwrap()).unwrap(), 1);
+    }
+}
@@ -186,4 +186,83 @@ impl<N: Network> Stack<N> {
 
         Ok(())
     }
+
+    /// Checks the given certificate for the given function, against the given verifying key.
+    #[inline]
+    pub fn verify_certificate<A: circuit::Aleo<Network = N>, R: Rng + CryptoRng>(
+        &self,
+        function_name: &Identifier<N>,
+        verifying_key: &VerifyingKey<N>,
+        certificate: &Certificate<N>,
+        rng: &mut R,
+    ) -> Result<()> {
+        let program_id = self.program.id();
+        // Retrieve the function.
+        let function = self.get_function(function_name)?;
+
+        // The `root_tvk` and `caller` are `None` when verifying an individual circuit.
+        let (root_tvk, caller) = (None, None);
+
+        // Initialize a burner private key.
+        let burner_private_key = PrivateKey::new(rng)?;
+        // Compute the burner address.
+        let burner_address = Address::try_from(&burner_private_key)?;
+        // Retrieve the input types.
+        let input_types = function.input_types();
+        // Sample the inputs.
+        let inputs = input_types
+            .iter()
+            .map(|input_type| match input_type {
+                ValueType::ExternalRecord(locator) => {
+                    // Retrieve the external stack.
+                    let stack = self.get_external_stack(locator.program_id())?;
+                    // Sample the input.
+                    stack.sample_value(&burner_address, &ValueType::Record(*locator.resource()), rng)
+                }
+                _ => self.sample_value(&burner_address, input_type, rng),
+            })
+            .collect::<Result<Vec<_>>>()?;
+        // Compute the request, with a burner private key.
+        let request = Request::sign(
+            &burner_private_key,
+            *program_id,
+            *function_name,
+            inputs.into_iter(),
+            &input_types,
+            root_tvk,
+            true,
+            rng,
+        )?;
+
+        // Initialize the assignments.
+        let assignments = Assignments::<N>::default();
+        // Initialize the constraint limit. Account for the constraint added after synthesis that makes the Varuna zerocheck hiding.
+        let Some(constraint_limit) = verifying_key.circuit_info.num_constraints.checked_sub(1) else {
+            bail!("The constraint limit of 0 for function '{function_name}' is invalid")
+        };
+        // Initialize the call stack.
+        let call_stack = CallStack::CheckDeployment(
+            vec![request],
+            burner_private_key,
+            assignments.clone(),
+            Some(constraint_limit as u64),
+            Some(verifying_key.num_variables()),
+        );
+
+        // Synthesize the circuit.
+        if let Err(err) = self.execute_function::<A, _>(call_stack, caller, root_tvk, rng) {
+            bail!("Failed to synthesize the circuit for '{function_name}': {err}")
+        }
+        // Check the certificate.
+        match assignments.read().last() {
+            None => bail!("The assignment for function '{function_name}' is missing in '{program_id}'"),
+            Some((assignment, _metrics)) => {
+                // Ensure the certificate is valid.
+                if !certificate.verify(&function_name.to_string(), assignment, verifying_key) {
+                    bail!("The certificate for function '{function_name}' is invalid in '{program_id}'")
+                }
+            }
+        };
+        Ok(())
+    }
 }
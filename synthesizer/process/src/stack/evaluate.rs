@@ -32,6 +32,9 @@ impl<N: Network> StackEvaluate<N> for Stack<N> {
     ) -> Result<Vec<Value<N>>> {
         let timer = timer!("Stack::evaluate_closure");
 
+        // Account for the call depth, which is released when the closure returns.
+        let _call_depth = call_stack.enter(self.program_id(), closure.name())?;
+
         // Ensure the number of inputs matches the number of input statements.
         if closure.inputs().len() != inputs.len() {
             bail!("Expected {} inputs, found {}", closure.inputs().len(), inputs.len())
@@ -123,6 +126,9 @@ impl<N: Network> StackEvaluate<N> for Stack<N> {
         };
         lap!(timer, "Retrieve the next request");
 
+        // Account for the call depth, which is released when the function returns.
+        let _call_depth = call_stack.enter(request.program_id(), request.function_name())?;
+
         // Ensure the network ID matches.
         ensure!(
             **request.network_id() == N::ID,
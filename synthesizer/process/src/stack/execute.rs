@@ -35,6 +35,9 @@ impl<N: Network> StackExecute<N> for Stack<N> {
         // Ensure the call stack is not `Evaluate`.
         ensure!(!matches!(call_stack, CallStack::Evaluate(..)), "Illegal operation: cannot evaluate in execute mode");
 
+        // Account for the call depth, which is released when the closure returns.
+        let _call_depth = call_stack.enter(self.program_id(), closure.name())?;
+
         // Ensure the number of inputs matches the number of input statements.
         if closure.inputs().len() != inputs.len() {
             bail!("Expected {} inputs, found {}", closure.inputs().len(), inputs.len())
@@ -160,6 +163,9 @@ impl<N: Network> StackExecute<N> for Stack<N> {
         // Retrieve the next request.
         let console_request = call_stack.pop()?;
 
+        // Account for the call depth, which is released when the function returns.
+        let _call_depth = call_stack.enter(console_request.program_id(), console_request.function_name())?;
+
         // Ensure the network ID matches.
         ensure!(
             **console_request.network_id() == N::ID,
@@ -19,3 +19,4 @@ mod initialize;
 mod matches;
 mod sample;
 mod synthesize;
+mod verifier_inputs;
@@ -0,0 +1,137 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network> Stack<N> {
+    /// Returns the public inputs to verify the proof for the given transition of this program.
+    ///
+    /// The `parent` is the program ID of the calling transition, or `None` if the transition is the root.
+    /// The `children` are the transitions called by the given transition, in the order they were called.
+    pub fn verifier_inputs_for_transition(
+        &self,
+        transition: &Transition<N>,
+        parent: Option<&ProgramID<N>>,
+        children: &[&Transition<N>],
+    ) -> Result<Vec<N::Field>> {
+        // Ensure the transition belongs to this program.
+        ensure!(
+            transition.program_id() == self.program_id(),
+            "Transition '{}' belongs to '{}', not '{}'",
+            transition.id(),
+            transition.program_id(),
+            self.program_id()
+        );
+        // Retrieve the function from the stack.
+        let function = self.get_function_ref(transition.function_name())?;
+        // Ensure the number of inputs and outputs match the expected number in the function.
+        ensure!(function.inputs().len() == transition.inputs().len(), "The number of transition inputs is incorrect");
+        ensure!(
+            function.outputs().len() == transition.outputs().len(),
+            "The number of transition outputs is incorrect"
+        );
+
+        // Compute the x- and y-coordinate of `tpk`.
+        let (tpk_x, tpk_y) = transition.tpk().to_xy_coordinates();
+
+        // Determine the value of `is_root` and `parent`.
+        let (is_root, parent) = match parent {
+            // If there is a parent, then `is_root` is `0` and `parent` is the parent program ID.
+            Some(program_id) => (Field::<N>::zero(), *program_id),
+            // If there is no parent, then `is_root` is `1` and `parent` is the root program ID.
+            None => (Field::one(), *transition.program_id()),
+        };
+        // Compute the x- and y-coordinate of `parent`.
+        let (parent_x, parent_y) = parent.to_address()?.to_xy_coordinates();
+
+        // [Inputs] Construct the verifier inputs to verify the proof.
+        let mut inputs = vec![N::Field::one(), *tpk_x, *tpk_y, **transition.tcm(), **transition.scm()];
+        // [Inputs] Extend the verifier inputs with the input IDs.
+        inputs.extend(transition.inputs().iter().flat_map(|input| input.verifier_inputs()));
+        // [Inputs] Extend the verifier inputs with the public inputs for 'self.caller'.
+        inputs.extend([*is_root, *parent_x, *parent_y]);
+
+        // If there are function calls, append their inputs and outputs.
+        for transition in children {
+            // [Inputs] Extend the verifier inputs with the transition commitment of the external call.
+            inputs.extend([**transition.tcm()]);
+            // [Inputs] Extend the verifier inputs with the input IDs of the external call.
+            inputs.extend(transition.inputs().iter().flat_map(|input| input.verifier_inputs()));
+            // [Inputs] Extend the verifier inputs with the output IDs of the external call.
+            inputs.extend(transition.output_ids().map(|id| **id));
+        }
+
+        // [Inputs] Extend the verifier inputs with the output IDs.
+        inputs.extend(transition.outputs().iter().flat_map(|output| output.verifier_inputs()));
+
+        #[cfg(debug_assertions)]
+        println!("Transition public inputs ({} elements): {:#?}", inputs.len(), inputs);
+        Ok(inputs)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helpers::{sample_execution, sample_process};
+    use console::network::MainnetV0;
+
+    type CurrentNetwork = MainnetV0;
+    type CurrentAleo = circuit::network::AleoV0;
+
+    #[test]
+    fn test_verifier_inputs_for_transition() {
+        let rng = &mut TestRng::default();
+
+        // Initialize the program used by the sample execution.
+        let program = Program::<CurrentNetwork>::from_str(
+            r"
+program testing.aleo;
+
+function compute:
+    input r0 as u32.private;
+    input r1 as u32.public;
+    add r0 r1 into r2;
+    output r2 as u32.public;",
+        )
+        .unwrap();
+        let function_name = Identifier::from_str("compute").unwrap();
+
+        // Construct the process, and synthesize the verifying key.
+        let process = sample_process(&program);
+        process.synthesize_key::<CurrentAleo, _>(program.id(), &function_name, rng).unwrap();
+        let stack = process.get_stack(program.id()).unwrap();
+        let verifying_key = stack.get_verifying_key(&function_name).unwrap();
+
+        // Retrieve the sample execution and its transition.
+        let execution = sample_execution();
+        let transition = execution.peek().unwrap();
+
+        // Ensure the verifier inputs verify the execution proof.
+        let inputs = stack.verifier_inputs_for_transition(transition, None, &[]).unwrap();
+        let verifier_inputs = vec![(verifying_key.clone(), vec![inputs])];
+        Trace::verify_execution_proof("testing", verifier_inputs, &execution, None).unwrap();
+
+        // Ensure the verifier inputs with an incorrect parent do not verify the execution proof.
+        let parent = ProgramID::from_str("credits.aleo").unwrap();
+        let inputs = stack.verifier_inputs_for_transition(transition, Some(&parent), &[]).unwrap();
+        let verifier_inputs = vec![(verifying_key, vec![inputs])];
+        assert!(Trace::verify_execution_proof("testing", verifier_inputs, &execution, None).is_err());
+
+        // Ensure the transition is rejected by the stack of a different program.
+        let credits = process.get_stack(parent).unwrap();
+        assert!(credits.verifier_inputs_for_transition(transition, None, &[]).is_err());
+    }
+}
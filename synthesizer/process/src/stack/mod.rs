@@ -19,6 +19,9 @@ pub use authorization::*;
 mod call;
 pub use call::*;
 
+mod call_depth;
+pub use call_depth::*;
+
 mod finalize_registers;
 pub use finalize_registers::*;
 
@@ -299,6 +302,12 @@ impl<N: Network> StackProgram<N> for Stack<N> {
         self.program.get_function_ref(function_name)
     }
 
+    /// Returns the ABI hash of the function with the given function name.
+    #[inline]
+    fn get_function_abi_hash(&self, function_name: &Identifier<N>) -> Result<Field<N>> {
+        self.program.get_function_abi_hash(function_name)
+    }
+
     /// Returns the expected number of calls for the given function name.
     #[inline]
     fn get_number_of_calls(&self, function_name: &Identifier<N>) -> Result<usize> {
@@ -445,7 +454,7 @@ impl<N: Network> Stack<N> {
     /// Inserts the proving key if the program ID is 'credits.aleo'.
     fn try_insert_credits_function_proving_key(&self, function_name: &Identifier<N>) -> Result<()> {
         // If the program is 'credits.aleo' and it does not exist yet, load the proving key directly.
-        if self.program_id() == &ProgramID::from_str("credits.aleo")?
+        if self.program_id() == &ProgramID::intern("credits.aleo")?
             && !self.proving_keys.read().contains_key(function_name)
         {
             // Load the 'credits.aleo' function proving key.
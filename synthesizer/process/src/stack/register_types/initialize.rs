@@ -714,6 +714,30 @@ impl<N: Network> RegisterTypes<N> {
                 matches!(instruction, Instruction::CommitPED128(..)),
                 "Instruction '{instruction}' is not for opcode '{opcode}'."
             ),
+            "commit.verify.bhp256" => ensure!(
+                matches!(instruction, Instruction::CommitVerifyBHP256(..)),
+                "Instruction '{instruction}' is not for opcode '{opcode}'."
+            ),
+            "commit.verify.bhp512" => ensure!(
+                matches!(instruction, Instruction::CommitVerifyBHP512(..)),
+                "Instruction '{instruction}' is not for opcode '{opcode}'."
+            ),
+            "commit.verify.bhp768" => ensure!(
+                matches!(instruction, Instruction::CommitVerifyBHP768(..)),
+                "Instruction '{instruction}' is not for opcode '{opcode}'."
+            ),
+            "commit.verify.bhp1024" => ensure!(
+                matches!(instruction, Instruction::CommitVerifyBHP1024(..)),
+                "Instruction '{instruction}' is not for opcode '{opcode}'."
+            ),
+            "commit.verify.ped64" => ensure!(
+                matches!(instruction, Instruction::CommitVerifyPED64(..)),
+                "Instruction '{instruction}' is not for opcode '{opcode}'."
+            ),
+            "commit.verify.ped128" => ensure!(
+                matches!(instruction, Instruction::CommitVerifyPED128(..)),
+                "Instruction '{instruction}' is not for opcode '{opcode}'."
+            ),
             _ => bail!("Instruction '{instruction}' is not for opcode '{opcode}'."),
         }
         Ok(())
@@ -796,6 +820,10 @@ impl<N: Network> RegisterTypes<N> {
                 matches!(instruction, Instruction::HashManyPSD8(..)),
                 "Instruction '{instruction}' is not for opcode '{opcode}'."
             ),
+            "hash_to_curve.sha3_256" => ensure!(
+                matches!(instruction, Instruction::HashToCurveSha3_256(..)),
+                "Instruction '{instruction}' is not for opcode '{opcode}'."
+            ),
             _ => bail!("Instruction '{instruction}' is not for opcode '{opcode}'."),
         }
         Ok(())
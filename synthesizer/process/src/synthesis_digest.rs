@@ -0,0 +1,173 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{Process, Stack};
+use console::{prelude::*, program::Identifier};
+use ledger_block::Deployment;
+use synthesizer_snark::CircuitDigest;
+
+use indexmap::IndexMap;
+
+/// A mismatch between the synthesized circuit of a function and its deployed verifying key.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct SynthesisMismatch<N: Network> {
+    /// The function name.
+    function_name: Identifier<N>,
+    /// The digest of the deployed verifying key.
+    expected: CircuitDigest,
+    /// The digest of the locally-synthesized circuit.
+    candidate: CircuitDigest,
+}
+
+impl<N: Network> SynthesisMismatch<N> {
+    /// Returns the function name.
+    pub const fn function_name(&self) -> &Identifier<N> {
+        &self.function_name
+    }
+
+    /// Returns the digest of the deployed verifying key.
+    pub const fn expected(&self) -> &CircuitDigest {
+        &self.expected
+    }
+
+    /// Returns the digest of the locally-synthesized circuit.
+    pub const fn candidate(&self) -> &CircuitDigest {
+        &self.candidate
+    }
+}
+
+impl<N: Network> Display for SynthesisMismatch<N> {
+    /// Prints the mismatch.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "'{}' synthesized to {}, but was deployed as {}", self.function_name, self.candidate, self.expected)
+    }
+}
+
+impl<N: Network> Stack<N> {
+    /// Synthesizes each function in the program, and returns the digest of its R1CS instance.
+    ///
+    /// The digests are independent of the sampled inputs, so they are stable across runs,
+    /// and should be identical across platforms.
+    pub fn synthesis_digests<A: circuit::Aleo<Network = N>, R: Rng + CryptoRng>(
+        &self,
+        rng: &mut R,
+    ) -> Result<IndexMap<Identifier<N>, CircuitDigest>> {
+        self.synthesize_assignments::<A, R>(rng)?
+            .into_iter()
+            .map(|(function_name, assignment)| Ok((function_name, CircuitDigest::from_assignment::<N>(&assignment)?)))
+            .collect()
+    }
+}
+
+impl<N: Network> Process<N> {
+    /// Synthesizes each function in the given deployment, and returns the functions whose circuit
+    /// does not match the deployed verifying key.
+    pub fn synthesis_mismatches<A: circuit::Aleo<Network = N>, R: Rng + CryptoRng>(
+        &self,
+        deployment: &Deployment<N>,
+        rng: &mut R,
+    ) -> Result<Vec<SynthesisMismatch<N>>> {
+        // Retrieve the stack, or compute it if the program is not yet in the process.
+        let program_id = deployment.program_id();
+        let digests = match self.contains_program(program_id) {
+            true => self.get_stack(program_id)?.synthesis_digests::<A, R>(rng)?,
+            false => Stack::new(self, deployment.program())?.synthesis_digests::<A, R>(rng)?,
+        };
+        ensure!(
+            digests.len() == deployment.verifying_keys().len(),
+            "The number of synthesized circuits does not match the number of verifying keys for '{program_id}'"
+        );
+
+        // Compare the digests against the verifying keys.
+        let mut mismatches = Vec::new();
+        for ((function_name, candidate), (vk_function_name, (verifying_key, _))) in
+            digests.into_iter().zip_eq(deployment.verifying_keys())
+        {
+            ensure!(function_name == *vk_function_name, "The verifying keys of '{program_id}' are out of order");
+            let expected = CircuitDigest::from_verifying_key(verifying_key);
+            if candidate != expected {
+                mismatches.push(SynthesisMismatch { function_name, expected, candidate });
+            }
+        }
+        Ok(mismatches)
+    }
+
+    /// Checks that each function in the given deployment synthesizes to the circuit of its deployed verifying key.
+    ///
+    /// A mismatch indicates that the local synthesis is platform-dependent, and would diverge from consensus.
+    pub fn check_synthesis_digests<A: circuit::Aleo<Network = N>, R: Rng + CryptoRng>(
+        &self,
+        deployment: &Deployment<N>,
+        rng: &mut R,
+    ) -> Result<()> {
+        let mismatches = self.synthesis_mismatches::<A, R>(deployment, rng)?;
+        ensure!(
+            mismatches.is_empty(),
+            "The synthesized circuits of '{}' do not match the deployment: {}",
+            deployment.program_id(),
+            mismatches.iter().join("; ")
+        );
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::MainnetV0;
+    use synthesizer_program::Program;
+
+    type CurrentNetwork = MainnetV0;
+    type CurrentAleo = circuit::network::AleoV0;
+
+    #[test]
+    fn test_synthesis_digests() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        // Initialize a program with two functions of different sizes.
+        let program = Program::<CurrentNetwork>::from_str(
+            r"
+program synthesis.aleo;
+
+function small:
+    input r0 as u8.private;
+    output r0 as u8.public;
+
+function large:
+    input r0 as field.private;
+    hash.bhp256 r0 into r1 as field;
+    output r1 as field.public;",
+        )?;
+        let process = Process::<CurrentNetwork>::load()?;
+        let deployment = process.deploy::<CurrentAleo, _>(&program, rng)?;
+
+        // Ensure the digests are stable across runs.
+        let stack = Stack::new(&process, &program)?;
+        assert_eq!(stack.synthesis_digests::<CurrentAleo, _>(rng)?, stack.synthesis_digests::<CurrentAleo, _>(rng)?);
+        // Ensure the deployment matches the synthesized circuits.
+        process.check_synthesis_digests::<CurrentAleo, _>(&deployment, rng)?;
+
+        // Swap the verifying keys, and ensure both functions mismatch.
+        let mut verifying_keys = deployment.verifying_keys().clone();
+        let (small, large) = (verifying_keys[0].1.clone(), verifying_keys[1].1.clone());
+        verifying_keys[0].1 = large;
+        verifying_keys[1].1 = small;
+        let swapped = Deployment::new(deployment.edition(), deployment.program().clone(), verifying_keys)?;
+        let mismatches = process.synthesis_mismatches::<CurrentAleo, _>(&swapped, rng)?;
+        assert_eq!(mismatches.len(), 2);
+        assert!(process.check_synthesis_digests::<CurrentAleo, _>(&swapped, rng).is_err());
+        Ok(())
+    }
+}
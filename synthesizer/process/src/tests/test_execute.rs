@@ -16,6 +16,7 @@
 use crate::{
     traits::{StackEvaluate, StackExecute},
     CallStack,
+    ExternalRecordOrigin,
     Process,
     Stack,
     Trace,
@@ -27,7 +28,7 @@ use console::{
     program::{Identifier, Literal, Plaintext, ProgramID, Record, Value},
     types::{Field, U64},
 };
-use ledger_block::{Fee, Transaction};
+use ledger_block::{Execution, Fee, Transaction};
 use ledger_query::Query;
 use ledger_store::{
     helpers::memory::{BlockMemory, FinalizeMemory},
@@ -39,7 +40,6 @@ use ledger_store::{
 use synthesizer_program::{FinalizeGlobalState, FinalizeStoreTrait, Program, StackProgram};
 use synthesizer_snark::UniversalSRS;
 
-use indexmap::IndexMap;
 use parking_lot::RwLock;
 use std::sync::Arc;
 
@@ -1080,7 +1080,7 @@ function transfer:
     assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
 
     // Construct the process.
-    let mut process = crate::test_helpers::sample_process(&program0);
+    let process = crate::test_helpers::sample_process(&program0);
     // Initialize another program.
     let (string, program1) = Program::<CurrentNetwork>::parse(
         r"
@@ -1172,12 +1172,36 @@ function transfer:
     assert_eq!(authorization.len(), 5);
 
     // Execute the request.
-    let (response, _trace) = process.execute::<CurrentAleo, _>(authorization, rng).unwrap();
+    let (response, trace) = process.execute::<CurrentAleo, _>(authorization, rng).unwrap();
     let candidate = response.outputs();
     assert_eq!(2, candidate.len());
     assert_eq!(output_a, candidate[0]);
     assert_eq!(output_b, candidate[1]);
 
+    // Resolve the external records of the execution.
+    let execution = Execution::from(trace.transitions().iter().cloned(), Default::default(), None).unwrap();
+    process.check_external_record_flow(&execution).unwrap();
+    let external_records = process.resolve_external_records(&execution).unwrap();
+    assert_eq!(external_records.len(), 3);
+    let token_transfer = execution.get(3).unwrap();
+    for (i, resolved) in external_records.iter().enumerate() {
+        assert_eq!(resolved.entry().program_id(), program1.id());
+        assert_eq!(resolved.record_type().to_string(), "token.aleo/token");
+        match i {
+            // The record input is supplied by the caller.
+            0 => assert_eq!(resolved.origins(), &[ExternalRecordOrigin::External]),
+            // The record outputs are produced by 'token.aleo/transfer', or passed through from the record input.
+            _ => {
+                assert_eq!(resolved.origins().len(), 3);
+                assert!(resolved.origins().iter().all(|origin| match origin {
+                    ExternalRecordOrigin::Transition { transition_id, .. } => transition_id == token_transfer.id(),
+                    ExternalRecordOrigin::PassThrough { index } => *index == 0,
+                    ExternalRecordOrigin::External => false,
+                }));
+            }
+        }
+    }
+
     // use circuit::Environment;
     //
     // assert_eq!(6427, CurrentAleo::num_constants());
@@ -1239,7 +1263,7 @@ finalize compute:
     process.synthesize_key::<CurrentAleo, _>(program.id(), &function_name, rng).unwrap();
 
     // Reset the process.
-    let mut process = Process::load().unwrap();
+    let process = Process::load().unwrap();
 
     // Initialize a new block store.
     let block_store = BlockStore::<CurrentNetwork, BlockMemory<_>>::open(None).unwrap();
@@ -1352,7 +1376,7 @@ finalize compute:
     process.synthesize_key::<CurrentAleo, _>(program.id(), &function_name, rng).unwrap();
 
     // Reset the process.
-    let mut process = Process::load().unwrap();
+    let process = Process::load().unwrap();
 
     // Initialize a new block store.
     let block_store = BlockStore::<CurrentNetwork, BlockMemory<_>>::open(None).unwrap();
@@ -1479,7 +1503,7 @@ finalize mint_public:
     process.synthesize_key::<CurrentAleo, _>(program.id(), &function_name, rng).unwrap();
 
     // Reset the process.
-    let mut process = Process::load().unwrap();
+    let process = Process::load().unwrap();
 
     // Initialize a new block store.
     let block_store = BlockStore::<CurrentNetwork, BlockMemory<_>>::open(None).unwrap();
@@ -1608,7 +1632,7 @@ finalize mint_public:
     process.synthesize_key::<CurrentAleo, _>(program0.id(), &function_name, rng).unwrap();
 
     // Reset the process.
-    let mut process = Process::load().unwrap();
+    let process = Process::load().unwrap();
 
     // Initialize a new block store.
     let block_store = BlockStore::<CurrentNetwork, BlockMemory<_>>::open(None).unwrap();
@@ -1766,7 +1790,7 @@ finalize compute:
     process.synthesize_key::<CurrentAleo, _>(program.id(), &function_name, rng).unwrap();
 
     // Reset the process.
-    let mut process = Process::load().unwrap();
+    let process = Process::load().unwrap();
 
     // Initialize a new block store.
     let block_store = BlockStore::<CurrentNetwork, BlockMemory<_>>::open(None).unwrap();
@@ -1849,7 +1873,7 @@ function c:
     assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
 
     // Construct the process.
-    let mut process = crate::test_helpers::sample_process(&program0);
+    let process = crate::test_helpers::sample_process(&program0);
 
     // Initialize another program.
     let (string, program1) = Program::<CurrentNetwork>::parse(
@@ -1987,7 +2011,7 @@ fn test_complex_execution_order() {
     assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
 
     // Construct the process.
-    let mut process = crate::test_helpers::sample_process(&program0);
+    let process = crate::test_helpers::sample_process(&program0);
 
     // Initialize another program.
     let (string, program1) = Program::<CurrentNetwork>::parse(
@@ -2195,7 +2219,7 @@ finalize compute:
     process.synthesize_key::<CurrentAleo, _>(program.id(), &function_name, rng).unwrap();
 
     // Reset the process.
-    let mut process = Process::load().unwrap();
+    let process = Process::load().unwrap();
 
     // Initialize a new block store.
     let block_store = BlockStore::<CurrentNetwork, BlockMemory<_>>::open(None).unwrap();
@@ -2364,8 +2388,12 @@ fn test_process_deploy_credits_program() {
     let rng = &mut TestRng::default();
 
     // Initialize an empty process without the `credits` program.
-    let empty_process =
-        Process { universal_srs: Arc::new(UniversalSRS::<CurrentNetwork>::load().unwrap()), stacks: IndexMap::new() };
+    let empty_process = Process {
+        universal_srs: Arc::new(UniversalSRS::<CurrentNetwork>::load().unwrap()),
+        stacks: Default::default(),
+        base: None,
+        inclusion_keys: None,
+    };
 
     // Construct the process.
     let process = Process::load().unwrap();
@@ -2422,7 +2450,7 @@ function {function_name}:
     .unwrap();
 
     // Reset the process.
-    let mut process = Process::load().unwrap();
+    let process = Process::load().unwrap();
 
     // Initialize a new block store.
     let block_store = BlockStore::<CurrentNetwork, BlockMemory<_>>::open(None).unwrap();
@@ -2497,7 +2525,7 @@ fn test_long_import_chain() {
     .unwrap();
 
     // Construct the process.
-    let mut process = crate::test_helpers::sample_process(&program);
+    let process = crate::test_helpers::sample_process(&program);
 
     // Add `MAX_PROGRAM_DEPTH` programs to the process.
     for i in 1..=CurrentNetwork::MAX_PROGRAM_DEPTH {
@@ -2541,7 +2569,7 @@ fn test_long_import_chain_with_calls() {
     .unwrap();
 
     // Construct the process.
-    let mut process = crate::test_helpers::sample_process(&program);
+    let process = crate::test_helpers::sample_process(&program);
 
     // Check that the number of calls, up to `Transaction::MAX_TRANSITIONS - 1`, is correct.
     for i in 1..(Transaction::<CurrentNetwork>::MAX_TRANSITIONS - 1) {
@@ -2585,7 +2613,7 @@ fn test_long_import_chain_with_calls() {
 #[test]
 fn test_max_imports() {
     // Construct the process.
-    let mut process = Process::<CurrentNetwork>::load().unwrap();
+    let process = Process::<CurrentNetwork>::load().unwrap();
 
     // Add `MAX_IMPORTS` programs to the process.
     for i in 0..CurrentNetwork::MAX_IMPORTS {
@@ -2636,7 +2664,7 @@ fn test_program_exceeding_transaction_spend_limit() {
     .unwrap();
 
     // Initialize a `Process`.
-    let mut process = Process::<CurrentNetwork>::load().unwrap();
+    let process = Process::<CurrentNetwork>::load().unwrap();
 
     // Attempt to add the program to the process, which should fail.
     let result = process.add_program(&program);
@@ -2646,3 +2674,144 @@ fn test_program_exceeding_transaction_spend_limit() {
     let result = Stack::initialize(&process, &program);
     assert!(result.is_err());
 }
+
+#[test]
+fn test_synthesize_key_pair() {
+    // Initialize a new program.
+    let program = Program::<CurrentNetwork>::from_str(
+        r"program synthesize_key_pair.aleo;
+function compute:
+    input r0 as u32.private;
+    input r1 as u32.public;
+    add r0 r1 into r2;
+    output r2 as u32.public;",
+    )
+    .unwrap();
+    let function_name = Identifier::from_str("compute").unwrap();
+
+    // Initialize a `Process`.
+    let process = Process::<CurrentNetwork>::load().unwrap();
+
+    // Synthesize the key pair without adding the program to the process.
+    let (proving_key, verifying_key) =
+        process.synthesize_key_pair::<CurrentAleo, _>(&program, &function_name, &mut TestRng::default()).unwrap();
+    assert!(!process.contains_program(program.id()));
+
+    // Ensure the key pair matches the keys synthesized for the added program.
+    process.add_program(&program).unwrap();
+    process.synthesize_key::<CurrentAleo, _>(program.id(), &function_name, &mut TestRng::default()).unwrap();
+    assert_eq!(verifying_key, process.get_verifying_key(program.id(), function_name).unwrap());
+    assert_eq!(
+        proving_key.to_bytes_le().unwrap(),
+        process.get_proving_key(program.id(), function_name).unwrap().to_bytes_le().unwrap()
+    );
+
+    // Ensure synthesis fails for a function that does not exist.
+    let missing = Identifier::from_str("missing").unwrap();
+    assert!(process.synthesize_key_pair::<CurrentAleo, _>(&program, &missing, &mut TestRng::default()).is_err());
+}
+
+#[test]
+fn test_add_program_concurrently() {
+    // Initialize the programs.
+    let programs = (0..4)
+        .map(|i| {
+            Program::<CurrentNetwork>::from_str(&format!(
+                r"program concurrent_{i}.aleo;
+function compute:
+    input r0 as u32.private;
+    add r0 {i}u32 into r1;
+    output r1 as u32.public;"
+            ))
+            .unwrap()
+        })
+        .collect::<Vec<_>>();
+
+    // Initialize a `Process`.
+    let process = Process::<CurrentNetwork>::load().unwrap();
+
+    // Add the programs concurrently, with a shared reference to the process.
+    std::thread::scope(|scope| {
+        for program in &programs {
+            let process = &process;
+            scope.spawn(move || process.add_program(program).unwrap());
+        }
+    });
+
+    // Ensure all of the programs were added.
+    for program in &programs {
+        assert!(process.contains_program(program.id()));
+        assert_eq!(&process.get_program(program.id()).unwrap(), program);
+    }
+
+    // Ensure a clone of the process has its own mapping of stacks.
+    let clone = process.clone();
+    assert!(clone.remove_stack(programs[0].id()).is_some());
+    assert!(!clone.contains_program(programs[0].id()));
+    assert!(process.contains_program(programs[0].id()));
+}
+
+#[test]
+fn test_process_finalize_execution_metered() {
+    // Initialize a program, whose finalize scope skips the 'set' command if the amount is zero.
+    let program = Program::<CurrentNetwork>::from_str(
+        r"program testing_metering.aleo;
+mapping account:
+    key as address.public;
+    value as u64.public;
+
+function compute:
+    input r0 as address.public;
+    input r1 as u64.public;
+    async compute r0 r1 into r2;
+    output r2 as testing_metering.aleo/compute.future;
+
+finalize compute:
+    input r0 as address.public;
+    input r1 as u64.public;
+    branch.eq r1 0u64 to end;
+    set r1 into account[r0];
+    position end;",
+    )
+    .unwrap();
+    let function_name = Identifier::from_str("compute").unwrap();
+
+    // Initialize the RNG.
+    let rng = &mut TestRng::default();
+
+    // Initialize the process, block store, and finalize store.
+    let process = Process::<CurrentNetwork>::load().unwrap();
+    let block_store = BlockStore::<CurrentNetwork, BlockMemory<_>>::open(None).unwrap();
+    let finalize_store = FinalizeStore::<_, FinalizeMemory<_>>::open(None).unwrap();
+
+    // Deploy the program.
+    let deployment = process.deploy::<CurrentAleo, _>(&program, rng).unwrap();
+    let fee = sample_fee::<_, CurrentAleo, _, _>(&process, &block_store, &finalize_store, rng);
+    let (stack, _) = process.finalize_deployment(sample_finalize_state(1), &finalize_store, &deployment, &fee).unwrap();
+    process.add_stack(stack);
+
+    // Retrieve the static (worst-case) finalize cost.
+    let static_cost = process.get_stack(program.id()).unwrap().get_finalize_cost(&function_name).unwrap();
+
+    // Initialize a new caller account.
+    let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+    let caller = Address::try_from(&caller_private_key).unwrap();
+
+    for (amount, expected_cost) in [("0u64", 600), ("5u64", static_cost)] {
+        // Execute the function.
+        let inputs =
+            [Value::<CurrentNetwork>::from_str(&caller.to_string()).unwrap(), Value::from_str(amount).unwrap()];
+        let authorization = process
+            .authorize::<CurrentAleo, _>(&caller_private_key, program.id(), function_name, inputs.iter(), rng)
+            .unwrap();
+        let (_, mut trace) = process.execute::<CurrentAleo, _>(authorization, rng).unwrap();
+        trace.prepare(Query::from(block_store.clone())).unwrap();
+        let execution = trace.prove_execution::<CurrentAleo, _>("testing", rng).unwrap();
+
+        // Finalize the execution, and ensure only the evaluated commands were metered.
+        let (_, consumed) =
+            process.finalize_execution_metered(sample_finalize_state(1), &finalize_store, &execution, None).unwrap();
+        assert_eq!(consumed, expected_cost);
+        assert!(consumed <= static_cost);
+    }
+}
@@ -112,6 +112,11 @@ impl<N: Network> Inclusion<N> {
 
         Ok(())
     }
+
+    /// Returns the number of input tasks, which is the number of inclusion assignments.
+    pub fn num_input_tasks(&self) -> usize {
+        self.input_tasks.values().map(Vec::len).sum()
+    }
 }
 
 impl<N: Network> Inclusion<N> {
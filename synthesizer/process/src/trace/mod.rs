@@ -41,6 +41,8 @@ pub struct Trace<N: Network> {
     inclusion_tasks: Inclusion<N>,
     /// A list of call metrics.
     call_metrics: Vec<CallMetrics<N>>,
+    /// The inclusion proving key, if it is supplied in place of the network's inclusion proving key.
+    inclusion_proving_key: Option<ProvingKey<N>>,
 
     /// A tracker for the inclusion assignments.
     inclusion_assignments: OnceCell<Vec<InclusionAssignment<N>>>,
@@ -58,9 +60,16 @@ impl<N: Network> Trace<N> {
             inclusion_assignments: OnceCell::new(),
             global_state_root: OnceCell::new(),
             call_metrics: Vec::new(),
+            inclusion_proving_key: None,
         }
     }
 
+    /// Sets the inclusion proving key to use in place of the network's inclusion proving key.
+    pub(crate) fn with_inclusion_proving_key(mut self, inclusion_proving_key: Option<ProvingKey<N>>) -> Self {
+        self.inclusion_proving_key = inclusion_proving_key;
+        self
+    }
+
     /// Returns the list of transitions.
     pub fn transitions(&self) -> &[Transition<N>] {
         &self.transitions
@@ -147,6 +156,27 @@ impl<N: Network> Trace<N> {
         Ok(())
     }
 
+    /// Returns the number of bytes in the execution for the current transition(s), without computing the proof.
+    /// Note: This does *not* require the inclusion assignments or global state root to be set.
+    pub fn execution_size_in_bytes(&self) -> Result<u64> {
+        // Ensure this is not a fee.
+        ensure!(!self.is_fee(), "The trace cannot call 'execution_size_in_bytes' for a fee type");
+        // Construct the batch sizes of the proof, including the inclusion assignments (if any).
+        let mut batch_sizes =
+            self.transition_tasks.values().map(|(_, assignments)| assignments.len()).collect::<Vec<_>>();
+        let num_inclusions = self.inclusion_tasks.num_input_tasks();
+        if num_inclusions > 0 {
+            batch_sizes.push(num_inclusions);
+        }
+        // Compute the number of bytes in the execution without a proof.
+        let size_in_bytes =
+            Execution::from(self.transitions.iter().cloned(), N::StateRoot::default(), None)?.size_in_bytes()?;
+        // Compute the number of bytes in the proof.
+        let proof_size_in_bytes = u64::try_from(Proof::<N>::size_in_bytes_for(&batch_sizes))?;
+        // Return the number of bytes in the execution.
+        size_in_bytes.checked_add(proof_size_in_bytes).ok_or_else(|| anyhow!("The size of the execution overflowed"))
+    }
+
     /// Returns a new execution with a proof, for the current inclusion assignments and global state root.
     pub fn prove_execution<A: circuit::Aleo<Network = N>, R: Rng + CryptoRng>(
         &self,
@@ -169,8 +199,14 @@ impl<N: Network> Trace<N> {
         // Construct the proving tasks.
         let proving_tasks = self.transition_tasks.values().cloned().collect();
         // Compute the proof.
-        let (global_state_root, proof) =
-            Self::prove_batch::<A, R>(locator, proving_tasks, inclusion_assignments, *global_state_root, rng)?;
+        let (global_state_root, proof) = Self::prove_batch::<A, R>(
+            locator,
+            proving_tasks,
+            inclusion_assignments,
+            self.inclusion_proving_key.as_ref(),
+            *global_state_root,
+            rng,
+        )?;
         // Return the execution.
         Execution::from(self.transitions.iter().cloned(), global_state_root, Some(proof))
     }
@@ -201,6 +237,7 @@ impl<N: Network> Trace<N> {
             "credits.aleo/fee (private or public)",
             proving_tasks,
             inclusion_assignments,
+            self.inclusion_proving_key.as_ref(),
             *global_state_root,
             rng,
         )?;
@@ -209,11 +246,13 @@ impl<N: Network> Trace<N> {
     }
 
     /// Checks the proof for the execution.
+    /// If no inclusion verifying key is given, the network's inclusion verifying key is used.
     /// Note: This does *not* check that the global state root exists in the ledger.
     pub fn verify_execution_proof(
         locator: &str,
         verifier_inputs: Vec<(VerifyingKey<N>, Vec<Vec<N::Field>>)>,
         execution: &Execution<N>,
+        inclusion_verifying_key: Option<&VerifyingKey<N>>,
     ) -> Result<()> {
         // Retrieve the global state root.
         let global_state_root = execution.global_state_root();
@@ -224,15 +263,63 @@ impl<N: Network> Trace<N> {
         // Retrieve the proof.
         let Some(proof) = execution.proof() else { bail!("Expected the execution to contain a proof") };
         // Verify the execution proof.
-        match Self::verify_batch(locator, verifier_inputs, global_state_root, execution.transitions(), proof) {
+        match Self::verify_batch(
+            locator,
+            verifier_inputs,
+            global_state_root,
+            execution.transitions(),
+            proof,
+            inclusion_verifying_key,
+        ) {
             Ok(()) => Ok(()),
             Err(e) => bail!("Execution is invalid - {e}"),
         }
     }
 
+    /// Checks the proofs for the given executions, with a single product of pairings.
+    /// If no inclusion verifying key is given, the network's inclusion verifying key is used.
+    /// Note: This does *not* check that the global state roots exist in the ledger.
+    pub fn verify_execution_proofs<R: Rng + CryptoRng>(
+        batches: Vec<(Vec<(VerifyingKey<N>, Vec<Vec<N::Field>>)>, &Execution<N>)>,
+        inclusion_verifying_key: Option<&VerifyingKey<N>>,
+        rng: &mut R,
+    ) -> Result<()> {
+        let batches = batches
+            .into_iter()
+            .map(|(verifier_inputs, execution)| {
+                // Retrieve the global state root.
+                let global_state_root = execution.global_state_root();
+                // Ensure the global state root is not zero.
+                if global_state_root == N::StateRoot::default() {
+                    bail!("Inclusion expected the global state root in the execution to *not* be zero")
+                }
+                // Retrieve the proof.
+                let Some(proof) = execution.proof() else { bail!("Expected the execution to contain a proof") };
+                // Construct the verifier inputs, including the inclusion verifier inputs.
+                let verifier_inputs = Self::to_batch_verifier_inputs(
+                    verifier_inputs,
+                    global_state_root,
+                    execution.transitions(),
+                    inclusion_verifying_key,
+                )?;
+                Ok((verifier_inputs, proof))
+            })
+            .collect::<Result<Vec<_>>>()?;
+        // Verify the execution proofs.
+        match VerifyingKey::verify_batches(batches, rng) {
+            Ok(()) => Ok(()),
+            Err(e) => bail!("Executions are invalid - Failed to verify proofs - {e}"),
+        }
+    }
+
     /// Checks the proof for the fee.
+    /// If no inclusion verifying key is given, the network's inclusion verifying key is used.
     /// Note: This does *not* check that the global state root exists in the ledger.
-    pub fn verify_fee_proof(verifier_inputs: (VerifyingKey<N>, Vec<Vec<N::Field>>), fee: &Fee<N>) -> Result<()> {
+    pub fn verify_fee_proof(
+        verifier_inputs: (VerifyingKey<N>, Vec<Vec<N::Field>>),
+        fee: &Fee<N>,
+        inclusion_verifying_key: Option<&VerifyingKey<N>>,
+    ) -> Result<()> {
         // Retrieve the global state root.
         let global_state_root = fee.global_state_root();
         // Ensure the global state root is not zero.
@@ -248,11 +335,48 @@ impl<N: Network> Trace<N> {
             global_state_root,
             [fee.transition()].into_iter(),
             proof,
+            inclusion_verifying_key,
         ) {
             Ok(()) => Ok(()),
             Err(e) => bail!("Fee is invalid - {e}"),
         }
     }
+
+    /// Checks the proofs for the given fees, with a single product of pairings.
+    /// If no inclusion verifying key is given, the network's inclusion verifying key is used.
+    /// Note: This does *not* check that the global state roots exist in the ledger.
+    pub fn verify_fee_proofs<R: Rng + CryptoRng>(
+        batches: Vec<((VerifyingKey<N>, Vec<Vec<N::Field>>), &Fee<N>)>,
+        inclusion_verifying_key: Option<&VerifyingKey<N>>,
+        rng: &mut R,
+    ) -> Result<()> {
+        let batches = batches
+            .into_iter()
+            .map(|(verifier_inputs, fee)| {
+                // Retrieve the global state root.
+                let global_state_root = fee.global_state_root();
+                // Ensure the global state root is not zero.
+                if global_state_root == N::StateRoot::default() {
+                    bail!("Inclusion expected the global state root in the fee to *not* be zero")
+                }
+                // Retrieve the proof.
+                let Some(proof) = fee.proof() else { bail!("Expected the fee to contain a proof") };
+                // Construct the verifier inputs, including the inclusion verifier inputs.
+                let verifier_inputs = Self::to_batch_verifier_inputs(
+                    vec![verifier_inputs],
+                    global_state_root,
+                    [fee.transition()].into_iter(),
+                    inclusion_verifying_key,
+                )?;
+                Ok((verifier_inputs, proof))
+            })
+            .collect::<Result<Vec<_>>>()?;
+        // Verify the fee proofs.
+        match VerifyingKey::verify_batches(batches, rng) {
+            Ok(()) => Ok(()),
+            Err(e) => bail!("Fees are invalid - Failed to verify proofs - {e}"),
+        }
+    }
 }
 
 impl<N: Network> Trace<N> {
@@ -261,6 +385,7 @@ impl<N: Network> Trace<N> {
         locator: &str,
         mut proving_tasks: Vec<(ProvingKey<N>, Vec<Assignment<N::Field>>)>,
         inclusion_assignments: &[InclusionAssignment<N>],
+        inclusion_proving_key: Option<&ProvingKey<N>>,
         global_state_root: N::StateRoot,
         rng: &mut R,
     ) -> Result<(N::StateRoot, Proof<N>)> {
@@ -284,8 +409,11 @@ impl<N: Network> Trace<N> {
         }
 
         if !batch_inclusions.is_empty() {
-            // Fetch the inclusion proving key.
-            let proving_key = ProvingKey::<N>::new(N::inclusion_proving_key().clone());
+            // Fetch the inclusion proving key, falling back to the network's inclusion proving key.
+            let proving_key = match inclusion_proving_key {
+                Some(proving_key) => proving_key.clone(),
+                None => ProvingKey::<N>::new(N::inclusion_proving_key().clone()),
+            };
             // Insert the inclusion proving key and assignments.
             proving_tasks.push((proving_key, batch_inclusions));
         }
@@ -300,25 +428,45 @@ impl<N: Network> Trace<N> {
     /// Note: This does *not* check that the global state root exists in the ledger.
     fn verify_batch<'a>(
         locator: &str,
-        mut verifier_inputs: Vec<(VerifyingKey<N>, Vec<Vec<N::Field>>)>,
+        verifier_inputs: Vec<(VerifyingKey<N>, Vec<Vec<N::Field>>)>,
         global_state_root: N::StateRoot,
         transitions: impl ExactSizeIterator<Item = &'a Transition<N>>,
         proof: &Proof<N>,
+        inclusion_verifying_key: Option<&VerifyingKey<N>>,
     ) -> Result<()> {
+        // Construct the verifier inputs, including the inclusion verifier inputs.
+        let verifier_inputs =
+            Self::to_batch_verifier_inputs(verifier_inputs, global_state_root, transitions, inclusion_verifying_key)?;
+        // Verify the proof.
+        VerifyingKey::verify_batch(locator, verifier_inputs, proof).map_err(|e| anyhow!("Failed to verify proof - {e}"))
+    }
+
+    /// Returns the given verifier inputs, extended with the inclusion verifier inputs for the given transitions.
+    fn to_batch_verifier_inputs<'a>(
+        mut verifier_inputs: Vec<(VerifyingKey<N>, Vec<Vec<N::Field>>)>,
+        global_state_root: N::StateRoot,
+        transitions: impl ExactSizeIterator<Item = &'a Transition<N>>,
+        inclusion_verifying_key: Option<&VerifyingKey<N>>,
+    ) -> Result<Vec<(VerifyingKey<N>, Vec<Vec<N::Field>>)>> {
         // Construct the batch of inclusion verifier inputs.
         let batch_inclusion_inputs = Inclusion::prepare_verifier_inputs(global_state_root, transitions)?;
         // Insert the batch of inclusion verifier inputs to the verifier inputs.
         if !batch_inclusion_inputs.is_empty() {
-            // Retrieve the inclusion verifying key.
-            let verifying_key = N::inclusion_verifying_key().clone();
-            // Retrieve the number of public and private variables.
-            // Note: This number does *NOT* include the number of constants. This is safe because
-            // this program is never deployed, as it is a first-class citizen of the protocol.
-            let num_variables = verifying_key.circuit_info.num_public_and_private_variables as u64;
+            // Retrieve the inclusion verifying key, falling back to the network's inclusion verifying key.
+            let verifying_key = match inclusion_verifying_key {
+                Some(verifying_key) => verifying_key.clone(),
+                None => {
+                    let verifying_key = N::inclusion_verifying_key().clone();
+                    // Retrieve the number of public and private variables.
+                    // Note: This number does *NOT* include the number of constants. This is safe because
+                    // this program is never deployed, as it is a first-class citizen of the protocol.
+                    let num_variables = verifying_key.circuit_info.num_public_and_private_variables as u64;
+                    VerifyingKey::<N>::new(verifying_key, num_variables)
+                }
+            };
             // Insert the inclusion verifier inputs.
-            verifier_inputs.push((VerifyingKey::<N>::new(verifying_key, num_variables), batch_inclusion_inputs));
+            verifier_inputs.push((verifying_key, batch_inclusion_inputs));
         }
-        // Verify the proof.
-        VerifyingKey::verify_batch(locator, verifier_inputs, proof).map_err(|e| anyhow!("Failed to verify proof - {e}"))
+        Ok(verifier_inputs)
     }
 }
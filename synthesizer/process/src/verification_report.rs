@@ -0,0 +1,206 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use console::{
+    prelude::*,
+    program::{Identifier, ProgramID},
+};
+
+use indexmap::IndexMap;
+use std::time::Duration;
+
+/// The verification details of a single transition in an execution.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TransitionReport<N: Network> {
+    /// The transition ID.
+    transition_id: N::TransitionID,
+    /// The program ID.
+    program_id: ProgramID<N>,
+    /// The function name.
+    function_name: Identifier<N>,
+    /// The number of public inputs to the transition circuit.
+    num_public_inputs: usize,
+    /// The time spent checking the inputs and outputs, and constructing the verifier inputs.
+    input_check_time: Duration,
+}
+
+impl<N: Network> TransitionReport<N> {
+    /// Initializes a new transition report.
+    pub const fn new(
+        transition_id: N::TransitionID,
+        program_id: ProgramID<N>,
+        function_name: Identifier<N>,
+        num_public_inputs: usize,
+        input_check_time: Duration,
+    ) -> Self {
+        Self { transition_id, program_id, function_name, num_public_inputs, input_check_time }
+    }
+
+    /// Returns the transition ID.
+    pub const fn transition_id(&self) -> &N::TransitionID {
+        &self.transition_id
+    }
+
+    /// Returns the program ID.
+    pub const fn program_id(&self) -> &ProgramID<N> {
+        &self.program_id
+    }
+
+    /// Returns the function name.
+    pub const fn function_name(&self) -> &Identifier<N> {
+        &self.function_name
+    }
+
+    /// Returns the number of public inputs to the transition circuit.
+    pub const fn num_public_inputs(&self) -> usize {
+        self.num_public_inputs
+    }
+
+    /// Returns the time spent checking the inputs and outputs, and constructing the verifier inputs.
+    pub const fn input_check_time(&self) -> Duration {
+        self.input_check_time
+    }
+}
+
+/// The verification details of an execution, as returned by `Process::verify_execution_with_report`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct VerificationReport<N: Network> {
+    /// The reports for each transition, in the order of the execution.
+    transitions: Vec<TransitionReport<N>>,
+    /// The number of distinct circuits in the batch proof (excluding the inclusion circuit).
+    num_circuits: usize,
+    /// The number of circuit instances in the batch proof (excluding the inclusion instances).
+    num_instances: usize,
+    /// The time spent verifying the batch proof.
+    proof_verification_time: Duration,
+}
+
+impl<N: Network> Default for VerificationReport<N> {
+    /// Returns an empty verification report.
+    fn default() -> Self {
+        Self { transitions: Vec::new(), num_circuits: 0, num_instances: 0, proof_verification_time: Duration::ZERO }
+    }
+}
+
+impl<N: Network> VerificationReport<N> {
+    /// Adds the given transition report.
+    pub(crate) fn push_transition(&mut self, transition: TransitionReport<N>) {
+        self.transitions.push(transition);
+    }
+
+    /// Sets the number of distinct circuits and instances in the batch proof.
+    pub(crate) fn set_batch_size(&mut self, num_circuits: usize, num_instances: usize) {
+        self.num_circuits = num_circuits;
+        self.num_instances = num_instances;
+    }
+
+    /// Sets the time spent verifying the batch proof.
+    pub(crate) fn set_proof_verification_time(&mut self, proof_verification_time: Duration) {
+        self.proof_verification_time = proof_verification_time;
+    }
+}
+
+impl<N: Network> VerificationReport<N> {
+    /// Returns the reports for each transition, in the order of the execution.
+    pub fn transitions(&self) -> &[TransitionReport<N>] {
+        &self.transitions
+    }
+
+    /// Returns the number of distinct circuits in the batch proof (excluding the inclusion circuit).
+    pub const fn num_circuits(&self) -> usize {
+        self.num_circuits
+    }
+
+    /// Returns the number of circuit instances in the batch proof (excluding the inclusion instances).
+    pub const fn num_instances(&self) -> usize {
+        self.num_instances
+    }
+
+    /// Returns the time spent verifying the batch proof.
+    pub const fn proof_verification_time(&self) -> Duration {
+        self.proof_verification_time
+    }
+
+    /// Returns the total time spent checking the inputs and outputs of the transitions.
+    pub fn input_check_time(&self) -> Duration {
+        self.transitions.iter().map(TransitionReport::input_check_time).sum()
+    }
+
+    /// Returns the total time spent verifying the execution.
+    pub fn total_time(&self) -> Duration {
+        self.input_check_time().saturating_add(self.proof_verification_time)
+    }
+
+    /// Returns the time spent checking the inputs and outputs of the transitions, grouped by program ID,
+    /// in descending order of time spent.
+    pub fn input_check_time_by_program(&self) -> IndexMap<ProgramID<N>, Duration> {
+        let mut times = IndexMap::<_, Duration>::new();
+        for transition in &self.transitions {
+            *times.entry(*transition.program_id()).or_default() += transition.input_check_time();
+        }
+        times.sort_by(|_, a, _, b| b.cmp(a));
+        times
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helpers::{sample_execution, sample_process};
+    use console::network::MainnetV0;
+    use synthesizer_program::Program;
+
+    type CurrentNetwork = MainnetV0;
+    type CurrentAleo = circuit::network::AleoV0;
+
+    #[test]
+    fn test_verify_execution_with_report() {
+        let rng = &mut TestRng::default();
+
+        // Initialize the program used by the sample execution.
+        let program = Program::<CurrentNetwork>::from_str(
+            r"
+program testing.aleo;
+
+function compute:
+    input r0 as u32.private;
+    input r1 as u32.public;
+    add r0 r1 into r2;
+    output r2 as u32.public;",
+        )
+        .unwrap();
+        let function_name = Identifier::from_str("compute").unwrap();
+
+        // Construct the process, and synthesize the verifying key.
+        let process = sample_process(&program);
+        process.synthesize_key::<CurrentAleo, _>(program.id(), &function_name, rng).unwrap();
+
+        // Verify the sample execution, with a report.
+        let execution = sample_execution();
+        let report = process.verify_execution_with_report(&execution).unwrap();
+
+        // Ensure the report describes the execution.
+        assert_eq!(report.transitions().len(), 1);
+        let transition = &report.transitions()[0];
+        assert_eq!(transition.transition_id(), execution.peek().unwrap().id());
+        assert_eq!(transition.program_id(), program.id());
+        assert_eq!(transition.function_name(), &function_name);
+        assert!(transition.num_public_inputs() > 0);
+        assert_eq!(report.num_circuits(), 1);
+        assert_eq!(report.num_instances(), 1);
+        assert_eq!(report.total_time(), report.input_check_time() + report.proof_verification_time());
+        assert_eq!(report.input_check_time_by_program().keys().collect::<Vec<_>>(), vec![program.id()]);
+    }
+}
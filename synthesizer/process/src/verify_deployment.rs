@@ -41,6 +41,54 @@ impl<N: Network> Process<N> {
         finish!(timer);
         verification
     }
+
+    /// Verifies the given import manifest for the given deployment and program owner.
+    ///
+    /// This ensures the manifest is signed by the program owner, covers the deployment,
+    /// and records the checksum of each imported program, as it exists in the process.
+    #[inline]
+    pub fn verify_import_manifest(
+        &self,
+        deployment: &Deployment<N>,
+        owner: &ProgramOwner<N>,
+        manifest: &ImportManifest<N>,
+    ) -> Result<()> {
+        // Retrieve the program ID.
+        let program_id = deployment.program_id();
+        // Ensure the manifest is for the deployment.
+        ensure!(manifest.program_id() == program_id, "The import manifest is not for program '{program_id}'");
+        ensure!(
+            *manifest.deployment_id() == deployment.to_deployment_id()?,
+            "The import manifest is not for the deployment of '{program_id}'"
+        );
+        // Ensure the manifest is signed by the program owner.
+        ensure!(*manifest.owner() == owner.address(), "The import manifest is not from the owner of '{program_id}'");
+        ensure!(manifest.verify_signature(), "The import manifest for '{program_id}' has an invalid signature");
+
+        // Ensure the manifest entries correspond to the program imports.
+        let imports = deployment.program().imports().keys();
+        ensure!(
+            imports.len() == manifest.imports().len(),
+            "The import manifest for '{program_id}' has {} entries, but the program has {} imports",
+            manifest.imports().len(),
+            imports.len()
+        );
+        for (import_id, entry) in imports.zip_eq(manifest.imports()) {
+            ensure!(
+                import_id == entry.program_id(),
+                "The import manifest for '{program_id}' lists '{}', but expected '{import_id}'",
+                entry.program_id()
+            );
+            // Ensure the checksum matches the imported program in the process.
+            let checksum = ImportManifestEntry::compute_checksum(&self.get_program(import_id)?)?;
+            ensure!(
+                *entry.checksum() == checksum,
+                "The import manifest for '{program_id}' has a mismatching checksum for '{import_id}' (source: '{}')",
+                entry.source()
+            );
+        }
+        Ok(())
+    }
 }
 
 #[cfg(test)]
@@ -69,4 +117,61 @@ mod tests {
 
         bail!("\n\nRemember to #[ignore] this test!\n\n")
     }
+
+    #[test]
+    fn test_verify_import_manifest() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        // Initialize the process.
+        let process = Process::load()?;
+
+        // Create a deployment for a program that imports 'credits.aleo'.
+        let program = Program::from_str(
+            r"
+import credits.aleo;
+program manifest_test.aleo;
+function compute:
+    input r0 as u32.private;
+    output r0 as u32.public;",
+        )?;
+        let deployment = process.deploy::<CurrentAleo, _>(&program, rng)?;
+
+        // Sign the manifest as the program owner.
+        let private_key = PrivateKey::new(rng)?;
+        let owner = ProgramOwner::new(&private_key, deployment.to_deployment_id()?, rng)?;
+        let credits = process.get_program("credits.aleo")?;
+        let entry = ImportManifestEntry::new(&credits, "mainnet")?;
+        let manifest = ImportManifest::new(&private_key, &deployment, vec![entry], rng)?;
+
+        // Ensure the manifest is verified.
+        process.verify_import_manifest(&deployment, &owner, &manifest)?;
+
+        // Ensure a manifest pinned to the checksum of the imported program is verified.
+        let entry = ImportManifestEntry::from_checksum(*credits.id(), "mainnet", credits.to_checksum()?)?;
+        let pinned_manifest = ImportManifest::new(&private_key, &deployment, vec![entry], rng)?;
+        process.verify_import_manifest(&deployment, &owner, &pinned_manifest)?;
+        // Ensure a manifest pinned to a different checksum is rejected.
+        let entry =
+            ImportManifestEntry::from_checksum(*credits.id(), "mainnet", credits.to_checksum()? + Field::one())?;
+        let pinned_manifest = ImportManifest::new(&private_key, &deployment, vec![entry], rng)?;
+        assert!(process.verify_import_manifest(&deployment, &owner, &pinned_manifest).is_err());
+
+        // Ensure a manifest from another signer is rejected.
+        let other_private_key = PrivateKey::new(rng)?;
+        let entry = ImportManifestEntry::new(&credits, "mainnet")?;
+        let other_manifest = ImportManifest::new(&other_private_key, &deployment, vec![entry], rng)?;
+        assert!(process.verify_import_manifest(&deployment, &owner, &other_manifest).is_err());
+
+        // Ensure a manifest with a mismatching checksum is rejected.
+        let modified = credits.to_string().replace(
+            "program credits.aleo;",
+            "program credits.aleo;\n\nmapping unused:\n    key as u8.public;\n    value as u8.public;",
+        );
+        let modified = Program::from_str(&modified)?;
+        assert_eq!(modified.id(), credits.id());
+        let entry = ImportManifestEntry::new(&modified, "elsewhere")?;
+        let bad_manifest = ImportManifest::new(&private_key, &deployment, vec![entry], rng)?;
+        assert!(process.verify_import_manifest(&deployment, &owner, &bad_manifest).is_err());
+        Ok(())
+    }
 }
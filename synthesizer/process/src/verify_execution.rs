@@ -15,6 +15,9 @@
 
 use super::*;
 
+#[cfg(not(feature = "serial"))]
+use rayon::prelude::*;
+
 impl<N: Network> Process<N> {
     /// Verifies the given execution is valid.
     /// Note: This does *not* check that the global state root exists in the ledger.
@@ -22,6 +25,84 @@ impl<N: Network> Process<N> {
     pub fn verify_execution(&self, execution: &Execution<N>) -> Result<()> {
         let timer = timer!("Process::verify_execution");
 
+        // Construct the verifier inputs for the execution.
+        let (locator, verifier_inputs) = self.to_execution_verifier_inputs(execution)?;
+        lap!(timer, "Construct the verifier inputs");
+
+        // Verify the execution proof.
+        Trace::verify_execution_proof(&locator, verifier_inputs, execution, self.inclusion_verifying_key())?;
+        lap!(timer, "Verify the proof");
+
+        finish!(timer);
+        Ok(())
+    }
+
+    /// Verifies the given execution is valid, and returns a report with the verification details of each transition.
+    /// Note: This does *not* check that the global state root exists in the ledger.
+    #[inline]
+    pub fn verify_execution_with_report(&self, execution: &Execution<N>) -> Result<VerificationReport<N>> {
+        // Initialize the report.
+        let mut report = VerificationReport::default();
+
+        // Construct the verifier inputs for the execution.
+        let (locator, verifier_inputs) = self.to_execution_verifier_inputs_internal(execution, Some(&mut report))?;
+
+        // Verify the execution proof.
+        let start = std::time::Instant::now();
+        Trace::verify_execution_proof(&locator, verifier_inputs, execution, self.inclusion_verifying_key())?;
+        report.set_proof_verification_time(start.elapsed());
+
+        Ok(report)
+    }
+
+    /// Verifies the given executions are valid, checking all of their proofs with a single product of pairings.
+    /// Note: This does *not* check that the global state roots exist in the ledger.
+    /// On failure, this does not identify which execution is invalid; use `Process::verify_execution` to do so.
+    #[inline]
+    pub fn verify_execution_batch<R: Rng + CryptoRng>(&self, executions: &[&Execution<N>], rng: &mut R) -> Result<()> {
+        let timer = timer!("Process::verify_execution_batch");
+
+        // Ensure there are executions to verify.
+        ensure!(!executions.is_empty(), "There are no executions to verify");
+
+        // Construct the verifier inputs for each execution.
+        let batches = cfg_iter!(executions)
+            .map(|execution| Ok((self.to_execution_verifier_inputs(execution)?.1, *execution)))
+            .collect::<Result<Vec<_>>>()?;
+        lap!(timer, "Construct the verifier inputs");
+
+        // Verify the execution proofs.
+        Trace::verify_execution_proofs(batches, self.inclusion_verifying_key(), rng)?;
+        lap!(timer, "Verify the proofs");
+
+        finish!(timer);
+        Ok(())
+    }
+
+    /// Checks the transitions of the given execution, and returns the locator of the main function
+    /// along with the verifier inputs for the execution proof.
+    fn to_execution_verifier_inputs(
+        &self,
+        execution: &Execution<N>,
+    ) -> Result<(String, Vec<(VerifyingKey<N>, Vec<Vec<N::Field>>)>)> {
+        self.to_execution_verifier_inputs_internal(execution, None)
+    }
+
+    /// Checks the transitions of the given execution, and returns the locator of the main function
+    /// along with the verifier inputs for the execution proof. If a report is given, it is populated
+    /// with the verification details of each transition.
+    fn to_execution_verifier_inputs_internal(
+        &self,
+        execution: &Execution<N>,
+        mut report: Option<&mut VerificationReport<N>>,
+    ) -> Result<(String, Vec<(VerifyingKey<N>, Vec<Vec<N::Field>>)>)> {
+        let timer = timer!("Process::to_execution_verifier_inputs");
+
+        // Ensure no platform-dependent operations influence the verification result.
+        // Note: The timings in the report are measured, but never influence the result.
+        #[cfg(feature = "audit")]
+        let _scope = crate::audit::DeterministicScope::enter("verify_execution");
+
         // Ensure the execution contains transitions.
         ensure!(!execution.is_empty(), "There are no transitions in the execution");
 
@@ -50,7 +131,8 @@ impl<N: Network> Process<N> {
         let reverse_call_graph = Self::reverse_call_graph(&call_graph);
 
         // Initialize a map of verifying keys to public inputs.
-        let mut verifier_inputs = HashMap::new();
+        // Note: This map is ordered by insertion, so the resulting list of verifier inputs is deterministic.
+        let mut verifier_inputs = IndexMap::new();
 
         // Initialize a map of transition IDs to references of the transition.
         let mut transition_map = HashMap::new();
@@ -59,6 +141,8 @@ impl<N: Network> Process<N> {
         for transition in execution.transitions() {
             #[cfg(debug_assertions)]
             println!("Verifying transition for {}/{}...", transition.program_id(), transition.function_name());
+            // Start the transition timer, if a report is requested.
+            let start = report.as_ref().map(|_| std::time::Instant::now());
             // Debug-mode only, as the `Transition` constructor recomputes the transition ID at initialization.
             debug_assert_eq!(
                 **transition.id(),
@@ -116,10 +200,30 @@ impl<N: Network> Process<N> {
             // Note: The last transition in the execution does not have a parent, by definition.
             let parent = reverse_call_graph.get(transition.id()).and_then(|tid| execution.get_program_id(tid));
 
+            // Retrieve the transitions called by this transition.
+            // Note: As transitions are processed in post-order, all child transitions have been added to `transition_map`.
+            let children = match call_graph.get(transition.id()) {
+                Some(transition_ids) => transition_ids
+                    .iter()
+                    .map(|id| transition_map.get(id).copied().ok_or_else(|| anyhow!("Missing child transition '{id}'")))
+                    .collect::<Result<Vec<_>>>()?,
+                None => bail!("Transition '{}' is missing from the call graph", transition.id()),
+            };
             // Construct the verifier inputs for the transition.
-            let inputs = self.to_transition_verifier_inputs(transition, parent, &call_graph, &mut transition_map)?;
+            let inputs = stack.verifier_inputs_for_transition(transition, parent, &children)?;
             lap!(timer, "Constructed the verifier inputs for a transition of {}", function.name());
 
+            // Record the transition in the report, if one is requested.
+            if let (Some(report), Some(start)) = (report.as_deref_mut(), start) {
+                report.push_transition(TransitionReport::new(
+                    *transition.id(),
+                    *transition.program_id(),
+                    *transition.function_name(),
+                    inputs.len(),
+                    start.elapsed(),
+                ));
+            }
+
             // Save the verifying key and its inputs.
             verifier_inputs
                 .entry(Locator::new(*stack.program_id(), *function.name()))
@@ -148,66 +252,16 @@ impl<N: Network> Process<N> {
             })
         })?;
 
-        // Construct the list of verifier inputs.
-        let verifier_inputs: Vec<_> = verifier_inputs.values().cloned().collect();
-        // Verify the execution proof.
-        Trace::verify_execution_proof(&locator, verifier_inputs, execution)?;
-
-        lap!(timer, "Verify the proof");
-
-        finish!(timer);
-        Ok(())
-    }
-}
-
-impl<N: Network> Process<N> {
-    /// Returns the public inputs to verify the proof for the given transition.
-    fn to_transition_verifier_inputs(
-        &self,
-        transition: &Transition<N>,
-        parent: Option<&ProgramID<N>>,
-        call_graph: &HashMap<N::TransitionID, Vec<N::TransitionID>>,
-        transition_map: &mut HashMap<N::TransitionID, &Transition<N>>,
-    ) -> Result<Vec<N::Field>> {
-        // Compute the x- and y-coordinate of `tpk`.
-        let (tpk_x, tpk_y) = transition.tpk().to_xy_coordinates();
-
-        // Determine the value of `is_root` and `parent`.
-        let (is_root, parent) = match parent {
-            // If there is a parent, then `is_root` is `0` and `parent` is the parent program ID.
-            Some(program_id) => (Field::<N>::zero(), *program_id),
-            // If there is no parent, then `is_root` is `1` and `parent` is the root program ID.
-            None => (Field::one(), *transition.program_id()),
-        };
-        // Compute the x- and y-coordinate of `parent`.
-        let (parent_x, parent_y) = parent.to_address()?.to_xy_coordinates();
-
-        // [Inputs] Construct the verifier inputs to verify the proof.
-        let mut inputs = vec![N::Field::one(), *tpk_x, *tpk_y, **transition.tcm(), **transition.scm()];
-        // [Inputs] Extend the verifier inputs with the input IDs.
-        inputs.extend(transition.inputs().iter().flat_map(|input| input.verifier_inputs()));
-        // [Inputs] Extend the verifier inputs with the public inputs for 'self.caller'.
-        inputs.extend([*is_root, *parent_x, *parent_y]);
-
-        // If there are function calls, append their inputs and outputs.
-        for transition_id in call_graph.get(transition.id()).unwrap() {
-            // Note: This unwrap is safe, as we are processing transitions in post-order,
-            // which implies that all child transition IDs have been added to `transition_map`.
-            let transition: &&Transition<N> = transition_map.get(transition_id).unwrap();
-            // [Inputs] Extend the verifier inputs with the transition commitment of the external call.
-            inputs.extend([**transition.tcm()]);
-            // [Inputs] Extend the verifier inputs with the input IDs of the external call.
-            inputs.extend(transition.inputs().iter().flat_map(|input| input.verifier_inputs()));
-            // [Inputs] Extend the verifier inputs with the output IDs of the external call.
-            inputs.extend(transition.output_ids().map(|id| **id));
+        // Record the size of the batch proof in the report, if one is requested.
+        if let Some(report) = report {
+            report.set_batch_size(verifier_inputs.len(), num_instances);
         }
 
-        // [Inputs] Extend the verifier inputs with the output IDs.
-        inputs.extend(transition.outputs().iter().flat_map(|output| output.verifier_inputs()));
+        // Construct the list of verifier inputs.
+        let verifier_inputs: Vec<_> = verifier_inputs.into_values().collect();
 
-        #[cfg(debug_assertions)]
-        println!("Transition public inputs ({} elements): {:#?}", inputs.len(), inputs);
-        Ok(inputs)
+        finish!(timer);
+        Ok((locator, verifier_inputs))
     }
 }
 
@@ -398,3 +452,35 @@ impl<N: Network> Process<N> {
         reverse_call_graph
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::prelude::TestRng;
+
+    #[test]
+    fn test_verify_execution_batch() {
+        let rng = &mut TestRng::default();
+
+        // Fetch the execution.
+        let execution = ledger_test_helpers::sample_execution(rng);
+
+        // Construct a new process.
+        let process = Process::load().unwrap();
+
+        // Verify the executions in a batch.
+        process.verify_execution_batch(&[&execution, &execution], rng).unwrap();
+        // Ensure the batch agrees with verifying the execution individually.
+        process.verify_execution(&execution).unwrap();
+
+        // Construct an execution with a proof from a different circuit.
+        let fee = ledger_test_helpers::sample_fee_private_hardcoded(rng);
+        let invalid_execution =
+            Execution::from(execution.transitions().cloned(), execution.global_state_root(), fee.proof().cloned())
+                .unwrap();
+        // Ensure a batch with an invalid proof is rejected.
+        assert!(process.verify_execution_batch(&[&execution, &invalid_execution], rng).is_err());
+        // Ensure an empty batch is rejected.
+        assert!(process.verify_execution_batch(&[], rng).is_err());
+    }
+}
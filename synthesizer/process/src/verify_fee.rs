@@ -15,6 +15,9 @@
 
 use super::*;
 
+#[cfg(not(feature = "serial"))]
+use rayon::prelude::*;
+
 impl<N: Network> Process<N> {
     /// Verifies the given fee is valid.
     /// Note: This does *not* check that the global state root exists in the ledger.
@@ -22,6 +25,49 @@ impl<N: Network> Process<N> {
     pub fn verify_fee(&self, fee: &Fee<N>, deployment_or_execution_id: Field<N>) -> Result<()> {
         let timer = timer!("Process::verify_fee");
 
+        // Check the fee transition, and construct the verifier inputs for the fee proof.
+        let verifier_inputs = self.to_fee_verifier_inputs(fee, deployment_or_execution_id)?;
+        lap!(timer, "Verify the fee transition");
+
+        // Ensure the fee proof is valid.
+        Trace::verify_fee_proof(verifier_inputs, fee, self.inclusion_verifying_key())?;
+        finish!(timer, "Verify the fee proof");
+        Ok(())
+    }
+
+    /// Verifies the given fees are valid, checking all of their proofs with a single product of pairings.
+    /// Each fee is given with its deployment or execution ID.
+    /// Note: This does *not* check that the global state roots exist in the ledger.
+    /// On failure, this does not identify which fee is invalid; use `Process::verify_fee` to do so.
+    #[inline]
+    pub fn verify_fees<R: Rng + CryptoRng>(&self, fees: &[(&Fee<N>, Field<N>)], rng: &mut R) -> Result<()> {
+        let timer = timer!("Process::verify_fees");
+
+        // Ensure there are fees to verify.
+        ensure!(!fees.is_empty(), "There are no fees to verify");
+
+        // Check each fee transition, and construct the verifier inputs for each fee proof.
+        let batches = cfg_iter!(fees)
+            .map(|(fee, deployment_or_execution_id)| {
+                Ok((self.to_fee_verifier_inputs(fee, *deployment_or_execution_id)?, *fee))
+            })
+            .collect::<Result<Vec<_>>>()?;
+        lap!(timer, "Construct the verifier inputs");
+
+        // Verify the fee proofs.
+        Trace::verify_fee_proofs(batches, self.inclusion_verifying_key(), rng)?;
+        finish!(timer, "Verify the proofs");
+        Ok(())
+    }
+}
+
+impl<N: Network> Process<N> {
+    /// Checks the given fee transition is well-formed, and returns the verifier inputs for the fee proof.
+    fn to_fee_verifier_inputs(
+        &self,
+        fee: &Fee<N>,
+        deployment_or_execution_id: Field<N>,
+    ) -> Result<(VerifyingKey<N>, Vec<Vec<N::Field>>)> {
         #[cfg(debug_assertions)]
         {
             println!("Verifying fee from {}/{}...", fee.program_id(), fee.function_name());
@@ -60,21 +106,18 @@ impl<N: Network> Process<N> {
         if candidate_id != deployment_or_execution_id {
             bail!("Incorrect deployment or execution ID in the fee transition")
         }
-        lap!(timer, "Verify the deployment or execution ID");
 
         // Verify the fee transition is well-formed.
         match is_fee_private {
-            true => self.verify_fee_private(&fee)?,
-            false => self.verify_fee_public(&fee)?,
+            true => self.to_fee_private_verifier_inputs(&fee),
+            false => self.to_fee_public_verifier_inputs(&fee),
         }
-        finish!(timer, "Verify the fee transition");
-        Ok(())
     }
 }
 
 impl<N: Network> Process<N> {
-    /// Verifies the transition for `credits.aleo/fee_private` is well-formed.
-    fn verify_fee_private(&self, fee: &&Fee<N>) -> Result<()> {
+    /// Checks the `credits.aleo/fee_private` transition is well-formed, and returns the verifier inputs for its proof.
+    fn to_fee_private_verifier_inputs(&self, fee: &&Fee<N>) -> Result<(VerifyingKey<N>, Vec<Vec<N::Field>>)> {
         let timer = timer!("Process::verify_fee_private");
 
         // Retrieve the network ID.
@@ -134,16 +177,14 @@ impl<N: Network> Process<N> {
 
         // Retrieve the verifying key.
         let verifying_key = self.get_verifying_key(fee.program_id(), fee.function_name())?;
+        finish!(timer, "Retrieve the verifying key");
 
-        // Ensure the fee proof is valid.
-        Trace::verify_fee_proof((verifying_key, vec![inputs]), fee)?;
-        finish!(timer, "Verify the fee proof");
-        Ok(())
+        Ok((verifying_key, vec![inputs]))
     }
 
-    /// Verifies the transition for `credits.aleo/fee_public` is well-formed.
+    /// Checks the `credits.aleo/fee_public` transition is well-formed, and returns the verifier inputs for its proof.
     /// Attention: This method does *not* verify the account balance is sufficient.
-    fn verify_fee_public(&self, fee: &&Fee<N>) -> Result<()> {
+    fn to_fee_public_verifier_inputs(&self, fee: &&Fee<N>) -> Result<(VerifyingKey<N>, Vec<Vec<N::Field>>)> {
         let timer = timer!("Process::verify_fee_public");
 
         // Retrieve the network ID.
@@ -203,11 +244,9 @@ impl<N: Network> Process<N> {
 
         // Retrieve the verifying key.
         let verifying_key = self.get_verifying_key(fee.program_id(), fee.function_name())?;
+        finish!(timer, "Retrieve the verifying key");
 
-        // Ensure the fee proof is valid.
-        Trace::verify_fee_proof((verifying_key, vec![inputs]), fee)?;
-        finish!(timer, "Verify the fee proof");
-        Ok(())
+        Ok((verifying_key, vec![inputs]))
     }
 }
 
@@ -248,11 +287,44 @@ mod tests {
                     // Verify the fee.
                     process.verify_fee(&fee.unwrap(), execution_id).unwrap();
                 }
-                Transaction::Fee(_, fee) => match fee.is_fee_private() {
-                    true => process.verify_fee_private(&&fee).unwrap(),
-                    false => process.verify_fee_public(&&fee).unwrap(),
-                },
+                Transaction::Fee(_, fee) => {
+                    let verifier_inputs = match fee.is_fee_private() {
+                        true => process.to_fee_private_verifier_inputs(&&fee).unwrap(),
+                        false => process.to_fee_public_verifier_inputs(&&fee).unwrap(),
+                    };
+                    Trace::verify_fee_proof(verifier_inputs, &fee, None).unwrap();
+                }
             }
         }
     }
+
+    #[test]
+    fn test_verify_fees() {
+        let rng = &mut TestRng::default();
+
+        // Fetch the fees.
+        let fees = [
+            ledger_test_helpers::sample_fee_private_transaction(rng),
+            ledger_test_helpers::sample_fee_public_transaction(rng),
+        ]
+        .into_iter()
+        .map(|transaction| match transaction {
+            Transaction::Fee(_, fee) => fee,
+            _ => unreachable!("Expected a fee transaction"),
+        })
+        .collect::<Vec<_>>();
+
+        // Construct a new process.
+        let process = Process::load().unwrap();
+
+        // Verify the fees in a batch.
+        let batch = fees.iter().map(|fee| (fee, fee.deployment_or_execution_id().unwrap())).collect::<Vec<_>>();
+        process.verify_fees(&batch, rng).unwrap();
+
+        // Ensure a batch with an incorrect deployment or execution ID is rejected.
+        let invalid_batch = vec![batch[0], (batch[1].0, Field::zero())];
+        assert!(process.verify_fees(&invalid_batch, rng).is_err());
+        // Ensure an empty batch is rejected.
+        assert!(process.verify_fees(&[], rng).is_err());
+    }
 }
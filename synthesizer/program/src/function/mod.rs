@@ -29,6 +29,7 @@ use crate::{
 use console::{
     network::prelude::*,
     program::{Identifier, Register, ValueType},
+    types::Field,
 };
 
 use indexmap::IndexSet;
@@ -88,6 +89,23 @@ impl<N: Network, Instruction: InstructionTrait<N>, Command: CommandTrait<N>> Fun
     pub const fn finalize_logic(&self) -> Option<&FinalizeCore<N, Command>> {
         self.finalize_logic.as_ref()
     }
+
+    /// Returns the ABI hash of the function, which is a hash of its name, input types, and output types.
+    /// The ABI hash only changes if the interface of the function changes, and is independent of its logic.
+    pub fn abi_hash(&self) -> Result<Field<N>> {
+        // Construct the preimage from the name, input types, and output types.
+        let mut preimage = self.name.to_bytes_le()?;
+        u8::try_from(self.inputs.len())?.write_le(&mut preimage)?;
+        for input in &self.inputs {
+            input.value_type().write_le(&mut preimage)?;
+        }
+        u8::try_from(self.outputs.len())?.write_le(&mut preimage)?;
+        for output in &self.outputs {
+            output.value_type().write_le(&mut preimage)?;
+        }
+        // Hash the preimage.
+        N::hash_bhp1024(&preimage.to_bits_le())
+    }
 }
 
 impl<N: Network, Instruction: InstructionTrait<N>, Command: CommandTrait<N>> FunctionCore<N, Instruction, Command> {
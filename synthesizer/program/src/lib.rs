@@ -80,6 +80,7 @@ use console::{
         Sanitizer,
         Serialize,
         Serializer,
+        ToBits,
         ToBytes,
         ToBytesSerializer,
         TypeName,
@@ -90,6 +91,10 @@ use console::{
 
 use indexmap::IndexMap;
 
+/// The consensus version from which a program string must import every external program that it references.
+/// Note: Programs deployed before this version may reference external programs that they do not import.
+pub const IMPORTED_REFERENCES_CONSENSUS_VERSION: u16 = 1;
+
 #[derive(Copy, Clone, PartialEq, Eq, Hash)]
 enum ProgramDefinition {
     /// A program mapping.
@@ -284,6 +289,17 @@ impl<N: Network, Instruction: InstructionTrait<N>, Command: CommandTrait<N>> Pro
         // Return the function.
         Ok(function)
     }
+
+    /// Returns the ABI hash of the function with the given name.
+    pub fn get_function_abi_hash(&self, name: &Identifier<N>) -> Result<Field<N>> {
+        self.get_function_ref(name)?.abi_hash()
+    }
+
+    /// Returns the checksum of the program, which is the hash of its bytecode.
+    /// An import of this program may be pinned to this checksum in the import manifest of a deployment.
+    pub fn to_checksum(&self) -> Result<Field<N>> {
+        N::hash_bhp1024(&self.to_bytes_le()?.to_bits_le())
+    }
 }
 
 impl<N: Network, Instruction: InstructionTrait<N>, Command: CommandTrait<N>> ProgramCore<N, Instruction, Command> {
@@ -700,6 +716,45 @@ mapping message:
         Ok(())
     }
 
+    #[test]
+    fn test_program_function_abi_hash() -> Result<()> {
+        let name = Identifier::from_str("compute")?;
+
+        // Initialize the programs.
+        let program = Program::<CurrentNetwork>::from_str(
+            r"program abi.aleo;
+function compute:
+    input r0 as u32.private;
+    add r0 r0 into r1;
+    output r1 as u32.public;",
+        )?;
+        let different_logic = Program::<CurrentNetwork>::from_str(
+            r"program abi.aleo;
+function compute:
+    input r0 as u32.private;
+    mul r0 r0 into r1;
+    output r1 as u32.public;",
+        )?;
+        let different_mode = Program::<CurrentNetwork>::from_str(
+            r"program abi.aleo;
+function compute:
+    input r0 as u32.public;
+    add r0 r0 into r1;
+    output r1 as u32.public;",
+        )?;
+
+        // Ensure the ABI hash is deterministic, and independent of the function logic.
+        let abi_hash = program.get_function_abi_hash(&name)?;
+        assert_eq!(abi_hash, program.get_function_abi_hash(&name)?);
+        assert_eq!(abi_hash, different_logic.get_function_abi_hash(&name)?);
+        // Ensure the ABI hash changes with the interface.
+        assert_ne!(abi_hash, different_mode.get_function_abi_hash(&name)?);
+        // Ensure the ABI hash of a missing function fails.
+        assert!(program.get_function_abi_hash(&Identifier::from_str("missing")?).is_err());
+
+        Ok(())
+    }
+
     #[test]
     fn test_program_struct() -> Result<()> {
         // Create a new struct.
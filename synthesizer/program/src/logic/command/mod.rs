@@ -41,6 +41,7 @@ mod set;
 pub use set::*;
 
 use crate::{
+    logic::instruction::operand_program_ids,
     traits::{
         CommandTrait,
         FinalizeStoreTrait,
@@ -50,14 +51,16 @@ use crate::{
         StackMatches,
         StackProgram,
     },
+    CallOperator,
     CastType,
     FinalizeOperation,
     FinalizeRegistersState,
     Instruction,
+    Operand,
 };
 use console::{
     network::prelude::*,
-    program::{Identifier, Register},
+    program::{Identifier, ProgramID, Register},
 };
 
 #[derive(Clone, PartialEq, Eq, Hash)]
@@ -143,6 +146,36 @@ impl<N: Network> CommandTrait<N> for Command<N> {
     fn is_write(&self) -> bool {
         matches!(self, Command::Set(_) | Command::Remove(_))
     }
+
+    /// Returns the program IDs referenced by the command, in its operands or external mapping.
+    #[inline]
+    fn program_ids(&self) -> Vec<ProgramID<N>> {
+        // A helper to retrieve the program IDs in the given mapping and operands.
+        let mapping_program_ids = |mapping: &CallOperator<N>, operands: &[Operand<N>]| {
+            let mut program_ids = operand_program_ids(operands);
+            if let CallOperator::Locator(locator) = mapping {
+                program_ids.push(*locator.program_id());
+            }
+            program_ids
+        };
+
+        match self {
+            Command::Instruction(instruction) => instruction.program_ids(),
+            Command::Contains(contains) => mapping_program_ids(contains.mapping(), &contains.operands()),
+            Command::Get(get) => mapping_program_ids(get.mapping(), &get.operands()),
+            Command::GetOrUse(get_or_use) => mapping_program_ids(get_or_use.mapping(), &get_or_use.operands()),
+            Command::RandChaCha(rand_chacha) => operand_program_ids(&rand_chacha.operands()),
+            Command::Remove(remove) => operand_program_ids(&remove.operands()),
+            Command::Set(set) => operand_program_ids(&set.operands()),
+            Command::BranchEq(branch_eq) => {
+                operand_program_ids(&[branch_eq.first().clone(), branch_eq.second().clone()])
+            }
+            Command::BranchNeq(branch_neq) => {
+                operand_program_ids(&[branch_neq.first().clone(), branch_neq.second().clone()])
+            }
+            Command::Await(_) | Command::Position(_) => vec![],
+        }
+    }
 }
 
 impl<N: Network> Command<N> {
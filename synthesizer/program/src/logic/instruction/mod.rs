@@ -61,7 +61,7 @@ use console::{
         ToBytes,
         Write,
     },
-    program::{Register, RegisterType},
+    program::{ProgramID, Register, RegisterType},
 };
 
 #[derive(Clone, PartialEq, Eq, Hash)]
@@ -202,6 +202,21 @@ pub enum Instruction<N: Network> {
     Ternary(Ternary<N>),
     /// Performs a bitwise `xor` on `first` and `second`, storing the outcome in `destination`.
     Xor(Xor<N>),
+    /// Performs an RFC 9380 hash-to-curve on inputs, with `expand_message_xmd` over SHA3-256.
+    /// Note: This instruction is appended after `Xor`, as the opcode index is serialized.
+    HashToCurveSha3_256(HashToCurveSha3_256<N>),
+    /// Verifies that a BHP commitment on 256-bit chunks opens to the given input and randomizer.
+    CommitVerifyBHP256(CommitVerifyBHP256<N>),
+    /// Verifies that a BHP commitment on 512-bit chunks opens to the given input and randomizer.
+    CommitVerifyBHP512(CommitVerifyBHP512<N>),
+    /// Verifies that a BHP commitment on 768-bit chunks opens to the given input and randomizer.
+    CommitVerifyBHP768(CommitVerifyBHP768<N>),
+    /// Verifies that a BHP commitment on 1024-bit chunks opens to the given input and randomizer.
+    CommitVerifyBHP1024(CommitVerifyBHP1024<N>),
+    /// Verifies that a Pedersen commitment on up to a 64-bit input opens to the given input and randomizer.
+    CommitVerifyPED64(CommitVerifyPED64<N>),
+    /// Verifies that a Pedersen commitment on up to a 128-bit input opens to the given input and randomizer.
+    CommitVerifyPED128(CommitVerifyPED128<N>),
 }
 
 /// Creates a match statement that applies the given operation for each instruction.
@@ -301,6 +316,13 @@ macro_rules! instruction {
             SubWrapped,
             Ternary,
             Xor,
+            HashToCurveSha3_256,
+            CommitVerifyBHP256,
+            CommitVerifyBHP512,
+            CommitVerifyBHP768,
+            CommitVerifyBHP1024,
+            CommitVerifyPED64,
+            CommitVerifyPED128,
         }}
     };
     // A variant **without** curly braces:
@@ -386,6 +408,44 @@ impl<N: Network> InstructionTrait<N> for Instruction<N> {
         // Check if the given name matches any opcode (in its entirety; including past the first '.' if it exists).
         Instruction::<N>::OPCODES.iter().any(|opcode| **opcode == name)
     }
+
+    /// Returns the program IDs referenced by the instruction, in its operands, call operator, or cast type.
+    #[inline]
+    fn program_ids(&self) -> Vec<ProgramID<N>> {
+        // Retrieve the program IDs in the operands.
+        let mut program_ids = operand_program_ids(self.operands());
+        // Retrieve the program ID of an external call or an external record cast.
+        match self {
+            Self::Call(call) => {
+                if let CallOperator::Locator(locator) = call.operator() {
+                    program_ids.push(*locator.program_id());
+                }
+            }
+            Self::Cast(cast) => {
+                if let CastType::ExternalRecord(locator) = cast.cast_type() {
+                    program_ids.push(*locator.program_id());
+                }
+            }
+            Self::CastLossy(cast) => {
+                if let CastType::ExternalRecord(locator) = cast.cast_type() {
+                    program_ids.push(*locator.program_id());
+                }
+            }
+            _ => (),
+        }
+        program_ids
+    }
+}
+
+/// Returns the program IDs in the given operands.
+pub(crate) fn operand_program_ids<N: Network>(operands: &[Operand<N>]) -> Vec<ProgramID<N>> {
+    operands
+        .iter()
+        .filter_map(|operand| match operand {
+            Operand::ProgramID(program_id) => Some(*program_id),
+            _ => None,
+        })
+        .collect()
 }
 
 impl<N: Network> Instruction<N> {
@@ -470,7 +530,7 @@ mod tests {
     fn test_opcodes() {
         // Sanity check the number of instructions is unchanged.
         assert_eq!(
-            68,
+            75,
             Instruction::<CurrentNetwork>::OPCODES.len(),
             "Update me if the number of instructions changes."
         );
@@ -0,0 +1,353 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use crate::{
+    traits::{RegistersLoad, RegistersLoadCircuit, RegistersStore, RegistersStoreCircuit, StackMatches, StackProgram},
+    Opcode,
+    Operand,
+};
+use console::{
+    network::prelude::*,
+    program::{Literal, LiteralType, PlaintextType, Register, RegisterType},
+    types::Boolean,
+};
+
+/// BHP256 is a collision-resistant function that processes inputs in 256-bit chunks.
+pub type CommitVerifyBHP256<N> = CommitVerifyInstruction<N, { CommitVerifier::CommitVerifyBHP256 as u8 }>;
+/// BHP512 is a collision-resistant function that processes inputs in 512-bit chunks.
+pub type CommitVerifyBHP512<N> = CommitVerifyInstruction<N, { CommitVerifier::CommitVerifyBHP512 as u8 }>;
+/// BHP768 is a collision-resistant function that processes inputs in 768-bit chunks.
+pub type CommitVerifyBHP768<N> = CommitVerifyInstruction<N, { CommitVerifier::CommitVerifyBHP768 as u8 }>;
+/// BHP1024 is a collision-resistant function that processes inputs in 1024-bit chunks.
+pub type CommitVerifyBHP1024<N> = CommitVerifyInstruction<N, { CommitVerifier::CommitVerifyBHP1024 as u8 }>;
+
+/// Pedersen64 is a collision-resistant function that processes inputs in 64-bit chunks.
+pub type CommitVerifyPED64<N> = CommitVerifyInstruction<N, { CommitVerifier::CommitVerifyPED64 as u8 }>;
+/// Pedersen128 is a collision-resistant function that processes inputs in 128-bit chunks.
+pub type CommitVerifyPED128<N> = CommitVerifyInstruction<N, { CommitVerifier::CommitVerifyPED128 as u8 }>;
+
+enum CommitVerifier {
+    CommitVerifyBHP256,
+    CommitVerifyBHP512,
+    CommitVerifyBHP768,
+    CommitVerifyBHP1024,
+    CommitVerifyPED64,
+    CommitVerifyPED128,
+}
+
+/// Returns 'true' if the commitment type is valid.
+fn is_valid_commitment_type(commitment_type: LiteralType) -> bool {
+    matches!(commitment_type, LiteralType::Address | LiteralType::Field | LiteralType::Group)
+}
+
+/// Computes whether `commitment` opens to the given `input` and `randomizer`.
+///
+/// The commitment is recomputed as in the corresponding `commit` instruction,
+/// and cast to the type of the given commitment, before it is compared.
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct CommitVerifyInstruction<N: Network, const VARIANT: u8> {
+    /// The operands as `input`, `randomizer`, and `commitment`.
+    operands: Vec<Operand<N>>,
+    /// The destination register.
+    destination: Register<N>,
+}
+
+impl<N: Network, const VARIANT: u8> CommitVerifyInstruction<N, VARIANT> {
+    /// Initializes a new `commit.verify` instruction.
+    #[inline]
+    pub fn new(operands: Vec<Operand<N>>, destination: Register<N>) -> Result<Self> {
+        // Sanity check the number of operands.
+        ensure!(operands.len() == 3, "Instruction '{}' must have three operands", Self::opcode());
+        // Return the instruction.
+        Ok(Self { operands, destination })
+    }
+
+    /// Returns the opcode.
+    #[inline]
+    pub const fn opcode() -> Opcode {
+        match VARIANT {
+            0 => Opcode::Commit("commit.verify.bhp256"),
+            1 => Opcode::Commit("commit.verify.bhp512"),
+            2 => Opcode::Commit("commit.verify.bhp768"),
+            3 => Opcode::Commit("commit.verify.bhp1024"),
+            4 => Opcode::Commit("commit.verify.ped64"),
+            5 => Opcode::Commit("commit.verify.ped128"),
+            6.. => panic!("Invalid 'commit.verify' instruction opcode"),
+        }
+    }
+
+    /// Returns the operands in the operation.
+    #[inline]
+    pub fn operands(&self) -> &[Operand<N>] {
+        // Sanity check that there are exactly three operands.
+        debug_assert!(self.operands.len() == 3, "Instruction '{}' must have three operands", Self::opcode());
+        // Return the operands.
+        &self.operands
+    }
+
+    /// Returns the destination register.
+    #[inline]
+    pub fn destinations(&self) -> Vec<Register<N>> {
+        vec![self.destination.clone()]
+    }
+}
+
+impl<N: Network, const VARIANT: u8> CommitVerifyInstruction<N, VARIANT> {
+    /// Evaluates the instruction.
+    #[inline]
+    pub fn evaluate(
+        &self,
+        stack: &(impl StackMatches<N> + StackProgram<N>),
+        registers: &mut (impl RegistersLoad<N> + RegistersStore<N>),
+    ) -> Result<()> {
+        // Ensure the number of operands is correct.
+        if self.operands.len() != 3 {
+            bail!("Instruction '{}' expects 3 operands, found {} operands", Self::opcode(), self.operands.len())
+        }
+
+        // Retrieve the inputs.
+        let input = registers.load(stack, &self.operands[0])?;
+        let randomizer = match registers.load_literal(stack, &self.operands[1])? {
+            Literal::Scalar(randomizer) => randomizer,
+            _ => bail!("Expected the second operand to be a scalar."),
+        };
+        let commitment = registers.load_literal(stack, &self.operands[2])?;
+        ensure!(is_valid_commitment_type(commitment.to_type()), "Expected the third operand to be a commitment.");
+
+        // Recompute the commitment.
+        let candidate = match VARIANT {
+            0 => Literal::Group(N::commit_to_group_bhp256(&input.to_bits_le(), &randomizer)?),
+            1 => Literal::Group(N::commit_to_group_bhp512(&input.to_bits_le(), &randomizer)?),
+            2 => Literal::Group(N::commit_to_group_bhp768(&input.to_bits_le(), &randomizer)?),
+            3 => Literal::Group(N::commit_to_group_bhp1024(&input.to_bits_le(), &randomizer)?),
+            4 => Literal::Group(N::commit_to_group_ped64(&input.to_bits_le(), &randomizer)?),
+            5 => Literal::Group(N::commit_to_group_ped128(&input.to_bits_le(), &randomizer)?),
+            6.. => bail!("Invalid 'commit.verify' variant: {VARIANT}"),
+        };
+        // Cast the recomputed commitment to the type of the given commitment, and compare them.
+        let output = Literal::Boolean(Boolean::new(candidate.cast_lossy(commitment.to_type())? == commitment));
+
+        // Store the output.
+        registers.store_literal(stack, &self.destination, output)
+    }
+
+    /// Executes the instruction.
+    #[inline]
+    pub fn execute<A: circuit::Aleo<Network = N>>(
+        &self,
+        stack: &(impl StackMatches<N> + StackProgram<N>),
+        registers: &mut (impl RegistersLoadCircuit<N, A> + RegistersStoreCircuit<N, A>),
+    ) -> Result<()> {
+        use circuit::traits::{Equal, ToBits};
+
+        // Ensure the number of operands is correct.
+        if self.operands.len() != 3 {
+            bail!("Instruction '{}' expects 3 operands, found {} operands", Self::opcode(), self.operands.len())
+        }
+
+        // Retrieve the inputs.
+        let input = registers.load_circuit(stack, &self.operands[0])?;
+        let randomizer = match registers.load_literal_circuit(stack, &self.operands[1])? {
+            circuit::Literal::Scalar(randomizer) => randomizer,
+            _ => bail!("Expected the second operand to be a scalar."),
+        };
+        let commitment = registers.load_literal_circuit(stack, &self.operands[2])?;
+        ensure!(is_valid_commitment_type(commitment.to_type()), "Expected the third operand to be a commitment.");
+
+        // Recompute the commitment.
+        let candidate = match VARIANT {
+            0 => circuit::Literal::Group(A::commit_to_group_bhp256(&input.to_bits_le(), &randomizer)),
+            1 => circuit::Literal::Group(A::commit_to_group_bhp512(&input.to_bits_le(), &randomizer)),
+            2 => circuit::Literal::Group(A::commit_to_group_bhp768(&input.to_bits_le(), &randomizer)),
+            3 => circuit::Literal::Group(A::commit_to_group_bhp1024(&input.to_bits_le(), &randomizer)),
+            4 => circuit::Literal::Group(A::commit_to_group_ped64(&input.to_bits_le(), &randomizer)),
+            5 => circuit::Literal::Group(A::commit_to_group_ped128(&input.to_bits_le(), &randomizer)),
+            6.. => bail!("Invalid 'commit.verify' variant: {VARIANT}"),
+        };
+        // Cast the recomputed commitment to the type of the given commitment, and compare them.
+        let output = circuit::Literal::Boolean(candidate.cast_lossy(commitment.to_type())?.is_equal(&commitment));
+
+        // Store the output.
+        registers.store_literal_circuit(stack, &self.destination, output)
+    }
+
+    /// Finalizes the instruction.
+    #[inline]
+    pub fn finalize(
+        &self,
+        stack: &(impl StackMatches<N> + StackProgram<N>),
+        registers: &mut (impl RegistersLoad<N> + RegistersStore<N>),
+    ) -> Result<()> {
+        self.evaluate(stack, registers)
+    }
+
+    /// Returns the output type from the given program and input types.
+    #[inline]
+    pub fn output_types(
+        &self,
+        _stack: &impl StackProgram<N>,
+        input_types: &[RegisterType<N>],
+    ) -> Result<Vec<RegisterType<N>>> {
+        // Ensure the number of input types is correct.
+        if input_types.len() != 3 {
+            bail!("Instruction '{}' expects 3 inputs, found {} inputs", Self::opcode(), input_types.len())
+        }
+
+        // Ensure the second operand is a scalar.
+        if input_types[1] != RegisterType::Plaintext(PlaintextType::Literal(LiteralType::Scalar)) {
+            bail!(
+                "Instruction '{}' expects the second input to be a 'scalar'. Found input of type '{}'",
+                Self::opcode(),
+                input_types[1]
+            )
+        }
+
+        // Ensure the third operand is an address, field, or group.
+        match &input_types[2] {
+            RegisterType::Plaintext(PlaintextType::Literal(literal_type))
+                if is_valid_commitment_type(*literal_type) => {}
+            _ => bail!(
+                "Instruction '{}' expects the third input to be an 'address', 'field', or 'group'. Found input of type '{}'",
+                Self::opcode(),
+                input_types[2]
+            ),
+        }
+
+        match VARIANT {
+            0..=5 => Ok(vec![RegisterType::Plaintext(PlaintextType::Literal(LiteralType::Boolean))]),
+            6.. => bail!("Invalid 'commit.verify' variant: {VARIANT}"),
+        }
+    }
+}
+
+impl<N: Network, const VARIANT: u8> Parser for CommitVerifyInstruction<N, VARIANT> {
+    /// Parses a string into an operation.
+    #[inline]
+    fn parse(string: &str) -> ParserResult<Self> {
+        // Parse the opcode from the string.
+        let (string, _) = tag(*Self::opcode())(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the first operand from the string.
+        let (string, first) = Operand::parse(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the second operand from the string.
+        let (string, second) = Operand::parse(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the third operand from the string.
+        let (string, third) = Operand::parse(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the "into" from the string.
+        let (string, _) = tag("into")(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the destination register from the string.
+        let (string, destination) = Register::parse(string)?;
+
+        Ok((string, Self { operands: vec![first, second, third], destination }))
+    }
+}
+
+impl<N: Network, const VARIANT: u8> FromStr for CommitVerifyInstruction<N, VARIANT> {
+    type Err = Error;
+
+    /// Parses a string into an operation.
+    #[inline]
+    fn from_str(string: &str) -> Result<Self> {
+        match Self::parse(string) {
+            Ok((remainder, object)) => {
+                // Ensure the remainder is empty.
+                ensure!(remainder.is_empty(), "Failed to parse string. Found invalid character in: \"{remainder}\"");
+                // Return the object.
+                Ok(object)
+            }
+            Err(error) => bail!("Failed to parse string. {error}"),
+        }
+    }
+}
+
+impl<N: Network, const VARIANT: u8> Debug for CommitVerifyInstruction<N, VARIANT> {
+    /// Prints the operation as a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+impl<N: Network, const VARIANT: u8> Display for CommitVerifyInstruction<N, VARIANT> {
+    /// Prints the operation to a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        // Ensure the number of operands is 3.
+        if self.operands.len() != 3 {
+            return Err(fmt::Error);
+        }
+        // Print the operation.
+        write!(f, "{} ", Self::opcode())?;
+        self.operands.iter().try_for_each(|operand| write!(f, "{operand} "))?;
+        write!(f, "into {}", self.destination)
+    }
+}
+
+impl<N: Network, const VARIANT: u8> FromBytes for CommitVerifyInstruction<N, VARIANT> {
+    /// Reads the operation from a buffer.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Read the operands.
+        let operands = (0..3).map(|_| Operand::read_le(&mut reader)).collect::<Result<_, _>>()?;
+        // Read the destination register.
+        let destination = Register::read_le(&mut reader)?;
+
+        // Return the operation.
+        Self::new(operands, destination).map_err(error)
+    }
+}
+
+impl<N: Network, const VARIANT: u8> ToBytes for CommitVerifyInstruction<N, VARIANT> {
+    /// Writes the operation to a buffer.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Ensure the number of operands is 3.
+        if self.operands.len() != 3 {
+            return Err(error(format!("The number of operands must be 3, found {}", self.operands.len())));
+        }
+        // Write the operands.
+        self.operands.iter().try_for_each(|operand| operand.write_le(&mut writer))?;
+        // Write the destination register.
+        self.destination.write_le(&mut writer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::CommitBHP512;
+    use console::network::MainnetV0;
+
+    type CurrentNetwork = MainnetV0;
+
+    #[test]
+    fn test_parse() {
+        let (string, commit) =
+            CommitVerifyBHP512::<CurrentNetwork>::parse("commit.verify.bhp512 r0 r1 r2 into r3").unwrap();
+        assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+        assert_eq!(commit.operands.len(), 3, "The number of operands is incorrect");
+        assert_eq!(commit.operands[0], Operand::Register(Register::Locator(0)), "The first operand is incorrect");
+        assert_eq!(commit.operands[1], Operand::Register(Register::Locator(1)), "The second operand is incorrect");
+        assert_eq!(commit.operands[2], Operand::Register(Register::Locator(2)), "The third operand is incorrect");
+        assert_eq!(commit.destination, Register::Locator(3), "The destination register is incorrect");
+
+        // Ensure the `commit` instruction does not parse the `commit.verify` instruction.
+        assert!(CommitBHP512::<CurrentNetwork>::parse("commit.verify.bhp512 r0 r1 r2 into r3").is_err());
+    }
+}
@@ -28,6 +28,9 @@ pub use cast::*;
 mod commit;
 pub use commit::*;
 
+mod commit_verify;
+pub use commit_verify::*;
+
 mod hash;
 pub use hash::*;
 
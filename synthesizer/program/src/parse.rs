@@ -14,24 +14,105 @@
 // limitations under the License.
 
 use super::*;
+use console::{
+    network::prelude::ParseError,
+    program::{FinalizeType, ProgramParseError, RegisterType, ValueType},
+};
+
+use indexmap::IndexSet;
+
+/// A component of a program.
+enum ProgramComponent<N: Network, Instruction: InstructionTrait<N>, Command: CommandTrait<N>> {
+    M(Mapping<N>),
+    I(StructType<N>),
+    R(RecordType<N>),
+    C(ClosureCore<N, Instruction>),
+    F(FunctionCore<N, Instruction, Command>),
+}
 
-impl<N: Network, Instruction: InstructionTrait<N>, Command: CommandTrait<N>> Parser
-    for ProgramCore<N, Instruction, Command>
-{
-    /// Parses a string into a program.
-    #[inline]
-    fn parse(string: &str) -> ParserResult<Self> {
-        // A helper to parse a program.
-        enum P<N: Network, Instruction: InstructionTrait<N>, Command: CommandTrait<N>> {
-            M(Mapping<N>),
-            I(StructType<N>),
-            R(RecordType<N>),
-            C(ClosureCore<N, Instruction>),
-            F(FunctionCore<N, Instruction, Command>),
+impl<N: Network, Instruction: InstructionTrait<N>, Command: CommandTrait<N>> ProgramComponent<N, Instruction, Command> {
+    /// Returns the name of the component.
+    fn name(&self) -> String {
+        match self {
+            Self::M(mapping) => mapping.name().to_string(),
+            Self::I(struct_) => struct_.name().to_string(),
+            Self::R(record) => record.name().to_string(),
+            Self::C(closure) => closure.name().to_string(),
+            Self::F(function) => function.name().to_string(),
+        }
+    }
+
+    /// Returns the program IDs referenced by the component, in its types, instructions, and commands.
+    fn program_ids(&self) -> Vec<ProgramID<N>> {
+        match self {
+            Self::M(..) | Self::I(..) | Self::R(..) => vec![],
+            Self::C(closure) => {
+                let types = closure
+                    .inputs()
+                    .iter()
+                    .map(|input| input.register_type())
+                    .chain(closure.outputs().iter().map(|output| output.register_type()));
+                types
+                    .filter_map(|register_type| match register_type {
+                        RegisterType::ExternalRecord(locator) | RegisterType::Future(locator) => {
+                            Some(*locator.program_id())
+                        }
+                        _ => None,
+                    })
+                    .chain(closure.instructions().iter().flat_map(|instruction| instruction.program_ids()))
+                    .collect()
+            }
+            Self::F(function) => {
+                let types = function
+                    .inputs()
+                    .iter()
+                    .map(|input| input.value_type())
+                    .chain(function.outputs().iter().map(|output| output.value_type()));
+                let finalize_types = function
+                    .finalize_logic()
+                    .into_iter()
+                    .flat_map(|finalize| finalize.inputs().iter().map(|input| input.finalize_type()));
+                let commands = function.finalize_logic().into_iter().flat_map(|finalize| finalize.commands().iter());
+                types
+                    .filter_map(|value_type| match value_type {
+                        ValueType::ExternalRecord(locator) | ValueType::Future(locator) => Some(*locator.program_id()),
+                        _ => None,
+                    })
+                    .chain(finalize_types.filter_map(|finalize_type| match finalize_type {
+                        FinalizeType::Future(locator) => Some(*locator.program_id()),
+                        _ => None,
+                    }))
+                    .chain(function.instructions().iter().flat_map(|instruction| instruction.program_ids()))
+                    .chain(commands.flat_map(|command| command.program_ids()))
+                    .collect()
+            }
         }
+    }
+}
+
+/// The program ID, imports, and components of a program, each paired with the string at its start.
+type ProgramParts<'a, N, Instruction, Command> =
+    ((&'a str, ProgramID<N>), Vec<(&'a str, Import<N>)>, Vec<(&'a str, ProgramComponent<N, Instruction, Command>)>);
+
+/// Returns a parser that skips the leading whitespace and comments,
+/// and pairs the output of the given parser with the string at its start.
+fn with_start<'a, O>(
+    mut parser: impl FnMut(&'a str) -> ParserResult<'a, O>,
+) -> impl FnMut(&'a str) -> ParserResult<'a, (&'a str, O)> {
+    move |string: &'a str| {
+        // Parse the whitespace and comments from the string.
+        let (string, _) = Sanitizer::parse(string)?;
+        // Parse the output from the string.
+        let (remainder, output) = parser(string)?;
+        Ok((remainder, (string, output)))
+    }
+}
 
+impl<N: Network, Instruction: InstructionTrait<N>, Command: CommandTrait<N>> ProgramCore<N, Instruction, Command> {
+    /// Parses the program ID, imports, and components of a program, without constructing the program.
+    fn parse_parts(string: &str) -> ParserResult<ProgramParts<N, Instruction, Command>> {
         // Parse the imports from the string.
-        let (string, imports) = many0(Import::parse)(string)?;
+        let (string, imports) = many0(with_start(Import::parse))(string)?;
         // Parse the whitespace and comments from the string.
         let (string, _) = Sanitizer::parse(string)?;
         // Parse the 'program' keyword from the string.
@@ -39,64 +120,121 @@ impl<N: Network, Instruction: InstructionTrait<N>, Command: CommandTrait<N>> Par
         // Parse the whitespace from the string.
         let (string, _) = Sanitizer::parse_whitespaces(string)?;
         // Parse the program ID from the string.
-        let (string, id) = ProgramID::parse(string)?;
+        let (string, id) = with_start(ProgramID::parse)(string)?;
         // Parse the whitespace from the string.
         let (string, _) = Sanitizer::parse_whitespaces(string)?;
         // Parse the semicolon ';' keyword from the string.
         let (string, _) = tag(";")(string)?;
 
         // Parse the struct or function from the string.
-        let (string, components) = many1(alt((
-            map(Mapping::parse, |mapping| P::<N, Instruction, Command>::M(mapping)),
-            map(StructType::parse, |struct_| P::<N, Instruction, Command>::I(struct_)),
-            map(RecordType::parse, |record| P::<N, Instruction, Command>::R(record)),
-            map(ClosureCore::parse, |closure| P::<N, Instruction, Command>::C(closure)),
-            map(FunctionCore::parse, |function| P::<N, Instruction, Command>::F(function)),
-        )))(string)?;
+        let (string, components) = many1(with_start(alt((
+            map(Mapping::parse, |mapping| ProgramComponent::<N, Instruction, Command>::M(mapping)),
+            map(StructType::parse, |struct_| ProgramComponent::<N, Instruction, Command>::I(struct_)),
+            map(RecordType::parse, |record| ProgramComponent::<N, Instruction, Command>::R(record)),
+            map(ClosureCore::parse, |closure| ProgramComponent::<N, Instruction, Command>::C(closure)),
+            map(FunctionCore::parse, |function| ProgramComponent::<N, Instruction, Command>::F(function)),
+        ))))(string)?;
         // Parse the whitespace and comments from the string.
         let (string, _) = Sanitizer::parse(string)?;
 
-        // Return the program.
-        map_res(take(0usize), move |_| {
-            // Initialize a new program.
-            let mut program = match ProgramCore::<N, Instruction, Command>::new(id) {
-                Ok(program) => program,
-                Err(error) => {
-                    eprintln!("{error}");
-                    return Err(error);
+        Ok((string, (id, imports, components)))
+    }
+
+    /// Constructs a program from its parsed parts, where `string` is the program string they were parsed from.
+    ///
+    /// If `check_imports` is set, each program ID that is referenced by a component must be imported,
+    /// or be the ID of the program itself.
+    /// Note: This is not checked when reading a program from bytes, as programs were deployed before
+    /// the check was introduced.
+    fn from_parts(
+        string: &str,
+        (id, imports, components): &ProgramParts<N, Instruction, Command>,
+        check_imports: bool,
+    ) -> Result<Self, ProgramParseError> {
+        // A helper to construct the error for the given part.
+        let declaration_error = |start: &str, name: String, error: Error| {
+            ProgramParseError::invalid_declaration(string, string.len().saturating_sub(start.len()), name, error)
+        };
+
+        // Initialize a new program.
+        let mut program = Self::new(id.1).map_err(|error| declaration_error(id.0, id.1.to_string(), error))?;
+        // Retrieve the imported program IDs.
+        let imported = imports.iter().map(|(_, import)| *import.program_id()).collect::<IndexSet<_>>();
+        // Construct the program with the parsed components.
+        for (start, component) in components.iter() {
+            // Ensure the program IDs referenced by the component are imported.
+            if check_imports {
+                let program_ids = component.program_ids();
+                if let Some(program_id) =
+                    program_ids.iter().find(|program_id| **program_id != id.1 && !imported.contains(*program_id))
+                {
+                    // Locate the first reference to the program ID in the component.
+                    let offset = start.find(&program_id.to_string()).unwrap_or_default();
+                    let position = string.len().saturating_sub(start.len()) + offset;
+                    return Err(ProgramParseError::unknown_import(string, position, program_id));
                 }
+            }
+
+            let result = match component {
+                ProgramComponent::M(mapping) => program.add_mapping(mapping.clone()),
+                ProgramComponent::I(struct_) => program.add_struct(struct_.clone()),
+                ProgramComponent::R(record) => program.add_record(record.clone()),
+                ProgramComponent::C(closure) => program.add_closure(closure.clone()),
+                ProgramComponent::F(function) => program.add_function(function.clone()),
             };
-            // Construct the program with the parsed components.
-            for component in components.iter() {
-                let result = match component {
-                    P::M(mapping) => program.add_mapping(mapping.clone()),
-                    P::I(struct_) => program.add_struct(struct_.clone()),
-                    P::R(record) => program.add_record(record.clone()),
-                    P::C(closure) => program.add_closure(closure.clone()),
-                    P::F(function) => program.add_function(function.clone()),
-                };
-
-                match result {
-                    Ok(_) => (),
-                    Err(error) => {
-                        eprintln!("{error}");
-                        return Err(error);
-                    }
-                }
+            result.map_err(|error| declaration_error(start, component.name(), error))?;
+        }
+        // Lastly, add the imports (if any) to the program.
+        for (start, import) in imports.iter() {
+            program
+                .add_import(import.clone())
+                .map_err(|error| declaration_error(start, import.program_id().to_string(), error))?;
+        }
+        // Output the program.
+        Ok(program)
+    }
+
+    /// Returns the furthest position (in bytes) in the program string that the component parsers reach
+    /// in the given remainder of the program string.
+    fn furthest_component_position(string: &str, remainder: &str) -> usize {
+        /// Returns the position reached by the given parser result.
+        fn position<O>(string: &str, result: ParserResult<O>) -> usize {
+            match result {
+                Ok((remainder, _)) => string.len().saturating_sub(remainder.len()),
+                Err(error) => ParseError::from_parser_error("program", string, error).position(),
             }
-            // Lastly, add the imports (if any) to the program.
-            for import in imports.iter() {
-                match program.add_import(import.clone()) {
-                    Ok(_) => (),
-                    Err(error) => {
-                        eprintln!("{error}");
-                        return Err(error);
-                    }
-                }
+        }
+
+        [
+            position(string, Mapping::<N>::parse(remainder)),
+            position(string, StructType::<N>::parse(remainder)),
+            position(string, RecordType::<N>::parse(remainder)),
+            position(string, ClosureCore::<N, Instruction>::parse(remainder)),
+            position(string, FunctionCore::<N, Instruction, Command>::parse(remainder)),
+        ]
+        .into_iter()
+        .max()
+        .unwrap_or_default()
+    }
+}
+
+impl<N: Network, Instruction: InstructionTrait<N>, Command: CommandTrait<N>> Parser
+    for ProgramCore<N, Instruction, Command>
+{
+    /// Parses a string into a program.
+    #[inline]
+    fn parse(string: &str) -> ParserResult<Self> {
+        // Parse the program ID, imports, and components from the string.
+        let (remainder, parts) = Self::parse_parts(string)?;
+
+        // Return the program.
+        map_res(take(0usize), move |_| match Self::from_parts(string, &parts, true) {
+            Ok(program) => Ok(program),
+            Err(error) => {
+                eprintln!("{error}");
+                Err(Error::from(error))
             }
-            // Output the program.
-            Ok::<_, Error>(program)
-        })(string)
+        })(remainder)
     }
 }
 
@@ -106,18 +244,47 @@ impl<N: Network, Instruction: InstructionTrait<N>, Command: CommandTrait<N>> Fro
     type Err = Error;
 
     /// Returns a program from a string literal.
+    ///
+    /// Each external program that is referenced by the program must be imported.
+    /// To parse a program that was deployed under an earlier consensus version,
+    /// use `ProgramCore::from_str_with_consensus_version` instead.
+    ///
+    /// On failure, the error is a `ProgramParseError`, with the span and the offending token of the failure.
     fn from_str(string: &str) -> Result<Self> {
+        Self::from_str_with_imports_check(string, true)
+    }
+}
+
+impl<N: Network, Instruction: InstructionTrait<N>, Command: CommandTrait<N>> ProgramCore<N, Instruction, Command> {
+    /// Returns a program from a string literal, under the given consensus version.
+    ///
+    /// From `IMPORTED_REFERENCES_CONSENSUS_VERSION`, each external program that is referenced
+    /// by the program must be imported.
+    pub fn from_str_with_consensus_version(string: &str, consensus_version: u16) -> Result<Self> {
+        Self::from_str_with_imports_check(string, consensus_version >= IMPORTED_REFERENCES_CONSENSUS_VERSION)
+    }
+
+    /// Returns a program from a string literal, and checks that its referenced programs are imported if specified.
+    fn from_str_with_imports_check(string: &str, check_imports: bool) -> Result<Self> {
         // Ensure the raw program string is less than MAX_PROGRAM_SIZE.
-        ensure!(string.len() <= N::MAX_PROGRAM_SIZE, "Program length exceeds N::MAX_PROGRAM_SIZE.");
+        if string.len() > N::MAX_PROGRAM_SIZE {
+            return Err(ProgramParseError::program_too_large(string, N::MAX_PROGRAM_SIZE).into());
+        }
 
-        match Self::parse(string) {
-            Ok((remainder, object)) => {
+        match Self::parse_parts(string) {
+            Ok((remainder, parts)) => {
                 // Ensure the remainder is empty.
-                ensure!(remainder.is_empty(), "Failed to parse string. Remaining invalid string is: \"{remainder}\"");
-                // Return the object.
-                Ok(object)
+                if !remainder.is_empty() {
+                    let position = Self::furthest_component_position(string, remainder);
+                    return Err(ProgramParseError::diagnose::<N>(string, position).into());
+                }
+                // Construct the program from its parts.
+                Self::from_parts(string, &parts, check_imports).map_err(Error::from)
+            }
+            Err(error) => {
+                let position = ParseError::from_parser_error("program", string, error).position();
+                Err(ProgramParseError::diagnose::<N>(string, position).into())
             }
-            Err(error) => bail!("Failed to parse string. {error}"),
         }
     }
 }
@@ -260,6 +427,90 @@ function compute:
         Ok(())
     }
 
+    #[test]
+    fn test_program_parse_error() {
+        // A helper to parse a program, and return its parse error.
+        let parse_error = |program: &str| {
+            let error = Program::<CurrentNetwork>::from_str(program).unwrap_err();
+            error.downcast::<ProgramParseError>().unwrap()
+        };
+
+        // Ensure a malformed type is reported at the type.
+        let error =
+            parse_error("program foo.aleo;\n\nfunction bar:\n    input r0 as u8.privat;\n    output r0 as u8.private;");
+        assert!(matches!(error, ProgramParseError::InvalidType { .. }), "{error}");
+        assert_eq!(error.token(), "u8.privat");
+        assert_eq!((error.span().line(), error.span().column()), (4, 17));
+
+        // Ensure a malformed register is reported at the register.
+        let error =
+            parse_error("program foo.aleo;\n\nfunction bar:\n    add 1u8 r1x into r0;\n    output r0 as u8.private;");
+        assert!(matches!(error, ProgramParseError::InvalidRegister { .. }), "{error}");
+        assert_eq!(error.token(), "r1x");
+        assert_eq!((error.span().line(), error.span().column()), (4, 13));
+
+        // Ensure a malformed program ID is reported at the program ID.
+        let error = parse_error("program foo;\n\nfunction bar:\n    add 1u8 1u8 into r0;");
+        assert!(matches!(error, ProgramParseError::InvalidProgramID { .. }), "{error}");
+        assert_eq!(error.token(), "foo");
+
+        // Ensure a duplicate declaration is reported at the declaration.
+        let error = parse_error(
+            "program foo.aleo;\n\nfunction bar:\n    add 1u8 1u8 into r0;\n\nfunction bar:\n    add 1u8 1u8 into r0;",
+        );
+        assert!(matches!(error, ProgramParseError::InvalidDeclaration { .. }), "{error}");
+        assert_eq!(error.token(), "bar");
+        assert_eq!((error.span().line(), error.span().column()), (6, 1));
+    }
+
+    #[test]
+    fn test_program_parse_unknown_import() {
+        // A helper to parse a program, and return its parse error.
+        let parse_error = |program: &str| {
+            let error = Program::<CurrentNetwork>::from_str(program).unwrap_err();
+            error.downcast::<ProgramParseError>().unwrap()
+        };
+
+        // Ensure an external call requires an import.
+        let function = "function bar:\n    call baz.aleo/qux 1u8 into r0;\n    output r0 as u8.private;";
+        let error = parse_error(&format!("program foo.aleo;\n\n{function}"));
+        assert_eq!(error.to_string(), "Program 'baz.aleo' is referenced at 4:10, but is not imported");
+        assert!(
+            Program::<CurrentNetwork>::from_str(&format!("import baz.aleo;\nprogram foo.aleo;\n\n{function}")).is_ok()
+        );
+
+        // Ensure a cast to an external record requires an import.
+        let function =
+            "function bar:\n    input r0 as address.private;\n    cast r0 1u64 into r1 as baz.aleo/token.record;";
+        let error = parse_error(&format!("program foo.aleo;\n\n{function}"));
+        assert!(matches!(error, ProgramParseError::UnknownImport { .. }), "{error}");
+        assert_eq!(error.token(), "baz.aleo");
+
+        // Ensure a program ID operand requires an import, unless it is the program itself.
+        let function = "function bar:\n    assert.eq self.caller baz.aleo;";
+        let error = parse_error(&format!("program foo.aleo;\n\n{function}"));
+        assert_eq!(error.to_string(), "Program 'baz.aleo' is referenced at 4:27, but is not imported");
+        let function = "function bar:\n    assert.eq self.caller foo.aleo;";
+        assert!(Program::<CurrentNetwork>::from_str(&format!("program foo.aleo;\n\n{function}")).is_ok());
+    }
+
+    #[test]
+    fn test_program_parse_unknown_import_before_activation() -> Result<()> {
+        let program =
+            "program foo.aleo;\n\nfunction bar:\n    call baz.aleo/qux 1u8 into r0;\n    output r0 as u8.private;";
+
+        // Ensure the program parses before the consensus version that requires imports.
+        let version = IMPORTED_REFERENCES_CONSENSUS_VERSION - 1;
+        let expected = Program::<CurrentNetwork>::from_str_with_consensus_version(program, version)?;
+        assert!(Program::<CurrentNetwork>::from_str_with_consensus_version(program, version + 1).is_err());
+        assert!(Program::<CurrentNetwork>::from_str(program).is_err());
+
+        // Ensure the program deserializes, as it may have been deployed before the consensus version.
+        let candidate: Program<CurrentNetwork> = serde_json::from_value(serde_json::Value::String(program.into()))?;
+        assert_eq!(expected, candidate);
+        Ok(())
+    }
+
     #[test]
     fn test_program_size() {
         // Define variable name for easy experimentation with program sizes.
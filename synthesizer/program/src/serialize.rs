@@ -31,9 +31,14 @@ impl<'de, N: Network, Instruction: InstructionTrait<N>, Command: CommandTrait<N>
     for ProgramCore<N, Instruction, Command>
 {
     /// Deserializes the program from a string or bytes.
+    ///
+    /// As with the bytes encoding, the string does not need to import the external programs it references,
+    /// so that programs deployed before `IMPORTED_REFERENCES_CONSENSUS_VERSION` can be deserialized.
     fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
         match deserializer.is_human_readable() {
-            true => FromStr::from_str(&String::deserialize(deserializer)?).map_err(de::Error::custom),
+            true => {
+                Self::from_str_with_consensus_version(&String::deserialize(deserializer)?, 0).map_err(de::Error::custom)
+            }
             false => FromBytesDeserializer::<Self>::deserialize_with_size_encoding(deserializer, "program"),
         }
     }
@@ -16,7 +16,7 @@
 use console::{
     network::Network,
     prelude::{FromBytes, Parser, ToBytes},
-    program::{Identifier, Register},
+    program::{Identifier, ProgramID, Register},
 };
 
 pub trait CommandTrait<N: Network>: Clone + Parser + FromBytes + ToBytes {
@@ -32,4 +32,6 @@ pub trait CommandTrait<N: Network>: Clone + Parser + FromBytes + ToBytes {
     fn is_cast_to_record(&self) -> bool;
     /// Returns `true` if the command is a write operation.
     fn is_write(&self) -> bool;
+    /// Returns the program IDs referenced by the command.
+    fn program_ids(&self) -> Vec<ProgramID<N>>;
 }
@@ -16,7 +16,7 @@
 use console::{
     network::Network,
     prelude::{FromBytes, Parser, ToBytes},
-    program::Register,
+    program::{ProgramID, Register},
 };
 
 pub trait InstructionTrait<N: Network>: Clone + Parser + FromBytes + ToBytes {
@@ -24,4 +24,6 @@ pub trait InstructionTrait<N: Network>: Clone + Parser + FromBytes + ToBytes {
     fn destinations(&self) -> Vec<Register<N>>;
     /// Returns `true` if the given name is a reserved opcode.
     fn is_reserved_opcode(name: &str) -> bool;
+    /// Returns the program IDs referenced by the instruction.
+    fn program_ids(&self) -> Vec<ProgramID<N>>;
 }
@@ -90,6 +90,9 @@ pub trait StackProgram<N: Network> {
     /// Returns a reference to the function with the given function name.
     fn get_function_ref(&self, function_name: &Identifier<N>) -> Result<&Function<N>>;
 
+    /// Returns the ABI hash of the function with the given function name.
+    fn get_function_abi_hash(&self, function_name: &Identifier<N>) -> Result<Field<N>>;
+
     /// Returns the expected number of calls for the given function name.
     fn get_number_of_calls(&self, function_name: &Identifier<N>) -> Result<usize>;
 
@@ -0,0 +1,214 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+include!("../helpers/macros.rs");
+
+use crate::helpers::sample::{sample_finalize_registers, sample_registers};
+
+use circuit::{AleoV0, Eject};
+use console::{
+    network::MainnetV0,
+    prelude::*,
+    program::{Identifier, Literal, LiteralType, Plaintext, Register, Value},
+    types::{Boolean, Scalar},
+};
+use snarkvm_synthesizer_program::{
+    CommitVerifyBHP1024,
+    CommitVerifyBHP256,
+    CommitVerifyBHP512,
+    CommitVerifyBHP768,
+    CommitVerifyInstruction,
+    CommitVerifyPED128,
+    CommitVerifyPED64,
+    Opcode,
+    Operand,
+    Program,
+    RegistersLoad,
+    RegistersLoadCircuit,
+};
+use synthesizer_process::{Process, Stack};
+
+type CurrentNetwork = MainnetV0;
+type CurrentAleo = AleoV0;
+
+const ITERATIONS: usize = 5;
+
+/// The valid commitment types.
+const COMMITMENT_TYPES: [LiteralType; 3] = [LiteralType::Address, LiteralType::Field, LiteralType::Group];
+
+/// Samples the stack. Note: Do not replicate this for real program use, it is insecure.
+#[allow(clippy::type_complexity)]
+fn sample_stack(
+    opcode: Opcode,
+    type_a: LiteralType,
+    commitment_type: LiteralType,
+    mode: circuit::Mode,
+) -> Result<(Stack<CurrentNetwork>, Vec<Operand<CurrentNetwork>>, Register<CurrentNetwork>)> {
+    // Initialize the opcode.
+    let opcode = opcode.to_string();
+
+    // Initialize the function name.
+    let function_name = Identifier::<CurrentNetwork>::from_str("run")?;
+
+    // Initialize the registers.
+    let r0 = Register::Locator(0);
+    let r1 = Register::Locator(1);
+    let r2 = Register::Locator(2);
+    let r3 = Register::Locator(3);
+
+    // Initialize the program.
+    let program = Program::from_str(&format!(
+        "program testing.aleo;
+            function {function_name}:
+                input {r0} as {type_a}.{mode};
+                input {r1} as scalar.{mode};
+                input {r2} as {commitment_type}.{mode};
+                {opcode} {r0} {r1} {r2} into {r3};
+                async {function_name} {r0} {r1} {r2} into r4;
+                output r4 as testing.aleo/{function_name}.future;
+
+            finalize {function_name}:
+                input {r0} as {type_a}.public;
+                input {r1} as scalar.public;
+                input {r2} as {commitment_type}.public;
+                {opcode} {r0} {r1} {r2} into {r3};
+        "
+    ))?;
+
+    // Initialize the operands.
+    let operands = vec![Operand::Register(r0), Operand::Register(r1), Operand::Register(r2)];
+
+    // Initialize the stack.
+    let stack = Stack::new(&Process::load()?, &program)?;
+
+    Ok((stack, operands, r3))
+}
+
+/// Checks that evaluation, execution, and finalization agree on whether the commitment opens to the input,
+/// and that the agreed output is `expected`.
+fn check_commit_verify<const VARIANT: u8>(
+    operation: impl FnOnce(
+        Vec<Operand<CurrentNetwork>>,
+        Register<CurrentNetwork>,
+    ) -> CommitVerifyInstruction<CurrentNetwork, VARIANT>,
+    input: &Literal<CurrentNetwork>,
+    randomizer: &Literal<CurrentNetwork>,
+    commitment: &Literal<CurrentNetwork>,
+    mode: circuit::Mode,
+    expected: bool,
+) {
+    let opcode = CommitVerifyInstruction::<CurrentNetwork, VARIANT>::opcode();
+    println!("Checking '{opcode}' for '{input}.{mode}' and '{commitment}'");
+
+    // Initialize the stack.
+    let (stack, operands, destination) = sample_stack(opcode, input.to_type(), commitment.to_type(), mode).unwrap();
+    // Initialize the operation.
+    let operation = operation(operands, destination.clone());
+    // Initialize the function name.
+    let function_name = Identifier::from_str("run").unwrap();
+    // Initialize a destination operand.
+    let destination_operand = Operand::Register(destination);
+
+    // Evaluate the operation.
+    let values = [(input, None), (randomizer, None), (commitment, None)];
+    let mut evaluate_registers = sample_registers(&stack, &function_name, &values).unwrap();
+    operation.evaluate(&stack, &mut evaluate_registers).unwrap();
+
+    // Execute the operation.
+    let values = [(input, Some(mode)), (randomizer, Some(mode)), (commitment, Some(mode))];
+    let mut execute_registers = sample_registers(&stack, &function_name, &values).unwrap();
+    operation.execute::<CurrentAleo>(&stack, &mut execute_registers).unwrap();
+
+    // Finalize the operation.
+    let mut finalize_registers =
+        sample_finalize_registers(&stack, &function_name, &[input, randomizer, commitment]).unwrap();
+    operation.finalize(&stack, &mut finalize_registers).unwrap();
+
+    // Retrieve the outputs.
+    let output_a = evaluate_registers.load(&stack, &destination_operand).unwrap();
+    let output_b = execute_registers.load_circuit(&stack, &destination_operand).unwrap();
+    let output_c = finalize_registers.load(&stack, &destination_operand).unwrap();
+
+    // Check that the outputs are consistent, and as expected.
+    let expected = Value::Plaintext(Plaintext::from(Literal::Boolean(Boolean::new(expected))));
+    assert_eq!(output_a, expected, "The result of the evaluation is incorrect");
+    assert_eq!(output_b.eject_value(), expected, "The result of the execution is incorrect");
+    assert_eq!(output_c, expected, "The result of the finalization is incorrect");
+    assert!(<CurrentAleo as circuit::Environment>::is_satisfied(), "The circuit is not satisfied");
+
+    // Reset the circuit.
+    <CurrentAleo as circuit::Environment>::reset();
+}
+
+macro_rules! test_commit_verify {
+    ($name:tt, $commit_verify:ident, $commit_to_group:ident, |$rng:ident| $literals:expr) => {
+        paste::paste! {
+            #[test]
+            fn [<test _ $name _ is _ consistent>]() {
+                // Initialize the operation.
+                let operation = |operands, destination| $commit_verify::<CurrentNetwork>::new(operands, destination).unwrap();
+
+                // Prepare the rng.
+                let $rng = &mut TestRng::default();
+
+                for _ in 0..ITERATIONS {
+                    for input in $literals {
+                        // Sample the randomizer, and commit to the input, skipping unsupported inputs.
+                        let randomizer = Scalar::rand($rng);
+                        let preimage = Value::Plaintext(Plaintext::from(&input)).to_bits_le();
+                        let Ok(commitment) = CurrentNetwork::$commit_to_group(&preimage, &randomizer) else { continue };
+                        let commitment = Literal::Group(commitment);
+                        let wrong_randomizer = Literal::Scalar(randomizer + Scalar::one());
+                        let randomizer = Literal::Scalar(randomizer);
+
+                        for mode in [circuit::Mode::Public, circuit::Mode::Private] {
+                            for commitment_type in COMMITMENT_TYPES {
+                                let commitment = commitment.cast_lossy(commitment_type).unwrap();
+                                // Ensure the commitment opens to the input and randomizer.
+                                check_commit_verify(operation, &input, &randomizer, &commitment, mode, true);
+                                // Ensure the commitment does not open to a different randomizer.
+                                check_commit_verify(operation, &input, &wrong_randomizer, &commitment, mode, false);
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    };
+}
+
+test_commit_verify!(commit_verify_bhp256, CommitVerifyBHP256, commit_to_group_bhp256, |rng| sample_literals!(
+    CurrentNetwork,
+    rng
+));
+test_commit_verify!(commit_verify_bhp512, CommitVerifyBHP512, commit_to_group_bhp512, |rng| sample_literals!(
+    CurrentNetwork,
+    rng
+));
+test_commit_verify!(commit_verify_bhp768, CommitVerifyBHP768, commit_to_group_bhp768, |rng| sample_literals!(
+    CurrentNetwork,
+    rng
+));
+test_commit_verify!(commit_verify_bhp1024, CommitVerifyBHP1024, commit_to_group_bhp1024, |rng| sample_literals!(
+    CurrentNetwork,
+    rng
+));
+test_commit_verify!(commit_verify_ped64, CommitVerifyPED64, commit_to_group_ped64, |rng| sample_literals!(
+    CurrentNetwork,
+    rng
+));
+test_commit_verify!(commit_verify_ped128, CommitVerifyPED128, commit_to_group_ped128, |rng| sample_literals!(
+    CurrentNetwork,
+    rng
+));
@@ -15,5 +15,6 @@
 
 mod assert;
 mod commit;
+mod commit_verify;
 mod hash;
 mod is;
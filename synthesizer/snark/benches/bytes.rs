@@ -0,0 +1,87 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#[macro_use]
+extern crate criterion;
+
+use circuit::{
+    environment::{Circuit, Environment, Inject, Mode},
+    types::Field,
+};
+use console::{
+    network::{prelude::*, MainnetV0},
+    types::Field as ConsoleField,
+};
+use snarkvm_synthesizer_snark::{Certificate, Compress, Proof, UniversalSRS, Validate, VerifyingKey};
+
+use criterion::Criterion;
+
+type CurrentNetwork = MainnetV0;
+
+/// Returns a sample verifying key, certificate, and proof.
+fn sample() -> (VerifyingKey<CurrentNetwork>, Certificate<CurrentNetwork>, Proof<CurrentNetwork>) {
+    // Synthesize a small circuit.
+    let two = ConsoleField::<CurrentNetwork>::one() + ConsoleField::one();
+    let mut candidate = Field::<Circuit>::new(Mode::Public, two);
+    for _ in 0..64 {
+        candidate *= Field::new(Mode::Private, two);
+    }
+    let assignment = Circuit::eject_assignment_and_reset();
+
+    // Compute the keys, certificate, and proof.
+    let srs = UniversalSRS::<CurrentNetwork>::load().unwrap();
+    let (proving_key, verifying_key) = srs.to_circuit_key("bench", &assignment).unwrap();
+    let certificate = Certificate::certify("bench", &proving_key, &verifying_key).unwrap();
+    let proof = proving_key.prove("bench", &assignment, &mut TestRng::default()).unwrap();
+    (verifying_key, certificate, proof)
+}
+
+/// Benchmarks reading the given object with each combination of compression and validation modes.
+macro_rules! bench_modes {
+    ($c:expr, $name:expr, $object:expr, $type:ty) => {{
+        for (compress, compress_name) in [(Compress::Yes, "compressed"), (Compress::No, "uncompressed")] {
+            // Serialize the object.
+            let mut bytes = Vec::new();
+            $object.write_le_with_mode(&mut bytes, compress).unwrap();
+
+            $c.bench_function(&format!("{} write ({compress_name})", $name), |b| {
+                b.iter(|| {
+                    let mut buffer = Vec::with_capacity(bytes.len());
+                    $object.write_le_with_mode(&mut buffer, compress).unwrap()
+                })
+            });
+            for (validate, validate_name) in [(Validate::Yes, "validated"), (Validate::No, "unvalidated")] {
+                $c.bench_function(&format!("{} read ({compress_name}, {validate_name})", $name), |b| {
+                    b.iter(|| <$type>::read_le_with_mode(&bytes[..], compress, validate).unwrap())
+                });
+            }
+        }
+    }};
+}
+
+fn bytes(c: &mut Criterion) {
+    let (verifying_key, certificate, proof) = sample();
+
+    bench_modes!(c, "VerifyingKey", verifying_key, VerifyingKey<CurrentNetwork>);
+    bench_modes!(c, "Certificate", certificate, Certificate<CurrentNetwork>);
+    bench_modes!(c, "Proof", proof, Proof<CurrentNetwork>);
+}
+
+criterion_group! {
+    name = snark_bytes;
+    config = Criterion::default().sample_size(10);
+    targets = bytes
+}
+criterion_main!(snark_bytes);
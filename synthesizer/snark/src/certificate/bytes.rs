@@ -41,6 +41,42 @@ impl<N: Network> ToBytes for Certificate<N> {
     }
 }
 
+impl<N: Network> Certificate<N> {
+    /// Reads the certificate from a buffer, with the given compression and validation modes.
+    ///
+    /// Skipping validation omits the curve and subgroup checks on the group elements,
+    /// and must only be used for bytes from a trusted source.
+    pub fn read_le_with_mode<R: Read>(mut reader: R, compress: Compress, validate: Validate) -> IoResult<Self> {
+        // Read the version.
+        let version = u8::read_le(&mut reader)?;
+        // Ensure the version is valid.
+        if version != 1 {
+            return Err(error("Invalid certificate version"));
+        }
+        // Read the certificate.
+        let certificate = CanonicalDeserialize::deserialize_with_mode(&mut reader, compress, validate)
+            .map_err(|_| error("Failed to deserialize the certificate"))?;
+        // Return the certificate.
+        Ok(Self { certificate })
+    }
+
+    /// Writes the certificate to a buffer, with the given compression mode.
+    /// Note: The `ToBytes` encoding is equivalent to `Compress::Yes`.
+    pub fn write_le_with_mode<W: Write>(&self, mut writer: W, compress: Compress) -> IoResult<()> {
+        // Write the version.
+        1u8.write_le(&mut writer)?;
+        // Write the bytes.
+        self.certificate
+            .serialize_with_mode(&mut writer, compress)
+            .map_err(|_| error("Failed to serialize the certificate"))
+    }
+
+    /// Returns the number of bytes in the certificate, with the given compression mode.
+    pub fn size_in_bytes_with_mode(&self, compress: Compress) -> usize {
+        1 + self.certificate.serialized_size(compress)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -56,4 +92,30 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_bytes_with_mode() -> Result<()> {
+        // Sample the certificate.
+        let expected = crate::test_helpers::sample_certificate();
+
+        // Ensure the compressed encoding matches the default encoding.
+        let mut compressed = Vec::new();
+        expected.write_le_with_mode(&mut compressed, Compress::Yes)?;
+        assert_eq!(expected.to_bytes_le()?, compressed);
+        assert_eq!(compressed.len(), expected.size_in_bytes_with_mode(Compress::Yes));
+
+        // Check each combination of compression and validation modes.
+        for compress in [Compress::Yes, Compress::No] {
+            let mut bytes = Vec::new();
+            expected.write_le_with_mode(&mut bytes, compress)?;
+            assert_eq!(bytes.len(), expected.size_in_bytes_with_mode(compress));
+            for validate in [Validate::Yes, Validate::No] {
+                assert_eq!(expected, Certificate::read_le_with_mode(&bytes[..], compress, validate)?);
+            }
+        }
+        // Ensure the uncompressed encoding is larger than the compressed encoding.
+        assert!(expected.size_in_bytes_with_mode(Compress::No) > expected.size_in_bytes_with_mode(Compress::Yes));
+
+        Ok(())
+    }
 }
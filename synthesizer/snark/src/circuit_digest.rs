@@ -0,0 +1,79 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+/// A stable digest of a synthesized circuit, i.e. the hash of its index info and R1CS matrices.
+///
+/// The digest of a verifying key is the ID of the circuit it was indexed from, so two circuits
+/// that synthesize to different R1CS instances on different platforms have different digests.
+#[derive(Copy, Clone, PartialEq, Eq, Hash)]
+pub struct CircuitDigest([u8; 32]);
+
+impl CircuitDigest {
+    /// Computes the digest of the circuit for the given assignment.
+    pub fn from_assignment<N: Network>(assignment: &circuit::Assignment<N::Field>) -> Result<Self> {
+        let id = varuna::AHPForR1CS::<N::Field, varuna::VarunaHidingMode>::index_id(assignment)?;
+        Ok(Self(id.0))
+    }
+
+    /// Returns the digest of the circuit that the given verifying key was indexed from.
+    pub fn from_verifying_key<N: Network>(verifying_key: &VerifyingKey<N>) -> Self {
+        Self(verifying_key.id.0)
+    }
+
+    /// Returns the digest as bytes.
+    pub const fn to_bytes(&self) -> [u8; 32] {
+        self.0
+    }
+}
+
+impl Display for CircuitDigest {
+    /// Writes the digest as a hex string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        for byte in self.0 {
+            write!(f, "{byte:02x}")?;
+        }
+        Ok(())
+    }
+}
+
+impl Debug for CircuitDigest {
+    /// Writes the digest as a hex string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::MainnetV0;
+
+    type CurrentNetwork = MainnetV0;
+
+    #[test]
+    fn test_circuit_digest() -> Result<()> {
+        let assignment = crate::test_helpers::sample_assignment();
+        let (_, verifying_key) = crate::test_helpers::sample_keys();
+
+        // Ensure the digest is deterministic, and matches the verifying key.
+        let digest = CircuitDigest::from_assignment::<CurrentNetwork>(&assignment)?;
+        assert_eq!(digest, CircuitDigest::from_assignment::<CurrentNetwork>(&assignment)?);
+        assert_eq!(digest, CircuitDigest::from_verifying_key(&verifying_key));
+        assert_eq!(digest.to_string().len(), 64);
+        Ok(())
+    }
+}
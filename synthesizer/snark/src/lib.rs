@@ -20,6 +20,8 @@
 
 use console::network::{prelude::*, FiatShamir};
 use snarkvm_algorithms::{snark::varuna, traits::SNARK};
+use utilities::serialize::{CanonicalDeserialize, CanonicalSerialize};
+pub use utilities::serialize::{Compress, Validate};
 
 use once_cell::sync::OnceCell;
 use std::sync::Arc;
@@ -32,6 +34,9 @@ type Varuna<N> = varuna::VarunaSNARK<<N as Environment>::PairingCurve, FiatShami
 mod certificate;
 pub use certificate::Certificate;
 
+mod circuit_digest;
+pub use circuit_digest::CircuitDigest;
+
 mod proof;
 pub use proof::Proof;
 
@@ -162,6 +167,28 @@ mod test {
         assert!(!verifying_key.verify("test", &[one, one + one], &proof));
     }
 
+    #[test]
+    fn test_varuna_prove_with_seed() {
+        let assignment = crate::test_helpers::sample_assignment();
+
+        let srs = UniversalSRS::<CurrentNetwork>::load().unwrap();
+        let (proving_key, verifying_key) = srs.to_circuit_key("test", &assignment).unwrap();
+
+        // Ensure proofs with the same seed are identical.
+        let proof_a = proving_key.prove_with_seed("test", &assignment, [1u8; 32]).unwrap();
+        let proof_b = proving_key.prove_with_seed("test", &assignment, [1u8; 32]).unwrap();
+        assert_eq!(proof_a.to_bytes_le().unwrap(), proof_b.to_bytes_le().unwrap());
+
+        // Ensure proofs with different seeds differ.
+        let proof_c = proving_key.prove_with_seed("test", &assignment, [2u8; 32]).unwrap();
+        assert_ne!(proof_a.to_bytes_le().unwrap(), proof_c.to_bytes_le().unwrap());
+
+        // Ensure the proofs verify.
+        let one = <Circuit as Environment>::BaseField::one();
+        assert!(verifying_key.verify("test", &[one, one], &proof_a));
+        assert!(verifying_key.verify("test", &[one, one], &proof_c));
+    }
+
     #[test]
     fn test_varuna_verify_public_input_size() {
         /// Creates a simple circuit: a * b.
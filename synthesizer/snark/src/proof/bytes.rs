@@ -41,9 +41,52 @@ impl<N: Network> ToBytes for Proof<N> {
     }
 }
 
+impl<N: Network> Proof<N> {
+    /// Reads the proof from a buffer, with the given compression and validation modes.
+    ///
+    /// Skipping validation omits the curve and subgroup checks on the group elements,
+    /// and must only be used for bytes from a trusted source.
+    pub fn read_le_with_mode<R: Read>(mut reader: R, compress: Compress, validate: Validate) -> IoResult<Self> {
+        // Read the version.
+        let version = u8::read_le(&mut reader)?;
+        // Ensure the version is valid.
+        if version != 1 {
+            return Err(error("Invalid proof version"));
+        }
+        // Read the proof.
+        let proof = CanonicalDeserialize::deserialize_with_mode(&mut reader, compress, validate)
+            .map_err(|_| error("Failed to deserialize the proof"))?;
+        // Return the proof.
+        Ok(Self { proof })
+    }
+
+    /// Writes the proof to a buffer, with the given compression mode.
+    /// Note: The `ToBytes` encoding is equivalent to `Compress::Yes`.
+    pub fn write_le_with_mode<W: Write>(&self, mut writer: W, compress: Compress) -> IoResult<()> {
+        // Write the version.
+        1u8.write_le(&mut writer)?;
+        // Write the bytes.
+        self.proof.serialize_with_mode(&mut writer, compress).map_err(|_| error("Failed to serialize the proof"))
+    }
+
+    /// Returns the number of bytes in the proof, with the given compression mode.
+    pub fn size_in_bytes_with_mode(&self, compress: Compress) -> usize {
+        1 + self.proof.serialized_size(compress)
+    }
+
+    /// Returns the number of bytes in a proof for the given batch sizes, without computing the proof.
+    /// Note: The size matches the `ToBytes` encoding of a hiding proof.
+    pub fn size_in_bytes_for(batch_sizes: &[usize]) -> usize {
+        1 + varuna::Proof::<N::PairingCurve>::serialized_size_for(batch_sizes, true, Compress::Yes)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use console::network::MainnetV0;
+
+    type CurrentNetwork = MainnetV0;
 
     #[test]
     fn test_bytes() -> Result<()> {
@@ -56,4 +99,41 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_bytes_with_mode() -> Result<()> {
+        // Sample the proof.
+        let expected = crate::test_helpers::sample_proof();
+
+        // Ensure the compressed encoding matches the default encoding.
+        let mut compressed = Vec::new();
+        expected.write_le_with_mode(&mut compressed, Compress::Yes)?;
+        assert_eq!(expected.to_bytes_le()?, compressed);
+        assert_eq!(compressed.len(), expected.size_in_bytes_with_mode(Compress::Yes));
+
+        // Check each combination of compression and validation modes.
+        for compress in [Compress::Yes, Compress::No] {
+            let mut bytes = Vec::new();
+            expected.write_le_with_mode(&mut bytes, compress)?;
+            assert_eq!(bytes.len(), expected.size_in_bytes_with_mode(compress));
+            for validate in [Validate::Yes, Validate::No] {
+                assert_eq!(expected, Proof::read_le_with_mode(&bytes[..], compress, validate)?);
+            }
+        }
+        // Ensure the uncompressed encoding is larger than the compressed encoding.
+        assert!(expected.size_in_bytes_with_mode(Compress::No) > expected.size_in_bytes_with_mode(Compress::Yes));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_size_in_bytes_for() -> Result<()> {
+        // Sample the proof.
+        let expected = crate::test_helpers::sample_proof();
+
+        // Ensure the size for the batch sizes matches the size of the proof.
+        assert_eq!(expected.to_bytes_le()?.len(), Proof::<CurrentNetwork>::size_in_bytes_for(expected.batch_sizes()));
+
+        Ok(())
+    }
 }
@@ -37,17 +37,8 @@ impl<N: Network> FromStr for Proof<N> {
 
     /// Reads in the proof string.
     fn from_str(proof: &str) -> Result<Self, Self::Err> {
-        // Decode the proof string from bech32m.
-        let (hrp, data, variant) = bech32::decode(proof)?;
-        if hrp != PROOF_PREFIX {
-            bail!("Failed to decode proof: '{hrp}' is an invalid prefix")
-        } else if data.is_empty() {
-            bail!("Failed to decode proof: data field is empty")
-        } else if variant != bech32::Variant::Bech32m {
-            bail!("Found an proof that is not bech32m encoded: {proof}");
-        }
-        // Decode the proof data from u5 to u8, and into the proof.
-        Ok(Self::read_le(&Vec::from_base32(&data)?[..])?)
+        // Decode the proof string from bech32m, and into the proof.
+        Ok(Self::read_le(&decode_bech32m(proof, PROOF_PREFIX)?[..])?)
     }
 }
 
@@ -63,8 +54,7 @@ impl<N: Network> Display for Proof<N> {
         // Convert the proof to bytes.
         let bytes = self.to_bytes_le().map_err(|_| fmt::Error)?;
         // Encode the bytes into bech32m.
-        let string =
-            bech32::encode(PROOF_PREFIX, bytes.to_base32(), bech32::Variant::Bech32m).map_err(|_| fmt::Error)?;
+        let string = encode_bech32m(PROOF_PREFIX, &bytes).map_err(|_| fmt::Error)?;
         // Output the string.
         Display::fmt(&string, f)
     }
@@ -55,6 +55,29 @@ impl<N: Network> ProvingKey<N> {
         Ok(proof)
     }
 
+    /// Returns a proof for the given assignment on the circuit, using randomness derived from the given seed.
+    /// Proofs for the same seed and assignment are bit-for-bit identical, which makes them suitable for test vectors.
+    pub fn prove_with_seed(
+        &self,
+        function_name: &str,
+        assignment: &circuit::Assignment<N::Field>,
+        seed: [u8; 32],
+    ) -> Result<Proof<N>> {
+        #[cfg(feature = "aleo-cli")]
+        let timer = std::time::Instant::now();
+
+        // Retrieve the proving parameters.
+        let universal_prover = N::varuna_universal_prover();
+        let fiat_shamir = N::varuna_fs_parameters();
+
+        // Compute the proof.
+        let proof = Proof::new(Varuna::<N>::prove_with_seed(universal_prover, fiat_shamir, self, assignment, seed)?);
+
+        #[cfg(feature = "aleo-cli")]
+        println!("{}", format!(" • Executed '{function_name}' (in {} ms)", timer.elapsed().as_millis()).dimmed());
+        Ok(proof)
+    }
+
     /// Returns a proof for the given batch of proving keys and assignments.
     #[allow(clippy::type_complexity)]
     pub fn prove_batch<R: Rng + CryptoRng>(
@@ -44,3 +44,74 @@ impl<N: Network> ToBytes for VerifyingKey<N> {
         self.num_variables.write_le(&mut writer)
     }
 }
+
+impl<N: Network> VerifyingKey<N> {
+    /// Reads the verifying key from a buffer, with the given compression and validation modes.
+    ///
+    /// Skipping validation omits the curve and subgroup checks on the group elements,
+    /// and must only be used for bytes from a trusted source.
+    pub fn read_le_with_mode<R: Read>(mut reader: R, compress: Compress, validate: Validate) -> IoResult<Self> {
+        // Read the version.
+        let version = u8::read_le(&mut reader)?;
+        // Ensure the version is valid.
+        if version != 1 {
+            return Err(error("Invalid verifying key version"));
+        }
+        // Read the verifying key.
+        let verifying_key = CanonicalDeserialize::deserialize_with_mode(&mut reader, compress, validate)
+            .map_err(|_| error("Failed to deserialize the verifying key"))?;
+        // Read the number of variables.
+        let num_variables = u64::read_le(&mut reader)?;
+        // Return the verifying key.
+        Ok(Self { verifying_key: Arc::new(verifying_key), num_variables })
+    }
+
+    /// Writes the verifying key to a buffer, with the given compression mode.
+    /// Note: The `ToBytes` encoding is equivalent to `Compress::Yes`.
+    pub fn write_le_with_mode<W: Write>(&self, mut writer: W, compress: Compress) -> IoResult<()> {
+        // Write the version.
+        1u8.write_le(&mut writer)?;
+        // Write the verifying key.
+        self.verifying_key
+            .serialize_with_mode(&mut writer, compress)
+            .map_err(|_| error("Failed to serialize the verifying key"))?;
+        // Write the number of variables.
+        self.num_variables.write_le(&mut writer)
+    }
+
+    /// Returns the number of bytes in the verifying key, with the given compression mode.
+    pub fn size_in_bytes_with_mode(&self, compress: Compress) -> usize {
+        1 + self.verifying_key.serialized_size(compress) + 8
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bytes_with_mode() -> Result<()> {
+        // Sample the verifying key.
+        let (_, expected) = crate::test_helpers::sample_keys();
+
+        // Ensure the compressed encoding matches the default encoding.
+        let mut compressed = Vec::new();
+        expected.write_le_with_mode(&mut compressed, Compress::Yes)?;
+        assert_eq!(expected.to_bytes_le()?, compressed);
+        assert_eq!(compressed.len(), expected.size_in_bytes_with_mode(Compress::Yes));
+
+        // Check each combination of compression and validation modes.
+        for compress in [Compress::Yes, Compress::No] {
+            let mut bytes = Vec::new();
+            expected.write_le_with_mode(&mut bytes, compress)?;
+            assert_eq!(bytes.len(), expected.size_in_bytes_with_mode(compress));
+            for validate in [Validate::Yes, Validate::No] {
+                assert_eq!(expected, VerifyingKey::read_le_with_mode(&bytes[..], compress, validate)?);
+            }
+        }
+        // Ensure the uncompressed encoding is larger than the compressed encoding.
+        assert!(expected.size_in_bytes_with_mode(Compress::No) > expected.size_in_bytes_with_mode(Compress::Yes));
+
+        Ok(())
+    }
+}
@@ -104,6 +104,54 @@ impl<N: Network> VerifyingKey<N> {
             }
         }
     }
+
+    /// Returns `true` if all of the given batch proofs are valid, using a single product of pairings.
+    /// Note: On failure, this does not identify which of the batch proofs is invalid.
+    pub fn verify_batches<R: Rng + CryptoRng>(
+        batches: Vec<(Vec<(VerifyingKey<N>, Vec<Vec<N::Field>>)>, &Proof<N>)>,
+        rng: &mut R,
+    ) -> Result<()> {
+        #[cfg(feature = "aleo-cli")]
+        let timer = std::time::Instant::now();
+
+        // Convert the instances.
+        let num_batches = batches.len();
+        let batches = batches
+            .iter()
+            .map(|(inputs, proof)| {
+                let num_expected_keys = inputs.len();
+                let keys_to_inputs: BTreeMap<_, _> =
+                    inputs.iter().map(|(verifying_key, inputs)| (verifying_key.deref(), inputs.as_slice())).collect();
+                ensure!(
+                    keys_to_inputs.len() == num_expected_keys,
+                    "Incorrect number of verifying keys for batch proof"
+                );
+                Ok((keys_to_inputs, (**proof).deref()))
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        // Retrieve the verification parameters.
+        let universal_verifier = N::varuna_universal_verifier();
+        let fiat_shamir = N::varuna_fs_parameters();
+
+        // Verify the batch proofs.
+        match Varuna::<N>::verify_batches(universal_verifier, fiat_shamir, &batches, rng) {
+            Ok(is_valid) => {
+                #[cfg(feature = "aleo-cli")]
+                println!(
+                    "{}",
+                    format!(" • Verified {num_batches} proofs: {is_valid} (in {} ms)", timer.elapsed().as_millis())
+                        .dimmed()
+                );
+                if is_valid { Ok(()) } else { bail!("'verify_batches' failed for {num_batches} proofs") }
+            }
+            Err(error) => {
+                #[cfg(feature = "aleo-cli")]
+                println!("{}", format!(" • Verifier failed: {error}").dimmed());
+                bail!(error)
+            }
+        }
+    }
 }
 
 impl<N: Network> Deref for VerifyingKey<N> {
@@ -37,17 +37,8 @@ impl<N: Network> FromStr for VerifyingKey<N> {
 
     /// Reads in the verifying key string.
     fn from_str(key: &str) -> Result<Self, Self::Err> {
-        // Decode the verifying key string from bech32m.
-        let (hrp, data, variant) = bech32::decode(key)?;
-        if hrp != VERIFYING_KEY {
-            bail!("Failed to decode verifying key: '{hrp}' is an invalid prefix")
-        } else if data.is_empty() {
-            bail!("Failed to decode verifying key: data field is empty")
-        } else if variant != bech32::Variant::Bech32m {
-            bail!("Found a verifying key that is not bech32m encoded: {key}");
-        }
-        // Decode the verifying key data from u5 to u8, and into the verifying key.
-        Ok(Self::read_le(&Vec::from_base32(&data)?[..])?)
+        // Decode the verifying key string from bech32m, and into the verifying key.
+        Ok(Self::read_le(&decode_bech32m(key, VERIFYING_KEY)?[..])?)
     }
 }
 
@@ -63,8 +54,7 @@ impl<N: Network> Display for VerifyingKey<N> {
         // Convert the verifying key to bytes.
         let bytes = self.to_bytes_le().map_err(|_| fmt::Error)?;
         // Encode the bytes into bech32m.
-        let string =
-            bech32::encode(VERIFYING_KEY, bytes.to_base32(), bech32::Variant::Bech32m).map_err(|_| fmt::Error)?;
+        let string = encode_bech32m(VERIFYING_KEY, &bytes).map_err(|_| fmt::Error)?;
         // Output the string.
         Display::fmt(&string, f)
     }
@@ -129,6 +129,9 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
         inputs: Vec<Value<N>>,
         rng: &mut R,
     ) -> Result<Authorization<N>> {
+        // Ensure the program is loaded.
+        self.hydrate_programs([&program_id])?;
+
         macro_rules! logic {
             ($process:expr, $network:path, $aleo:path) => {{
                 // Compute the authorization.
@@ -0,0 +1,368 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use console::account::{GraphKey, ViewKey};
+
+/// An input to a call in a transaction builder.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum BuilderInput<N: Network> {
+    /// The given value.
+    Value(Value<N>),
+    /// A 'credits.aleo' record with at least the given amount of microcredits, selected from the candidate records.
+    Credits(u64),
+}
+
+/// A decoy transaction, and the delay that the caller should wait before broadcasting it.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DecoyTransaction<N: Network> {
+    /// The transaction.
+    transaction: Transaction<N>,
+    /// The delay before broadcasting, in seconds.
+    delay_secs: u64,
+}
+
+impl<N: Network> DecoyTransaction<N> {
+    /// Returns the transaction.
+    pub const fn transaction(&self) -> &Transaction<N> {
+        &self.transaction
+    }
+
+    /// Returns the delay before broadcasting, in seconds.
+    pub const fn delay_secs(&self) -> u64 {
+        self.delay_secs
+    }
+
+    /// Returns the transaction.
+    pub fn into_transaction(self) -> Transaction<N> {
+        self.transaction
+    }
+}
+
+/// A builder for execute transactions, which selects the 'credits.aleo' records
+/// to spend for the inputs and the fee from a set of candidate records.
+pub struct TransactionBuilder<'a, N: Network, C: ConsensusStorage<N>> {
+    /// The VM.
+    vm: &'a VM<N, C>,
+    /// The private key of the caller.
+    private_key: PrivateKey<N>,
+    /// The candidate records, and their balances in microcredits.
+    records: Vec<(Record<N, Plaintext<N>>, u64)>,
+    /// The coin selection strategy.
+    strategy: CoinSelection,
+    /// The priority fee in microcredits.
+    priority_fee_in_microcredits: u64,
+    /// Whether to pay the fee publicly, instead of with a record.
+    is_fee_public: bool,
+}
+
+impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
+    /// Returns the unspent 'credits.aleo' records that belong to the given view key.
+    pub fn find_unspent_credits(&self, view_key: &ViewKey<N>) -> Result<Vec<Record<N, Plaintext<N>>>> {
+        // Derive the x-coordinate of the address corresponding to the given view key.
+        let address_x_coordinate = view_key.to_address().to_x_coordinate();
+        // Derive the `sk_tag` from the graph key.
+        let sk_tag = match GraphKey::try_from(view_key) {
+            Ok(graph_key) => graph_key.sk_tag(),
+            Err(e) => bail!("Failed to derive the graph key from the view key: {e}"),
+        };
+        // Initialize the 'credits.aleo' program ID.
+        let credits_program_id = ProgramID::<N>::from_str("credits.aleo")?;
+
+        let mut records = Vec::new();
+        for (commitment, record) in self.transition_store().records() {
+            // Skip records that do not belong to the view key.
+            if !record.is_owner_with_address_x_coordinate(view_key, &address_x_coordinate) {
+                continue;
+            }
+            // Skip records that are spent.
+            if self.transition_store().contains_tag(&Record::<N, Plaintext<N>>::tag(sk_tag, *commitment)?)? {
+                continue;
+            }
+            // Skip records that are not 'credits.aleo' records.
+            let transition_id = self.transition_store().find_transition_id(&commitment)?;
+            if self.transition_store().get_program_id(&transition_id)? != Some(credits_program_id) {
+                continue;
+            }
+            records.push(record.decrypt(view_key)?);
+        }
+        Ok(records)
+    }
+
+    /// Returns a transaction builder for the given private key,
+    /// which uses the unspent 'credits.aleo' records of the caller as candidate records.
+    pub fn transaction_builder(&self, private_key: &PrivateKey<N>) -> Result<TransactionBuilder<'_, N, C>> {
+        let records = self.find_unspent_credits(&ViewKey::try_from(private_key)?)?;
+        Ok(TransactionBuilder::new(self, private_key, records))
+    }
+}
+
+impl<'a, N: Network, C: ConsensusStorage<N>> TransactionBuilder<'a, N, C> {
+    /// Initializes a new transaction builder with the given candidate records.
+    /// Note: Records that do not contain a 'microcredits' entry are ignored.
+    pub fn new(vm: &'a VM<N, C>, private_key: &PrivateKey<N>, records: Vec<Record<N, Plaintext<N>>>) -> Self {
+        Self {
+            vm,
+            private_key: *private_key,
+            records: Self::to_candidates(records),
+            strategy: CoinSelection::default(),
+            priority_fee_in_microcredits: 0,
+            is_fee_public: false,
+        }
+    }
+
+    /// Sets the candidate records.
+    /// Note: Records that do not contain a 'microcredits' entry are ignored.
+    pub fn with_records(mut self, records: Vec<Record<N, Plaintext<N>>>) -> Self {
+        self.records = Self::to_candidates(records);
+        self
+    }
+
+    /// Sets the coin selection strategy.
+    pub fn with_strategy(mut self, strategy: CoinSelection) -> Self {
+        self.strategy = strategy;
+        self
+    }
+
+    /// Sets the priority fee in microcredits.
+    pub fn with_priority_fee(mut self, priority_fee_in_microcredits: u64) -> Self {
+        self.priority_fee_in_microcredits = priority_fee_in_microcredits;
+        self
+    }
+
+    /// Sets the fee to be paid publicly, instead of with a record.
+    pub fn with_public_fee(mut self) -> Self {
+        self.is_fee_public = true;
+        self
+    }
+
+    /// Returns the remaining candidate records.
+    pub fn records(&self) -> impl '_ + Iterator<Item = &Record<N, Plaintext<N>>> {
+        self.records.iter().map(|(record, _)| record)
+    }
+
+    /// Returns a plan to produce one fee record for each of the given fees (in microcredits),
+    /// where the record indices refer to the remaining candidate records, in order.
+    pub fn plan_fees(&self, fees: &[u64]) -> Result<FeePlan> {
+        FeePlan::new(&self.records.iter().map(|(_, balance)| *balance).collect::<Vec<_>>(), fees)
+    }
+
+    /// Authorizes a call to the given program function, selecting a candidate record for each `BuilderInput::Credits` input.
+    /// The selected records are removed from the candidate records.
+    pub fn authorize<R: Rng + CryptoRng>(
+        &mut self,
+        program_id: impl TryInto<ProgramID<N>>,
+        function_name: impl TryInto<Identifier<N>>,
+        inputs: Vec<BuilderInput<N>>,
+        rng: &mut R,
+    ) -> Result<Authorization<N>> {
+        // Prepare the inputs, selecting the records.
+        let inputs = inputs
+            .into_iter()
+            .map(|input| match input {
+                BuilderInput::Value(value) => Ok(value),
+                BuilderInput::Credits(amount) => Ok(Value::Record(self.select(amount)?)),
+            })
+            .collect::<Result<Vec<_>>>()?;
+        // Authorize the call.
+        self.vm.authorize(&self.private_key, program_id, function_name, inputs, rng)
+    }
+
+    /// Returns a new execute transaction for a call to the given program function,
+    /// selecting a candidate record for each `BuilderInput::Credits` input, and for the fee.
+    /// The selected records are removed from the candidate records.
+    pub fn execute<R: Rng + CryptoRng>(
+        &mut self,
+        program_id: impl TryInto<ProgramID<N>>,
+        function_name: impl TryInto<Identifier<N>>,
+        inputs: Vec<BuilderInput<N>>,
+        query: Option<Query<N, C::BlockStorage>>,
+        rng: &mut R,
+    ) -> Result<Transaction<N>> {
+        // Compute the authorization.
+        let authorization = self.authorize(program_id, function_name, inputs, rng)?;
+        // Determine if a fee is required.
+        let is_fee_required = !authorization.is_split() || self.priority_fee_in_microcredits > 0;
+        // Compute the execution.
+        let transaction = self.vm.execute_authorization(authorization, None, query.clone(), rng)?;
+        if !is_fee_required {
+            return Ok(transaction);
+        }
+        let Some(execution) = transaction.execution() else { bail!("Expected an execute transaction") };
+
+        // Compute the minimum execution cost.
+        let (minimum_execution_cost, (_, _)) = execution_cost(&self.vm.process().read(), execution)?;
+        // Compute the execution ID.
+        let execution_id = execution.to_execution_id()?;
+        // Authorize the fee.
+        let authorization = match self.is_fee_public {
+            true => self.vm.authorize_fee_public(
+                &self.private_key,
+                minimum_execution_cost,
+                self.priority_fee_in_microcredits,
+                execution_id,
+                rng,
+            )?,
+            false => {
+                // Select the fee record.
+                let total_fee = minimum_execution_cost
+                    .checked_add(self.priority_fee_in_microcredits)
+                    .ok_or_else(|| anyhow!("The total fee overflowed"))?;
+                let record = self.select(total_fee)?;
+                self.vm.authorize_fee_private(
+                    &self.private_key,
+                    record,
+                    minimum_execution_cost,
+                    self.priority_fee_in_microcredits,
+                    execution_id,
+                    rng,
+                )?
+            }
+        };
+        // Compute the fee.
+        let fee = self.vm.execute_fee_authorization(authorization, query, rng)?;
+        // Return the execute transaction.
+        Transaction::from_execution(execution.clone(), Some(fee))
+    }
+
+    /// Returns a new execute transaction for a private transfer of the given amount to the given recipient.
+    pub fn transfer_private<R: Rng + CryptoRng>(
+        &mut self,
+        recipient: Address<N>,
+        amount_in_microcredits: u64,
+        query: Option<Query<N, C::BlockStorage>>,
+        rng: &mut R,
+    ) -> Result<Transaction<N>> {
+        let inputs = vec![
+            BuilderInput::Credits(amount_in_microcredits),
+            BuilderInput::Value(Value::from_str(&recipient.to_string())?),
+            BuilderInput::Value(Value::from_str(&format!("{amount_in_microcredits}u64"))?),
+        ];
+        self.execute("credits.aleo", "transfer_private", inputs, query, rng)
+    }
+
+    /// Returns a new decoy transaction for the given configuration, which is either a private transfer
+    /// of a random amount to the caller, or a join of two records, along with a random broadcast delay.
+    /// The selected records are removed from the candidate records.
+    pub fn decoy<R: Rng + CryptoRng>(
+        &mut self,
+        config: &DecoyConfig,
+        query: Option<Query<N, C::BlockStorage>>,
+        rng: &mut R,
+    ) -> Result<DecoyTransaction<N>> {
+        // Ensure the fee is private, as a public fee reveals the caller.
+        ensure!(!self.is_fee_public, "A decoy transaction must pay a private fee, as a public fee reveals the caller");
+
+        // Sample the delay.
+        let delay_secs = config.sample_delay_secs(rng);
+        // Join two records, if enabled and there are enough records for the inputs and the fee.
+        let transaction = match config.allow_joins() && self.records.len() >= 3 && rng.gen_bool(0.5) {
+            true => {
+                let inputs = vec![BuilderInput::Credits(0), BuilderInput::Credits(0)];
+                self.execute("credits.aleo", "join", inputs, query, rng)?
+            }
+            false => {
+                let caller = Address::try_from(&self.private_key)?;
+                self.transfer_private(caller, config.sample_amount(rng), query, rng)?
+            }
+        };
+        Ok(DecoyTransaction { transaction, delay_secs })
+    }
+}
+
+impl<'a, N: Network, C: ConsensusStorage<N>> TransactionBuilder<'a, N, C> {
+    /// Returns the given records with their balances, ignoring records without a 'microcredits' entry.
+    fn to_candidates(records: Vec<Record<N, Plaintext<N>>>) -> Vec<(Record<N, Plaintext<N>>, u64)> {
+        let microcredits = Identifier::from_str("microcredits").expect("Failed to parse 'microcredits'");
+        records
+            .into_iter()
+            .filter_map(|record| match record.find(&[microcredits]) {
+                Ok(console::program::Entry::Private(Plaintext::Literal(Literal::U64(amount), _))) => {
+                    Some((record, *amount))
+                }
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// Selects and removes a candidate record with at least the given amount of microcredits.
+    fn select(&mut self, amount: u64) -> Result<Record<N, Plaintext<N>>> {
+        let balances = self.records.iter().map(|(_, balance)| *balance).collect::<Vec<_>>();
+        match self.strategy.select(&balances, amount) {
+            Some(index) => Ok(self.records.remove(index).0),
+            None => bail!("No candidate record has at least {amount} microcredits"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::vm::test_helpers::sample_vm_with_genesis_block;
+
+    #[test]
+    fn test_transaction_builder() {
+        let rng = &mut TestRng::default();
+
+        // Initialize the VM.
+        let vm = sample_vm_with_genesis_block(rng);
+        // Initialize the caller.
+        let caller_private_key = crate::vm::test_helpers::sample_genesis_private_key(rng);
+        let caller_address = Address::try_from(&caller_private_key).unwrap();
+
+        // Ensure the caller has unspent records.
+        let num_records = vm.find_unspent_credits(&ViewKey::try_from(&caller_private_key).unwrap()).unwrap().len();
+        assert!(num_records >= 2);
+
+        // Build a private transfer, selecting the input and fee records.
+        let mut builder =
+            vm.transaction_builder(&caller_private_key).unwrap().with_strategy(CoinSelection::MinimizeChange);
+        let transaction = builder.transfer_private(caller_address, 1, None, rng).unwrap();
+        vm.check_transaction(&transaction, None, rng).unwrap();
+
+        // Ensure the input and fee records were removed from the candidates.
+        assert_eq!(builder.records().count(), num_records - 2);
+
+        // Ensure the builder fails if no record is sufficient.
+        let mut builder = vm.transaction_builder(&caller_private_key).unwrap();
+        assert!(builder.transfer_private(caller_address, u64::MAX, None, rng).is_err());
+    }
+
+    #[test]
+    fn test_decoy() {
+        let rng = &mut TestRng::default();
+
+        // Initialize the VM.
+        let vm = sample_vm_with_genesis_block(rng);
+        // Initialize the caller.
+        let caller_private_key = crate::vm::test_helpers::sample_genesis_private_key(rng);
+
+        // Build a decoy, and ensure it is valid.
+        let config = DecoyConfig::default().with_amounts(1, 100).unwrap().with_delays(10, 20).unwrap();
+        let mut builder = vm.transaction_builder(&caller_private_key).unwrap();
+        let decoy = builder.decoy(&config, None, rng).unwrap();
+        vm.check_transaction(decoy.transaction(), None, rng).unwrap();
+        assert!((10..=20).contains(&decoy.delay_secs()));
+
+        // Ensure the decoy pays a private fee, and only uses 'credits.aleo'.
+        let transaction = decoy.into_transaction();
+        assert!(transaction.fee_transition().unwrap().is_fee_private());
+        assert!(transaction.transitions().all(|transition| transition.program_id().to_string() == "credits.aleo"));
+
+        // Ensure a decoy with a public fee is rejected.
+        let mut builder = vm.transaction_builder(&caller_private_key).unwrap().with_public_fee();
+        assert!(builder.decoy(&config, None, rng).is_err());
+    }
+}
@@ -0,0 +1,402 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use super::*;
+use console::{program::Entry, types::U8};
+use ledger_committee::{MIN_DELEGATOR_STAKE, MIN_VALIDATOR_SELF_STAKE, MIN_VALIDATOR_STAKE};
+
+/// The minimum amount of microcredits for a call to `bond_validator` or `bond_public`.
+const MIN_BOND_IN_MICROCREDITS: u64 = 1_000_000;
+/// The maximum commission percentage of a validator.
+const MAX_COMMISSION_PERCENTAGE: u8 = 100;
+
+/// A typed call to a `credits.aleo` function.
+///
+/// Each variant corresponds to the function of the same name, and holds its inputs, excluding the caller.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum CreditsCall<N: Network> {
+    /// Bonds `amount` microcredits of the caller to itself, as a validator with the given commission percentage.
+    BondValidator { withdrawal: Address<N>, amount: u64, commission: u8 },
+    /// Bonds `amount` microcredits of the caller to the given validator.
+    BondPublic { validator: Address<N>, withdrawal: Address<N>, amount: u64 },
+    /// Unbonds `amount` microcredits of the given staker.
+    UnbondPublic { staker: Address<N>, amount: u64 },
+    /// Claims the unbonded microcredits of the given staker, to its withdrawal address.
+    ClaimUnbondPublic { staker: Address<N> },
+    /// Transfers `amount` microcredits from the public balance of the caller to the recipient.
+    TransferPublic { recipient: Address<N>, amount: u64 },
+    /// Transfers `amount` microcredits from the public balance of the signer to the recipient.
+    TransferPublicAsSigner { recipient: Address<N>, amount: u64 },
+    /// Transfers `amount` microcredits from the given record to a new record for the recipient.
+    TransferPrivate { record: Record<N, Plaintext<N>>, recipient: Address<N>, amount: u64 },
+    /// Transfers `amount` microcredits from the given record to the public balance of the recipient.
+    TransferPrivateToPublic { record: Record<N, Plaintext<N>>, recipient: Address<N>, amount: u64 },
+    /// Transfers `amount` microcredits from the public balance of the caller to a new record for the recipient.
+    TransferPublicToPrivate { recipient: Address<N>, amount: u64 },
+}
+
+impl<N: Network> CreditsCall<N> {
+    /// Returns the name of the `credits.aleo` function.
+    pub const fn function_name(&self) -> &'static str {
+        match self {
+            Self::BondValidator { .. } => "bond_validator",
+            Self::BondPublic { .. } => "bond_public",
+            Self::UnbondPublic { .. } => "unbond_public",
+            Self::ClaimUnbondPublic { .. } => "claim_unbond_public",
+            Self::TransferPublic { .. } => "transfer_public",
+            Self::TransferPublicAsSigner { .. } => "transfer_public_as_signer",
+            Self::TransferPrivate { .. } => "transfer_private",
+            Self::TransferPrivateToPublic { .. } => "transfer_private_to_public",
+            Self::TransferPublicToPrivate { .. } => "transfer_public_to_private",
+        }
+    }
+
+    /// Returns the amount of microcredits in the call, or `None` for `claim_unbond_public`.
+    pub const fn amount(&self) -> Option<u64> {
+        match self {
+            Self::BondValidator { amount, .. }
+            | Self::BondPublic { amount, .. }
+            | Self::UnbondPublic { amount, .. }
+            | Self::TransferPublic { amount, .. }
+            | Self::TransferPublicAsSigner { amount, .. }
+            | Self::TransferPrivate { amount, .. }
+            | Self::TransferPrivateToPublic { amount, .. }
+            | Self::TransferPublicToPrivate { amount, .. } => Some(*amount),
+            Self::ClaimUnbondPublic { .. } => None,
+        }
+    }
+
+    /// Returns the inputs to the `credits.aleo` function.
+    pub fn inputs(&self) -> Vec<Value<N>> {
+        let address = |address: &Address<N>| Value::from(Literal::Address(*address));
+        let amount = |amount: &u64| Value::from(Literal::U64(U64::new(*amount)));
+        match self {
+            Self::BondValidator { withdrawal, amount: microcredits, commission } => {
+                vec![address(withdrawal), amount(microcredits), Value::from(Literal::U8(U8::new(*commission)))]
+            }
+            Self::BondPublic { validator, withdrawal, amount: microcredits } => {
+                vec![address(validator), address(withdrawal), amount(microcredits)]
+            }
+            Self::UnbondPublic { staker, amount: microcredits } => vec![address(staker), amount(microcredits)],
+            Self::ClaimUnbondPublic { staker } => vec![address(staker)],
+            Self::TransferPublic { recipient, amount: microcredits }
+            | Self::TransferPublicAsSigner { recipient, amount: microcredits }
+            | Self::TransferPublicToPrivate { recipient, amount: microcredits } => {
+                vec![address(recipient), amount(microcredits)]
+            }
+            Self::TransferPrivate { record, recipient, amount: microcredits }
+            | Self::TransferPrivateToPublic { record, recipient, amount: microcredits } => {
+                vec![Value::Record(record.clone()), address(recipient), amount(microcredits)]
+            }
+        }
+    }
+}
+
+impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
+    /// Checks that the given `credits.aleo` call by the given caller is valid, against the latest finalize state.
+    ///
+    /// Note: This check mirrors the assertions of the function and its finalize scope, so that a call which would
+    /// be rejected is caught before it is built. A call that passes may still be rejected, if the finalize state
+    /// changes before the transaction is confirmed.
+    pub fn check_credits_call(&self, caller: &Address<N>, call: &CreditsCall<N>) -> Result<()> {
+        // Ensure the amount is nonzero.
+        if let Some(amount) = call.amount() {
+            ensure!(amount > 0, "The amount for '{}' must be nonzero", call.function_name());
+        }
+
+        match call {
+            CreditsCall::BondValidator { withdrawal, amount, commission } => {
+                ensure!(caller != withdrawal, "The withdrawal address must differ from the validator address");
+                ensure!(*amount >= MIN_BOND_IN_MICROCREDITS, "The bond amount must be at least 1 credit");
+                ensure!(*commission <= MAX_COMMISSION_PERCENTAGE, "The commission must not exceed 100%");
+                self.check_public_balance(caller, *amount)?;
+                self.check_withdrawal_address(caller, withdrawal)?;
+                // Ensure the commission is unchanged, if the validator is in the committee.
+                match self.credits_value(Self::COMMITTEE, caller)? {
+                    Some(state) => {
+                        let Literal::U8(current) = Self::credits_member(&state, "commission")? else {
+                            bail!("Invalid committee state for '{caller}' - {state}")
+                        };
+                        ensure!(*current == *commission, "The commission of validator '{caller}' is {current}%");
+                    }
+                    None => ensure!(
+                        *amount >= MIN_VALIDATOR_SELF_STAKE,
+                        "The initial bond of a validator must be at least {MIN_VALIDATOR_SELF_STAKE} microcredits"
+                    ),
+                }
+                // Ensure the caller is not bonded to another validator.
+                if let Some(state) = self.credits_value(Self::BONDED, caller)? {
+                    let Literal::Address(validator) = Self::credits_member(&state, "validator")? else {
+                        bail!("Invalid bond state for '{caller}' - {state}")
+                    };
+                    ensure!(validator == *caller, "Address '{caller}' is already bonded to validator '{validator}'");
+                }
+                // Ensure the total stake of the validator meets the committee threshold.
+                let delegated = self.credits_u64(Self::DELEGATED, caller)?.unwrap_or(0);
+                ensure!(
+                    delegated.saturating_add(*amount) >= MIN_VALIDATOR_STAKE,
+                    "The total stake of validator '{caller}' must be at least {MIN_VALIDATOR_STAKE} microcredits"
+                );
+            }
+            CreditsCall::BondPublic { validator, withdrawal, amount } => {
+                ensure!(caller != validator, "Validators must bond to themselves with 'bond_validator'");
+                ensure!(*amount >= MIN_BOND_IN_MICROCREDITS, "The bond amount must be at least 1 credit");
+                self.check_public_balance(caller, *amount)?;
+                self.check_withdrawal_address(caller, withdrawal)?;
+                // Retrieve the current bond of the caller.
+                let bonded = match self.credits_value(Self::BONDED, caller)? {
+                    Some(state) => {
+                        let (Literal::Address(current), Literal::U64(bonded)) =
+                            (Self::credits_member(&state, "validator")?, Self::credits_member(&state, "microcredits")?)
+                        else {
+                            bail!("Invalid bond state for '{caller}' - {state}")
+                        };
+                        ensure!(current == *validator, "Address '{caller}' is already bonded to validator '{current}'");
+                        *bonded
+                    }
+                    None => {
+                        // Ensure the validator is open to new stakers.
+                        if let Some(state) = self.credits_value(Self::COMMITTEE, validator)? {
+                            let Literal::Boolean(is_open) = Self::credits_member(&state, "is_open")? else {
+                                bail!("Invalid committee state for '{validator}' - {state}")
+                            };
+                            ensure!(*is_open, "Validator '{validator}' is closed to new stakers");
+                        }
+                        0
+                    }
+                };
+                ensure!(
+                    bonded.saturating_add(*amount) >= MIN_DELEGATOR_STAKE,
+                    "The total bond of delegator '{caller}' must be at least {MIN_DELEGATOR_STAKE} microcredits"
+                );
+                // Ensure the validator is not unbonding.
+                ensure!(
+                    self.credits_value(Self::UNBONDING, validator)?.is_none(),
+                    "Validator '{validator}' is currently unbonding"
+                );
+            }
+            CreditsCall::UnbondPublic { staker, amount } => {
+                // Retrieve the bond of the staker.
+                let Some(state) = self.credits_value(Self::BONDED, staker)? else {
+                    bail!("Address '{staker}' is not bonded")
+                };
+                let (Literal::Address(validator), Literal::U64(bonded)) =
+                    (Self::credits_member(&state, "validator")?, Self::credits_member(&state, "microcredits")?)
+                else {
+                    bail!("Invalid bond state for '{staker}' - {state}")
+                };
+                // Ensure the caller is the withdrawal address of the staker or of its validator.
+                let is_staker = self.credits_address(Self::WITHDRAW, staker)? == Some(*caller);
+                let is_validator = self.credits_address(Self::WITHDRAW, &validator)? == Some(*caller);
+                ensure!(is_staker || is_validator, "Address '{caller}' may not unbond staker '{staker}'");
+                ensure!(*amount <= *bonded, "Staker '{staker}' has only {bonded} microcredits bonded");
+            }
+            CreditsCall::ClaimUnbondPublic { staker } => {
+                // Retrieve the unbonding state of the staker.
+                let Some(state) = self.credits_value(Self::UNBONDING, staker)? else {
+                    bail!("Address '{staker}' is not unbonding")
+                };
+                // Ensure the unbonding completes by the next block.
+                let Literal::U32(height) = Self::credits_member(&state, "height")? else {
+                    bail!("Invalid unbond state for '{staker}' - {state}")
+                };
+                let next_height = self.block_store().current_block_height().saturating_add(1);
+                ensure!(next_height >= *height, "The unbonding of '{staker}' completes at block {height}");
+            }
+            CreditsCall::TransferPublic { amount, .. }
+            | CreditsCall::TransferPublicAsSigner { amount, .. }
+            | CreditsCall::TransferPublicToPrivate { amount, .. } => self.check_public_balance(caller, *amount)?,
+            CreditsCall::TransferPrivate { record, amount, .. }
+            | CreditsCall::TransferPrivateToPublic { record, amount, .. } => {
+                // Ensure the record is owned by the caller.
+                ensure!(**record.owner() == *caller, "The record is not owned by '{caller}'");
+                // Ensure the record has a sufficient balance.
+                let balance = match record.find(&[Identifier::from_str("microcredits")?]) {
+                    Ok(Entry::Private(Plaintext::Literal(Literal::U64(balance), _))) => *balance,
+                    _ => bail!("The record does not contain a microcredits entry"),
+                };
+                ensure!(*amount <= balance, "The record has only {balance} microcredits");
+            }
+        }
+        Ok(())
+    }
+
+    /// Checks and authorizes the given `credits.aleo` call.
+    pub fn authorize_credits_call<R: Rng + CryptoRng>(
+        &self,
+        private_key: &PrivateKey<N>,
+        call: &CreditsCall<N>,
+        rng: &mut R,
+    ) -> Result<Authorization<N>> {
+        // Ensure the call is valid.
+        self.check_credits_call(&Address::try_from(private_key)?, call)?;
+        // Authorize the call.
+        self.authorize(private_key, "credits.aleo", call.function_name(), call.inputs(), rng)
+    }
+
+    /// Checks the given `credits.aleo` call, and returns a new transaction for it.
+    pub fn execute_credits_call<R: Rng + CryptoRng>(
+        &self,
+        private_key: &PrivateKey<N>,
+        call: &CreditsCall<N>,
+        fee_record: Option<Record<N, Plaintext<N>>>,
+        priority_fee_in_microcredits: u64,
+        query: Option<Query<N, C::BlockStorage>>,
+        rng: &mut R,
+    ) -> Result<Transaction<N>> {
+        // Ensure the call is valid.
+        self.check_credits_call(&Address::try_from(private_key)?, call)?;
+        // Execute the call.
+        let locator = ("credits.aleo", call.function_name());
+        self.execute(
+            private_key,
+            locator,
+            call.inputs().into_iter(),
+            fee_record,
+            priority_fee_in_microcredits,
+            query,
+            rng,
+        )
+    }
+}
+
+impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
+    /// The name of the `account` mapping.
+    const ACCOUNT: &'static str = "account";
+    /// The name of the `bonded` mapping.
+    const BONDED: &'static str = "bonded";
+    /// The name of the `committee` mapping.
+    const COMMITTEE: &'static str = "committee";
+    /// The name of the `delegated` mapping.
+    const DELEGATED: &'static str = "delegated";
+    /// The name of the `unbonding` mapping.
+    const UNBONDING: &'static str = "unbonding";
+    /// The name of the `withdraw` mapping.
+    const WITHDRAW: &'static str = "withdraw";
+
+    /// Ensures the public balance of the given address is at least the given amount.
+    fn check_public_balance(&self, address: &Address<N>, amount: u64) -> Result<()> {
+        let balance = self.credits_u64(Self::ACCOUNT, address)?.unwrap_or(0);
+        ensure!(amount <= balance, "The public balance of '{address}' is only {balance} microcredits");
+        Ok(())
+    }
+
+    /// Ensures the withdrawal address of the given staker is the given address, if it is already set.
+    fn check_withdrawal_address(&self, staker: &Address<N>, withdrawal: &Address<N>) -> Result<()> {
+        if let Some(current) = self.credits_address(Self::WITHDRAW, staker)? {
+            ensure!(current == *withdrawal, "The withdrawal address of '{staker}' is already set to '{current}'");
+        }
+        Ok(())
+    }
+
+    /// Returns the confirmed value for the given address in the given `credits.aleo` mapping.
+    fn credits_value(&self, mapping: &str, address: &Address<N>) -> Result<Option<Value<N>>> {
+        self.finalize_store().get_value_confirmed(
+            ProgramID::from_str("credits.aleo")?,
+            Identifier::intern(mapping)?,
+            &Plaintext::from(Literal::Address(*address)),
+        )
+    }
+
+    /// Returns the confirmed `u64` value for the given address in the given `credits.aleo` mapping.
+    fn credits_u64(&self, mapping: &str, address: &Address<N>) -> Result<Option<u64>> {
+        match self.credits_value(mapping, address)? {
+            Some(Value::Plaintext(Plaintext::Literal(Literal::U64(value), _))) => Ok(Some(*value)),
+            Some(value) => bail!("Invalid value in the '{mapping}' mapping - {value}"),
+            None => Ok(None),
+        }
+    }
+
+    /// Returns the confirmed address value for the given address in the given `credits.aleo` mapping.
+    fn credits_address(&self, mapping: &str, address: &Address<N>) -> Result<Option<Address<N>>> {
+        match self.credits_value(mapping, address)? {
+            Some(Value::Plaintext(Plaintext::Literal(Literal::Address(value), _))) => Ok(Some(value)),
+            Some(value) => bail!("Invalid value in the '{mapping}' mapping - {value}"),
+            None => Ok(None),
+        }
+    }
+
+    /// Returns the literal member of the given `credits.aleo` struct value.
+    fn credits_member(value: &Value<N>, member: &str) -> Result<Literal<N>> {
+        match value {
+            Value::Plaintext(Plaintext::Struct(members, _)) => match members.get(&Identifier::intern(member)?) {
+                Some(Plaintext::Literal(literal, _)) => Ok(literal.clone()),
+                _ => bail!("Invalid struct in 'credits.aleo' (missing '{member}') - {value}"),
+            },
+            _ => bail!("Invalid value in 'credits.aleo' (expected a struct) - {value}"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::vm::test_helpers::{sample_genesis_private_key, sample_vm_with_genesis_block, CurrentNetwork};
+
+    #[test]
+    fn test_credits_call_inputs() {
+        let rng = &mut TestRng::default();
+        let recipient = Address::<CurrentNetwork>::rand(rng);
+
+        let call = CreditsCall::TransferPublic { recipient, amount: 5 };
+        assert_eq!(call.function_name(), "transfer_public");
+        assert_eq!(call.inputs(), vec![
+            Value::from_str(&recipient.to_string()).unwrap(),
+            Value::from_str("5u64").unwrap()
+        ]);
+
+        let call = CreditsCall::BondValidator { withdrawal: recipient, amount: 5, commission: 10 };
+        assert_eq!(call.function_name(), "bond_validator");
+        assert_eq!(call.inputs()[2], Value::from_str("10u8").unwrap());
+
+        let call = CreditsCall::ClaimUnbondPublic { staker: recipient };
+        assert_eq!(call.amount(), None);
+        assert_eq!(call.inputs().len(), 1);
+    }
+
+    #[test]
+    fn test_check_credits_call() {
+        let rng = &mut TestRng::default();
+
+        // Initialize the VM, and the genesis committee member, which has a public balance and a bond to itself.
+        let vm = sample_vm_with_genesis_block(rng);
+        let private_key = sample_genesis_private_key(rng);
+        let caller = Address::try_from(&private_key).unwrap();
+        let recipient = Address::rand(rng);
+
+        // Ensure a transfer within the public balance is valid, and can be authorized.
+        let call = CreditsCall::TransferPublic { recipient, amount: 1 };
+        vm.check_credits_call(&caller, &call).unwrap();
+        let authorization = vm.authorize_credits_call(&private_key, &call, rng).unwrap();
+        assert_eq!(authorization.len(), 1);
+
+        // Ensure a zero amount is rejected.
+        let call = CreditsCall::TransferPublic { recipient, amount: 0 };
+        assert!(vm.check_credits_call(&caller, &call).is_err());
+        // Ensure a transfer beyond the public balance is rejected.
+        let call = CreditsCall::TransferPublic { recipient, amount: u64::MAX };
+        assert!(vm.check_credits_call(&caller, &call).is_err());
+        assert!(vm.check_credits_call(&recipient, &CreditsCall::TransferPublic { recipient, amount: 1 }).is_err());
+
+        // Ensure a validator may not bond to itself with `bond_public`.
+        let call = CreditsCall::BondPublic { validator: caller, withdrawal: caller, amount: MIN_DELEGATOR_STAKE };
+        assert!(vm.check_credits_call(&caller, &call).is_err());
+        // Ensure an unrelated address may not unbond the validator.
+        let call = CreditsCall::UnbondPublic { staker: caller, amount: 1 };
+        assert!(vm.check_credits_call(&recipient, &call).is_err());
+        // Ensure a staker that is not unbonding may not claim.
+        let call = CreditsCall::ClaimUnbondPublic { staker: caller };
+        assert!(vm.check_credits_call(&caller, &call).is_err());
+    }
+}
@@ -66,12 +66,46 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
         // Return the deploy transaction.
         Transaction::from_deployment(owner, deployment, fee)
     }
+
+    /// Checks that the given program is deployable, without generating the proving keys and certificates.
+    ///
+    /// This runs the well-formedness checks, resolves the imports, and synthesizes each function
+    /// to count its constraints and variables, which is significantly faster than `VM::deploy`.
+    /// Note: This does not check the deployment fee, nor the program owner.
+    pub fn check_deployment_syntactic<R: Rng + CryptoRng>(
+        &self,
+        program: &Program<N>,
+        rng: &mut R,
+    ) -> Result<DeploymentCheck<N>> {
+        macro_rules! logic {
+            ($process:expr, $network:path, $aleo:path) => {{
+                // Prepare the program.
+                let program = cast_ref!(&program as Program<$network>);
+                // Check the program.
+                let check = $process.check_deployment_syntactic::<$aleo, _>(program, rng)?;
+                // Prepare the deployment check.
+                Ok(cast_ref!(check as DeploymentCheck<N>).clone())
+            }};
+        }
+
+        // Ensure the imports of the program are loaded.
+        self.hydrate_programs(program.imports().keys())?;
+
+        // Check the program.
+        let timer = timer!("VM::check_deployment_syntactic");
+        let result = process!(self, logic);
+        finish!(timer, "Check the program for deployment");
+        result
+    }
 }
 
 impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
     /// Returns a deployment for the given program.
     #[inline]
     pub(super) fn deploy_raw<R: Rng + CryptoRng>(&self, program: &Program<N>, rng: &mut R) -> Result<Deployment<N>> {
+        // Ensure the imports of the program are loaded.
+        self.hydrate_programs(program.imports().keys())?;
+
         macro_rules! logic {
             ($process:expr, $network:path, $aleo:path) => {{
                 // Prepare the program.
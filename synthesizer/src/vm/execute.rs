@@ -95,6 +95,71 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
         Transaction::from_execution(execution, fee)
     }
 
+    /// Returns the minimum fee in microcredits to execute the given authorization,
+    /// as `(total cost, (storage cost, finalize cost))`.
+    ///
+    /// Note: This method computes the execution (including its proofs), so the estimate is exact,
+    /// and can be used to plan the fee records for a batch of executions with `FeePlan`.
+    pub fn estimate_execution_fee<R: Rng + CryptoRng>(
+        &self,
+        authorization: Authorization<N>,
+        query: Option<Query<N, C::BlockStorage>>,
+        rng: &mut R,
+    ) -> Result<(u64, (u64, u64))> {
+        // Compute the execution.
+        let execution = self.execute_authorization_raw(authorization, query, rng)?;
+        // Compute the minimum execution cost.
+        execution_cost(&self.process().read(), &execution)
+    }
+
+    /// Returns the minimum fee in microcredits to execute the given program function with the given inputs,
+    /// as `(total cost, (storage cost, finalize cost))`.
+    ///
+    /// Note: This method synthesizes the circuits for the call, but does *not* compute the proof.
+    /// The size of the proof is determined by the number of circuits and assignments, so the estimate
+    /// matches the cost of the execution returned by `VM::execute` for the same call.
+    pub fn estimate_execution_cost<R: Rng + CryptoRng>(
+        &self,
+        private_key: &PrivateKey<N>,
+        (program_id, function_name): (impl TryInto<ProgramID<N>>, impl TryInto<Identifier<N>>),
+        inputs: impl ExactSizeIterator<Item = impl TryInto<Value<N>>>,
+        rng: &mut R,
+    ) -> Result<(u64, (u64, u64))> {
+        let timer = timer!("VM::estimate_execution_cost");
+
+        // Compute the authorization.
+        let authorization = self.authorize(private_key, program_id, function_name, inputs, rng)?;
+        lap!(timer, "Authorize the call");
+
+        // Retrieve the program ID and function name of the main function.
+        let (program_id, function_name) = {
+            let request = authorization.peek_next()?;
+            (*request.program_id(), *request.function_name())
+        };
+        // Ensure the programs in the authorization are loaded.
+        self.hydrate_programs(authorization.to_vec_deque().iter().map(|request| request.program_id()))?;
+
+        macro_rules! logic {
+            ($process:expr, $network:path, $aleo:path) => {{
+                // Prepare the authorization.
+                let authorization = cast_ref!(authorization as Authorization<$network>);
+                // Execute the call.
+                let (_, trace) = $process.execute::<$aleo, _>(authorization.clone(), rng)?;
+                lap!(timer, "Execute the call");
+
+                // Compute the size of the execution.
+                cast_ref!(trace as Trace<N>).execution_size_in_bytes()
+            }};
+        }
+
+        // Compute the size of the execution.
+        let size_in_bytes = process!(self, logic)?;
+        // Compute the minimum execution cost.
+        let result = execution_cost_for_size(&self.process().read(), &program_id, &function_name, size_in_bytes);
+        finish!(timer, "Estimate the execution cost");
+        result
+    }
+
     /// Returns a new fee for the given authorization.
     pub fn execute_fee_authorization<R: Rng + CryptoRng>(
         &self,
@@ -116,6 +181,20 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
         authorization: Authorization<N>,
         query: Option<Query<N, C::BlockStorage>>,
         rng: &mut R,
+    ) -> Result<Execution<N>> {
+        self.execute_authorization_raw_with_checkpoint(authorization, query, || Ok(()), rng)
+    }
+
+    /// Executes a call to the program function for the given authorization.
+    /// The `checkpoint` is invoked between the synthesis, preparation, and proving stages,
+    /// and the execution is aborted if it returns an error.
+    /// Returns the execution.
+    pub(super) fn execute_authorization_raw_with_checkpoint<R: Rng + CryptoRng>(
+        &self,
+        authorization: Authorization<N>,
+        query: Option<Query<N, C::BlockStorage>>,
+        checkpoint: impl Fn() -> Result<()>,
+        rng: &mut R,
     ) -> Result<Execution<N>> {
         let timer = timer!("VM::execute_authorization_raw");
 
@@ -124,6 +203,8 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
             let request = authorization.peek_next()?;
             Locator::new(*request.program_id(), *request.function_name()).to_string()
         };
+        // Ensure the programs in the authorization are loaded.
+        self.hydrate_programs(authorization.to_vec_deque().iter().map(|request| request.program_id()))?;
         // Prepare the query.
         let query = match query {
             Some(query) => query,
@@ -138,10 +219,12 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
                 // Execute the call.
                 let (_, mut trace) = $process.execute::<$aleo, _>(authorization.clone(), rng)?;
                 lap!(timer, "Execute the call");
+                checkpoint()?;
 
                 // Prepare the assignments.
                 cast_mut_ref!(trace as Trace<N>).prepare(query)?;
                 lap!(timer, "Prepare the assignments");
+                checkpoint()?;
 
                 // Compute the proof and construct the execution.
                 let execution = trace.prove_execution::<$aleo, _>(&locator, rng)?;
@@ -166,6 +249,20 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
         authorization: Authorization<N>,
         query: Option<Query<N, C::BlockStorage>>,
         rng: &mut R,
+    ) -> Result<Fee<N>> {
+        self.execute_fee_authorization_raw_with_checkpoint(authorization, query, || Ok(()), rng)
+    }
+
+    /// Executes a call to the program function for the given fee authorization.
+    /// The `checkpoint` is invoked between the synthesis, preparation, and proving stages,
+    /// and the execution is aborted if it returns an error.
+    /// Returns the fee.
+    pub(super) fn execute_fee_authorization_raw_with_checkpoint<R: Rng + CryptoRng>(
+        &self,
+        authorization: Authorization<N>,
+        query: Option<Query<N, C::BlockStorage>>,
+        checkpoint: impl Fn() -> Result<()>,
+        rng: &mut R,
     ) -> Result<Fee<N>> {
         let timer = timer!("VM::execute_fee_authorization_raw");
 
@@ -183,10 +280,12 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
                 // Execute the call.
                 let (_, mut trace) = $process.execute::<$aleo, _>(authorization.clone(), rng)?;
                 lap!(timer, "Execute the call");
+                checkpoint()?;
 
                 // Prepare the assignments.
                 cast_mut_ref!(trace as Trace<N>).prepare(query)?;
                 lap!(timer, "Prepare the assignments");
+                checkpoint()?;
 
                 // Compute the proof and construct the fee.
                 let fee = trace.prove_fee::<$aleo, _>(rng)?;
@@ -431,6 +530,86 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_estimate_execution_fee() {
+        let rng = &mut TestRng::default();
+
+        // Initialize a new caller.
+        let caller_private_key = crate::vm::test_helpers::sample_genesis_private_key(rng);
+        let address = Address::try_from(&caller_private_key).unwrap();
+
+        // Prepare the VM and records.
+        let (vm, _) = prepare_vm(rng).unwrap();
+
+        // Prepare the inputs.
+        let inputs = [
+            Value::<CurrentNetwork>::from_str(&address.to_string()).unwrap(),
+            Value::<CurrentNetwork>::from_str("1u64").unwrap(),
+        ];
+
+        // Estimate the fee.
+        let authorization =
+            vm.authorize(&caller_private_key, "credits.aleo", "transfer_public", inputs.clone(), rng).unwrap();
+        let (estimated_fee, (_, finalize_cost)) = vm.estimate_execution_fee(authorization, None, rng).unwrap();
+        assert!(finalize_cost > 0);
+
+        // Ensure the estimate matches the base fee of the transaction.
+        let transaction = vm
+            .execute(&caller_private_key, ("credits.aleo", "transfer_public"), inputs.into_iter(), None, 0, None, rng)
+            .unwrap();
+        assert_eq!(*transaction.base_fee_amount().unwrap(), estimated_fee);
+    }
+
+    #[test]
+    fn test_estimate_execution_cost() {
+        let rng = &mut TestRng::default();
+
+        // Initialize a new caller.
+        let caller_private_key = crate::vm::test_helpers::sample_genesis_private_key(rng);
+        let caller_view_key = ViewKey::try_from(&caller_private_key).unwrap();
+        let address = Address::try_from(&caller_private_key).unwrap();
+
+        // Prepare the VM and records.
+        let (vm, records) = prepare_vm(rng).unwrap();
+
+        // Fetch the unspent record.
+        let record = records.values().next().unwrap().decrypt(&caller_view_key).unwrap();
+
+        // Prepare the calls, with and without a record input.
+        let calls = [
+            ("transfer_public", vec![
+                Value::<CurrentNetwork>::from_str(&address.to_string()).unwrap(),
+                Value::<CurrentNetwork>::from_str("1u64").unwrap(),
+            ]),
+            ("transfer_private", vec![
+                Value::<CurrentNetwork>::Record(record),
+                Value::<CurrentNetwork>::from_str(&address.to_string()).unwrap(),
+                Value::<CurrentNetwork>::from_str("1u64").unwrap(),
+            ]),
+        ];
+
+        for (function_name, inputs) in calls {
+            // Estimate the cost, without computing the proof.
+            let estimate = vm
+                .estimate_execution_cost(
+                    &caller_private_key,
+                    ("credits.aleo", function_name),
+                    inputs.clone().into_iter(),
+                    rng,
+                )
+                .unwrap();
+
+            // Execute.
+            let transaction = vm
+                .execute(&caller_private_key, ("credits.aleo", function_name), inputs.into_iter(), None, 0, None, rng)
+                .unwrap();
+            let Transaction::Execute(_, execution, _) = &transaction else { panic!("Expected an execute transaction") };
+
+            // Ensure the estimate matches the cost of the execution.
+            assert_eq!(execution_cost(&vm.process().read(), execution).unwrap(), estimate);
+        }
+    }
+
     #[test]
     fn test_transfer_public_transaction_size() {
         let rng = &mut TestRng::default();
@@ -744,7 +923,7 @@ finalize test:
             let program_id = transition.program_id();
             let function_name = transition.function_name();
             // Get the stack.
-            let stack = vm.process().read().get_stack(program_id).unwrap().clone();
+            let stack = vm.process().read().get_stack(program_id).unwrap();
             // Get the finalize block of the transition and sum the cost of each command.
             let cost = match stack.get_function(function_name).unwrap().finalize_logic() {
                 None => 0,
@@ -879,7 +1058,7 @@ finalize test:
             let program_id = transition.program_id();
             let function_name = transition.function_name();
             // Get the stack.
-            let stack = vm.process().read().get_stack(program_id).unwrap().clone();
+            let stack = vm.process().read().get_stack(program_id).unwrap();
             // Get the finalize block of the transition and sum the cost of each command.
             let cost = match stack.get_function(function_name).unwrap().finalize_logic() {
                 None => 0,
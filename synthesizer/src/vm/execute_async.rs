@@ -0,0 +1,283 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#![allow(clippy::too_many_arguments)]
+
+use super::*;
+
+use std::{
+    future::Future,
+    panic::{catch_unwind, AssertUnwindSafe},
+    pin::Pin,
+    sync::atomic::{AtomicBool, Ordering},
+    task::{Context, Poll, Waker},
+};
+
+/// A handle to cancel a pending execution.
+#[derive(Clone, Debug, Default)]
+pub struct CancellationHandle {
+    /// The flag indicating the execution is cancelled.
+    is_cancelled: Arc<AtomicBool>,
+}
+
+impl CancellationHandle {
+    /// Cancels the execution.
+    pub fn cancel(&self) {
+        self.is_cancelled.store(true, Ordering::SeqCst);
+    }
+
+    /// Returns `true` if the execution is cancelled.
+    pub fn is_cancelled(&self) -> bool {
+        self.is_cancelled.load(Ordering::SeqCst)
+    }
+
+    /// Returns an error if the execution is cancelled.
+    fn checkpoint(&self) -> Result<()> {
+        match self.is_cancelled() {
+            true => bail!("The execution was cancelled"),
+            false => Ok(()),
+        }
+    }
+}
+
+/// The state shared between a pending execution and its worker thread.
+struct PendingState<N: Network> {
+    /// The transaction, once the execution completes.
+    result: Option<Result<Transaction<N>>>,
+    /// The waker of the task awaiting the execution.
+    waker: Option<Waker>,
+}
+
+/// A future that resolves to the transaction of an execution, which is computed on a dedicated thread.
+pub struct PendingExecution<N: Network> {
+    /// The state shared with the worker thread.
+    state: Arc<Mutex<PendingState<N>>>,
+}
+
+impl<N: Network> Future for PendingExecution<N> {
+    type Output = Result<Transaction<N>>;
+
+    /// Returns the transaction, if the execution has completed.
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let mut state = self.state.lock();
+        match state.result.take() {
+            Some(result) => Poll::Ready(result),
+            None => {
+                state.waker = Some(cx.waker().clone());
+                Poll::Pending
+            }
+        }
+    }
+}
+
+impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
+    /// Returns a future for a new execute transaction, and a handle to cancel it.
+    ///
+    /// The transaction is computed as in `VM::execute`, on a dedicated thread, so the caller is not blocked.
+    /// The future does not depend on a specific async runtime.
+    ///
+    /// Cancellation is checked between the stages of the execution, i.e. before and after the synthesis,
+    /// before each proof, and before the fee. Once a proof has started, it runs to completion,
+    /// and the execution is aborted at the next stage. A cancelled execution resolves to an error.
+    pub fn execute_async<R: Rng + CryptoRng>(
+        &self,
+        private_key: &PrivateKey<N>,
+        (program_id, function_name): (impl TryInto<ProgramID<N>>, impl TryInto<Identifier<N>>),
+        inputs: impl ExactSizeIterator<Item = impl TryInto<Value<N>>>,
+        fee_record: Option<Record<N, Plaintext<N>>>,
+        priority_fee_in_microcredits: u64,
+        query: Option<Query<N, C::BlockStorage>>,
+        rng: &mut R,
+    ) -> Result<(PendingExecution<N>, CancellationHandle)> {
+        // Prepare the program ID, function name, and inputs, before moving them to the worker thread.
+        let program_id = program_id.try_into().map_err(|_| anyhow!("Invalid program ID"))?;
+        let function_name = function_name.try_into().map_err(|_| anyhow!("Invalid function name"))?;
+        let inputs = inputs
+            .map(|input| input.try_into().map_err(|_| anyhow!("Failed to parse an input")))
+            .collect::<Result<Vec<_>>>()?;
+
+        let state = Arc::new(Mutex::new(PendingState { result: None, waker: None }));
+        let handle = CancellationHandle::default();
+
+        // Spawn the worker thread.
+        let (vm, private_key, worker_state, worker_handle) =
+            (self.clone(), *private_key, state.clone(), handle.clone());
+        let mut rng = StdRng::from_seed(rng.gen());
+        std::thread::spawn(move || {
+            let execute = || {
+                vm.execute_with_checkpoint(
+                    &private_key,
+                    (program_id, function_name),
+                    inputs,
+                    fee_record,
+                    priority_fee_in_microcredits,
+                    query,
+                    &worker_handle,
+                    &mut rng,
+                )
+            };
+            // Compute the transaction, and convert a panic into an error.
+            let result = catch_unwind(AssertUnwindSafe(execute))
+                .unwrap_or_else(|_| Err(anyhow!("The execution of '{program_id}/{function_name}' panicked")));
+            // Store the transaction, and wake the task awaiting it.
+            let waker = {
+                let mut state = worker_state.lock();
+                state.result = Some(result);
+                state.waker.take()
+            };
+            if let Some(waker) = waker {
+                waker.wake();
+            }
+        });
+
+        Ok((PendingExecution { state }, handle))
+    }
+}
+
+impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
+    /// Returns a new execute transaction, checking for cancellation between the stages of the execution.
+    fn execute_with_checkpoint<R: Rng + CryptoRng>(
+        &self,
+        private_key: &PrivateKey<N>,
+        (program_id, function_name): (ProgramID<N>, Identifier<N>),
+        inputs: Vec<Value<N>>,
+        fee_record: Option<Record<N, Plaintext<N>>>,
+        priority_fee_in_microcredits: u64,
+        query: Option<Query<N, C::BlockStorage>>,
+        handle: &CancellationHandle,
+        rng: &mut R,
+    ) -> Result<Transaction<N>> {
+        let checkpoint = || handle.checkpoint();
+
+        // Compute the authorization.
+        checkpoint()?;
+        let authorization = self.authorize(private_key, program_id, function_name, inputs, rng)?;
+        // Determine if a fee is required.
+        let is_fee_required = !authorization.is_split();
+        // Determine if a priority fee is declared.
+        let is_priority_fee_declared = priority_fee_in_microcredits > 0;
+        // Compute the execution.
+        checkpoint()?;
+        let execution =
+            self.execute_authorization_raw_with_checkpoint(authorization, query.clone(), checkpoint, rng)?;
+        // Compute the fee.
+        let fee = match is_fee_required || is_priority_fee_declared {
+            true => {
+                checkpoint()?;
+                // Compute the minimum execution cost.
+                let (minimum_execution_cost, (_, _)) = execution_cost(&self.process().read(), &execution)?;
+                // Compute the execution ID.
+                let execution_id = execution.to_execution_id()?;
+                // Authorize the fee.
+                let authorization = match fee_record {
+                    Some(record) => self.authorize_fee_private(
+                        private_key,
+                        record,
+                        minimum_execution_cost,
+                        priority_fee_in_microcredits,
+                        execution_id,
+                        rng,
+                    )?,
+                    None => self.authorize_fee_public(
+                        private_key,
+                        minimum_execution_cost,
+                        priority_fee_in_microcredits,
+                        execution_id,
+                        rng,
+                    )?,
+                };
+                // Execute the fee.
+                Some(self.execute_fee_authorization_raw_with_checkpoint(authorization, query, checkpoint, rng)?)
+            }
+            false => None,
+        };
+        // Return the execute transaction.
+        Transaction::from_execution(execution, fee)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::vm::test_helpers::{sample_genesis_private_key, sample_vm_with_genesis_block};
+    use console::account::Address;
+
+    use std::{
+        task::Wake,
+        thread::{self, Thread},
+    };
+
+    /// A waker that unparks the thread awaiting the future.
+    struct ThreadWaker(Thread);
+
+    impl Wake for ThreadWaker {
+        fn wake(self: Arc<Self>) {
+            self.0.unpark();
+        }
+    }
+
+    /// Blocks the current thread on the given future.
+    fn block_on<F: Future>(future: F) -> F::Output {
+        let waker = Waker::from(Arc::new(ThreadWaker(thread::current())));
+        let mut cx = Context::from_waker(&waker);
+        let mut future = Box::pin(future);
+        loop {
+            match future.as_mut().poll(&mut cx) {
+                Poll::Ready(output) => return output,
+                Poll::Pending => thread::park(),
+            }
+        }
+    }
+
+    #[test]
+    fn test_execute_async() {
+        let rng = &mut TestRng::default();
+
+        // Initialize the VM.
+        let vm = sample_vm_with_genesis_block(rng);
+        let private_key = sample_genesis_private_key(rng);
+        let address = Address::try_from(&private_key).unwrap();
+
+        // Execute a public transfer.
+        let inputs = [Value::from_str(&address.to_string()).unwrap(), Value::from_str("1u64").unwrap()];
+        let (pending, _) = vm
+            .execute_async(&private_key, ("credits.aleo", "transfer_public"), inputs.iter(), None, 0, None, rng)
+            .unwrap();
+        let transaction = block_on(pending).unwrap();
+        // Ensure the transaction is valid.
+        vm.check_transaction(&transaction, None, rng).unwrap();
+    }
+
+    #[test]
+    fn test_execute_async_cancelled() {
+        let rng = &mut TestRng::default();
+
+        // Initialize the VM.
+        let vm = sample_vm_with_genesis_block(rng);
+        let private_key = sample_genesis_private_key(rng);
+        let address = Address::try_from(&private_key).unwrap();
+
+        // Execute a public transfer, and cancel it.
+        let inputs = [Value::from_str(&address.to_string()).unwrap(), Value::from_str("1u64").unwrap()];
+        let (pending, handle) = vm
+            .execute_async(&private_key, ("credits.aleo", "transfer_public"), inputs.iter(), None, 0, None, rng)
+            .unwrap();
+        handle.cancel();
+        assert!(handle.is_cancelled());
+        // Ensure the execution is aborted.
+        let error = block_on(pending).unwrap_err();
+        assert!(error.to_string().contains("cancelled"), "{error}");
+    }
+}
@@ -15,9 +15,10 @@
 
 use super::*;
 
-use ledger_committee::{MAX_DELEGATORS, MIN_DELEGATOR_STAKE, MIN_VALIDATOR_SELF_STAKE};
 use utilities::cfg_sort_by_cached_key;
 
+use std::borrow::Borrow;
+
 impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
     /// Speculates on the given list of transactions in the VM.
     /// This function aborts all transactions that are not are well-formed or unique.
@@ -41,10 +42,90 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
         candidate_transactions: impl ExactSizeIterator<Item = &'a Transaction<N>>,
         rng: &mut R,
     ) -> Result<(Ratifications<N>, Transactions<N>, Vec<N::TransactionID>, Vec<FinalizeOperation<N>>)> {
+        // Speculate on the transactions.
+        let (ratifications, transactions, aborted_transactions, ratified_finalize_operations) = self
+            .speculate_with_abort_reasons(
+                state,
+                coinbase_reward,
+                candidate_ratifications,
+                candidate_solutions,
+                candidate_transactions,
+                rng,
+            )?;
+        // Return the ratifications, confirmed transactions, aborted transaction IDs, and ratified finalize operations.
+        Ok((
+            ratifications,
+            transactions,
+            aborted_transactions.into_iter().map(|(id, _)| id).collect(),
+            ratified_finalize_operations,
+        ))
+    }
+
+    /// Speculates on the given list of transactions in the VM, and records the reason for each aborted transaction.
+    ///
+    /// Returns the confirmed transactions, aborted transaction IDs with their reasons,
+    /// and finalize operations from pre-ratify and post-ratify.
+    #[inline]
+    pub fn speculate_with_abort_reasons<'a, R: Rng + CryptoRng>(
+        &self,
+        state: FinalizeGlobalState,
+        coinbase_reward: Option<u64>,
+        candidate_ratifications: Vec<Ratify<N>>,
+        candidate_solutions: &Solutions<N>,
+        candidate_transactions: impl ExactSizeIterator<Item = &'a Transaction<N>>,
+        rng: &mut R,
+    ) -> Result<(Ratifications<N>, Transactions<N>, Vec<(N::TransactionID, AbortReason)>, Vec<FinalizeOperation<N>>)>
+    {
         let timer = timer!("VM::speculate");
 
         // Collect the candidate transactions into a vector.
         let candidate_transactions: Vec<_> = candidate_transactions.collect::<Vec<_>>();
+        // Speculate on the candidate transactions, verifying each of them.
+        let (ratifications, confirmed_transactions, aborted_transaction_ids, ratified_finalize_operations) = self
+            .speculate_candidates(
+                state,
+                coinbase_reward,
+                candidate_ratifications,
+                candidate_solutions,
+                candidate_transactions,
+                &IndexSet::new(),
+                rng,
+            )?;
+
+        finish!(timer, "Finished dry-run of the transactions");
+
+        // Return the ratifications, confirmed transactions, aborted transaction IDs with their reasons, and ratified finalize operations.
+        Ok((
+            ratifications,
+            confirmed_transactions.into_iter().collect(),
+            aborted_transaction_ids,
+            ratified_finalize_operations,
+        ))
+    }
+
+    /// Speculates on the given candidate transactions, after aborting the transactions that are not well-formed
+    /// or unique. The transactions in `verified_transaction_ids` are already verified, and are not verified again.
+    ///
+    /// Returns the ratifications, confirmed transactions, aborted transaction IDs with their reasons
+    /// (in the order of the candidate transactions), and finalize operations from pre-ratify and post-ratify.
+    #[allow(clippy::too_many_arguments, clippy::type_complexity)]
+    fn speculate_candidates<R: Rng + CryptoRng>(
+        &self,
+        state: FinalizeGlobalState,
+        coinbase_reward: Option<u64>,
+        candidate_ratifications: Vec<Ratify<N>>,
+        candidate_solutions: &Solutions<N>,
+        candidate_transactions: Vec<&Transaction<N>>,
+        verified_transaction_ids: &IndexSet<N::TransactionID>,
+        rng: &mut R,
+    ) -> Result<(
+        Ratifications<N>,
+        Vec<ConfirmedTransaction<N>>,
+        Vec<(N::TransactionID, AbortReason)>,
+        Vec<FinalizeOperation<N>>,
+    )> {
+        // Ensure the programs used by the candidate transactions are loaded.
+        self.hydrate_transactions(candidate_transactions.iter().copied())?;
         let candidate_transaction_ids: Vec<_> = candidate_transactions.iter().map(|tx| tx.id()).collect();
 
         // Determine if the vm is currently processing the genesis block.
@@ -55,7 +136,9 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
             // If the current state root does not exist in the block store, then the genesis block has not been introduced yet.
             true => (candidate_transactions, vec![]),
             // Verify transactions for all non-genesis cases.
-            false => self.prepare_for_speculate(&candidate_transactions, rng)?,
+            false => {
+                self.prepare_for_speculate_with_verified(&candidate_transactions, verified_transaction_ids, rng)?
+            }
         };
 
         // Performs a **dry-run** over the list of ratifications, solutions, and transactions.
@@ -69,31 +152,109 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
             )?;
 
         // Get the aborted transaction ids.
-        let verification_aborted_transaction_ids = verification_aborted_transactions.iter().map(|(tx, e)| (tx.id(), e));
-        let speculation_aborted_transaction_ids = speculation_aborted_transactions.iter().map(|(tx, e)| (tx.id(), e));
-        let unordered_aborted_transaction_ids: IndexMap<N::TransactionID, &String> =
+        let verification_aborted_transaction_ids =
+            verification_aborted_transactions.iter().map(|(tx, reason, e)| (tx.id(), (*reason, e)));
+        let speculation_aborted_transaction_ids =
+            speculation_aborted_transactions.iter().map(|(tx, reason, e)| (tx.id(), (*reason, e)));
+        let unordered_aborted_transaction_ids: IndexMap<N::TransactionID, (AbortReason, &String)> =
             verification_aborted_transaction_ids.chain(speculation_aborted_transaction_ids).collect();
 
         // Filter and order the aborted transaction ids according to candidate_transactions
         let aborted_transaction_ids: Vec<_> = candidate_transaction_ids
             .into_iter()
             .filter_map(|tx_id| {
-                unordered_aborted_transaction_ids.get(&tx_id).map(|error| {
+                unordered_aborted_transaction_ids.get(&tx_id).map(|(reason, error)| {
                     warn!("Speculation safely aborted a transaction - {error} ({tx_id})");
-                    tx_id
+                    (tx_id, *reason)
                 })
             })
             .collect();
 
-        finish!(timer, "Finished dry-run of the transactions");
+        Ok((ratifications, confirmed_transactions, aborted_transaction_ids, ratified_finalize_operations))
+    }
 
-        // Return the ratifications, confirmed transactions, aborted transaction IDs, and ratified finalize operations.
-        Ok((
-            ratifications,
-            confirmed_transactions.into_iter().collect(),
-            aborted_transaction_ids,
-            ratified_finalize_operations,
-        ))
+    /// Speculates on the given transactions in the VM, and streams the outcome of each transaction
+    /// to `on_outcome`, in the order of the candidate transactions.
+    ///
+    /// Unlike `VM::speculate`, the transactions are verified and speculated on in bounded windows,
+    /// and the confirmed and aborted transactions are not retained, so the memory used is bounded by
+    /// the size of a window, rather than the size of the block. This allows a block of many large
+    /// transactions, e.g. deployments, to be built from transactions that are loaded on demand.
+    ///
+    /// Note: The candidate transactions are iterated twice, first to verify them, and then to speculate on them,
+    /// so each clone of `candidate_transactions` must yield the same transactions in the same order.
+    /// A transaction that duplicates one in an earlier window is aborted during speculation, not verification.
+    ///
+    /// Returns the ratifications, and finalize operations from pre-ratify and post-ratify.
+    #[inline]
+    pub fn speculate_streaming<T: Borrow<Transaction<N>>, R: Rng + CryptoRng>(
+        &self,
+        state: FinalizeGlobalState,
+        coinbase_reward: Option<u64>,
+        candidate_ratifications: Vec<Ratify<N>>,
+        candidate_solutions: &Solutions<N>,
+        candidate_transactions: impl Iterator<Item = T> + Clone,
+        mut on_outcome: impl FnMut(SpeculationOutcome<N>) -> Result<()>,
+        rng: &mut R,
+    ) -> Result<(Ratifications<N>, Vec<FinalizeOperation<N>>)> {
+        let timer = timer!("VM::speculate_streaming");
+
+        // Determine if the vm is currently processing the genesis block.
+        let is_genesis =
+            self.block_store().find_block_height_from_state_root(self.block_store().current_state_root())?.is_none();
+
+        // If the transactions are not part of the genesis block, verify the transactions one window at a time,
+        // and retain the abort reason of each invalid transaction.
+        let mut verification_aborted = IndexMap::new();
+        if !is_genesis {
+            let mut transactions = candidate_transactions.clone();
+            loop {
+                // Retrieve the next window of transactions.
+                let window = Self::next_speculation_window(&mut transactions);
+                if window.is_empty() {
+                    break;
+                }
+                let window = window.iter().map(Borrow::<Transaction<N>>::borrow).collect::<Vec<_>>();
+                // Ensure the programs used by the transactions are loaded.
+                self.hydrate_transactions(window.iter().copied())?;
+                // Verify the transactions, and retain the aborted transactions.
+                let (_, aborted) = self.prepare_for_speculate(&window, rng)?;
+                verification_aborted.extend(aborted.into_iter().map(|(tx, reason, error)| (tx.id(), (reason, error))));
+            }
+            lap!(timer, "Verified the transactions");
+        }
+
+        // Prepare the windows of transactions to speculate on, loading the programs used by each window.
+        let mut transactions = candidate_transactions;
+        let windows = std::iter::from_fn(|| {
+            let window = Self::next_speculation_window(&mut transactions);
+            match window.is_empty() {
+                true => None,
+                false => {
+                    Some(self.hydrate_transactions(window.iter().map(Borrow::<Transaction<N>>::borrow)).map(|_| window))
+                }
+            }
+        });
+
+        // Performs a **dry-run** over the list of ratifications, solutions, and transactions.
+        let result = self.atomic_speculate_windows(
+            state,
+            coinbase_reward,
+            candidate_ratifications,
+            candidate_solutions,
+            windows,
+            &verification_aborted,
+            |transaction, outcome| match outcome {
+                Ok(confirmed_transaction) => on_outcome(SpeculationOutcome::Confirmed(confirmed_transaction)),
+                Err((reason, error)) => {
+                    warn!("Speculation safely aborted a transaction - {error} ({})", transaction.id());
+                    on_outcome(SpeculationOutcome::Aborted(transaction.id(), reason))
+                }
+            },
+        );
+
+        finish!(timer, "Finished dry-run of the transactions");
+        result
     }
 
     /// Checks the speculation on the given transactions in the VM.
@@ -109,27 +270,112 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
         transactions: &Transactions<N>,
         rng: &mut R,
     ) -> Result<Vec<FinalizeOperation<N>>> {
-        let timer = timer!("VM::check_speculate");
+        // Ensure each transaction is well-formed and unique.
+        // NOTE: We perform the transaction checks here prior to `atomic_speculate` because we must
+        // ensure that the `Fee` transactions are valid. We can't unify the transaction checks in `atomic_speculate`
+        // because we run speculation on the unconfirmed variant of the transactions.
+        self.check_confirmed_transactions(transactions, &VerifiedTransactions::default(), rng)?;
+        // Ensure the speculation over the transactions is correct.
+        self.check_speculate_without_verification(state, ratifications, solutions, transactions, None, &[], rng)
+    }
+
+    /// Ensures each of the given confirmed transactions is well-formed and unique.
+    /// The proofs of the transactions that consensus has already fully verified are trusted,
+    /// under the policy of `verified`.
+    #[inline]
+    pub fn check_confirmed_transactions<R: Rng + CryptoRng>(
+        &self,
+        transactions: &Transactions<N>,
+        verified: &VerifiedTransactions<N>,
+        rng: &mut R,
+    ) -> Result<()> {
+        let timer = timer!("VM::check_confirmed_transactions");
+
+        // Ensure the programs used by the transactions are loaded.
+        self.hydrate_transactions(transactions.iter().map(|transaction| transaction.deref()))?;
 
         // Retrieve the transactions and their rejected IDs.
         let transactions_and_rejected_ids = cfg_iter!(transactions)
             .map(|transaction| transaction.to_rejected_id().map(|rejected_id| (transaction.deref(), rejected_id)))
             .collect::<Result<Vec<_>>>()?;
         // Ensure each transaction is well-formed and unique.
-        // NOTE: We perform the transaction checks here prior to `atomic_speculate` because we must
-        // ensure that the `Fee` transactions are valid. We can't unify the transaction checks in `atomic_speculate`
-        // because we run speculation on the unconfirmed variant of the transactions.
-        self.check_transactions(&transactions_and_rejected_ids, rng)?;
+        self.check_transactions_with_verified(&transactions_and_rejected_ids, verified, rng)?;
+
+        finish!(timer, "Checked the transactions");
+        Ok(())
+    }
+
+    /// Checks the speculation on the given transactions in the VM, without verifying the transactions.
+    ///
+    /// If the candidate transactions are given, i.e. the confirmed and aborted transactions of the block
+    /// in the order they were speculated on, then the aborted transactions are speculated on as well,
+    /// and the reasons they abort must match `aborted_transaction_reasons`, in order.
+    /// Otherwise, the confirmed transactions are speculated on, and none of them may abort.
+    ///
+    /// Note: This does *not* ensure the transactions are well-formed and unique. The caller must do so
+    /// with `VM::check_confirmed_transactions`, unless the transactions were already verified, e.g. in the mempool.
+    /// The aborted transactions are verified here, as the reasons they abort depend on it.
+    ///
+    /// Returns the finalize operations from pre-ratify and post-ratify.
+    #[inline]
+    #[allow(clippy::too_many_arguments)]
+    pub fn check_speculate_without_verification<R: Rng + CryptoRng>(
+        &self,
+        state: FinalizeGlobalState,
+        ratifications: &Ratifications<N>,
+        solutions: &Solutions<N>,
+        transactions: &Transactions<N>,
+        candidate_transactions: Option<&[&Transaction<N>]>,
+        aborted_transaction_reasons: &[(N::TransactionID, AbortReason)],
+        rng: &mut R,
+    ) -> Result<Vec<FinalizeOperation<N>>> {
+        let timer = timer!("VM::check_speculate");
+
+        // Ensure the programs used by the transactions are loaded.
+        self.hydrate_transactions(transactions.iter().map(|transaction| transaction.deref()))?;
 
         // Reconstruct the candidate ratifications to verify the speculation.
         let candidate_ratifications = ratifications.iter().cloned().collect::<Vec<_>>();
         // Reconstruct the unconfirmed transactions to verify the speculation.
-        let candidate_transactions =
+        let unconfirmed_transactions =
             transactions.iter().map(|confirmed| confirmed.to_unconfirmed_transaction()).collect::<Result<Vec<_>>>()?;
 
         // Performs a **dry-run** over the list of ratifications, solutions, and transactions.
         let (speculate_ratifications, confirmed_transactions, aborted_transactions, ratified_finalize_operations) =
-            self.atomic_speculate(state, None, candidate_ratifications, solutions, candidate_transactions.iter())?;
+            match candidate_transactions {
+                // Speculate on the candidate transactions, verifying only the aborted transactions.
+                Some(candidate_transactions) => {
+                    let verified_transaction_ids = unconfirmed_transactions.iter().map(|tx| tx.id()).collect();
+                    self.speculate_candidates(
+                        state,
+                        None,
+                        candidate_ratifications,
+                        solutions,
+                        candidate_transactions.to_vec(),
+                        &verified_transaction_ids,
+                        rng,
+                    )?
+                }
+                // Speculate on the confirmed transactions.
+                None => {
+                    // Ensure there are no aborted transaction reasons to check.
+                    ensure!(
+                        aborted_transaction_reasons.is_empty(),
+                        "The candidate transactions are required to check the aborted transaction reasons"
+                    );
+                    let (ratifications, confirmed_transactions, aborted_transactions, ratified_finalize_operations) =
+                        self.atomic_speculate(
+                            state,
+                            None,
+                            candidate_ratifications,
+                            solutions,
+                            unconfirmed_transactions.iter(),
+                        )?;
+                    let aborted_transactions =
+                        aborted_transactions.into_iter().map(|(tx, reason, _)| (tx.id(), reason)).collect();
+                    (ratifications, confirmed_transactions, aborted_transactions, ratified_finalize_operations)
+                }
+            };
 
         // Ensure the ratifications after speculation match.
         if ratifications != &speculate_ratifications {
@@ -139,10 +385,14 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
         if transactions != &confirmed_transactions.into_iter().collect() {
             bail!("The transactions after speculation do not match the transactions in the block");
         }
-        // Ensure there are no aborted transaction IDs from this speculation.
-        // Note: There should be no aborted transactions, because we are checking a block,
-        // where any aborted transactions should be in the aborted transaction ID list, not in transactions.
-        ensure!(aborted_transactions.is_empty(), "Aborted transactions found in the block (from speculation)");
+        // Ensure the aborted transactions from this speculation match the given aborted transaction reasons.
+        // Note: If the candidate transactions are not given, there should be no aborted transactions, because we are
+        // checking a block, where any aborted transactions should be in the aborted transaction ID list,
+        // not in transactions.
+        ensure!(
+            aborted_transactions == aborted_transaction_reasons,
+            "The aborted transactions after speculation do not match the aborted transaction reasons in the block"
+        );
 
         finish!(timer, "Finished dry-run of the transactions");
 
@@ -163,12 +413,34 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
     ) -> Result<Vec<FinalizeOperation<N>>> {
         let timer = timer!("VM::finalize");
 
+        // Ensure the programs used by the transactions are loaded.
+        self.hydrate_transactions(transactions.iter().map(|transaction| transaction.deref()))?;
+
         // Performs a **real-run** of finalize over the list of ratifications, solutions, and transactions.
         let ratified_finalize_operations = self.atomic_finalize(state, ratifications, solutions, transactions)?;
 
         finish!(timer, "Finished real-run of finalize");
         Ok(ratified_finalize_operations)
     }
+
+    /// Finalizes the given transactions into the VM, and records the trace of the finalize logic,
+    /// i.e. every mapping read and write, `rand.chacha` draw, and branch taken, in order.
+    /// The traces of two nodes that diverge on the same block can be compared to locate the divergence.
+    ///
+    /// Returns the finalize operations from pre-ratify and post-ratify, and the finalize trace.
+    #[inline]
+    pub fn finalize_with_trace(
+        &self,
+        state: FinalizeGlobalState,
+        ratifications: &Ratifications<N>,
+        solutions: &Solutions<N>,
+        transactions: &Transactions<N>,
+    ) -> Result<(Vec<FinalizeOperation<N>>, FinalizeTrace)> {
+        // Record the finalize events on this thread, while the transactions are finalized.
+        let recorder = FinalizeRecorder::start();
+        let ratified_finalize_operations = self.finalize(state, ratifications, solutions, transactions)?;
+        Ok((ratified_finalize_operations, recorder.finish()))
+    }
 }
 
 impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
@@ -200,9 +472,60 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
     ) -> Result<(
         Ratifications<N>,
         Vec<ConfirmedTransaction<N>>,
-        Vec<(Transaction<N>, String)>,
+        Vec<(Transaction<N>, AbortReason, String)>,
         Vec<FinalizeOperation<N>>,
     )> {
+        // Initialize a list of the confirmed transactions.
+        let mut confirmed = Vec::with_capacity(transactions.len());
+        // Initialize a list of the aborted transactions.
+        let mut aborted = Vec::new();
+
+        // Speculate on the transactions as a single window, and collect the outcomes.
+        let window = transactions.collect::<Vec<_>>();
+        let (ratifications, ratified_finalize_operations) = self.atomic_speculate_windows(
+            state,
+            coinbase_reward,
+            ratifications,
+            solutions,
+            std::iter::once(Ok(window)),
+            &IndexMap::new(),
+            |transaction, outcome| {
+                match outcome {
+                    Ok(confirmed_transaction) => confirmed.push(confirmed_transaction),
+                    Err((reason, error)) => aborted.push((transaction.clone(), reason, error)),
+                }
+                Ok(())
+            },
+        )?;
+
+        Ok((ratifications, confirmed, aborted, ratified_finalize_operations))
+    }
+
+    /// Performs atomic speculation over the given windows of transactions, in a single atomic batch.
+    /// The outcome of each transaction is passed to `on_outcome` at the end of its window, in order,
+    /// and the transactions are not retained, so the caller may drop each window once it is speculated on.
+    ///
+    /// The transactions in `verification_aborted` were aborted before speculation, e.g. as they failed
+    /// verification, and are passed to `on_outcome` with their abort reason, in order.
+    ///
+    /// Note: The process lock is released between windows, so each window may be prepared,
+    /// e.g. by loading its programs, while the atomic batch is in progress.
+    ///
+    /// Returns the ratifications, and finalize operations from pre-ratify and post-ratify.
+    fn atomic_speculate_windows<T, F>(
+        &self,
+        state: FinalizeGlobalState,
+        coinbase_reward: Option<u64>,
+        ratifications: Vec<Ratify<N>>,
+        solutions: &Solutions<N>,
+        windows: impl Iterator<Item = Result<Vec<T>>>,
+        verification_aborted: &IndexMap<N::TransactionID, (AbortReason, String)>,
+        mut on_outcome: F,
+    ) -> Result<(Ratifications<N>, Vec<FinalizeOperation<N>>)>
+    where
+        T: Borrow<Transaction<N>>,
+        F: FnMut(&Transaction<N>, Result<ConfirmedTransaction<N>, (AbortReason, String)>) -> Result<()>,
+    {
         // Acquire the atomic lock, which is needed to ensure this function is not called concurrently
         // with other `atomic_finalize!` macro calls, which will cause a `bail!` to be triggered erroneously.
         // Note: This lock must be held for the entire scope of the call to `atomic_finalize!`.
@@ -212,8 +535,6 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
 
         // Retrieve the number of solutions.
         let num_solutions = solutions.len();
-        // Retrieve the number of transactions.
-        let num_transactions = transactions.len();
 
         // Perform the finalize operation on the preset finalize mode.
         atomic_finalize!(self.finalize_store(), FinalizeMode::DryRun, {
@@ -226,15 +547,6 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
                 ));
             }
 
-            // Ensure the number of transactions does not exceed the maximum.
-            if num_transactions > Transactions::<N>::MAX_ABORTED_TRANSACTIONS {
-                // Note: This will abort the entire atomic batch.
-                return Err(format!(
-                    "Too many transactions in the block - {num_transactions} (max: {})",
-                    Transactions::<N>::MAX_ABORTED_TRANSACTIONS
-                ));
-            }
-
             // Initialize an iterator for ratifications before finalize.
             let pre_ratifications = ratifications.iter().filter(|r| match r {
                 Ratify::Genesis(_, _, _) => true,
@@ -263,15 +575,12 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
 
             /* Perform the atomic finalize over the transactions. */
 
-            // Acquire the write lock on the process.
-            // Note: Due to the highly-sensitive nature of processing all `finalize` calls,
-            // we choose to acquire the write lock for the entire duration of this atomic batch.
-            let process = self.process.write();
-
-            // Initialize a list of the confirmed transactions.
-            let mut confirmed = Vec::with_capacity(num_transactions);
-            // Initialize a list of the aborted transactions.
-            let mut aborted = Vec::new();
+            // Initialize a counter for the transactions.
+            let mut num_transactions = 0usize;
+            // Initialize a counter for the aborted transactions.
+            let mut num_aborted = 0usize;
+            // Initialize the sum of the priority fees of the confirmed transactions.
+            let mut transaction_fees = 0u64;
             // Initialize a list of the successful deployments.
             let mut deployments = IndexSet::new();
             // Initialize a counter for the confirmed transaction index.
@@ -287,176 +596,254 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
             // Initialize the list of deployment payers.
             let mut deployment_payers: IndexSet<Address<N>> = IndexSet::new();
 
-            // Finalize the transactions.
-            'outer: for transaction in transactions {
-                // Ensure the number of confirmed transactions does not exceed the maximum.
-                // Upon reaching the maximum number of confirmed transactions, all remaining transactions are aborted.
-                if confirmed.len() >= Self::MAXIMUM_CONFIRMED_TRANSACTIONS {
-                    // Store the aborted transaction.
-                    aborted.push((transaction.clone(), "Exceeds block transaction limit".to_string()));
-                    // Continue to the next transaction.
-                    continue 'outer;
-                }
+            // Finalize the transactions, one window at a time.
+            for window in windows {
+                // Retrieve the transactions in the window.
+                let window = match window {
+                    Ok(window) => window,
+                    // Note: This will abort the entire atomic batch.
+                    Err(e) => return Err(format!("Failed to prepare the transactions - {e}")),
+                };
 
-                // Determine if the transaction should be aborted.
-                if let Some(reason) = self.should_abort_transaction(
-                    transaction,
-                    &transition_ids,
-                    &input_ids,
-                    &output_ids,
-                    &tpks,
-                    &deployment_payers,
-                ) {
-                    // Store the aborted transaction.
-                    aborted.push((transaction.clone(), reason));
-                    // Continue to the next transaction.
-                    continue 'outer;
-                }
+                // Acquire the write lock on the process.
+                // Note: Due to the highly-sensitive nature of processing all `finalize` calls,
+                // we choose to acquire the write lock for the entire duration of the window.
+                let process = self.process.write();
 
-                // Process the transaction in an isolated atomic batch.
-                // - If the transaction succeeds, the finalize operations are stored.
-                // - If the transaction fails, the atomic batch is aborted and no finalize operations are stored.
-                let outcome = match transaction {
-                    // The finalize operation here involves appending the 'stack',
-                    // and adding the program to the finalize tree.
-                    Transaction::Deploy(_, program_owner, deployment, fee) => {
-                        // Define the closure for processing a rejected deployment.
-                        let process_rejected_deployment =
-                            |fee: &Fee<N>,
-                             deployment: Deployment<N>|
-                             -> Result<Result<ConfirmedTransaction<N>, String>> {
-                                process
-                                    .finalize_fee(state, store, fee)
-                                    .and_then(|finalize| {
-                                        Transaction::from_fee(fee.clone()).map(|fee_tx| (fee_tx, finalize))
-                                    })
-                                    .map(|(fee_tx, finalize)| {
-                                        let rejected = Rejected::new_deployment(*program_owner, deployment);
-                                        ConfirmedTransaction::rejected_deploy(counter, fee_tx, rejected, finalize)
-                                            .map_err(|e| e.to_string())
-                                    })
-                            };
+                // Initialize a list of the outcomes of the transactions in the window.
+                let mut outcomes = Vec::with_capacity(window.len());
 
-                        // Check if the program has already been deployed in this block.
-                        match deployments.contains(deployment.program_id()) {
-                            // If the program has already been deployed, construct the rejected deploy transaction.
-                            true => match process_rejected_deployment(fee, *deployment.clone()) {
-                                Ok(result) => result,
-                                Err(error) => {
-                                    // Note: On failure, skip this transaction, and continue speculation.
-                                    #[cfg(debug_assertions)]
-                                    eprintln!("Failed to finalize the fee in a rejected deploy - {error}");
-                                    // Store the aborted transaction.
-                                    aborted.push((transaction.clone(), error.to_string()));
-                                    // Continue to the next transaction.
-                                    continue 'outer;
-                                }
-                            },
-                            // If the program has not yet been deployed, attempt to deploy it.
-                            false => match process.finalize_deployment(state, store, deployment, fee) {
-                                // Construct the accepted deploy transaction.
-                                Ok((_, finalize)) => {
-                                    // Add the program id to the list of deployments.
-                                    deployments.insert(*deployment.program_id());
-                                    ConfirmedTransaction::accepted_deploy(counter, transaction.clone(), finalize)
-                                        .map_err(|e| e.to_string())
-                                }
-                                // Construct the rejected deploy transaction.
-                                Err(_error) => match process_rejected_deployment(fee, *deployment.clone()) {
+                'outer: for transaction in window.iter().map(Borrow::<Transaction<N>>::borrow) {
+                    // Increment the transaction counter.
+                    num_transactions = num_transactions.saturating_add(1);
+                    // Ensure the number of transactions does not exceed the maximum.
+                    if num_transactions > Transactions::<N>::MAX_ABORTED_TRANSACTIONS {
+                        // Note: This will abort the entire atomic batch.
+                        return Err(format!(
+                            "Too many transactions in the block - {num_transactions} (max: {})",
+                            Transactions::<N>::MAX_ABORTED_TRANSACTIONS
+                        ));
+                    }
+
+                    // If the transaction was aborted prior to speculation, store the aborted transaction.
+                    if let Some((reason, error)) = verification_aborted.get(&transaction.id()) {
+                        outcomes.push((transaction, Err((*reason, error.clone()))));
+                        // Continue to the next transaction.
+                        continue 'outer;
+                    }
+
+                    // Ensure the number of confirmed transactions does not exceed the maximum.
+                    // Upon reaching the maximum number of confirmed transactions,
+                    // all remaining transactions are aborted.
+                    if counter as usize >= Self::MAXIMUM_CONFIRMED_TRANSACTIONS {
+                        // Store the aborted transaction.
+                        outcomes.push((
+                            transaction,
+                            Err((AbortReason::ExceedsBlockLimit, "Exceeds block transaction limit".to_string())),
+                        ));
+                        // Continue to the next transaction.
+                        continue 'outer;
+                    }
+
+                    // Determine if the transaction should be aborted.
+                    if let Some((reason, error)) = self.should_abort_transaction(
+                        transaction,
+                        &transition_ids,
+                        &input_ids,
+                        &output_ids,
+                        &tpks,
+                        &deployment_payers,
+                    ) {
+                        // Store the aborted transaction.
+                        outcomes.push((transaction, Err((reason, error))));
+                        // Continue to the next transaction.
+                        continue 'outer;
+                    }
+
+                    // Process the transaction in an isolated atomic batch.
+                    // - If the transaction succeeds, the finalize operations are stored.
+                    // - If the transaction fails, the atomic batch is aborted and no finalize operations are stored.
+                    let outcome = match transaction {
+                        // The finalize operation here involves appending the 'stack',
+                        // and adding the program to the finalize tree.
+                        Transaction::Deploy(_, program_owner, deployment, fee) => {
+                            // Define the closure for processing a rejected deployment.
+                            let process_rejected_deployment =
+                                |fee: &Fee<N>,
+                                 deployment: Deployment<N>|
+                                 -> Result<Result<ConfirmedTransaction<N>, String>> {
+                                    process
+                                        .finalize_fee(state, store, fee)
+                                        .and_then(|finalize| {
+                                            Transaction::from_fee(fee.clone()).map(|fee_tx| (fee_tx, finalize))
+                                        })
+                                        .map(|(fee_tx, finalize)| {
+                                            let rejected = Rejected::new_deployment(*program_owner, deployment);
+                                            ConfirmedTransaction::rejected_deploy(counter, fee_tx, rejected, finalize)
+                                                .map_err(|e| e.to_string())
+                                        })
+                                };
+
+                            // Check if the program has already been deployed in this block.
+                            match deployments.contains(deployment.program_id()) {
+                                // If the program has already been deployed, construct the rejected deploy transaction.
+                                true => match process_rejected_deployment(fee, *deployment.clone()) {
                                     Ok(result) => result,
                                     Err(error) => {
                                         // Note: On failure, skip this transaction, and continue speculation.
                                         #[cfg(debug_assertions)]
                                         eprintln!("Failed to finalize the fee in a rejected deploy - {error}");
                                         // Store the aborted transaction.
-                                        aborted.push((transaction.clone(), error.to_string()));
+                                        outcomes.push((
+                                            transaction,
+                                            Err((AbortReason::FeeFinalizeFailure, error.to_string())),
+                                        ));
                                         // Continue to the next transaction.
                                         continue 'outer;
                                     }
                                 },
-                            },
-                        }
-                    }
-                    // The finalize operation here involves calling 'update_key_value',
-                    // and update the respective leaves of the finalize tree.
-                    Transaction::Execute(_, execution, fee) => {
-                        // Determine if the transaction is safe for execution, and proceed to execute it.
-                        match Self::prepare_for_execution(store, execution)
-                            .and_then(|_| process.finalize_execution(state, store, execution, fee.as_ref()))
-                        {
-                            // Construct the accepted execute transaction.
-                            Ok(finalize) => {
-                                ConfirmedTransaction::accepted_execute(counter, transaction.clone(), finalize)
-                                    .map_err(|e| e.to_string())
-                            }
-                            // Construct the rejected execute transaction.
-                            Err(_error) => match fee {
-                                // Finalize the fee, to ensure it is valid.
-                                Some(fee) => {
-                                    match process.finalize_fee(state, store, fee).and_then(|finalize| {
-                                        Transaction::from_fee(fee.clone()).map(|fee_tx| (fee_tx, finalize))
-                                    }) {
-                                        Ok((fee_tx, finalize)) => {
-                                            // Construct the rejected execution.
-                                            let rejected = Rejected::new_execution(execution.clone());
-                                            // Construct the rejected execute transaction.
-                                            ConfirmedTransaction::rejected_execute(counter, fee_tx, rejected, finalize)
-                                                .map_err(|e| e.to_string())
-                                        }
+                                // If the program has not yet been deployed, attempt to deploy it.
+                                false => match process.finalize_deployment(state, store, deployment, fee) {
+                                    // Construct the accepted deploy transaction.
+                                    Ok((_, finalize)) => {
+                                        // Add the program id to the list of deployments.
+                                        deployments.insert(*deployment.program_id());
+                                        ConfirmedTransaction::accepted_deploy(counter, transaction.clone(), finalize)
+                                            .map_err(|e| e.to_string())
+                                    }
+                                    // Construct the rejected deploy transaction.
+                                    Err(_error) => match process_rejected_deployment(fee, *deployment.clone()) {
+                                        Ok(result) => result,
                                         Err(error) => {
                                             // Note: On failure, skip this transaction, and continue speculation.
                                             #[cfg(debug_assertions)]
-                                            eprintln!("Failed to finalize the fee in a rejected execute - {error}");
+                                            eprintln!("Failed to finalize the fee in a rejected deploy - {error}");
                                             // Store the aborted transaction.
-                                            aborted.push((transaction.clone(), error.to_string()));
+                                            outcomes.push((
+                                                transaction,
+                                                Err((AbortReason::FeeFinalizeFailure, error.to_string())),
+                                            ));
                                             // Continue to the next transaction.
                                             continue 'outer;
                                         }
-                                    }
+                                    },
+                                },
+                            }
+                        }
+                        // The finalize operation here involves calling 'update_key_value',
+                        // and update the respective leaves of the finalize tree.
+                        Transaction::Execute(_, execution, fee) => {
+                            // Determine if the transaction is safe for execution, and proceed to execute it.
+                            match Self::prepare_for_execution(store, execution)
+                                .and_then(|_| process.finalize_execution(state, store, execution, fee.as_ref()))
+                            {
+                                // Construct the accepted execute transaction.
+                                Ok(finalize) => {
+                                    ConfirmedTransaction::accepted_execute(counter, transaction.clone(), finalize)
+                                        .map_err(|e| e.to_string())
                                 }
-                                // This is a foundational bug - the caller is violating protocol rules.
-                                // Note: This will abort the entire atomic batch.
-                                None => Err("Rejected execute transaction has no fee".to_string()),
-                            },
+                                // Construct the rejected execute transaction.
+                                Err(_error) => match fee {
+                                    // Finalize the fee, to ensure it is valid.
+                                    Some(fee) => {
+                                        match process.finalize_fee(state, store, fee).and_then(|finalize| {
+                                            Transaction::from_fee(fee.clone()).map(|fee_tx| (fee_tx, finalize))
+                                        }) {
+                                            Ok((fee_tx, finalize)) => {
+                                                // Construct the rejected execution.
+                                                let rejected = Rejected::new_execution(execution.clone());
+                                                // Construct the rejected execute transaction.
+                                                ConfirmedTransaction::rejected_execute(
+                                                    counter, fee_tx, rejected, finalize,
+                                                )
+                                                .map_err(|e| e.to_string())
+                                            }
+                                            Err(error) => {
+                                                // Note: On failure, skip this transaction, and continue speculation.
+                                                #[cfg(debug_assertions)]
+                                                eprintln!("Failed to finalize the fee in a rejected execute - {error}");
+                                                // Store the aborted transaction.
+                                                outcomes.push((
+                                                    transaction,
+                                                    Err((AbortReason::FeeFinalizeFailure, error.to_string())),
+                                                ));
+                                                // Continue to the next transaction.
+                                                continue 'outer;
+                                            }
+                                        }
+                                    }
+                                    // This is a foundational bug - the caller is violating protocol rules.
+                                    // Note: This will abort the entire atomic batch.
+                                    None => Err("Rejected execute transaction has no fee".to_string()),
+                                },
+                            }
+                        }
+                        // There are no finalize operations here.
+                        // Note: This will abort the entire atomic batch.
+                        Transaction::Fee(..) => Err("Cannot speculate on a fee transaction".to_string()),
+                    };
+                    lap!(timer, "Speculated on transaction '{}'", transaction.id());
+
+                    match outcome {
+                        // If the transaction succeeded, store it and continue to the next transaction.
+                        Ok(confirmed_transaction) => {
+                            // Add the transition IDs to the set of produced transition IDs.
+                            transition_ids.extend(confirmed_transaction.transaction().transition_ids());
+                            // Add the input IDs to the set of spent input IDs.
+                            input_ids.extend(confirmed_transaction.transaction().input_ids());
+                            // Add the output IDs to the set of produced output IDs.
+                            output_ids.extend(confirmed_transaction.transaction().output_ids());
+                            // Add the transition public keys to the set of produced transition public keys.
+                            tpks.extend(confirmed_transaction.transaction().transition_public_keys());
+                            // Add any public deployment payer to the set of deployment payers.
+                            if let Transaction::Deploy(_, _, _, fee) = confirmed_transaction.transaction() {
+                                fee.payer().map(|payer| deployment_payers.insert(payer));
+                            }
+                            // Store the confirmed transaction.
+                            outcomes.push((transaction, Ok(confirmed_transaction)));
+                            // Increment the transaction index counter.
+                            counter = counter.saturating_add(1);
+                        }
+                        // If the transaction failed, abort the entire batch.
+                        Err(error) => {
+                            eprintln!("Critical bug in speculate: {error}\n\n{transaction}");
+                            // Note: This will abort the entire atomic batch.
+                            return Err(format!("Failed to speculate on transaction - {error}"));
                         }
                     }
-                    // There are no finalize operations here.
-                    // Note: This will abort the entire atomic batch.
-                    Transaction::Fee(..) => Err("Cannot speculate on a fee transaction".to_string()),
-                };
-                lap!(timer, "Speculated on transaction '{}'", transaction.id());
+                }
 
-                match outcome {
-                    // If the transaction succeeded, store it and continue to the next transaction.
-                    Ok(confirmed_transaction) => {
-                        // Add the transition IDs to the set of produced transition IDs.
-                        transition_ids.extend(confirmed_transaction.transaction().transition_ids());
-                        // Add the input IDs to the set of spent input IDs.
-                        input_ids.extend(confirmed_transaction.transaction().input_ids());
-                        // Add the output IDs to the set of produced output IDs.
-                        output_ids.extend(confirmed_transaction.transaction().output_ids());
-                        // Add the transition public keys to the set of produced transition public keys.
-                        tpks.extend(confirmed_transaction.transaction().transition_public_keys());
-                        // Add any public deployment payer to the set of deployment payers.
-                        if let Transaction::Deploy(_, _, _, fee) = confirmed_transaction.transaction() {
-                            fee.payer().map(|payer| deployment_payers.insert(payer));
+                // Release the process lock, before passing the outcomes of the window to the caller.
+                drop(process);
+
+                // Pass the outcomes of the transactions in the window to the caller, in order.
+                for (transaction, outcome) in outcomes {
+                    match &outcome {
+                        // If there is a coinbase reward, add the priority fee to the transaction fees.
+                        Ok(confirmed_transaction) if coinbase_reward.is_some() => {
+                            let Some(fees) = confirmed_transaction
+                                .priority_fee_amount()
+                                .ok()
+                                .and_then(|fee| transaction_fees.checked_add(*fee))
+                            else {
+                                // Note: This will abort the entire atomic batch.
+                                return Err("Failed to calculate the transaction fees during speculation".to_string());
+                            };
+                            transaction_fees = fees;
                         }
-                        // Store the confirmed transaction.
-                        confirmed.push(confirmed_transaction);
-                        // Increment the transaction index counter.
-                        counter = counter.saturating_add(1);
+                        Ok(_) => (),
+                        // Increment the aborted transaction counter.
+                        Err(_) => num_aborted = num_aborted.saturating_add(1),
                     }
-                    // If the transaction failed, abort the entire batch.
-                    Err(error) => {
-                        eprintln!("Critical bug in speculate: {error}\n\n{transaction}");
-                        // Note: This will abort the entire atomic batch.
-                        return Err(format!("Failed to speculate on transaction - {error}"));
+                    // Note: This will abort the entire atomic batch.
+                    if let Err(e) = on_outcome(transaction, outcome) {
+                        return Err(format!("Failed to process the outcome of a transaction - {e}"));
                     }
                 }
             }
 
             // Ensure all transactions were processed.
-            if confirmed.len() + aborted.len() != num_transactions {
+            if (counter as usize).saturating_add(num_aborted) != num_transactions {
                 // Note: This will abort the entire atomic batch.
                 return Err("Not all transactions were processed in 'VM::atomic_speculate'".to_string());
             }
@@ -469,14 +856,6 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
                 None => vec![],
                 // If the coinbase reward is `Some(coinbase_reward)`, then we must compute the reward ratifications.
                 Some(coinbase_reward) => {
-                    // Calculate the transaction fees.
-                    let Ok(transaction_fees) =
-                        confirmed.iter().map(|tx| Ok(*tx.priority_fee_amount()?)).sum::<Result<u64>>()
-                    else {
-                        // Note: This will abort the entire atomic batch.
-                        return Err("Failed to calculate the transaction fees during speculation".to_string());
-                    };
-
                     // Compute the block reward.
                     let block_reward = ledger_block::block_reward(
                         N::STARTING_SUPPLY,
@@ -515,8 +894,8 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
             finish!(timer);
 
             // On return, 'atomic_finalize!' will abort the batch, and return the ratifications,
-            // confirmed & aborted transactions, and finalize operations from pre-ratify and post-ratify.
-            Ok((ratifications, confirmed, aborted, ratified_finalize_operations))
+            // and finalize operations from pre-ratify and post-ratify.
+            Ok((ratifications, ratified_finalize_operations))
         })
     }
 
@@ -571,7 +950,7 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
             // Acquire the write lock on the process.
             // Note: Due to the highly-sensitive nature of processing all `finalize` calls,
             // we choose to acquire the write lock for the entire duration of this atomic batch.
-            let mut process = self.process.write();
+            let process = self.process.write();
 
             // Initialize a list for the deployed stacks.
             let mut stacks = Vec::new();
@@ -776,14 +1155,14 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
         output_ids: &IndexSet<Field<N>>,
         tpks: &IndexSet<Group<N>>,
         deployment_payers: &IndexSet<Address<N>>,
-    ) -> Option<String> {
+    ) -> Option<(AbortReason, String)> {
         // Ensure that the transaction is not producing a duplicate transition.
         for transition_id in transaction.transition_ids() {
             // If the transition ID is already produced in this block or previous blocks, abort the transaction.
             if transition_ids.contains(transition_id)
                 || self.transition_store().contains_transition_id(transition_id).unwrap_or(true)
             {
-                return Some(format!("Duplicate transition {transition_id}"));
+                return Some((AbortReason::DuplicateTransition, format!("Duplicate transition {transition_id}")));
             }
         }
 
@@ -791,7 +1170,7 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
         for input_id in transaction.input_ids() {
             // If the input ID is already spent in this block or previous blocks, abort the transaction.
             if input_ids.contains(input_id) || self.transition_store().contains_input_id(input_id).unwrap_or(true) {
-                return Some(format!("Double-spending input {input_id}"));
+                return Some((AbortReason::DoubleSpend, format!("Double-spending input {input_id}")));
             }
         }
 
@@ -799,7 +1178,7 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
         for output_id in transaction.output_ids() {
             // If the output ID is already produced in this block or previous blocks, abort the transaction.
             if output_ids.contains(output_id) || self.transition_store().contains_output_id(output_id).unwrap_or(true) {
-                return Some(format!("Duplicate output {output_id}"));
+                return Some((AbortReason::DuplicateOutput, format!("Duplicate output {output_id}")));
             }
         }
 
@@ -808,7 +1187,10 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
         for tpk in transaction.transition_public_keys() {
             // If the transition public key is already produced in this block or previous blocks, abort the transaction.
             if tpks.contains(tpk) || self.transition_store().contains_tpk(tpk).unwrap_or(true) {
-                return Some(format!("Duplicate transition public key {tpk}"));
+                return Some((
+                    AbortReason::DuplicateTransitionPublicKey,
+                    format!("Duplicate transition public key {tpk}"),
+                ));
             }
         }
 
@@ -817,7 +1199,10 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
             // If any public deployment payer has already deployed in this block, abort the transaction.
             if let Some(payer) = fee.payer() {
                 if deployment_payers.contains(&payer) {
-                    return Some(format!("Another deployment in the block from the same public fee payer {payer}"));
+                    return Some((
+                        AbortReason::DuplicateDeploymentPayer,
+                        format!("Another deployment in the block from the same public fee payer {payer}"),
+                    ));
                 }
             }
         }
@@ -826,6 +1211,23 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
         None
     }
 
+    /// Returns the next window of transactions for `VM::speculate_streaming`, which is bounded by the number
+    /// and the estimated verification cost of its transactions. The window is only empty once the iterator is.
+    fn next_speculation_window<T: Borrow<Transaction<N>>>(transactions: &mut impl Iterator<Item = T>) -> Vec<T> {
+        let mut window = Vec::new();
+        let mut window_cost = 0u64;
+        while window.len() < Self::MAX_PARALLEL_EXECUTE_VERIFICATIONS
+            && window_cost < Self::MAX_PARALLEL_VERIFICATION_COST
+        {
+            let Some(transaction) = transactions.next() else {
+                break;
+            };
+            window_cost = window_cost.saturating_add(Self::verification_cost(transaction.borrow()));
+            window.push(transaction);
+        }
+        window
+    }
+
     /// Performs precondition checks on the transactions prior to speculation.
     ///
     /// This method is used to check the following conditions:
@@ -835,7 +1237,19 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
         &self,
         transactions: &[&'a Transaction<N>],
         rng: &mut R,
-    ) -> Result<(Vec<&'a Transaction<N>>, Vec<(&'a Transaction<N>, String)>)> {
+    ) -> Result<(Vec<&'a Transaction<N>>, Vec<(&'a Transaction<N>, AbortReason, String)>)> {
+        self.prepare_for_speculate_with_verified(transactions, &IndexSet::new(), rng)
+    }
+
+    /// Performs precondition checks on the transactions prior to speculation.
+    /// The transactions in `verified_transaction_ids` are already verified, and are not verified again.
+    #[allow(clippy::type_complexity)]
+    pub(crate) fn prepare_for_speculate_with_verified<'a, R: CryptoRng + Rng>(
+        &self,
+        transactions: &[&'a Transaction<N>],
+        verified_transaction_ids: &IndexSet<N::TransactionID>,
+        rng: &mut R,
+    ) -> Result<(Vec<&'a Transaction<N>>, Vec<(&'a Transaction<N>, AbortReason, String)>)> {
         // Construct the list of transactions that need to verified.
         let mut transactions_to_verify = Vec::with_capacity(transactions.len());
         // Construct the list of valid and invalid transactions.
@@ -858,7 +1272,11 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
         for transaction in transactions.iter() {
             // Abort the transaction early if it is a fee transaction.
             if transaction.is_fee() {
-                aborted_transactions.push((*transaction, "Fee transactions are not allowed in speculate".to_string()));
+                aborted_transactions.push((
+                    *transaction,
+                    AbortReason::FeeTransaction,
+                    "Fee transactions are not allowed in speculate".to_string(),
+                ));
                 continue;
             }
 
@@ -872,7 +1290,7 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
                 &deployment_payers,
             ) {
                 // Store the aborted transaction.
-                Some(reason) => aborted_transactions.push((*transaction, reason.to_string())),
+                Some((reason, error)) => aborted_transactions.push((*transaction, reason, error)),
                 // Track the transaction state.
                 None => {
                     // Add the transition IDs to the set of produced transition IDs.
@@ -888,8 +1306,11 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
                         fee.payer().map(|payer| deployment_payers.insert(payer));
                     }
 
-                    // Add the transaction to the list of transactions to verify.
-                    transactions_to_verify.push(transaction);
+                    // Add the transaction to the list of transactions to verify, unless it is already verified.
+                    match verified_transaction_ids.contains(&transaction.id()) {
+                        true => valid_transactions.push(*transaction),
+                        false => transactions_to_verify.push(transaction),
+                    }
                 }
             };
         }
@@ -897,22 +1318,24 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
         // Separate the transactions into deploys and executions.
         let (deployments, executions): (Vec<&Transaction<N>>, Vec<&Transaction<N>>) =
             transactions_to_verify.into_iter().partition(|tx| tx.is_deploy());
-        // Chunk the deploys and executions into groups for parallel verification.
-        let deployments_for_verification = deployments.chunks(Self::MAX_PARALLEL_DEPLOY_VERIFICATIONS);
-        let executions_for_verification = executions.chunks(Self::MAX_PARALLEL_EXECUTE_VERIFICATIONS);
+        // Chunk the deploys and executions into groups for parallel verification, balanced by their cost.
+        let deployments_for_verification =
+            Self::verification_chunks(&deployments, Self::MAX_PARALLEL_DEPLOY_VERIFICATIONS, |tx| *tx);
+        let executions_for_verification =
+            Self::verification_chunks(&executions, Self::MAX_PARALLEL_EXECUTE_VERIFICATIONS, |tx| *tx);
 
         // Verify the transactions in batches and separate the valid and invalid transactions.
-        for transactions in deployments_for_verification.chain(executions_for_verification) {
+        for transactions in deployments_for_verification.into_iter().chain(executions_for_verification) {
             let rngs = (0..transactions.len()).map(|_| StdRng::from_seed(rng.gen())).collect::<Vec<_>>();
             // Verify the transactions and collect the error message if there is one.
             let (valid, invalid): (Vec<_>, Vec<_>) =
                 cfg_into_iter!(transactions).zip(rngs).partition_map(|(transaction, mut rng)| {
                     // Verify the transaction.
-                    match self.check_transaction(transaction, None, &mut rng) {
+                    match self.check_block_transaction(transaction, None, &mut rng) {
                         // If the transaction is valid, add it to the list of valid transactions.
                         Ok(_) => Either::Left(*transaction),
                         // If the transaction is invalid, add it to the list of aborted transactions.
-                        Err(e) => Either::Right((*transaction, e.to_string())),
+                        Err(e) => Either::Right((*transaction, AbortReason::InvalidTransaction, e.to_string())),
                     }
                 });
 
@@ -938,9 +1361,9 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
     #[inline]
     fn prepare_for_execution(store: &FinalizeStore<N, C::FinalizeStorage>, execution: &Execution<N>) -> Result<()> {
         // Construct the program ID.
-        let program_id = ProgramID::from_str("credits.aleo")?;
+        let program_id = ProgramID::intern("credits.aleo")?;
         // Construct the committee mapping name.
-        let committee_mapping = Identifier::from_str("committee")?;
+        let committee_mapping = Identifier::intern("committee")?;
 
         // Check if the execution has any `bond_validator` transitions, and collect
         // the unique validator addresses if so.
@@ -996,19 +1419,19 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
         pre_ratifications: impl Iterator<Item = &'a Ratify<N>>,
     ) -> Result<Vec<FinalizeOperation<N>>> {
         // Construct the program ID.
-        let program_id = ProgramID::from_str("credits.aleo")?;
+        let program_id = ProgramID::intern("credits.aleo")?;
         // Construct the committee mapping name.
-        let committee_mapping = Identifier::from_str("committee")?;
+        let committee_mapping = Identifier::intern("committee")?;
         // Construct the delegated mapping name.
-        let delegated_mapping: Identifier<N> = Identifier::from_str("delegated")?;
+        let delegated_mapping: Identifier<N> = Identifier::intern("delegated")?;
         // Construct the bonded mapping name.
-        let bonded_mapping = Identifier::from_str("bonded")?;
+        let bonded_mapping = Identifier::intern("bonded")?;
         // Construct the account mapping name.
-        let account_mapping = Identifier::from_str("account")?;
+        let account_mapping = Identifier::intern("account")?;
         // Construct the metadata mapping name.
-        let metadata_mapping = Identifier::from_str("metadata")?;
+        let metadata_mapping = Identifier::intern("metadata")?;
         // Construct the withdraw mapping name.
-        let withdraw_mapping = Identifier::from_str("withdraw")?;
+        let withdraw_mapping = Identifier::intern("withdraw")?;
 
         // Initialize a list of finalize operations.
         let mut finalize_operations = Vec::new();
@@ -1022,23 +1445,10 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
                 Ratify::Genesis(committee, public_balances, bonded_balances) => {
                     // Ensure this is the genesis block.
                     ensure!(state.block_height() == 0, "Ratify::Genesis(..) expected a genesis block");
-                    // Ensure the genesis committee round is 0.
-                    ensure!(
-                        committee.starting_round() == 0,
-                        "Ratify::Genesis(..) expected a genesis committee round of 0"
-                    );
-                    // Ensure that the number of members in the committee does not exceed the maximum.
-                    ensure!(
-                        committee.members().len() <= Committee::<N>::MAX_COMMITTEE_SIZE as usize,
-                        "Ratify::Genesis(..) exceeds the maximum number of committee members"
-                    );
-                    // Ensure that the number of delegators does not exceed the maximum.
-                    ensure!(
-                        bonded_balances.len().saturating_sub(committee.members().len()) <= MAX_DELEGATORS as usize,
-                        "Ratify::Genesis(..) exceeds the maximum number of delegators"
-                    );
                     // Ensure genesis has not been ratified yet.
                     ensure!(!is_genesis_ratified, "Ratify::Genesis(..) has already been ratified");
+                    // Ensure the genesis committee is consistent with the bonded balances.
+                    ratify.verify()?;
 
                     // TODO (howardwu): Consider whether to initialize the mappings here.
                     //  Currently, this is breaking for test cases that use VM but do not insert the genesis block.
@@ -1052,65 +1462,6 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
                     //     }
                     // }
 
-                    // Calculate the stake per validator using `bonded_balances`.
-                    //
-                    // Note: There is no need to check the `delegated` mapping in the genesis block,
-                    // because the design of `bonded_balances` by definition does not support
-                    // delegating to a non-bonded validator. Thus, the assumption is that the
-                    // `delegated` mapping will be correct by construction.
-                    let mut stake_per_validator = IndexMap::with_capacity(committee.members().len());
-                    for (address, (validator_address, _, amount)) in bonded_balances.iter() {
-                        // Check that the amount meets the minimum requirement, depending on whether the address is a validator.
-                        if *address == *validator_address {
-                            ensure!(
-                                *amount >= MIN_VALIDATOR_SELF_STAKE,
-                                "Ratify::Genesis(..) the validator {address} must stake at least {MIN_VALIDATOR_SELF_STAKE}",
-                            );
-                        } else {
-                            ensure!(
-                                *amount >= MIN_DELEGATOR_STAKE,
-                                "Ratify::Genesis(..) the delegator {address} must stake at least {MIN_DELEGATOR_STAKE}",
-                            );
-                            // If the corresponding validator is not a committee member yet, then continue.
-                            if !committee.is_committee_member(*validator_address) {
-                                continue;
-                            }
-                            // If the address is a delegator, check that the corresponding validator is open.
-                            ensure!(
-                                committee.is_committee_member_open(*validator_address),
-                                "Ratify::Genesis(..) the delegator {address} is delegating to a closed validator {validator_address}",
-                            );
-                        }
-                        // Accumulate the staked amount per validator.
-                        let total = stake_per_validator.entry(validator_address).or_insert(0u64);
-                        *total = total.saturating_add(*amount);
-                    }
-                    // Ensure the stake per validator matches the committee.
-                    ensure!(
-                        stake_per_validator.len() == committee.members().len(),
-                        "Ratify::Genesis(..) the number of validators in the committee does not match the number of validators in the bonded balances",
-                    );
-
-                    // Check that `committee` is consistent with `stake_per_validator`.
-                    for (validator_address, amount) in &stake_per_validator {
-                        // Retrieve the expected validator stake from the committee.
-                        let Some((expected_amount, _, _)) = committee.members().get(*validator_address) else {
-                            bail!(
-                                "Ratify::Genesis(..) found a validator in the bonded balances that is not in the committee"
-                            )
-                        };
-                        // Ensure the staked amount matches the committee.
-                        ensure!(
-                            *expected_amount == *amount,
-                            "Ratify::Genesis(..) inconsistent staked amount for validator {validator_address}",
-                        );
-                    }
-                    // Ensure that the total stake matches the sum of the staked amounts.
-                    ensure!(
-                        committee.total_stake() == stake_per_validator.values().sum::<u64>(),
-                        "Ratify::Genesis(..) incorrect total total stake for the committee"
-                    );
-
                     // Split the bonded balances into stakers and withdrawal addresses.
                     let (next_stakers, withdrawal_addresses) = bonded_balances.iter().fold(
                         (
@@ -1207,15 +1558,15 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
         solutions: &Solutions<N>,
     ) -> Result<Vec<FinalizeOperation<N>>> {
         // Construct the program ID.
-        let program_id = ProgramID::from_str("credits.aleo")?;
+        let program_id = ProgramID::intern("credits.aleo")?;
         // Construct the committee mapping name.
-        let committee_mapping = Identifier::from_str("committee")?;
+        let committee_mapping = Identifier::intern("committee")?;
         // Construct the delegated mapping name.
-        let delegated_mapping = Identifier::from_str("delegated")?;
+        let delegated_mapping = Identifier::intern("delegated")?;
         // Construct the bonded mapping name.
-        let bonded_mapping = Identifier::from_str("bonded")?;
+        let bonded_mapping = Identifier::intern("bonded")?;
         // Construct the account mapping name.
-        let account_mapping = Identifier::from_str("account")?;
+        let account_mapping = Identifier::intern("account")?;
 
         // Initialize a list of finalize operations.
         let mut finalize_operations = Vec::new();
@@ -1281,17 +1632,17 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
                             history.store_mapping(state.block_height(), MappingName::Bonded, &next_bonded_map)?;
 
                             // Write the metadata mapping as JSON.
-                            let metadata_mapping = Identifier::from_str("metadata")?;
+                            let metadata_mapping = Identifier::intern("metadata")?;
                             let metadata_map = store.get_mapping_speculative(program_id, metadata_mapping)?;
                             history.store_mapping(state.block_height(), MappingName::Metadata, &metadata_map)?;
 
                             // Write the unbonding mapping as JSON.
-                            let unbonding_mapping = Identifier::from_str("unbonding")?;
+                            let unbonding_mapping = Identifier::intern("unbonding")?;
                             let unbonding_map = store.get_mapping_speculative(program_id, unbonding_mapping)?;
                             history.store_mapping(state.block_height(), MappingName::Unbonding, &unbonding_map)?;
 
                             // Write the withdraw mapping as JSON.
-                            let withdraw_mapping = Identifier::from_str("withdraw")?;
+                            let withdraw_mapping = Identifier::intern("withdraw")?;
                             let withdraw_map = store.get_mapping_speculative(program_id, withdraw_mapping)?;
                             history.store_mapping(state.block_height(), MappingName::Withdraw, &withdraw_map)?;
                         }
@@ -1372,8 +1723,9 @@ mod tests {
         types::Field,
     };
     use ledger_block::{Block, Header, Metadata, Transaction, Transition};
-    use ledger_committee::{MAX_DELEGATORS, MIN_VALIDATOR_STAKE};
+    use ledger_committee::{MAX_DELEGATORS, MIN_DELEGATOR_STAKE, MIN_VALIDATOR_STAKE};
     use ledger_store::helpers::memory::ConsensusMemory;
+    use synthesizer_process::FinalizeEvent;
     use synthesizer_program::Program;
 
     use rand::distributions::DistString;
@@ -2232,11 +2584,10 @@ finalize compute:
         // Check that the storage was not updated.
         let program_id = ProgramID::from_str("testing.aleo").unwrap();
         let mapping_name = Identifier::from_str("entries").unwrap();
-        assert!(
-            !vm.finalize_store()
-                .contains_key_confirmed(program_id, mapping_name, &Plaintext::from(Literal::Address(address)))
-                .unwrap()
-        );
+        assert!(!vm
+            .finalize_store()
+            .contains_key_confirmed(program_id, mapping_name, &Plaintext::from(Literal::Address(address)))
+            .unwrap());
 
         // Create an execution transaction, that will be rejected.
         let r0 = Value::<CurrentNetwork>::from_str("100u8").unwrap();
@@ -2337,6 +2688,233 @@ finalize compute:
         );
     }
 
+    #[test]
+    fn test_finalize_with_trace() {
+        let rng = &mut TestRng::default();
+
+        // Sample a private key.
+        let caller_private_key = test_helpers::sample_genesis_private_key(rng);
+        let caller_address = Address::try_from(&caller_private_key).unwrap();
+
+        // Initialize the vm.
+        let vm = test_helpers::sample_vm_with_genesis_block(rng);
+        let genesis =
+            vm.block_store().get_block(&vm.block_store().get_block_hash(0).unwrap().unwrap()).unwrap().unwrap();
+
+        // Get the unspent records.
+        let mut unspent_records = genesis
+            .transitions()
+            .cloned()
+            .flat_map(Transition::into_records)
+            .map(|(_, record)| record)
+            .collect::<Vec<_>>();
+
+        // Deploy a new program.
+        let (program_id, deployment_block) =
+            new_program_deployment(&vm, &caller_private_key, &genesis, &mut unspent_records, rng).unwrap();
+        vm.add_next_block(&deployment_block).unwrap();
+
+        // Construct a block that mints to the caller.
+        let transaction =
+            sample_mint_public(&vm, caller_private_key, &program_id, caller_address, 10, &mut unspent_records, rng);
+        let block =
+            sample_next_block(&vm, &caller_private_key, &[transaction], &deployment_block, &mut unspent_records, rng)
+                .unwrap();
+        let state = sample_finalize_state(block.height());
+
+        // Record the trace of the speculation on the block.
+        let recorder = FinalizeRecorder::start();
+        vm.check_speculate_without_verification(
+            state,
+            block.ratifications(),
+            block.solutions(),
+            block.transactions(),
+            None,
+            &[],
+            rng,
+        )
+        .unwrap();
+        let speculation_trace = recorder.finish();
+
+        // Finalize the block, and record the trace.
+        let (_, trace) =
+            vm.finalize_with_trace(state, block.ratifications(), block.solutions(), block.transactions()).unwrap();
+
+        // Ensure the mapping read and write of the mint are recorded.
+        let mapping = format!("{program_id}/account");
+        assert!(trace.events().iter().any(|event| matches!(
+            event,
+            FinalizeEvent::Finalize { program_id: id, function_name, .. }
+                if id == &program_id && function_name == "mint_public"
+        )));
+        assert!(trace.events().iter().any(|event| matches!(
+            event,
+            FinalizeEvent::Read { opcode, mapping: m, key, value }
+                if opcode == "get.or_use" && m == &mapping && key == &caller_address.to_string() && value == "0u64"
+        )));
+        assert!(trace.events().iter().any(|event| matches!(
+            event,
+            FinalizeEvent::Write { mapping: m, key, value: Some(value) }
+                if m == &mapping && key == &caller_address.to_string() && value == "10u64"
+        )));
+
+        // Ensure the speculation and finalization of the block have the same trace.
+        assert_eq!(speculation_trace.first_divergence(&trace), None);
+        assert_eq!(trace, FinalizeTrace::from_str(&trace.to_string()).unwrap());
+    }
+
+    #[test]
+    fn test_speculate_streaming() {
+        let rng = &mut TestRng::default();
+
+        // Initialize the vm.
+        let vm = test_helpers::sample_vm_with_genesis_block(rng);
+
+        // Sample the candidate transactions, including a fee transaction, which is aborted.
+        let execution = test_helpers::sample_execution_transaction_with_public_fee(rng);
+        let fee_transaction = Transaction::from_fee(execution.fee_transition().unwrap()).unwrap();
+        let transactions = vec![
+            test_helpers::sample_deployment_transaction(rng),
+            fee_transaction,
+            execution,
+            test_helpers::sample_execution_transaction_with_private_fee(rng),
+        ];
+
+        // Speculate on the transactions.
+        let (ratifications, confirmed, aborted, operations) =
+            vm.speculate(sample_finalize_state(1), Some(0u64), vec![], &None.into(), transactions.iter(), rng).unwrap();
+        assert!(!aborted.is_empty());
+
+        // Speculate on the transactions, streaming the outcomes.
+        let mut outcomes = Vec::new();
+        let (streamed_ratifications, streamed_operations) = vm
+            .speculate_streaming(
+                sample_finalize_state(1),
+                Some(0u64),
+                vec![],
+                &None.into(),
+                transactions.iter(),
+                |outcome| {
+                    outcomes.push(outcome);
+                    Ok(())
+                },
+                rng,
+            )
+            .unwrap();
+
+        // Ensure the streamed outcomes match the speculation.
+        assert_eq!(ratifications, streamed_ratifications);
+        assert_eq!(operations, streamed_operations);
+        let streamed_confirmed = outcomes
+            .iter()
+            .filter_map(|outcome| match outcome {
+                SpeculationOutcome::Confirmed(confirmed) => Some(confirmed.clone()),
+                SpeculationOutcome::Aborted(..) => None,
+            })
+            .collect::<Transactions<_>>();
+        assert_eq!(confirmed, streamed_confirmed);
+        let streamed_aborted = outcomes
+            .iter()
+            .filter_map(|outcome| match outcome {
+                SpeculationOutcome::Confirmed(..) => None,
+                SpeculationOutcome::Aborted(transaction_id, _) => Some(*transaction_id),
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(aborted, streamed_aborted);
+
+        // Ensure the outcomes are streamed in the order of the candidate transactions.
+        assert_eq!(outcomes.len(), transactions.len());
+        for (outcome, transaction) in outcomes.iter().zip(&transactions) {
+            assert_eq!(outcome.transaction_id().unwrap(), transaction.id());
+        }
+
+        // Ensure an error from the caller aborts the speculation.
+        let result = vm.speculate_streaming(
+            sample_finalize_state(1),
+            Some(0u64),
+            vec![],
+            &None.into(),
+            transactions.iter(),
+            |_| bail!("The block is full"),
+            rng,
+        );
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_check_speculate_aborted_transaction_reasons() {
+        let rng = &mut TestRng::default();
+
+        // Initialize the vm.
+        let vm = test_helpers::sample_vm_with_genesis_block(rng);
+
+        // Sample the candidate transactions, including a fee transaction, which is aborted.
+        let execution = test_helpers::sample_execution_transaction_with_public_fee(rng);
+        let fee_transaction = Transaction::from_fee(execution.fee_transition().unwrap()).unwrap();
+        let transactions = vec![fee_transaction, execution];
+        let candidate_transactions = transactions.iter().collect::<Vec<_>>();
+
+        // Speculate on the transactions.
+        let state = sample_finalize_state(1);
+        let (ratifications, confirmed, aborted, operations) =
+            vm.speculate_with_abort_reasons(state, Some(0u64), vec![], &None.into(), transactions.iter(), rng).unwrap();
+        assert_eq!(confirmed.len(), 1);
+        assert_eq!(aborted, vec![(transactions[0].id(), AbortReason::FeeTransaction)]);
+
+        // Ensure the speculation is correct, including the reason for the aborted transaction.
+        let candidates = Some(candidate_transactions.as_slice());
+        let result = vm.check_speculate_without_verification(
+            state,
+            &ratifications,
+            &None.into(),
+            &confirmed,
+            candidates,
+            &aborted,
+            rng,
+        );
+        assert_eq!(result.unwrap(), operations);
+
+        // Ensure an incorrect reason for the aborted transaction is rejected.
+        let incorrect = vec![(transactions[0].id(), AbortReason::InvalidTransaction)];
+        let result = vm.check_speculate_without_verification(
+            state,
+            &ratifications,
+            &None.into(),
+            &confirmed,
+            candidates,
+            &incorrect,
+            rng,
+        );
+        assert!(result.is_err());
+        // Ensure a missing reason for the aborted transaction is rejected.
+        let result = vm.check_speculate_without_verification(
+            state,
+            &ratifications,
+            &None.into(),
+            &confirmed,
+            candidates,
+            &[],
+            rng,
+        );
+        assert!(result.is_err());
+
+        // Ensure the reasons can not be checked without the candidate transactions.
+        let result = vm.check_speculate_without_verification(
+            state,
+            &ratifications,
+            &None.into(),
+            &confirmed,
+            None,
+            &aborted,
+            rng,
+        );
+        assert!(result.is_err());
+        // Ensure the speculation over the confirmed transactions is correct.
+        let result =
+            vm.check_speculate_without_verification(state, &ratifications, &None.into(), &confirmed, None, &[], rng);
+        assert_eq!(result.unwrap(), operations);
+    }
+
     #[test]
     fn test_ratify_genesis_greater_than_max_committee_size() {
         // Initialize an RNG.
@@ -0,0 +1,62 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+/// The strategy used to select a record to spend, from a set of candidate records.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub enum CoinSelection {
+    /// Selects the record with the largest balance, which consolidates funds into fewer records over time.
+    #[default]
+    LargestFirst,
+    /// Selects the record with the smallest sufficient balance, which minimizes the change that is returned.
+    MinimizeChange,
+}
+
+impl CoinSelection {
+    /// Returns the index of the balance to spend for the given amount, or `None` if no balance is sufficient.
+    pub fn select(&self, balances: &[u64], amount: u64) -> Option<usize> {
+        // Retrieve the sufficient balances.
+        let sufficient = balances.iter().enumerate().filter(|(_, balance)| **balance >= amount);
+        // Select the balance according to the strategy.
+        // Note: Ties are broken by the lowest index, so the selection is deterministic.
+        match self {
+            Self::LargestFirst => sufficient.min_by_key(|(index, balance)| (core::cmp::Reverse(**balance), *index)),
+            Self::MinimizeChange => sufficient.min_by_key(|(index, balance)| (**balance, *index)),
+        }
+        .map(|(index, _)| index)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_coin_selection() {
+        let balances = [50, 200, 120, 200, 80];
+
+        // Ensure the largest balance is selected.
+        assert_eq!(CoinSelection::LargestFirst.select(&balances, 100), Some(1));
+        assert_eq!(CoinSelection::LargestFirst.select(&balances, 0), Some(1));
+        // Ensure the smallest sufficient balance is selected.
+        assert_eq!(CoinSelection::MinimizeChange.select(&balances, 100), Some(2));
+        assert_eq!(CoinSelection::MinimizeChange.select(&balances, 80), Some(4));
+        assert_eq!(CoinSelection::MinimizeChange.select(&balances, 121), Some(1));
+
+        // Ensure no balance is selected if none are sufficient.
+        assert_eq!(CoinSelection::LargestFirst.select(&balances, 201), None);
+        assert_eq!(CoinSelection::MinimizeChange.select(&balances, 201), None);
+        assert_eq!(CoinSelection::MinimizeChange.select(&[], 0), None);
+    }
+}
@@ -38,8 +38,8 @@ pub fn committee_and_delegated_maps_into_committee<N: Network>(
     delegated_map: Vec<(Plaintext<N>, Value<N>)>,
 ) -> Result<Committee<N>> {
     // Prepare the identifiers.
-    let is_open_identifier: Identifier<N> = Identifier::from_str("is_open")?;
-    let commission_identifier: Identifier<N> = Identifier::from_str("commission")?;
+    let is_open_identifier: Identifier<N> = Identifier::intern("is_open")?;
+    let commission_identifier: Identifier<N> = Identifier::intern("commission")?;
 
     // Extract the committee members.
     let committee_members: IndexMap<Address<N>, (u64, bool, u8)> = committee_map
@@ -104,8 +104,8 @@ pub fn bonded_map_into_stakers<N: Network>(
     bonded_map: Vec<(Plaintext<N>, Value<N>)>,
 ) -> Result<IndexMap<Address<N>, (Address<N>, u64)>> {
     // Prepare the identifiers.
-    let validator_identifier = Identifier::from_str("validator")?;
-    let microcredits_identifier = Identifier::from_str("microcredits")?;
+    let validator_identifier = Identifier::intern("validator")?;
+    let microcredits_identifier = Identifier::intern("microcredits")?;
 
     // Convert the given key and value into a staker entry.
     let convert = |key, value| {
@@ -236,10 +236,10 @@ pub fn to_next_committee_bonded_delegated_map<N: Network>(
     next_delegated: &IndexMap<Address<N>, u64>,
 ) -> (Vec<(Plaintext<N>, Value<N>)>, Vec<(Plaintext<N>, Value<N>)>, Vec<(Plaintext<N>, Value<N>)>) {
     // Prepare the identifiers.
-    let validator_identifier = Identifier::from_str("validator").expect("Failed to parse 'validator'");
-    let microcredits_identifier = Identifier::from_str("microcredits").expect("Failed to parse 'microcredits'");
-    let is_open_identifier = Identifier::from_str("is_open").expect("Failed to parse 'is_open'");
-    let commission_identifier = Identifier::from_str("commission").expect("Failed to parse 'commission'");
+    let validator_identifier = Identifier::intern("validator").expect("Failed to parse 'validator'");
+    let microcredits_identifier = Identifier::intern("microcredits").expect("Failed to parse 'microcredits'");
+    let is_open_identifier = Identifier::intern("is_open").expect("Failed to parse 'is_open'");
+    let commission_identifier = Identifier::intern("commission").expect("Failed to parse 'commission'");
 
     // Construct the committee map.
     let committee_map = cfg_iter!(next_committee.members())
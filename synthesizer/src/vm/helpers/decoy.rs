@@ -0,0 +1,130 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use console::network::prelude::*;
+
+/// The configuration of decoy transactions, which are private self-transfers that serve as cover traffic.
+///
+/// A decoy is either a `credits.aleo/transfer_private` of a random amount to the caller,
+/// or, if enabled, a `credits.aleo/join` of two records, with a private fee in both cases.
+/// Decoys are only effective if they are broadcast at unpredictable times, so each decoy
+/// is accompanied by a random delay, which the caller should wait before broadcasting it.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct DecoyConfig {
+    /// The minimum amount of a transfer, in microcredits.
+    min_amount: u64,
+    /// The maximum amount of a transfer, in microcredits.
+    max_amount: u64,
+    /// The minimum delay before broadcasting, in seconds.
+    min_delay_secs: u64,
+    /// The maximum delay before broadcasting, in seconds.
+    max_delay_secs: u64,
+    /// Whether decoys may join two records, instead of transferring from one record.
+    allow_joins: bool,
+}
+
+impl Default for DecoyConfig {
+    /// Returns a configuration for transfers of 1 to 10 credits, with a delay of 1 minute to 1 hour.
+    fn default() -> Self {
+        Self {
+            min_amount: 1_000_000,
+            max_amount: 10_000_000,
+            min_delay_secs: 60,
+            max_delay_secs: 3600,
+            allow_joins: true,
+        }
+    }
+}
+
+impl DecoyConfig {
+    /// Sets the bounds of the transfer amount, in microcredits.
+    pub fn with_amounts(mut self, min_amount: u64, max_amount: u64) -> Result<Self> {
+        ensure!(min_amount > 0, "The minimum decoy amount must be nonzero");
+        ensure!(min_amount <= max_amount, "The minimum decoy amount exceeds the maximum decoy amount");
+        self.min_amount = min_amount;
+        self.max_amount = max_amount;
+        Ok(self)
+    }
+
+    /// Sets the bounds of the delay before broadcasting, in seconds.
+    pub fn with_delays(mut self, min_delay_secs: u64, max_delay_secs: u64) -> Result<Self> {
+        ensure!(min_delay_secs <= max_delay_secs, "The minimum decoy delay exceeds the maximum decoy delay");
+        self.min_delay_secs = min_delay_secs;
+        self.max_delay_secs = max_delay_secs;
+        Ok(self)
+    }
+
+    /// Sets whether decoys may join two records, instead of transferring from one record.
+    pub const fn with_joins(mut self, allow_joins: bool) -> Self {
+        self.allow_joins = allow_joins;
+        self
+    }
+
+    /// Returns the minimum amount of a transfer, in microcredits.
+    pub const fn min_amount(&self) -> u64 {
+        self.min_amount
+    }
+
+    /// Returns the maximum amount of a transfer, in microcredits.
+    pub const fn max_amount(&self) -> u64 {
+        self.max_amount
+    }
+
+    /// Returns the minimum delay before broadcasting, in seconds.
+    pub const fn min_delay_secs(&self) -> u64 {
+        self.min_delay_secs
+    }
+
+    /// Returns the maximum delay before broadcasting, in seconds.
+    pub const fn max_delay_secs(&self) -> u64 {
+        self.max_delay_secs
+    }
+
+    /// Returns `true` if decoys may join two records, instead of transferring from one record.
+    pub const fn allow_joins(&self) -> bool {
+        self.allow_joins
+    }
+
+    /// Samples a transfer amount, in microcredits.
+    pub fn sample_amount<R: Rng>(&self, rng: &mut R) -> u64 {
+        rng.gen_range(self.min_amount..=self.max_amount)
+    }
+
+    /// Samples a delay before broadcasting, in seconds.
+    pub fn sample_delay_secs<R: Rng>(&self, rng: &mut R) -> u64 {
+        rng.gen_range(self.min_delay_secs..=self.max_delay_secs)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_decoy_config() {
+        let rng = &mut TestRng::default();
+
+        // Ensure the samples are within the bounds.
+        let config = DecoyConfig::default().with_amounts(10, 20).unwrap().with_delays(5, 5).unwrap();
+        for _ in 0..100 {
+            assert!((10..=20).contains(&config.sample_amount(rng)));
+            assert_eq!(config.sample_delay_secs(rng), 5);
+        }
+
+        // Ensure invalid bounds are rejected.
+        assert!(DecoyConfig::default().with_amounts(0, 20).is_err());
+        assert!(DecoyConfig::default().with_amounts(21, 20).is_err());
+        assert!(DecoyConfig::default().with_delays(6, 5).is_err());
+    }
+}
@@ -0,0 +1,174 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use console::network::prelude::*;
+
+/// The fixed amount in microcredits that `credits.aleo/split` removes from the input record.
+pub const SPLIT_FEE_IN_MICROCREDITS: u64 = 10_000;
+
+/// A `credits.aleo/split` operation in a fee plan.
+///
+/// A split consumes the record at `record` and produces two new records: one with `amount` microcredits,
+/// and one with the remaining balance, minus `SPLIT_FEE_IN_MICROCREDITS`.
+/// Note: A transaction with a single split transition does not require a fee, so these transactions
+/// must be submitted on their own, with a priority fee of 0.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct PlannedSplit {
+    /// The index of the record to split.
+    pub record: usize,
+    /// The amount in microcredits for the first output record.
+    pub amount: u64,
+    /// The index of the output record with `amount` microcredits.
+    pub output: usize,
+    /// The index of the output record with the remaining balance.
+    pub change: usize,
+}
+
+/// A plan to produce one fee record per pending execution, using zero-fee `credits.aleo/split` operations.
+///
+/// Records are identified by index: the first indices are the given records, in order,
+/// and each split appends its two output records.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FeePlan {
+    /// The splits to perform, in order.
+    splits: Vec<PlannedSplit>,
+    /// The index of the record that pays each fee, in the order of the given fees.
+    assignments: Vec<usize>,
+    /// The balance of each record in the plan.
+    balances: Vec<u64>,
+}
+
+impl FeePlan {
+    /// Returns a plan that assigns a distinct record to each of the given fees (in microcredits),
+    /// splitting the given record balances as needed.
+    ///
+    /// Each split output is only spendable once its split is confirmed, so the splits must be submitted in order,
+    /// and a split of a `change` record must wait for the split that produced it.
+    pub fn new(balances: &[u64], fees: &[u64]) -> Result<Self> {
+        let mut plan = Self { splits: vec![], assignments: vec![usize::MAX; fees.len()], balances: balances.to_vec() };
+        // Track the records that are available to be split or assigned.
+        let mut available = vec![true; balances.len()];
+
+        // Assign the fees in descending order, so the largest fees are matched first.
+        let mut order = (0..fees.len()).collect::<Vec<_>>();
+        order.sort_by_key(|index| (core::cmp::Reverse(fees[*index]), *index));
+
+        for (position, fee_index) in order.iter().enumerate() {
+            let fee = fees[*fee_index];
+            // Select the smallest available record that can pay the fee.
+            let Some(record) = (0..plan.balances.len())
+                .filter(|index| available[*index] && plan.balances[*index] >= fee)
+                .min_by_key(|index| (plan.balances[*index], *index))
+            else {
+                bail!("No available record has at least {fee} microcredits to pay fee #{fee_index}");
+            };
+            available[record] = false;
+
+            // Determine the smallest fee that remains to be assigned.
+            let smallest_remaining_fee = order[position + 1..].iter().map(|index| fees[*index]).min();
+            // Split the record if the change can pay a remaining fee, and there are not enough other records.
+            let num_remaining_fees = order.len() - position - 1;
+            let num_other_records = available.iter().filter(|is_available| **is_available).count();
+            let change = plan.balances[record].saturating_sub(fee).saturating_sub(SPLIT_FEE_IN_MICROCREDITS);
+            let is_split = match smallest_remaining_fee {
+                Some(smallest_remaining_fee) => {
+                    num_other_records < num_remaining_fees
+                        && plan.balances[record] >= fee.saturating_add(SPLIT_FEE_IN_MICROCREDITS)
+                        && change >= smallest_remaining_fee
+                }
+                None => false,
+            };
+
+            match is_split {
+                true => {
+                    // Split the record into the fee record and the change record.
+                    let output = plan.balances.len();
+                    plan.balances.push(fee);
+                    plan.balances.push(change);
+                    available.extend([false, true]);
+                    plan.splits.push(PlannedSplit { record, amount: fee, output, change: output + 1 });
+                    plan.assignments[*fee_index] = output;
+                }
+                false => plan.assignments[*fee_index] = record,
+            }
+        }
+        Ok(plan)
+    }
+
+    /// Returns the splits to perform, in order.
+    pub fn splits(&self) -> &[PlannedSplit] {
+        &self.splits
+    }
+
+    /// Returns the index of the record that pays each fee, in the order of the given fees.
+    pub fn assignments(&self) -> &[usize] {
+        &self.assignments
+    }
+
+    /// Returns the balance of the record at the given index.
+    pub fn balance(&self, record: usize) -> Option<u64> {
+        self.balances.get(record).copied()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Ensures each fee is assigned a distinct record with a sufficient balance.
+    fn check_plan(plan: &FeePlan, fees: &[u64]) {
+        let mut assigned = std::collections::HashSet::new();
+        for (fee, record) in fees.iter().zip_eq(plan.assignments()) {
+            assert!(plan.balance(*record).unwrap() >= *fee);
+            assert!(assigned.insert(*record));
+            // Ensure the assigned record is not split.
+            assert!(plan.splits().iter().all(|split| split.record != *record));
+        }
+        // Ensure each record is split at most once.
+        let mut split = std::collections::HashSet::new();
+        for planned in plan.splits() {
+            assert!(split.insert(planned.record));
+            assert!(planned.record < planned.output);
+        }
+    }
+
+    #[test]
+    fn test_fee_plan_without_splits() {
+        let fees = [100, 300, 200];
+        let plan = FeePlan::new(&[1_000_000, 250, 400], &fees).unwrap();
+        check_plan(&plan, &fees);
+        assert!(plan.splits().is_empty());
+        assert_eq!(plan.assignments(), &[0, 2, 1]);
+    }
+
+    #[test]
+    fn test_fee_plan_with_splits() {
+        let fees = [50_000, 50_000, 50_000, 20_000];
+        let plan = FeePlan::new(&[1_000_000], &fees).unwrap();
+        check_plan(&plan, &fees);
+        assert_eq!(plan.splits().len(), 3);
+        // Ensure the final change record is assigned.
+        let last = plan.splits().last().unwrap();
+        assert_eq!(plan.balance(last.change), Some(1_000_000 - 150_000 - 3 * SPLIT_FEE_IN_MICROCREDITS));
+    }
+
+    #[test]
+    fn test_fee_plan_insufficient() {
+        assert!(FeePlan::new(&[100], &[101]).is_err());
+        assert!(FeePlan::new(&[100], &[50, 50]).is_err());
+        assert!(FeePlan::new(&[], &[1]).is_err());
+        assert!(FeePlan::new(&[], &[]).unwrap().assignments().is_empty());
+    }
+}
@@ -0,0 +1,141 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use console::{account::Address, network::prelude::*};
+
+use indexmap::IndexMap;
+
+/// Tracks the pending public fees of each payer, so that several public-fee transactions
+/// from one account can be queued without invalidating each other.
+///
+/// Each pending public fee is assigned a sequence number for its payer, and a new public fee is only accepted
+/// if the speculative balance of the payer covers it, in addition to all of the payer's pending public fees.
+#[derive(Clone, Debug)]
+pub struct PublicFeeSequencer<N: Network> {
+    /// The pending public fees for each payer, as `(transaction ID, amount)`, in sequence order.
+    pending: IndexMap<Address<N>, IndexMap<N::TransactionID, u64>>,
+    /// The next sequence number for each payer.
+    next_sequence: IndexMap<Address<N>, u64>,
+    /// The sequence number for each pending transaction ID.
+    sequences: IndexMap<N::TransactionID, (Address<N>, u64)>,
+}
+
+impl<N: Network> Default for PublicFeeSequencer<N> {
+    /// Initializes a new public fee sequencer.
+    fn default() -> Self {
+        Self { pending: Default::default(), next_sequence: Default::default(), sequences: Default::default() }
+    }
+}
+
+impl<N: Network> PublicFeeSequencer<N> {
+    /// Reserves the given public fee from the given payer's balance, returning its sequence number.
+    /// If the transaction ID is already pending, its existing sequence number is returned.
+    pub fn reserve(
+        &mut self,
+        payer: Address<N>,
+        transaction_id: N::TransactionID,
+        amount: u64,
+        balance: u64,
+    ) -> Result<u64> {
+        // If the transaction is already pending, return its sequence number.
+        if let Some((_, sequence)) = self.sequences.get(&transaction_id) {
+            return Ok(*sequence);
+        }
+        // Ensure the balance covers the pending fees and the new fee.
+        let pending_amount = self.pending_amount(&payer);
+        let required = pending_amount.checked_add(amount).ok_or_else(|| anyhow!("The pending fees overflowed"))?;
+        ensure!(
+            balance >= required,
+            "Fee verification failed: insufficient balance for '{payer}' with {pending_amount} microcredits in pending public fees"
+        );
+        // Assign the next sequence number.
+        let next_sequence = self.next_sequence.entry(payer).or_default();
+        let sequence = *next_sequence;
+        *next_sequence += 1;
+        // Reserve the fee.
+        self.pending.entry(payer).or_default().insert(transaction_id, amount);
+        self.sequences.insert(transaction_id, (payer, sequence));
+        Ok(sequence)
+    }
+
+    /// Releases the public fee for the given transaction ID (e.g. when it is included in a block, or dropped).
+    pub fn release(&mut self, transaction_id: &N::TransactionID) {
+        if let Some((payer, _)) = self.sequences.shift_remove(transaction_id) {
+            if let Some(pending) = self.pending.get_mut(&payer) {
+                pending.shift_remove(transaction_id);
+                if pending.is_empty() {
+                    self.pending.shift_remove(&payer);
+                }
+            }
+        }
+    }
+
+    /// Returns the sequence number of the given pending transaction ID.
+    pub fn sequence(&self, transaction_id: &N::TransactionID) -> Option<u64> {
+        self.sequences.get(transaction_id).map(|(_, sequence)| *sequence)
+    }
+
+    /// Returns the next sequence number for the given payer.
+    pub fn next_sequence(&self, payer: &Address<N>) -> u64 {
+        self.next_sequence.get(payer).copied().unwrap_or_default()
+    }
+
+    /// Returns the total amount of pending public fees for the given payer.
+    pub fn pending_amount(&self, payer: &Address<N>) -> u64 {
+        self.pending.get(payer).map_or(0, |pending| pending.values().fold(0u64, |acc, x| acc.saturating_add(*x)))
+    }
+
+    /// Returns the pending transaction IDs for the given payer, in sequence order.
+    pub fn pending_transaction_ids(&self, payer: &Address<N>) -> impl '_ + Iterator<Item = &N::TransactionID> {
+        self.pending.get(payer).into_iter().flat_map(|pending| pending.keys())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::{account::PrivateKey, network::MainnetV0, types::Field};
+
+    type CurrentNetwork = MainnetV0;
+
+    #[test]
+    fn test_public_fee_sequencer() {
+        let rng = &mut TestRng::default();
+
+        let payer = Address::try_from(PrivateKey::<CurrentNetwork>::new(rng).unwrap()).unwrap();
+        let transaction_ids = (0..3).map(|_| Field::<CurrentNetwork>::rand(rng).into()).collect::<Vec<_>>();
+
+        let mut sequencer = PublicFeeSequencer::<CurrentNetwork>::default();
+        // Ensure the fees are sequenced while the balance covers them.
+        assert_eq!(sequencer.reserve(payer, transaction_ids[0], 40, 100).unwrap(), 0);
+        assert_eq!(sequencer.reserve(payer, transaction_ids[1], 60, 100).unwrap(), 1);
+        assert_eq!(sequencer.pending_amount(&payer), 100);
+        // Ensure reserving the same transaction is idempotent.
+        assert_eq!(sequencer.reserve(payer, transaction_ids[1], 60, 100).unwrap(), 1);
+        // Ensure a fee that exceeds the remaining balance is rejected.
+        assert!(sequencer.reserve(payer, transaction_ids[2], 1, 100).is_err());
+
+        // Release the first fee, and ensure the next fee is accepted with the next sequence number.
+        sequencer.release(&transaction_ids[0]);
+        assert_eq!(sequencer.pending_amount(&payer), 60);
+        assert_eq!(sequencer.reserve(payer, transaction_ids[2], 40, 100).unwrap(), 2);
+        assert_eq!(sequencer.pending_transaction_ids(&payer).collect::<Vec<_>>(), vec![
+            &transaction_ids[1],
+            &transaction_ids[2]
+        ]);
+        assert_eq!(sequencer.sequence(&transaction_ids[0]), None);
+        assert_eq!(sequencer.next_sequence(&payer), 3);
+    }
+}
@@ -13,9 +13,21 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod coin_selection;
+pub use coin_selection::*;
+
 pub(crate) mod committee;
 pub use committee::*;
 
+mod decoy;
+pub use decoy::*;
+
+mod fee_plan;
+pub use fee_plan::*;
+
+mod fee_sequence;
+pub use fee_sequence::*;
+
 #[cfg(feature = "history")]
 mod history;
 #[cfg(feature = "history")]
@@ -23,5 +35,17 @@ pub use history::*;
 
 mod macros;
 
+mod replay_window;
+pub use replay_window::*;
+
 mod rewards;
 pub use rewards::*;
+
+mod speculation_outcome;
+pub use speculation_outcome::*;
+
+mod tip;
+pub(crate) use tip::*;
+
+mod verified_transactions;
+pub use verified_transactions::*;
@@ -0,0 +1,218 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use console::{network::prelude::*, types::Field};
+
+use indexmap::IndexSet;
+use std::collections::BTreeMap;
+
+/// The configuration for the rejected execution ID replay check.
+///
+/// Rejected execution IDs from the most recent `blocks` blocks are retained exactly. Older rejected execution IDs
+/// are folded into a compact Bloom filter, sized for `expected_rejections` entries at the given `false_positive_rate`.
+///
+/// The trade-off for node operators is as follows: a replayed execution is always detected (there are no false
+/// negatives), but an execution whose ID collides in the Bloom filter is rejected as a replay, with probability up to
+/// `false_positive_rate` once `expected_rejections` old IDs have been accumulated. A larger window or a lower
+/// false positive rate reduces spurious rejections, at the cost of memory.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct ReplayWindow {
+    /// The number of recent blocks whose rejected execution IDs are retained exactly.
+    blocks: u32,
+    /// The expected number of rejected execution IDs that are accumulated beyond the window.
+    expected_rejections: usize,
+    /// The target false positive rate of the accumulator.
+    false_positive_rate: f64,
+}
+
+impl ReplayWindow {
+    /// Initializes a new replay window configuration.
+    pub fn new(blocks: u32, expected_rejections: usize, false_positive_rate: f64) -> Result<Self> {
+        ensure!(blocks > 0, "The replay window must contain at least one block");
+        ensure!(expected_rejections > 0, "The replay window must expect at least one rejection");
+        ensure!(
+            false_positive_rate > 0.0 && false_positive_rate < 1.0,
+            "The replay window false positive rate must be in (0, 1)"
+        );
+        Ok(Self { blocks, expected_rejections, false_positive_rate })
+    }
+
+    /// Returns the number of recent blocks whose rejected execution IDs are retained exactly.
+    pub const fn blocks(&self) -> u32 {
+        self.blocks
+    }
+
+    /// Returns the expected number of rejected execution IDs that are accumulated beyond the window.
+    pub const fn expected_rejections(&self) -> usize {
+        self.expected_rejections
+    }
+
+    /// Returns the target false positive rate of the accumulator.
+    pub const fn false_positive_rate(&self) -> f64 {
+        self.false_positive_rate
+    }
+
+    /// Returns the number of bits in the accumulator.
+    pub fn num_bits(&self) -> usize {
+        let n = self.expected_rejections as f64;
+        let bits = -n * self.false_positive_rate.ln() / core::f64::consts::LN_2.powi(2);
+        (bits.ceil() as usize).max(64)
+    }
+
+    /// Returns the number of hash functions in the accumulator.
+    pub fn num_hashes(&self) -> u32 {
+        let hashes = (self.num_bits() as f64 / self.expected_rejections as f64) * core::f64::consts::LN_2;
+        (hashes.round() as u32).max(1)
+    }
+
+    /// Returns the size of the accumulator in bytes.
+    pub fn accumulator_size_in_bytes(&self) -> usize {
+        self.num_bits().div_ceil(64) * 8
+    }
+}
+
+/// The replay protection for rejected execution IDs, bounded by a `ReplayWindow`.
+#[derive(Clone, Debug)]
+pub struct ReplayProtection<N: Network> {
+    /// The replay window configuration.
+    window: ReplayWindow,
+    /// The rejected execution IDs within the window, keyed by block height.
+    recent: BTreeMap<u32, Vec<Field<N>>>,
+    /// The set of rejected execution IDs within the window.
+    recent_ids: IndexSet<Field<N>>,
+    /// The Bloom filter of rejected execution IDs beyond the window.
+    accumulator: Vec<u64>,
+    /// The number of rejected execution IDs in the accumulator.
+    num_accumulated: usize,
+}
+
+impl<N: Network> ReplayProtection<N> {
+    /// Initializes a new replay protection for the given window.
+    pub fn new(window: ReplayWindow) -> Self {
+        let accumulator = vec![0u64; window.num_bits().div_ceil(64)];
+        Self { window, recent: Default::default(), recent_ids: Default::default(), accumulator, num_accumulated: 0 }
+    }
+
+    /// Returns the replay window configuration.
+    pub const fn window(&self) -> &ReplayWindow {
+        &self.window
+    }
+
+    /// Returns the number of rejected execution IDs retained exactly.
+    pub fn num_recent(&self) -> usize {
+        self.recent_ids.len()
+    }
+
+    /// Returns the number of rejected execution IDs in the accumulator.
+    pub const fn num_accumulated(&self) -> usize {
+        self.num_accumulated
+    }
+
+    /// Returns the estimated false positive rate of the accumulator, given its current contents.
+    pub fn estimated_false_positive_rate(&self) -> f64 {
+        let (m, k, n) = (self.window.num_bits() as f64, self.window.num_hashes() as f64, self.num_accumulated as f64);
+        (1.0 - (-k * n / m).exp()).powf(k)
+    }
+
+    /// Returns `true` if the given execution ID was previously rejected.
+    /// Note: For IDs beyond the window, this may return a false positive, but never a false negative.
+    pub fn contains(&self, execution_id: &Field<N>) -> bool {
+        self.recent_ids.contains(execution_id)
+            || (self.num_accumulated > 0
+                && self.indices(execution_id).all(|index| self.accumulator[index / 64] & (1 << (index % 64)) != 0))
+    }
+
+    /// Inserts the rejected execution IDs of the block at the given height,
+    /// and folds the rejected execution IDs that fall out of the window into the accumulator.
+    pub fn insert(&mut self, height: u32, execution_ids: impl IntoIterator<Item = Field<N>>) {
+        // Insert the execution IDs.
+        let execution_ids = execution_ids.into_iter().collect::<Vec<_>>();
+        if !execution_ids.is_empty() {
+            self.recent_ids.extend(execution_ids.iter().copied());
+            self.recent.entry(height).or_default().extend(execution_ids);
+        }
+        // Fold the execution IDs that fall out of the window into the accumulator.
+        let start = height.saturating_sub(self.window.blocks - 1);
+        let retained = self.recent.split_off(&start);
+        for execution_id in std::mem::replace(&mut self.recent, retained).into_values().flatten() {
+            self.recent_ids.swap_remove(&execution_id);
+            for index in self.indices(&execution_id).collect::<Vec<_>>() {
+                self.accumulator[index / 64] |= 1 << (index % 64);
+            }
+            self.num_accumulated += 1;
+        }
+    }
+
+    /// Returns the accumulator bit indices for the given execution ID.
+    fn indices(&self, execution_id: &Field<N>) -> impl '_ + Iterator<Item = usize> {
+        // Note: Execution IDs are the output of a hash function, so their bits are used directly (double hashing).
+        let bytes = execution_id.to_bytes_le().unwrap_or_default();
+        let read = |offset: usize| {
+            bytes.get(offset..offset + 8).map_or(0, |b| u64::from_le_bytes(b.try_into().unwrap_or_default()))
+        };
+        let (h1, h2) = (read(0), read(8) | 1);
+        let num_bits = self.accumulator.len() as u64 * 64;
+        (0..self.window.num_hashes() as u64).map(move |i| (h1.wrapping_add(i.wrapping_mul(h2)) % num_bits) as usize)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::MainnetV0;
+
+    type CurrentNetwork = MainnetV0;
+
+    #[test]
+    fn test_replay_window() {
+        assert!(ReplayWindow::new(0, 1, 0.01).is_err());
+        assert!(ReplayWindow::new(1, 0, 0.01).is_err());
+        assert!(ReplayWindow::new(1, 1, 0.0).is_err());
+        assert!(ReplayWindow::new(1, 1, 1.0).is_err());
+
+        let window = ReplayWindow::new(100, 10_000, 0.01).unwrap();
+        // Ensure a lower false positive rate requires a larger accumulator.
+        let stricter = ReplayWindow::new(100, 10_000, 0.0001).unwrap();
+        assert!(stricter.accumulator_size_in_bytes() > window.accumulator_size_in_bytes());
+        assert!(stricter.num_hashes() > window.num_hashes());
+    }
+
+    #[test]
+    fn test_replay_protection() {
+        let rng = &mut TestRng::default();
+
+        let window = ReplayWindow::new(2, 100, 0.001).unwrap();
+        let mut protection = ReplayProtection::<CurrentNetwork>::new(window);
+
+        // Insert rejected execution IDs across several blocks.
+        let execution_ids = (0..5u32).map(|_| Field::<CurrentNetwork>::rand(rng)).collect::<Vec<_>>();
+        for (height, execution_id) in execution_ids.iter().enumerate() {
+            protection.insert(height as u32, [*execution_id]);
+        }
+        // Ensure only the last two blocks are retained exactly.
+        assert_eq!(protection.num_recent(), 2);
+        assert_eq!(protection.num_accumulated(), 3);
+        // Ensure every rejected execution ID is still detected.
+        for execution_id in &execution_ids {
+            assert!(protection.contains(execution_id));
+        }
+        // Ensure the estimated false positive rate is within the target.
+        assert!(protection.estimated_false_positive_rate() <= window.false_positive_rate());
+
+        // Ensure unrelated execution IDs are not detected.
+        let num_false_positives = (0..1000).filter(|_| protection.contains(&Field::rand(rng))).count();
+        assert!(num_false_positives <= 10);
+    }
+}
@@ -0,0 +1,49 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use console::network::prelude::*;
+use ledger_block::{AbortReason, ConfirmedTransaction};
+
+/// The outcome of speculating on a candidate transaction, as streamed by `VM::speculate_streaming`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum SpeculationOutcome<N: Network> {
+    /// The transaction is included in the block, as an accepted or rejected transaction.
+    Confirmed(ConfirmedTransaction<N>),
+    /// The transaction is aborted, and only its transaction ID is included in the block.
+    Aborted(N::TransactionID, AbortReason),
+}
+
+impl<N: Network> SpeculationOutcome<N> {
+    /// Returns the ID of the candidate transaction.
+    ///
+    /// Note: For a rejected transaction, this is the ID of the unconfirmed transaction,
+    /// not the ID of the fee transaction that is included in the block.
+    pub fn transaction_id(&self) -> Result<N::TransactionID> {
+        match self {
+            Self::Confirmed(confirmed) => confirmed.to_unconfirmed_transaction_id(),
+            Self::Aborted(transaction_id, _) => Ok(*transaction_id),
+        }
+    }
+
+    /// Returns `true` if the transaction is included in the block.
+    pub const fn is_confirmed(&self) -> bool {
+        matches!(self, Self::Confirmed(..))
+    }
+
+    /// Returns `true` if the transaction is aborted.
+    pub const fn is_aborted(&self) -> bool {
+        matches!(self, Self::Aborted(..))
+    }
+}
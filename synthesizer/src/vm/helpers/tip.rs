@@ -0,0 +1,55 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use console::network::prelude::*;
+
+use parking_lot::RwLock;
+use std::sync::atomic::{AtomicU32, Ordering};
+
+/// A cache of the block height and state root of the latest block in the VM.
+///
+/// The tip is read on the per-transaction verification path, so it is kept separate from the block store,
+/// whose block tree lock is contended while blocks are being inserted.
+pub(crate) struct BlockTip<N: Network> {
+    /// The block height of the latest block.
+    height: AtomicU32,
+    /// The state root, as of the latest block.
+    state_root: RwLock<N::StateRoot>,
+}
+
+impl<N: Network> BlockTip<N> {
+    /// Initializes the tip with the given block height and state root.
+    pub(crate) fn new(height: u32, state_root: N::StateRoot) -> Self {
+        Self { height: AtomicU32::new(height), state_root: RwLock::new(state_root) }
+    }
+
+    /// Returns the block height of the latest block.
+    pub(crate) fn height(&self) -> u32 {
+        self.height.load(Ordering::Acquire)
+    }
+
+    /// Returns the state root, as of the latest block.
+    pub(crate) fn state_root(&self) -> N::StateRoot {
+        *self.state_root.read()
+    }
+
+    /// Updates the tip to the given block height and state root.
+    ///
+    /// Note: The caller must hold the block lock, so that updates are not interleaved.
+    pub(crate) fn update(&self, height: u32, state_root: N::StateRoot) {
+        *self.state_root.write() = state_root;
+        self.height.store(height, Ordering::Release);
+    }
+}
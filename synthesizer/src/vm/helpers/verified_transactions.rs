@@ -0,0 +1,102 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use console::network::prelude::*;
+use ledger_block::Transaction;
+use ledger_narwhal_data::Data;
+
+use indexmap::IndexMap;
+
+/// The policy for the transactions in a `VerifiedTransactions` set.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub enum VerificationPolicy {
+    /// Each transaction is verified in full, including the transactions in the set.
+    #[default]
+    VerifyAll,
+    /// The proofs of the transactions in the set are trusted, and are not verified again.
+    /// The checks against the current state of the ledger, e.g. uniqueness and fee balances, are still performed.
+    TrustVerified,
+}
+
+/// The set of transactions that consensus has already fully verified, e.g. on admission to the mempool.
+/// Each transaction is keyed by its transaction ID, with the checksum of the transaction bytes,
+/// such that a transaction is only trusted if it is identical to the verified transaction.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct VerifiedTransactions<N: Network> {
+    /// The policy for the verified transactions.
+    policy: VerificationPolicy,
+    /// The checksums of the verified transactions, keyed by transaction ID.
+    transactions: IndexMap<N::TransactionID, N::TransmissionChecksum>,
+}
+
+impl<N: Network> Default for VerifiedTransactions<N> {
+    /// Returns an empty set of verified transactions, which verifies each transaction in full.
+    fn default() -> Self {
+        Self::new(VerificationPolicy::default())
+    }
+}
+
+impl<N: Network> VerifiedTransactions<N> {
+    /// Initializes an empty set of verified transactions, with the given policy.
+    pub fn new(policy: VerificationPolicy) -> Self {
+        Self { policy, transactions: Default::default() }
+    }
+
+    /// Returns the policy for the verified transactions.
+    pub const fn policy(&self) -> VerificationPolicy {
+        self.policy
+    }
+
+    /// Returns the number of verified transactions.
+    pub fn len(&self) -> usize {
+        self.transactions.len()
+    }
+
+    /// Returns `true` if there are no verified transactions.
+    pub fn is_empty(&self) -> bool {
+        self.transactions.is_empty()
+    }
+
+    /// Inserts the given transaction ID and checksum, of a transaction that was fully verified.
+    pub fn insert(&mut self, transaction_id: N::TransactionID, checksum: N::TransmissionChecksum) {
+        self.transactions.insert(transaction_id, checksum);
+    }
+
+    /// Inserts the given transaction, which was fully verified.
+    pub fn insert_transaction(&mut self, transaction: &Transaction<N>) -> Result<()> {
+        self.insert(transaction.id(), Self::checksum(transaction)?);
+        Ok(())
+    }
+
+    /// Removes the given transaction ID, e.g. once the transaction is committed or evicted from the mempool.
+    pub fn remove(&mut self, transaction_id: &N::TransactionID) -> Option<N::TransmissionChecksum> {
+        self.transactions.shift_remove(transaction_id)
+    }
+
+    /// Returns `true` if the given transaction was fully verified, and is trusted under the policy.
+    pub fn is_trusted(&self, transaction: &Transaction<N>) -> bool {
+        match (self.policy, self.transactions.get(&transaction.id())) {
+            (VerificationPolicy::TrustVerified, Some(checksum)) => {
+                Self::checksum(transaction).is_ok_and(|candidate| candidate == *checksum)
+            }
+            _ => false,
+        }
+    }
+
+    /// Returns the checksum of the given transaction, which matches the checksum of its transmission in consensus.
+    fn checksum(transaction: &Transaction<N>) -> Result<N::TransmissionChecksum> {
+        Data::<Transaction<N>>::Buffer(transaction.to_bytes_le()?.into()).to_checksum::<N>()
+    }
+}
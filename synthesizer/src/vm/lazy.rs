@@ -0,0 +1,182 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+/// The tracker for the stacks that are loaded lazily, in order of most recent use.
+pub(crate) struct LazyStacks<N: Network> {
+    /// The maximum number of lazily-loaded stacks to retain in the process.
+    capacity: NonZeroUsize,
+    /// The program IDs of the lazily-loaded stacks, in order of most recent use.
+    recent: LruCache<ProgramID<N>, ()>,
+}
+
+impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
+    /// Initializes the VM from storage, in lazy mode.
+    ///
+    /// Rather than loading every deployed program at startup, each program (and its imports) is loaded
+    /// from storage on first use. At most `capacity` lazily-loaded stacks are retained in the process,
+    /// and the least-recently used stacks are evicted beyond that.
+    #[inline]
+    pub fn from_lazy(store: ConsensusStore<N, C>, capacity: NonZeroUsize) -> Result<Self> {
+        // Initialize a new process.
+        let process = Process::load()?;
+
+        // Initialize the store for 'credits.aleo'.
+        Self::initialize_credits_mappings(&store)?;
+
+        // Initialize the VM.
+        let mut vm = Self::from_process(process, store)?;
+        vm.lazy_stacks = Some(Arc::new(Mutex::new(LazyStacks { capacity, recent: LruCache::unbounded() })));
+        Ok(vm)
+    }
+
+    /// Returns `true` if the VM loads programs lazily.
+    #[inline]
+    pub fn is_lazy(&self) -> bool {
+        self.lazy_stacks.is_some()
+    }
+
+    /// Ensures the programs used by the given transactions are loaded into the process.
+    /// This is a no-op if the VM is not in lazy mode.
+    pub(crate) fn hydrate_transactions<'a>(
+        &self,
+        transactions: impl IntoIterator<Item = &'a Transaction<N>>,
+    ) -> Result<()> {
+        // If the VM is not in lazy mode, return early.
+        if !self.is_lazy() {
+            return Ok(());
+        }
+        // Collect the program IDs used by the transactions.
+        let mut program_ids = IndexSet::new();
+        for transaction in transactions {
+            match transaction {
+                // A deployment requires its imports to be loaded.
+                Transaction::Deploy(_, _, deployment, _) => {
+                    program_ids.extend(deployment.program().imports().keys().copied())
+                }
+                // An execution requires the program of each transition to be loaded.
+                Transaction::Execute(_, execution, _) => {
+                    program_ids.extend(execution.transitions().map(|transition| *transition.program_id()))
+                }
+                Transaction::Fee(..) => (),
+            }
+        }
+        self.hydrate_programs(program_ids.iter())
+    }
+
+    /// Ensures the given programs, and their imports, are loaded into the process.
+    /// Program IDs that are not deployed are skipped, so the caller reports the error in context.
+    /// This is a no-op if the VM is not in lazy mode.
+    pub(crate) fn hydrate_programs<'a>(&self, program_ids: impl IntoIterator<Item = &'a ProgramID<N>>) -> Result<()> {
+        // If the VM is not in lazy mode, return early.
+        let Some(lazy_stacks) = &self.lazy_stacks else {
+            return Ok(());
+        };
+        // Acquire the lock, to ensure programs are loaded and evicted one caller at a time.
+        let mut lazy_stacks = lazy_stacks.lock();
+
+        // Retrieve the transaction store.
+        let transaction_store = self.transaction_store();
+        // Track the program IDs that must remain loaded for this call.
+        let mut required = IndexSet::new();
+        for program_id in program_ids {
+            // Skip 'credits.aleo', as it is always loaded.
+            if program_id == &ProgramID::intern("credits.aleo")? {
+                continue;
+            }
+            // If the program is not loaded, load it and its imports from storage.
+            if !self.process.read().contains_program(program_id) {
+                // Fetch the deployment transaction ID.
+                let Some(transaction_id) =
+                    transaction_store.deployment_store().find_transaction_id_from_program_id(program_id)?
+                else {
+                    continue;
+                };
+                // Retrieve the deployment and its missing imports.
+                let deployments =
+                    Self::load_deployment_and_imports(&self.process.read(), transaction_store, transaction_id)?;
+                // Load the deployments.
+                // Note: Only a read lock is required, as the process supports concurrent program insertion.
+                let process = self.process.read();
+                for (program_id, deployment) in deployments {
+                    if !process.contains_program(&program_id) {
+                        process.load_deployment(&deployment)?;
+                    }
+                    required.insert(program_id);
+                }
+            }
+            required.insert(*program_id);
+        }
+
+        // Mark the required programs as the most recently used.
+        for program_id in &required {
+            lazy_stacks.recent.put(*program_id, ());
+        }
+        // Evict the least-recently used stacks beyond the capacity.
+        // Note: Required programs are never evicted, so the capacity may be exceeded temporarily.
+        while lazy_stacks.recent.len() > lazy_stacks.capacity.get() {
+            match lazy_stacks.recent.peek_lru() {
+                Some((program_id, _)) if !required.contains(program_id) => {
+                    if let Some((program_id, _)) = lazy_stacks.recent.pop_lru() {
+                        self.process.write().remove_stack(&program_id);
+                    }
+                }
+                _ => break,
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::vm::test_helpers::*;
+
+    #[test]
+    fn test_lazy_stack_loading() {
+        let rng = &mut TestRng::default();
+
+        // Initialize a VM, and deploy a program.
+        let vm = sample_vm_with_genesis_block(rng);
+        let transaction = sample_deployment_transaction(rng);
+        let block = sample_next_block(&vm, &sample_genesis_private_key(rng), &[transaction.clone()], rng).unwrap();
+        vm.add_next_block(&block).unwrap();
+        let Transaction::Deploy(_, _, deployment, _) = &transaction else { panic!("Expected a deployment") };
+        let program_id = deployment.program_id();
+
+        // Initialize a lazy VM from the same storage.
+        let lazy = VM::from_lazy(vm.store.clone(), NonZeroUsize::new(1).unwrap()).unwrap();
+        assert!(lazy.is_lazy());
+        // Ensure the program is not loaded, but is reported as deployed.
+        assert!(!lazy.process().read().contains_program(program_id));
+        assert!(lazy.contains_program(program_id));
+
+        // Ensure the program is loaded on first use.
+        lazy.hydrate_programs([program_id]).unwrap();
+        assert!(lazy.process().read().contains_program(program_id));
+        assert!(lazy
+            .process()
+            .read()
+            .get_verifying_key(program_id, deployment.program().functions().keys().next().unwrap())
+            .is_ok());
+
+        // Ensure an undeployed program is skipped.
+        let undeployed = ProgramID::from_str("undeployed_program.aleo").unwrap();
+        lazy.hydrate_programs([&undeployed]).unwrap();
+        assert!(!lazy.contains_program(&undeployed));
+    }
+}
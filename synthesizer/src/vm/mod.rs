@@ -17,10 +17,25 @@ mod helpers;
 pub use helpers::*;
 
 mod authorize;
+mod builder;
+pub use builder::*;
+mod credits;
+pub use credits::*;
 mod deploy;
 mod execute;
+#[cfg(feature = "async")]
+mod execute_async;
+#[cfg(feature = "async")]
+pub use execute_async::*;
 mod finalize;
+mod lazy;
+pub(crate) use lazy::*;
+mod replay;
+#[cfg(any(test, feature = "test-fixtures"))]
+pub mod test_fixtures;
+mod verification_queue;
 mod verify;
+pub use verification_queue::*;
 
 use crate::{cast_mut_ref, cast_ref, convert, process, Restrictions};
 use console::{
@@ -30,6 +45,7 @@ use console::{
     types::{Field, Group, U64},
 };
 use ledger_block::{
+    AbortReason,
     Block,
     ConfirmedTransaction,
     Deployment,
@@ -59,7 +75,18 @@ use ledger_store::{
     TransactionStore,
     TransitionStore,
 };
-use synthesizer_process::{deployment_cost, execution_cost, Authorization, Process, Trace};
+use synthesizer_process::{
+    deployment_cost,
+    execution_cost,
+    execution_cost_for_size,
+    Authorization,
+    DeploymentCheck,
+    FinalizeRecorder,
+    FinalizeTrace,
+    Process,
+    ProcessSnapshot,
+    Trace,
+};
 use synthesizer_program::{FinalizeGlobalState, FinalizeOperation, FinalizeStoreTrait, Program};
 use utilities::try_vm_runtime;
 
@@ -82,14 +109,24 @@ pub struct VM<N: Network, C: ConsensusStorage<N>> {
     puzzle: Puzzle<N>,
     /// The VM store.
     store: ConsensusStore<N, C>,
-    /// A cache containing the list of recent partially-verified transactions.
-    partially_verified_transactions: Arc<RwLock<LruCache<N::TransactionID, N::TransmissionChecksum>>>,
+    /// A cache containing the recent partially-verified transitions, with the checksum of their verified contents.
+    partially_verified_transitions: Arc<RwLock<LruCache<N::TransitionID, N::TransmissionChecksum>>>,
     /// The restrictions list.
     restrictions: Restrictions<N>,
     /// The lock to guarantee atomicity over calls to speculate and finalize.
     atomic_lock: Arc<Mutex<()>>,
     /// The lock for ensuring there is no concurrency when advancing blocks.
     block_lock: Arc<Mutex<()>>,
+    /// The tracker for lazily-loaded stacks, if the VM is in lazy mode.
+    lazy_stacks: Option<Arc<Mutex<LazyStacks<N>>>>,
+    /// The sequencer for pending public fees, if public fee sequencing is enabled.
+    public_fee_sequencer: Option<Arc<Mutex<PublicFeeSequencer<N>>>>,
+    /// The bounded replay protection for rejected executions, if a replay window is configured.
+    replay_protection: Option<Arc<RwLock<ReplayProtection<N>>>>,
+    /// The block height and state root of the latest block.
+    tip: Arc<BlockTip<N>>,
+    /// The number of recent blocks whose state roots are accepted for executions, if the window is bounded.
+    state_root_window: Option<u32>,
 }
 
 impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
@@ -97,67 +134,28 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
     #[inline]
     pub fn from(store: ConsensusStore<N, C>) -> Result<Self> {
         // Initialize a new process.
-        let mut process = Process::load()?;
-
-        // Initialize the store for 'credits.aleo'.
-        let credits = Program::<N>::credits()?;
-        for mapping in credits.mappings().values() {
-            // Ensure that all mappings are initialized.
-            if !store.finalize_store().contains_mapping_confirmed(credits.id(), mapping.name())? {
-                // Initialize the mappings for 'credits.aleo'.
-                store.finalize_store().initialize_mapping(*credits.id(), *mapping.name())?;
-            }
-        }
-
-        // A helper function to retrieve all the deployments.
-        fn load_deployment_and_imports<N: Network, T: TransactionStorage<N>>(
-            process: &Process<N>,
-            transaction_store: &TransactionStore<N, T>,
-            transaction_id: N::TransactionID,
-        ) -> Result<Vec<(ProgramID<N>, Deployment<N>)>> {
-            // Retrieve the deployment from the transaction ID.
-            let deployment = match transaction_store.get_deployment(&transaction_id)? {
-                Some(deployment) => deployment,
-                None => bail!("Deployment transaction '{transaction_id}' is not found in storage."),
-            };
-
-            // Fetch the program from the deployment.
-            let program = deployment.program();
-            let program_id = program.id();
-
-            // Return early if the program is already loaded.
-            if process.contains_program(program_id) {
-                return Ok(vec![]);
-            }
-
-            // Prepare a vector for the deployments.
-            let mut deployments = vec![];
-
-            // Iterate through the program imports.
-            for import_program_id in program.imports().keys() {
-                // Add the imports to the process if does not exist yet.
-                if !process.contains_program(import_program_id) {
-                    // Fetch the deployment transaction ID.
-                    let Some(transaction_id) =
-                        transaction_store.deployment_store().find_transaction_id_from_program_id(import_program_id)?
-                    else {
-                        bail!("Transaction ID for '{program_id}' is not found in storage.");
-                    };
-
-                    // Add the deployment and its imports found recursively.
-                    deployments.extend_from_slice(&load_deployment_and_imports(
-                        process,
-                        transaction_store,
-                        transaction_id,
-                    )?);
-                }
-            }
+        let process = Process::load()?;
+        // Load the deployments from the store, and return the new VM.
+        Self::from_process_and_deployments(process, store)
+    }
 
-            // Once all the imports have been included, add the parent deployment.
-            deployments.push((*program_id, deployment));
+    /// Initializes the VM from storage, sharing the programs of the given base process.
+    ///
+    /// Many VMs (e.g. one per simulated chain in a test framework) may share a single base process.
+    /// Programs deployed to this VM are added to its own overlay of the base process, and are not visible to other VMs.
+    #[inline]
+    pub fn from_shared_process(store: ConsensusStore<N, C>, base: Arc<Process<N>>) -> Result<Self> {
+        // Initialize an overlay of the base process.
+        let process = Process::overlay(&base);
+        // Load the deployments from the store, and return the new VM.
+        Self::from_process_and_deployments(process, store)
+    }
 
-            Ok(deployments)
-        }
+    /// Initializes the VM from the given process and storage, loading the deployments from storage.
+    /// Deployments that already exist in the process are skipped.
+    fn from_process_and_deployments(process: Process<N>, store: ConsensusStore<N, C>) -> Result<Self> {
+        // Initialize the store for 'credits.aleo'.
+        Self::initialize_credits_mappings(&store)?;
 
         // Retrieve the transaction store.
         let transaction_store = store.transaction_store();
@@ -174,7 +172,7 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
             let deployments = cfg_iter!(chunk)
                 .map(|transaction_id| {
                     // Load the deployment and its imports.
-                    load_deployment_and_imports(&process, transaction_store, **transaction_id)
+                    Self::load_deployment_and_imports(&process, transaction_store, **transaction_id)
                 })
                 .collect::<Result<Vec<_>>>()?;
 
@@ -187,23 +185,152 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
         }
 
         // Return the new VM.
+        Self::from_process(process, store)
+    }
+
+    /// Initializes the VM from storage, restoring the process from the given snapshot.
+    ///
+    /// This is significantly faster than `VM::from`, as the deployments are not loaded from storage.
+    /// The snapshot is validated against the programs and verifying keys in storage.
+    #[inline]
+    pub fn from_snapshot(store: ConsensusStore<N, C>, snapshot: &ProcessSnapshot<N>) -> Result<Self> {
+        // Retrieve the deployment store.
+        let deployment_store = store.transaction_store().deployment_store();
+
+        // Ensure the snapshot contains every deployed program.
+        for program_id in deployment_store.program_ids() {
+            ensure!(snapshot.contains_program(&program_id), "The process snapshot is missing program '{program_id}'");
+        }
+        // Ensure the snapshot is consistent with the deployed programs and verifying keys.
+        cfg_iter!(snapshot.programs().collect::<Vec<_>>()).try_for_each(|(program, verifying_keys)| {
+            let program_id = program.id();
+            // Ensure the program matches the deployed program.
+            match deployment_store.get_program(program_id)? {
+                Some(deployed) if &deployed == program => (),
+                Some(_) => bail!("The process snapshot contains a mismatched program '{program_id}'"),
+                None => bail!("The process snapshot contains an undeployed program '{program_id}'"),
+            }
+            // Ensure the verifying keys match the deployed verifying keys.
+            for (function_name, verifying_key) in verifying_keys {
+                match deployment_store.get_verifying_key(program_id, function_name)? {
+                    Some(deployed) if &deployed == verifying_key => (),
+                    _ => bail!(
+                        "The process snapshot contains a mismatched verifying key for '{program_id}/{function_name}'"
+                    ),
+                }
+            }
+            Ok(())
+        })?;
+
+        // Restore the process from the snapshot.
+        let process = Process::from_snapshot(snapshot)?;
+
+        // Initialize the store for 'credits.aleo'.
+        Self::initialize_credits_mappings(&store)?;
+
+        // Return the new VM.
+        Self::from_process(process, store)
+    }
+
+    /// Returns a snapshot of the process, which may be used to restore the VM via `VM::from_snapshot`.
+    #[inline]
+    pub fn snapshot(&self) -> Result<ProcessSnapshot<N>> {
+        self.process.read().snapshot()
+    }
+
+    /// Returns the deployments for the given deployment transaction ID, and its imports (recursively),
+    /// in the order they must be loaded. Programs that already exist in the process are skipped.
+    fn load_deployment_and_imports<T: TransactionStorage<N>>(
+        process: &Process<N>,
+        transaction_store: &TransactionStore<N, T>,
+        transaction_id: N::TransactionID,
+    ) -> Result<Vec<(ProgramID<N>, Deployment<N>)>> {
+        // Retrieve the deployment from the transaction ID.
+        let deployment = match transaction_store.get_deployment(&transaction_id)? {
+            Some(deployment) => deployment,
+            None => bail!("Deployment transaction '{transaction_id}' is not found in storage."),
+        };
+
+        // Fetch the program from the deployment.
+        let program = deployment.program();
+        let program_id = program.id();
+
+        // Return early if the program is already loaded.
+        if process.contains_program(program_id) {
+            return Ok(vec![]);
+        }
+
+        // Prepare a vector for the deployments.
+        let mut deployments = vec![];
+
+        // Iterate through the program imports.
+        for import_program_id in program.imports().keys() {
+            // Add the imports to the process if does not exist yet.
+            if !process.contains_program(import_program_id) {
+                // Fetch the deployment transaction ID.
+                let Some(transaction_id) =
+                    transaction_store.deployment_store().find_transaction_id_from_program_id(import_program_id)?
+                else {
+                    bail!("Transaction ID for '{program_id}' is not found in storage.");
+                };
+
+                // Add the deployment and its imports found recursively.
+                deployments.extend_from_slice(&Self::load_deployment_and_imports(
+                    process,
+                    transaction_store,
+                    transaction_id,
+                )?);
+            }
+        }
+
+        // Once all the imports have been included, add the parent deployment.
+        deployments.push((*program_id, deployment));
+
+        Ok(deployments)
+    }
+
+    /// Initializes the mappings for 'credits.aleo' in the given store, if they do not exist.
+    fn initialize_credits_mappings(store: &ConsensusStore<N, C>) -> Result<()> {
+        let credits = Program::<N>::credits()?;
+        for mapping in credits.mappings().values() {
+            // Ensure that all mappings are initialized.
+            if !store.finalize_store().contains_mapping_confirmed(credits.id(), mapping.name())? {
+                // Initialize the mappings for 'credits.aleo'.
+                store.finalize_store().initialize_mapping(*credits.id(), *mapping.name())?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Initializes the VM from the given process and storage.
+    fn from_process(process: Process<N>, store: ConsensusStore<N, C>) -> Result<Self> {
+        // Initialize the tip from the latest block in storage.
+        let block_store = store.block_store();
+        let tip = BlockTip::new(block_store.try_current_block_height().unwrap_or(0), block_store.current_state_root());
         Ok(Self {
             process: Arc::new(RwLock::new(process)),
             puzzle: Self::new_puzzle()?,
             store,
-            partially_verified_transactions: Arc::new(RwLock::new(LruCache::new(
+            partially_verified_transitions: Arc::new(RwLock::new(LruCache::new(
                 NonZeroUsize::new(Transactions::<N>::MAX_TRANSACTIONS).unwrap(),
             ))),
             restrictions: Restrictions::load()?,
             atomic_lock: Arc::new(Mutex::new(())),
             block_lock: Arc::new(Mutex::new(())),
+            lazy_stacks: None,
+            public_fee_sequencer: None,
+            replay_protection: None,
+            tip: Arc::new(tip),
+            state_root_window: None,
         })
     }
 
     /// Returns `true` if a program with the given program ID exists.
     #[inline]
     pub fn contains_program(&self, program_id: &ProgramID<N>) -> bool {
+        // In lazy mode, a deployed program may not be loaded in the process yet.
         self.process.read().contains_program(program_id)
+            || (self.is_lazy() && self.transaction_store().contains_program_id(program_id).unwrap_or(false))
     }
 
     /// Returns the process.
@@ -218,10 +345,10 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
         &self.puzzle
     }
 
-    /// Returns the partially-verified transactions.
+    /// Returns the partially-verified transitions.
     #[inline]
-    pub fn partially_verified_transactions(&self) -> Arc<RwLock<LruCache<N::TransactionID, N::TransmissionChecksum>>> {
-        self.partially_verified_transactions.clone()
+    pub fn partially_verified_transitions(&self) -> Arc<RwLock<LruCache<N::TransitionID, N::TransmissionChecksum>>> {
+        self.partially_verified_transitions.clone()
     }
 
     /// Returns the restrictions.
@@ -231,6 +358,50 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
     }
 }
 
+impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
+    /// Enables sequencing of pending public fees.
+    ///
+    /// When enabled, `check_transaction` assigns each valid public-fee transaction a per-payer sequence number,
+    /// and only accepts it if the payer's speculative balance covers it together with the payer's other
+    /// pending public fees. This allows a wallet to safely queue several public-fee transactions.
+    ///
+    /// Note: This is intended for mempool admission only. Transactions that are verified for a block,
+    /// i.e. by `check_transactions` or during speculation, never reserve their public fees.
+    #[inline]
+    pub fn with_public_fee_sequencing(mut self) -> Self {
+        self.public_fee_sequencer = Some(Arc::new(Mutex::new(PublicFeeSequencer::default())));
+        self
+    }
+
+    /// Returns `true` if the VM sequences pending public fees.
+    #[inline]
+    pub fn is_public_fee_sequencing(&self) -> bool {
+        self.public_fee_sequencer.is_some()
+    }
+
+    /// Returns the sequence number of the given pending public-fee transaction, if it exists.
+    #[inline]
+    pub fn public_fee_sequence(&self, transaction_id: &N::TransactionID) -> Option<u64> {
+        self.public_fee_sequencer.as_ref().and_then(|sequencer| sequencer.lock().sequence(transaction_id))
+    }
+
+    /// Returns the total amount of pending public fees for the given payer.
+    #[inline]
+    pub fn pending_public_fees(&self, payer: &Address<N>) -> u64 {
+        self.public_fee_sequencer.as_ref().map_or(0, |sequencer| sequencer.lock().pending_amount(payer))
+    }
+
+    /// Releases the pending public fees for the given transaction IDs (e.g. when they are dropped from the mempool).
+    /// This is a no-op if public fee sequencing is not enabled.
+    #[inline]
+    pub fn release_public_fees<'a>(&self, transaction_ids: impl IntoIterator<Item = &'a N::TransactionID>) {
+        if let Some(sequencer) = &self.public_fee_sequencer {
+            let mut sequencer = sequencer.lock();
+            transaction_ids.into_iter().for_each(|transaction_id| sequencer.release(transaction_id));
+        }
+    }
+}
+
 impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
     /// Returns the finalize store.
     #[inline]
@@ -238,6 +409,22 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
         self.store.finalize_store()
     }
 
+    /// Returns the block height of the latest block.
+    ///
+    /// Note: Unlike `BlockStore::current_block_height`, this does not acquire the block tree lock.
+    #[inline]
+    pub fn current_block_height(&self) -> u32 {
+        self.tip.height()
+    }
+
+    /// Returns the state root, as of the latest block.
+    ///
+    /// Note: Unlike `BlockStore::current_state_root`, this does not acquire the block tree lock.
+    #[inline]
+    pub fn current_state_root(&self) -> N::StateRoot {
+        self.tip.state_root()
+    }
+
     /// Returns the block store.
     #[inline]
     pub fn block_store(&self) -> &BlockStore<N, C::BlockStorage> {
@@ -350,7 +537,7 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
             vec![Ratify::Genesis(Box::new(committee), Box::new(public_balances), Box::new(bonded_balances))];
         // Prepare the solutions.
         let solutions = Solutions::<N>::from(None); // The genesis block does not require solutions.
-        // Prepare the aborted solution IDs.
+                                                    // Prepare the aborted solution IDs.
         let aborted_solution_ids = vec![];
         // Prepare the transactions.
         let transactions = (0..Block::<N>::NUM_GENESIS_TRANSACTIONS)
@@ -431,6 +618,19 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
                 // Unpause the atomic writes, executing the ones queued from block insertion and finalization.
                 #[cfg(feature = "rocks")]
                 self.block_store().unpause_atomic_writes::<false>()?;
+                // Update the tip to the new block.
+                self.tip.update(block.height(), self.block_store().current_state_root());
+                // Update the replay protection with the rejected executions in the block.
+                self.update_replay_protection(block)?;
+                // Release the pending public fees of the transactions in the block.
+                if self.is_public_fee_sequencing() {
+                    let transaction_ids = block
+                        .transactions()
+                        .iter()
+                        .map(|transaction| transaction.to_unconfirmed_transaction_id())
+                        .collect::<Result<Vec<_>>>()?;
+                    self.release_public_fees(transaction_ids.iter().chain(block.aborted_transaction_ids()));
+                }
                 Ok(())
             }
             Err(finalize_error) => {
@@ -467,7 +667,7 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
 
 #[cfg(test)]
 pub(crate) mod test_helpers {
-    use super::*;
+    use super::{test_fixtures::*, *};
     use console::{
         account::{Address, ViewKey},
         network::MainnetV0,
@@ -506,26 +706,18 @@ pub(crate) mod test_helpers {
         VM::from(ConsensusStore::open(path.to_owned()).unwrap()).unwrap()
     }
 
+    /// Returns the fixtures shared by the tests, sampled from the given RNG on first use.
+    pub(crate) fn sample_fixtures(rng: &mut TestRng) -> &'static TestFixtures<CurrentNetwork> {
+        static INSTANCE: OnceCell<TestFixtures<CurrentNetwork>> = OnceCell::new();
+        INSTANCE.get_or_init(|| TestFixtures::new(rng.gen()).unwrap())
+    }
+
     pub(crate) fn sample_genesis_private_key(rng: &mut TestRng) -> PrivateKey<CurrentNetwork> {
-        static INSTANCE: OnceCell<PrivateKey<CurrentNetwork>> = OnceCell::new();
-        *INSTANCE.get_or_init(|| {
-            // Initialize a new caller.
-            PrivateKey::<CurrentNetwork>::new(rng).unwrap()
-        })
+        *sample_fixtures(rng).private_key()
     }
 
     pub(crate) fn sample_genesis_block(rng: &mut TestRng) -> Block<CurrentNetwork> {
-        static INSTANCE: OnceCell<Block<CurrentNetwork>> = OnceCell::new();
-        INSTANCE
-            .get_or_init(|| {
-                // Initialize the VM.
-                let vm = crate::vm::test_helpers::sample_vm();
-                // Initialize a new caller.
-                let caller_private_key = crate::vm::test_helpers::sample_genesis_private_key(rng);
-                // Return the block.
-                vm.genesis_beacon(&caller_private_key, rng).unwrap()
-            })
-            .clone()
+        sample_fixtures(rng).genesis_block().clone()
     }
 
     pub(crate) fn sample_vm_with_genesis_block(
@@ -542,220 +734,23 @@ pub(crate) mod test_helpers {
     }
 
     pub(crate) fn sample_program() -> Program<CurrentNetwork> {
-        static INSTANCE: OnceCell<Program<CurrentNetwork>> = OnceCell::new();
-        INSTANCE
-            .get_or_init(|| {
-                // Initialize a new program.
-                Program::<CurrentNetwork>::from_str(
-                    r"
-program testing.aleo;
-
-struct message:
-    amount as u128;
-
-mapping account:
-    key as address.public;
-    value as u64.public;
-
-record token:
-    owner as address.private;
-    amount as u64.private;
-
-function initialize:
-    input r0 as address.private;
-    input r1 as u64.private;
-    cast r0 r1 into r2 as token.record;
-    output r2 as token.record;
-
-function compute:
-    input r0 as message.private;
-    input r1 as message.public;
-    input r2 as message.private;
-    input r3 as token.record;
-    add r0.amount r1.amount into r4;
-    cast r3.owner r3.amount into r5 as token.record;
-    output r4 as u128.public;
-    output r5 as token.record;",
-                )
-                .unwrap()
-            })
-            .clone()
+        Program::from_str(SAMPLE_PROGRAM).unwrap()
     }
 
     pub(crate) fn sample_deployment_transaction(rng: &mut TestRng) -> Transaction<CurrentNetwork> {
-        static INSTANCE: OnceCell<Transaction<CurrentNetwork>> = OnceCell::new();
-        INSTANCE
-            .get_or_init(|| {
-                // Initialize the program.
-                let program = sample_program();
-
-                // Initialize a new caller.
-                let caller_private_key = crate::vm::test_helpers::sample_genesis_private_key(rng);
-                let caller_view_key = ViewKey::try_from(&caller_private_key).unwrap();
-
-                // Initialize the genesis block.
-                let genesis = crate::vm::test_helpers::sample_genesis_block(rng);
-
-                // Fetch the unspent records.
-                let records =
-                    genesis.transitions().cloned().flat_map(Transition::into_records).collect::<IndexMap<_, _>>();
-                trace!("Unspent Records:\n{:#?}", records);
-
-                // Prepare the fee.
-                let credits = Some(records.values().next().unwrap().decrypt(&caller_view_key).unwrap());
-
-                // Initialize the VM.
-                let vm = sample_vm();
-                // Update the VM.
-                vm.add_next_block(&genesis).unwrap();
-
-                // Deploy.
-                let transaction = vm.deploy(&caller_private_key, &program, credits, 10, None, rng).unwrap();
-                // Verify.
-                vm.check_transaction(&transaction, None, rng).unwrap();
-                // Return the transaction.
-                transaction
-            })
-            .clone()
+        sample_fixtures(rng).deployment_transaction().unwrap()
     }
 
     pub(crate) fn sample_execution_transaction_without_fee(rng: &mut TestRng) -> Transaction<CurrentNetwork> {
-        static INSTANCE: OnceCell<Transaction<CurrentNetwork>> = OnceCell::new();
-        INSTANCE
-            .get_or_init(|| {
-                // Initialize a new caller.
-                let caller_private_key = crate::vm::test_helpers::sample_genesis_private_key(rng);
-                let caller_view_key = ViewKey::try_from(&caller_private_key).unwrap();
-
-                // Initialize the genesis block.
-                let genesis = crate::vm::test_helpers::sample_genesis_block(rng);
-
-                // Fetch the unspent records.
-                let records =
-                    genesis.transitions().cloned().flat_map(Transition::into_records).collect::<IndexMap<_, _>>();
-                trace!("Unspent Records:\n{:#?}", records);
-
-                // Select a record to spend.
-                let record = records.values().next().unwrap().decrypt(&caller_view_key).unwrap();
-
-                // Initialize the VM.
-                let vm = sample_vm();
-                // Update the VM.
-                vm.add_next_block(&genesis).unwrap();
-
-                // Prepare the inputs.
-                let inputs =
-                    [Value::<CurrentNetwork>::Record(record), Value::<CurrentNetwork>::from_str("1u64").unwrap()]
-                        .into_iter();
-
-                // Authorize.
-                let authorization = vm.authorize(&caller_private_key, "credits.aleo", "split", inputs, rng).unwrap();
-                assert_eq!(authorization.len(), 1);
-
-                // Construct the execute transaction.
-                let transaction = vm.execute_authorization(authorization, None, None, rng).unwrap();
-                // Verify.
-                vm.check_transaction(&transaction, None, rng).unwrap();
-                // Return the transaction.
-                transaction
-            })
-            .clone()
+        sample_fixtures(rng).execution_transaction_without_fee().unwrap()
     }
 
     pub(crate) fn sample_execution_transaction_with_private_fee(rng: &mut TestRng) -> Transaction<CurrentNetwork> {
-        static INSTANCE: OnceCell<Transaction<CurrentNetwork>> = OnceCell::new();
-        INSTANCE
-            .get_or_init(|| {
-                // Initialize a new caller.
-                let caller_private_key = crate::vm::test_helpers::sample_genesis_private_key(rng);
-                let caller_view_key = ViewKey::try_from(&caller_private_key).unwrap();
-                let address = Address::try_from(&caller_private_key).unwrap();
-
-                // Initialize the genesis block.
-                let genesis = crate::vm::test_helpers::sample_genesis_block(rng);
-
-                // Fetch the unspent records.
-                let records =
-                    genesis.transitions().cloned().flat_map(Transition::into_records).collect::<IndexMap<_, _>>();
-                trace!("Unspent Records:\n{:#?}", records);
-
-                // Select a record to spend.
-                let record = Some(records.values().next().unwrap().decrypt(&caller_view_key).unwrap());
-
-                // Initialize the VM.
-                let vm = sample_vm();
-                // Update the VM.
-                vm.add_next_block(&genesis).unwrap();
-
-                // Prepare the inputs.
-                let inputs = [
-                    Value::<CurrentNetwork>::from_str(&address.to_string()).unwrap(),
-                    Value::<CurrentNetwork>::from_str("1u64").unwrap(),
-                ]
-                .into_iter();
-
-                // Execute.
-                let transaction = vm
-                    .execute(&caller_private_key, ("credits.aleo", "transfer_public"), inputs, record, 0, None, rng)
-                    .unwrap();
-                // Verify.
-                vm.check_transaction(&transaction, None, rng).unwrap();
-                // Return the transaction.
-                transaction
-            })
-            .clone()
+        sample_fixtures(rng).execution_transaction_with_private_fee().unwrap()
     }
 
     pub(crate) fn sample_execution_transaction_with_public_fee(rng: &mut TestRng) -> Transaction<CurrentNetwork> {
-        static INSTANCE: OnceCell<Transaction<CurrentNetwork>> = OnceCell::new();
-        INSTANCE
-            .get_or_init(|| {
-                // Initialize a new caller.
-                let caller_private_key = crate::vm::test_helpers::sample_genesis_private_key(rng);
-                let address = Address::try_from(&caller_private_key).unwrap();
-
-                // Initialize the genesis block.
-                let genesis = crate::vm::test_helpers::sample_genesis_block(rng);
-
-                // Initialize the VM.
-                let vm = sample_vm();
-                // Update the VM.
-                vm.add_next_block(&genesis).unwrap();
-
-                // Prepare the inputs.
-                let inputs = [
-                    Value::<CurrentNetwork>::from_str(&address.to_string()).unwrap(),
-                    Value::<CurrentNetwork>::from_str("1u64").unwrap(),
-                ]
-                .into_iter();
-
-                // Execute.
-                let transaction_without_fee = vm
-                    .execute(&caller_private_key, ("credits.aleo", "transfer_public"), inputs, None, 0, None, rng)
-                    .unwrap();
-                let execution = transaction_without_fee.execution().unwrap().clone();
-
-                // Authorize the fee.
-                let authorization = vm
-                    .authorize_fee_public(
-                        &caller_private_key,
-                        10_000_000,
-                        100,
-                        execution.to_execution_id().unwrap(),
-                        rng,
-                    )
-                    .unwrap();
-                // Compute the fee.
-                let fee = vm.execute_fee_authorization(authorization, None, rng).unwrap();
-
-                // Construct the transaction.
-                let transaction = Transaction::from_execution(execution, Some(fee)).unwrap();
-                // Verify.
-                vm.check_transaction(&transaction, None, rng).unwrap();
-                // Return the transaction.
-                transaction
-            })
-            .clone()
+        sample_fixtures(rng).execution_transaction_with_public_fee().unwrap()
     }
 
     pub fn sample_next_block<R: Rng + CryptoRng>(
@@ -1075,6 +1070,59 @@ function a:
 
         // Enforce that the VM can load properly with the imports.
         assert!(VM::from(vm.store.clone()).is_ok());
+
+        // Enforce that the VM can be restored from a snapshot, with the imports.
+        let snapshot = vm.snapshot().unwrap();
+        assert_eq!(snapshot.len(), 4);
+        let restored = VM::from_snapshot(vm.store.clone(), &snapshot).unwrap();
+        for deployment in [&deployment_1, &deployment_2, &deployment_3, &deployment_4] {
+            let Transaction::Deploy(_, _, deployment, _) = deployment else { panic!("Expected a deployment") };
+            assert!(restored.contains_program(deployment.program_id()));
+        }
+
+        // Enforce that an incomplete snapshot is rejected.
+        let incomplete = ProcessSnapshot::new(snapshot.programs().take(3).cloned().collect()).unwrap();
+        assert!(VM::from_snapshot(vm.store.clone(), &incomplete).is_err());
+    }
+
+    #[test]
+    fn test_vms_with_shared_process() {
+        let rng = &mut TestRng::default();
+
+        // Initialize a new caller.
+        let caller_private_key = crate::vm::test_helpers::sample_genesis_private_key(rng);
+        // Initialize the genesis block.
+        let genesis = crate::vm::test_helpers::sample_genesis_block(rng);
+        // Initialize the deployment.
+        let deployment = crate::vm::test_helpers::sample_deployment_transaction(rng);
+        let program_id = *sample_program().id();
+
+        // Initialize two VMs that share a base process.
+        let base = Arc::new(Process::load().unwrap());
+        let vm_a = VM::from_shared_process(ConsensusStore::open(None).unwrap(), base.clone()).unwrap();
+        let vm_b = VM::from_shared_process(ConsensusStore::open(None).unwrap(), base.clone()).unwrap();
+        vm_a.add_next_block(&genesis).unwrap();
+        vm_b.add_next_block(&genesis).unwrap();
+
+        // Deploy the program in the first VM.
+        let block = sample_next_block(&vm_a, &caller_private_key, &[deployment], rng).unwrap();
+        vm_a.add_next_block(&block).unwrap();
+
+        // Ensure the program is only visible to the first VM.
+        assert!(vm_a.contains_program(&program_id));
+        assert!(!vm_b.contains_program(&program_id));
+        assert!(!base.contains_program(&program_id));
+
+        // Ensure both VMs share the 'credits.aleo' stack of the base process.
+        let credits_program_id = ProgramID::from_str("credits.aleo").unwrap();
+        let stack_a = vm_a.process().read().get_stack(credits_program_id).unwrap();
+        let stack_b = vm_b.process().read().get_stack(credits_program_id).unwrap();
+        assert!(Arc::ptr_eq(&stack_a, &stack_b));
+
+        // Ensure a VM restored from the first VM's storage loads the program into its overlay.
+        let restored = VM::from_shared_process(vm_a.store.clone(), base.clone()).unwrap();
+        assert!(restored.contains_program(&program_id));
+        assert!(!base.contains_program(&program_id));
     }
 
     #[test]
@@ -1141,6 +1189,28 @@ function multitransfer:
                 rng,
             )
             .unwrap();
+
+        // Verify the execution, which adds its transitions to the partially-verified cache.
+        vm.check_transaction(&execution, None, rng).unwrap();
+        assert!(vm.is_partially_verified(&execution));
+        let transitions = execution.transitions().cloned().collect::<Vec<_>>();
+        let (global_state_root, proof) = {
+            let execution = execution.execution().unwrap();
+            (execution.global_state_root(), execution.proof().cloned())
+        };
+
+        // Ensure a subset of the transitions under the same proof is re-verified, and rejected.
+        let subset = Execution::from(transitions[1..].iter().cloned(), global_state_root, proof.clone()).unwrap();
+        let subset_transaction = Transaction::from_execution(subset, None).unwrap();
+        assert!(!vm.is_partially_verified(&subset_transaction));
+        assert!(vm.check_transaction(&subset_transaction, None, rng).is_err());
+
+        // Ensure a permutation of the transitions under the same proof is re-verified, and rejected.
+        let permuted = Execution::from(transitions.iter().rev().cloned(), global_state_root, proof).unwrap();
+        let permuted_transaction = Transaction::from_execution(permuted, None).unwrap();
+        assert!(!vm.is_partially_verified(&permuted_transaction));
+        assert!(vm.check_transaction(&permuted_transaction, None, rng).is_err());
+
         vm.add_next_block(&sample_next_block(&vm, &caller_private_key, &[execution], rng).unwrap()).unwrap();
     }
 
@@ -1305,10 +1375,9 @@ function call_fee_private:
             Value::<MainnetV0>::from_str(&format!("{}u64", internal_priority_fee_amount)).unwrap(),
             Value::<MainnetV0>::from_str("1field").unwrap(),
         ];
-        assert!(
-            vm.execute(&private_key, ("test_program.aleo", "call_fee_public"), inputs.into_iter(), None, 0, None, rng)
-                .is_err()
-        );
+        assert!(vm
+            .execute(&private_key, ("test_program.aleo", "call_fee_public"), inputs.into_iter(), None, 0, None, rng)
+            .is_err());
 
         // Ensure that the transaction that calls `fee_private` internally cannot be generated.
         let inputs = [
@@ -1317,10 +1386,9 @@ function call_fee_private:
             Value::<MainnetV0>::from_str(&format!("{}u64", internal_priority_fee_amount)).unwrap(),
             Value::<MainnetV0>::from_str("1field").unwrap(),
         ];
-        assert!(
-            vm.execute(&private_key, ("test_program.aleo", "call_fee_private"), inputs.into_iter(), None, 0, None, rng)
-                .is_err()
-        );
+        assert!(vm
+            .execute(&private_key, ("test_program.aleo", "call_fee_private"), inputs.into_iter(), None, 0, None, rng)
+            .is_err());
     }
 
     #[test]
@@ -1357,6 +1425,54 @@ function do:
         assert!(vm.check_transaction(&deployment, None, rng).is_err());
     }
 
+    #[test]
+    fn test_check_deployment_syntactic() {
+        let rng = &mut TestRng::default();
+
+        // Initialize the genesis block.
+        let genesis = sample_genesis_block(rng);
+
+        // Initialize the VM.
+        let vm = sample_vm();
+        // Update the VM.
+        vm.add_next_block(&genesis).unwrap();
+
+        // Check the sample program.
+        let program = sample_program();
+        let check = vm.check_deployment_syntactic(&program, rng).unwrap();
+        assert_eq!(check.program_id(), program.id());
+        assert_eq!(check.functions().len(), program.functions().len());
+        assert!(check.num_combined_constraints().unwrap() > 0);
+        assert!(check.num_combined_variables().unwrap() > 0);
+
+        // Check a program that exceeds the constraint limit.
+        let program = Program::from_str(
+            r"
+program synthesis_overload.aleo;
+
+function do:
+    input r0 as [[u128; 32u32]; 2u32].private;
+    hash.sha3_256 r0 into r1 as field;
+    output r1 as field.public;",
+        )
+        .unwrap();
+        assert!(vm.check_deployment_syntactic(&program, rng).is_err());
+
+        // Check a program with a missing import.
+        let program = Program::from_str(
+            r"
+import missing_import.aleo;
+
+program missing_dependency.aleo;
+
+function do:
+    input r0 as u8.private;
+    output r0 as u8.public;",
+        )
+        .unwrap();
+        assert!(vm.check_deployment_syntactic(&program, rng).is_err());
+    }
+
     #[test]
     fn test_deployment_num_constant_overload() {
         let rng = &mut TestRng::default();
@@ -2392,17 +2508,13 @@ finalize transfer_public_to_private:
         assert!(vm.transition_store().get_record(commitment).unwrap().is_some());
 
         // Check that the serial number of the record does not exist in the VM.
-        assert!(
-            !vm.transition_store()
-                .contains_serial_number(
-                    &Record::<CurrentNetwork, Plaintext<CurrentNetwork>>::serial_number(
-                        recipient_private_key,
-                        *commitment
-                    )
+        assert!(!vm
+            .transition_store()
+            .contains_serial_number(
+                &Record::<CurrentNetwork, Plaintext<CurrentNetwork>>::serial_number(recipient_private_key, *commitment)
                     .unwrap()
-                )
-                .unwrap()
-        );
+            )
+            .unwrap());
     }
 
     #[test]
@@ -2512,6 +2624,34 @@ finalize transfer_public_to_private:
         vm.puzzle.prove(rng.gen(), rng.gen(), rng.gen(), None).unwrap();
     }
 
+    #[test]
+    fn test_vm_tip() {
+        let rng = &mut TestRng::default();
+
+        // Initialize a genesis private key.
+        let genesis_private_key = sample_genesis_private_key(rng);
+
+        // Ensure the tip tracks the block store, as blocks are added.
+        let vm = sample_vm_with_genesis_block(rng);
+        assert_eq!(vm.current_block_height(), 0);
+        assert_eq!(vm.current_state_root(), vm.block_store().current_state_root());
+        let block = sample_next_block(&vm, &genesis_private_key, &[], rng).unwrap();
+        vm.add_next_block(&block).unwrap();
+        assert_eq!(vm.current_block_height(), vm.block_store().current_block_height());
+        assert_eq!(vm.current_block_height(), 1);
+        assert_eq!(vm.current_state_root(), vm.block_store().current_state_root());
+
+        // Ensure the tip is not advanced by a block that fails to be added.
+        assert!(vm.add_next_block(&block).is_err());
+        assert_eq!(vm.current_block_height(), 1);
+        assert_eq!(vm.current_state_root(), vm.block_store().current_state_root());
+
+        // Ensure a VM restored from storage initializes its tip from the latest block.
+        let restored = VM::from(vm.store.clone()).unwrap();
+        assert_eq!(restored.current_block_height(), 1);
+        assert_eq!(restored.current_state_root(), vm.current_state_root());
+    }
+
     #[cfg(feature = "rocks")]
     #[test]
     fn test_atomic_unpause_on_error() {
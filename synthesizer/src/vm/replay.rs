@@ -0,0 +1,237 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
+    /// Bounds the rejected execution ID replay check for mempool admission by the given replay window.
+    ///
+    /// Rather than consulting the full history of rejected executions, the replay check retains the rejected
+    /// execution IDs of the most recent blocks exactly, and folds older ones into a compact accumulator.
+    /// See `ReplayWindow` for the trade-offs of this configuration. As the accumulator may report false positives,
+    /// transactions in blocks are still checked against the full history of rejected executions.
+    pub fn with_replay_window(mut self, window: ReplayWindow) -> Result<Self> {
+        // Initialize the replay protection.
+        let mut replay_protection = ReplayProtection::new(window);
+        // Populate the replay protection from the blocks in storage.
+        let mut heights = self.block_store().heights().map(|height| *height).collect::<Vec<_>>();
+        heights.sort_unstable();
+        for height in heights {
+            let Some(block_hash) = self.block_store().get_block_hash(height)? else {
+                bail!("Missing the block hash for height {height}")
+            };
+            let Some(transactions) = self.block_store().get_block_transactions(&block_hash)? else {
+                bail!("Missing the transactions for block {height}")
+            };
+            replay_protection.insert(height, Self::rejected_execution_ids(&transactions)?);
+        }
+        self.replay_protection = Some(Arc::new(RwLock::new(replay_protection)));
+        Ok(self)
+    }
+
+    /// Returns the replay window, if the replay check is bounded.
+    pub fn replay_window(&self) -> Option<ReplayWindow> {
+        self.replay_protection.as_ref().map(|replay_protection| *replay_protection.read().window())
+    }
+
+    /// Returns the number of rejected execution IDs that are retained exactly and accumulated, respectively,
+    /// along with the estimated false positive rate of the accumulator, if the replay check is bounded.
+    pub fn replay_protection_stats(&self) -> Option<(usize, usize, f64)> {
+        self.replay_protection.as_ref().map(|replay_protection| {
+            let replay_protection = replay_protection.read();
+            (
+                replay_protection.num_recent(),
+                replay_protection.num_accumulated(),
+                replay_protection.estimated_false_positive_rate(),
+            )
+        })
+    }
+
+    /// Returns `true` if the given execution ID was previously rejected, according to the replay protection.
+    ///
+    /// Note: The accumulator may report false positives, so this is only used for mempool admission.
+    /// A transaction in a block is checked against the rejected execution IDs in the block store exactly.
+    pub(crate) fn contains_rejected_execution_id(&self, execution_id: &Field<N>) -> Result<bool> {
+        match &self.replay_protection {
+            Some(replay_protection) => Ok(replay_protection.read().contains(execution_id)),
+            None => self.block_store().contains_rejected_deployment_or_execution_id(execution_id),
+        }
+    }
+
+    /// Updates the replay protection with the rejected executions in the given block.
+    /// This is a no-op if the replay check is not bounded.
+    pub(crate) fn update_replay_protection(&self, block: &Block<N>) -> Result<()> {
+        if let Some(replay_protection) = &self.replay_protection {
+            replay_protection.write().insert(block.height(), Self::rejected_execution_ids(block.transactions())?);
+        }
+        Ok(())
+    }
+
+    /// Returns the rejected execution IDs in the given transactions.
+    fn rejected_execution_ids(transactions: &Transactions<N>) -> Result<Vec<Field<N>>> {
+        transactions
+            .iter()
+            .filter_map(|transaction| transaction.to_rejected())
+            .filter(|rejected| rejected.is_execution())
+            .map(|rejected| rejected.to_id())
+            .collect()
+    }
+}
+
+impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
+    /// Bounds the age of the global state root of an execution to the given number of recent blocks.
+    ///
+    /// By default, an execution may reference the state root of any block in the ledger. With a window of `blocks`,
+    /// only the state roots of the `blocks` most recent blocks are accepted (i.e. a window of 1 only accepts the
+    /// latest state root). A smaller window limits how long a signed execution remains valid for inclusion,
+    /// at the cost of rejecting executions that were proven against an older state.
+    ///
+    /// As the window is configured by each node, it only governs mempool admission. A transaction in a block
+    /// may reference the state root of any block in the ledger.
+    pub fn with_state_root_window(mut self, blocks: u32) -> Result<Self> {
+        ensure!(blocks > 0, "The state root window must contain at least one block");
+        self.state_root_window = Some(blocks);
+        Ok(self)
+    }
+
+    /// Returns the number of recent blocks whose state roots are accepted for executions, if the window is bounded.
+    pub const fn state_root_window(&self) -> Option<u32> {
+        self.state_root_window
+    }
+
+    /// Returns `true` if the given state root exists, and is within the state root window.
+    /// On a state root that exists but is older than the window, returns an error.
+    pub(crate) fn contains_state_root_in_window(&self, state_root: &N::StateRoot) -> Result<bool> {
+        let Some(window) = self.state_root_window else {
+            return self.block_store().contains_state_root(state_root);
+        };
+        match self.block_store().find_block_height_from_state_root(*state_root)? {
+            Some(height) => {
+                let age = self.current_block_height().saturating_sub(height);
+                ensure!(
+                    age < window,
+                    "The global state root is {age} blocks old, beyond the window of {window} blocks"
+                );
+                Ok(true)
+            }
+            None => Ok(false),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::vm::test_helpers::{
+        sample_execution_transaction_with_private_fee,
+        sample_genesis_block,
+        sample_genesis_private_key,
+        sample_next_block,
+        sample_vm,
+        sample_vm_with_genesis_block,
+        CurrentNetwork,
+    };
+
+    #[test]
+    fn test_replay_window() {
+        let rng = &mut TestRng::default();
+
+        // Initialize the VM.
+        let vm = sample_vm();
+        // Update the VM.
+        vm.add_next_block(&sample_genesis_block(rng)).unwrap();
+        assert!(vm.replay_window().is_none());
+        assert!(vm.replay_protection_stats().is_none());
+
+        // Bound the replay check.
+        let window = ReplayWindow::new(10, 1_000, 0.001).unwrap();
+        let vm = vm.with_replay_window(window).unwrap();
+        assert_eq!(vm.replay_window(), Some(window));
+        assert_eq!(vm.replay_protection_stats(), Some((0, 0, 0.0)));
+
+        // Ensure an unknown execution ID is not reported as rejected.
+        assert!(!vm.contains_rejected_execution_id(&Field::<CurrentNetwork>::rand(rng)).unwrap());
+    }
+
+    #[test]
+    fn test_replay_window_is_mempool_only() {
+        let rng = &mut TestRng::default();
+
+        // Initialize the VM, with a bounded replay check.
+        let window = ReplayWindow::new(10, 1_000, 0.001).unwrap();
+        let vm = sample_vm_with_genesis_block(rng).with_replay_window(window).unwrap();
+
+        // Sample an execution, and report it as rejected in the replay protection, as a false positive would.
+        let transaction = sample_execution_transaction_with_private_fee(rng);
+        let execution_id = transaction.execution().unwrap().to_execution_id().unwrap();
+        vm.replay_protection.as_ref().unwrap().write().insert(vm.current_block_height(), [execution_id]);
+        assert!(vm.contains_rejected_execution_id(&execution_id).unwrap());
+
+        // Ensure the execution is refused for mempool admission, but remains valid in a block.
+        assert!(vm.check_transaction(&transaction, None, rng).is_err());
+        vm.check_block_transaction(&transaction, None, rng).unwrap();
+    }
+
+    #[test]
+    fn test_state_root_window() {
+        let rng = &mut TestRng::default();
+
+        // Initialize the VM, with the genesis block and one more block.
+        let genesis_private_key = sample_genesis_private_key(rng);
+        let vm = sample_vm_with_genesis_block(rng);
+        let genesis_state_root = vm.current_state_root();
+        let block = sample_next_block(&vm, &genesis_private_key, &[], rng).unwrap();
+        vm.add_next_block(&block).unwrap();
+        let latest_state_root = vm.current_state_root();
+
+        // Ensure the recent state roots are ordered from the latest block.
+        assert_eq!(vm.block_store().recent_state_roots(1).unwrap(), vec![latest_state_root]);
+        assert_eq!(vm.block_store().recent_state_roots(5).unwrap(), vec![latest_state_root, genesis_state_root]);
+        assert!(vm.block_store().recent_state_roots(0).unwrap().is_empty());
+
+        // Ensure the state roots are unbounded by default.
+        assert!(vm.state_root_window().is_none());
+        assert!(vm.contains_state_root_in_window(&genesis_state_root).unwrap());
+        assert!(vm.contains_state_root_in_window(&latest_state_root).unwrap());
+
+        // Ensure a window of one block only accepts the latest state root.
+        assert!(vm.clone().with_state_root_window(0).is_err());
+        let vm = vm.with_state_root_window(1).unwrap();
+        assert_eq!(vm.state_root_window(), Some(1));
+        assert!(vm.contains_state_root_in_window(&genesis_state_root).is_err());
+        assert!(vm.contains_state_root_in_window(&latest_state_root).unwrap());
+        assert!(!vm.contains_state_root_in_window(&rng.gen()).unwrap());
+    }
+
+    #[test]
+    fn test_state_root_window_is_mempool_only() {
+        let rng = &mut TestRng::default();
+
+        // Initialize the VM, and sample an execution against the genesis state root.
+        let genesis_private_key = sample_genesis_private_key(rng);
+        let vm = sample_vm_with_genesis_block(rng);
+        let transaction = sample_execution_transaction_with_private_fee(rng);
+        assert_eq!(transaction.execution().unwrap().global_state_root(), vm.current_state_root());
+
+        // Add a block, and only accept the latest state root.
+        let block = sample_next_block(&vm, &genesis_private_key, &[], rng).unwrap();
+        vm.add_next_block(&block).unwrap();
+        let vm = vm.with_state_root_window(1).unwrap();
+
+        // Ensure the execution is refused for mempool admission, but remains valid in a block.
+        assert!(vm.check_transaction(&transaction, None, rng).is_err());
+        vm.check_block_transaction(&transaction, None, rng).unwrap();
+    }
+}
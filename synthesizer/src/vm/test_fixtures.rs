@@ -0,0 +1,294 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Deterministically-sampled fixtures for integration tests, enabled by the `test-fixtures` feature.
+//!
+//! The fixtures are sampled from a seed, so the same seed always yields the same genesis block,
+//! keys, and transactions. Each fixture is sampled lazily on first use, and cached thereafter.
+
+use super::*;
+use console::account::ViewKey;
+use ledger_block::{Metadata, Transition};
+use ledger_store::helpers::memory::ConsensusMemory;
+
+use std::{borrow::Borrow, sync::OnceLock};
+
+/// The default seed for the sampled fixtures.
+pub const DEFAULT_FIXTURE_SEED: u64 = 1_234_567_890;
+
+/// The program of the sampled deployment transaction.
+pub const SAMPLE_PROGRAM: &str = r"
+program testing.aleo;
+
+struct message:
+    amount as u128;
+
+mapping account:
+    key as address.public;
+    value as u64.public;
+
+record token:
+    owner as address.private;
+    amount as u64.private;
+
+function initialize:
+    input r0 as address.private;
+    input r1 as u64.private;
+    cast r0 r1 into r2 as token.record;
+    output r2 as token.record;
+
+function compute:
+    input r0 as message.private;
+    input r1 as message.public;
+    input r2 as message.private;
+    input r3 as token.record;
+    add r0.amount r1.amount into r4;
+    cast r3.owner r3.amount into r5 as token.record;
+    output r4 as u128.public;
+    output r5 as token.record;";
+
+/// A set of fixtures sampled from a seed.
+pub struct TestFixtures<N: Network> {
+    /// The seed of the fixtures.
+    seed: u64,
+    /// The private key of the genesis committee member, which owns the genesis records.
+    private_key: PrivateKey<N>,
+    /// The genesis block.
+    genesis_block: Block<N>,
+    /// The deployment transaction of `SAMPLE_PROGRAM`, with a private fee.
+    deployment_transaction: OnceLock<Transaction<N>>,
+    /// The execution transaction of `credits.aleo/split`, without a fee.
+    execution_transaction_without_fee: OnceLock<Transaction<N>>,
+    /// The execution transaction of `credits.aleo/transfer_public`, with a private fee.
+    execution_transaction_with_private_fee: OnceLock<Transaction<N>>,
+    /// The execution transaction of `credits.aleo/transfer_public`, with a public fee.
+    execution_transaction_with_public_fee: OnceLock<Transaction<N>>,
+}
+
+impl<N: Network> TestFixtures<N> {
+    /// Samples the private key and genesis block for the given seed.
+    pub fn new(seed: u64) -> Result<Self> {
+        let rng = &mut TestRng::fixed(seed);
+        // Sample the private key.
+        let private_key = PrivateKey::new(rng)?;
+        // Sample the genesis block.
+        let genesis_block =
+            VM::from(ConsensusStore::<N, ConsensusMemory<N>>::open(None)?)?.genesis_beacon(&private_key, rng)?;
+        Ok(Self {
+            seed,
+            private_key,
+            genesis_block,
+            deployment_transaction: Default::default(),
+            execution_transaction_without_fee: Default::default(),
+            execution_transaction_with_private_fee: Default::default(),
+            execution_transaction_with_public_fee: Default::default(),
+        })
+    }
+
+    /// Samples the private key and genesis block for `DEFAULT_FIXTURE_SEED`.
+    pub fn sample() -> Result<Self> {
+        Self::new(DEFAULT_FIXTURE_SEED)
+    }
+
+    /// Returns the seed of the fixtures.
+    pub const fn seed(&self) -> u64 {
+        self.seed
+    }
+
+    /// Returns the private key of the genesis committee member, which owns the genesis records.
+    pub const fn private_key(&self) -> &PrivateKey<N> {
+        &self.private_key
+    }
+
+    /// Returns the genesis block.
+    pub const fn genesis_block(&self) -> &Block<N> {
+        &self.genesis_block
+    }
+
+    /// Returns a deterministic RNG for the fixture with the given index.
+    /// Note: Each fixture uses its own RNG, so the fixtures do not depend on the order in which they are sampled.
+    pub fn rng(&self, index: u64) -> TestRng {
+        TestRng::fixed(self.seed.wrapping_add(index.wrapping_mul(0x9E37_79B9_7F4A_7C15)))
+    }
+
+    /// Returns a new in-memory VM.
+    pub fn vm(&self) -> Result<VM<N, ConsensusMemory<N>>> {
+        VM::from(ConsensusStore::open(None)?)
+    }
+
+    /// Returns a new in-memory VM, with the genesis block added.
+    pub fn vm_with_genesis_block(&self) -> Result<VM<N, ConsensusMemory<N>>> {
+        let vm = self.vm()?;
+        vm.add_next_block(&self.genesis_block)?;
+        Ok(vm)
+    }
+
+    /// Returns the program of the sampled deployment transaction.
+    pub fn program(&self) -> Result<Program<N>> {
+        Program::from_str(SAMPLE_PROGRAM)
+    }
+
+    /// Returns the first genesis record, decrypted with the view key of the private key.
+    pub fn genesis_record(&self) -> Result<Record<N, Plaintext<N>>> {
+        let view_key = ViewKey::try_from(&self.private_key)?;
+        let mut records = self.genesis_block.transitions().cloned().flat_map(Transition::into_records);
+        match records.next() {
+            Some((_, record)) => record.decrypt(&view_key),
+            None => bail!("The genesis block does not contain any records"),
+        }
+    }
+
+    /// Returns the deployment transaction of `SAMPLE_PROGRAM`, with a private fee.
+    pub fn deployment_transaction(&self) -> Result<Transaction<N>> {
+        Self::get_or_try_init(&self.deployment_transaction, || {
+            let rng = &mut self.rng(0);
+            let vm = self.vm_with_genesis_block()?;
+            // Deploy the program, using the genesis record for the fee.
+            let transaction =
+                vm.deploy(&self.private_key, &self.program()?, Some(self.genesis_record()?), 10, None, rng)?;
+            vm.check_transaction(&transaction, None, rng)?;
+            Ok(transaction)
+        })
+    }
+
+    /// Returns the execution transaction of `credits.aleo/split`, without a fee.
+    pub fn execution_transaction_without_fee(&self) -> Result<Transaction<N>> {
+        Self::get_or_try_init(&self.execution_transaction_without_fee, || {
+            let rng = &mut self.rng(1);
+            let vm = self.vm_with_genesis_block()?;
+            // Split the genesis record.
+            let inputs = [Value::Record(self.genesis_record()?), Value::from_str("1u64")?];
+            let authorization = vm.authorize(&self.private_key, "credits.aleo", "split", inputs.iter(), rng)?;
+            let transaction = vm.execute_authorization(authorization, None, None, rng)?;
+            vm.check_transaction(&transaction, None, rng)?;
+            Ok(transaction)
+        })
+    }
+
+    /// Returns the execution transaction of `credits.aleo/transfer_public`, with a private fee.
+    pub fn execution_transaction_with_private_fee(&self) -> Result<Transaction<N>> {
+        Self::get_or_try_init(&self.execution_transaction_with_private_fee, || {
+            let rng = &mut self.rng(2);
+            let vm = self.vm_with_genesis_block()?;
+            // Transfer to the caller, using the genesis record for the fee.
+            let inputs = self.transfer_public_inputs()?;
+            let record = Some(self.genesis_record()?);
+            let locator = ("credits.aleo", "transfer_public");
+            let transaction = vm.execute(&self.private_key, locator, inputs.iter(), record, 0, None, rng)?;
+            vm.check_transaction(&transaction, None, rng)?;
+            Ok(transaction)
+        })
+    }
+
+    /// Returns the execution transaction of `credits.aleo/transfer_public`, with a public fee.
+    pub fn execution_transaction_with_public_fee(&self) -> Result<Transaction<N>> {
+        Self::get_or_try_init(&self.execution_transaction_with_public_fee, || {
+            let rng = &mut self.rng(3);
+            let vm = self.vm_with_genesis_block()?;
+            // Transfer to the caller.
+            let inputs = self.transfer_public_inputs()?;
+            let locator = ("credits.aleo", "transfer_public");
+            let transaction_without_fee = vm.execute(&self.private_key, locator, inputs.iter(), None, 0, None, rng)?;
+            let execution =
+                transaction_without_fee.execution().ok_or_else(|| anyhow!("Expected an execution"))?.clone();
+            // Authorize and compute the public fee.
+            let authorization =
+                vm.authorize_fee_public(&self.private_key, 10_000_000, 100, execution.to_execution_id()?, rng)?;
+            let fee = vm.execute_fee_authorization(authorization, None, rng)?;
+            // Construct the transaction.
+            let transaction = Transaction::from_execution(execution, Some(fee))?;
+            vm.check_transaction(&transaction, None, rng)?;
+            Ok(transaction)
+        })
+    }
+
+    /// Returns the next block for the given VM, containing the given transactions.
+    /// Note: The block is signed by the private key of the fixtures, and does not contain solutions.
+    pub fn next_block<C: ConsensusStorage<N>, R: Rng + CryptoRng>(
+        &self,
+        vm: &VM<N, C>,
+        transactions: &[Transaction<N>],
+        rng: &mut R,
+    ) -> Result<Block<N>> {
+        // Get the most recent block.
+        let block_store = vm.block_store();
+        let latest_height = block_store.heights().max().ok_or_else(|| anyhow!("The VM does not contain any blocks"))?;
+        let block_hash = block_store
+            .get_block_hash(*latest_height.borrow())?
+            .ok_or_else(|| anyhow!("Missing the latest block hash"))?;
+        let previous_block = block_store.get_block(&block_hash)?.ok_or_else(|| anyhow!("Missing the latest block"))?;
+
+        // Speculate on the transactions.
+        let next_height = previous_block.height() + 1;
+        let finalize_state = FinalizeGlobalState::from(next_height as u64, next_height, [0u8; 32]);
+        let (ratifications, transactions, aborted_transaction_ids, ratified_finalize_operations) =
+            vm.speculate(finalize_state, None, vec![], &None.into(), transactions.iter(), rng)?;
+
+        // Construct the metadata associated with the block.
+        let metadata = Metadata::new(
+            N::ID,
+            previous_block.round() + 1,
+            next_height,
+            0,
+            0,
+            N::GENESIS_COINBASE_TARGET,
+            N::GENESIS_PROOF_TARGET,
+            previous_block.last_coinbase_target(),
+            previous_block.last_coinbase_timestamp(),
+            previous_block.timestamp() + 1,
+        )?;
+
+        // Construct the block header.
+        let header = Header::from(
+            block_store.current_state_root(),
+            transactions.to_transactions_root()?,
+            transactions.to_finalize_root(ratified_finalize_operations)?,
+            ratifications.to_ratifications_root()?,
+            Field::zero(),
+            Field::zero(),
+            metadata,
+        )?;
+
+        // Construct the block.
+        Block::new_beacon(
+            &self.private_key,
+            previous_block.hash(),
+            header,
+            ratifications,
+            None.into(),
+            vec![],
+            transactions,
+            aborted_transaction_ids,
+            rng,
+        )
+    }
+}
+
+impl<N: Network> TestFixtures<N> {
+    /// Returns the inputs to transfer 1 microcredit to the caller, with `credits.aleo/transfer_public`.
+    fn transfer_public_inputs(&self) -> Result<[Value<N>; 2]> {
+        let address = Address::try_from(&self.private_key)?;
+        Ok([Value::from_str(&address.to_string())?, Value::from_str("1u64")?])
+    }
+
+    /// Returns the cached value, initializing it with the given closure if it is not yet set.
+    fn get_or_try_init<T: Clone>(cell: &OnceLock<T>, init: impl FnOnce() -> Result<T>) -> Result<T> {
+        if let Some(value) = cell.get() {
+            return Ok(value.clone());
+        }
+        let value = init()?;
+        Ok(cell.get_or_init(|| value).clone())
+    }
+}
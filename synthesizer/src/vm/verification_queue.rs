@@ -0,0 +1,429 @@
+// Copyright 2024 Aleo Network Foundation
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use ledger_block::TransactionWeight;
+
+use std::{
+    collections::HashMap,
+    hash::Hash,
+    time::{Duration, Instant},
+};
+
+/// The configuration of a `VerificationQueue`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct VerificationQueueConfig {
+    /// The maximum number of transactions that are verified concurrently.
+    max_concurrency: usize,
+    /// The maximum number of transactions that are pending or in verification.
+    max_pending: usize,
+    /// The maximum number of transactions from a single source that are pending or in verification.
+    max_pending_per_source: usize,
+    /// The time before its deadline at which a transaction is scheduled ahead of cheaper transactions.
+    urgency: Duration,
+}
+
+impl Default for VerificationQueueConfig {
+    /// Returns the default configuration of a `VerificationQueue`.
+    fn default() -> Self {
+        Self { max_concurrency: 16, max_pending: 10_000, max_pending_per_source: 100, urgency: Duration::from_secs(1) }
+    }
+}
+
+impl VerificationQueueConfig {
+    /// Initializes a new verification queue configuration.
+    pub fn new(
+        max_concurrency: usize,
+        max_pending: usize,
+        max_pending_per_source: usize,
+        urgency: Duration,
+    ) -> Result<Self> {
+        ensure!(max_concurrency > 0, "The verification queue must verify at least one transaction at a time");
+        ensure!(max_pending_per_source > 0, "The verification queue must accept at least one transaction per source");
+        ensure!(
+            max_pending >= max_pending_per_source,
+            "The verification queue must accept at least as many transactions as a single source"
+        );
+        Ok(Self { max_concurrency, max_pending, max_pending_per_source, urgency })
+    }
+
+    /// Returns the maximum number of transactions that are verified concurrently.
+    pub const fn max_concurrency(&self) -> usize {
+        self.max_concurrency
+    }
+
+    /// Returns the maximum number of transactions that are pending or in verification.
+    pub const fn max_pending(&self) -> usize {
+        self.max_pending
+    }
+
+    /// Returns the maximum number of transactions from a single source that are pending or in verification.
+    pub const fn max_pending_per_source(&self) -> usize {
+        self.max_pending_per_source
+    }
+
+    /// Returns the time before its deadline at which a transaction is scheduled ahead of cheaper transactions.
+    pub const fn urgency(&self) -> Duration {
+        self.urgency
+    }
+}
+
+/// The outcome of verifying a transaction in a `VerificationQueue`.
+#[derive(Debug)]
+pub struct VerificationOutcome<N: Network, S> {
+    /// The source of the transaction.
+    source: S,
+    /// The transaction.
+    transaction: Transaction<N>,
+    /// The result of the verification.
+    result: Result<()>,
+}
+
+impl<N: Network, S> VerificationOutcome<N, S> {
+    /// Returns the source of the transaction.
+    pub const fn source(&self) -> &S {
+        &self.source
+    }
+
+    /// Returns the transaction.
+    pub const fn transaction(&self) -> &Transaction<N> {
+        &self.transaction
+    }
+
+    /// Returns the result of the verification.
+    pub const fn result(&self) -> &Result<()> {
+        &self.result
+    }
+
+    /// Returns `true` if the transaction is valid.
+    pub const fn is_valid(&self) -> bool {
+        self.result.is_ok()
+    }
+
+    /// Returns the source, the transaction, and the result of the verification.
+    pub fn into_parts(self) -> (S, Transaction<N>, Result<()>) {
+        (self.source, self.transaction, self.result)
+    }
+}
+
+/// A pending transaction in a `VerificationQueue`.
+struct PendingTransaction<N: Network, S> {
+    /// The source of the transaction.
+    source: S,
+    /// The transaction.
+    transaction: Transaction<N>,
+    /// The rejected ID of the transaction, if it is a rejected transaction.
+    rejected_id: Option<Field<N>>,
+    /// The deadline, after which the transaction is no longer verified.
+    deadline: Instant,
+    /// The weight of the transaction.
+    weight: TransactionWeight,
+    /// The order in which the transaction was queued.
+    sequence: u64,
+}
+
+impl<N: Network, S> PendingTransaction<N, S> {
+    /// Returns the verification cost of the transaction, as the number of proofs and the size in bytes.
+    fn cost(&self) -> (usize, usize) {
+        (self.weight.num_proofs(), self.weight.size_in_bytes())
+    }
+}
+
+/// The state of a `VerificationQueue`.
+struct QueueState<N: Network, S> {
+    /// The pending transactions.
+    pending: Vec<PendingTransaction<N, S>>,
+    /// The IDs of the pending and in-verification transactions.
+    transaction_ids: IndexSet<N::TransactionID>,
+    /// The number of pending and in-verification transactions, for each source.
+    num_per_source: HashMap<S, usize>,
+    /// The number of transactions in verification.
+    num_in_flight: usize,
+    /// The sequence number of the next transaction.
+    next_sequence: u64,
+}
+
+impl<N: Network, S: Clone + Eq + Hash> QueueState<N, S> {
+    /// Returns the next batch of transactions to verify, and removes the expired transactions.
+    ///
+    /// Transactions within `urgency` of their deadline are scheduled first, earliest deadline first.
+    /// The remaining transactions are scheduled cheapest first, and in the order they were queued on ties.
+    fn next_batch(
+        &mut self,
+        config: &VerificationQueueConfig,
+        now: Instant,
+    ) -> (Vec<PendingTransaction<N, S>>, Vec<PendingTransaction<N, S>>) {
+        // Remove the expired transactions.
+        let (expired, pending): (Vec<_>, Vec<_>) =
+            std::mem::take(&mut self.pending).into_iter().partition(|entry| entry.deadline <= now);
+        self.pending = pending;
+        for entry in &expired {
+            self.release(entry);
+        }
+
+        // Order the pending transactions by their priority, with the highest priority last.
+        let is_urgent =
+            |entry: &PendingTransaction<N, S>| entry.deadline.saturating_duration_since(now) <= config.urgency;
+        self.pending.sort_by(|a, b| {
+            let priority = |entry: &PendingTransaction<N, S>| match is_urgent(entry) {
+                true => (0, entry.deadline, (0, 0), entry.sequence),
+                false => (1, now, entry.cost(), entry.sequence),
+            };
+            priority(b).cmp(&priority(a))
+        });
+
+        // Select the transactions to verify, up to the available concurrency.
+        let num_available = config.max_concurrency.saturating_sub(self.num_in_flight).min(self.pending.len());
+        let batch = self.pending.split_off(self.pending.len() - num_available).into_iter().rev().collect::<Vec<_>>();
+        self.num_in_flight += batch.len();
+        (batch, expired)
+    }
+
+    /// Releases the quota held by the given transaction.
+    fn release(&mut self, entry: &PendingTransaction<N, S>) {
+        self.transaction_ids.swap_remove(&entry.transaction.id());
+        if let Some(count) = self.num_per_source.get_mut(&entry.source) {
+            *count = count.saturating_sub(1);
+            if *count == 0 {
+                self.num_per_source.remove(&entry.source);
+            }
+        }
+    }
+}
+
+/// An admission queue for transactions, around `VM::check_transaction`.
+///
+/// The queue bounds the number of transactions that are pending in total and for each source, such as a peer,
+/// and verifies at most `max_concurrency` transactions at a time. Transactions that exceed the network limits
+/// are rejected before they are queued, and transactions that are not verified by their deadline are dropped.
+pub struct VerificationQueue<N: Network, S> {
+    /// The configuration of the queue.
+    config: VerificationQueueConfig,
+    /// The state of the queue.
+    state: Mutex<QueueState<N, S>>,
+}
+
+impl<N: Network, S: Clone + Eq + Hash + Send + Sync> VerificationQueue<N, S> {
+    /// Initializes a new verification queue with the given configuration.
+    pub fn new(config: VerificationQueueConfig) -> Self {
+        let state = QueueState {
+            pending: Default::default(),
+            transaction_ids: Default::default(),
+            num_per_source: Default::default(),
+            num_in_flight: 0,
+            next_sequence: 0,
+        };
+        Self { config, state: Mutex::new(state) }
+    }
+
+    /// Returns the configuration of the queue.
+    pub const fn config(&self) -> &VerificationQueueConfig {
+        &self.config
+    }
+
+    /// Returns the number of pending transactions.
+    pub fn num_pending(&self) -> usize {
+        self.state.lock().pending.len()
+    }
+
+    /// Returns the number of transactions in verification.
+    pub fn num_in_flight(&self) -> usize {
+        self.state.lock().num_in_flight
+    }
+
+    /// Returns the number of pending and in-verification transactions from the given source.
+    pub fn num_pending_for(&self, source: &S) -> usize {
+        self.state.lock().num_per_source.get(source).copied().unwrap_or(0)
+    }
+
+    /// Queues the given transaction from the given source, to be verified before the given deadline.
+    /// On failure, the transaction is not queued, and an error is returned.
+    pub fn push(
+        &self,
+        source: S,
+        transaction: Transaction<N>,
+        rejected_id: Option<Field<N>>,
+        deadline: Instant,
+    ) -> Result<()> {
+        // Ensure the transaction is within the network limits, before acquiring the lock.
+        let weight = transaction.weight()?;
+        ensure!(weight.is_within_limits::<N>(), "Transaction '{}' exceeds the network limits", transaction.id());
+
+        let mut state = self.state.lock();
+        // Ensure the deadline has not passed.
+        ensure!(deadline > Instant::now(), "Transaction '{}' was queued after its deadline", transaction.id());
+        // Ensure the transaction is not already queued.
+        if state.transaction_ids.contains(&transaction.id()) {
+            bail!("Transaction '{}' is already in the verification queue", transaction.id())
+        }
+        // Ensure the queue and the source are within their quotas.
+        let num_pending = state.pending.len() + state.num_in_flight;
+        ensure!(num_pending < self.config.max_pending, "The verification queue is full");
+        let num_for_source = state.num_per_source.get(&source).copied().unwrap_or(0);
+        ensure!(
+            num_for_source < self.config.max_pending_per_source,
+            "The source of transaction '{}' exceeds its verification quota",
+            transaction.id()
+        );
+
+        // Queue the transaction.
+        let sequence = state.next_sequence;
+        state.next_sequence += 1;
+        state.transaction_ids.insert(transaction.id());
+        *state.num_per_source.entry(source.clone()).or_default() += 1;
+        state.pending.push(PendingTransaction { source, transaction, rejected_id, deadline, weight, sequence });
+        Ok(())
+    }
+
+    /// Verifies the next batch of transactions with the given VM, and returns their outcomes.
+    /// The outcomes include the transactions that expired before they were verified.
+    ///
+    /// This method may be called concurrently, in which case the total number of transactions
+    /// in verification remains bounded by `max_concurrency`.
+    pub fn process<C: ConsensusStorage<N>, R: CryptoRng + Rng>(
+        &self,
+        vm: &VM<N, C>,
+        rng: &mut R,
+    ) -> Vec<VerificationOutcome<N, S>> {
+        // Select the next batch of transactions.
+        let (batch, expired) = self.state.lock().next_batch(&self.config, Instant::now());
+
+        // Verify the batch.
+        let rngs = (0..batch.len()).map(|_| StdRng::from_seed(rng.gen())).collect::<Vec<_>>();
+        let results = cfg_iter!(batch)
+            .zip(rngs)
+            .map(|(entry, mut rng)| vm.check_transaction(&entry.transaction, entry.rejected_id, &mut rng))
+            .collect::<Vec<_>>();
+
+        // Release the quotas held by the batch.
+        {
+            let mut state = self.state.lock();
+            state.num_in_flight = state.num_in_flight.saturating_sub(batch.len());
+            for entry in &batch {
+                state.release(entry);
+            }
+        }
+
+        // Return the outcomes.
+        let expired = expired.into_iter().map(|entry| {
+            let result = Err(anyhow!("Transaction '{}' expired before it was verified", entry.transaction.id()));
+            (entry, result)
+        });
+        batch
+            .into_iter()
+            .zip(results)
+            .chain(expired)
+            .map(|(entry, result)| VerificationOutcome { source: entry.source, transaction: entry.transaction, result })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::vm::test_helpers;
+
+    #[test]
+    fn test_verification_queue_config() {
+        assert!(VerificationQueueConfig::new(0, 10, 1, Duration::ZERO).is_err());
+        assert!(VerificationQueueConfig::new(1, 10, 0, Duration::ZERO).is_err());
+        assert!(VerificationQueueConfig::new(1, 1, 2, Duration::ZERO).is_err());
+        assert!(VerificationQueueConfig::new(1, 2, 2, Duration::ZERO).is_ok());
+    }
+
+    #[test]
+    fn test_verification_queue_admission() {
+        let rng = &mut TestRng::default();
+        let deadline = Instant::now() + Duration::from_secs(60);
+
+        let deployment = test_helpers::sample_deployment_transaction(rng);
+        let execution = test_helpers::sample_execution_transaction_with_public_fee(rng);
+        let transfer = test_helpers::sample_execution_transaction_with_private_fee(rng);
+
+        let config = VerificationQueueConfig::new(1, 2, 1, Duration::ZERO).unwrap();
+        let queue = VerificationQueue::new(config);
+
+        // Ensure a source cannot exceed its quota.
+        queue.push(0u8, deployment.clone(), None, deadline).unwrap();
+        assert!(queue.push(0u8, execution.clone(), None, deadline).is_err());
+        // Ensure a transaction cannot be queued twice.
+        assert!(queue.push(1u8, deployment.clone(), None, deadline).is_err());
+        // Ensure a transaction cannot be queued after its deadline.
+        assert!(queue.push(1u8, execution.clone(), None, Instant::now()).is_err());
+        // Ensure the queue cannot exceed its capacity.
+        queue.push(1u8, execution, None, deadline).unwrap();
+        assert!(queue.push(2u8, transfer, None, deadline).is_err());
+        assert_eq!(queue.num_pending(), 2);
+        assert_eq!(queue.num_pending_for(&0), 1);
+        assert_eq!(queue.num_pending_for(&1), 1);
+    }
+
+    #[test]
+    fn test_verification_queue_scheduling() {
+        let rng = &mut TestRng::default();
+        let now = Instant::now();
+
+        let deployment = test_helpers::sample_deployment_transaction(rng);
+        let execution = test_helpers::sample_execution_transaction_with_public_fee(rng);
+        let transfer = test_helpers::sample_execution_transaction_without_fee(rng);
+
+        let config = VerificationQueueConfig::new(1, 10, 10, Duration::from_secs(1)).unwrap();
+        let queue = VerificationQueue::new(config);
+        queue.push(0u8, deployment.clone(), None, now + Duration::from_secs(60)).unwrap();
+        queue.push(0u8, execution.clone(), None, now + Duration::from_secs(60)).unwrap();
+        queue.push(0u8, transfer.clone(), None, now + Duration::from_secs(30)).unwrap();
+
+        let mut state = queue.state.lock();
+        // Ensure the cheapest transaction is scheduled first.
+        let (batch, expired) = state.next_batch(&config, now);
+        assert_eq!(batch.iter().map(|entry| entry.transaction.id()).collect::<Vec<_>>(), vec![transfer.id()]);
+        assert!(expired.is_empty());
+        // Ensure no transaction is scheduled beyond the concurrency.
+        assert!(state.next_batch(&config, now).0.is_empty());
+        state.num_in_flight = 0;
+        // Ensure an urgent transaction is scheduled ahead of a cheaper one.
+        let (batch, _) = state.next_batch(&config, now + Duration::from_secs(59));
+        assert_eq!(batch[0].transaction.id(), deployment.id());
+        state.num_in_flight = 0;
+        // Ensure an expired transaction is removed.
+        let (batch, expired) = state.next_batch(&config, now + Duration::from_secs(61));
+        assert!(batch.is_empty());
+        assert_eq!(expired[0].transaction.id(), execution.id());
+        assert_eq!(state.num_per_source.get(&0), Some(&2));
+    }
+
+    #[test]
+    fn test_verification_queue_process() {
+        let rng = &mut TestRng::default();
+        let vm = test_helpers::sample_vm_with_genesis_block(rng);
+
+        let execution = test_helpers::sample_execution_transaction_with_public_fee(rng);
+        let transfer = test_helpers::sample_execution_transaction_with_private_fee(rng);
+
+        let queue = VerificationQueue::new(VerificationQueueConfig::default());
+        queue.push("peer", execution.clone(), None, Instant::now() + Duration::from_secs(600)).unwrap();
+        queue.push("peer", transfer.clone(), None, Instant::now() + Duration::from_secs(600)).unwrap();
+
+        // Ensure the transactions are verified, and their quotas are released.
+        let outcomes = queue.process(&vm, rng);
+        assert_eq!(outcomes.len(), 2);
+        assert!(outcomes.iter().all(VerificationOutcome::is_valid));
+        assert_eq!(queue.num_pending(), 0);
+        assert_eq!(queue.num_in_flight(), 0);
+        assert_eq!(queue.num_pending_for(&"peer"), 0);
+        // Ensure the transactions can be queued again.
+        queue.push("peer", execution, None, Instant::now() + Duration::from_secs(600)).unwrap();
+    }
+}
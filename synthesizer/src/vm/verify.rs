@@ -33,48 +33,279 @@ macro_rules! ensure_is_unique {
 }
 
 impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
+    /// The estimated cost to verify a function in a deployment, in units of transition proofs,
+    /// as its verifying key is checked by synthesizing the function.
+    pub(crate) const DEPLOYED_FUNCTION_VERIFICATION_COST: u64 = 100;
     /// The maximum number of deployments to verify in parallel.
     pub(crate) const MAX_PARALLEL_DEPLOY_VERIFICATIONS: usize = 5;
     /// The maximum number of executions to verify in parallel.
     pub(crate) const MAX_PARALLEL_EXECUTE_VERIFICATIONS: usize = 1000;
+    /// The maximum estimated cost of the transactions to verify in parallel, in units of transition proofs.
+    pub(crate) const MAX_PARALLEL_VERIFICATION_COST: u64 = 2 * Self::MAX_PARALLEL_EXECUTE_VERIFICATIONS as u64;
+
+    /// Returns the estimated cost to verify the given transaction, in units of transition proofs.
+    pub(crate) fn verification_cost(transaction: &Transaction<N>) -> u64 {
+        match transaction {
+            Transaction::Deploy(_, _, deployment, _) => {
+                let num_functions = deployment.program().functions().len() as u64;
+                num_functions.saturating_mul(Self::DEPLOYED_FUNCTION_VERIFICATION_COST).saturating_add(1)
+            }
+            Transaction::Execute(_, execution, fee) => execution.len() as u64 + u64::from(fee.is_some()),
+            Transaction::Fee(..) => 1,
+        }
+    }
+
+    /// Splits the given items into contiguous chunks for parallel verification, where `transaction` returns
+    /// the transaction of an item.
+    ///
+    /// Each chunk contains at most `max_count` items, and costs at most `MAX_PARALLEL_VERIFICATION_COST`,
+    /// unless it consists of a single item that exceeds it. The chunks are balanced, such that each costs
+    /// approximately the same, rather than leaving a small remainder in the last chunk.
+    pub(crate) fn verification_chunks<'a, T>(
+        items: &'a [T],
+        max_count: usize,
+        transaction: impl Fn(&T) -> &Transaction<N>,
+    ) -> Vec<&'a [T]> {
+        // Estimate the cost of each item.
+        let costs = items.iter().map(|item| Self::verification_cost(transaction(item))).collect::<Vec<_>>();
+        // Determine the number of chunks needed, and the target cost of each chunk.
+        let total_cost = costs.iter().fold(0u64, |total, cost| total.saturating_add(*cost));
+        let num_chunks = total_cost
+            .div_ceil(Self::MAX_PARALLEL_VERIFICATION_COST)
+            .max(items.len().div_ceil(max_count.max(1)) as u64)
+            .max(1);
+        let target_cost = total_cost.div_ceil(num_chunks);
+
+        // Split the items, closing a chunk once it reaches the target cost, or a limit.
+        let mut chunks = Vec::with_capacity(num_chunks as usize);
+        let (mut start, mut chunk_cost) = (0, 0u64);
+        for (index, cost) in costs.iter().enumerate() {
+            let is_full = index - start >= max_count
+                || chunk_cost >= target_cost
+                || chunk_cost.saturating_add(*cost) > Self::MAX_PARALLEL_VERIFICATION_COST;
+            if index > start && is_full {
+                chunks.push(&items[start..index]);
+                (start, chunk_cost) = (index, 0);
+            }
+            chunk_cost = chunk_cost.saturating_add(*cost);
+        }
+        if start < items.len() {
+            chunks.push(&items[start..]);
+        }
+        chunks
+    }
 
     /// Verifies the list of transactions in the VM. On failure, returns an error.
     pub fn check_transactions<R: CryptoRng + Rng>(
         &self,
         transactions: &[(&Transaction<N>, Option<Field<N>>)],
         rng: &mut R,
+    ) -> Result<()> {
+        self.check_transactions_with_verified(transactions, &VerifiedTransactions::default(), rng)
+    }
+
+    /// Verifies the list of transactions in the VM, given the transactions that consensus has already
+    /// fully verified. On failure, returns an error.
+    ///
+    /// If the policy of `verified` is `VerificationPolicy::TrustVerified`, the proofs of the verified transactions
+    /// are not verified again. The checks against the current state of the ledger are performed for every transaction.
+    pub fn check_transactions_with_verified<R: CryptoRng + Rng>(
+        &self,
+        transactions: &[(&Transaction<N>, Option<Field<N>>)],
+        verified: &VerifiedTransactions<N>,
+        rng: &mut R,
     ) -> Result<()> {
         // Separate the transactions into deploys and executions.
         let (deployments, executions): (Vec<_>, Vec<_>) = transactions.iter().partition(|(tx, _)| tx.is_deploy());
-        // Chunk the deploys and executions into groups for parallel verification.
-        let deployments_for_verification = deployments.chunks(Self::MAX_PARALLEL_DEPLOY_VERIFICATIONS);
-        let executions_for_verification = executions.chunks(Self::MAX_PARALLEL_EXECUTE_VERIFICATIONS);
+        // Chunk the deploys and executions into groups for parallel verification, balanced by their cost.
+        let deployments_for_verification =
+            Self::verification_chunks(&deployments, Self::MAX_PARALLEL_DEPLOY_VERIFICATIONS, |(tx, _)| *tx);
+        let executions_for_verification =
+            Self::verification_chunks(&executions, Self::MAX_PARALLEL_EXECUTE_VERIFICATIONS, |(tx, _)| *tx);
 
         // Verify the transactions in batches.
-        for transactions in deployments_for_verification.chain(executions_for_verification) {
+        for transactions in deployments_for_verification.into_iter().chain(executions_for_verification) {
+            // Determine the transactions whose proofs are trusted, as they were already fully verified.
+            let is_trusted = transactions.iter().map(|(tx, _)| verified.is_trusted(tx)).collect::<Vec<_>>();
+            let untrusted = transactions
+                .iter()
+                .zip(&is_trusted)
+                .filter(|(_, is_trusted)| !**is_trusted)
+                .map(|(transaction, _)| *transaction)
+                .collect::<Vec<_>>();
+            // A helper to map the batch results of the untrusted transactions back to the transactions.
+            let with_trusted = |is_verified: Vec<bool>| {
+                let mut is_verified = is_verified.into_iter();
+                is_trusted
+                    .iter()
+                    .map(|is_trusted| !is_trusted && is_verified.next().unwrap_or(false))
+                    .collect::<Vec<_>>()
+            };
+
+            // Verify the execution proofs in the batch, with a single product of pairings.
+            // If this fails, each execution proof is verified individually, to identify the invalid transaction.
+            let is_execution_proof_verified = with_trusted(self.check_execution_proofs(&untrusted, rng));
+            // Verify the fee proofs of the rejected transactions in the batch, with a single product of pairings.
+            // If this fails, each fee proof is verified individually, to identify the invalid transaction.
+            let is_fee_proof_verified = with_trusted(self.check_fee_proofs(&untrusted, rng));
             // Ensure each transaction is well-formed and unique.
             let rngs = (0..transactions.len()).map(|_| StdRng::from_seed(rng.gen())).collect::<Vec<_>>();
-            cfg_iter!(transactions).zip(rngs).try_for_each(|((transaction, rejected_id), mut rng)| {
-                self.check_transaction(transaction, *rejected_id, &mut rng)
-                    .map_err(|e| anyhow!("Invalid transaction found in the transactions list: {e}"))
-            })?;
+            cfg_iter!(transactions)
+                .zip(is_execution_proof_verified)
+                .zip(is_fee_proof_verified)
+                .zip(&is_trusted)
+                .zip(rngs)
+                .try_for_each(
+                    |(
+                        (
+                            (((transaction, rejected_id), is_execution_proof_verified), is_fee_proof_verified),
+                            is_trusted,
+                        ),
+                        mut rng,
+                    )| {
+                        self.check_transaction_internal(
+                            transaction,
+                            *rejected_id,
+                            is_execution_proof_verified,
+                            is_fee_proof_verified,
+                            *is_trusted,
+                            false,
+                            &mut rng,
+                        )
+                        .map_err(|e| anyhow!("Invalid transaction found in the transactions list: {e}"))
+                    },
+                )?;
         }
 
         Ok(())
     }
+
+    /// Verifies the proofs of the executions in the given transactions, with a single product of pairings.
+    /// Returns, for each transaction, `true` if its execution proof was verified in the batch.
+    /// Executions in the partially-verified cache are not included in the batch.
+    ///
+    /// Note: This does *not* check the remaining components of the executions.
+    fn check_execution_proofs<R: CryptoRng + Rng>(
+        &self,
+        transactions: &[&(&Transaction<N>, Option<Field<N>>)],
+        rng: &mut R,
+    ) -> Vec<bool> {
+        // Select the executions that have not been verified before.
+        let is_selected = transactions
+            .iter()
+            .map(|(transaction, _)| transaction.is_execute() && !self.is_partially_verified(transaction))
+            .collect::<Vec<_>>();
+        let selected =
+            || transactions.iter().zip(&is_selected).filter(|(_, is_selected)| **is_selected).map(|((tx, _), _)| *tx);
+        let executions = selected().filter_map(|transaction| transaction.execution()).collect::<Vec<_>>();
+
+        // If there are fewer than two executions, there is nothing to gain from batching.
+        if executions.len() < 2 {
+            return vec![false; transactions.len()];
+        }
+        // Ensure the programs used by the executions are loaded.
+        if self.hydrate_transactions(selected()).is_err() {
+            return vec![false; transactions.len()];
+        }
+
+        // Verify the execution proofs.
+        match try_vm_runtime!(|| self.process.read().verify_execution_batch(&executions, rng)) {
+            Ok(Ok(())) => is_selected,
+            _ => vec![false; transactions.len()],
+        }
+    }
+
+    /// Verifies the fee proofs of the rejected transactions (i.e. `Transaction::Fee`) in the given transactions,
+    /// with a single product of pairings.
+    /// Returns, for each transaction, `true` if its fee proof was verified in the batch.
+    ///
+    /// Note: This does *not* check the fee amount, the payer balance, or the global state root.
+    fn check_fee_proofs<R: CryptoRng + Rng>(
+        &self,
+        transactions: &[&(&Transaction<N>, Option<Field<N>>)],
+        rng: &mut R,
+    ) -> Vec<bool> {
+        // Select the fee transactions with a rejected ID.
+        let is_selected = transactions
+            .iter()
+            .map(|(transaction, rejected_id)| transaction.is_fee() && rejected_id.is_some())
+            .collect::<Vec<_>>();
+        let fees = transactions
+            .iter()
+            .filter_map(|(transaction, rejected_id)| match (transaction, rejected_id) {
+                (Transaction::Fee(_, fee), Some(rejected_id)) => Some((fee, *rejected_id)),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+
+        // If there are fewer than two fees, there is nothing to gain from batching.
+        if fees.len() < 2 {
+            return vec![false; transactions.len()];
+        }
+
+        // Verify the fee proofs.
+        match try_vm_runtime!(|| self.process.read().verify_fees(&fees, rng)) {
+            Ok(Ok(())) => is_selected,
+            _ => vec![false; transactions.len()],
+        }
+    }
 }
 
 impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
     /// Verifies the transaction in the VM. On failure, returns an error.
+    ///
+    /// If public fee sequencing is enabled, the public fee of a valid transaction is then reserved
+    /// against the payer's pending public fees, which fails if the payer's balance does not cover it.
     #[inline]
     pub fn check_transaction<R: CryptoRng + Rng>(
         &self,
         transaction: &Transaction<N>,
         rejected_id: Option<Field<N>>,
         rng: &mut R,
+    ) -> Result<()> {
+        self.check_transaction_internal(transaction, rejected_id, false, false, false, true, rng)?;
+        // Reserve the public fee, only once the transaction is fully verified.
+        self.reserve_public_fee(transaction)
+    }
+
+    /// Verifies the transaction in the VM, for inclusion in a block. On failure, returns an error.
+    ///
+    /// Unlike `VM::check_transaction`, this does not reserve the public fee of the transaction,
+    /// checks the rejected execution IDs exactly, and accepts any global state root in the ledger,
+    /// as these are only configured by this node for mempool admission.
+    #[inline]
+    pub(crate) fn check_block_transaction<R: CryptoRng + Rng>(
+        &self,
+        transaction: &Transaction<N>,
+        rejected_id: Option<Field<N>>,
+        rng: &mut R,
+    ) -> Result<()> {
+        self.check_transaction_internal(transaction, rejected_id, false, false, false, false, rng)
+    }
+
+    /// Verifies the transaction in the VM. On failure, returns an error.
+    /// If `is_execution_proof_verified` is `true`, the execution proof is not re-verified.
+    /// If `is_fee_proof_verified` is `true`, the fee proof is not re-verified.
+    /// If `is_trusted` is `true`, none of the proofs are re-verified, as consensus has already fully verified
+    /// the transaction. Its transitions are not added to the partially-verified cache, as they were not verified here.
+    /// If `is_mempool` is `true`, the transaction is checked for mempool admission, against the replay protection
+    /// and the state root window of this node. Otherwise, it is checked for inclusion in a block, against the ledger.
+    #[allow(clippy::too_many_arguments)]
+    fn check_transaction_internal<R: CryptoRng + Rng>(
+        &self,
+        transaction: &Transaction<N>,
+        rejected_id: Option<Field<N>>,
+        is_execution_proof_verified: bool,
+        is_fee_proof_verified: bool,
+        is_trusted: bool,
+        is_mempool: bool,
+        rng: &mut R,
     ) -> Result<()> {
         let timer = timer!("VM::check_transaction");
 
+        // Ensure the programs used by the transaction are loaded.
+        self.hydrate_transactions([transaction])?;
+
         /* Transaction */
 
         // Allocate a buffer to write the transaction.
@@ -132,15 +363,23 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
 
         lap!(timer, "Check for duplicate elements");
 
+        // Ensure the transitions match the signatures of their functions, before verifying the proofs.
+        {
+            let process = self.process.read();
+            for transition in transaction.transitions() {
+                process.check_transition_structure(transition)?;
+            }
+        }
+        lap!(timer, "Check the transition structure");
+
         // First, verify the fee.
-        self.check_fee(transaction, rejected_id)?;
+        self.check_transaction_fee(transaction, rejected_id, is_fee_proof_verified || is_trusted)?;
 
-        // Construct the transaction checksum.
-        let checksum = Data::<Transaction<N>>::Buffer(transaction.to_bytes_le()?.into()).to_checksum::<N>()?;
+        // Construct the keys and checksum of the transaction in the partially-verified cache.
+        let partial_verification_keys = Self::partial_verification_keys(transaction)?;
 
-        // Check if the transaction exists in the partially-verified cache.
-        let is_partially_verified =
-            self.partially_verified_transactions.read().peek(&(transaction.id())) == Some(&checksum);
+        // Check if the transitions exist in the partially-verified cache, or if the transaction is trusted.
+        let is_partially_verified = is_trusted || self.contains_partial_verification_keys(&partial_verification_keys);
 
         // Next, verify the deployment or execution.
         match transaction {
@@ -178,11 +417,21 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
                     bail!("Failed to compute the Merkle root for an execution transaction '{id}'")
                 };
                 // Ensure the execution was not previously rejected (replay attack prevention).
-                if self.block_store().contains_rejected_deployment_or_execution_id(&execution_id)? {
+                // Note: The replay protection may report false positives, so it only governs mempool admission.
+                let is_rejected = match is_mempool {
+                    true => self.contains_rejected_execution_id(&execution_id)?,
+                    false => self.block_store().contains_rejected_deployment_or_execution_id(&execution_id)?,
+                };
+                if is_rejected {
                     bail!("Transaction '{id}' contains a previously rejected execution")
                 }
+                // Ensure the global state root is within the state root window, for mempool admission.
+                if is_mempool && !self.contains_state_root_in_window(&execution.global_state_root())? {
+                    bail!("Transaction '{id}' references an unknown global state root")
+                }
                 // Verify the execution.
-                match try_vm_runtime!(|| self.check_execution_internal(execution, is_partially_verified)) {
+                let is_proof_verified = is_partially_verified || is_execution_proof_verified;
+                match try_vm_runtime!(|| self.check_execution_internal(execution, is_proof_verified)) {
                     Ok(result) => result?,
                     Err(_) => bail!("VM safely halted transaction '{id}' during verification"),
                 }
@@ -191,18 +440,89 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
         }
 
         // If the above checks have passed and this is not a fee transaction,
-        // then add the transaction ID to the partially-verified transactions cache.
-        if !matches!(transaction, Transaction::Fee(..)) && !is_partially_verified {
-            self.partially_verified_transactions.write().push(transaction.id(), checksum);
+        // then add the transition IDs to the partially-verified transitions cache.
+        if let (Some((transition_ids, checksum)), false) = (partial_verification_keys, is_partially_verified) {
+            let mut partially_verified_transitions = self.partially_verified_transitions.write();
+            for transition_id in transition_ids {
+                partially_verified_transitions.push(transition_id, checksum);
+            }
         }
 
         finish!(timer, "Verify the transaction");
         Ok(())
     }
 
+    /// Returns `true` if the deployment or execution in the given transaction has been partially-verified before,
+    /// i.e. its proofs were verified, possibly within a different transaction.
+    pub fn is_partially_verified(&self, transaction: &Transaction<N>) -> bool {
+        match Self::partial_verification_keys(transaction) {
+            Ok(keys) => self.contains_partial_verification_keys(&keys),
+            Err(_) => false,
+        }
+    }
+
+    /// Returns the keys of the given transaction in the partially-verified cache, and their expected checksum,
+    /// or `None` for a fee transaction.
+    ///
+    /// An execution is keyed by each of its transition IDs, with the checksum of its execution ID, global state root,
+    /// and proof. The execution ID commits to every transition ID in order, so a transition is only considered
+    /// verified within the exact same execution and proof; a subset or a reordering of the transitions is
+    /// re-verified, while the same execution re-bundled with a different fee is not.
+    /// A deployment is keyed by its fee transition ID, with the checksum of the deployment.
+    fn partial_verification_keys(
+        transaction: &Transaction<N>,
+    ) -> Result<Option<(Vec<N::TransitionID>, N::TransmissionChecksum)>> {
+        let (transition_ids, bytes) = match transaction {
+            Transaction::Deploy(_, _, deployment, fee) => (vec![*fee.id()], deployment.to_bytes_le()?),
+            Transaction::Execute(_, execution, _) => {
+                // Note: An execution without a proof is never verified, so it has no keys.
+                let Some(proof) = execution.proof() else {
+                    return Ok(None);
+                };
+                let transition_ids = execution.transitions().map(|transition| *transition.id()).collect();
+                let bytes = [
+                    execution.to_execution_id()?.to_bytes_le()?,
+                    execution.global_state_root().to_bytes_le()?,
+                    proof.to_bytes_le()?,
+                ]
+                .concat();
+                (transition_ids, bytes)
+            }
+            Transaction::Fee(..) => return Ok(None),
+        };
+        let checksum = Data::<Transaction<N>>::Buffer(bytes.into()).to_checksum::<N>()?;
+        Ok(Some((transition_ids, checksum)))
+    }
+
+    /// Returns `true` if all of the given keys exist in the partially-verified cache, with the given checksum.
+    fn contains_partial_verification_keys(
+        &self,
+        keys: &Option<(Vec<N::TransitionID>, N::TransmissionChecksum)>,
+    ) -> bool {
+        match keys {
+            Some((transition_ids, checksum)) => {
+                let partially_verified_transitions = self.partially_verified_transitions.read();
+                !transition_ids.is_empty()
+                    && transition_ids.iter().all(|id| partially_verified_transitions.peek(id) == Some(checksum))
+            }
+            None => false,
+        }
+    }
+
     /// Verifies the `fee` in the given transaction. On failure, returns an error.
     #[inline]
     pub fn check_fee(&self, transaction: &Transaction<N>, rejected_id: Option<Field<N>>) -> Result<()> {
+        self.check_transaction_fee(transaction, rejected_id, false)
+    }
+
+    /// Verifies the `fee` in the given transaction. On failure, returns an error.
+    /// If `is_fee_proof_verified` is `true`, the fee proof is not re-verified.
+    fn check_transaction_fee(
+        &self,
+        transaction: &Transaction<N>,
+        rejected_id: Option<Field<N>>,
+        is_fee_proof_verified: bool,
+    ) -> Result<()> {
         match transaction {
             Transaction::Deploy(id, _, deployment, fee) => {
                 // Ensure the rejected ID is not present.
@@ -218,7 +538,7 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
                     bail!("Transaction '{id}' has an insufficient base fee (deployment) - requires {cost} microcredits")
                 }
                 // Verify the fee.
-                self.check_fee_internal(fee, deployment_id)?;
+                self.check_fee_internal(fee, deployment_id, is_fee_proof_verified)?;
             }
             Transaction::Execute(id, execution, fee) => {
                 // Ensure the rejected ID is not present.
@@ -246,7 +566,7 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
                         ensure!(*fee.base_amount()? == 0, "Transaction '{id}' has a non-zero base fee (execution)");
                     }
                     // Verify the fee.
-                    self.check_fee_internal(fee, execution_id)?;
+                    self.check_fee_internal(fee, execution_id, is_fee_proof_verified)?;
                 } else {
                     // Ensure the fee can be safely skipped.
                     ensure!(!is_fee_required, "Transaction '{id}' is missing a fee (execution)");
@@ -258,13 +578,47 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
             Transaction::Fee(id, fee) => {
                 // Verify the fee.
                 match rejected_id {
-                    Some(rejected_id) => self.check_fee_internal(fee, rejected_id)?,
+                    Some(rejected_id) => self.check_fee_internal(fee, rejected_id, is_fee_proof_verified)?,
                     None => bail!("Transaction '{id}' is missing a rejected ID (fee)"),
                 }
             }
         }
         Ok(())
     }
+
+    /// If public fee sequencing is enabled, reserves the public fee of the given transaction
+    /// against the payer's pending public fees. On failure, returns an error.
+    ///
+    /// Note: This must only be called once the transaction is fully verified, so that no stale reservation
+    /// is left behind for an invalid transaction.
+    fn reserve_public_fee(&self, transaction: &Transaction<N>) -> Result<()> {
+        // Note: Rejected fee transactions are only found in blocks, and are therefore not sequenced.
+        if let (Some(sequencer), Some(fee)) = (&self.public_fee_sequencer, transaction.fee_transition()) {
+            if fee.is_fee_public() && !transaction.is_fee() {
+                // Retrieve the payer.
+                let Some(payer) = fee.payer() else {
+                    bail!("Fee verification failed: fee is public, but the payer is missing");
+                };
+                // Retrieve the account balance of the payer.
+                let balance = self.get_public_balance_speculative(payer)?;
+                // Reserve the fee.
+                sequencer.lock().reserve(payer, transaction.id(), *fee.amount()?, balance)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns the speculative public balance of the given address in `credits.aleo`.
+    fn get_public_balance_speculative(&self, address: Address<N>) -> Result<u64> {
+        match self.finalize_store().get_value_speculative(
+            ProgramID::from_str("credits.aleo")?,
+            Identifier::from_str("account")?,
+            &Plaintext::from(Literal::Address(address)),
+        )? {
+            Some(Value::Plaintext(Plaintext::Literal(Literal::U64(balance), _))) => Ok(*balance),
+            _ => bail!("Fee verification failed: fee is public, but the payer account balance is missing"),
+        }
+    }
 }
 
 impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
@@ -299,48 +653,99 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
         let timer = timer!("VM::check_execution");
 
         // Retrieve the block height.
-        let block_height = self.block_store().current_block_height();
+        let block_height = self.current_block_height();
 
         // Ensure the execution does not contain any restricted transitions.
         if self.restrictions.contains_restricted_transitions(execution, block_height) {
             bail!("Execution verification failed - restricted transition found");
         }
 
+        // Verify the execution, and ensure the global state root exists in the block store.
+        let result = self.check_execution_with_state_roots(execution, is_partially_verified, |state_root| {
+            self.block_store().contains_state_root(state_root)
+        });
+        finish!(timer, "Verify the execution");
+        result
+    }
+
+    /// Verifies the given execution against the given set of global state roots. On failure, returns an error.
+    ///
+    /// Unlike `VM::check_transaction`, this does not read from the consensus store. As such, it does **not**
+    /// check the fee, the uniqueness of the execution contents, or the transition restrictions in the ledger.
+    #[inline]
+    pub fn verify_execution_stateless(
+        &self,
+        execution: &Execution<N>,
+        state_roots: &IndexSet<N::StateRoot>,
+    ) -> Result<()> {
+        let timer = timer!("VM::verify_execution_stateless");
+
+        // Ensure the execution is not empty.
+        ensure!(!execution.is_empty(), "Execution verification failed - the execution is empty");
+        // Ensure the execution ID can be computed.
+        if let Err(error) = execution.to_execution_id() {
+            bail!("Execution verification failed - failed to compute the execution ID: {error}")
+        }
+
+        // Verify the execution, and ensure the global state root is in the given set.
+        let result = match try_vm_runtime!(|| {
+            self.check_execution_with_state_roots(execution, false, |state_root| Ok(state_roots.contains(state_root)))
+        }) {
+            Ok(result) => result,
+            Err(_) => bail!("VM safely halted the execution during verification"),
+        };
+        finish!(timer, "Verify the execution");
+        result
+    }
+
+    /// Verifies the given execution, and ensures its global state root satisfies `contains_state_root`.
+    /// If the execution has been partially-verified before, the proof is not re-verified.
+    fn check_execution_with_state_roots(
+        &self,
+        execution: &Execution<N>,
+        is_partially_verified: bool,
+        contains_state_root: impl Fn(&N::StateRoot) -> Result<bool>,
+    ) -> Result<()> {
         // Verify the execution proof, if it has not been partially-verified before.
         let verification = match is_partially_verified {
             true => Ok(()),
             false => self.process.read().verify_execution(execution),
         };
-        lap!(timer, "Verify the execution");
 
-        // Ensure the global state root exists in the block store.
-        let result = match verification {
-            // Ensure the global state root exists in the block store.
-            Ok(()) => match self.block_store().contains_state_root(&execution.global_state_root()) {
+        match verification {
+            // Ensure the global state root exists.
+            Ok(()) => match contains_state_root(&execution.global_state_root()) {
                 Ok(true) => Ok(()),
                 Ok(false) => bail!("Execution verification failed - global state root does not exist (yet)"),
                 Err(error) => bail!("Execution verification failed - {error}"),
             },
             Err(error) => bail!("Execution verification failed - {error}"),
-        };
-        finish!(timer, "Check the global state root");
-        result
+        }
     }
 
     /// Verifies the given fee. On failure, returns an error.
+    /// If `is_proof_verified` is `true`, the fee proof is not re-verified.
     ///
     /// Note: This is an internal check only. To ensure all components of the fee are checked,
     /// use `VM::check_fee` instead.
     #[inline]
-    fn check_fee_internal(&self, fee: &Fee<N>, deployment_or_execution_id: Field<N>) -> Result<()> {
+    fn check_fee_internal(
+        &self,
+        fee: &Fee<N>,
+        deployment_or_execution_id: Field<N>,
+        is_proof_verified: bool,
+    ) -> Result<()> {
         let timer = timer!("VM::check_fee");
 
         // Ensure the fee does not exceed the limit.
         let fee_amount = fee.amount()?;
         ensure!(*fee_amount <= N::MAX_FEE, "Fee verification failed: fee exceeds the maximum limit");
 
-        // Verify the fee.
-        let verification = self.process.read().verify_fee(fee, deployment_or_execution_id);
+        // Verify the fee, if its proof has not been verified in a batch.
+        let verification = match is_proof_verified {
+            true => Ok(()),
+            false => self.process.read().verify_fee(fee, deployment_or_execution_id),
+        };
         lap!(timer, "Verify the fee");
 
         // TODO (howardwu): This check is technically insufficient. Consider moving this upstream
@@ -352,17 +757,9 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
                 bail!("Fee verification failed: fee is public, but the payer is missing");
             };
             // Retrieve the account balance of the payer.
-            let Some(Value::Plaintext(Plaintext::Literal(Literal::U64(balance), _))) =
-                self.finalize_store().get_value_speculative(
-                    ProgramID::from_str("credits.aleo")?,
-                    Identifier::from_str("account")?,
-                    &Plaintext::from(Literal::Address(payer)),
-                )?
-            else {
-                bail!("Fee verification failed: fee is public, but the payer account balance is missing");
-            };
+            let balance = self.get_public_balance_speculative(payer)?;
             // Ensure the balance is sufficient.
-            ensure!(balance >= fee_amount, "Fee verification failed: insufficient balance");
+            ensure!(balance >= *fee_amount, "Fee verification failed: insufficient balance");
         }
 
         // Ensure the global state root exists in the block store.
@@ -388,7 +785,7 @@ mod tests {
         account::{Address, ViewKey},
         types::Field,
     };
-    use ledger_block::{Block, Header, Metadata, Transaction, Transition};
+    use ledger_block::{Block, Header, Metadata, Transaction, TransitionBuilder};
 
     type CurrentNetwork = test_helpers::CurrentNetwork;
 
@@ -463,6 +860,59 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_check_execution_proofs() {
+        let rng = &mut TestRng::default();
+        let vm = crate::vm::test_helpers::sample_vm_with_genesis_block(rng);
+
+        // Fetch execution transactions.
+        let transactions = [
+            crate::vm::test_helpers::sample_execution_transaction_with_private_fee(rng),
+            crate::vm::test_helpers::sample_execution_transaction_with_public_fee(rng),
+        ];
+        let entries = transactions.iter().map(|transaction| (transaction, None)).collect::<Vec<_>>();
+        let batch = entries.iter().collect::<Vec<_>>();
+
+        // Construct an execution transaction, with the proof of the other execution.
+        let (first, second) = (transactions[0].execution().unwrap(), transactions[1].execution().unwrap());
+        let invalid_execution =
+            Execution::from(first.transitions().cloned(), first.global_state_root(), second.proof().cloned()).unwrap();
+        let invalid_transaction =
+            Transaction::from_execution(invalid_execution, transactions[0].fee_transition()).unwrap();
+        let invalid_entries = [(&invalid_transaction, None), (&transactions[1], None)];
+        let invalid_batch = invalid_entries.iter().collect::<Vec<_>>();
+
+        // Ensure the execution proofs are verified in a single batch.
+        assert_eq!(vm.check_execution_proofs(&batch, rng), vec![true, true]);
+        // Ensure a batch with an invalid proof is not verified.
+        assert_eq!(vm.check_execution_proofs(&invalid_batch, rng), vec![false, false]);
+        assert!(vm.check_transactions(&invalid_entries, rng).is_err());
+
+        // Ensure the transactions are valid.
+        vm.check_transactions(&entries, rng).unwrap();
+        // Ensure the partially-verified transactions are not included in the batch.
+        assert_eq!(vm.check_execution_proofs(&batch, rng), vec![false, false]);
+    }
+
+    #[test]
+    fn test_verify_execution_stateless() {
+        let rng = &mut TestRng::default();
+        let vm = crate::vm::test_helpers::sample_vm_with_genesis_block(rng);
+
+        // Fetch an execution transaction.
+        let transaction = crate::vm::test_helpers::sample_execution_transaction_with_public_fee(rng);
+        let Transaction::Execute(_, execution, _) = transaction else { panic!("Expected an execution transaction") };
+
+        // Ensure the execution is valid against a set containing its global state root.
+        let state_roots = IndexSet::from([execution.global_state_root()]);
+        vm.verify_execution_stateless(&execution, &state_roots).unwrap();
+
+        // Ensure the execution is invalid against a set without its global state root.
+        let state_roots = IndexSet::from([rng.gen()]);
+        assert!(vm.verify_execution_stateless(&execution, &state_roots).is_err());
+        assert!(vm.verify_execution_stateless(&execution, &IndexSet::new()).is_err());
+    }
+
     #[test]
     fn test_verify_fee() {
         let rng = &mut TestRng::default();
@@ -482,12 +932,12 @@ mod tests {
                     // Ensure the proof exists.
                     assert!(fee.proof().is_some());
                     // Verify the fee.
-                    vm.check_fee_internal(&fee, execution_id).unwrap();
+                    vm.check_fee_internal(&fee, execution_id, false).unwrap();
 
                     // Ensure that deserialization doesn't break the transaction verification.
                     let serialized_fee = fee.to_string();
                     let recovered_fee: Fee<CurrentNetwork> = serde_json::from_str(&serialized_fee).unwrap();
-                    vm.check_fee_internal(&recovered_fee, execution_id).unwrap();
+                    vm.check_fee_internal(&recovered_fee, execution_id, false).unwrap();
                 }
                 _ => panic!("Expected an execution with a fee"),
             }
@@ -518,6 +968,65 @@ mod tests {
         vm.check_transaction(&valid_transaction, None, rng).unwrap();
     }
 
+    #[test]
+    fn test_check_fee_public_fee_sequencing() {
+        let rng = &mut TestRng::default();
+
+        // Initialize the VM, with public fee sequencing.
+        let vm = crate::vm::test_helpers::sample_vm().with_public_fee_sequencing();
+        // Initialize the genesis block.
+        let genesis = crate::vm::test_helpers::sample_genesis_block(rng);
+        // Update the VM.
+        vm.add_next_block(&genesis).unwrap();
+
+        // Initialize the caller.
+        let caller_private_key = crate::vm::test_helpers::sample_genesis_private_key(rng);
+        let address = Address::try_from(&caller_private_key).unwrap();
+
+        // Construct two transactions with public fees from the same payer.
+        let transactions = (0..2)
+            .map(|_| {
+                let inputs = [
+                    Value::<CurrentNetwork>::from_str(&address.to_string()).unwrap(),
+                    Value::from_str("1u64").unwrap(),
+                ];
+                vm.execute(&caller_private_key, ("credits.aleo", "transfer_public"), inputs.iter(), None, 0, None, rng)
+                    .unwrap()
+            })
+            .collect::<Vec<_>>();
+
+        // Ensure the transactions are sequenced in order.
+        for (sequence, transaction) in transactions.iter().enumerate() {
+            vm.check_transaction(transaction, None, rng).unwrap();
+            assert_eq!(vm.public_fee_sequence(&transaction.id()), Some(sequence as u64));
+        }
+        // Ensure checking a transaction again does not reserve its fee twice.
+        vm.check_transaction(&transactions[0], None, rng).unwrap();
+        let fees = transactions.iter().map(|transaction| *transaction.fee_amount().unwrap()).sum::<u64>();
+        assert_eq!(vm.pending_public_fees(&address), fees);
+
+        // Release the transactions, and ensure the pending public fees are cleared.
+        let transaction_ids = transactions.iter().map(|transaction| transaction.id()).collect::<Vec<_>>();
+        vm.release_public_fees(&transaction_ids);
+        assert_eq!(vm.pending_public_fees(&address), 0);
+        assert_eq!(vm.public_fee_sequence(&transactions[0].id()), None);
+
+        // Ensure verifying the transactions for a block does not reserve their public fees.
+        let block_transactions = transactions.iter().map(|transaction| (transaction, None)).collect::<Vec<_>>();
+        vm.check_transactions(&block_transactions, rng).unwrap();
+        assert_eq!(vm.pending_public_fees(&address), 0);
+
+        // Ensure a transaction that fails verification after its fee check does not reserve its public fee.
+        let execution = transactions[0].execution().unwrap();
+        let unproven_execution =
+            Execution::from(execution.transitions().cloned(), execution.global_state_root(), None).unwrap();
+        let unproven_transaction =
+            Transaction::from_execution(unproven_execution, transactions[0].fee_transition()).unwrap();
+        assert!(vm.check_transaction(&unproven_transaction, None, rng).is_err());
+        assert_eq!(vm.pending_public_fees(&address), 0);
+        assert_eq!(vm.public_fee_sequence(&unproven_transaction.id()), None);
+    }
+
     #[test]
     fn test_verify_deploy_and_execute() {
         // Initialize the RNG.
@@ -676,17 +1185,8 @@ function compute:
         // verifier pads the inputs with `Field::zero`s, which means that the same proof is valid for both the
         // original and the mutated executions.
         let added_output = Output::ExternalRecord(Field::zero());
-        let mutated_outputs = [transition.outputs(), &[added_output]].concat();
-        let mutated_transition = Transition::new(
-            *transition.program_id(),
-            *transition.function_name(),
-            transition.inputs().to_vec(),
-            mutated_outputs,
-            *transition.tpk(),
-            *transition.tcm(),
-            *transition.scm(),
-        )
-        .unwrap();
+        let mutated_transition =
+            TransitionBuilder::from_transition(&transition).push_output(added_output).build().unwrap();
 
         // Construct the mutated execution.
         let mutated_execution = Execution::from(
@@ -713,6 +1213,208 @@ function compute:
         let mutated_transaction = Transaction::from_execution(mutated_execution, Some(fee)).unwrap();
 
         // Ensure that the mutated transaction fails verification due to an extra output.
-        assert!(vm.check_transaction(&mutated_transaction, None, rng).is_err());
+        let error = vm.check_transaction(&mutated_transaction, None, rng).unwrap_err();
+        // Ensure the extra output is rejected by the structural checks, before the proof is verified.
+        assert!(error.to_string().contains("outputs, but the function expects"), "{error}");
+    }
+
+    #[test]
+    fn test_check_rebound_execution() {
+        let rng = &mut TestRng::default();
+
+        // Initialize the VM.
+        let vm = crate::vm::test_helpers::sample_vm();
+        // Fetch the caller's private key.
+        let caller_private_key = crate::vm::test_helpers::sample_genesis_private_key(rng);
+        // Initialize the genesis block.
+        let genesis = crate::vm::test_helpers::sample_genesis_block(rng);
+        // Update the VM.
+        vm.add_next_block(&genesis).unwrap();
+
+        // Fetch a valid execution transaction with a public fee.
+        let valid_transaction = crate::vm::test_helpers::sample_execution_transaction_with_public_fee(rng);
+        let execution = valid_transaction.execution().unwrap();
+        let transition = execution.transitions().next().unwrap();
+
+        // Rebind the future output of the transition as a public output, with the same ID.
+        let output = transition.outputs().last().unwrap();
+        assert!(matches!(output, Output::Future(..)));
+        let index = transition.outputs().len() - 1;
+        let mutated_transition = TransitionBuilder::from_transition(transition)
+            .replace_output(index, Output::Public(*output.id(), None))
+            .unwrap()
+            .build()
+            .unwrap();
+
+        // Construct the mutated execution.
+        let mutated_execution = Execution::from(
+            [mutated_transition].into_iter(),
+            execution.global_state_root(),
+            execution.proof().cloned(),
+        )
+        .unwrap();
+
+        // Authorize and compute the fee.
+        let authorization = vm
+            .authorize_fee_public(
+                &caller_private_key,
+                10_000_000,
+                100,
+                mutated_execution.to_execution_id().unwrap(),
+                rng,
+            )
+            .unwrap();
+        let fee = vm.execute_fee_authorization(authorization, None, rng).unwrap();
+        let mutated_transaction = Transaction::from_execution(mutated_execution, Some(fee)).unwrap();
+
+        // Ensure the rebound output is rejected by the structural checks.
+        let error = vm.check_transaction(&mutated_transaction, None, rng).unwrap_err();
+        assert!(error.to_string().contains("does not match 'credits.aleo/"), "{error}");
+    }
+
+    #[test]
+    fn test_partially_verified_transitions() {
+        let rng = &mut TestRng::default();
+
+        // Initialize the VM.
+        let vm = crate::vm::test_helpers::sample_vm();
+        // Fetch the caller's private key.
+        let caller_private_key = crate::vm::test_helpers::sample_genesis_private_key(rng);
+        // Initialize the genesis block.
+        let genesis = crate::vm::test_helpers::sample_genesis_block(rng);
+        // Update the VM.
+        vm.add_next_block(&genesis).unwrap();
+
+        // Verify a valid execution transaction.
+        let transaction = crate::vm::test_helpers::sample_execution_transaction_with_public_fee(rng);
+        assert!(!vm.is_partially_verified(&transaction));
+        vm.check_transaction(&transaction, None, rng).unwrap();
+        assert!(vm.is_partially_verified(&transaction));
+        let execution = transaction.execution().unwrap().clone();
+
+        // Re-bundle the execution with a new fee.
+        let authorization = vm
+            .authorize_fee_public(&caller_private_key, 10_000_000, 200, execution.to_execution_id().unwrap(), rng)
+            .unwrap();
+        let fee = vm.execute_fee_authorization(authorization, None, rng).unwrap();
+        let rebundled_transaction = Transaction::from_execution(execution.clone(), Some(fee)).unwrap();
+        assert_ne!(rebundled_transaction.id(), transaction.id());
+
+        // Ensure the re-bundled execution is already partially-verified, and remains valid.
+        assert!(vm.is_partially_verified(&rebundled_transaction));
+        vm.check_transaction(&rebundled_transaction, None, rng).unwrap();
+
+        // Ensure the same transitions under a different proof are not partially-verified.
+        let unproven_execution =
+            Execution::from(execution.transitions().cloned(), execution.global_state_root(), None).unwrap();
+        let unproven_transaction = Transaction::from_execution(unproven_execution, None).unwrap();
+        assert!(!vm.is_partially_verified(&unproven_transaction));
+
+        // Ensure the same transitions and proof under a different global state root are not partially-verified.
+        let rerooted_execution =
+            Execution::from(execution.transitions().cloned(), Default::default(), execution.proof().cloned()).unwrap();
+        let rerooted_transaction = Transaction::from_execution(rerooted_execution, None).unwrap();
+        assert!(!vm.is_partially_verified(&rerooted_transaction));
+    }
+
+    #[test]
+    fn test_check_transactions_with_verified() {
+        let rng = &mut TestRng::default();
+
+        // Initialize the VM.
+        let vm = crate::vm::test_helpers::sample_vm();
+        // Initialize the genesis block.
+        let genesis = crate::vm::test_helpers::sample_genesis_block(rng);
+        // Update the VM.
+        vm.add_next_block(&genesis).unwrap();
+
+        // Construct a transaction without an execution proof.
+        let transaction = crate::vm::test_helpers::sample_execution_transaction_with_public_fee(rng);
+        let execution = transaction.execution().unwrap();
+        let unproven_execution =
+            Execution::from(execution.transitions().cloned(), execution.global_state_root(), None).unwrap();
+        let unproven_transaction =
+            Transaction::from_execution(unproven_execution, transaction.fee_transition()).unwrap();
+        let transactions = [(&unproven_transaction, None)];
+
+        // Ensure the transaction is rejected, if it is verified in full.
+        assert!(vm.check_transactions(&transactions, rng).is_err());
+        let mut verified = VerifiedTransactions::new(VerificationPolicy::VerifyAll);
+        verified.insert_transaction(&unproven_transaction).unwrap();
+        assert!(!verified.is_trusted(&unproven_transaction));
+        assert!(vm.check_transactions_with_verified(&transactions, &verified, rng).is_err());
+
+        // Ensure the transaction is rejected, if its checksum does not match.
+        let mut verified = VerifiedTransactions::new(VerificationPolicy::TrustVerified);
+        verified.insert(
+            unproven_transaction.id(),
+            Data::Object(transaction.clone()).to_checksum::<CurrentNetwork>().unwrap(),
+        );
+        assert!(!verified.is_trusted(&unproven_transaction));
+        assert!(vm.check_transactions_with_verified(&transactions, &verified, rng).is_err());
+
+        // Ensure the proofs of a trusted transaction are not verified again.
+        verified.insert_transaction(&unproven_transaction).unwrap();
+        assert!(verified.is_trusted(&unproven_transaction));
+        vm.check_transactions_with_verified(&transactions, &verified, rng).unwrap();
+
+        // Ensure a trusted transaction is not added to the partially-verified cache.
+        assert!(!vm.is_partially_verified(&unproven_transaction));
+
+        // Ensure a valid transaction is accepted under the policy, and is no longer trusted once removed.
+        let mut verified = VerifiedTransactions::new(VerificationPolicy::TrustVerified);
+        verified.insert_transaction(&transaction).unwrap();
+        assert!(verified.is_trusted(&transaction));
+        vm.check_transactions_with_verified(&[(&transaction, None)], &verified, rng).unwrap();
+        verified.remove(&transaction.id());
+        assert!(!verified.is_trusted(&transaction));
+    }
+
+    #[test]
+    fn test_verification_chunks() {
+        type TestVM = VM<CurrentNetwork, ledger_store::helpers::memory::ConsensusMemory<CurrentNetwork>>;
+
+        let rng = &mut TestRng::default();
+
+        // Sample executions with varying verification costs.
+        let with_fee = crate::vm::test_helpers::sample_execution_transaction_with_public_fee(rng);
+        let without_fee = crate::vm::test_helpers::sample_execution_transaction_without_fee(rng);
+        let max_item_cost = TestVM::verification_cost(&with_fee);
+        assert_eq!(max_item_cost, TestVM::verification_cost(&without_fee) + 1);
+
+        // Ensure there are no chunks for no items.
+        let max_count = TestVM::MAX_PARALLEL_EXECUTE_VERIFICATIONS;
+        let no_items: &[Transaction<CurrentNetwork>] = &[];
+        assert!(TestVM::verification_chunks(no_items, max_count, |tx| tx).is_empty());
+
+        for num_items in [1, 7, max_count, max_count + 1, 3 * max_count + 17] {
+            let items = (0..num_items)
+                .map(|i| if i % 3 == 0 { with_fee.clone() } else { without_fee.clone() })
+                .collect::<Vec<_>>();
+            let chunks = TestVM::verification_chunks(&items, max_count, |tx| tx);
+
+            // Ensure the chunks cover all of the items, in order.
+            assert_eq!(chunks.iter().map(|chunk| chunk.len()).sum::<usize>(), num_items);
+            assert!(chunks.iter().flat_map(|chunk| chunk.iter()).zip(&items).all(|(a, b)| std::ptr::eq(a, b)));
+
+            // Ensure each chunk is within the limits.
+            let costs =
+                chunks.iter().map(|chunk| chunk.iter().map(TestVM::verification_cost).sum::<u64>()).collect::<Vec<_>>();
+            for (chunk, cost) in chunks.iter().zip(&costs) {
+                assert!(!chunk.is_empty());
+                assert!(chunk.len() <= max_count);
+                assert!(*cost <= TestVM::MAX_PARALLEL_VERIFICATION_COST);
+            }
+
+            // Ensure the minimum number of chunks is used.
+            let total_cost = costs.iter().sum::<u64>();
+            let expected_num_chunks =
+                num_items.div_ceil(max_count).max(total_cost.div_ceil(TestVM::MAX_PARALLEL_VERIFICATION_COST) as usize);
+            assert_eq!(chunks.len(), expected_num_chunks);
+
+            // Ensure the chunks are balanced, rather than leaving a small remainder.
+            let (min, max) = (costs.iter().min().unwrap(), costs.iter().max().unwrap());
+            assert!(max - min <= chunks.len() as u64 * max_item_cost, "Unbalanced chunk costs: {costs:?}");
+        }
     }
 }
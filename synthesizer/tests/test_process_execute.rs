@@ -55,7 +55,7 @@ fn run_test(process: Process<CurrentNetwork>, test: &ProgramTest) -> serde_yaml:
     );
 
     // Add the programs into the process.
-    let mut process = process.clone();
+    let process = process.clone();
     for program in test.programs() {
         if let Err(err) = process.add_program(program) {
             output
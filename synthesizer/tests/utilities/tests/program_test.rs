@@ -92,6 +92,12 @@ impl ExpectedTest for ProgramTest {
         // If the `randomness` field is present in the config, parse it as a `u64`.
         let randomness = test_config.get("randomness").map(|value| value.as_u64().expect("`randomness` must be a u64"));
 
+        // If the `consensus_version` field is present in the config, parse it as a `u16`.
+        let consensus_version = test_config.get("consensus_version").map(|value| {
+            let value = value.as_u64().expect("`consensus_version` must be a u16");
+            u16::try_from(value).expect("`consensus_version` must be a u16")
+        });
+
         // If the `keys` field is present in the config, parse it as a sequence of `PrivateKey`s.
         let keys = match test_config.get("keys") {
             None => Vec::new(),
@@ -117,7 +123,13 @@ impl ExpectedTest for ProgramTest {
         // Parse the remainder of the test file into a program.
         let programs = source[first_comment_start + 2 + end_first_comment + 2..]
             .split("/////////////////////////////////////////////////")
-            .map(|string| Program::<CurrentNetwork>::from_str(string).expect("Failed to parse program."))
+            .map(|string| match consensus_version {
+                Some(consensus_version) => {
+                    Program::<CurrentNetwork>::from_str_with_consensus_version(string, consensus_version)
+                }
+                None => Program::<CurrentNetwork>::from_str(string),
+            })
+            .map(|program| program.expect("Failed to parse program."))
             .collect::<Vec<_>>();
 
         // Construct the path to the expectation file.
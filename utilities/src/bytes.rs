@@ -457,8 +457,8 @@ impl<'a, T: 'a + ToBytes> ToBytes for &'a T {
 }
 
 #[inline]
-pub fn bits_from_bytes_le(bytes: &[u8]) -> impl DoubleEndedIterator<Item = bool> + '_ {
-    bytes.iter().flat_map(|byte| (0..8).map(move |i| (*byte >> i) & 1 == 1))
+pub fn bits_from_bytes_le(bytes: &[u8]) -> impl DoubleEndedIterator<Item = bool> + ExactSizeIterator + '_ {
+    (0..bytes.len() * 8).map(move |i| (bytes[i / 8] >> (i % 8)) & 1 == 1)
 }
 
 #[inline]
@@ -555,6 +555,8 @@ mod test {
             false, false, true, true, false, false, true, true, // 204
             false, false, true, true, false, false, true, false, // 76
         ]);
+        assert_eq!(bits_from_bytes_le(&[204, 76]).len(), 16);
+        assert_eq!(bits_from_bytes_le(&[204, 76]).rev().next(), Some(false));
     }
 
     #[test]
@@ -17,6 +17,7 @@ use rand::{
     distributions::{Distribution, Standard},
     rngs::StdRng,
     Rng,
+    RngCore,
     SeedableRng,
 };
 use rand_xorshift::XorShiftRng;
@@ -37,19 +38,50 @@ where
     }
 }
 
+/// The environment variable that overrides the seed of `TestRng::default`.
+pub const TEST_RNG_SEED_VAR: &str = "SNARKVM_TEST_SEED";
+
 /// A fast RNG used **solely** for testing and benchmarking, **not** for any real world purposes.
-pub struct TestRng(XorShiftRng);
+pub struct TestRng {
+    /// The underlying RNG.
+    rng: XorShiftRng,
+    /// The seed of the underlying RNG.
+    seed: u64,
+    /// The mode of the RNG.
+    mode: TestRngMode,
+}
+
+/// The mode of a `TestRng`.
+enum TestRngMode {
+    /// The draws are sampled from the underlying RNG.
+    Live,
+    /// The draws are sampled from the underlying RNG, and appended to the trace.
+    Record(Vec<u8>),
+    /// The draws are read from the trace, starting at the given position.
+    Replay(Vec<u8>, usize),
+}
 
 impl Default for TestRng {
     fn default() -> Self {
-        // Obtain the initial seed using entropy provided by the OS.
-        let seed = StdRng::from_entropy().gen();
+        // Use the seed from the environment, if it is set.
+        // Otherwise, obtain the initial seed using entropy provided by the OS.
+        let seed = match std::env::var(TEST_RNG_SEED_VAR) {
+            Ok(seed) => seed.parse().unwrap_or_else(|_| panic!("'{TEST_RNG_SEED_VAR}' must be a u64, found '{seed}'")),
+            Err(_) => StdRng::from_entropy().gen(),
+        };
 
         // Use it as the basis for the underlying Rng.
         Self::fixed(seed)
     }
 }
 
+impl core::fmt::Debug for TestRng {
+    /// Prints the seed, so that it is displayed in the failing cases of property tests.
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        f.debug_struct("TestRng").field("seed", &self.seed).finish()
+    }
+}
+
 impl TestRng {
     pub fn fixed(seed: u64) -> Self {
         // Print the seed, so it's displayed if any of the tests using `test_rng` fails.
@@ -63,7 +95,64 @@ impl TestRng {
     // been initialized in a test or benchmark and an auxiliary one is desired without
     // spamming the stdout.
     pub fn from_seed(seed: u64) -> Self {
-        Self(XorShiftRng::seed_from_u64(seed))
+        Self { rng: XorShiftRng::seed_from_u64(seed), seed, mode: TestRngMode::Live }
+    }
+
+    /// Initializes an RNG with the given seed, which records every draw to a trace.
+    /// The trace can be retrieved with `TestRng::trace`, and replayed with `TestRng::replay`.
+    pub fn recording(seed: u64) -> Self {
+        Self { mode: TestRngMode::Record(Vec::new()), ..Self::fixed(seed) }
+    }
+
+    /// Initializes an RNG that replays the draws of the given trace exactly.
+    ///
+    /// # Panics
+    /// Drawing beyond the end of the trace panics, as it indicates that the replayed code diverged.
+    pub fn replay(trace: RngTrace) -> Self {
+        Self { mode: TestRngMode::Replay(trace.draws, 0), ..Self::from_seed(trace.seed) }
+    }
+
+    /// Returns the seed of the RNG.
+    pub const fn seed(&self) -> u64 {
+        self.seed
+    }
+
+    /// Returns the trace of the draws so far, if the RNG is recording.
+    pub fn trace(&self) -> Option<RngTrace> {
+        match &self.mode {
+            TestRngMode::Record(draws) => Some(RngTrace { seed: self.seed, draws: draws.clone() }),
+            _ => None,
+        }
+    }
+
+    /// Returns the number of bytes that remain to be replayed, if the RNG is replaying.
+    pub fn remaining(&self) -> Option<usize> {
+        match &self.mode {
+            TestRngMode::Replay(draws, position) => Some(draws.len() - position),
+            _ => None,
+        }
+    }
+
+    /// Fills the given buffer with the next draw, in the mode of the RNG.
+    fn draw(&mut self, dest: &mut [u8]) {
+        match &mut self.mode {
+            TestRngMode::Live => self.rng.fill_bytes(dest),
+            TestRngMode::Record(draws) => {
+                self.rng.fill_bytes(dest);
+                draws.extend_from_slice(dest);
+            }
+            TestRngMode::Replay(draws, position) => {
+                let end = *position + dest.len();
+                assert!(
+                    end <= draws.len(),
+                    "The RNG trace is exhausted after {} bytes (seed '{}'), so the replayed code has diverged",
+                    draws.len(),
+                    self.seed
+                );
+                dest.copy_from_slice(&draws[*position..end]);
+                *position = end;
+            }
+        }
     }
 
     /// Returns a randomly-sampled `String`, given the maximum size in bytes and an RNG.
@@ -131,20 +220,120 @@ impl TestRng {
 
 impl rand::RngCore for TestRng {
     fn next_u32(&mut self) -> u32 {
-        self.0.next_u32()
+        match self.mode {
+            TestRngMode::Live => self.rng.next_u32(),
+            _ => {
+                let mut bytes = [0u8; 4];
+                self.draw(&mut bytes);
+                u32::from_le_bytes(bytes)
+            }
+        }
     }
 
     fn next_u64(&mut self) -> u64 {
-        self.0.next_u64()
+        match self.mode {
+            TestRngMode::Live => self.rng.next_u64(),
+            _ => {
+                let mut bytes = [0u8; 8];
+                self.draw(&mut bytes);
+                u64::from_le_bytes(bytes)
+            }
+        }
     }
 
     fn fill_bytes(&mut self, dest: &mut [u8]) {
-        self.0.fill_bytes(dest)
+        self.draw(dest)
     }
 
     fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), rand::Error> {
-        self.0.try_fill_bytes(dest)
+        self.draw(dest);
+        Ok(())
     }
 }
 
 impl rand::CryptoRng for TestRng {}
+
+/// A compact trace of the draws of a `TestRng`, which can be replayed exactly with `TestRng::replay`.
+///
+/// The trace is printed as `seed:hex`, where `hex` is the hex encoding of the drawn bytes,
+/// so that it can be stored alongside a failing test and parsed back with `RngTrace::from_str`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RngTrace {
+    /// The seed of the recorded RNG.
+    seed: u64,
+    /// The drawn bytes, in order.
+    draws: Vec<u8>,
+}
+
+impl RngTrace {
+    /// Returns the seed of the recorded RNG.
+    pub const fn seed(&self) -> u64 {
+        self.seed
+    }
+
+    /// Returns the drawn bytes, in order.
+    pub fn draws(&self) -> &[u8] {
+        &self.draws
+    }
+}
+
+impl core::fmt::Display for RngTrace {
+    /// Prints the trace as `seed:hex`.
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        write!(f, "{}:", self.seed)?;
+        self.draws.iter().try_for_each(|byte| write!(f, "{byte:02x}"))
+    }
+}
+
+impl core::str::FromStr for RngTrace {
+    type Err = anyhow::Error;
+
+    /// Parses a trace from `seed:hex`.
+    fn from_str(string: &str) -> Result<Self, Self::Err> {
+        let (seed, hex) = string.trim().split_once(':').ok_or_else(|| anyhow::anyhow!("Expected 'seed:hex'"))?;
+        anyhow::ensure!(
+            hex.is_ascii() && hex.len() % 2 == 0,
+            "The RNG trace must have an even number of hex characters"
+        );
+        let draws =
+            (0..hex.len()).step_by(2).map(|i| u8::from_str_radix(&hex[i..i + 2], 16)).collect::<Result<Vec<_>, _>>()?;
+        Ok(Self { seed: seed.parse()?, draws })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use core::str::FromStr;
+
+    #[test]
+    fn test_record_and_replay() {
+        // Record the draws.
+        let mut rng = TestRng::recording(123);
+        let expected = (rng.gen::<u64>(), rng.gen::<[u8; 13]>(), rng.gen::<u32>(), rng.next_string(16, true));
+        let trace = rng.trace().unwrap();
+
+        // Ensure the recorded draws match the live draws.
+        let mut live = TestRng::from_seed(123);
+        assert_eq!(
+            expected,
+            (live.gen::<u64>(), live.gen::<[u8; 13]>(), live.gen::<u32>(), live.next_string(16, true))
+        );
+
+        // Ensure the trace roundtrips, and replays the draws exactly.
+        let trace = RngTrace::from_str(&trace.to_string()).unwrap();
+        let mut replay = TestRng::replay(trace);
+        assert_eq!(
+            expected,
+            (replay.gen::<u64>(), replay.gen::<[u8; 13]>(), replay.gen::<u32>(), replay.next_string(16, true))
+        );
+        assert_eq!(replay.remaining(), Some(0));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_replay_exhausted() {
+        let mut rng = TestRng::replay(RngTrace::from_str("0:0011").unwrap());
+        rng.gen::<u32>();
+    }
+}